@@ -3,6 +3,17 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+#[cfg(feature = "num-bigint")]
+pub mod bid;
+#[cfg(feature = "num-bigint")]
+pub mod bigint;
+pub mod bignum;
+pub mod binary128;
 pub mod display;
+#[cfg(feature = "rug")]
+pub mod mpfr;
+pub mod parse;
+#[cfg(feature = "postgres")]
+pub mod postgres;
 pub mod primitive;
 pub mod utils;
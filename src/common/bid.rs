@@ -0,0 +1,222 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::bigint as b;
+use num_bigint::{BigInt, BigUint, Sign};
+use std::convert::TryFrom;
+
+/// The parameters of an IEEE 754 decimal interchange format, as encoded in binary integer
+/// decimal (BID).
+///
+/// A BID number is a sign bit, a biased exponent, and an integer coefficient, with a value
+/// of the coefficient times ten to the exponent. The exponent bits normally come right after
+/// the sign, followed by the coefficient. If the two bits after the sign are both set,
+/// though, the exponent comes after those two bits instead, and the coefficient is the
+/// remaining bits with `100` in front of them; that's how coefficients too large for the
+/// normal field are encoded. Five set bits after the sign mean `NaN`, and four set bits
+/// followed by a clear one mean infinity.
+pub struct Format {
+    /// The total number of bits.
+    pub bits: u32,
+    /// The number of decimal digits in the coefficient.
+    pub digits: u32,
+    /// The number of bits in the coefficient field, in the normal encoding.
+    pub coef_bits: u32,
+    /// The smallest exponent (that of the smallest subnormal).
+    pub qmin: i32,
+    /// The largest exponent (that of the largest finite number, when its coefficient has all
+    /// of its digits).
+    pub qmax: i32,
+}
+
+/// The decimal64 format, with 16 digits and exponents from -398 to 369.
+pub const DECIMAL64: Format = Format {
+    bits: 64,
+    digits: 16,
+    coef_bits: 53,
+    qmin: -398,
+    qmax: 369,
+};
+
+/// The decimal128 format, with 34 digits and exponents from -6176 to 6111.
+pub const DECIMAL128: Format = Format {
+    bits: 128,
+    digits: 34,
+    coef_bits: 113,
+    qmin: -6176,
+    qmax: 6111,
+};
+
+impl Format {
+    fn sign_bit(&self) -> u128 {
+        1 << (self.bits - 1)
+    }
+
+    fn nan(&self) -> u128 {
+        0x1f << (self.bits - 6)
+    }
+
+    fn infinity(&self) -> u128 {
+        0x1e << (self.bits - 6)
+    }
+
+    fn exp_mask(&self) -> u128 {
+        (1 << (self.bits - 1 - self.coef_bits)) - 1
+    }
+}
+
+/// Adds the exact values of a number of `f64`s together and rounds the sum to the nearest
+/// number in the given decimal format (ties to even), returning its bits.
+///
+/// The parts are meant to be the components of a `Double` or `Quad`, so the first one decides
+/// whether the result is `NaN`, infinite, or zero. The coefficient of the result has no
+/// trailing zeros unless the exponent would have to go past its maximum to remove them, and
+/// zero has an exponent of zero.
+pub fn to_bits(parts: &[f64], format: &Format) -> u128 {
+    let first = parts[0];
+    let sign = if first.is_sign_negative() {
+        format.sign_bit()
+    } else {
+        0
+    };
+    if first.is_nan() {
+        return format.nan();
+    }
+    if first.is_infinite() {
+        return sign | format.infinity();
+    }
+
+    let (mantissa, exp) = b::sum_dyadic(parts);
+    if mantissa.sign() == Sign::NoSign {
+        return sign | encode(format, 0, 0);
+    }
+    let sign = if mantissa.sign() == Sign::Minus {
+        format.sign_bit()
+    } else {
+        0
+    };
+    let (num, den) = if exp >= 0 {
+        (mantissa.magnitude() << exp as usize, BigUint::from(1u32))
+    } else {
+        (
+            mantissa.magnitude().clone(),
+            BigUint::from(1u32) << -exp as usize,
+        )
+    };
+
+    // The exponent is the smallest one that leaves a coefficient (after rounding) with no more
+    // digits than the format can hold. This starts from an estimate of that and moves it
+    // until it's actually the case, or until it's as small as it can go.
+    let max_coef = BigUint::from(10u32).pow(format.digits);
+    let log2 = num.bits() as f64 - den.bits() as f64;
+    let mut q = ((log2 * std::f64::consts::LOG10_2).floor() as i32 - format.digits as i32 + 1)
+        .max(format.qmin);
+    let mut coef = round_quotient(&num, &den, q);
+    loop {
+        if coef >= max_coef {
+            q += 1;
+            coef = round_quotient(&num, &den, q);
+        } else if q > format.qmin {
+            let lower = round_quotient(&num, &den, q - 1);
+            if lower >= max_coef {
+                break;
+            }
+            q -= 1;
+            coef = lower;
+        } else {
+            break;
+        }
+    }
+
+    if coef.bits() == 0 {
+        return sign | encode(format, 0, 0);
+    }
+    if q > format.qmax {
+        return sign | format.infinity();
+    }
+    let ten = BigUint::from(10u32);
+    while q < format.qmax && (&coef % &ten).bits() == 0 {
+        coef /= &ten;
+        q += 1;
+    }
+    sign | encode(format, u128::try_from(coef).unwrap(), q)
+}
+
+/// Splits the value of a number in the given decimal format, given by its bits, into
+/// `parts` `f64`s. The first is the number rounded to the nearest `f64`, and each one after
+/// it is what's left of it, once the ones before it have been subtracted, rounded the same
+/// way.
+///
+/// A number too large for an `f64` becomes an infinity, and one too small is rounded into
+/// the subnormals or to zero. Any `NaN` is `NaN`, whatever its payload, and a coefficient
+/// that's too large for the format (which IEEE 754 calls non-canonical) is zero.
+pub fn from_bits(bits: u128, parts: usize, format: &Format) -> Vec<f64> {
+    let negative = bits & format.sign_bit() != 0;
+    let sign = if negative { -1.0 } else { 1.0 };
+    let mut result = vec![0.0; parts];
+
+    let top = bits >> (format.bits - 6) & 0x1f;
+    if top == 0x1f {
+        result[0] = f64::NAN;
+        return result;
+    }
+    if top == 0x1e {
+        result[0] = sign * f64::INFINITY;
+        return result;
+    }
+
+    let (biased, coef) = if bits >> (format.bits - 3) & 0b11 == 0b11 {
+        let shift = format.coef_bits - 2;
+        let coef = bits & ((1 << shift) - 1) | 0b100 << shift;
+        (bits >> shift & format.exp_mask(), coef)
+    } else {
+        let coef = bits & ((1 << format.coef_bits) - 1);
+        (bits >> format.coef_bits & format.exp_mask(), coef)
+    };
+    let q = biased as i32 + format.qmin;
+    if coef == 0 || coef >= 10u128.pow(format.digits) {
+        result[0] = sign * 0.0;
+        return result;
+    }
+
+    let coef = BigInt::from(coef);
+    let coef = if negative { -coef } else { coef };
+    let (num, den) = if q >= 0 {
+        (coef * BigInt::from(10).pow(q as u32), BigInt::from(1))
+    } else {
+        (coef, BigInt::from(10).pow(-q as u32))
+    };
+    let mut result = b::split_fraction(num, den, parts);
+    if result[0] == 0.0 {
+        result[0] = sign * 0.0;
+    }
+    result
+}
+
+fn encode(format: &Format, coef: u128, q: i32) -> u128 {
+    let biased = (q - format.qmin) as u128;
+    if coef >> format.coef_bits == 0 {
+        biased << format.coef_bits | coef
+    } else {
+        let shift = format.coef_bits - 2;
+        0b11 << (format.bits - 3) | biased << shift | coef & ((1 << shift) - 1)
+    }
+}
+
+// Divides `num` by `den` times 10^q, rounding to the nearest integer with ties going to even.
+fn round_quotient(num: &BigUint, den: &BigUint, q: i32) -> BigUint {
+    let (num, den) = if q >= 0 {
+        (num.clone(), den * BigUint::from(10u32).pow(q as u32))
+    } else {
+        (num * BigUint::from(10u32).pow(-q as u32), den.clone())
+    };
+    let quotient = &num / &den;
+    let twice = (num % &den) * 2u32;
+    if twice > den || (twice == den && quotient.bit(0)) {
+        quotient + 1u32
+    } else {
+        quotient
+    }
+}
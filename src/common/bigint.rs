@@ -0,0 +1,191 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+#[cfg(feature = "bigdecimal")]
+use bigdecimal::BigDecimal;
+use num_bigint::{BigInt, BigUint, Sign};
+#[cfg(feature = "num-rational")]
+use num_rational::Ratio;
+
+/// Splits a finite `f64` into an integer mantissa and a binary exponent, so that `x` is
+/// exactly `mantissa * 2^exp`.
+pub fn dyadic(x: f64) -> (BigInt, i64) {
+    let bits = x.to_bits();
+    let biased = ((bits >> 52) & 0x7ff) as i64;
+    let fraction = bits & 0xf_ffff_ffff_ffff;
+    // Subnormals have no implicit leading bit and the same exponent as the smallest normals
+    let (mantissa, exp) = if biased == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased - 1075)
+    };
+    let sign = if x < 0.0 { Sign::Minus } else { Sign::Plus };
+    (BigInt::from_biguint(sign, BigUint::from(mantissa)), exp)
+}
+
+/// Converts an `f64` with an integral value to a `BigInt`, exactly. All of the components of
+/// a finite number that has been truncated are integral, so the integer part of a `Double`
+/// or `Quad` is the sum of its components converted by this function.
+pub fn from_integral(x: f64) -> BigInt {
+    let (mantissa, exp) = dyadic(x);
+    // An integer has no bits below the ones place, so shifting right loses nothing
+    if exp >= 0 {
+        mantissa << exp as u64
+    } else {
+        mantissa >> (-exp) as u64
+    }
+}
+
+/// Adds the exact values of a number of finite `f64`s together into an integer mantissa and
+/// a binary exponent, so that the sum is exactly `mantissa * 2^exp`. Zero is 0 * 2⁰.
+pub fn sum_dyadic(parts: &[f64]) -> (BigInt, i64) {
+    let parts: Vec<(BigInt, i64)> = parts
+        .iter()
+        .filter(|&&x| x != 0.0)
+        .map(|&x| dyadic(x))
+        .collect();
+    let min = parts.iter().map(|(_, exp)| *exp).min().unwrap_or(0);
+    let mantissa = parts
+        .into_iter()
+        .map(|(mantissa, exp)| mantissa << (exp - min) as u64)
+        .sum();
+    (mantissa, min)
+}
+
+/// Adds the exact values of a number of finite `f64`s together into a `Ratio`, whose
+/// denominator is a power of two.
+#[cfg(feature = "num-rational")]
+pub fn to_ratio(parts: &[f64]) -> Ratio<BigInt> {
+    let (numer, exp) = sum_dyadic(parts);
+    if exp >= 0 {
+        Ratio::from_integer(numer << exp as u64)
+    } else {
+        Ratio::new(numer, BigInt::from(1) << (-exp) as u64)
+    }
+}
+
+/// Splits a fraction (with a positive denominator) into `parts` `f64`s. The first is the
+/// fraction correctly rounded to the nearest `f64`, with ties going to even, and each one
+/// after it is what's left, once the ones before it have been subtracted, rounded the same
+/// way.
+///
+/// The subtraction is exact, so a fraction that's the exact sum of the components of a
+/// `Double` or `Quad` (each of which is the nearest `f64` to the rest of the sum) gives back
+/// exactly those components. A fraction too large for an `f64` makes its part infinite (and
+/// the rest zero), and one too small is rounded into the subnormals or to a zero with the
+/// fraction's sign.
+pub fn split_fraction(mut num: BigInt, mut den: BigInt, parts: usize) -> Vec<f64> {
+    let mut result = vec![0.0; parts];
+    for (i, part) in result.iter_mut().enumerate() {
+        if num.sign() == Sign::NoSign {
+            break;
+        }
+        let (q, lsb) = match nearest(&num, &den) {
+            Some(nearest) => nearest,
+            None => {
+                *part = if num.sign() == Sign::Minus {
+                    f64::NEG_INFINITY
+                } else {
+                    f64::INFINITY
+                };
+                break;
+            }
+        };
+        *part = q as f64 * ldexp(1.0, lsb);
+        if q == 0 {
+            // Nothing that's left can be represented, but a fraction that rounds to zero
+            // altogether keeps its sign
+            if i == 0 && num.sign() == Sign::Minus {
+                *part = -0.0;
+            }
+            break;
+        }
+        // What's left over is num/den - q·2^lsb
+        let q = BigInt::from(q);
+        if lsb >= 0 {
+            num -= (q * &den) << lsb as usize;
+        } else {
+            num = (num << -lsb as usize) - q * &den;
+            den <<= -lsb as usize;
+        }
+    }
+    result
+}
+
+/// Adds the exact values of a number of finite `f64`s together into a `BigDecimal`. A
+/// negative power of two 2⁻ⁿ is 5ⁿ × 10⁻ⁿ, so each of them has an exact decimal expansion.
+/// Trailing zeros are removed from the result.
+#[cfg(feature = "bigdecimal")]
+pub fn to_bigdecimal(parts: &[f64]) -> BigDecimal {
+    let sum: BigDecimal = parts
+        .iter()
+        .filter(|&&x| x != 0.0)
+        .map(|&x| {
+            let (mantissa, exp) = dyadic(x);
+            if exp >= 0 {
+                BigDecimal::new(mantissa << exp as u64, 0)
+            } else {
+                BigDecimal::new(mantissa * BigInt::from(5).pow((-exp) as u32), -exp)
+            }
+        })
+        .sum();
+    sum.normalized()
+}
+
+// Rounds a non-zero fraction (with a positive denominator) to the nearest `f64`, with ties
+// going to even. That's returned as an integer mantissa and the power of two that its last
+// bit is worth, so that the caller can subtract it back off exactly. The mantissa is signed
+// like the fraction, and it's zero if the fraction rounds to zero. If the fraction is too
+// large for an `f64`, `None` is returned.
+fn nearest(num: &BigInt, den: &BigInt) -> Option<(i64, i32)> {
+    let mag = num.magnitude();
+    let den = den.magnitude();
+
+    // The fraction is in [2^high, 2^(high + 1))
+    let mut high = mag.bits() as i64 - den.bits() as i64;
+    let below = if high >= 0 {
+        mag < &(den << high as usize)
+    } else {
+        &(mag << -high as usize) < den
+    };
+    if below {
+        high -= 1;
+    }
+    if high > 1023 {
+        return None;
+    }
+    if high < -1076 {
+        return Some((0, -1074));
+    }
+
+    // Take one bit past the last one that fits, along with whether anything is left after it
+    let lsb = (high - 52).max(-1074) as i32;
+    let shift = 1 - lsb as i64;
+    let (q, r) = if shift >= 0 {
+        let mag = mag << shift as usize;
+        (&mag / den, mag % den)
+    } else {
+        let den = den << -shift as usize;
+        (mag / &den, mag % den)
+    };
+    let half = q.bit(0);
+    let mut q: BigUint = q >> 1;
+    if half && (r.bits() > 0 || q.bit(0)) {
+        q += 1u32;
+    }
+    // Rounding up to 2^53 at the top of the largest binade is the only way to overflow
+    if high == 1023 && q.bit(53) {
+        return None;
+    }
+    let q = q.iter_u64_digits().next().unwrap_or(0) as i64;
+    Some((if num.sign() == Sign::Minus { -q } else { q }, lsb))
+}
+
+// Multiplies by 2^n in two steps, so that neither factor overflows or underflows on its own
+// and the result is rounded only once.
+fn ldexp(x: f64, n: i32) -> f64 {
+    let half = n / 2;
+    x * 2f64.powi(half) * 2f64.powi(n - half)
+}
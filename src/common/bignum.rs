@@ -0,0 +1,217 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::cmp::Ordering;
+use std::ops::{Deref, DerefMut};
+
+// Arithmetic on unsigned integers too large for any primitive type, which is needed to parse
+// and format numbers exactly. They're stored as `u32` limbs, least significant first, with
+// no zero limbs at the top. Zero has no limbs at all.
+
+// Storage for the limbs of an integer, which can grow and shrink at the top end.
+pub trait Limbs: Deref<Target = [u32]> + DerefMut {
+    fn push(&mut self, limb: u32);
+    fn truncate(&mut self, len: usize);
+}
+
+impl Limbs for Vec<u32> {
+    fn push(&mut self, limb: u32) {
+        Vec::push(self, limb);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        Vec::truncate(self, len);
+    }
+}
+
+// The number of limbs in a `Fixed`. That's enough for any integer that comes up in parsing
+// a decimal or hex string, none of which have more than about 5800 bits.
+const FIXED_LIMBS: usize = 192;
+
+// An integer whose limbs are kept in an array rather than a vector, so that working with it
+// never allocates.
+#[derive(Clone)]
+pub struct Fixed {
+    limbs: [u32; FIXED_LIMBS],
+    len: usize,
+}
+
+impl Fixed {
+    pub fn new() -> Fixed {
+        Fixed {
+            limbs: [0; FIXED_LIMBS],
+            len: 0,
+        }
+    }
+
+    // An integer that's just 2^n.
+    pub fn pow2(n: usize) -> Fixed {
+        let mut result = Fixed::new();
+        result.push(1);
+        shl_assign(&mut result, n);
+        result
+    }
+}
+
+impl Deref for Fixed {
+    type Target = [u32];
+
+    fn deref(&self) -> &[u32] {
+        &self.limbs[..self.len]
+    }
+}
+
+impl DerefMut for Fixed {
+    fn deref_mut(&mut self) -> &mut [u32] {
+        &mut self.limbs[..self.len]
+    }
+}
+
+impl Limbs for Fixed {
+    fn push(&mut self, limb: u32) {
+        self.limbs[self.len] = limb;
+        self.len += 1;
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.len = self.len.min(len);
+    }
+}
+
+// Drops any zero limbs from the top of an integer.
+fn trim<L: Limbs>(a: &mut L) {
+    let len = a.iter().rposition(|&limb| limb != 0).map_or(0, |i| i + 1);
+    a.truncate(len);
+}
+
+// Multiplies an integer by `m` and adds `add`.
+pub fn mul_small<L: Limbs>(a: &mut L, m: u32, add: u32) {
+    let mut carry = add as u64;
+    for limb in a.iter_mut() {
+        let t = *limb as u64 * m as u64 + carry;
+        *limb = t as u32;
+        carry = t >> 32;
+    }
+    if carry > 0 {
+        a.push(carry as u32);
+    }
+}
+
+// Shifts an integer left by `n` bits.
+pub fn shl(a: &[u32], n: usize) -> Vec<u32> {
+    if a.is_empty() {
+        return vec![];
+    }
+    let (limbs, bits) = (n / 32, n % 32);
+    let mut result = vec![0; limbs];
+    if bits == 0 {
+        result.extend_from_slice(a);
+    } else {
+        let mut carry = 0;
+        for &limb in a {
+            result.push(limb << bits | carry);
+            carry = limb >> (32 - bits);
+        }
+        if carry > 0 {
+            result.push(carry);
+        }
+    }
+    result
+}
+
+// Shifts an integer left by `n` bits in place.
+pub fn shl_assign<L: Limbs>(a: &mut L, n: usize) {
+    if a.is_empty() {
+        return;
+    }
+    let (limbs, bits) = (n / 32, n % 32);
+    let len = a.len();
+    for _ in 0..(limbs + 1) {
+        a.push(0);
+    }
+    for i in (0..=len).rev() {
+        let high = if i < len { a[i] << bits } else { 0 };
+        let low = if i > 0 && bits > 0 {
+            a[i - 1] >> (32 - bits)
+        } else {
+            0
+        };
+        a[i + limbs] = high | low;
+    }
+    for limb in a[..limbs].iter_mut() {
+        *limb = 0;
+    }
+    trim(a);
+}
+
+// Subtracts `b` from `a`, which must be at least as large.
+pub fn sub_assign<L: Limbs>(a: &mut L, b: &[u32]) {
+    let mut borrow = 0;
+    for (i, limb) in a.iter_mut().enumerate() {
+        let t = *limb as i64 - b.get(i).copied().unwrap_or(0) as i64 - borrow;
+        *limb = t as u32;
+        borrow = if t < 0 { 1 } else { 0 };
+    }
+    trim(a);
+}
+
+pub fn compare(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+pub fn bits(a: &[u32]) -> usize {
+    match a.last() {
+        Some(top) => 32 * a.len() - top.leading_zeros() as usize,
+        None => 0,
+    }
+}
+
+// Divides an integer by `d` in place, returning the remainder.
+pub fn div_small<L: Limbs>(a: &mut L, d: u32) -> u32 {
+    let mut rem = 0u64;
+    for limb in a.iter_mut().rev() {
+        let t = rem << 32 | *limb as u64;
+        *limb = (t / d as u64) as u32;
+        rem = t % d as u64;
+    }
+    trim(a);
+    rem as u32
+}
+
+// Determines whether bit `n` of an integer is set.
+pub fn bit(a: &[u32], n: usize) -> bool {
+    matches!(a.get(n / 32), Some(limb) if limb >> (n % 32) & 1 == 1)
+}
+
+// Keeps only the lowest `n` bits of an integer.
+pub fn truncate_bits<L: Limbs>(a: &mut L, n: usize) {
+    let (limbs, bits) = (n / 32, n % 32);
+    if limbs < a.len() {
+        a.truncate(limbs + 1);
+        a[limbs] &= (1 << bits) - 1;
+    }
+    trim(a);
+}
+
+// Converts an integer of no more than 64 bits to a `u64`.
+pub fn to_u64(a: &[u32]) -> u64 {
+    a.iter().rev().fold(0, |acc, &limb| acc << 32 | limb as u64)
+}
+
+// Determines whether any of the lowest `n` bits of an integer are set.
+pub fn low_bits_set(a: &[u32], n: usize) -> bool {
+    let (limbs, bits) = (n / 32, n % 32);
+    a.iter().take(limbs).any(|&limb| limb != 0)
+        || matches!(a.get(limbs), Some(limb) if limb & ((1 << bits) - 1) != 0)
+}
+
+// Returns the bits of an integer from bit `n` up, which have to fit into a `u64`.
+pub fn high_bits(a: &[u32], n: usize) -> u64 {
+    let (limbs, bits) = (n / 32, n % 32);
+    let limb = |i: usize| a.get(limbs + i).copied().unwrap_or(0) as u128;
+    ((limb(0) | limb(1) << 32 | limb(2) << 64) >> bits) as u64
+}
@@ -0,0 +1,421 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::cmp::Ordering;
+
+// The layout of an IEEE 754 binary128: one sign bit, fifteen exponent bits, and 112
+// fraction bits, with an implicit leading bit giving 113 bits of precision in all.
+const FRAC_BITS: u32 = 112;
+const FRAC_MASK: u128 = (1 << FRAC_BITS) - 1;
+const EXP_MASK: u128 = 0x7fff;
+const EXP_BIAS: i32 = 16383;
+const SIGN_BIT: u128 = 1 << 127;
+const INF_BITS: u128 = EXP_MASK << FRAC_BITS;
+const NAN_BITS: u128 = INF_BITS | 1 << (FRAC_BITS - 1);
+
+// Enough 64-bit words to hold the exact sum of any four finite `f64`s as a two's
+// complement integer. Bit `i` of the accumulator has a weight of 2^(i - OFFSET), so the
+// lowest bit is the smallest subnormal `f64`. The accumulator is also used to write out the
+// exact values of `Double`s and `Quad`s in hex.
+pub const WORDS: usize = 34;
+pub const OFFSET: i32 = 1074;
+
+/// Adds the exact values of a number of `f64`s together and rounds the sum to the nearest
+/// binary128 (ties to even), returning its bits.
+///
+/// The parts are meant to be the components of a `Double` or `Quad`, so the first one decides
+/// whether the result is `NaN`, infinite, or zero. Every finite sum of them fits well within
+/// the range of a binary128, so there's never any overflow or underflow.
+pub fn to_bits(parts: &[f64]) -> u128 {
+    let first = parts[0];
+    let sign = if first.is_sign_negative() {
+        SIGN_BIT
+    } else {
+        0
+    };
+    if first.is_nan() {
+        return NAN_BITS;
+    }
+    if first.is_infinite() {
+        return sign | INF_BITS;
+    }
+
+    let mut acc = [0u64; WORDS];
+    for &x in parts {
+        accumulate(&mut acc, x);
+    }
+    let negative = acc[WORDS - 1] >> 63 == 1;
+    if negative {
+        negate(&mut acc);
+    }
+    let high = match highest_bit(&acc) {
+        Some(high) => high,
+        None => return sign,
+    };
+    let sign = if negative { SIGN_BIT } else { 0 };
+
+    let mut mantissa = 0u128;
+    for i in 0..=FRAC_BITS as i32 {
+        mantissa = mantissa << 1 | bit(&acc, high - i) as u128;
+    }
+    let round = bit(&acc, high - FRAC_BITS as i32 - 1);
+    let sticky = any_below(&acc, high - FRAC_BITS as i32 - 1);
+    let mut exp = high - OFFSET;
+    if round && (sticky || mantissa & 1 == 1) {
+        mantissa += 1;
+        if mantissa >> (FRAC_BITS + 1) == 1 {
+            mantissa >>= 1;
+            exp += 1;
+        }
+    }
+    sign | ((exp + EXP_BIAS) as u128) << FRAC_BITS | mantissa & FRAC_MASK
+}
+
+/// Splits the value of a binary128, given by its bits, into `parts` `f64`s. The first is the
+/// binary128 rounded to the nearest `f64`, and each one after it is what's left of it, once
+/// the ones before it have been subtracted, rounded the same way.
+///
+/// Two parts are enough to round a binary128 to the nearest `Double`, and three always hold
+/// it exactly, as long as it's within the range of an `f64`. One too large for that becomes
+/// an infinity, and one too small is rounded into the subnormals or to zero. A binary128 `NaN`
+/// is `NaN`, whatever its payload.
+pub fn from_bits(bits: u128, parts: usize) -> Vec<f64> {
+    let sign = if bits & SIGN_BIT == 0 { 1.0 } else { -1.0 };
+    let biased = (bits >> FRAC_BITS & EXP_MASK) as i32;
+    let fraction = bits & FRAC_MASK;
+
+    let mut result = vec![0.0; parts];
+    if biased == EXP_MASK as i32 {
+        result[0] = if fraction == 0 {
+            sign * f64::INFINITY
+        } else {
+            f64::NAN
+        };
+        return result;
+    }
+    // Anything smaller than half of the smallest subnormal `f64` rounds to zero. That includes
+    // all of the binary128 subnormals.
+    let exp = biased - EXP_BIAS;
+    if biased == 0 || exp < -OFFSET - 1 {
+        result[0] = sign * 0.0;
+        return result;
+    }
+    if exp > 1023 {
+        result[0] = sign * f64::INFINITY;
+        return result;
+    }
+
+    // The value is the mantissa times 2^scale. Conversions from integers to floats round to
+    // the nearest, so each part is just what's left of the mantissa, rounded.
+    let scale = exp - FRAC_BITS as i32;
+    let mut rest = (fraction | 1 << FRAC_BITS) as i128;
+    for part in result.iter_mut() {
+        let x = rest as f64;
+        rest -= x as i128;
+        *part = sign * ldexp(x, scale);
+    }
+    if result[0].is_infinite() {
+        for part in result[1..].iter_mut() {
+            *part = 0.0;
+        }
+    }
+    result
+}
+
+// Multiplies by 2^n in two steps, so that neither factor overflows or underflows on its own
+// and the result is rounded only once.
+fn ldexp(x: f64, n: i32) -> f64 {
+    let half = n / 2;
+    x * 2f64.powi(half) * 2f64.powi(n - half)
+}
+
+// The accumulator used to find the signs of sums of `f64`s and of their products needs
+// more room, since a product of two `f64`s can be as small as 2^-2148 and as large as
+// 2^2048. This is enough for the exact sum of a few dozen of them.
+const WIDE_WORDS: usize = 67;
+const WIDE_OFFSET: usize = 2148;
+
+/// Finds the sign of the exact sum of the `f64`s in `terms` and of the products of the
+/// pairs of `f64`s in `products`, returning whether it's greater than, less than, or equal
+/// to zero.
+///
+/// Every product is added in exactly, however large or small it is, so the sign is always
+/// right. Infinities and `NaN` are ignored, as they are by `accumulate`.
+pub fn sum_sign(terms: &[f64], products: &[(f64, f64)]) -> Ordering {
+    let mut acc = [0u64; WIDE_WORDS];
+    for &x in terms {
+        if let Some((mantissa, shift)) = decompose(x) {
+            let shift = shift + WIDE_OFFSET - OFFSET as usize;
+            add_shifted(&mut acc, mantissa as u128, shift, x < 0.0);
+        }
+    }
+    for &(a, b) in products {
+        if let (Some((ma, sa)), Some((mb, sb))) = (decompose(a), decompose(b)) {
+            let negative = (a < 0.0) != (b < 0.0);
+            add_shifted(&mut acc, ma as u128 * mb as u128, sa + sb, negative);
+        }
+    }
+    if acc[WIDE_WORDS - 1] >> 63 == 1 {
+        Ordering::Less
+    } else if acc.iter().any(|&w| w != 0) {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+// Splits a finite `f64` that isn't zero into an integer mantissa and the power of two that
+// it's multiplied by, less the exponent of the smallest subnormal.
+fn decompose(x: f64) -> Option<(u64, usize)> {
+    if x == 0.0 || !x.is_finite() {
+        return None;
+    }
+    let bits = x.to_bits();
+    let biased = (bits >> 52 & 0x7ff) as usize;
+    if biased == 0 {
+        Some((bits & 0xf_ffff_ffff_ffff, 0))
+    } else {
+        Some((bits & 0xf_ffff_ffff_ffff | 1 << 52, biased - 1))
+    }
+}
+
+// Adds (or subtracts) an integer, shifted left by `shift` bits, to a two's complement
+// accumulator of any size.
+fn add_shifted(acc: &mut [u64], value: u128, shift: usize, negative: bool) {
+    let (word, r) = (shift / 64, shift % 64);
+    let parts = if r == 0 {
+        [value as u64, (value >> 64) as u64, 0]
+    } else {
+        [
+            (value << r) as u64,
+            (value >> (64 - r)) as u64,
+            (value >> (128 - r)) as u64,
+        ]
+    };
+
+    let mut carry = false;
+    for (i, slot) in acc.iter_mut().enumerate().skip(word) {
+        let part = parts.get(i - word).copied().unwrap_or(0);
+        if i - word >= parts.len() && !carry {
+            break;
+        }
+        let (x, c1) = if negative {
+            slot.overflowing_sub(part)
+        } else {
+            slot.overflowing_add(part)
+        };
+        let (x, c2) = if negative {
+            x.overflowing_sub(carry as u64)
+        } else {
+            x.overflowing_add(carry as u64)
+        };
+        *slot = x;
+        carry = c1 || c2;
+    }
+}
+
+// Adds (or subtracts, if it's negative) the exact value of an `f64` to the accumulator.
+pub fn accumulate(acc: &mut [u64; WORDS], x: f64) {
+    if let Some((mantissa, shift)) = decompose(x) {
+        add_shifted(acc, mantissa as u128, shift, x < 0.0);
+    }
+}
+
+pub fn negate(acc: &mut [u64; WORDS]) {
+    let mut carry = true;
+    for word in acc.iter_mut() {
+        let (sum, c) = (!*word).overflowing_add(carry as u64);
+        *word = sum;
+        carry = c;
+    }
+}
+
+pub fn highest_bit(acc: &[u64; WORDS]) -> Option<i32> {
+    acc.iter()
+        .enumerate()
+        .rev()
+        .find(|(_, &word)| word != 0)
+        .map(|(i, word)| i as i32 * 64 + 63 - word.leading_zeros() as i32)
+}
+
+pub fn bit(acc: &[u64; WORDS], i: i32) -> bool {
+    i >= 0 && acc[i as usize / 64] >> (i % 64) & 1 == 1
+}
+
+fn any_below(acc: &[u64; WORDS], i: i32) -> bool {
+    if i <= 0 {
+        return false;
+    }
+    let word = i as usize / 64;
+    let mask = (1u64 << (i % 64)) - 1;
+    acc[word] & mask != 0 || acc[..word].iter().any(|&w| w != 0)
+}
+
+/// Divides the exact sum of the `f64`s in `x` by the exact sum of those in `y`, with the
+/// quotient truncated toward zero, and returns the quotient and the remainder, each split
+/// into `parts` `f64`s (no more than four) like the components of a `Double` or `Quad`. Any
+/// parts past those are zero.
+///
+/// This is long division, one bit of the quotient at a time, so the remainder is exact
+/// however large the quotient is. Each part is the nearest `f64` to what's left once the
+/// parts before it have been subtracted, so the remainder is only rounded if it has too many
+/// bits to be held in `parts` `f64`s, and the quotient is rounded the same way (or becomes
+/// infinite if it's too large for an `f64`). Both sums have to be finite, and `y` can't be
+/// zero. The remainder has the sign of `x` and the quotient the sign of `x / y`, even when
+/// they're zero.
+pub fn div_rem(x: &[f64], y: &[f64], parts: usize) -> ([f64; 4], [f64; 4]) {
+    let (mut quot, mut rem, overflow) = long_division(x, y);
+    let negative = x[0].is_sign_negative() != y[0].is_sign_negative();
+    let quot = if overflow {
+        let inf = if negative {
+            f64::NEG_INFINITY
+        } else {
+            f64::INFINITY
+        };
+        [inf, 0.0, 0.0, 0.0]
+    } else {
+        split(&mut quot, parts, negative)
+    };
+    (quot, split(&mut rem, parts, x[0].is_sign_negative()))
+}
+
+/// Divides the exact sum of the `f64`s in `x` by the exact sum of those in `y`, with the
+/// quotient truncated toward zero, and returns the remainder split into `parts` `f64`s (no
+/// more than four) like the components of a `Double` or `Quad`. Any parts past those are
+/// zero.
+///
+/// This is long division, one bit of the quotient at a time, so the remainder is exact
+/// however large the quotient is. Each part is the nearest `f64` to what's left once the
+/// parts before it have been subtracted, so the remainder is only rounded if it has too many
+/// bits to be held in `parts` `f64`s. Both sums have to be finite, and `y` can't be zero. The
+/// remainder has the sign of `x`, even when it's zero.
+pub fn rem(x: &[f64], y: &[f64], parts: usize) -> [f64; 4] {
+    let (_, mut rem, _) = long_division(x, y);
+    split(&mut rem, parts, x[0].is_sign_negative())
+}
+
+// Divides the magnitudes of two exact sums, returning the quotient, the remainder, and
+// whether the quotient was too large for its accumulator (which is far too large for an
+// `f64`).
+fn long_division(x: &[f64], y: &[f64]) -> ([u64; WORDS], [u64; WORDS], bool) {
+    let mut rem = [0u64; WORDS];
+    let mut div = [0u64; WORDS];
+    for &v in x {
+        accumulate(&mut rem, v);
+    }
+    for &v in y {
+        accumulate(&mut div, v);
+    }
+    if rem[WORDS - 1] >> 63 == 1 {
+        negate(&mut rem);
+    }
+    if div[WORDS - 1] >> 63 == 1 {
+        negate(&mut div);
+    }
+
+    let mut quot = [0u64; WORDS];
+    let mut overflow = false;
+    let (high, div_high) = match (highest_bit(&rem), highest_bit(&div)) {
+        (Some(high), Some(div_high)) if high >= div_high => (high, div_high),
+        _ => return (quot, rem, overflow),
+    };
+    let shift = high - div_high;
+    shift_left(&mut div, shift);
+    // The words that the shifted divisor covers. Everything that changes in the remainder
+    // at each step is within them, or in the word just above them.
+    let mut low = div.iter().position(|&w| w != 0).unwrap_or(0);
+    let mut top = high as usize / 64;
+    for s in (0..=shift).rev() {
+        let end = (top + 1).min(WORDS - 1);
+        if !rem[low..=end].iter().rev().lt(div[low..=end].iter().rev()) {
+            subtract(&mut rem[low..=end], &div[low..=end]);
+            // Any bit too high for the accumulator is far past the largest `f64` anyway
+            let i = s + OFFSET;
+            if i >= WORDS as i32 * 64 - 1 {
+                overflow = true;
+            } else {
+                quot[i as usize / 64] |= 1 << (i % 64);
+            }
+        }
+        if s > 0 {
+            low = low.saturating_sub(1);
+            for i in low..=top {
+                div[i] = div[i] >> 1 | div.get(i + 1).map_or(0, |w| w << 63);
+            }
+            if div[low] == 0 {
+                low += 1;
+            }
+            if div[top] == 0 {
+                top -= 1;
+            }
+        }
+    }
+    (quot, rem, overflow)
+}
+
+// Splits the value in an accumulator into `parts` `f64`s, each the nearest (ties to even) to
+// what's left once the ones before it have been taken off, and negates them if `negative` is
+// set. A zero value gives a zero first part with that sign. The accumulator is used up.
+fn split(acc: &mut [u64; WORDS], parts: usize, negative: bool) -> [f64; 4] {
+    let sign = if negative { -1.0 } else { 1.0 };
+    let mut result = [0.0; 4];
+    result[0] = sign * 0.0;
+    for part in result.iter_mut().take(parts) {
+        let below = acc[WORDS - 1] >> 63 == 1;
+        if below {
+            negate(acc);
+        }
+        let high = match highest_bit(acc) {
+            Some(high) => high,
+            None => break,
+        };
+        let low = (high - 52).max(0);
+        let (word, r) = (low as usize / 64, low % 64);
+        let mut mantissa = acc[word] >> r;
+        if r > 0 && word + 1 < WORDS {
+            mantissa |= acc[word + 1] << (64 - r);
+        }
+        mantissa &= u64::MAX >> (63 - (high - low));
+        if bit(acc, low - 1) && (any_below(acc, low - 1) || mantissa & 1 == 1) {
+            mantissa += 1;
+        }
+        let value = ldexp(mantissa as f64, low - OFFSET);
+        *part = if below { -sign * value } else { sign * value };
+        if value.is_infinite() {
+            break;
+        }
+        accumulate(acc, -value);
+        if below {
+            negate(acc);
+        }
+    }
+    result
+}
+
+// Shifts an unsigned accumulator left by `n` bits. Bits shifted past the top are lost.
+fn shift_left(acc: &mut [u64; WORDS], n: i32) {
+    let (words, bits) = (n as usize / 64, n % 64);
+    for i in (0..WORDS).rev() {
+        let high = if i >= words { acc[i - words] } else { 0 };
+        let low = if i > words { acc[i - words - 1] } else { 0 };
+        acc[i] = if bits == 0 {
+            high
+        } else {
+            high << bits | low >> (64 - bits)
+        };
+    }
+}
+
+// Subtracts one unsigned integer, given as words from the lowest up, from another that's no
+// smaller.
+fn subtract(a: &mut [u64], b: &[u64]) {
+    let mut borrow = false;
+    for (x, &y) in a.iter_mut().zip(b.iter()) {
+        let (d, b1) = x.overflowing_sub(y);
+        let (d, b2) = d.overflowing_sub(borrow as u64);
+        *x = d;
+        borrow = b1 || b2;
+    }
+}
@@ -3,9 +3,27 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
-use std::fmt::Formatter;
+use crate::common::bignum::{div_small, mul_small, shl};
+use crate::common::binary128 as b;
+use crate::options::FormatOptions;
+use crate::round::RoundingMode;
+use std::fmt::{self, Formatter};
 use std::{char, fmt::Alignment};
 
+// The powers of ten that are exactly representable as `f64`s. Normalizing a number by one
+// of these takes a single operation with an `f64` instead of building the power and doing
+// a full division.
+pub const POWERS_OF_TEN: [f64; 23] = [
+    1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15, 1e16,
+    1e17, 1e18, 1e19, 1e20, 1e21, 1e22,
+];
+
+// No finite sum of `f64`s has a nonzero digit more than 1074 places after the decimal point
+// (the place of the smallest subnormal) or more than 1383 significant digits (adding the 309
+// digits before the point), so asking for more of either than this only adds zeros at the
+// end. It also keeps the counts small enough for the `i32` place arithmetic.
+pub const EXACT_DIGITS: usize = 1500;
+
 // Add a "not-a-number" representation to the input vector.
 pub fn push_nan(chars: &mut Vec<char>) {
     chars.append(&mut "NaN".chars().collect());
@@ -36,9 +54,443 @@ pub fn push_exp(chars: &mut Vec<char>, marker: char, exp: i32) {
     chars.append(&mut exp.to_string().chars().collect());
 }
 
+// Add the exact magnitude of the sum of a number of finite `f64`s (the components of a
+// `Double` or `Quad`) to the input vector as a C99-style hex float, like `0x1.8p+3`. The
+// leading digit is always 1 (unless the sum is zero), and there are as many digits after
+// the point as it takes to hold every bit of the sum and no more. `upper` makes the letters
+// uppercase, as they are for the `%A` conversion in C.
+pub fn push_hex(chars: &mut Vec<char>, parts: &[f64], upper: bool) {
+    let mut acc = [0u64; b::WORDS];
+    for &x in parts {
+        b::accumulate(&mut acc, x);
+    }
+    if acc[b::WORDS - 1] >> 63 == 1 {
+        b::negate(&mut acc);
+    }
+
+    let mut result = String::from("0x");
+    match b::highest_bit(&acc) {
+        None => result.push_str("0p+0"),
+        Some(high) => {
+            let low = acc
+                .iter()
+                .position(|&word| word != 0)
+                .map(|i| i as i32 * 64 + acc[i].trailing_zeros() as i32)
+                .unwrap_or(high);
+            result.push('1');
+            if low < high {
+                result.push('.');
+                let mut i = high - 1;
+                while i >= low {
+                    let nibble = (0..4).fold(0, |n, j| n << 1 | b::bit(&acc, i - j) as u32);
+                    result.push(char::from_digit(nibble, 16).unwrap());
+                    i -= 4;
+                }
+            }
+            result.push_str(&format!("p{:+}", high - b::OFFSET));
+        }
+    }
+    if upper {
+        result.make_ascii_uppercase();
+    }
+    chars.append(&mut result.chars().collect());
+}
+
+// Writes the components of a `Double` or `Quad` for `{:#?}`, one to a line, each in
+// scientific notation and as an exact hexadecimal float, followed by whether they're
+// normalized.
+pub fn write_debug_alt(f: &mut Formatter, name: &str, parts: &[f64]) -> fmt::Result {
+    writeln!(f, "{}(", name)?;
+    for &x in parts {
+        let mut hex = vec![];
+        if x.is_nan() {
+            push_nan(&mut hex);
+        } else {
+            if x.is_sign_negative() {
+                hex.push('-');
+            }
+            if x.is_infinite() {
+                push_inf(&mut hex);
+            } else {
+                push_hex(&mut hex, &[x.abs()], false);
+            }
+        }
+        writeln!(f, "    {:e} ({}),", x, hex.into_iter().collect::<String>())?;
+    }
+    writeln!(f, "    normalized: {},", is_normalized(parts))?;
+    write!(f, ")")
+}
+
+// Determines whether each component of a `Double` or `Quad` is small enough that adding it
+// to the one before it doesn't change that one, which is what normalization guarantees. A
+// component after a zero has to be zero itself, and one after a `NaN` has to be zero too.
+fn is_normalized(parts: &[f64]) -> bool {
+    parts
+        .windows(2)
+        .all(|pair| pair[1] == 0.0 || pair[0] + pair[1] == pair[0])
+}
+
+// The fractional part of a number being written in a radix is kept as a fraction of
+// 2^(32 * FRAC_LIMBS), which is a whole number of limbs at least as large as the 2^1074
+// that it takes to hold the smallest subnormal `f64`.
+const FRAC_LIMBS: usize = 34;
+
+// Add the magnitude of the sum of a number of finite, non-zero `f64`s (the components of a
+// `Double` or `Quad`) to the input vector, written in the given radix (from 2 to 36) with no
+// exponent.
+//
+// The digits are the exact expansion of the sum, rounded to the nearest (ties go up) at the
+// first length where `round_trips` says that the string parses back into the same number.
+// That's the shortest string in the radix that does, unless none does within enough digits
+// to cover every bit that a `Double` or `Quad` can have, in which case the last one tried
+// is used. An expansion in a radix that's a power of two always ends before then.
+pub fn push_radix(
+    chars: &mut Vec<char>,
+    parts: &[f64],
+    radix: u32,
+    round_trips: impl Fn(&str) -> bool,
+) {
+    let (mut digits, mut frac) = expand(parts, radix);
+    let point = digits.len();
+    let limit = (2200.0 / (radix as f64).log2()).ceil() as usize;
+
+    // `cut` is the number of digits that are kept. Digits of the fractional part are only
+    // generated as they're needed, so past the point there are never any digits after the
+    // cut, and `frac` is everything that's left.
+    let mut cut = 0;
+    loop {
+        cut += 1;
+        if cut > digits.len() {
+            digits.push(next_digit(&mut frac, radix));
+        }
+        let first = match digits.iter().position(|&d| d != 0) {
+            Some(first) => first,
+            None => continue,
+        };
+
+        let tail = &digits[cut..];
+        let exact = frac.is_empty() && tail.iter().all(|&d| d == 0);
+        let candidate = round_radix(&digits[..cut], point, radix, round_up(tail, &frac, radix));
+        if exact || cut - first >= limit || round_trips(&candidate) {
+            chars.append(&mut candidate.chars().collect());
+            return;
+        }
+    }
+}
+
+// Splits the magnitude of the sum of a number of finite `f64`s into the digits of its
+// integer part in the given radix, most significant first, and its fractional part as a
+// fraction of 2^(32 * FRAC_LIMBS) (with its least significant limb first, and with no
+// leading zero limbs).
+pub fn expand(parts: &[f64], radix: u32) -> (Vec<u8>, Vec<u32>) {
+    let mut acc = [0u64; b::WORDS];
+    for &x in parts {
+        b::accumulate(&mut acc, x);
+    }
+    if acc[b::WORDS - 1] >> 63 == 1 {
+        b::negate(&mut acc);
+    }
+
+    // The sum is `value` times 2^-(32 * FRAC_LIMBS), so the limbs above FRAC_LIMBS are its
+    // integer part, and the ones below are its fractional part
+    let mut limbs: Vec<u32> = acc
+        .iter()
+        .flat_map(|&word| vec![word as u32, (word >> 32) as u32])
+        .collect();
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+    let value = shl(&limbs, 32 * FRAC_LIMBS - b::OFFSET as usize);
+    let mut int = value.get(FRAC_LIMBS..).unwrap_or(&[]).to_vec();
+    let mut frac = value[..value.len().min(FRAC_LIMBS)].to_vec();
+    while frac.last() == Some(&0) {
+        frac.pop();
+    }
+
+    let mut digits = vec![];
+    while !int.is_empty() {
+        digits.push(div_small(&mut int, radix) as u8);
+    }
+    digits.reverse();
+    (digits, frac)
+}
+
+// Takes the next digit in the given radix off of the front of a fractional part from
+// `expand`, leaving what's left of the fractional part behind.
+pub fn next_digit(frac: &mut Vec<u32>, radix: u32) -> u8 {
+    mul_small(frac, radix, 0);
+    let digit = if frac.len() > FRAC_LIMBS {
+        frac.pop().unwrap()
+    } else {
+        0
+    };
+    while frac.last() == Some(&0) {
+        frac.pop();
+    }
+    digit as u8
+}
+
+// Add the exact decimal expansion of the magnitude of the sum of a number of finite `f64`s
+// to the input vector. Every finite `f64` is a dyadic rational, so the expansion always
+// ends, though it can take over a thousand digits.
+pub fn push_exact(chars: &mut Vec<char>, parts: &[f64]) {
+    let (digits, mut frac) = expand(parts, 10);
+    let digit = |d: u8| char::from_digit(d as u32, 10).unwrap();
+    if digits.is_empty() {
+        chars.push('0');
+    } else {
+        chars.extend(digits.into_iter().map(digit));
+    }
+    if !frac.is_empty() {
+        chars.push('.');
+        while !frac.is_empty() {
+            chars.push(digit(next_digit(&mut frac, 10)));
+        }
+    }
+}
+
+// Returns the exponent of the leading decimal digit of the sum of a number of finite
+// `f64`s, or `None` if the sum is zero.
+pub fn leading_exp(parts: &[f64]) -> Option<i32> {
+    let (digits, mut frac) = expand(parts, 10);
+    if !digits.is_empty() {
+        return Some(digits.len() as i32 - 1);
+    }
+    let mut exp = -1;
+    while !frac.is_empty() {
+        if next_digit(&mut frac, 10) != 0 {
+            return Some(exp);
+        }
+        exp -= 1;
+    }
+    None
+}
+
+// Rounds the magnitude of the exact sum of a number of finite `f64`s to a whole number of
+// units of 10^-places, returning that number as decimal digits (most significant first,
+// with no leading zeros, so zero has no digits at all). `places` can be negative, which
+// rounds to a multiple of a power of ten. `negative` is the sign of the number, which the
+// directed rounding modes need to know which way is up.
+pub fn round_decimal(parts: &[f64], places: i32, negative: bool, mode: RoundingMode) -> Vec<u8> {
+    let (mut digits, mut frac) = expand(parts, 10);
+    let len = digits.len() as i32 + places;
+
+    // `first` is the first digit that's dropped, and `rest` is whether anything at all
+    // after it isn't zero
+    let (first, rest) = if len < 0 {
+        let nonzero = !digits.is_empty() || !frac.is_empty();
+        digits.clear();
+        (0, nonzero)
+    } else if (len as usize) < digits.len() {
+        let dropped = digits.split_off(len as usize);
+        let rest = dropped[1..].iter().any(|&d| d != 0) || !frac.is_empty();
+        (dropped[0], rest)
+    } else {
+        while (digits.len() as i32) < len {
+            digits.push(next_digit(&mut frac, 10));
+        }
+        if frac.is_empty() {
+            (0, false)
+        } else {
+            let first = next_digit(&mut frac, 10);
+            (first, !frac.is_empty())
+        }
+    };
+
+    let inexact = first != 0 || rest;
+    let odd = matches!(digits.last(), Some(&d) if d & 1 == 1);
+    let up = match mode {
+        RoundingMode::HalfEven => first > 5 || (first == 5 && (rest || odd)),
+        RoundingMode::HalfAwayFromZero => first >= 5,
+        RoundingMode::Up => inexact && !negative,
+        RoundingMode::Down => inexact && negative,
+        RoundingMode::TowardZero => false,
+    };
+    if up {
+        let mut i = digits.len();
+        loop {
+            if i == 0 {
+                digits.insert(0, 1);
+                break;
+            }
+            i -= 1;
+            if digits[i] == 9 {
+                digits[i] = 0;
+            } else {
+                digits[i] += 1;
+                break;
+            }
+        }
+    }
+    let leading = digits.iter().take_while(|&&d| d == 0).count();
+    digits.drain(..leading);
+    digits
+}
+
+// Writes out a whole number of units of 10^-places (as returned by `round_decimal`) in
+// fixed notation, with exactly `places` digits after the decimal point if `places` is
+// positive, and with zeros in place of the units if it's negative.
+pub fn push_decimal(chars: &mut Vec<char>, digits: &[u8], places: i32) {
+    let digit = |&d: &u8| char::from_digit(d as u32, 10).unwrap();
+    if places <= 0 {
+        if digits.is_empty() {
+            chars.push('0');
+        } else {
+            chars.extend(digits.iter().map(digit));
+            chars.extend((0..-places).map(|_| '0'));
+        }
+        return;
+    }
+
+    let places = places as usize;
+    let mut padded = vec![0; (places + 1).saturating_sub(digits.len())];
+    padded.extend_from_slice(digits);
+    let point = padded.len() - places;
+    chars.extend(padded[..point].iter().map(digit));
+    chars.push('.');
+    chars.extend(padded[point..].iter().map(digit));
+}
+
+// Determines whether the digits that follow a cut, followed by a fractional part of
+// 2^(32 * FRAC_LIMBS), are at least half of a unit in the last place that's kept.
+fn round_up(tail: &[u8], frac: &[u32], radix: u32) -> bool {
+    let half_frac = frac.len() == FRAC_LIMBS && frac[FRAC_LIMBS - 1] >> 31 == 1;
+    if radix & 1 == 0 {
+        // Half is the digit radix / 2 followed by nothing but zeros
+        match tail.first() {
+            Some(&d) => d as u32 >= radix / 2,
+            None => half_frac,
+        }
+    } else {
+        // Half is the digit (radix - 1) / 2 repeated forever, so it's decided by the first
+        // digit that isn't that one, or by the fractional part if there isn't one
+        let h = (radix - 1) / 2;
+        match tail.iter().find(|&&d| d as u32 != h) {
+            Some(&d) => d as u32 > h,
+            None => half_frac,
+        }
+    }
+}
+
+// Writes out the kept digits, rounded up by one in the last place if `up` is set. `point` is
+// the number of digits before the point, which may be more than there are kept digits.
+fn round_radix(kept: &[u8], point: usize, radix: u32, up: bool) -> String {
+    let mut kept = kept.to_vec();
+    let mut point = point;
+    if up {
+        let mut i = kept.len();
+        loop {
+            if i == 0 {
+                kept.insert(0, 1);
+                point += 1;
+                break;
+            }
+            i -= 1;
+            kept[i] += 1;
+            if kept[i] as u32 == radix {
+                kept[i] = 0;
+            } else {
+                break;
+            }
+        }
+    }
+    if kept.len() < point {
+        kept.resize(point, 0);
+    }
+
+    let digit = |&d: &u8| char::from_digit(d as u32, radix).unwrap();
+    let mut result: String = if point == 0 {
+        String::from("0")
+    } else {
+        kept[..point].iter().map(digit).collect()
+    };
+    let fraction = &kept[point..];
+    if let Some(last) = fraction.iter().rposition(|&d| d != 0) {
+        result.push('.');
+        result.extend(fraction[..=last].iter().map(digit));
+    }
+    result
+}
+
+// Rewrites a number in fixed notation (as produced by `Display`) with the separators in
+// `options`. Group separators go between each group of three digits in the integer part,
+// counting from the decimal point, and the decimal point itself is replaced. Anything that
+// doesn't start with a digit after its sign (`NaN` and the infinities) is left alone.
+pub fn separate(s: &str, options: &FormatOptions) -> String {
+    let digits = s.trim_start_matches(['-', '+']);
+    if !digits.starts_with(|c: char| c.is_ascii_digit()) {
+        return s.to_string();
+    }
+    let sign = &s[..s.len() - digits.len()];
+    let (int, frac) = match digits.find('.') {
+        Some(point) => (&digits[..point], Some(&digits[point + 1..])),
+        None => (digits, None),
+    };
+
+    let mut result = String::from(sign);
+    for (i, c) in int.chars().enumerate() {
+        if i > 0 && (int.len() - i) % 3 == 0 {
+            if let Some(separator) = options.group_separator {
+                result.push(separator);
+            }
+        }
+        result.push(c);
+    }
+    if let Some(frac) = frac {
+        result.push(options.decimal_separator);
+        result.push_str(frac);
+    }
+    result
+}
+
+// Rewrites a number in scientific notation (as produced by `LowerExp`) in engineering
+// notation, where the exponent is a multiple of three and there are one to three digits
+// before the decimal point. The mantissa of the input can have more than one digit before
+// its decimal point (it will if rounding carried all the way up), as that's accounted for
+// when working out the exponent. Anything without an exponent (`NaN` and the infinities)
+// is left alone.
+pub fn engineering(s: &str) -> String {
+    let e = match s.find('e') {
+        Some(e) => e,
+        None => return s.to_string(),
+    };
+    let (mantissa, exp) = (&s[..e], s[e + 1..].parse::<i32>().unwrap());
+    let unsigned = mantissa.trim_start_matches(['-', '+']);
+    let sign = &mantissa[..mantissa.len() - unsigned.len()];
+    let int_len = unsigned.find('.').unwrap_or(unsigned.len());
+    let mut digits: String = unsigned.chars().filter(|&c| c != '.').collect();
+
+    // `exp` is the exponent of the first digit, and `eng_exp` is the one that it's reduced
+    // to, so the first digit has to end up `exp - eng_exp` places to the left of the point
+    let exp = exp + int_len as i32 - 1;
+    let eng_exp = exp - exp.rem_euclid(3);
+    let int_len = (exp - eng_exp) as usize + 1;
+    if digits.chars().all(|c| c == '0') {
+        return format!("{}{}e0", sign, digits.chars().take(1).collect::<String>());
+    }
+    while digits.len() < int_len {
+        digits.push('0');
+    }
+
+    let (int, frac) = digits.split_at(int_len);
+    let frac = frac.trim_end_matches('0');
+    if frac.is_empty() {
+        format!("{}{}e{}", sign, int, eng_exp)
+    } else {
+        format!("{}{}.{}e{}", sign, int, frac, eng_exp)
+    }
+}
+
 // Rounds the digits in a vector to a certain index and then truncates the vector at that
 // index.
 pub fn round_and_trunc(digits: &mut Vec<u8>, len: usize) {
+    round_digits(digits, len);
+    digits.truncate(len);
+}
+
+// Rounds the digits in a slice to a certain index, leaving the digits from that index on
+// as they were.
+pub fn round_digits(digits: &mut [u8], len: usize) {
     if digits[len] >= 5 {
         // Round up if the digit after the last desired digit is 5 or higher
         let mut i: usize = 1;
@@ -51,7 +503,6 @@ pub fn round_and_trunc(digits: &mut Vec<u8>, len: usize) {
             digits[len - i] += 1;
         }
     }
-    digits.truncate(len);
 }
 
 // Adds or removes zeros to the vector depending on the exponent. If the exponent is
@@ -116,6 +567,37 @@ pub fn adjust_prec(digits: &mut Vec<u8>, exp: i32, prec: Option<usize>) {
     }
 }
 
+// Does what `adjust_prec` does, except that digits that have to be rounded away are instead
+// replaced by the exact value of the number (the sum of `parts`, which can't be negative)
+// rounded to the same place, with ties going to even. The digits of `Display` are
+// themselves rounded, so a number like 2.25 can otherwise look like a tie that it isn't,
+// or hide one that it is. `scale` is the power of ten that the digits have been divided
+// by, which is zero for fixed notation and the exponent for scientific notation. A carry
+// into a new leading digit leaves a 10 there, the same as with `adjust_prec`.
+pub fn adjust_prec_exact(
+    digits: &mut Vec<u8>,
+    exp: i32,
+    prec: Option<usize>,
+    parts: &[f64],
+    scale: i32,
+) {
+    if let Some(p) = prec {
+        let desired = if exp < 0 { p + 1 } else { exp as usize + p + 1 };
+        if desired < digits.len() {
+            let rounded = round_decimal(parts, p as i32 - scale, false, RoundingMode::HalfEven);
+            let mut result = vec![0; desired.saturating_sub(rounded.len())];
+            result.extend_from_slice(&rounded);
+            if result.len() > desired {
+                result.remove(0);
+                result[0] = 10;
+            }
+            *digits = result;
+            return;
+        }
+    }
+    adjust_prec(digits, exp, prec);
+}
+
 // Positions a decimal point at the correct location dependiong on the exponent. Since the
 // decimal point is not a `u8` like the digits are, this function returns a character vector
 // rather than manipulating the input vector in place.
@@ -152,6 +634,71 @@ pub fn place_decimal(digits: Vec<u8>, exp: i32) -> Vec<char> {
     result
 }
 
+// Writes digits in plain decimal notation, the way that `{}` writes them when there's no
+// precision, with no trailing zeros after the point. `exp` is the exponent of the first
+// digit, and the digits have to be from 0 to 9. Nothing is allocated, so this can write to
+// anything that implements `fmt::Write`.
+pub fn write_fixed<W: fmt::Write>(w: &mut W, digits: &[u8], exp: i32) -> fmt::Result {
+    let mut len = digits.len();
+    while len > 1 && digits[len - 1] == 0 && len as i32 > exp + 1 {
+        len -= 1;
+    }
+    let digits = digits[..len].iter().map(|&d| (b'0' + d) as char);
+
+    if exp < 0 {
+        w.write_str("0.")?;
+        for _ in 1..-exp {
+            w.write_char('0')?;
+        }
+        for ch in digits {
+            w.write_char(ch)?;
+        }
+    } else {
+        // Zeros fill in any places between the last digit and the point
+        let point = exp as usize + 1;
+        for (i, ch) in digits
+            .chain(std::iter::repeat('0'))
+            .take(point.max(len))
+            .enumerate()
+        {
+            if i == point {
+                w.write_char('.')?;
+            }
+            w.write_char(ch)?;
+        }
+    }
+    Ok(())
+}
+
+// A `fmt::Write` that writes into a byte slice, failing if there isn't room.
+pub struct ByteWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> ByteWriter<'a> {
+    pub fn new(buf: &'a mut [u8]) -> ByteWriter<'a> {
+        ByteWriter { buf, len: 0 }
+    }
+
+    // The number of bytes that have been written.
+    pub fn written(&self) -> usize {
+        self.len
+    }
+}
+
+impl fmt::Write for ByteWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
 // Adjust the width of the number based on alignment, width, and fill settings. This
 // function also handles the sign-aware zero fill.
 //
@@ -159,6 +706,10 @@ pub fn place_decimal(digits: Vec<u8>, exp: i32) -> Vec<char> {
 // not greater than the current vector length. A width setting can increase the number of
 // characters in the vector, but it cannot decrease it. As a consequence, align and fill are
 // ignored if there isn't a width specified that is higher than the vector length.
+//
+// As with `f64`, sign-aware zero fill takes precedence over the fill character and the
+// alignment, so `{:<010}` zero-pads after the sign just like `{:010}` does. It applies to
+// the infinities and `NaN` as well.
 pub fn align_and_fill(chars: &mut Vec<char>, signed: bool, f: &mut Formatter) {
     if let Some(width) = f.width() {
         let len = chars.len();
@@ -166,17 +717,20 @@ pub fn align_and_fill(chars: &mut Vec<char>, signed: bool, f: &mut Formatter) {
             let delta = width - len;
             let fill = f.fill();
 
+            if f.sign_aware_zero_pad() {
+                let index = if signed { 1 } else { 0 };
+                for _ in 0..delta {
+                    chars.insert(index, '0');
+                }
+                return;
+            }
+
             match f.align() {
                 Some(Alignment::Left) => {
                     for _ in 0..delta {
                         chars.push(fill);
                     }
                 }
-                Some(Alignment::Right) => {
-                    for _ in 0..delta {
-                        chars.insert(0, fill);
-                    }
-                }
                 Some(Alignment::Center) => {
                     let left = delta / 2;
                     let right = delta - left;
@@ -187,16 +741,9 @@ pub fn align_and_fill(chars: &mut Vec<char>, signed: bool, f: &mut Formatter) {
                         chars.push(fill);
                     }
                 }
-                None => {
-                    if f.sign_aware_zero_pad() {
-                        let index = if signed { 1 } else { 0 };
-                        for _ in 0..delta {
-                            chars.insert(index, '0');
-                        }
-                    } else {
-                        for _ in 0..delta {
-                            chars.insert(0, fill);
-                        }
+                Some(Alignment::Right) | None => {
+                    for _ in 0..delta {
+                        chars.insert(0, fill);
                     }
                 }
             }
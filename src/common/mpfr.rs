@@ -0,0 +1,73 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use rug::Float;
+
+/// Adds the exact values of a number of `f64`s together into a `Float`, which is given just
+/// enough precision to hold the sum exactly (but never less than the 53 bits of an `f64`).
+///
+/// The parts are meant to be the components of a `Double` or `Quad`, so the first one decides
+/// whether the result is `NaN`, infinite, or zero. Zeros after the first are skipped, so
+/// that a negative zero keeps its sign.
+pub fn to_float(parts: &[f64]) -> Float {
+    let first = parts[0];
+    if !first.is_finite() || first == 0.0 {
+        return Float::with_val(53, first);
+    }
+    let ranges: Vec<(i32, i32)> = parts
+        .iter()
+        .filter(|&&x| x != 0.0)
+        .map(|&x| bit_range(x))
+        .collect();
+    let high = ranges.iter().map(|&(high, _)| high).max().unwrap_or(0);
+    let low = ranges.iter().map(|&(_, low)| low).min().unwrap_or(0);
+    // One extra bit in case the parts overlap and their sum carries past the highest of them
+    let prec = ((high - low + 2) as u32).max(53);
+
+    let mut f = Float::with_val(prec, first);
+    for &x in parts[1..].iter().filter(|&&x| x != 0.0) {
+        f += x;
+    }
+    f
+}
+
+/// Splits a `Float` into `parts` `f64`s. The first is the `Float` rounded to the nearest
+/// `f64`, and each one after it is what's left of the `Float`, once the ones before it have
+/// been subtracted, rounded the same way. The subtractions are all exact, since what's left
+/// never needs more bits than the `Float` itself has.
+///
+/// Once what's left is zero, or once a part isn't finite, the rest of the parts are zero.
+pub fn split(f: &Float, parts: usize) -> Vec<f64> {
+    let mut rest = f.clone();
+    let mut result = Vec::with_capacity(parts);
+    while result.len() < parts {
+        let x = rest.to_f64();
+        result.push(x);
+        if x == 0.0 || !x.is_finite() {
+            result.resize(parts, 0.0);
+        } else {
+            rest -= x;
+        }
+    }
+    result
+}
+
+// Returns the binary exponents of the highest and lowest set bits of a finite, non-zero
+// `f64`.
+fn bit_range(x: f64) -> (i32, i32) {
+    let bits = x.to_bits();
+    let biased = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & 0xf_ffff_ffff_ffff;
+    // Subnormals have no implicit leading bit and the same exponent as the smallest normals
+    let (mantissa, exp) = if biased == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased - 1075)
+    };
+    (
+        exp + 63 - mantissa.leading_zeros() as i32,
+        exp + mantissa.trailing_zeros() as i32,
+    )
+}
@@ -0,0 +1,633 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::bignum::{
+    bit, bits, compare, div_small, high_bits, low_bits_set, mul_small, shl, shl_assign, sub_assign,
+    to_u64, truncate_bits, Fixed,
+};
+use crate::error::ErrorKind;
+use crate::options::ParseOptions;
+use std::cmp::Ordering;
+
+/// The most parts that a number is ever split into, which is the number of components in a
+/// `Quad`. The functions here return this many parts whatever the number of parts that they
+/// were asked for, so that they don't have to allocate; the ones past those are zero.
+pub const MAX_PARTS: usize = 4;
+
+/// Parses the exponent of a decimal string, which is everything after the exponent marker.
+///
+/// `start` is the byte offset of the exponent in the whole string, and it's added to the
+/// position of any error so that the position is relative to the whole string. An empty
+/// exponent (or one that's only a sign) is `Invalid` at the end of the string, a sign or
+/// decimal point after the first character is `Invalid` where it appears, any other
+/// character that isn't a digit is `InvalidDigit`, and an exponent too large for an `i32`
+/// is `ExponentOverflow` at the start of the exponent.
+pub fn exponent(s: &str, start: usize) -> Result<i32, (ErrorKind, usize)> {
+    let digits = s.strip_prefix(|c| c == '+' || c == '-').unwrap_or(s);
+    let sign_len = s.len() - digits.len();
+
+    if digits.is_empty() {
+        return Err((ErrorKind::Invalid, start + s.len()));
+    }
+    if let Some((index, ch)) = digits.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        let kind = match ch {
+            '+' | '-' | '.' => ErrorKind::Invalid,
+            _ => ErrorKind::InvalidDigit,
+        };
+        return Err((kind, start + sign_len + index));
+    }
+    // Nothing but digits (and maybe a sign) is left, so the only way this can fail is if it
+    // overflows
+    s.parse().map_err(|_| (ErrorKind::ExponentOverflow, start))
+}
+
+/// Parses a decimal string into `parts` `f64`s, correctly rounded the same way as
+/// [`split_decimal`] rounds.
+///
+/// The string must already be trimmed, and it's an optional sign, digits with an optional
+/// decimal separator among them, and an optional exponent, which is an `e` (in either case)
+/// followed by a power of ten. Underscores and the group separator in `options` are
+/// ignored among the digits. `start` is added to the position of any error, as it is in
+/// [`exponent`]. A second decimal separator is `TooManyPoints`, a sign anywhere but at the
+/// start is `Invalid`, and anything else that isn't a digit is `InvalidDigit`. There has to
+/// be at least one digit before the exponent; a string that's only a sign is `Empty`, an
+/// exponent marker with no digits before it is `Invalid` where it appears, and any other
+/// string without digits is `Invalid` at its end.
+///
+/// [`split_decimal`]: fn.split_decimal.html
+/// [`exponent`]: fn.exponent.html
+pub fn decimal(
+    s: &str,
+    start: usize,
+    parts: usize,
+    options: &ParseOptions,
+) -> Result<[f64; MAX_PARTS], (ErrorKind, usize)> {
+    let mut digits = Digits::new(10, MAX_DIGITS);
+    let mut count = 0;
+    let mut point = -1;
+    let mut sign = 0;
+    let mut exp = 0;
+
+    for (index, ch) in s.char_indices() {
+        if ch == options.decimal_separator {
+            if point >= 0 {
+                return Err((ErrorKind::TooManyPoints, start + index));
+            }
+            point = count;
+            continue;
+        }
+        if ch == '_' || Some(ch) == options.group_separator {
+            continue;
+        }
+        match ch.to_digit(10) {
+            Some(d) => {
+                digits.push(d);
+                count += 1;
+            }
+            None => match ch {
+                '-' | '+' => {
+                    if sign != 0 || count > 0 {
+                        return Err((ErrorKind::Invalid, start + index));
+                    }
+                    sign = if ch == '-' { -1 } else { 1 };
+                }
+                'e' | 'E' => {
+                    if count == 0 {
+                        return Err((ErrorKind::Invalid, start + index));
+                    }
+                    exp = exponent(&s[(index + 1)..], start + index + 1)? as i64;
+                    break;
+                }
+                _ => return Err((ErrorKind::InvalidDigit, start + index)),
+            },
+        }
+    }
+
+    if count == 0 {
+        let kind = if s == "-" || s == "+" {
+            ErrorKind::Empty
+        } else {
+            ErrorKind::Invalid
+        };
+        return Err((kind, start + s.len()));
+    }
+    if point >= 0 {
+        exp -= count - point;
+    }
+    let (num, len, shift) = digits.finish();
+    let mut result = split_decimal(num, len, exp + shift, parts);
+    if sign == -1 {
+        for part in result.iter_mut() {
+            *part = -*part;
+        }
+    }
+    Ok(result)
+}
+
+/// The number of significant digits that are kept from a decimal string. The boundaries
+/// between neighboring `Double`s or `Quad`s (the values that decide which way a number
+/// rounds) are dyadic rationals with no more than about 1385 significant digits. A number
+/// with more digits than this can be cut down to this many, with a non-zero digit added to
+/// the end to stand in for everything that was cut off, without moving it to the other side
+/// of any of those boundaries.
+const MAX_DIGITS: usize = 1400;
+
+/// Splits the exact value of a decimal number into `parts` `f64`s. The first is the number
+/// correctly rounded to the nearest `f64`, with ties going to even, and each one after it is
+/// what's left, once the ones before it have been subtracted, rounded the same way.
+///
+/// The number is `num`, an integer with `len` significant decimal digits, multiplied by
+/// 10^`exp`. It's never negative; the caller takes care of the sign. A number too large for
+/// an `f64` makes its first part infinite (and the rest zero), and one too small is rounded
+/// into the subnormals or to zero.
+pub fn split_decimal(mut num: Fixed, len: usize, exp: i64, parts: usize) -> [f64; MAX_PARTS] {
+    let mut result = [0.0; MAX_PARTS];
+    if num.is_empty() {
+        return result;
+    }
+
+    // The number is at least 10^(magnitude - 1) and less than 10^magnitude, so these are
+    // far enough past the largest `f64` and half of the smallest to be sure of the result
+    let magnitude = exp + len as i64;
+    if magnitude > 310 {
+        result[0] = f64::INFINITY;
+        return result;
+    }
+    if magnitude < -330 {
+        return result;
+    }
+
+    // The number is `num` × 5^`exp` × 2^`exp`, so a non-negative `exp` just makes an integer
+    // times a power of two
+    if exp >= 0 {
+        let mut power = exp;
+        while power > 0 {
+            let step = power.min(POW5_STEP);
+            mul_small(&mut num, 5u32.pow(step as u32), 0);
+            power -= step;
+        }
+        return split_scaled(num, exp, false, parts).unwrap();
+    }
+
+    // A negative `exp` needs a division by 5^-`exp`, which is done one `u32` power of five at
+    // a time after shifting `num` left far enough that the quotient has plenty more bits
+    // than all of the parts put together. Those are nearly always enough to round each
+    // part. If they aren't, this tries again with more, and once the last bit of the quotient
+    // is below half of the smallest subnormal, they're always enough.
+    let den_bits = (-exp as f64 * (std::f64::consts::LOG2_10 - 1.0)).ceil() as i64 + 1;
+    let mut guard = 64;
+    loop {
+        let shift = (53 * parts as i64 + guard + den_bits - bits(&num) as i64)
+            .min(exp + 1100)
+            .max(0);
+        let mut quotient = num.clone();
+        shl_assign(&mut quotient, shift as usize);
+        let mut inexact = false;
+        let mut power = -exp;
+        while power > 0 {
+            let step = power.min(POW5_STEP);
+            inexact |= div_small(&mut quotient, 5u32.pow(step as u32)) != 0;
+            power -= step;
+        }
+        if let Some(result) = split_scaled(quotient, exp - shift, inexact, parts) {
+            return result;
+        }
+        guard *= 4;
+    }
+}
+
+/// The number of significant hex digits that are kept from a hex string. The boundaries
+/// between neighboring `Double`s or `Quad`s are dyadic rationals whose bits run from no
+/// higher than 2^1024 to no lower than 2^-1075, which is about 525 hex digits. As with
+/// decimal strings, a number with more digits than this is cut down, with a non-zero digit
+/// added to the end to stand in for what was cut off.
+const MAX_HEX_DIGITS: usize = 600;
+
+/// Splits the exact value of a decimal number, given as its digits (most significant first)
+/// times 10^`exp`, into `parts` `f64`s, the same way that [`split_decimal`] does.
+///
+/// [`split_decimal`]: fn.split_decimal.html
+pub fn split_digits(digits: &[u8], exp: i64, parts: usize) -> [f64; MAX_PARTS] {
+    let mut acc = Digits::new(10, MAX_DIGITS);
+    for &d in digits {
+        acc.push(d as u32);
+    }
+    let (num, len, shift) = acc.finish();
+    split_decimal(num, len, exp + shift, parts)
+}
+
+/// Splits the exact value of a hexadecimal number into `parts` `f64`s, the same way that
+/// [`split_decimal`] splits a decimal number.
+///
+/// The number is `num`, an integer with `len` significant hex digits, multiplied by
+/// 2^`exp`.
+///
+/// [`split_decimal`]: fn.split_decimal.html
+pub fn split_hex(num: Fixed, len: usize, exp: i64, parts: usize) -> [f64; MAX_PARTS] {
+    // The number is at least 2^(magnitude - 4) and less than 2^magnitude
+    let magnitude = exp + 4 * len as i64;
+    if !num.is_empty() && magnitude > 1030 {
+        let mut result = [0.0; MAX_PARTS];
+        result[0] = f64::INFINITY;
+        return result;
+    }
+    if num.is_empty() || magnitude < -1080 {
+        return [0.0; MAX_PARTS];
+    }
+    split_scaled(num, exp, false, parts).unwrap()
+}
+
+/// Parses a C99-style hexadecimal floating-point string (such as `-0x1.8p+3`, which is -12)
+/// into `parts` `f64`s, correctly rounded the same way as [`split_hex`] rounds.
+///
+/// The string must already be trimmed, and it's an optional sign, `0x`, hex digits with an
+/// optional point among them, and an optional exponent, which is a `p` followed by a power
+/// of two written in decimal. Letters can be in either case. Underscores are ignored, as they are in
+/// decimal strings. `start` is added to the position of any error, as it is in
+/// [`exponent`]. A string with no digits is `Invalid` where the digits should have been, a
+/// second point is `TooManyPoints`, a sign after the `0x` is `Invalid`, and anything else
+/// that isn't a hex digit is `InvalidDigit`.
+///
+/// [`split_hex`]: fn.split_hex.html
+/// [`exponent`]: fn.exponent.html
+pub fn hex(s: &str, start: usize, parts: usize) -> Result<[f64; MAX_PARTS], (ErrorKind, usize)> {
+    let unsigned = s.strip_prefix(|c| c == '+' || c == '-').unwrap_or(s);
+    let prefix = s.len() - unsigned.len() + 2;
+    let mut digits = Digits::new(16, MAX_HEX_DIGITS);
+    let (places, exp) = read_digits(s, prefix, start, 16, Some('p'), |d| digits.push(d))?;
+    let (num, len, shift) = digits.finish();
+    let result = split_hex(num, len, exp + 4 * (shift - places), parts);
+    Ok(apply_sign(s, result))
+}
+
+/// Splits the exact value of a number in any radix from 2 to 36 into `parts` `f64`s, the
+/// same way that [`split_decimal`] splits a decimal number.
+///
+/// The number is `digits` (a slice of digits in the radix, from most to least significant)
+/// taken as an integer and divided by `radix`^`places`. Every digit is used, so this takes
+/// time that grows with the square of the number of digits.
+///
+/// [`split_decimal`]: fn.split_decimal.html
+pub fn split_radix(digits: &[u8], radix: u32, places: i64, parts: usize) -> [f64; MAX_PARTS] {
+    let mut num = vec![];
+    for &d in digits {
+        mul_small(&mut num, radix, d as u32);
+    }
+    if num.is_empty() {
+        return [0.0; MAX_PARTS];
+    }
+    let mut den = vec![1];
+    for _ in 0..places {
+        mul_small(&mut den, radix, 0);
+    }
+    split(num, den, parts)
+}
+
+/// Parses a string of digits in any radix from 2 to 36 into `parts` `f64`s, correctly
+/// rounded the same way as [`split_radix`] rounds.
+///
+/// The string must already be trimmed, and it's an optional sign and digits (with letters
+/// for digits past 9, in either case) with an optional point among them. There's no
+/// exponent, since `e` is a digit in the larger radices. Errors are the same as they are
+/// for [`hex`], except that a sign after the first digit is `Invalid` and a digit that's too
+/// large for the radix is `InvalidDigit`.
+///
+/// [`split_radix`]: fn.split_radix.html
+/// [`hex`]: fn.hex.html
+pub fn radix(
+    s: &str,
+    start: usize,
+    radix: u32,
+    parts: usize,
+) -> Result<[f64; MAX_PARTS], (ErrorKind, usize)> {
+    let unsigned = s.strip_prefix(|c| c == '+' || c == '-').unwrap_or(s);
+    let prefix = s.len() - unsigned.len();
+    let mut digits = vec![];
+    let (places, _) = read_digits(s, prefix, start, radix, None, |d| digits.push(d as u8))?;
+    let result = split_radix(&digits, radix, places, parts);
+    Ok(apply_sign(s, result))
+}
+
+/// Determines whether a trimmed string is a hexadecimal one, which is to say whether it
+/// starts with `0x` or `0X` once any sign is taken off.
+pub fn is_hex(s: &str) -> bool {
+    let unsigned = s.strip_prefix(|c| c == '+' || c == '-').unwrap_or(s);
+    unsigned.starts_with("0x") || unsigned.starts_with("0X")
+}
+
+// Reads the digits of a number in a radix, starting at byte `prefix` of the string (which is
+// after any sign or prefix), up to the end of the string or to the exponent `marker` (in
+// either case), and hands each one to `push`. This returns the number of digits that came
+// after the point and the exponent, which is 0 if there's no marker.
+fn read_digits(
+    s: &str,
+    prefix: usize,
+    start: usize,
+    radix: u32,
+    marker: Option<char>,
+    mut push: impl FnMut(u32),
+) -> Result<(i64, i64), (ErrorKind, usize)> {
+    let mut count = 0;
+    let mut point = -1;
+    let mut exp = 0;
+    let mut end = s.len();
+    for (index, ch) in s[prefix..].char_indices() {
+        let index = prefix + index;
+        match ch.to_digit(radix) {
+            Some(d) => {
+                push(d);
+                count += 1;
+            }
+            None => match ch {
+                '.' => {
+                    if point >= 0 {
+                        return Err((ErrorKind::TooManyPoints, start + index));
+                    }
+                    point = count;
+                }
+                _ if Some(ch.to_ascii_lowercase()) == marker => {
+                    end = index;
+                    exp = exponent(&s[(index + 1)..], start + index + 1)? as i64;
+                    break;
+                }
+                '_' => {}
+                '+' | '-' => return Err((ErrorKind::Invalid, start + index)),
+                _ => return Err((ErrorKind::InvalidDigit, start + index)),
+            },
+        }
+    }
+    if count == 0 {
+        return Err((ErrorKind::Invalid, start + end));
+    }
+    let places = if point >= 0 { count - point } else { 0 };
+    Ok((places, exp))
+}
+
+// Collects the significant digits of a number in some radix into an integer as they're
+// read, without keeping the digits themselves. Leading zeros are skipped. Zeros that might
+// be trailing ones are only counted until a digit that isn't zero comes along, so that a
+// long run of them at the end doesn't make the integer any bigger. Digits after the first
+// `max` are dropped, with a 1 put after the rest to stand in for them if any of them aren't
+// zero.
+struct Digits {
+    num: Fixed,
+    radix: u32,
+    max: usize,
+    // Digits that haven't been multiplied into `num` yet, which go in all at once
+    chunk: u32,
+    chunk_len: u32,
+    chunk_max: u32,
+    // The number of digits in `num` and `chunk` together, and the number of zeros after them
+    kept: usize,
+    zeros: usize,
+    dropped: i64,
+    sticky: bool,
+}
+
+impl Digits {
+    fn new(radix: u32, max: usize) -> Digits {
+        // As many digits as fit into a `u32`
+        let mut chunk_max = 1;
+        while (radix as u64).pow(chunk_max + 1) <= u32::MAX as u64 {
+            chunk_max += 1;
+        }
+        Digits {
+            num: Fixed::new(),
+            radix,
+            max,
+            chunk: 0,
+            chunk_len: 0,
+            chunk_max,
+            kept: 0,
+            zeros: 0,
+            dropped: 0,
+            sticky: false,
+        }
+    }
+
+    fn push(&mut self, d: u32) {
+        if self.kept + self.zeros == 0 && d == 0 {
+            return;
+        }
+        if self.kept + self.zeros >= self.max {
+            self.dropped += 1;
+            self.sticky |= d != 0;
+            return;
+        }
+        if d == 0 {
+            self.zeros += 1;
+            return;
+        }
+        self.put_zeros();
+        self.put(d);
+    }
+
+    fn put(&mut self, d: u32) {
+        self.chunk = self.chunk * self.radix + d;
+        self.chunk_len += 1;
+        self.kept += 1;
+        if self.chunk_len == self.chunk_max {
+            self.flush();
+        }
+    }
+
+    fn put_zeros(&mut self) {
+        for _ in 0..self.zeros {
+            self.put(0);
+        }
+        self.zeros = 0;
+    }
+
+    fn flush(&mut self) {
+        mul_small(&mut self.num, self.radix.pow(self.chunk_len), self.chunk);
+        self.chunk = 0;
+        self.chunk_len = 0;
+    }
+
+    // Returns the integer, the number of significant digits in it, and the power of the
+    // radix that it has to be multiplied by to make up for the zeros and digits that never
+    // went into it.
+    fn finish(mut self) -> (Fixed, usize, i64) {
+        let shift = if self.sticky {
+            self.put_zeros();
+            self.put(1);
+            self.dropped - 1
+        } else {
+            self.dropped + self.zeros as i64
+        };
+        self.flush();
+        (self.num, self.kept, shift)
+    }
+}
+
+// Negates all of the parts if the string starts with a minus sign.
+fn apply_sign(s: &str, mut parts: [f64; MAX_PARTS]) -> [f64; MAX_PARTS] {
+    if s.starts_with('-') {
+        for part in parts.iter_mut() {
+            *part = -*part;
+        }
+    }
+    parts
+}
+
+// Splits `num / den` into `parts` `f64`s, each one rounded to the nearest and taken away
+// from what's left before the next one is found.
+fn split(mut num: Vec<u32>, mut den: Vec<u32>, parts: usize) -> [f64; MAX_PARTS] {
+    let mut result = [0.0; MAX_PARTS];
+
+    // The remainder is `num / den`, negated if `negative` is set
+    let mut negative = false;
+    for part in result[..parts].iter_mut() {
+        if num.is_empty() {
+            break;
+        }
+
+        // 2^e <= num / den < 2^(e + 1)
+        let mut e = bits(&num) as i64 - bits(&den) as i64;
+        let less = if e >= 0 {
+            compare(&num, &shl(&den, e as usize)) == Ordering::Less
+        } else {
+            compare(&shl(&num, -e as usize), &den) == Ordering::Less
+        };
+        if less {
+            e -= 1;
+        }
+        if e > 1023 {
+            *part = if negative {
+                f64::NEG_INFINITY
+            } else {
+                f64::INFINITY
+            };
+            break;
+        }
+
+        // The quotient of `x` and `y` is the remainder divided by the worth of the last
+        // bit of the `f64`, which leaves it with no more than 53 bits
+        let lsb = (e - 52).max(-1074);
+        let (x, y) = if lsb >= 0 {
+            (num, shl(&den, lsb as usize))
+        } else {
+            (shl(&num, -lsb as usize), den.clone())
+        };
+        let mut rem = x;
+        let mut q = 0u64;
+        for i in (0..53).rev() {
+            let t = shl(&y, i);
+            if compare(&rem, &t) != Ordering::Less {
+                sub_assign(&mut rem, &t);
+                q |= 1 << i;
+            }
+        }
+        let up = match compare(&shl(&rem, 1), &y) {
+            Ordering::Greater => true,
+            Ordering::Equal => q & 1 == 1,
+            Ordering::Less => false,
+        };
+        let value = ldexp((q + up as u64) as f64, lsb as i32);
+        if value == 0.0 {
+            // What's left is less than half of the smallest subnormal, so the rest of the
+            // parts are zero too (and positive, whatever the sign of what's left)
+            break;
+        }
+        *part = if negative { -value } else { value };
+        if value.is_infinite() {
+            break;
+        }
+
+        // What's left is (rem - y) / y × 2^lsb if it rounded up, or rem / y × 2^lsb if it
+        // didn't
+        num = if up {
+            negative = !negative;
+            let mut diff = y.clone();
+            sub_assign(&mut diff, &rem);
+            diff
+        } else {
+            rem
+        };
+        if lsb < 0 {
+            den = shl(&den, -lsb as usize);
+        }
+    }
+    result
+}
+
+// The largest power of five that fits in a `u32`, which is the most that an integer can be
+// multiplied or divided by at once.
+const POW5_STEP: i64 = 13;
+
+// Splits (`num` + f) × 2^`exp` into `parts` `f64`s in the same way that `split` does, where
+// f is zero if `inexact` isn't set, and something strictly between 0 and 1 (but otherwise
+// unknown) if it is. That's the integer part and the leftover fraction of a quotient.
+//
+// Rounding a part only needs to know whether what's below its last bit is less than, equal
+// to, or more than half of that bit, and the fraction can only make a difference when
+// there are no bits of `num` below the last bit of the part at all. Then there's no way to
+// tell, and this returns `None`.
+fn split_scaled(mut num: Fixed, exp: i64, inexact: bool, parts: usize) -> Option<[f64; MAX_PARTS]> {
+    let mut result = [0.0; MAX_PARTS];
+
+    // The remainder is (`num` + f) × 2^`exp`, negated if `negative` is set
+    let mut negative = false;
+    for part in result[..parts].iter_mut() {
+        let len = bits(&num) as i64;
+        if len == 0 && !inexact {
+            break;
+        }
+        if exp + len > 1024 {
+            *part = if negative {
+                f64::NEG_INFINITY
+            } else {
+                f64::INFINITY
+            };
+            break;
+        }
+
+        // The place of the last bit of the part, counting from the last bit of `num`
+        let lsb = (exp + len - 53).max(-1074) - exp;
+        if lsb <= 0 {
+            if inexact {
+                return None;
+            }
+            // Everything that's left fits in this part
+            let value = ldexp(to_u64(&num) as f64, exp as i32);
+            *part = if negative { -value } else { value };
+            break;
+        }
+
+        let lsb = lsb as usize;
+        let q = high_bits(&num, lsb);
+        let half = bit(&num, lsb - 1);
+        truncate_bits(&mut num, lsb);
+        let below = low_bits_set(&num, lsb - 1);
+        let up = half && (below || inexact || q & 1 == 1);
+        let value = ldexp((q + up as u64) as f64, (exp + lsb as i64) as i32);
+        if value == 0.0 {
+            break;
+        }
+        *part = if negative { -value } else { value };
+        if value.is_infinite() {
+            break;
+        }
+
+        // Rounding up leaves 2^lsb - (`num` + f) to be taken away, which is 2^lsb - `num` if
+        // there's no fraction and 2^lsb - `num` - 1 plus another fraction if there is
+        if up {
+            negative = !negative;
+            let mut diff = Fixed::pow2(lsb);
+            sub_assign(&mut diff, &num);
+            if inexact {
+                sub_assign(&mut diff, &[1]);
+            }
+            num = diff;
+        }
+    }
+    Some(result)
+}
+
+// Multiplies by a power of two in two steps, so that neither overflows or underflows early.
+fn ldexp(x: f64, n: i32) -> f64 {
+    let half = n / 2;
+    x * 2f64.powi(half) * 2f64.powi(n - half)
+}
@@ -0,0 +1,154 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::bigint as b;
+use bytes::{BufMut, BytesMut};
+use num_bigint::{BigInt, Sign};
+use std::error::Error;
+
+// The binary format of a Postgres `NUMERIC` is a header of four 16-bit big-endian integers
+// (the number of digits, the weight of the first digit, the sign, and the display scale),
+// followed by the digits themselves. Each digit is a base-10000 digit (a group of four
+// decimal digits) in its own 16-bit integer, and the weight is the power of 10000 that the
+// first one is multiplied by.
+const POSITIVE: u16 = 0x0000;
+const NEGATIVE: u16 = 0x4000;
+const NAN: u16 = 0xc000;
+const INFINITY: u16 = 0xd000;
+const NEG_INFINITY: u16 = 0xf000;
+
+/// Writes the exact sum of a number of `f64`s to a buffer as a `NUMERIC`.
+///
+/// The parts are meant to be the components of a `Double` or `Quad`, so the first one decides
+/// whether the result is `NaN`, infinite, or zero. Every finite `f64` has an exact decimal
+/// expansion, so the `NUMERIC` has exactly the same value as the sum, and its display scale
+/// is just the number of decimal places that it takes to write it out.
+pub fn to_numeric(parts: &[f64], out: &mut BytesMut) {
+    let first = parts[0];
+    if first.is_nan() {
+        return write_header(out, 0, 0, NAN, 0);
+    }
+    if first.is_infinite() {
+        let sign = if first > 0.0 { INFINITY } else { NEG_INFINITY };
+        return write_header(out, 0, 0, sign, 0);
+    }
+
+    let (mantissa, scale) = b::to_bigdecimal(parts).as_bigint_and_exponent();
+    if mantissa.sign() == Sign::NoSign {
+        return write_header(out, 0, 0, POSITIVE, 0);
+    }
+    let sign = if mantissa.sign() == Sign::Minus {
+        NEGATIVE
+    } else {
+        POSITIVE
+    };
+
+    // Pad the decimal digits with zeros on both ends so that the decimal point falls on a
+    // boundary between groups of four, and so that there are whole groups of four on each
+    // side of it
+    let mut decimal = mantissa.magnitude().to_string();
+    let scale = if scale < 0 {
+        decimal.push_str(&"0".repeat(-scale as usize));
+        0
+    } else {
+        scale as usize
+    };
+    if decimal.len() < scale {
+        decimal.insert_str(0, &"0".repeat(scale - decimal.len()));
+    }
+    let int_len = decimal.len() - scale;
+    let left = (4 - int_len % 4) % 4;
+    let right = (4 - scale % 4) % 4;
+    let decimal = format!("{}{}{}", "0".repeat(left), decimal, "0".repeat(right));
+
+    let mut digits: Vec<i16> = decimal
+        .as_bytes()
+        .chunks(4)
+        .map(|chunk| chunk.iter().fold(0, |acc, d| acc * 10 + (d - b'0') as i16))
+        .collect();
+    let mut weight = ((int_len + left) / 4) as i16 - 1;
+    let leading = digits.iter().take_while(|&&d| d == 0).count();
+    digits.drain(..leading);
+    weight -= leading as i16;
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+
+    write_header(out, digits.len() as i16, weight, sign, scale as u16);
+    for d in digits {
+        out.put_i16(d);
+    }
+}
+
+/// Reads a `NUMERIC` in its binary format and splits its value into `parts` `f64`s.
+///
+/// The first part is the `NUMERIC` correctly rounded to the nearest `f64`, and each one after
+/// it is what's left, once the ones before it have been subtracted, rounded the same way.
+/// Subtracting is exact, so a `NUMERIC` that was written from the components of a `Double`
+/// or `Quad` gives back exactly those components. One too large for an `f64` becomes an
+/// infinity, and one too small is rounded into the subnormals or to zero.
+pub fn from_numeric(raw: &[u8], parts: usize) -> Result<Vec<f64>, Box<dyn Error + Sync + Send>> {
+    if raw.len() < 8 {
+        return Err("invalid NUMERIC value: too short".into());
+    }
+    let word = |i: usize| u16::from_be_bytes([raw[2 * i], raw[2 * i + 1]]);
+    let ndigits = word(0) as i16;
+    let weight = word(1) as i16;
+    let sign = word(2);
+    if ndigits < 0 || raw.len() != 8 + 2 * ndigits as usize {
+        return Err("invalid NUMERIC value: wrong length".into());
+    }
+
+    let mut special = vec![0.0; parts];
+    let negative = match sign {
+        POSITIVE => false,
+        NEGATIVE => true,
+        NAN => {
+            special[0] = f64::NAN;
+            return Ok(special);
+        }
+        INFINITY => {
+            special[0] = f64::INFINITY;
+            return Ok(special);
+        }
+        NEG_INFINITY => {
+            special[0] = f64::NEG_INFINITY;
+            return Ok(special);
+        }
+        _ => return Err("invalid NUMERIC value: unknown sign".into()),
+    };
+
+    let mut mantissa = BigInt::from(0);
+    for i in 0..ndigits as usize {
+        let d = word(4 + i);
+        if d >= 10000 {
+            return Err("invalid NUMERIC value: digit out of range".into());
+        }
+        mantissa = mantissa * 10000 + d;
+    }
+    if negative {
+        mantissa = -mantissa;
+    }
+
+    // The value is the mantissa times 10000^(weight - ndigits + 1)
+    let exp = 4 * (weight as i32 - ndigits as i32 + 1);
+    let (num, den) = if exp >= 0 {
+        (mantissa * BigInt::from(10).pow(exp as u32), BigInt::from(1))
+    } else {
+        (mantissa, BigInt::from(10).pow(-exp as u32))
+    };
+    let mut result = b::split_fraction(num, den, parts);
+    if negative && result[0] == 0.0 {
+        result[0] = -0.0;
+    }
+    Ok(result)
+}
+
+fn write_header(out: &mut BytesMut, ndigits: i16, weight: i16, sign: u16, scale: u16) {
+    out.put_i16(ndigits);
+    out.put_i16(weight);
+    out.put_u16(sign);
+    out.put_u16(scale);
+}
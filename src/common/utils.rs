@@ -86,6 +86,60 @@ pub fn accumulate(a: f64, b: f64, c: f64) -> (f64, f64, f64) {
     }
 }
 
+/// Adds two expansions, putting the sum into the components of `out` without
+/// renormalizing it.
+///
+/// Each expansion is a slice of components in decreasing order of magnitude, like the
+/// components of a `Double` or a `Quad`, and neither can be empty. This is the merge sort
+/// and accumulation from `Quad` addition, except that the expansions can be of any length
+/// and as many components of the sum are kept as there are in `out`. Whatever doesn't fit
+/// is added to the last one. `out` should be all zeros to begin with.
+pub fn merge_sum(a: &[f64], b: &[f64], out: &mut [f64]) {
+    let mut i = 0;
+    let mut j = 0;
+    let mut k = 0;
+    let n = out.len();
+
+    let u = take_larger(a, b, &mut i, &mut j);
+    let v = take_larger(a, b, &mut i, &mut j);
+    let (mut u, mut v) = renorm2(u, v);
+
+    while k < n {
+        if i >= a.len() && j >= b.len() {
+            out[k] = u;
+            if k < n - 1 {
+                out[k + 1] = v;
+            }
+            break;
+        }
+
+        let t = take_larger(a, b, &mut i, &mut j);
+        let (s, y, z) = accumulate(u, v, t);
+        u = y;
+        v = z;
+
+        if s != 0.0 {
+            out[k] = s;
+            k += 1;
+        }
+    }
+
+    out[n - 1] += a[i..].iter().chain(b[j..].iter()).sum::<f64>();
+}
+
+// Returns whichever of the next components of two expansions is larger in magnitude, and
+// moves past it. Once one expansion runs out, the components come from the other.
+#[inline]
+fn take_larger(a: &[f64], b: &[f64], i: &mut usize, j: &mut usize) -> f64 {
+    if *j >= b.len() || (*i < a.len() && a[*i].abs() > b[*j].abs()) {
+        *i += 1;
+        a[*i - 1]
+    } else {
+        *j += 1;
+        b[*j - 1]
+    }
+}
+
 /// Renormalizes two components into a two-component value.
 ///
 /// Renormalization ensures that the components of the returned tuple are arranged in such a
@@ -211,3 +265,130 @@ pub fn is_dyadic(n: f64) -> bool {
         f % base == 0.0
     }
 }
+
+/// Converts an integral `f64` into an `i128`, wrapping around modulo 2<sup>128</sup>.
+///
+/// The components of a `Double` or `Quad` that holds an integer are all integers, and the
+/// first one can be as large as 2<sup>128</sup> even if the whole number fits into an `i128`
+/// or a `u128`. Adding the components' wrapped values together (also wrapping) gives the
+/// number's bits as an `i128`, which only need to be cast to the target type.
+#[inline]
+pub fn wrapping_i128(a: f64) -> i128 {
+    if a.abs() < 2f64.powi(126) {
+        a as i128
+    } else {
+        // Anything this large is a multiple of 4, so the division is exact
+        ((a / 4.0) as i128).wrapping_mul(4)
+    }
+}
+
+/// Converts an `f64` into bits whose order as unsigned integers is the same as the numeric
+/// order of the `f64`s.
+///
+/// Positive numbers just need their sign bit set, so that they come after the negatives,
+/// and negative numbers need all of their bits flipped, so that the larger magnitudes come
+/// first. Negative zero is treated as positive zero, and every `NaN` becomes the same
+/// positive quiet `NaN`, which comes after positive infinity.
+#[inline]
+pub fn to_sortable_bits(a: f64) -> u64 {
+    let a = if a == 0.0 {
+        0.0
+    } else if a.is_nan() {
+        f64::NAN
+    } else {
+        a
+    };
+    let bits = a.to_bits();
+    if bits >> 63 == 1 {
+        !bits
+    } else {
+        bits | 1 << 63
+    }
+}
+
+/// Converts bits generated by `to_sortable_bits` back into an `f64`.
+#[inline]
+pub fn from_sortable_bits(bits: u64) -> f64 {
+    if bits >> 63 == 1 {
+        f64::from_bits(bits & !(1 << 63))
+    } else {
+        f64::from_bits(!bits)
+    }
+}
+
+/// Rounds the sum of `hi` and `rest` to an `f64` with round-to-odd, where `hi` is already
+/// that sum rounded to the nearest `f64` (as the first component of a `Double` or `Quad`
+/// always is) and only the sign of `rest` matters.
+///
+/// Rounding to odd means that an inexact result is whichever neighbor has an odd last bit.
+/// That keeps the information that the result was inexact, so rounding it again to nearest
+/// at a precision of at least two bits fewer gives the same result as rounding the exact
+/// sum would. That's how `Double`s and `Quad`s are correctly rounded to `f32` and narrower
+/// types, rather than just rounding their first components.
+#[inline]
+pub fn round_to_odd(hi: f64, rest: f64) -> f64 {
+    if rest == 0.0 || !hi.is_finite() || hi.to_bits() & 1 == 1 {
+        hi
+    } else if hi == 0.0 {
+        rest
+    } else if (rest > 0.0) == (hi > 0.0) {
+        // The exact sum is strictly between `hi` and its neighbor in the direction of `rest`,
+        // which is odd since `hi` is even
+        f64::from_bits(hi.to_bits() + 1)
+    } else {
+        f64::from_bits(hi.to_bits() - 1)
+    }
+}
+
+/// Rounds the sum of `hi` and `rest` to one of the two `f64`s on either side of it at
+/// random, where `hi` is already that sum rounded to the nearest `f64` and `rest` is what's
+/// left over (which only has to be accurate to an `f64`).
+///
+/// `u` is a random number uniformly distributed over [0, 1). The neighbor of `hi` on the
+/// side of `rest` is chosen when `u` is less than the distance from `hi` to the sum as a
+/// fraction of the distance from `hi` to that neighbor, so each of the two is chosen with a
+/// probability proportional to how close the sum is to it. That makes the expected value
+/// of the result the sum itself, which is what stochastic rounding is for. The sum is
+/// never rounded to an infinity.
+#[cfg(feature = "rand")]
+#[inline]
+pub fn round_stochastic(hi: f64, rest: f64, u: f64) -> f64 {
+    if rest == 0.0 || !hi.is_finite() {
+        return hi;
+    }
+    let next = if hi == 0.0 {
+        f64::from_bits(1).copysign(rest)
+    } else if (rest > 0.0) == (hi > 0.0) {
+        f64::from_bits(hi.to_bits() + 1)
+    } else {
+        f64::from_bits(hi.to_bits() - 1)
+    };
+    if next.is_finite() && u < rest.abs() / (next - hi).abs() {
+        next
+    } else {
+        hi
+    }
+}
+
+/// Rounds an `f64` to the nearest number (ties to even) with `bits` bits of precision, none
+/// of which can be worth less than 2<sup>`min_exp`</sup>. That's the rounding to a narrower
+/// floating-point format whose smallest subnormal is 2<sup>`min_exp`</sup>, except that
+/// there's no overflow; a number too large for the format is returned as it is (or rounded
+/// to the next power of two), which the format's own conversion turns into an infinity.
+#[cfg(feature = "half")]
+pub fn round_to_format(a: f64, bits: i32, min_exp: i32) -> f64 {
+    if a == 0.0 || !a.is_finite() {
+        return a;
+    }
+    let exp = ((a.to_bits() >> 52 & 0x7ff) as i32 - 1023).max(-1022);
+    let lsb = (exp - bits + 1).max(min_exp);
+    // Scaling by a power of two is exact here, and it's done in two steps so that neither
+    // factor overflows or underflows
+    let half = lsb / 2;
+    let scaled = a * 2f64.powi(-half) * 2f64.powi(half - lsb);
+    let mut rounded = scaled.round();
+    if (rounded - scaled).abs() == 0.5 && rounded % 2.0 != 0.0 {
+        rounded -= scaled.signum();
+    }
+    rounded * 2f64.powi(half) * 2f64.powi(lsb - half)
+}
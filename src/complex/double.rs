@@ -0,0 +1,1077 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::double::Double;
+use crate::geometry;
+use std::fmt::{Display, Formatter, Result};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[macro_use]
+mod macros {
+    /// Creates a new complex double-double from its real and imaginary parts, or from its
+    /// real part alone.
+    ///
+    /// Each argument can be anything that [`dd!`] accepts. If only one is given, the
+    /// imaginary part is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cdd, dd, ComplexDouble, Double};
+    /// let z = cdd!(1.5, -2);
+    /// assert!(z.re == dd!(1.5));
+    /// assert!(z.im == dd!(-2));
+    ///
+    /// assert!(cdd!("3.25") == ComplexDouble::new(dd!(3.25), Double::ZERO));
+    /// ```
+    ///
+    /// [`dd!`]: macro.dd.html
+    #[macro_export]
+    macro_rules! cdd {
+        ($re:expr) => {
+            $crate::ComplexDouble::new($crate::dd!($re), $crate::Double::ZERO)
+        };
+        ($re:expr, $im:expr) => {
+            $crate::ComplexDouble::new($crate::dd!($re), $crate::dd!($im))
+        };
+    }
+}
+
+/// A complex number whose real and imaginary parts are [`Double`]s.
+///
+/// The arithmetic operators work between two `ComplexDouble`s and between a `ComplexDouble`
+/// and a `Double` (on either side), and every one of them is calculated with the full
+/// precision of a `Double`. Division uses Smith's algorithm, which avoids the overflow and
+/// underflow that dividing by the squared magnitude of the divisor would risk.
+///
+/// # Examples
+/// ```
+/// # use qd::{cdd, dd, ComplexDouble, Double};
+/// let z = cdd!(3, 4);
+/// assert!(z * z.conj() == cdd!(25));
+/// assert!(z.norm() == dd!(5));
+/// assert!(ComplexDouble::I * ComplexDouble::I == cdd!(-1));
+///
+/// let w = cdd!(1) / cdd!(0, 3);
+/// assert!((w.im + dd!(1) / dd!(3)).abs() < dd!(1e-30));
+/// ```
+///
+/// [`Double`]: struct.Double.html
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ComplexDouble {
+    /// The real part of the complex number.
+    pub re: Double,
+    /// The imaginary part of the complex number.
+    pub im: Double,
+}
+
+impl ComplexDouble {
+    /// The complex number 0.
+    pub const ZERO: ComplexDouble = ComplexDouble::new(Double::ZERO, Double::ZERO);
+
+    /// The complex number 1.
+    pub const ONE: ComplexDouble = ComplexDouble::new(Double::ONE, Double::ZERO);
+
+    /// The imaginary unit *i*, the square root of -1.
+    pub const I: ComplexDouble = ComplexDouble::new(Double::ZERO, Double::ONE);
+
+    /// Creates a `ComplexDouble` with the given real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, ComplexDouble, Double};
+    /// let z = ComplexDouble::new(dd!(3), dd!(2));
+    /// assert!(z.re == dd!(3));
+    /// assert!(z.im == dd!(2));
+    /// ```
+    #[inline]
+    pub const fn new(re: Double, im: Double) -> ComplexDouble {
+        ComplexDouble { re, im }
+    }
+
+    /// Creates a `ComplexDouble` from its magnitude and its argument (its angle from the
+    /// positive real axis, in radians).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cdd, dd, ComplexDouble, Double};
+    /// let z = ComplexDouble::from_polar(dd!(2), Double::FRAC_PI_6);
+    /// assert!((z.re - dd!(3).sqrt()).abs() < dd!(1e-30));
+    /// assert!((z.im - dd!(1)).abs() < dd!(1e-30));
+    /// ```
+    pub fn from_polar(r: Double, theta: Double) -> ComplexDouble {
+        let (re, im) = geometry::from_polar(r, theta);
+        ComplexDouble::new(re, im)
+    }
+
+    /// Converts the `ComplexDouble` into polar form, returning its magnitude and its
+    /// argument in that order. This is the same as ([`norm`], [`arg`]).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cdd, dd, ComplexDouble, Double};
+    /// let (r, theta) = cdd!(-2, 2).to_polar();
+    /// assert!((r - dd!(2) * Double::SQRT_2).abs() < dd!(1e-30));
+    /// assert!((theta - Double::FRAC_3_PI_4).abs() < dd!(1e-30));
+    /// ```
+    ///
+    /// [`norm`]: #method.norm
+    /// [`arg`]: #method.arg
+    pub fn to_polar(self) -> (Double, Double) {
+        (self.norm(), self.arg())
+    }
+
+    /// Calculates the complex conjugate of the `ComplexDouble`, which has the same real part
+    /// and the negation of its imaginary part.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cdd, ComplexDouble};
+    /// assert!(cdd!(3, 2).conj() == cdd!(3, -2));
+    /// ```
+    #[inline]
+    pub fn conj(self) -> ComplexDouble {
+        ComplexDouble::new(self.re, -self.im)
+    }
+
+    /// Calculates the magnitude (or absolute value, or modulus) of the `ComplexDouble`,
+    /// √(re² + im²).
+    ///
+    /// The squares are scaled so that they can neither overflow nor underflow. If either
+    /// part is infinite, the magnitude is infinite even if the other part is `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cdd, dd, ComplexDouble};
+    /// assert!(cdd!(3, -4).norm() == dd!(5));
+    ///
+    /// // 10³⁰⁰ squared would overflow
+    /// assert!((cdd!(0, 1e300).norm() / dd!(1e300) - dd!(1)).abs() < dd!(1e-30));
+    /// ```
+    pub fn norm(self) -> Double {
+        geometry::hypot(self.re, self.im)
+    }
+
+    /// Calculates the square of the magnitude of the `ComplexDouble`, re² + im².
+    ///
+    /// This is cheaper than [`norm`] and is exact whenever the squares are, but unlike
+    /// [`norm`] it overflows for parts larger than about 10¹⁵⁴.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cdd, dd, ComplexDouble};
+    /// assert!(cdd!(3, -4).norm_sqr() == dd!(25));
+    /// ```
+    ///
+    /// [`norm`]: #method.norm
+    #[inline]
+    pub fn norm_sqr(self) -> Double {
+        self.re.sqr() + self.im.sqr()
+    }
+
+    /// Calculates the argument of the `ComplexDouble`, its angle from the positive real axis
+    /// in radians. This will be in the range [-π, π].
+    ///
+    /// Unlike [`atan2`], this pays attention to the sign of a zero imaginary part. On the
+    /// negative real axis, the argument is π if the imaginary part is 0 and -π if it's -0,
+    /// which puts the branch cuts of [`ln`] and [`sqrt`] in the right places. Zero itself
+    /// has an argument of 0 (or π if its real part is -0, with the same rule for the sign).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cdd, dd, ComplexDouble, Double};
+    /// assert!(cdd!(0, 2).arg() == Double::FRAC_PI_2);
+    /// assert!(cdd!(-1).arg() == Double::PI);
+    /// assert!(cdd!(-1, -0.0).arg() == -Double::PI);
+    /// ```
+    ///
+    /// [`atan2`]: struct.Double.html#method.atan2
+    /// [`ln`]: #method.ln
+    /// [`sqrt`]: #method.sqrt
+    pub fn arg(self) -> Double {
+        if self.is_nan() {
+            Double::NAN
+        } else if self.im.is_zero() {
+            let theta = if self.re.is_sign_negative() {
+                Double::PI
+            } else {
+                Double::ZERO
+            };
+            if self.im.is_sign_negative() {
+                -theta
+            } else {
+                theta
+            }
+        } else {
+            geometry::scaled_atan2(self.re, self.im)
+        }
+    }
+
+    /// Calculates the reciprocal of the `ComplexDouble`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cdd, dd, ComplexDouble};
+    /// assert!(cdd!(0, 2).recip() == cdd!(0, -0.5));
+    /// assert!(cdd!(1, 1).recip() == cdd!(0.5, -0.5));
+    /// ```
+    #[inline]
+    pub fn recip(self) -> ComplexDouble {
+        ComplexDouble::ONE / self
+    }
+
+    /// Indicates whether either part of the `ComplexDouble` is `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cdd, ComplexDouble, Double};
+    /// assert!(ComplexDouble::new(Double::NAN, Double::ONE).is_nan());
+    /// assert!(!cdd!(1, 2).is_nan());
+    /// ```
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.re.is_nan() || self.im.is_nan()
+    }
+
+    /// Indicates whether either part of the `ComplexDouble` is infinite, as long as neither
+    /// is `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cdd, ComplexDouble, Double};
+    /// assert!(ComplexDouble::new(Double::ONE, Double::NEG_INFINITY).is_infinite());
+    /// assert!(!ComplexDouble::new(Double::NAN, Double::INFINITY).is_infinite());
+    /// ```
+    #[inline]
+    pub fn is_infinite(self) -> bool {
+        !self.is_nan() && (self.re.is_infinite() || self.im.is_infinite())
+    }
+
+    /// Indicates whether both parts of the `ComplexDouble` are finite.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cdd, ComplexDouble, Double};
+    /// assert!(cdd!(1, 2).is_finite());
+    /// assert!(!ComplexDouble::new(Double::INFINITY, Double::ONE).is_finite());
+    /// ```
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+
+    /// Indicates whether both parts of the `ComplexDouble` are zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cdd, ComplexDouble};
+    /// assert!(cdd!(0, -0.0).is_zero());
+    /// assert!(!cdd!(0, 1).is_zero());
+    /// ```
+    #[inline]
+    pub fn is_zero(self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+
+    /// Calculates the exponential function, e<sup>z</sup>, of the `ComplexDouble`.
+    ///
+    /// This is e<sup>re</sup>(cos im + i sin im). A real argument gives exactly the same
+    /// result as [`Double::exp`], with a zero imaginary part of the same sign.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cdd, dd, ComplexDouble, Double};
+    /// let z = ComplexDouble::new(Double::ZERO, Double::PI).exp();
+    /// assert!((z - cdd!(-1)).norm() < dd!(1e-30));
+    /// ```
+    ///
+    /// [`Double::exp`]: struct.Double.html#method.exp
+    pub fn exp(self) -> ComplexDouble {
+        let e = self.re.exp();
+        if self.im.is_zero() {
+            ComplexDouble::new(e, self.im)
+        } else {
+            let (s, c) = self.im.sin_cos();
+            ComplexDouble::new(e * c, e * s)
+        }
+    }
+
+    /// Calculates the principal value of the natural logarithm of the `ComplexDouble`,
+    /// ln |z| + i arg z.
+    ///
+    /// The imaginary part is in the range [-π, π]. The branch cut is along the negative
+    /// real axis, where the imaginary part of the result is π if the imaginary part of the
+    /// argument is 0 and -π if it's -0 (see [`arg`]). The logarithm of zero has a real part
+    /// of -∞.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cdd, dd, ComplexDouble, Double};
+    /// assert!(cdd!(-1).ln() == ComplexDouble::new(Double::ZERO, Double::PI));
+    /// assert!(cdd!(-1, -0.0).ln() == ComplexDouble::new(Double::ZERO, -Double::PI));
+    ///
+    /// let z = cdd!(1.5, -2.25);
+    /// assert!((z.ln().exp() - z).norm() < dd!(1e-30));
+    /// ```
+    ///
+    /// [`arg`]: #method.arg
+    pub fn ln(self) -> ComplexDouble {
+        let re = if self.is_finite() && !self.is_zero() {
+            // The magnitude is taken after scaling by a power of two that brings it near 1,
+            // since it can't keep all of its bits if it's subnormal, and the logarithm of
+            // the power is put back as a multiple of ln(2)
+            let n = geometry::scale(self.re, self.im);
+            let z = ComplexDouble::new(self.re.ldexp(-n), self.im.ldexp(-n));
+            z.norm().ln() + Double::LN_2 * Double::from(n)
+        } else {
+            self.norm().ln()
+        };
+        ComplexDouble::new(re, self.arg())
+    }
+
+    /// Calculates the principal square root of the `ComplexDouble`.
+    ///
+    /// The result always has a non-negative real part. The branch cut is along the negative
+    /// real axis, where the sign of a zero imaginary part of the argument decides the sign of
+    /// the imaginary part of the result.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cdd, dd, ComplexDouble, Double};
+    /// let z = cdd!(-4).sqrt();
+    /// assert!((z - cdd!(0, 2)).norm() < dd!(1e-30));
+    /// let z = cdd!(-4, -0.0).sqrt();
+    /// assert!((z - cdd!(0, -2)).norm() < dd!(1e-30));
+    ///
+    /// let expected = cdd!(1, 1) / Double::SQRT_2;
+    /// assert!((ComplexDouble::I.sqrt() - expected).norm() < dd!(1e-30));
+    /// ```
+    pub fn sqrt(self) -> ComplexDouble {
+        if self.is_zero() {
+            ComplexDouble::new(Double::ZERO, self.im)
+        } else if self.im.is_infinite() {
+            ComplexDouble::new(Double::INFINITY, self.im)
+        } else {
+            // This is √((|re| + |z|) / 2), which suffers no cancellation; the other part is
+            // found by dividing it into im / 2. Halving before adding keeps the sum from
+            // overflowing.
+            let t = (self.re.abs().ldexp(-1) + self.norm().ldexp(-1)).sqrt();
+            let u = self.im.abs() / (t + t);
+            if self.re.is_sign_positive() {
+                ComplexDouble::new(t, if self.im.is_sign_negative() { -u } else { u })
+            } else {
+                ComplexDouble::new(u, if self.im.is_sign_negative() { -t } else { t })
+            }
+        }
+    }
+
+    /// Calculates the `ComplexDouble` raised to a `ComplexDouble` power, using the principal
+    /// value of the logarithm: z<sup>w</sup> = e<sup>w ln z</sup>.
+    ///
+    /// Anything raised to the power of zero is 1, and zero raised to a power with a positive
+    /// real part is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cdd, dd, ComplexDouble, Double};
+    /// // iⁱ = e^(-π/2)
+    /// let z = ComplexDouble::I.powc(ComplexDouble::I);
+    /// assert!((z - cdd!((-Double::FRAC_PI_2).exp())).norm() < dd!(1e-30));
+    /// ```
+    pub fn powc(self, other: ComplexDouble) -> ComplexDouble {
+        if other.is_zero() {
+            ComplexDouble::ONE
+        } else if self.is_zero() && other.re.is_sign_positive() && !other.re.is_zero() {
+            ComplexDouble::ZERO
+        } else {
+            (other * self.ln()).exp()
+        }
+    }
+
+    /// Computes the sine of the `ComplexDouble`, sin re cosh im + i cos re sinh im.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cdd, dd, ComplexDouble, Double};
+    /// // sin i = i sinh 1
+    /// let z = ComplexDouble::I.sin();
+    /// assert!((z - cdd!(0, dd!(1).sinh())).norm() < dd!(1e-30));
+    /// ```
+    pub fn sin(self) -> ComplexDouble {
+        let (s, c) = self.re.sin_cos();
+        let (sh, ch) = self.im.sinh_cosh();
+        ComplexDouble::new(s * ch, c * sh)
+    }
+
+    /// Computes the cosine of the `ComplexDouble`, cos re cosh im - i sin re sinh im.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cdd, dd, ComplexDouble, Double};
+    /// // cos i = cosh 1
+    /// let z = ComplexDouble::I.cos();
+    /// assert!((z - cdd!(dd!(1).cosh())).norm() < dd!(1e-30));
+    /// ```
+    pub fn cos(self) -> ComplexDouble {
+        let (s, c) = self.re.sin_cos();
+        let (sh, ch) = self.im.sinh_cosh();
+        ComplexDouble::new(c * ch, -(s * sh))
+    }
+
+    /// Computes the tangent of the `ComplexDouble`.
+    ///
+    /// This is calculated as -i tanh(iz), so as the imaginary part grows, the result
+    /// approaches ±i without overflowing along the way.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cdd, dd, ComplexDouble, Double};
+    /// let z = cdd!(1.5, -2.25);
+    /// assert!((z.tan() - z.sin() / z.cos()).norm() < dd!(1e-30));
+    ///
+    /// assert!(cdd!(1, 1000).tan() == ComplexDouble::I);
+    /// ```
+    pub fn tan(self) -> ComplexDouble {
+        let t = ComplexDouble::new(-self.im, self.re).tanh();
+        ComplexDouble::new(t.im, -t.re)
+    }
+
+    /// Computes the hyperbolic sine of the `ComplexDouble`, sinh re cos im + i cosh re sin
+    /// im.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cdd, dd, ComplexDouble, Double};
+    /// // sinh iπ/2 = i
+    /// let z = ComplexDouble::new(Double::ZERO, Double::FRAC_PI_2).sinh();
+    /// assert!((z - ComplexDouble::I).norm() < dd!(1e-30));
+    /// ```
+    pub fn sinh(self) -> ComplexDouble {
+        let (sh, ch) = self.re.sinh_cosh();
+        let (s, c) = self.im.sin_cos();
+        ComplexDouble::new(sh * c, ch * s)
+    }
+
+    /// Computes the hyperbolic cosine of the `ComplexDouble`, cosh re cos im + i sinh re sin
+    /// im.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cdd, dd, ComplexDouble, Double};
+    /// // cosh iπ = -1
+    /// let z = ComplexDouble::new(Double::ZERO, Double::PI).cosh();
+    /// assert!((z - cdd!(-1)).norm() < dd!(1e-30));
+    /// ```
+    pub fn cosh(self) -> ComplexDouble {
+        let (sh, ch) = self.re.sinh_cosh();
+        let (s, c) = self.im.sin_cos();
+        ComplexDouble::new(ch * c, sh * s)
+    }
+
+    /// Computes the hyperbolic tangent of the `ComplexDouble`.
+    ///
+    /// This is (sinh re cosh re + i sin im cos im) / (sinh² re + cos² im), in which nothing
+    /// cancels. Once the real part is large enough for that to overflow, the real part of the
+    /// result is ±1 to far more precision than is available, and the imaginary part is
+    /// calculated directly from its asymptotic form.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cdd, dd, ComplexDouble, Double};
+    /// let z = cdd!(1.5, -2.25);
+    /// assert!((z.tanh() - z.sinh() / z.cosh()).norm() < dd!(1e-30));
+    ///
+    /// assert!(cdd!(-1000, 1).tanh() == cdd!(-1));
+    /// ```
+    pub fn tanh(self) -> ComplexDouble {
+        let (s, c) = self.im.sin_cos();
+        if self.re.abs() > Double::from(350) {
+            // The imaginary part is 4 sin im cos im e^(-2|re|). The exponential is squared
+            // rather than calculated directly, because `exp` underflows sooner than its result
+            // would.
+            let re = if self.re.is_sign_negative() {
+                Double::NEG_ONE
+            } else {
+                Double::ONE
+            };
+            let e = (-self.re.abs()).exp();
+            let im = (s * c).ldexp(2) * e * e;
+            ComplexDouble::new(re, im)
+        } else {
+            let (sh, ch) = self.re.sinh_cosh();
+            let d = sh.sqr() + c.sqr();
+            ComplexDouble::new(sh * ch / d, s * c / d)
+        }
+    }
+}
+
+impl From<Double> for ComplexDouble {
+    /// Creates a `ComplexDouble` with the given real part and a zero imaginary part.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, ComplexDouble, Double};
+    /// let z = ComplexDouble::from(dd!(3));
+    /// assert!(z == ComplexDouble::new(dd!(3), Double::ZERO));
+    /// ```
+    #[inline]
+    fn from(re: Double) -> ComplexDouble {
+        ComplexDouble::new(re, Double::ZERO)
+    }
+}
+
+impl From<(Double, Double)> for ComplexDouble {
+    /// Creates a `ComplexDouble` from a tuple of its real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, ComplexDouble};
+    /// let z = ComplexDouble::from((dd!(3), dd!(2)));
+    /// assert!(z == ComplexDouble::new(dd!(3), dd!(2)));
+    /// ```
+    #[inline]
+    fn from((re, im): (Double, Double)) -> ComplexDouble {
+        ComplexDouble::new(re, im)
+    }
+}
+
+impl Display for ComplexDouble {
+    /// Formats the `ComplexDouble` as *a*+*b*i or *a*-*b*i.
+    ///
+    /// Any precision given is used for both parts. The other options (width, sign, and so
+    /// on) apply only to the real part.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cdd, ComplexDouble};
+    /// assert!(format!("{}", cdd!(1.5, -2)) == "1.5-2i");
+    /// assert!(format!("{:.2}", cdd!(1, 0.25)) == "1.00+0.25i");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let sign = if self.im.is_sign_negative() { "-" } else { "+" };
+        let im = match f.precision() {
+            Some(p) => format!("{:.*}", p, self.im.abs()),
+            None => format!("{}", self.im.abs()),
+        };
+        Display::fmt(&self.re, f)?;
+        write!(f, "{}{}i", sign, im)
+    }
+}
+
+impl Neg for ComplexDouble {
+    type Output = ComplexDouble;
+
+    /// Computes the negation of the `ComplexDouble`, negating both parts.
+    #[inline]
+    fn neg(self) -> ComplexDouble {
+        ComplexDouble::new(-self.re, -self.im)
+    }
+}
+
+impl Add for ComplexDouble {
+    type Output = ComplexDouble;
+
+    /// Computes the sum of two `ComplexDouble`s.
+    #[inline]
+    fn add(self, other: ComplexDouble) -> ComplexDouble {
+        ComplexDouble::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl Add<Double> for ComplexDouble {
+    type Output = ComplexDouble;
+
+    /// Computes the sum of a `ComplexDouble` and a `Double`.
+    #[inline]
+    fn add(self, other: Double) -> ComplexDouble {
+        ComplexDouble::new(self.re + other, self.im)
+    }
+}
+
+impl Add<ComplexDouble> for Double {
+    type Output = ComplexDouble;
+
+    /// Computes the sum of a `Double` and a `ComplexDouble`.
+    #[inline]
+    fn add(self, other: ComplexDouble) -> ComplexDouble {
+        ComplexDouble::new(self + other.re, other.im)
+    }
+}
+
+impl Sub for ComplexDouble {
+    type Output = ComplexDouble;
+
+    /// Computes the difference of two `ComplexDouble`s.
+    #[inline]
+    fn sub(self, other: ComplexDouble) -> ComplexDouble {
+        ComplexDouble::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl Sub<Double> for ComplexDouble {
+    type Output = ComplexDouble;
+
+    /// Computes the difference of a `ComplexDouble` and a `Double`.
+    #[inline]
+    fn sub(self, other: Double) -> ComplexDouble {
+        ComplexDouble::new(self.re - other, self.im)
+    }
+}
+
+impl Sub<ComplexDouble> for Double {
+    type Output = ComplexDouble;
+
+    /// Computes the difference of a `Double` and a `ComplexDouble`.
+    #[inline]
+    fn sub(self, other: ComplexDouble) -> ComplexDouble {
+        ComplexDouble::new(self - other.re, -other.im)
+    }
+}
+
+impl Mul for ComplexDouble {
+    type Output = ComplexDouble;
+
+    /// Computes the product of two `ComplexDouble`s, (ac - bd) + (ad + bc)i.
+    #[inline]
+    fn mul(self, other: ComplexDouble) -> ComplexDouble {
+        ComplexDouble::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+impl Mul<Double> for ComplexDouble {
+    type Output = ComplexDouble;
+
+    /// Computes the product of a `ComplexDouble` and a `Double`.
+    #[inline]
+    fn mul(self, other: Double) -> ComplexDouble {
+        ComplexDouble::new(self.re * other, self.im * other)
+    }
+}
+
+impl Mul<ComplexDouble> for Double {
+    type Output = ComplexDouble;
+
+    /// Computes the product of a `Double` and a `ComplexDouble`.
+    #[inline]
+    fn mul(self, other: ComplexDouble) -> ComplexDouble {
+        ComplexDouble::new(self * other.re, self * other.im)
+    }
+}
+
+impl Div for ComplexDouble {
+    type Output = ComplexDouble;
+
+    /// Computes the quotient of two `ComplexDouble`s.
+    ///
+    /// This uses Smith's algorithm, which divides through by the larger part of the divisor
+    /// rather than by its squared magnitude. Dividing by zero gives `NaN` in both parts.
+    fn div(self, other: ComplexDouble) -> ComplexDouble {
+        if other.re.abs() >= other.im.abs() {
+            let r = other.im / other.re;
+            let d = other.re + other.im * r;
+            ComplexDouble::new((self.re + self.im * r) / d, (self.im - self.re * r) / d)
+        } else {
+            let r = other.re / other.im;
+            let d = other.re * r + other.im;
+            ComplexDouble::new((self.re * r + self.im) / d, (self.im * r - self.re) / d)
+        }
+    }
+}
+
+impl Div<Double> for ComplexDouble {
+    type Output = ComplexDouble;
+
+    /// Computes the quotient of a `ComplexDouble` and a `Double`.
+    #[inline]
+    fn div(self, other: Double) -> ComplexDouble {
+        ComplexDouble::new(self.re / other, self.im / other)
+    }
+}
+
+impl Div<ComplexDouble> for Double {
+    type Output = ComplexDouble;
+
+    /// Computes the quotient of a `Double` and a `ComplexDouble`.
+    #[inline]
+    fn div(self, other: ComplexDouble) -> ComplexDouble {
+        ComplexDouble::from(self) / other
+    }
+}
+
+impl AddAssign for ComplexDouble {
+    /// Adds another `ComplexDouble` to this one, in place.
+    #[inline]
+    fn add_assign(&mut self, other: ComplexDouble) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<Double> for ComplexDouble {
+    /// Adds a `Double` to this `ComplexDouble`, in place.
+    #[inline]
+    fn add_assign(&mut self, other: Double) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for ComplexDouble {
+    /// Subtracts another `ComplexDouble` from this one, in place.
+    #[inline]
+    fn sub_assign(&mut self, other: ComplexDouble) {
+        *self = *self - other;
+    }
+}
+
+impl SubAssign<Double> for ComplexDouble {
+    /// Subtracts a `Double` from this `ComplexDouble`, in place.
+    #[inline]
+    fn sub_assign(&mut self, other: Double) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for ComplexDouble {
+    /// Multiplies this `ComplexDouble` by another, in place.
+    #[inline]
+    fn mul_assign(&mut self, other: ComplexDouble) {
+        *self = *self * other;
+    }
+}
+
+impl MulAssign<Double> for ComplexDouble {
+    /// Multiplies this `ComplexDouble` by a `Double`, in place.
+    #[inline]
+    fn mul_assign(&mut self, other: Double) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign for ComplexDouble {
+    /// Divides this `ComplexDouble` by another, in place.
+    #[inline]
+    fn div_assign(&mut self, other: ComplexDouble) {
+        *self = *self / other;
+    }
+}
+
+impl DivAssign<Double> for ComplexDouble {
+    /// Divides this `ComplexDouble` by a `Double`, in place.
+    #[inline]
+    fn div_assign(&mut self, other: Double) {
+        *self = *self / other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dd;
+
+    fn near(expected: ComplexDouble, actual: ComplexDouble) {
+        let diff = (expected - actual).norm();
+        let scale = if expected.norm() > Double::ONE {
+            expected.norm()
+        } else {
+            Double::ONE
+        };
+        let tolerance = scale * dd!(1e-30);
+        assert!(
+            diff <= tolerance,
+            "expected {}, actual {}, difference {}",
+            expected,
+            actual,
+            diff
+        );
+    }
+
+    test!(constructors: {
+        assert!(cdd!(1.5, 2) == ComplexDouble::new(dd!(1.5), dd!(2)));
+        assert!(cdd!(1.5) == ComplexDouble::new(dd!(1.5), Double::ZERO));
+        assert!(ComplexDouble::from(dd!(1.5)) == cdd!(1.5));
+        assert!(ComplexDouble::from((dd!(1), dd!(2))) == cdd!(1, 2));
+        assert!(ComplexDouble::default() == ComplexDouble::ZERO);
+        near(
+            cdd!(0, 2),
+            ComplexDouble::from_polar(dd!(2), Double::FRAC_PI_2),
+        );
+    });
+
+    test!(arithmetic: {
+        let a = cdd!(3, 2);
+        let b = cdd!(1, -4);
+        assert!(a + b == cdd!(4, -2));
+        assert!(a - b == cdd!(2, 6));
+        assert!(a * b == cdd!(11, -10));
+        assert!(-a == cdd!(-3, -2));
+        // (3 + 2i)(1 + 4i) / 17
+        near(cdd!(-5, 14) / dd!(17), a / b);
+        near(a, a / b * b);
+        assert!(ComplexDouble::I * ComplexDouble::I == -ComplexDouble::ONE);
+    });
+
+    test!(arithmetic_real: {
+        let z = cdd!(3, 2);
+        let x = dd!(2);
+        assert!(z + x == cdd!(5, 2));
+        assert!(x + z == cdd!(5, 2));
+        assert!(z - x == cdd!(1, 2));
+        assert!(x - z == cdd!(-1, -2));
+        assert!(z * x == cdd!(6, 4));
+        assert!(x * z == cdd!(6, 4));
+        assert!(z / x == cdd!(1.5, 1));
+        // 2(3 - 2i) / 13
+        near(cdd!(6, -4) / dd!(13), x / z);
+    });
+
+    test!(assignment: {
+        let a = cdd!(3, 2);
+        let mut b = a;
+        b += a;
+        assert!(b == cdd!(6, 4));
+        b -= a;
+        assert!(b == a);
+        b *= a;
+        assert!(b == cdd!(5, 12));
+        b /= a;
+        near(a, b);
+        b += dd!(1);
+        b -= dd!(2);
+        b *= dd!(2);
+        b /= dd!(4);
+        near(cdd!(1, 1), b);
+    });
+
+    test!(division_extremes: {
+        // Dividing by the squared magnitude would overflow or underflow here
+        let big = cdd!(1e300, 1e300);
+        near(ComplexDouble::ONE, big / big);
+        let small = cdd!(1e-300, -1e-300);
+        near(ComplexDouble::ONE, small / small);
+
+        let q = ComplexDouble::ONE / ComplexDouble::ZERO;
+        assert!(q.re.is_nan() && q.im.is_nan());
+    });
+
+    test!(polar: {
+        let z = cdd!(-3, 4);
+        assert!(z.norm() == dd!(5));
+        assert!(z.norm_sqr() == dd!(25));
+        assert!(z.conj() == cdd!(-3, -4));
+        assert!(z * z.conj() == cdd!(25));
+        assert!(cdd!(0, -2).arg() == -Double::FRAC_PI_2);
+        let (r, theta) = z.to_polar();
+        near(z, ComplexDouble::from_polar(r, theta));
+        assert!(ComplexDouble::ZERO.arg() == Double::ZERO);
+        assert!(cdd!(-0.0, 0).arg() == Double::PI);
+        assert!(ComplexDouble::new(Double::NAN, Double::ZERO).arg().is_nan());
+    });
+
+    test!(recip: {
+        assert!(cdd!(2).recip() == cdd!(0.5));
+        assert!(ComplexDouble::I.recip() == -ComplexDouble::I);
+        near(
+            ComplexDouble::ONE,
+            cdd!(0.75, -1.25).recip() * cdd!(0.75, -1.25),
+        );
+    });
+
+    test!(classification: {
+        let nan = ComplexDouble::new(Double::NAN, Double::ONE);
+        let inf = ComplexDouble::new(Double::ONE, Double::INFINITY);
+        assert!(nan.is_nan() && !nan.is_infinite() && !nan.is_finite());
+        assert!(!inf.is_nan() && inf.is_infinite() && !inf.is_finite());
+        assert!(cdd!(1, 2).is_finite());
+        assert!(ComplexDouble::ZERO.is_zero() && !ComplexDouble::I.is_zero());
+        assert!(ComplexDouble::new(Double::NAN, Double::INFINITY).norm() == Double::INFINITY);
+    });
+
+    test!(exp_ln: {
+        let z = cdd!(1.5, -2.25);
+        near(
+            cdd!(
+                "-2.815278859231166819573947220100141460",
+                "-3.487082142415593819929689993102338598"
+            ),
+            z.exp(),
+        );
+        near(
+            cdd!(
+                "0.9947926062789874405875247147888318709",
+                "-0.9827937232473290679857106110146660145"
+            ),
+            z.ln(),
+        );
+        assert!(cdd!(2, -0.0).exp() == ComplexDouble::new(dd!(2).exp(), Double::NEG_ZERO));
+        assert!(ComplexDouble::ZERO.ln().re == Double::NEG_INFINITY);
+        for z in [
+            cdd!(0.25, 3),
+            cdd!(-7, 0.5),
+            cdd!(-1e-10, -1e10),
+            cdd!(100, -100),
+        ] {
+            near(z, z.ln().exp());
+        }
+    });
+
+    test!(ln_large_and_tiny: {
+        // The real part is the logarithm of a magnitude far outside the range where an
+        // exponential can be taken directly. The parts are scaled by powers of two so that
+        // they're exact, in two steps because the power itself underflows for the
+        // subnormal ones.
+        let scale = |x: f64, n: i32| dd!(x).ldexp(n / 2).ldexp(n - n / 2);
+        let z = |re: f64, im: f64, n: i32| ComplexDouble::new(scale(re, n), scale(im, n));
+        near(
+            cdd!("694.7566184723794097918328807914027557", "0.9272952180016122324285124629224288040"),
+            z(3.0, 4.0, 1000).ln(),
+        );
+        near(
+            cdd!("-691.5377426475112090426313621249503804", "-0.9272952180016122324285124629224288040"),
+            z(3.0, -4.0, -1000).ln(),
+        );
+        near(
+            cdd!("707.3566977614241882602853799480691877", "0.7853981633974483096156608458198757210"),
+            z(1.0, 1.0, 1020).ln(),
+        );
+        near(
+            cdd!("-714.5231713816465091312831696782251244", "2.034443935795702735445577923100965844"),
+            z(-1.0, 2.0, -1032).ln(),
+        );
+    });
+
+    test!(branch_cuts: {
+        let pi = Double::PI;
+        assert!(cdd!(-2).ln().im == pi);
+        assert!(cdd!(-2, -0.0).ln().im == -pi);
+        assert!(cdd!(2, -0.0).ln().im == Double::NEG_ZERO);
+        near(cdd!(0, 3), cdd!(-9).sqrt());
+        near(cdd!(0, -3), cdd!(-9, -0.0).sqrt());
+        let s = cdd!(-9, -0.0).sqrt();
+        assert!(s.re.is_zero() && s.re.is_sign_positive());
+        // Just above and below the cut, the results are on opposite sides
+        assert!(cdd!(-1, 1e-300).sqrt().im > Double::ZERO);
+        assert!(cdd!(-1, -1e-300).sqrt().im < Double::ZERO);
+    });
+
+    test!(sqrt: {
+        let z = cdd!(1.5, -2.25);
+        near(
+            cdd!(
+                "1.449855761204884816772380362034366571",
+                "-0.7759392555471053011877738836100457404"
+            ),
+            z.sqrt(),
+        );
+        for z in [
+            cdd!(0.25, 3),
+            cdd!(-7, 0.5),
+            cdd!(-1e-10, -1e10),
+            cdd!(1e300, -1e300),
+        ] {
+            let s = z.sqrt();
+            assert!(s.re >= Double::ZERO);
+            near(z, s * s);
+        }
+        assert!(cdd!(0, -0.0).sqrt() == cdd!(0, -0.0));
+        let inf = ComplexDouble::new(Double::NAN, Double::INFINITY).sqrt();
+        assert!(inf == ComplexDouble::new(Double::INFINITY, Double::INFINITY));
+    });
+
+    test!(powc: {
+        near(
+            cdd!(
+                "3.325763558346581649232375659006968238",
+                "0.8658686658939642719995692875798722912"
+            ),
+            cdd!(1.5, -2.25).powc(cdd!(0.5, 0.75)),
+        );
+        near(cdd!(-8), cdd!(2).powc(cdd!(3)) * cdd!(-1));
+        assert!(cdd!(1.5, -2.25).powc(ComplexDouble::ZERO) == ComplexDouble::ONE);
+        assert!(ComplexDouble::ZERO.powc(cdd!(0.5, 1)) == ComplexDouble::ZERO);
+        near(
+            cdd!((-Double::FRAC_PI_2).exp()),
+            ComplexDouble::I.powc(ComplexDouble::I),
+        );
+    });
+
+    test!(trig: {
+        let z = cdd!(1.5, -2.25);
+        near(
+            cdd!(
+                "4.784552064541834683762933138580013266",
+                "-0.3318401185114664332780365643709156649"
+            ),
+            z.sin(),
+        );
+        near(
+            cdd!(
+                "0.3392957647149185367647981621322628951",
+                "4.679416866449420092395042249852898637"
+            ),
+            z.cos(),
+        );
+        near(
+            cdd!(
+                "0.003205515147866465816518842064720161884",
+                "-1.022235159497119497761794665715271900"
+            ),
+            z.tan(),
+        );
+        for z in [cdd!(0.25, 0.5), cdd!(-0.75, 1.25)] {
+            near(ComplexDouble::ONE, z.sin() * z.sin() + z.cos() * z.cos());
+        }
+        for z in [cdd!(0.25, 3), cdd!(-7, 0.5), cdd!(2, -20)] {
+            near(z.sin(), z.tan() * z.cos());
+        }
+        assert!(cdd!(1, -1000).tan() == -ComplexDouble::I);
+    });
+
+    test!(hyperbolic: {
+        let z = cdd!(1.5, -2.25);
+        near(
+            cdd!(
+                "-1.337557189096011353832656632294264316",
+                "-1.830346869722198230052381208900749291"
+            ),
+            z.sinh(),
+        );
+        near(
+            cdd!(
+                "-1.477721670135155465741290587805877144",
+                "-1.656735272693395589877308784201589307"
+            ),
+            z.cosh(),
+        );
+        near(
+            cdd!(
+                "1.016334677559343005649181147292696837",
+                "0.09917250556037532427207298153062564663"
+            ),
+            z.tanh(),
+        );
+        for z in [cdd!(0.5, 0.25), cdd!(1.25, -0.75)] {
+            near(
+                ComplexDouble::ONE,
+                z.cosh() * z.cosh() - z.sinh() * z.sinh(),
+            );
+        }
+        for z in [cdd!(0.25, 3), cdd!(-7, 0.5), cdd!(20, -2)] {
+            near(z.sinh(), z.tanh() * z.cosh());
+            // sinh z = -i sin iz
+            near(z.sinh(), -ComplexDouble::I * (ComplexDouble::I * z).sin());
+        }
+        let t = cdd!(351, 1).tanh();
+        assert!(t.re == Double::ONE);
+        assert!(t.im > Double::ZERO && t.im < dd!(1e-300));
+    });
+
+    test!(display: {
+        assert!(format!("{}", cdd!(1.5, 2)) == "1.5+2i");
+        assert!(format!("{}", cdd!(-1.5, -2)) == "-1.5-2i");
+        assert!(format!("{}", cdd!(0, -0.0)) == "0-0i");
+        assert!(format!("{:.3}", cdd!(1, -0.5)) == "1.000-0.500i");
+    });
+}
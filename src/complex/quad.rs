@@ -0,0 +1,1106 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::quad::Quad;
+use std::fmt::{Display, Formatter, Result};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[macro_use]
+mod macros {
+    /// Creates a new complex quad-double from its real and imaginary parts, or from its
+    /// real part alone.
+    ///
+    /// Each argument can be anything that [`qd!`] accepts. If only one is given, the
+    /// imaginary part is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cqd, qd, ComplexQuad, Quad};
+    /// let z = cqd!(1.5, -2);
+    /// assert!(z.re == qd!(1.5));
+    /// assert!(z.im == qd!(-2));
+    ///
+    /// assert!(cqd!("3.25") == ComplexQuad::new(qd!(3.25), Quad::ZERO));
+    /// ```
+    ///
+    /// [`qd!`]: macro.qd.html
+    #[macro_export]
+    macro_rules! cqd {
+        ($re:expr) => {
+            $crate::ComplexQuad::new($crate::qd!($re), $crate::Quad::ZERO)
+        };
+        ($re:expr, $im:expr) => {
+            $crate::ComplexQuad::new($crate::qd!($re), $crate::qd!($im))
+        };
+    }
+}
+
+/// A complex number whose real and imaginary parts are [`Quad`]s.
+///
+/// The arithmetic operators work between two `ComplexQuad`s and between a `ComplexQuad`
+/// and a `Quad` (on either side), and every one of them is calculated with the full
+/// precision of a `Quad`. Division uses Smith's algorithm, which avoids the overflow and
+/// underflow that dividing by the squared magnitude of the divisor would risk.
+///
+/// # Examples
+/// ```
+/// # use qd::{cqd, qd, ComplexQuad, Quad};
+/// let z = cqd!(3, 4);
+/// assert!(z * z.conj() == cqd!(25));
+/// assert!((z.norm() - qd!(5)).abs() < qd!(1e-60));
+/// assert!(ComplexQuad::I * ComplexQuad::I == cqd!(-1));
+///
+/// let w = cqd!(1) / cqd!(0, 3);
+/// assert!((w.im + qd!(1) / qd!(3)).abs() < qd!(1e-60));
+/// ```
+///
+/// [`Quad`]: struct.Quad.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ComplexQuad {
+    /// The real part of the complex number.
+    pub re: Quad,
+    /// The imaginary part of the complex number.
+    pub im: Quad,
+}
+
+impl ComplexQuad {
+    /// The complex number 0.
+    pub const ZERO: ComplexQuad = ComplexQuad::new(Quad::ZERO, Quad::ZERO);
+
+    /// The complex number 1.
+    pub const ONE: ComplexQuad = ComplexQuad::new(Quad::ONE, Quad::ZERO);
+
+    /// The imaginary unit *i*, the square root of -1.
+    pub const I: ComplexQuad = ComplexQuad::new(Quad::ZERO, Quad::ONE);
+
+    /// Creates a `ComplexQuad` with the given real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, ComplexQuad, Quad};
+    /// let z = ComplexQuad::new(qd!(3), qd!(2));
+    /// assert!(z.re == qd!(3));
+    /// assert!(z.im == qd!(2));
+    /// ```
+    #[inline]
+    pub const fn new(re: Quad, im: Quad) -> ComplexQuad {
+        ComplexQuad { re, im }
+    }
+
+    /// Creates a `ComplexQuad` from its magnitude and its argument (its angle from the
+    /// positive real axis, in radians).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cqd, qd, ComplexQuad, Quad};
+    /// let z = ComplexQuad::from_polar(qd!(2), Quad::FRAC_PI_6);
+    /// assert!((z.re - qd!(3).sqrt()).abs() < qd!(1e-60));
+    /// assert!((z.im - qd!(1)).abs() < qd!(1e-60));
+    /// ```
+    pub fn from_polar(r: Quad, theta: Quad) -> ComplexQuad {
+        let (s, c) = theta.sin_cos();
+        ComplexQuad::new(r * c, r * s)
+    }
+
+    /// Converts the `ComplexQuad` into polar form, returning its magnitude and its
+    /// argument in that order. This is the same as ([`norm`], [`arg`]).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cqd, qd, ComplexQuad, Quad};
+    /// let (r, theta) = cqd!(-2, 2).to_polar();
+    /// assert!((r - qd!(2) * Quad::SQRT_2).abs() < qd!(1e-60));
+    /// assert!((theta - Quad::FRAC_3_PI_4).abs() < qd!(1e-60));
+    /// ```
+    ///
+    /// [`norm`]: #method.norm
+    /// [`arg`]: #method.arg
+    pub fn to_polar(self) -> (Quad, Quad) {
+        (self.norm(), self.arg())
+    }
+
+    /// Calculates the complex conjugate of the `ComplexQuad`, which has the same real part
+    /// and the negation of its imaginary part.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cqd, ComplexQuad};
+    /// assert!(cqd!(3, 2).conj() == cqd!(3, -2));
+    /// ```
+    #[inline]
+    pub fn conj(self) -> ComplexQuad {
+        ComplexQuad::new(self.re, -self.im)
+    }
+
+    /// Calculates the magnitude (or absolute value, or modulus) of the `ComplexQuad`,
+    /// √(re² + im²).
+    ///
+    /// The squares are scaled so that they can neither overflow nor underflow. If either
+    /// part is infinite, the magnitude is infinite even if the other part is `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cqd, qd, ComplexQuad};
+    /// assert!((cqd!(3, -4).norm() - qd!(5)).abs() < qd!(1e-60));
+    ///
+    /// // 10³⁰⁰ squared would overflow
+    /// assert!((cqd!(0, 1e300).norm() / qd!(1e300) - qd!(1)).abs() < qd!(1e-60));
+    /// ```
+    pub fn norm(self) -> Quad {
+        if self.re.is_infinite() || self.im.is_infinite() {
+            Quad::INFINITY
+        } else if self.is_nan() {
+            Quad::NAN
+        } else if self.is_zero() {
+            Quad::ZERO
+        } else {
+            let n = self.scale();
+            let (re, im) = (self.re.ldexp(-n), self.im.ldexp(-n));
+            (re.sqr() + im.sqr()).sqrt().ldexp(n)
+        }
+    }
+
+    /// Calculates the square of the magnitude of the `ComplexQuad`, re² + im².
+    ///
+    /// This is cheaper than [`norm`] and is exact whenever the squares are, but unlike
+    /// [`norm`] it overflows for parts larger than about 10¹⁵⁴.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cqd, qd, ComplexQuad};
+    /// assert!(cqd!(3, -4).norm_sqr() == qd!(25));
+    /// ```
+    ///
+    /// [`norm`]: #method.norm
+    #[inline]
+    pub fn norm_sqr(self) -> Quad {
+        self.re.sqr() + self.im.sqr()
+    }
+
+    /// Calculates the argument of the `ComplexQuad`, its angle from the positive real axis
+    /// in radians. This will be in the range [-π, π].
+    ///
+    /// Unlike [`atan2`], this pays attention to the sign of a zero imaginary part. On the
+    /// negative real axis, the argument is π if the imaginary part is 0 and -π if it's -0,
+    /// which puts the branch cuts of [`ln`] and [`sqrt`] in the right places. Zero itself
+    /// has an argument of 0 (or π if its real part is -0, with the same rule for the sign).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cqd, qd, ComplexQuad, Quad};
+    /// assert!(cqd!(0, 2).arg() == Quad::FRAC_PI_2);
+    /// assert!(cqd!(-1).arg() == Quad::PI);
+    /// assert!(cqd!(-1, -0.0).arg() == -Quad::PI);
+    /// ```
+    ///
+    /// [`atan2`]: struct.Quad.html#method.atan2
+    /// [`ln`]: #method.ln
+    /// [`sqrt`]: #method.sqrt
+    pub fn arg(self) -> Quad {
+        if self.is_nan() {
+            Quad::NAN
+        } else if self.im.is_zero() {
+            let theta = if self.re.is_sign_negative() {
+                Quad::PI
+            } else {
+                Quad::ZERO
+            };
+            if self.im.is_sign_negative() {
+                -theta
+            } else {
+                theta
+            }
+        } else if self.is_finite() {
+            // `atan2` squares its arguments to normalize them, so they're scaled first; the
+            // angle doesn't change when both are multiplied by the same positive number
+            let n = self.scale();
+            self.im.ldexp(-n).atan2(self.re.ldexp(-n))
+        } else {
+            self.im.atan2(self.re)
+        }
+    }
+
+    // Returns the binary exponent of the larger of the two (finite, not both zero) parts, by
+    // which both can be scaled (exactly) to bring the larger near 1. Subnormals come out as
+    // -1023, which scales them up far enough to be safe either way.
+    fn scale(self) -> i32 {
+        let exp = |q: Quad| ((q[0].to_bits() >> 52) & 0x7ff) as i32 - 1023;
+        exp(self.re).max(exp(self.im))
+    }
+
+    /// Calculates the reciprocal of the `ComplexQuad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cqd, qd, ComplexQuad};
+    /// assert!(cqd!(0, 2).recip() == cqd!(0, -0.5));
+    /// assert!(cqd!(1, 1).recip() == cqd!(0.5, -0.5));
+    /// ```
+    #[inline]
+    pub fn recip(self) -> ComplexQuad {
+        ComplexQuad::ONE / self
+    }
+
+    /// Indicates whether either part of the `ComplexQuad` is `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cqd, ComplexQuad, Quad};
+    /// assert!(ComplexQuad::new(Quad::NAN, Quad::ONE).is_nan());
+    /// assert!(!cqd!(1, 2).is_nan());
+    /// ```
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.re.is_nan() || self.im.is_nan()
+    }
+
+    /// Indicates whether either part of the `ComplexQuad` is infinite, as long as neither
+    /// is `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cqd, ComplexQuad, Quad};
+    /// assert!(ComplexQuad::new(Quad::ONE, Quad::NEG_INFINITY).is_infinite());
+    /// assert!(!ComplexQuad::new(Quad::NAN, Quad::INFINITY).is_infinite());
+    /// ```
+    #[inline]
+    pub fn is_infinite(self) -> bool {
+        !self.is_nan() && (self.re.is_infinite() || self.im.is_infinite())
+    }
+
+    /// Indicates whether both parts of the `ComplexQuad` are finite.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cqd, ComplexQuad, Quad};
+    /// assert!(cqd!(1, 2).is_finite());
+    /// assert!(!ComplexQuad::new(Quad::INFINITY, Quad::ONE).is_finite());
+    /// ```
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+
+    /// Indicates whether both parts of the `ComplexQuad` are zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cqd, ComplexQuad};
+    /// assert!(cqd!(0, -0.0).is_zero());
+    /// assert!(!cqd!(0, 1).is_zero());
+    /// ```
+    #[inline]
+    pub fn is_zero(self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+
+    /// Calculates the exponential function, e<sup>z</sup>, of the `ComplexQuad`.
+    ///
+    /// This is e<sup>re</sup>(cos im + i sin im). A real argument gives exactly the same
+    /// result as [`Quad::exp`], with a zero imaginary part of the same sign.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cqd, qd, ComplexQuad, Quad};
+    /// let z = ComplexQuad::new(Quad::ZERO, Quad::PI).exp();
+    /// assert!((z - cqd!(-1)).norm() < qd!(1e-60));
+    /// ```
+    ///
+    /// [`Quad::exp`]: struct.Quad.html#method.exp
+    pub fn exp(self) -> ComplexQuad {
+        let e = self.re.exp();
+        if self.im.is_zero() {
+            ComplexQuad::new(e, self.im)
+        } else {
+            let (s, c) = self.im.sin_cos();
+            ComplexQuad::new(e * c, e * s)
+        }
+    }
+
+    /// Calculates the principal value of the natural logarithm of the `ComplexQuad`,
+    /// ln |z| + i arg z.
+    ///
+    /// The imaginary part is in the range [-π, π]. The branch cut is along the negative
+    /// real axis, where the imaginary part of the result is π if the imaginary part of the
+    /// argument is 0 and -π if it's -0 (see [`arg`]). The logarithm of zero has a real part
+    /// of -∞.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cqd, qd, ComplexQuad, Quad};
+    /// assert!(cqd!(-1).ln() == ComplexQuad::new(Quad::ZERO, Quad::PI));
+    /// assert!(cqd!(-1, -0.0).ln() == ComplexQuad::new(Quad::ZERO, -Quad::PI));
+    ///
+    /// let z = cqd!(1.5, -2.25);
+    /// assert!((z.ln().exp() - z).norm() < qd!(1e-60));
+    /// ```
+    ///
+    /// [`arg`]: #method.arg
+    pub fn ln(self) -> ComplexQuad {
+        let re = if self.is_finite() && !self.is_zero() {
+            // The magnitude is taken after scaling by a power of two that brings it near 1,
+            // since it can't keep all of its bits if it's subnormal, and the logarithm of
+            // the power is put back as a multiple of ln(2)
+            let n = self.scale();
+            let z = ComplexQuad::new(self.re.ldexp(-n), self.im.ldexp(-n));
+            z.norm().ln() + Quad::LN_2 * Quad::from(n)
+        } else {
+            self.norm().ln()
+        };
+        ComplexQuad::new(re, self.arg())
+    }
+
+    /// Calculates the principal square root of the `ComplexQuad`.
+    ///
+    /// The result always has a non-negative real part. The branch cut is along the negative
+    /// real axis, where the sign of a zero imaginary part of the argument decides the sign of
+    /// the imaginary part of the result.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cqd, qd, ComplexQuad, Quad};
+    /// let z = cqd!(-4).sqrt();
+    /// assert!((z - cqd!(0, 2)).norm() < qd!(1e-60));
+    /// let z = cqd!(-4, -0.0).sqrt();
+    /// assert!((z - cqd!(0, -2)).norm() < qd!(1e-60));
+    ///
+    /// let expected = cqd!(1, 1) / Quad::SQRT_2;
+    /// assert!((ComplexQuad::I.sqrt() - expected).norm() < qd!(1e-60));
+    /// ```
+    pub fn sqrt(self) -> ComplexQuad {
+        if self.is_zero() {
+            ComplexQuad::new(Quad::ZERO, self.im)
+        } else if self.im.is_infinite() {
+            ComplexQuad::new(Quad::INFINITY, self.im)
+        } else {
+            // This is √((|re| + |z|) / 2), which suffers no cancellation; the other part is
+            // found by dividing it into im / 2. The number is scaled by an even power of two
+            // first, both to keep the sum from overflowing and because `Quad::sqrt` loses
+            // precision near the ends of the exponent range.
+            let n = self.scale() & !1;
+            let z = ComplexQuad::new(self.re.ldexp(-n), self.im.ldexp(-n));
+            let t = (z.re.abs() + z.norm()).ldexp(-1).sqrt().ldexp(n / 2);
+            let u = self.im.abs() / (t + t);
+            if self.re.is_sign_positive() {
+                ComplexQuad::new(t, if self.im.is_sign_negative() { -u } else { u })
+            } else {
+                ComplexQuad::new(u, if self.im.is_sign_negative() { -t } else { t })
+            }
+        }
+    }
+
+    /// Calculates the `ComplexQuad` raised to a `ComplexQuad` power, using the principal
+    /// value of the logarithm: z<sup>w</sup> = e<sup>w ln z</sup>.
+    ///
+    /// Anything raised to the power of zero is 1, and zero raised to a power with a positive
+    /// real part is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cqd, qd, ComplexQuad, Quad};
+    /// // iⁱ = e^(-π/2)
+    /// let z = ComplexQuad::I.powc(ComplexQuad::I);
+    /// assert!((z - cqd!((-Quad::FRAC_PI_2).exp())).norm() < qd!(1e-60));
+    /// ```
+    pub fn powc(self, other: ComplexQuad) -> ComplexQuad {
+        if other.is_zero() {
+            ComplexQuad::ONE
+        } else if self.is_zero() && other.re.is_sign_positive() && !other.re.is_zero() {
+            ComplexQuad::ZERO
+        } else {
+            (other * self.ln()).exp()
+        }
+    }
+
+    /// Computes the sine of the `ComplexQuad`, sin re cosh im + i cos re sinh im.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cqd, qd, ComplexQuad, Quad};
+    /// // sin i = i sinh 1
+    /// let z = ComplexQuad::I.sin();
+    /// assert!((z - cqd!(0, qd!(1).sinh())).norm() < qd!(1e-60));
+    /// ```
+    pub fn sin(self) -> ComplexQuad {
+        let (s, c) = self.re.sin_cos();
+        let (sh, ch) = self.im.sinh_cosh();
+        ComplexQuad::new(s * ch, c * sh)
+    }
+
+    /// Computes the cosine of the `ComplexQuad`, cos re cosh im - i sin re sinh im.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cqd, qd, ComplexQuad, Quad};
+    /// // cos i = cosh 1
+    /// let z = ComplexQuad::I.cos();
+    /// assert!((z - cqd!(qd!(1).cosh())).norm() < qd!(1e-60));
+    /// ```
+    pub fn cos(self) -> ComplexQuad {
+        let (s, c) = self.re.sin_cos();
+        let (sh, ch) = self.im.sinh_cosh();
+        ComplexQuad::new(c * ch, -(s * sh))
+    }
+
+    /// Computes the tangent of the `ComplexQuad`.
+    ///
+    /// This is calculated as -i tanh(iz), so as the imaginary part grows, the result
+    /// approaches ±i without overflowing along the way.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cqd, qd, ComplexQuad, Quad};
+    /// let z = cqd!(1.5, -2.25);
+    /// assert!((z.tan() - z.sin() / z.cos()).norm() < qd!(1e-60));
+    ///
+    /// assert!(cqd!(1, 1000).tan() == ComplexQuad::I);
+    /// ```
+    pub fn tan(self) -> ComplexQuad {
+        let t = ComplexQuad::new(-self.im, self.re).tanh();
+        ComplexQuad::new(t.im, -t.re)
+    }
+
+    /// Computes the hyperbolic sine of the `ComplexQuad`, sinh re cos im + i cosh re sin
+    /// im.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cqd, qd, ComplexQuad, Quad};
+    /// // sinh iπ/2 = i
+    /// let z = ComplexQuad::new(Quad::ZERO, Quad::FRAC_PI_2).sinh();
+    /// assert!((z - ComplexQuad::I).norm() < qd!(1e-60));
+    /// ```
+    pub fn sinh(self) -> ComplexQuad {
+        let (sh, ch) = self.re.sinh_cosh();
+        let (s, c) = self.im.sin_cos();
+        ComplexQuad::new(sh * c, ch * s)
+    }
+
+    /// Computes the hyperbolic cosine of the `ComplexQuad`, cosh re cos im + i sinh re sin
+    /// im.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cqd, qd, ComplexQuad, Quad};
+    /// // cosh iπ = -1
+    /// let z = ComplexQuad::new(Quad::ZERO, Quad::PI).cosh();
+    /// assert!((z - cqd!(-1)).norm() < qd!(1e-60));
+    /// ```
+    pub fn cosh(self) -> ComplexQuad {
+        let (sh, ch) = self.re.sinh_cosh();
+        let (s, c) = self.im.sin_cos();
+        ComplexQuad::new(ch * c, sh * s)
+    }
+
+    /// Computes the hyperbolic tangent of the `ComplexQuad`.
+    ///
+    /// This is (sinh re cosh re + i sin im cos im) / (sinh² re + cos² im), in which nothing
+    /// cancels. Once the real part is large enough for that to overflow, the real part of the
+    /// result is ±1 to far more precision than is available, and the imaginary part is
+    /// calculated directly from its asymptotic form.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cqd, qd, ComplexQuad, Quad};
+    /// let z = cqd!(1.5, -2.25);
+    /// assert!((z.tanh() - z.sinh() / z.cosh()).norm() < qd!(1e-60));
+    ///
+    /// assert!(cqd!(-1000, 1).tanh() == cqd!(-1));
+    /// ```
+    pub fn tanh(self) -> ComplexQuad {
+        let (s, c) = self.im.sin_cos();
+        if self.re.abs() > Quad::from(350) {
+            // The imaginary part is 4 sin im cos im e^(-2|re|). The exponential is squared
+            // rather than calculated directly, because `exp` underflows sooner than its result
+            // would.
+            let re = if self.re.is_sign_negative() {
+                Quad::NEG_ONE
+            } else {
+                Quad::ONE
+            };
+            let e = (-self.re.abs()).exp();
+            let im = (s * c).ldexp(2) * e * e;
+            ComplexQuad::new(re, im)
+        } else {
+            let (sh, ch) = self.re.sinh_cosh();
+            let d = sh.sqr() + c.sqr();
+            ComplexQuad::new(sh * ch / d, s * c / d)
+        }
+    }
+}
+
+impl Default for ComplexQuad {
+    /// Returns [`ZERO`], since `Quad` itself has no default.
+    ///
+    /// [`ZERO`]: #associatedconstant.ZERO
+    #[inline]
+    fn default() -> ComplexQuad {
+        ComplexQuad::ZERO
+    }
+}
+
+impl From<Quad> for ComplexQuad {
+    /// Creates a `ComplexQuad` with the given real part and a zero imaginary part.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, ComplexQuad, Quad};
+    /// let z = ComplexQuad::from(qd!(3));
+    /// assert!(z == ComplexQuad::new(qd!(3), Quad::ZERO));
+    /// ```
+    #[inline]
+    fn from(re: Quad) -> ComplexQuad {
+        ComplexQuad::new(re, Quad::ZERO)
+    }
+}
+
+impl From<(Quad, Quad)> for ComplexQuad {
+    /// Creates a `ComplexQuad` from a tuple of its real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, ComplexQuad};
+    /// let z = ComplexQuad::from((qd!(3), qd!(2)));
+    /// assert!(z == ComplexQuad::new(qd!(3), qd!(2)));
+    /// ```
+    #[inline]
+    fn from((re, im): (Quad, Quad)) -> ComplexQuad {
+        ComplexQuad::new(re, im)
+    }
+}
+
+impl Display for ComplexQuad {
+    /// Formats the `ComplexQuad` as *a*+*b*i or *a*-*b*i.
+    ///
+    /// Any precision given is used for both parts. The other options (width, sign, and so
+    /// on) apply only to the real part.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{cqd, ComplexQuad};
+    /// assert!(format!("{}", cqd!(1.5, -2)) == "1.5-2i");
+    /// assert!(format!("{:.2}", cqd!(1, 0.25)) == "1.00+0.25i");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let sign = if self.im.is_sign_negative() { "-" } else { "+" };
+        let im = match f.precision() {
+            Some(p) => format!("{:.*}", p, self.im.abs()),
+            None => format!("{}", self.im.abs()),
+        };
+        Display::fmt(&self.re, f)?;
+        write!(f, "{}{}i", sign, im)
+    }
+}
+
+impl Neg for ComplexQuad {
+    type Output = ComplexQuad;
+
+    /// Computes the negation of the `ComplexQuad`, negating both parts.
+    #[inline]
+    fn neg(self) -> ComplexQuad {
+        ComplexQuad::new(-self.re, -self.im)
+    }
+}
+
+impl Add for ComplexQuad {
+    type Output = ComplexQuad;
+
+    /// Computes the sum of two `ComplexQuad`s.
+    #[inline]
+    fn add(self, other: ComplexQuad) -> ComplexQuad {
+        ComplexQuad::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl Add<Quad> for ComplexQuad {
+    type Output = ComplexQuad;
+
+    /// Computes the sum of a `ComplexQuad` and a `Quad`.
+    #[inline]
+    fn add(self, other: Quad) -> ComplexQuad {
+        ComplexQuad::new(self.re + other, self.im)
+    }
+}
+
+impl Add<ComplexQuad> for Quad {
+    type Output = ComplexQuad;
+
+    /// Computes the sum of a `Quad` and a `ComplexQuad`.
+    #[inline]
+    fn add(self, other: ComplexQuad) -> ComplexQuad {
+        ComplexQuad::new(self + other.re, other.im)
+    }
+}
+
+impl Sub for ComplexQuad {
+    type Output = ComplexQuad;
+
+    /// Computes the difference of two `ComplexQuad`s.
+    #[inline]
+    fn sub(self, other: ComplexQuad) -> ComplexQuad {
+        ComplexQuad::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl Sub<Quad> for ComplexQuad {
+    type Output = ComplexQuad;
+
+    /// Computes the difference of a `ComplexQuad` and a `Quad`.
+    #[inline]
+    fn sub(self, other: Quad) -> ComplexQuad {
+        ComplexQuad::new(self.re - other, self.im)
+    }
+}
+
+impl Sub<ComplexQuad> for Quad {
+    type Output = ComplexQuad;
+
+    /// Computes the difference of a `Quad` and a `ComplexQuad`.
+    #[inline]
+    fn sub(self, other: ComplexQuad) -> ComplexQuad {
+        ComplexQuad::new(self - other.re, -other.im)
+    }
+}
+
+impl Mul for ComplexQuad {
+    type Output = ComplexQuad;
+
+    /// Computes the product of two `ComplexQuad`s, (ac - bd) + (ad + bc)i.
+    #[inline]
+    fn mul(self, other: ComplexQuad) -> ComplexQuad {
+        ComplexQuad::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+impl Mul<Quad> for ComplexQuad {
+    type Output = ComplexQuad;
+
+    /// Computes the product of a `ComplexQuad` and a `Quad`.
+    #[inline]
+    fn mul(self, other: Quad) -> ComplexQuad {
+        ComplexQuad::new(self.re * other, self.im * other)
+    }
+}
+
+impl Mul<ComplexQuad> for Quad {
+    type Output = ComplexQuad;
+
+    /// Computes the product of a `Quad` and a `ComplexQuad`.
+    #[inline]
+    fn mul(self, other: ComplexQuad) -> ComplexQuad {
+        ComplexQuad::new(self * other.re, self * other.im)
+    }
+}
+
+impl Div for ComplexQuad {
+    type Output = ComplexQuad;
+
+    /// Computes the quotient of two `ComplexQuad`s.
+    ///
+    /// This uses Smith's algorithm, which divides through by the larger part of the divisor
+    /// rather than by its squared magnitude. Dividing by zero gives `NaN` in both parts.
+    fn div(self, other: ComplexQuad) -> ComplexQuad {
+        if other.re.abs() >= other.im.abs() {
+            let r = other.im / other.re;
+            let d = other.re + other.im * r;
+            ComplexQuad::new((self.re + self.im * r) / d, (self.im - self.re * r) / d)
+        } else {
+            let r = other.re / other.im;
+            let d = other.re * r + other.im;
+            ComplexQuad::new((self.re * r + self.im) / d, (self.im * r - self.re) / d)
+        }
+    }
+}
+
+impl Div<Quad> for ComplexQuad {
+    type Output = ComplexQuad;
+
+    /// Computes the quotient of a `ComplexQuad` and a `Quad`.
+    #[inline]
+    fn div(self, other: Quad) -> ComplexQuad {
+        ComplexQuad::new(self.re / other, self.im / other)
+    }
+}
+
+impl Div<ComplexQuad> for Quad {
+    type Output = ComplexQuad;
+
+    /// Computes the quotient of a `Quad` and a `ComplexQuad`.
+    #[inline]
+    fn div(self, other: ComplexQuad) -> ComplexQuad {
+        ComplexQuad::from(self) / other
+    }
+}
+
+impl AddAssign for ComplexQuad {
+    /// Adds another `ComplexQuad` to this one, in place.
+    #[inline]
+    fn add_assign(&mut self, other: ComplexQuad) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<Quad> for ComplexQuad {
+    /// Adds a `Quad` to this `ComplexQuad`, in place.
+    #[inline]
+    fn add_assign(&mut self, other: Quad) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for ComplexQuad {
+    /// Subtracts another `ComplexQuad` from this one, in place.
+    #[inline]
+    fn sub_assign(&mut self, other: ComplexQuad) {
+        *self = *self - other;
+    }
+}
+
+impl SubAssign<Quad> for ComplexQuad {
+    /// Subtracts a `Quad` from this `ComplexQuad`, in place.
+    #[inline]
+    fn sub_assign(&mut self, other: Quad) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for ComplexQuad {
+    /// Multiplies this `ComplexQuad` by another, in place.
+    #[inline]
+    fn mul_assign(&mut self, other: ComplexQuad) {
+        *self = *self * other;
+    }
+}
+
+impl MulAssign<Quad> for ComplexQuad {
+    /// Multiplies this `ComplexQuad` by a `Quad`, in place.
+    #[inline]
+    fn mul_assign(&mut self, other: Quad) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign for ComplexQuad {
+    /// Divides this `ComplexQuad` by another, in place.
+    #[inline]
+    fn div_assign(&mut self, other: ComplexQuad) {
+        *self = *self / other;
+    }
+}
+
+impl DivAssign<Quad> for ComplexQuad {
+    /// Divides this `ComplexQuad` by a `Quad`, in place.
+    #[inline]
+    fn div_assign(&mut self, other: Quad) {
+        *self = *self / other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::qd;
+
+    fn near(expected: ComplexQuad, actual: ComplexQuad) {
+        let diff = (expected - actual).norm();
+        let scale = if expected.norm() > Quad::ONE {
+            expected.norm()
+        } else {
+            Quad::ONE
+        };
+        let tolerance = scale * qd!(1e-60);
+        assert!(
+            diff <= tolerance,
+            "expected {}, actual {}, difference {}",
+            expected,
+            actual,
+            diff
+        );
+    }
+
+    test!(constructors: {
+        assert!(cqd!(1.5, 2) == ComplexQuad::new(qd!(1.5), qd!(2)));
+        assert!(cqd!(1.5) == ComplexQuad::new(qd!(1.5), Quad::ZERO));
+        assert!(ComplexQuad::from(qd!(1.5)) == cqd!(1.5));
+        assert!(ComplexQuad::from((qd!(1), qd!(2))) == cqd!(1, 2));
+        assert!(ComplexQuad::default() == ComplexQuad::ZERO);
+        near(cqd!(0, 2), ComplexQuad::from_polar(qd!(2), Quad::FRAC_PI_2));
+    });
+
+    test!(arithmetic: {
+        let a = cqd!(3, 2);
+        let b = cqd!(1, -4);
+        assert!(a + b == cqd!(4, -2));
+        assert!(a - b == cqd!(2, 6));
+        assert!(a * b == cqd!(11, -10));
+        assert!(-a == cqd!(-3, -2));
+        // (3 + 2i)(1 + 4i) / 17
+        near(cqd!(-5, 14) / qd!(17), a / b);
+        near(a, a / b * b);
+        assert!(ComplexQuad::I * ComplexQuad::I == -ComplexQuad::ONE);
+    });
+
+    test!(arithmetic_real: {
+        let z = cqd!(3, 2);
+        let x = qd!(2);
+        assert!(z + x == cqd!(5, 2));
+        assert!(x + z == cqd!(5, 2));
+        assert!(z - x == cqd!(1, 2));
+        assert!(x - z == cqd!(-1, -2));
+        assert!(z * x == cqd!(6, 4));
+        assert!(x * z == cqd!(6, 4));
+        assert!(z / x == cqd!(1.5, 1));
+        // 2(3 - 2i) / 13
+        near(cqd!(6, -4) / qd!(13), x / z);
+    });
+
+    test!(assignment: {
+        let a = cqd!(3, 2);
+        let mut b = a;
+        b += a;
+        assert!(b == cqd!(6, 4));
+        b -= a;
+        assert!(b == a);
+        b *= a;
+        assert!(b == cqd!(5, 12));
+        b /= a;
+        near(a, b);
+        b += qd!(1);
+        b -= qd!(2);
+        b *= qd!(2);
+        b /= qd!(4);
+        near(cqd!(1, 1), b);
+    });
+
+    test!(division_extremes: {
+        // Dividing by the squared magnitude would overflow or underflow here
+        let big = cqd!(1e300, 1e300);
+        near(ComplexQuad::ONE, big / big);
+        let small = cqd!(1e-300, -1e-300);
+        near(ComplexQuad::ONE, small / small);
+
+        let q = ComplexQuad::ONE / ComplexQuad::ZERO;
+        assert!(q.re.is_nan() && q.im.is_nan());
+    });
+
+    test!(polar: {
+        let z = cqd!(-3, 4);
+        near(cqd!(5), cqd!(z.norm()));
+        assert!(z.norm_sqr() == qd!(25));
+        assert!(z.conj() == cqd!(-3, -4));
+        assert!(z * z.conj() == cqd!(25));
+        assert!(cqd!(0, -2).arg() == -Quad::FRAC_PI_2);
+        let (r, theta) = z.to_polar();
+        near(z, ComplexQuad::from_polar(r, theta));
+        assert!(ComplexQuad::ZERO.arg() == Quad::ZERO);
+        assert!(cqd!(-0.0, 0).arg() == Quad::PI);
+        assert!(ComplexQuad::new(Quad::NAN, Quad::ZERO).arg().is_nan());
+    });
+
+    test!(recip: {
+        assert!(cqd!(2).recip() == cqd!(0.5));
+        assert!(ComplexQuad::I.recip() == -ComplexQuad::I);
+        near(
+            ComplexQuad::ONE,
+            cqd!(0.75, -1.25).recip() * cqd!(0.75, -1.25),
+        );
+    });
+
+    test!(classification: {
+        let nan = ComplexQuad::new(Quad::NAN, Quad::ONE);
+        let inf = ComplexQuad::new(Quad::ONE, Quad::INFINITY);
+        assert!(nan.is_nan() && !nan.is_infinite() && !nan.is_finite());
+        assert!(!inf.is_nan() && inf.is_infinite() && !inf.is_finite());
+        assert!(cqd!(1, 2).is_finite());
+        assert!(ComplexQuad::ZERO.is_zero() && !ComplexQuad::I.is_zero());
+        assert!(ComplexQuad::new(Quad::NAN, Quad::INFINITY).norm() == Quad::INFINITY);
+    });
+
+    test!(exp_ln: {
+        let z = cqd!(1.5, -2.25);
+        near(
+            cqd!(
+                "-2.81527885923116681957394722010014146041654809082514966566397675354",
+                "-3.48708214241559381992968999310233859773902170943829559227232082259"
+            ),
+            z.exp(),
+        );
+        near(
+            cqd!(
+                "0.994792606278987440587524714788831870899124261482342501702857069982",
+                "-0.982793723247329067985710611014666014496877453631628556761425088318"
+            ),
+            z.ln(),
+        );
+        assert!(cqd!(2, -0.0).exp() == ComplexQuad::new(qd!(2).exp(), Quad::NEG_ZERO));
+        assert!(ComplexQuad::ZERO.ln().re == Quad::NEG_INFINITY);
+        for z in [
+            cqd!(0.25, 3),
+            cqd!(-7, 0.5),
+            cqd!(-1e-10, -1e10),
+            cqd!(100, -100),
+        ] {
+            near(z, z.ln().exp());
+        }
+    });
+
+    test!(ln_large_and_tiny: {
+        // The real part is the logarithm of a magnitude far outside the range where an
+        // exponential can be taken directly. The parts are scaled by powers of two so that
+        // they're exact, in two steps because the power itself underflows for the
+        // subnormal ones.
+        let scale = |x: f64, n: i32| qd!(x).ldexp(n / 2).ldexp(n - n / 2);
+        let z = |re: f64, im: f64, n: i32| ComplexQuad::new(scale(re, n), scale(im, n));
+        near(
+            cqd!("694.756618472379409791832880791402755715025735714523771842592657384", "0.927295218001612232428512462922428804057074108572240527621866177440"),
+            z(3.0, 4.0, 1000).ln(),
+        );
+        near(
+            cqd!("-691.537742647511209042631362124950380435974533005986736398767361601", "-0.927295218001612232428512462922428804057074108572240527621866177440"),
+            z(3.0, -4.0, -1000).ln(),
+        );
+        near(
+            cqd!("707.356697761424188260285379948069187721047887114640486830153949688", "0.785398163397448309615660845819875721049292349843776455243736148076"),
+            z(1.0, 1.0, 1020).ln(),
+        );
+        near(
+            cqd!("-714.523171381646509131283169678225124434153337982649163391585445851", "2.03444393579570273544557792310096584412712175397367317429840538487"),
+            z(-1.0, 2.0, -1032).ln(),
+        );
+    });
+
+    test!(branch_cuts: {
+        let pi = Quad::PI;
+        assert!(cqd!(-2).ln().im == pi);
+        assert!(cqd!(-2, -0.0).ln().im == -pi);
+        assert!(cqd!(2, -0.0).ln().im == Quad::NEG_ZERO);
+        near(cqd!(0, 3), cqd!(-9).sqrt());
+        near(cqd!(0, -3), cqd!(-9, -0.0).sqrt());
+        let s = cqd!(-9, -0.0).sqrt();
+        assert!(s.re.is_zero() && s.re.is_sign_positive());
+        // Just above and below the cut, the results are on opposite sides
+        assert!(cqd!(-1, 1e-300).sqrt().im > Quad::ZERO);
+        assert!(cqd!(-1, -1e-300).sqrt().im < Quad::ZERO);
+    });
+
+    test!(sqrt: {
+        let z = cqd!(1.5, -2.25);
+        near(
+            cqd!(
+                "1.44985576120488481677238036203436657121811982450524518214799147613",
+                "-0.775939255547105301187773883610045740361564192945364951422177171086"
+            ),
+            z.sqrt(),
+        );
+        for z in [
+            cqd!(0.25, 3),
+            cqd!(-7, 0.5),
+            cqd!(-1e-10, -1e10),
+            cqd!(1e300, -1e300),
+        ] {
+            let s = z.sqrt();
+            assert!(s.re >= Quad::ZERO);
+            near(z, s * s);
+        }
+        assert!(cqd!(0, -0.0).sqrt() == cqd!(0, -0.0));
+        let inf = ComplexQuad::new(Quad::NAN, Quad::INFINITY).sqrt();
+        assert!(inf == ComplexQuad::new(Quad::INFINITY, Quad::INFINITY));
+    });
+
+    test!(powc: {
+        near(
+            cqd!(
+                "3.3257635583465816492323756590069682377325695016914267133642950004",
+                "0.865868665893964271999569287579872291178724775687457637485481080918"
+            ),
+            cqd!(1.5, -2.25).powc(cqd!(0.5, 0.75)),
+        );
+        near(cqd!(-8), cqd!(2).powc(cqd!(3)) * cqd!(-1));
+        assert!(cqd!(1.5, -2.25).powc(ComplexQuad::ZERO) == ComplexQuad::ONE);
+        assert!(ComplexQuad::ZERO.powc(cqd!(0.5, 1)) == ComplexQuad::ZERO);
+        near(
+            cqd!((-Quad::FRAC_PI_2).exp()),
+            ComplexQuad::I.powc(ComplexQuad::I),
+        );
+    });
+
+    test!(trig: {
+        let z = cqd!(1.5, -2.25);
+        near(
+            cqd!(
+                "4.78455206454183468376293313858001326644589827567133864164966420187",
+                "-0.331840118511466433278036564370915664859189055829310017561679454076"
+            ),
+            z.sin(),
+        );
+        near(
+            cqd!(
+                "0.339295764714918536764798162132262895147653901636178423640741922685",
+                "4.67941686644942009239504224985289863706184473303907201576996025091"
+            ),
+            z.cos(),
+        );
+        near(
+            cqd!(
+                "0.00320551514786646581651884206472016188431313127779366881727559523391",
+                "-1.02223515949711949776179466571527189987176458555122839197244950842"
+            ),
+            z.tan(),
+        );
+        for z in [cqd!(0.25, 0.5), cqd!(-0.75, 1.25)] {
+            near(ComplexQuad::ONE, z.sin() * z.sin() + z.cos() * z.cos());
+        }
+        for z in [cqd!(0.25, 3), cqd!(-7, 0.5), cqd!(2, -20)] {
+            near(z.sin(), z.tan() * z.cos());
+        }
+        assert!(cqd!(1, -1000).tan() == -ComplexQuad::I);
+    });
+
+    test!(hyperbolic: {
+        let z = cqd!(1.5, -2.25);
+        near(
+            cqd!(
+                "-1.3375571890960113538326566322942643162681474420712806192633221128",
+                "-1.83034686972219823005238120890074929109631502976918245359955969949"
+            ),
+            z.sinh(),
+        );
+        near(
+            cqd!(
+                "-1.47772167013515546574129058780587714414840064875386904640065464073",
+                "-1.6567352726933955898773087842015893066427066796691131386727611231"
+            ),
+            z.cosh(),
+        );
+        near(
+            cqd!(
+                "1.01633467755934300564918114729269683730547835877531900704420946832",
+                "0.0991725055603753242720729815306256466293467541150851175179255489279"
+            ),
+            z.tanh(),
+        );
+        for z in [cqd!(0.5, 0.25), cqd!(1.25, -0.75)] {
+            near(ComplexQuad::ONE, z.cosh() * z.cosh() - z.sinh() * z.sinh());
+        }
+        for z in [cqd!(0.25, 3), cqd!(-7, 0.5), cqd!(20, -2)] {
+            near(z.sinh(), z.tanh() * z.cosh());
+            // sinh z = -i sin iz
+            near(z.sinh(), -ComplexQuad::I * (ComplexQuad::I * z).sin());
+        }
+        let t = cqd!(351, 1).tanh();
+        assert!(t.re == Quad::ONE);
+        assert!(t.im > Quad::ZERO && t.im < qd!(1e-300));
+    });
+
+    test!(display: {
+        assert!(format!("{}", cqd!(1.5, 2)) == "1.5+2i");
+        assert!(format!("{}", cqd!(-1.5, -2)) == "-1.5-2i");
+        assert!(format!("{}", cqd!(0, -0.0)) == "0-0i");
+        assert!(format!("{:.3}", cqd!(1, -0.5)) == "1.000-0.500i");
+    });
+}
@@ -0,0 +1,111 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::display as d;
+use std::iter::FusedIterator;
+
+/// An iterator over the exact decimal digits of a `Double` or a `Quad`, most significant
+/// first.
+///
+/// This is returned by `Double::digits` and `Quad::digits`. The digits are those of the
+/// magnitude of the number, starting with its first digit that isn't zero and ending with
+/// its last one that isn't zero, so the number is exactly 0.*d₁d₂d₃*... × 10^(`exp` + 1),
+/// with its sign given by `is_sign_negative`. Every finite number has a finite decimal
+/// expansion, but it can run to over a thousand digits, and the digits are only worked out
+/// as they're asked for; taking the first few of them is cheap, however many there are.
+///
+/// Zero, the infinities, and `NaN` have no digits at all.
+///
+/// # Examples
+/// ```
+/// # use qd::{dd, Double};
+/// let digits = dd!(-1234.5).digits();
+/// assert!(digits.exp() == 3);
+/// assert!(digits.collect::<Vec<_>>() == vec![1, 2, 3, 4, 5]);
+///
+/// // The first 20 digits of the exact value of the nearest `Double` to 1/3
+/// let third: Vec<u8> = (dd!(1) / dd!(3)).digits().take(20).collect();
+/// assert!(third == vec![3; 20]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DecimalDigits {
+    // The digits of the integer part that haven't been produced yet, last one first
+    int: Vec<u8>,
+    // The fractional part that's left once the integer digits are gone
+    frac: Vec<u32>,
+    exp: i32,
+}
+
+impl DecimalDigits {
+    // Creates an iterator over the digits of the exact sum of the components of a number.
+    pub(crate) fn new(parts: &[f64]) -> DecimalDigits {
+        if !parts[0].is_finite() || parts[0] == 0.0 {
+            return DecimalDigits {
+                int: vec![],
+                frac: vec![],
+                exp: 0,
+            };
+        }
+
+        let (mut int, mut frac) = d::expand(parts, 10);
+        let mut exp = int.len() as i32 - 1;
+        if int.is_empty() {
+            // Leading zeros after the point aren't significant
+            loop {
+                let digit = d::next_digit(&mut frac, 10);
+                if digit != 0 {
+                    int.push(digit);
+                    break;
+                }
+                exp -= 1;
+            }
+        }
+        if frac.is_empty() {
+            while int.last() == Some(&0) {
+                int.pop();
+            }
+        }
+        int.reverse();
+        DecimalDigits { int, frac, exp }
+    }
+
+    /// Returns the power of ten of the first digit. For a number that has no digits, this
+    /// is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// assert!(dd!(1234.5).digits().exp() == 3);
+    /// assert!(dd!(0.00125).digits().exp() == -3);
+    /// assert!(dd!(1e22).digits().exp() == 22);
+    /// ```
+    pub fn exp(&self) -> i32 {
+        self.exp
+    }
+}
+
+impl Iterator for DecimalDigits {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        match self.int.pop() {
+            Some(digit) => Some(digit),
+            // A fraction that isn't zero always has a last digit that isn't zero either,
+            // so this never produces trailing zeros
+            None if !self.frac.is_empty() => Some(d::next_digit(&mut self.frac, 10)),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.frac.is_empty() {
+            (self.int.len(), Some(self.int.len()))
+        } else {
+            (self.int.len() + 1, None)
+        }
+    }
+}
+
+impl FusedIterator for DecimalDigits {}
@@ -10,10 +10,14 @@ use std::ops::Index;
 mod macros {
     /// Creates a new double-double from another number or from a string.
     ///
-    /// The argument can be any expression that evaluates to a type that this library
-    /// defines a `From` implementation for. This includes `&str`, `Double`, any primitive
-    /// number that is not a `u128` or `i128`, and 2-tuples of any of those primitive number
-    /// types.
+    /// The argument can be a `&str` or any expression that evaluates to a type that this
+    /// library defines a `From` implementation for. This includes `Double`, any primitive
+    /// number (a `u128` or `i128` is rounded if it has more than 106 significant bits), and
+    /// 2-tuples of any of those primitive number types.
+    ///
+    /// A string is parsed, and the macro panics if it isn't a valid number. That makes it
+    /// fine for literals written into the code but not for strings from anywhere else; use
+    /// `try_from` or `parse` for those, which return an error instead.
     ///
     /// # Examples
     /// ```
@@ -28,7 +32,7 @@ mod macros {
     #[macro_export]
     macro_rules! dd {
         ($x:expr) => {
-            $crate::Double::from($x)
+            $crate::literal::IntoDouble::into_double($x)
         };
     }
 }
@@ -40,21 +44,53 @@ mod test_macros;
 mod aconsts; // "a" for "associated", or maybe just to make it appear first in docs
 mod add;
 mod alg;
+#[cfg(feature = "approx")]
+mod approx;
+#[cfg(feature = "rkyv")]
+mod archive;
+#[cfg(feature = "ndarray")]
+mod array;
+#[cfg(feature = "num-bigint")]
+mod bid;
+mod binary128;
+#[cfg(feature = "borsh")]
+mod borsh;
+mod bytes;
 mod common;
 mod comp;
+#[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+mod decimal;
 mod display;
 mod div;
+#[cfg(feature = "simba")]
+mod field;
 mod from;
 mod from_str;
 mod hyper;
 mod iter;
 mod misc;
+#[cfg(feature = "rug")]
+mod mpfr;
 mod mul;
 mod neg;
+#[cfg(feature = "num-traits")]
+mod num;
+#[cfg(feature = "postgres")]
+mod postgres;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "num-rational")]
+mod ratio;
 mod rem;
+#[cfg(feature = "serde")]
+mod serde;
+mod special;
+mod stats;
 mod sub;
 mod trans;
 mod trig;
+#[cfg(feature = "twofloat")]
+mod twofloat;
 
 /// A 128-bit floating-point number implemented as the unevaluated sum of two 64-bit
 /// floating-point numbers. Discarding the bits used for exponents, this makes for about
@@ -63,7 +99,7 @@ mod trig;
 /// There are several ways to create a new `Double`:
 ///
 /// * calling the [`new`] function
-/// * calling [`from`] with a primitive number (except for `u128` and `i128`) or a string
+/// * calling [`from`] with a primitive number or a string
 /// * calling [`parse`] on a string (or equivalently using [`from_str`])
 /// * using the [`dd!`] macro
 ///
@@ -85,6 +121,14 @@ mod trig;
 /// Parsing from strings or from numbers that are not dyadic cannot take these shortcuts.
 /// The results will be precise, but at the cost of speed.
 ///
+/// In memory, a `Double` is laid out exactly as a C struct of its two `f64` components in
+/// order, with no padding (it's `#[repr(C)]`), for a total of 16 bytes aligned to 8. That
+/// means that arrays and slices of them can be handed to anything that expects that layout,
+/// like GPU buffers, memory-mapped files, and FFI structs, without converting them one by
+/// one. The `bytemuck` and `zerocopy` features implement those crates' traits to make
+/// this safe. For files and network protocols that need a layout that doesn't depend on the
+/// machine, [`to_le_bytes`] and [`to_be_bytes`] give the same bytes in a fixed byte order.
+///
 /// See the [module-level documentation](index.html) for more information.
 ///
 /// [`new`]: #method.new
@@ -93,7 +137,24 @@ mod trig;
 /// [`from_str`]: #method.from_str
 /// [`dd!`]: macro.dd.html
 /// [*dyadic*]: https://en.wikipedia.org/wiki/Dyadic_rational
+/// [`to_le_bytes`]: #method.to_le_bytes
+/// [`to_be_bytes`]: #method.to_be_bytes
 #[derive(Clone, Copy, Default)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Clone, Copy, Debug))
+)]
+#[repr(C)]
 pub struct Double(f64, f64);
 
 impl Double {
@@ -118,6 +179,46 @@ impl Double {
     pub const fn new(a: f64, b: f64) -> Double {
         Double(a, b)
     }
+
+    /// Returns the two components of the `Double` as an array.
+    ///
+    /// The high component comes first, then the low one, and they are exactly the same
+    /// values that [`from_array`] will turn back into the same `Double`. This is meant for
+    /// handing `Double`s to code that works on flat buffers of `f64`s, like FFI and SIMD
+    /// code. Its `AsRef<[f64; 2]>` implementation borrows the components as an array instead
+    /// of copying them.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let [hi, lo] = Double::PI.to_array();
+    /// assert!(hi == 3.141592653589793e0);
+    /// assert!(lo == 1.2246467991473532e-16);
+    /// ```
+    ///
+    /// [`from_array`]: #method.from_array
+    pub const fn to_array(self) -> [f64; 2] {
+        [self.0, self.1]
+    }
+
+    /// Creates a `Double` with the two elements of the array as the internal components.
+    ///
+    /// Like [`new`], this does not normalize its components, and the same warning applies.
+    /// It's meant for reconstructing a `Double` from the array returned by [`to_array`],
+    /// which it does exactly.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let a = Double::PI.to_array();
+    /// assert!(Double::from_array(a) == Double::PI);
+    /// ```
+    ///
+    /// [`new`]: #method.new
+    /// [`to_array`]: #method.to_array
+    pub const fn from_array(a: [f64; 2]) -> Double {
+        Double(a[0], a[1])
+    }
 }
 
 impl Index<usize> for Double {
@@ -169,4 +270,14 @@ mod tests {
         index_zero: Double::PI[0], Double::PI.0;
         index_one: Double::PI[1], Double::PI.1;
     );
+
+    test!(layout: {
+        use std::mem;
+        assert!(mem::size_of::<Double>() == 16);
+        assert!(mem::align_of::<Double>() == 8);
+        let x = Double::PI;
+        let base = &x as *const Double as usize;
+        assert!(&x.0 as *const f64 as usize - base == 0);
+        assert!(&x.1 as *const f64 as usize - base == 8);
+    });
 }
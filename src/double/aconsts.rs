@@ -3,6 +3,7 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::double::common as c;
 use crate::double::Double;
 use std::f64;
 
@@ -138,3 +139,44 @@ impl Double {
     /// log<sub>*e*</sub> 10
     pub const LN_10: Double = Double(2.302585092994046e0, -2.1707562233822496e-16);
 }
+
+impl Double {
+    /// Calculates π at runtime using Machin's formula, π = 16 arctan(1/5) - 4 arctan(1/239).
+    ///
+    /// This is a diagnostic rather than something to use in calculations; [`PI`] is both
+    /// faster and more accurate, since it's correctly rounded while this can be off in the
+    /// last bit or two. What this offers is a value of π that's derived independently of
+    /// that stored constant, so it can be used to check that the constant, and the
+    /// arctangent implementation, are correct.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let diff = (Double::compute_pi() - Double::PI).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`PI`]: #associatedconstant.PI
+    pub fn compute_pi() -> Double {
+        let a = (Double::ONE / Double::from(5)).atan();
+        let b = (Double::ONE / Double::from(239)).atan();
+        c::mul_pwr2(a, 16.0) - c::mul_pwr2(b, 4.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Rounding errors in the arctangents are multiplied by 16, so the last bit or two may
+    // differ
+    test_all_assert!(
+        compute_pi:
+            (Double::compute_pi() - Double::PI).abs() <= Double::PI.eps_at() * dd!(2);
+    );
+    test_all_near!(
+        compute_pi_digits:
+            dd!("3.1415926535897932384626433832795028841971693993751"),
+            Double::compute_pi();
+    );
+}
@@ -3,9 +3,12 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::binary128 as b;
 use crate::common::primitive as p;
 use crate::common::utils as u;
+use crate::double::common as c;
 use crate::double::Double;
+use crate::round::RoundMode;
 use std::ops::{Add, AddAssign};
 
 #[allow(clippy::suspicious_arithmetic_impl)]
@@ -28,13 +31,7 @@ impl Add for Double {
     fn add(self, other: Double) -> Double {
         match self.pre_add(&other) {
             Some(r) => r,
-            None => {
-                let (s0, e0) = p::two_sum(self.0, other.0);
-                let (s1, e1) = p::two_sum(self.1, other.1);
-                let (s2, e2) = p::quick_two_sum(s0, s1 + e0);
-                let (a, b) = u::renorm2(s2, e1 + e2);
-                Double(a, b)
-            }
+            None => self.add_unchecked(other),
         }
     }
 }
@@ -154,6 +151,307 @@ impl AddAssign<&Double> for Double {
     }
 }
 
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Add<f64> for Double {
+    type Output = Double;
+
+    /// Adds an `f64` to this `Double`, producing a new `Double` as a result.
+    ///
+    /// This implements the `+` operator between a `Double` and an `f64`.
+    ///
+    /// The `f64` is taken at its exact binary value, as with `Double::from((x, 0.0))`, and
+    /// is worked directly into the components rather than first being promoted to a
+    /// `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::PI + 1.5;
+    /// let expected = dd!("4.641592653589793238462643383280");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    fn add(self, other: f64) -> Double {
+        match self.pre_add(&Double(other, 0.0)) {
+            Some(r) => r,
+            None => {
+                let (s, e) = p::two_sum(self.0, other);
+                let (a, b) = u::renorm2(s, e + self.1);
+                Double(a, b)
+            }
+        }
+    }
+}
+
+impl Add<f64> for &Double {
+    type Output = Double;
+
+    /// Adds an `f64` to a reference to this `Double`, producing a new `Double` as a result.
+    ///
+    /// This implements the `+` operator between a reference to a `Double` and an `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = &Double::PI + 1.5;
+    /// let expected = dd!("4.641592653589793238462643383280");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn add(self, other: f64) -> Double {
+        (*self).add(other)
+    }
+}
+
+impl Add<Double> for f64 {
+    type Output = Double;
+
+    /// Adds this `f64` to a `Double`, producing a new `Double` as a result.
+    ///
+    /// This implements the `+` operator between an `f64` and a `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = 1.5 + Double::PI;
+    /// let expected = dd!("4.641592653589793238462643383280");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn add(self, other: Double) -> Double {
+        other.add(self)
+    }
+}
+
+impl Add<&Double> for f64 {
+    type Output = Double;
+
+    /// Adds this `f64` to a reference to a `Double`, producing a new `Double` as a result.
+    ///
+    /// This implements the `+` operator between an `f64` and a reference to a `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = 1.5 + &Double::PI;
+    /// let expected = dd!("4.641592653589793238462643383280");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn add(self, other: &Double) -> Double {
+        self.add(*other)
+    }
+}
+
+impl AddAssign<f64> for Double {
+    /// Adds an `f64` to this `Double`, modifying this one to equal the result.
+    ///
+    /// This implements the `+=` operator between a `Double` and an `f64`. As with the
+    /// non-assigning operator, the `f64` is taken at its exact binary value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let mut x = Double::PI;
+    /// x += 1.5;
+    /// let expected = dd!("4.641592653589793238462643383280");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: f64) {
+        let r = self.add(other);
+        self.0 = r.0;
+        self.1 = r.1;
+    }
+}
+
+macro_rules! add_int_impl {
+    ($($t:ty)*) => ($(
+        impl Add<$t> for Double {
+            type Output = Double;
+
+            /// Adds an integer to this `Double`, producing a new `Double` as a result.
+            ///
+            /// This implements the `+` operator between a `Double` and an integer. Every
+            /// integer of this type converts exactly to an `f64`, so this takes the same
+            /// fast path as the `f64` operator.
+            #[inline]
+            fn add(self, other: $t) -> Double {
+                self.add(f64::from(other))
+            }
+        }
+
+        impl Add<$t> for &Double {
+            type Output = Double;
+
+            /// Adds an integer to a reference to this `Double`, producing a new `Double` as
+            /// a result.
+            ///
+            /// This implements the `+` operator between a reference to a `Double` and an
+            /// integer.
+            #[inline]
+            fn add(self, other: $t) -> Double {
+                (*self).add(f64::from(other))
+            }
+        }
+
+        impl Add<Double> for $t {
+            type Output = Double;
+
+            /// Adds this integer to a `Double`, producing a new `Double` as a result.
+            ///
+            /// This implements the `+` operator between an integer and a `Double`.
+            #[inline]
+            fn add(self, other: Double) -> Double {
+                f64::from(self).add(other)
+            }
+        }
+
+        impl AddAssign<$t> for Double {
+            /// Adds an integer to this `Double`, modifying this one to equal the result.
+            ///
+            /// This implements the `+=` operator between a `Double` and an integer.
+            #[inline]
+            fn add_assign(&mut self, other: $t) {
+                self.add_assign(f64::from(other));
+            }
+        }
+    )*);
+}
+
+add_int_impl! { i32 u32 }
+
+impl Double {
+    /// Adds this `Double` to another, clamping the result to the range of finite
+    /// `Double`s instead of overflowing.
+    ///
+    /// If both `Double`s are finite but their sum is too large in magnitude to represent,
+    /// the result is [`MAX`] or [`MIN`], whichever has the sign the sum would have had.
+    /// Otherwise the result is the same as it is with the `+` operator, so infinite
+    /// arguments still give infinite results and `NaN` propagates as usual.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::MAX.saturating_add(Double::MAX) == Double::MAX);
+    /// assert!(Double::MIN.saturating_add(Double::MIN) == Double::MIN);
+    /// assert!(dd!(2).saturating_add(dd!(3)) == dd!(2) + dd!(3));
+    /// ```
+    ///
+    /// [`MAX`]: #associatedconstant.MAX
+    /// [`MIN`]: #associatedconstant.MIN
+    pub fn saturating_add(self, other: Double) -> Double {
+        let r = self + other;
+        if !r.is_finite() && self.is_finite() && other.is_finite() {
+            // The overflowed result can be `NaN` rather than infinite, so the sign is taken
+            // from the sum of the first components instead
+            if (self.0 + other.0).is_sign_negative() {
+                Double::MIN
+            } else {
+                Double::MAX
+            }
+        } else {
+            r
+        }
+    }
+
+    /// Adds this `Double` to another, returning `None` if the sum overflows.
+    ///
+    /// This is the same as the `+` operator, except that a sum that is infinite or `NaN` is
+    /// turned into `None` when both `Double`s were finite. That makes it possible to catch
+    /// an overflow where it happens, instead of finding an infinity or a `NaN` in the
+    /// result of a long calculation. Arguments that are already infinite or `NaN` still
+    /// produce `Some`, with the same value that the `+` operator would produce.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(2).checked_add(dd!(3)) == Some(dd!(5)));
+    /// assert!(Double::MAX.checked_add(Double::MAX) == None);
+    /// assert!(Double::INFINITY.checked_add(dd!(1)) == Some(Double::INFINITY));
+    /// ```
+    pub fn checked_add(self, other: Double) -> Option<Double> {
+        let r = self + other;
+        if r.is_finite() || !(self.is_finite() && other.is_finite()) {
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    /// Adds this `Double` to another without checking for special cases first.
+    ///
+    /// The `+` operator checks both `Double`s for infinities and `NaN` before it adds them,
+    /// so that it can give the right results for them. This function skips those checks,
+    /// which makes it a little faster in inner loops where the arguments are known to be
+    /// finite. It isn't unsafe, but if either `Double` is infinite or `NaN`, the result is
+    /// meaningless. For finite `Double`s, it's exactly the same as `+`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// assert!(Double::PI.add_unchecked(Double::E) == Double::PI + Double::E);
+    /// ```
+    pub fn add_unchecked(self, other: Double) -> Double {
+        let (s0, e0) = p::two_sum(self.0, other.0);
+        let (s1, e1) = p::two_sum(self.1, other.1);
+        let (s2, e2) = p::quick_two_sum(s0, s1 + e0);
+        let (a, b) = u::renorm2(s2, e1 + e2);
+        Double(a, b)
+    }
+
+    /// Adds this `Double` to another, rounding the sum in the direction given by `mode`.
+    ///
+    /// The sum is first calculated as it is by the `+` operator. It's then compared
+    /// exactly with the sum of the components of both `Double`s, and if it's on the wrong
+    /// side of that for `mode`, it's moved a step at a time until it isn't. Each step adds
+    /// about the size returned by [`eps_at`] to its last component, which always changes
+    /// its value, and the steps start doubling if it takes more than a few. The result with
+    /// [`RoundMode::Up`] is never less than the exact sum and the result with
+    /// [`RoundMode::Down`] is never greater, so the two always bracket it.
+    /// [`RoundMode::Nearest`] gives the same result as `+`.
+    ///
+    /// If either `Double` is infinite or `NaN`, the result is the same as it is with `+`.
+    /// When a sum of finite `Double`s overflows, the result is an infinity if `mode`
+    /// rounds it away from zero and [`MAX`] or [`MIN`] if `mode` rounds it toward zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, RoundMode};
+    /// let x = dd!(1) / dd!(3);
+    /// let y = dd!(1e-40);
+    /// assert!(x.add_round(y, RoundMode::Up) > x.add_round(y, RoundMode::Down));
+    ///
+    /// // Exact sums aren't moved
+    /// assert!(dd!(1.5).add_round(dd!(2.25), RoundMode::Down) == dd!(3.75));
+    /// ```
+    ///
+    /// [`eps_at`]: #method.eps_at
+    /// [`MAX`]: #associatedconstant.MAX
+    /// [`MIN`]: #associatedconstant.MIN
+    /// [`RoundMode::Up`]: enum.RoundMode.html#variant.Up
+    /// [`RoundMode::Down`]: enum.RoundMode.html#variant.Down
+    /// [`RoundMode::Nearest`]: enum.RoundMode.html#variant.Nearest
+    pub fn add_round(self, other: Double, mode: RoundMode) -> Double {
+        let r = self + other;
+        if !(self.is_finite() && other.is_finite()) {
+            return r;
+        }
+        c::round_directed(r, mode, |x| {
+            b::sum_sign(&[x.0, x.1, -self.0, -self.1, -other.0, -other.1], &[])
+        })
+    }
+}
+
 impl Double {
     // Precalc functions
     //
@@ -201,6 +499,7 @@ impl Double {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::f64;
 
     // add tests
     test_all_near!(
@@ -292,6 +591,208 @@ mod tests {
             Double::NAN + Double::NAN;
     );
 
+    // f64 tests
+    test_all_exact!(
+        f64_int:
+            dd!(5),
+            dd!(3) + 2.0;
+        f64_rev_int:
+            dd!(5),
+            2.0 + dd!(3);
+        f64_id:
+            Double::PI,
+            Double::PI + 0.0;
+        f64_inf:
+            Double::INFINITY,
+            Double::ONE + f64::INFINITY;
+        f64_rev_inf:
+            Double::NEG_INFINITY,
+            f64::NEG_INFINITY + Double::ONE;
+        f64_inf_neg_inf:
+            Double::NAN,
+            Double::INFINITY + f64::NEG_INFINITY;
+        f64_nan:
+            Double::NAN,
+            Double::ONE + f64::NAN;
+        f64_rev_nan:
+            Double::NAN,
+            f64::NAN + Double::ONE;
+    );
+    test_all_near!(
+        f64_num:
+            Double::PI + Double(1.1, 0.0),
+            Double::PI + 1.1;
+        f64_ref:
+            Double::PI + Double(1.1, 0.0),
+            &Double::PI + 1.1;
+        f64_rev_num:
+            Double::PI + Double(1.1, 0.0),
+            1.1 + Double::PI;
+        f64_rev_ref:
+            Double::PI + Double(1.1, 0.0),
+            1.1 + &Double::PI;
+        f64_cancel:
+            Double::E - Double(f64::consts::E, 0.0),
+            Double::E + -f64::consts::E;
+    );
+
+    // saturating_add tests
+    test_all_exact!(
+        saturating_add_max:
+            Double::MAX,
+            Double::MAX.saturating_add(Double::MAX);
+        saturating_add_min:
+            Double::MIN,
+            Double::MIN.saturating_add(Double::MIN);
+        saturating_add_max_small:
+            Double::MAX,
+            Double::MAX.saturating_add(dd!(1e300));
+        saturating_add_max_min:
+            Double::ZERO,
+            Double::MAX.saturating_add(Double::MIN);
+        saturating_add_finite:
+            Double::PI + Double::E,
+            Double::PI.saturating_add(Double::E);
+        saturating_add_inf:
+            Double::INFINITY,
+            Double::INFINITY.saturating_add(Double::ONE);
+        saturating_add_neg_inf:
+            Double::NEG_INFINITY,
+            Double::ONE.saturating_add(Double::NEG_INFINITY);
+        saturating_add_nan:
+            Double::NAN,
+            Double::NAN.saturating_add(Double::MAX);
+        saturating_add_inf_neg_inf:
+            Double::NAN,
+            Double::INFINITY.saturating_add(Double::NEG_INFINITY);
+    );
+
+    // checked_add tests
+    test_all_eq!(
+        checked_add_finite:
+            Some(dd!(5)),
+            dd!(2).checked_add(dd!(3));
+        checked_add_overflow:
+            None,
+            Double::MAX.checked_add(Double::MAX);
+        checked_add_neg_overflow:
+            None,
+            Double::MIN.checked_add(Double::MIN);
+        checked_add_inf:
+            Some(Double::INFINITY),
+            Double::INFINITY.checked_add(dd!(1));
+    );
+    test_all_assert!(
+        checked_add_nan:
+            Double::NAN.checked_add(dd!(1)).unwrap().is_nan();
+        checked_add_inf_inf:
+            Double::INFINITY.checked_add(Double::NEG_INFINITY).unwrap().is_nan();
+    );
+
+    // add_unchecked tests
+    test_all_exact!(
+        add_unchecked_num:
+            Double::PI + Double::E,
+            Double::PI.add_unchecked(Double::E);
+        add_unchecked_neg:
+            Double::PI + -Double::E,
+            Double::PI.add_unchecked(-Double::E);
+        add_unchecked_small:
+            Double::E + dd!(3.7e-200),
+            Double::E.add_unchecked(dd!(3.7e-200));
+        add_unchecked_int:
+            dd!(6) + dd!(3),
+            dd!(6).add_unchecked(dd!(3));
+    );
+
+    // add_round tests
+    test!(add_round_inexact: {
+        let x = dd!(1) / dd!(3);
+        let y = dd!(1) / dd!(7e20);
+        let up = x.add_round(y, RoundMode::Up);
+        let down = x.add_round(y, RoundMode::Down);
+        let exact = c::to_quad(x) + c::to_quad(y);
+        assert!(up > down);
+        assert!(c::to_quad(down) < exact && exact < c::to_quad(up));
+        assert!(up - down <= (x + y).eps_at());
+    });
+    test!(add_round_bracket: {
+        let values = [Double::PI, Double::E, -Double::LN_2, dd!(7), dd!(-1e10), dd!(3e-20)];
+        for &a in &values {
+            for &b in &values {
+                let exact = c::to_quad(a) + c::to_quad(b);
+                let up = c::to_quad(a.add_round(b, RoundMode::Up));
+                let down = c::to_quad(a.add_round(b, RoundMode::Down));
+                let zero = c::to_quad(a.add_round(b, RoundMode::TowardZero));
+                assert!(down <= exact && exact <= up, "{} + {} is not bracketed", a, b);
+                assert!(zero.abs() <= exact.abs(), "{} + {} is not toward zero", a, b);
+            }
+        }
+    });
+    test_all_exact!(
+        add_round_nearest:
+            Double::PI + Double::E,
+            Double::PI.add_round(Double::E, RoundMode::Nearest);
+        add_round_exact_up:
+            dd!(3.75),
+            dd!(1.5).add_round(dd!(2.25), RoundMode::Up);
+        add_round_exact_down:
+            dd!(3.75),
+            dd!(1.5).add_round(dd!(2.25), RoundMode::Down);
+        add_round_toward_zero_pos:
+            Double::PI.add_round(Double::E, RoundMode::Down),
+            Double::PI.add_round(Double::E, RoundMode::TowardZero);
+        add_round_toward_zero_neg:
+            (-Double::PI).add_round(-Double::E, RoundMode::Up),
+            (-Double::PI).add_round(-Double::E, RoundMode::TowardZero);
+        add_round_inf:
+            Double::INFINITY,
+            Double::INFINITY.add_round(dd!(1), RoundMode::Down);
+    );
+    test_all_assert!(
+        add_round_nan:
+            Double::NAN.add_round(dd!(1), RoundMode::Up).is_nan();
+    );
+    test_all_exact!(
+        add_round_overflow_down:
+            Double::MAX,
+            Double::MAX.add_round(Double::MAX, RoundMode::Down);
+        add_round_overflow_up:
+            Double::INFINITY,
+            Double::MAX.add_round(Double::MAX, RoundMode::Up);
+        add_round_overflow_toward_zero:
+            Double::MIN,
+            Double::MIN.add_round(Double::MIN, RoundMode::TowardZero);
+        add_round_neg_overflow_down:
+            Double::NEG_INFINITY,
+            Double::MIN.add_round(Double::MIN, RoundMode::Down);
+    );
+
+    // integer tests
+    test_all_exact!(
+        int_i32:
+            dd!(5),
+            dd!(3) + 2;
+        int_rev_i32:
+            dd!(5),
+            2 + dd!(3);
+        int_u32:
+            dd!(5),
+            dd!(3) + 2u32;
+        int_rev_u32:
+            dd!(5),
+            2u32 + dd!(3);
+        int_neg:
+            dd!(1),
+            dd!(3) + -2;
+        int_max:
+            dd!(4294967298.0),
+            dd!(3) + u32::MAX;
+        int_ref:
+            Double::PI + Double::from(2.0),
+            &Double::PI + 2;
+    );
+
     // Assign tests. Assign code delegates to add code, so there's no need to re-test all
     // of the cases above.
     test_all!(
@@ -305,5 +806,37 @@ mod tests {
             b += &Double::E;
             near!(dd!("5.8598744820488384738229308546322"), b);
         }
+        assign_f64: {
+            let mut c = Double::PI;
+            c += 1.1;
+            near!(Double::PI + Double(1.1, 0.0), c);
+        }
+        assign_int: {
+            let mut e = Double::PI;
+            e += 2;
+            e += 3u32;
+            exact!(Double::PI + 2.0 + 3.0, e);
+        }
+        assign_f64_accumulate: {
+            // The `f64` closest to 0.1 is a little larger than 0.1, and ten of them add up to
+            // exactly 1 + 2⁻⁵⁴. An `f64` accumulator loses that along the way and comes up
+            // short of 1, but the low components of the `Double` keep the sum exact.
+            let mut total = Double::ZERO;
+            let mut naive = 0.0f64;
+            for _ in 0..10 {
+                total += 0.1;
+                naive += 0.1;
+            }
+            let expected = Double(1.0, 2f64.powi(-54));
+            exact!(expected, total);
+            assert!((total - expected).abs() < (Double::from((naive, 0.0)) - expected).abs());
+        }
+        assign_f64_accumulate_long: {
+            let mut total = Double::ZERO;
+            for _ in 0..1000 {
+                total += 0.1;
+            }
+            exact!(Double(100.0, 1000.0 * 2f64.powi(-54) / 10.0), total);
+        }
     );
 }
@@ -84,6 +84,32 @@ impl Double {
         }
     }
 
+    /// Calculates the square root of the `Double`, returning `None` if it's negative.
+    ///
+    /// This is the same as [`sqrt`], except that the `NaN` that the square root of a
+    /// negative number produces is turned into `None`. Arguments that are already infinite
+    /// or `NaN` still produce `Some`, with the same value that [`sqrt`] would produce, so
+    /// `None` always means that a finite `Double` was negative. Negative zero isn't
+    /// negative for this purpose; its square root is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(4).checked_sqrt() == Some(dd!(2)));
+    /// assert!(dd!(-4).checked_sqrt() == None);
+    /// assert!(Double::INFINITY.checked_sqrt() == Some(Double::INFINITY));
+    /// ```
+    ///
+    /// [`sqrt`]: #method.sqrt
+    pub fn checked_sqrt(self) -> Option<Double> {
+        let r = self.sqrt();
+        if r.is_nan() && self.is_finite() {
+            None
+        } else {
+            Some(r)
+        }
+    }
+
     /// Calculates the *n*th root of the `Double`.
     ///
     /// # Examples
@@ -152,36 +178,96 @@ impl Double {
     /// * `x.powi(n)` is ±0 for `x` = ±0 and any odd positive `n`
     /// * `x.powi(n)` is +0 for `x` = ±0 and any even positive `n`
     ///
+    /// A result too large in magnitude to be represented is an infinity, and one too
+    /// small is a zero, either one with the sign that the true result would have.
+    ///
     /// # Examples
     /// ```
-    /// # use qd::dd;
+    /// # use qd::{dd, Double};
     /// let x = dd!(3);
     /// assert!(x.powi(3) == dd!(27));
+    /// assert!(dd!(-2).powi(2001) == Double::NEG_INFINITY);
+    /// assert!(dd!(2).powi(i32::MIN) == Double::ZERO);
     /// ```
+    #[inline]
     pub fn powi(self, n: i32) -> Double {
-        match self.pre_powi(n) {
+        self.powi64(n as i64)
+    }
+
+    /// Calculates x<sup>n</sup>, the `Double` raised to the integral power `n`, returning
+    /// `None` if the calculation overflows.
+    ///
+    /// This is the same as [`powi`], except that a result that is infinite or `NaN` is
+    /// turned into `None` when `self` was finite. This makes it possible to distinguish an
+    /// overflow from an infinite or `NaN` input, which still produces `Some` with the same
+    /// value that [`powi`] would produce. Note that raising zero to a negative power also
+    /// produces `None`, as there is no finite answer.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(2).checked_powi(10) == Some(dd!(1024)));
+    /// assert!(dd!(10).checked_powi(400) == None);
+    /// assert!(Double::INFINITY.checked_powi(1) == Some(Double::INFINITY));
+    /// ```
+    ///
+    /// [`powi`]: #method.powi
+    pub fn checked_powi(self, n: i32) -> Option<Double> {
+        let r = self.powi(n);
+        if r.is_finite() || !self.is_finite() {
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    /// Calculates the `Double` raised to a 64-bit integer power.
+    ///
+    /// This is the same as [`powi`], using the same repeated squaring, but it accepts
+    /// exponents that are too large to fit into an `i32`. Those will overflow or underflow
+    /// for any argument whose magnitude is not exactly 1, producing the infinity (for a
+    /// positive exponent) or zero (for a negative exponent) that the result approaches,
+    /// with the sign that the true result would have. The same special inputs are handled
+    /// in the same way as they are in [`powi`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(3).powi64(3) == dd!(27));
+    /// assert!(dd!(-2).powi64(5_000_000_001) == Double::NEG_INFINITY);
+    /// assert!(dd!(2).powi64(-5_000_000_000) == Double::ZERO);
+    /// assert!(dd!(-1).powi64(i64::MAX) == dd!(-1));
+    /// ```
+    ///
+    /// [`powi`]: #method.powi
+    pub fn powi64(self, n: i64) -> Double {
+        match self.pre_powi64(n) {
             Some(r) => r,
             None => {
-                let mut r = self;
+                let mut r = self.abs();
                 let mut s = Double::ONE;
-                let mut i = n.abs();
-
-                if i > 1 {
-                    while i > 0 {
-                        if i % 2 == 1 {
-                            s *= r;
-                        }
-                        i /= 2;
-                        if i > 0 {
-                            r = r.sqr();
-                        }
+                let mut i = n.unsigned_abs();
+
+                while i > 0 {
+                    if i % 2 == 1 {
+                        s *= r;
+                    }
+                    i /= 2;
+                    if i > 0 {
+                        r = r.sqr();
+                    }
+                    // Overflowing multiplication produces NaN rather than infinity. Since
+                    // `r` is only squared when it will be multiplied into `s` again, an
+                    // overflow in either means that the final result would overflow too.
+                    if !s.is_finite() || !r.is_finite() {
+                        s = Double::INFINITY;
+                        break;
                     }
-                } else {
-                    s = r;
                 }
 
-                if n < 0 {
-                    s.recip()
+                let s = if n < 0 { s.recip() } else { s };
+                if self.is_sign_negative() && n % 2 != 0 {
+                    -s
                 } else {
                     s
                 }
@@ -238,6 +324,31 @@ impl Double {
         Double::ONE / self
     }
 
+    /// Calculates the reciprocal of the `Double`, returning `None` if it overflows.
+    ///
+    /// This is the same as [`recip`], except that a reciprocal that is infinite is turned
+    /// into `None` when the `Double` was finite. That happens when the `Double` is zero, or
+    /// so close to zero that its reciprocal overflows. An argument that is already infinite
+    /// or `NaN` still produces `Some`, with the same value that [`recip`] would produce.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(4).checked_recip() == Some(dd!(0.25)));
+    /// assert!(Double::ZERO.checked_recip() == None);
+    /// assert!(Double::INFINITY.checked_recip() == Some(Double::ZERO));
+    /// ```
+    ///
+    /// [`recip`]: #method.recip
+    pub fn checked_recip(self) -> Option<Double> {
+        let r = self.recip();
+        if r.is_finite() || !self.is_finite() {
+            Some(r)
+        } else {
+            None
+        }
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -311,35 +422,12 @@ impl Double {
     }
 
     #[inline]
-    fn pre_powi(&self, n: i32) -> Option<Double> {
+    fn pre_powi64(&self, n: i64) -> Option<Double> {
+        // Zeros and infinities fall out of the calculation naturally, with the right signs
         if n == 0 {
             Some(Double::ONE)
         } else if self.is_nan() {
             Some(Double::NAN)
-        } else if self.is_zero() {
-            if n % 2 == 0 || self.is_sign_positive() {
-                if n > 0 {
-                    Some(Double::ZERO)
-                } else {
-                    Some(Double::INFINITY)
-                }
-            } else if n > 0 {
-                Some(Double::NEG_ZERO)
-            } else {
-                Some(Double::NEG_INFINITY)
-            }
-        } else if self.is_infinite() {
-            if n % 2 == 0 || self.is_sign_positive() {
-                if n > 0 {
-                    Some(Double::INFINITY)
-                } else {
-                    Some(Double::ZERO)
-                }
-            } else if n > 0 {
-                Some(Double::NEG_INFINITY)
-            } else {
-                Some(Double::NEG_ZERO)
-            }
         } else {
             None
         }
@@ -542,6 +630,34 @@ mod tests {
             Double::NAN.sqrt();
     );
 
+    // checked_sqrt tests
+    test_all_eq!(
+        checked_sqrt_finite:
+            Some(dd!(2)),
+            dd!(4).checked_sqrt();
+        checked_sqrt_zero:
+            Some(Double::ZERO),
+            Double::ZERO.checked_sqrt();
+        checked_sqrt_neg_zero:
+            Some(Double::ZERO),
+            Double::NEG_ZERO.checked_sqrt();
+        checked_sqrt_neg:
+            None,
+            dd!(-4).checked_sqrt();
+        checked_sqrt_neg_tiny:
+            None,
+            dd!(-1e-300).checked_sqrt();
+        checked_sqrt_inf:
+            Some(Double::INFINITY),
+            Double::INFINITY.checked_sqrt();
+    );
+    test_all_assert!(
+        checked_sqrt_neg_inf:
+            Double::NEG_INFINITY.checked_sqrt().unwrap().is_nan();
+        checked_sqrt_nan:
+            Double::NAN.checked_sqrt().unwrap().is_nan();
+    );
+
     // nroot tests
     test_all_near!(
         nroot_pi_one:
@@ -818,6 +934,222 @@ mod tests {
             Double::NAN,
             Double::NAN.powi(2);
     );
+    test_all_exact!(
+        powi_neg_two_cubed:
+            dd!(-8),
+            dd!(-2).powi(3);
+        powi_neg_two_squared:
+            dd!(4),
+            dd!(-2).powi(2);
+        powi_neg_two_neg_cubed:
+            dd!(-0.125),
+            dd!(-2).powi(-3);
+        powi_neg_two_neg_squared:
+            dd!(0.25),
+            dd!(-2).powi(-2);
+        powi_neg_inf_neg_odd_exp:
+            Double::NEG_ZERO,
+            Double::NEG_INFINITY.powi(-3);
+        powi_neg_inf_neg_even_exp:
+            Double::ZERO,
+            Double::NEG_INFINITY.powi(-2);
+        powi_one_min:
+            Double::ONE,
+            Double::ONE.powi(i32::MIN);
+        powi_neg_one_min:
+            Double::ONE,
+            dd!(-1).powi(i32::MIN);
+        powi_neg_one_max:
+            dd!(-1),
+            dd!(-1).powi(i32::MAX);
+        powi_overflow:
+            Double::INFINITY,
+            dd!(2).powi(2000);
+        powi_overflow_max:
+            Double::INFINITY,
+            dd!(2).powi(i32::MAX);
+        powi_underflow:
+            Double::ZERO,
+            dd!(2).powi(-2000);
+        powi_underflow_min:
+            Double::ZERO,
+            dd!(2).powi(i32::MIN);
+        powi_neg_overflow_odd:
+            Double::NEG_INFINITY,
+            dd!(-2).powi(2001);
+        powi_neg_overflow_even:
+            Double::INFINITY,
+            dd!(-2).powi(2000);
+        powi_neg_overflow_max:
+            Double::NEG_INFINITY,
+            dd!(-2).powi(i32::MAX);
+        powi_neg_underflow_min:
+            Double::ZERO,
+            dd!(-2).powi(i32::MIN);
+        powi_neg_underflow_odd:
+            Double::NEG_ZERO,
+            dd!(-2).powi(-2001);
+        powi_neg_underflow_even:
+            Double::ZERO,
+            dd!(-2).powi(-2000);
+    );
+    test_all_assert!(
+        powi_neg_zero_odd_sign:
+            Double::NEG_ZERO.powi(5).is_sign_negative();
+        powi_neg_zero_even_sign:
+            Double::NEG_ZERO.powi(6).is_sign_positive();
+        powi_neg_underflow_odd_sign:
+            dd!(-2).powi(-2001).is_sign_negative();
+        powi_neg_underflow_even_sign:
+            dd!(-2).powi(-2000).is_sign_positive();
+        powi_neg_underflow_min_sign:
+            dd!(-2).powi(i32::MIN).is_sign_positive();
+    );
+    test!(powi_sign_matrix: {
+        // The sign of the result depends only on the sign of the base and the parity of the
+        // exponent, and its magnitude should be exactly the same as for the positive base
+        let bases = [dd!(2), dd!(0.5), dd!(3), Double::PI, dd!(1e-3), Double::ONE];
+        let exps = [1, 2, 3, 4, 5, 10, 11, 63, 64, -1, -2, -3, -4, -5, -10, -11, -63, -64];
+        for &x in &bases {
+            for &n in &exps {
+                let pos = x.powi(n);
+                let neg = (-x).powi(n);
+                assert!(pos > Double::ZERO, "{}^{} is not positive", x, n);
+                if n % 2 == 0 {
+                    assert!(neg == pos, "(-{})^{} is not {}", x, n, pos);
+                } else {
+                    assert!(neg == -pos, "(-{})^{} is not {}", x, n, -pos);
+                }
+            }
+        }
+    });
+
+    // checked_powi tests
+    test_all_eq!(
+        checked_powi_small:
+            Some(dd!(1024)),
+            dd!(2).checked_powi(10);
+        checked_powi_neg:
+            Some(dd!(-27)),
+            dd!(-3).checked_powi(3);
+        checked_powi_recip:
+            Some(dd!(0.125)),
+            dd!(2).checked_powi(-3);
+        checked_powi_zero_exp:
+            Some(Double::ONE),
+            Double::MAX.checked_powi(0);
+        checked_powi_overflow:
+            None,
+            dd!(10).checked_powi(400);
+        checked_powi_neg_overflow:
+            None,
+            dd!(-10).checked_powi(401);
+        checked_powi_max:
+            None,
+            Double::MAX.checked_powi(2);
+        checked_powi_zero_recip:
+            None,
+            Double::ZERO.checked_powi(-1);
+        checked_powi_inf:
+            Some(Double::INFINITY),
+            Double::INFINITY.checked_powi(1);
+        checked_powi_neg_inf:
+            Some(Double::NEG_INFINITY),
+            Double::NEG_INFINITY.checked_powi(3);
+    );
+    test_all_assert!(
+        checked_powi_nan:
+            Double::NAN.checked_powi(2).unwrap().is_nan();
+    );
+
+    // powi64 tests
+    test_all_exact!(
+        powi64_small:
+            dd!(27),
+            dd!(3).powi64(3);
+        powi64_neg:
+            dd!(-27),
+            dd!(-3).powi64(3);
+        powi64_recip:
+            dd!(0.125),
+            dd!(2).powi64(-3);
+        powi64_zero_exp:
+            Double::ONE,
+            Double::NAN.powi64(0);
+        powi64_overflow:
+            Double::INFINITY,
+            dd!(1.5).powi64(10_000_000_000);
+        powi64_neg_overflow:
+            Double::NEG_INFINITY,
+            dd!(-1.5).powi64(10_000_000_001);
+        powi64_neg_overflow_even:
+            Double::INFINITY,
+            dd!(-1.5).powi64(10_000_000_000);
+        powi64_i32_overflow:
+            Double::INFINITY,
+            dd!(10).powi64(400);
+        powi64_underflow:
+            Double::ZERO,
+            dd!(0.5).powi64(10_000_000_000);
+        powi64_neg_underflow:
+            Double::NEG_ZERO,
+            dd!(-0.5).powi64(10_000_000_001);
+        powi64_recip_underflow:
+            Double::ZERO,
+            dd!(1.5).powi64(-10_000_000_000);
+        powi64_recip_overflow:
+            Double::INFINITY,
+            dd!(0.5).powi64(-10_000_000_000);
+        powi64_one:
+            Double::ONE,
+            Double::ONE.powi64(i64::MAX);
+        powi64_neg_one_odd:
+            Double::NEG_ONE,
+            Double::NEG_ONE.powi64(i64::MAX);
+        powi64_neg_one_even:
+            Double::ONE,
+            Double::NEG_ONE.powi64(i64::MIN);
+        powi64_zero:
+            Double::ZERO,
+            Double::ZERO.powi64(3);
+        powi64_neg_zero:
+            Double::NEG_ZERO,
+            Double::NEG_ZERO.powi64(3);
+        powi64_neg_zero_even:
+            Double::ZERO,
+            Double::NEG_ZERO.powi64(4);
+        powi64_zero_recip:
+            Double::INFINITY,
+            Double::ZERO.powi64(-2);
+        powi64_neg_zero_recip:
+            Double::NEG_INFINITY,
+            Double::NEG_ZERO.powi64(-3);
+        powi64_inf:
+            Double::INFINITY,
+            Double::INFINITY.powi64(3);
+        powi64_neg_inf:
+            Double::NEG_INFINITY,
+            Double::NEG_INFINITY.powi64(3);
+        powi64_neg_inf_even:
+            Double::INFINITY,
+            Double::NEG_INFINITY.powi64(4);
+        powi64_inf_recip:
+            Double::ZERO,
+            Double::INFINITY.powi64(-3);
+        powi64_neg_inf_recip:
+            Double::NEG_ZERO,
+            Double::NEG_INFINITY.powi64(-3);
+        powi64_nan:
+            Double::NAN,
+            Double::NAN.powi64(3);
+    );
+    test!(powi64_matches_powi: {
+        for x in [Double::PI, -Double::E, dd!(0.1), dd!(-7), dd!(1.0001)] {
+            for n in -40..=40 {
+                exact!(x.powi(n), x.powi64(n as i64));
+            }
+        }
+    });
 
     // powf_tests
     test_all_near!(
@@ -979,4 +1311,30 @@ mod tests {
             Double::NAN,
             Double::NAN.recip();
     );
+
+    // checked_recip tests
+    test_all_eq!(
+        checked_recip_finite:
+            Some(dd!(0.25)),
+            dd!(4).checked_recip();
+        checked_recip_zero:
+            None,
+            Double::ZERO.checked_recip();
+        checked_recip_neg_zero:
+            None,
+            Double::NEG_ZERO.checked_recip();
+        checked_recip_overflow:
+            None,
+            Double::from(f64::from_bits(1)).checked_recip();
+        checked_recip_max:
+            Some(Double::MAX.recip()),
+            Double::MAX.checked_recip();
+        checked_recip_inf:
+            Some(Double::ZERO),
+            Double::INFINITY.checked_recip();
+    );
+    test_all_assert!(
+        checked_recip_nan:
+            Double::NAN.checked_recip().unwrap().is_nan();
+    );
 }
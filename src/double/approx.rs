@@ -0,0 +1,145 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::double::Double;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+impl AbsDiffEq for Double {
+    type Epsilon = Double;
+
+    /// Returns [`EPSILON`], the default tolerance for the `approx` macros.
+    ///
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    #[inline]
+    fn default_epsilon() -> Double {
+        Double::EPSILON
+    }
+
+    /// Determines whether two `Double`s are within `epsilon` of each other.
+    ///
+    /// As with the `f64` implementation, infinities are not within any tolerance of
+    /// anything (even themselves), and neither is `NaN`.
+    #[inline]
+    fn abs_diff_eq(&self, other: &Double, epsilon: Double) -> bool {
+        (*self - *other).abs() <= epsilon
+    }
+}
+
+impl RelativeEq for Double {
+    /// Returns [`EPSILON`], the default relative tolerance for the `approx` macros.
+    ///
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    #[inline]
+    fn default_max_relative() -> Double {
+        Double::EPSILON
+    }
+
+    /// Determines whether two `Double`s are equal to within `epsilon` in absolute terms or to
+    /// within `max_relative` times the larger of their magnitudes.
+    ///
+    /// Equal numbers are always equal, including two infinities of the same sign. Other
+    /// infinities and `NaN` are not equal to anything.
+    fn relative_eq(&self, other: &Double, epsilon: Double, max_relative: Double) -> bool {
+        if self == other {
+            true
+        } else if self.is_infinite() || other.is_infinite() {
+            false
+        } else {
+            let diff = (*self - *other).abs();
+            let largest = if self.abs() > other.abs() {
+                self.abs()
+            } else {
+                other.abs()
+            };
+            diff <= epsilon || diff <= largest * max_relative
+        }
+    }
+}
+
+impl UlpsEq for Double {
+    /// Returns 4, the default number of units in the last place for the `approx` macros.
+    /// This is the same default as for `f64`.
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        4
+    }
+
+    /// Determines whether two `Double`s are equal to within `epsilon` in absolute terms
+    /// or, failing that, within `max_ulps` units in the last place of each other. The
+    /// units are counted by [`approx_eq_ulps`].
+    ///
+    /// [`approx_eq_ulps`]: #method.approx_eq_ulps
+    fn ulps_eq(&self, other: &Double, epsilon: Double, max_ulps: u32) -> bool {
+        AbsDiffEq::abs_diff_eq(self, other, epsilon) || self.approx_eq_ulps(*other, max_ulps as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::{
+        abs_diff_eq, abs_diff_ne, assert_relative_eq, assert_ulps_eq, relative_eq, relative_ne,
+        ulps_eq, ulps_ne,
+    };
+
+    // abs_diff_eq tests
+    test_all_assert!(
+        abs_diff_eq_equal:
+            abs_diff_eq!(Double::PI, Double::PI);
+        abs_diff_eq_close:
+            abs_diff_eq!(Double::ONE, Double::ONE + Double::EPSILON / dd!(2));
+        abs_diff_eq_far:
+            abs_diff_ne!(Double::ONE, Double::ONE + Double::EPSILON * dd!(2));
+        abs_diff_eq_epsilon:
+            abs_diff_eq!(Double::PI, dd!(3), epsilon = dd!(0.2));
+        abs_diff_eq_inf:
+            abs_diff_ne!(Double::INFINITY, Double::INFINITY);
+        abs_diff_eq_nan:
+            abs_diff_ne!(Double::NAN, Double::NAN);
+    );
+
+    // relative_eq tests
+    test_all_assert!(
+        relative_eq_equal:
+            relative_eq!(Double::E, Double::E);
+        relative_eq_large:
+            relative_eq!(dd!(1e100) * Double::PI, dd!(1e100) * (Double::PI + Double::EPSILON));
+        relative_eq_large_far:
+            relative_ne!(dd!(1e100) * Double::PI, dd!(1e100) * (Double::PI + dd!(1e-20)));
+        relative_eq_max_relative:
+            relative_eq!(dd!(1e100), dd!(1.001e100), max_relative = dd!(0.01));
+        relative_eq_inf:
+            relative_eq!(Double::INFINITY, Double::INFINITY);
+        relative_eq_inf_max:
+            relative_ne!(Double::INFINITY, Double::MAX, max_relative = dd!(1));
+        relative_eq_nan:
+            relative_ne!(Double::NAN, Double::NAN);
+    );
+
+    // ulps_eq tests
+    test_all_assert!(
+        ulps_eq_equal:
+            ulps_eq!(Double::LN_2, Double::LN_2);
+        ulps_eq_close:
+            ulps_eq!(dd!(1e100), dd!(1e100) + dd!(1e100).eps_at() * dd!(3));
+        ulps_eq_far:
+            ulps_ne!(dd!(1e100), dd!(1e100) + dd!(1e100).eps_at() * dd!(8));
+        ulps_eq_max_ulps:
+            ulps_eq!(dd!(1e100), dd!(1e100) + dd!(1e100).eps_at() * dd!(8), max_ulps = 8);
+        ulps_eq_signs:
+            ulps_ne!(dd!(1e-300), dd!(-1e-300), epsilon = Double::ZERO, max_ulps = u32::MAX);
+        ulps_eq_inf:
+            ulps_eq!(Double::INFINITY, Double::INFINITY);
+        ulps_eq_nan:
+            ulps_ne!(Double::NAN, Double::NAN);
+    );
+
+    // macro tests
+    test!(assert_macros: {
+        assert_relative_eq!(Double::PI.sin(), Double::ZERO, epsilon = dd!(1e-30));
+        assert_relative_eq!(dd!(2).sqrt().powi(2), dd!(2), max_relative = Double::EPSILON * dd!(4));
+        assert_ulps_eq!(Double::SQRT_2 * Double::SQRT_2, dd!(2), max_ulps = 8);
+    });
+}
@@ -0,0 +1,84 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::double::{ArchivedDouble, Double};
+
+impl From<&ArchivedDouble> for Double {
+    /// Converts an archived `Double`, as it's laid out in an `rkyv` archive, back into a
+    /// `Double`.
+    ///
+    /// This is a cheap conversion that copies the components directly, so it's a
+    /// convenient way to get at individual values while accessing an archive without
+    /// deserializing all of it. The result is bit-for-bit the `Double` that was archived.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use qd::ArchivedDouble;
+    /// use rkyv::rancor::Error;
+    /// use rkyv::vec::ArchivedVec;
+    ///
+    /// let values = vec![Double::PI, Double::E, dd!(1) / dd!(3)];
+    /// let bytes = rkyv::to_bytes::<Error>(&values).unwrap();
+    ///
+    /// let archived = rkyv::access::<ArchivedVec<ArchivedDouble>, Error>(&bytes).unwrap();
+    /// assert!(Double::from(&archived[1]) == Double::E);
+    /// ```
+    #[inline]
+    fn from(a: &ArchivedDouble) -> Double {
+        Double(a.0.to_native(), a.1.to_native())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rkyv::rancor::Error;
+    use rkyv::vec::ArchivedVec;
+
+    fn values() -> Vec<Double> {
+        vec![
+            Double::PI,
+            -Double::E,
+            Double::NEG_ZERO,
+            Double::MAX,
+            Double::MIN_POSITIVE,
+            Double::NEG_INFINITY,
+            dd!(1) / dd!(3),
+        ]
+    }
+
+    fn same(a: Double, b: Double) -> bool {
+        (0..2).all(|i| a[i].to_bits() == b[i].to_bits())
+    }
+
+    // archive tests
+    test!(archive_access: {
+        let values = values();
+        let bytes = rkyv::to_bytes::<Error>(&values).unwrap();
+        let archived = rkyv::access::<ArchivedVec<ArchivedDouble>, Error>(&bytes).unwrap();
+        assert!(archived.len() == values.len());
+        for (a, v) in archived.iter().zip(values.iter()) {
+            assert!(same(Double::from(a), *v), "{:?}", v);
+        }
+    });
+    test!(archive_deserialize: {
+        let values = values();
+        let bytes = rkyv::to_bytes::<Error>(&values).unwrap();
+        let back = rkyv::from_bytes::<Vec<Double>, Error>(&bytes).unwrap();
+        assert!(back.len() == values.len());
+        for (b, v) in back.iter().zip(values.iter()) {
+            assert!(same(*b, *v), "{:?}", v);
+        }
+    });
+    test!(archive_nan: {
+        let bytes = rkyv::to_bytes::<Error>(&Double::NAN).unwrap();
+        let archived = rkyv::access::<ArchivedDouble, Error>(&bytes).unwrap();
+        assert!(Double::from(archived).is_nan());
+    });
+    test!(archive_bad_bytes: {
+        assert!(rkyv::access::<ArchivedVec<ArchivedDouble>, Error>(&[0xff; 3]).is_err());
+    });
+}
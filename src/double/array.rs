@@ -0,0 +1,81 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Support for `Double`s as `ndarray` elements. Elementwise arithmetic between arrays only
+// needs the operator traits, and `LinalgScalar` (which `dot` requires) is implemented by
+// `ndarray` for everything that has those and `num-traits`' `Zero` and `One`. All that's left
+// is `ScalarOperand`, which allows arithmetic between an array and a single `Double`.
+
+use crate::double::Double;
+use ndarray::ScalarOperand;
+
+impl ScalarOperand for Double {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{array, Array1, Array2, LinalgScalar};
+
+    fn linalg<T: LinalgScalar>() {}
+
+    #[rustfmt::skip]
+    fn matrix() -> Array2<Double> {
+        array![
+            [dd!(4), dd!(-2), dd!(1)],
+            [dd!(3), dd!(6), dd!(-4)],
+            [dd!(2), dd!(1), dd!(8)],
+        ]
+    }
+
+    // elementwise tests
+    test!(elementwise_arrays: {
+        let a = matrix();
+        let b = a.mapv(|x| x / dd!(3));
+        let sum = &a + &b;
+        let product = &a * &b;
+        for ((x, s), p) in a.iter().zip(sum.iter()).zip(product.iter()) {
+            exact!(*x + *x / dd!(3), *s);
+            exact!(*x * (*x / dd!(3)), *p);
+        }
+    });
+    test!(elementwise_scalar: {
+        let a = matrix();
+        let scaled = &a * Double::PI;
+        let shifted = a.clone() - dd!(1);
+        for ((x, s), t) in a.iter().zip(scaled.iter()).zip(shifted.iter()) {
+            exact!(*x * Double::PI, *s);
+            exact!(*x - dd!(1), *t);
+        }
+    });
+    test!(elementwise_sum: {
+        exact!(dd!(19), matrix().sum());
+        let thirds = Array1::from_elem(3, dd!(1) / dd!(3));
+        near!(dd!(1), thirds.sum());
+    });
+
+    // linalg tests
+    test!(linalg_scalar: {
+        linalg::<Double>();
+    });
+    test!(linalg_dot: {
+        let a = matrix();
+        let identity = Array2::<Double>::eye(3);
+        assert!(a.dot(&identity) == a);
+        let expected = array![
+            [dd!(12), dd!(-19), dd!(20)],
+            [dd!(22), dd!(26), dd!(-53)],
+            [dd!(27), dd!(10), dd!(62)],
+        ];
+        assert!(a.dot(&a) == expected);
+    });
+    test!(linalg_dot_precision: {
+        // The 1 is lost entirely when this is done in f64
+        let a = array![dd!(1e20), dd!(1), dd!(-1e20)];
+        let b = Array1::from_elem(3, dd!(1));
+        exact!(dd!(1), a.dot(&b));
+        let af = array![1e20, 1.0, -1e20];
+        assert!(af.dot(&Array1::from_elem(3, 1.0)) == 0.0);
+    });
+}
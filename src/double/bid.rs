@@ -0,0 +1,245 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::bid as b;
+use crate::double::Double;
+
+impl Double {
+    /// Converts the `Double` into the bytes of an IEEE 754 decimal64 number, in the binary
+    /// integer decimal (BID) encoding and in little-endian order.
+    ///
+    /// BID is the encoding used by Intel's decimal floating-point library, and through it
+    /// by many of the systems that store decimal64s. A decimal64 has 16 significant digits,
+    /// about half of what a `Double` has, so the `Double` is correctly rounded to the
+    /// nearest decimal64, with ties going to the one whose last digit is even. Every finite
+    /// `Double` is well within the range of a decimal64, so there's no overflow or
+    /// underflow.
+    ///
+    /// The coefficient of the result has no trailing zeros, so a `Double` that's an integer
+    /// small enough to have all of its digits in the coefficient has an exponent of at
+    /// least zero. Zero always has an exponent of zero, and it keeps its sign. `NaN`
+    /// becomes the decimal64 quiet `NaN`, and the infinities carry over.
+    ///
+    /// This is only available when the `num-bigint` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let bits = u64::from_le_bytes(dd!(1.5).to_decimal64_bytes());
+    /// assert!(bits == 0x31a0_0000_0000_000f); // 15 × 10⁻¹
+    ///
+    /// let bits = u64::from_le_bytes(Double::PI.to_decimal64_bytes());
+    /// assert!(bits == 0x2feb_2943_0a25_6d21); // 3141592653589793 × 10⁻¹⁵
+    /// ```
+    pub fn to_decimal64_bytes(self) -> [u8; 8] {
+        (b::to_bits(&[self.0, self.1], &b::DECIMAL64) as u64).to_le_bytes()
+    }
+
+    /// Generates a `Double` from the bytes of an IEEE 754 decimal64 number, in the binary
+    /// integer decimal (BID) encoding and in little-endian order.
+    ///
+    /// BID is the encoding used by Intel's decimal floating-point library, and through it
+    /// by many of the systems that store decimal64s. A decimal64 has 16 significant digits,
+    /// so every one of them within the normal range of an `f64` is held by a `Double`
+    /// closely enough that it converts back to the same decimal64. One that's too large
+    /// becomes [`INFINITY`] or [`NEG_INFINITY`], and one that's too small is rounded to the
+    /// nearest `f64` subnormal or to zero. Any decimal64 `NaN` becomes [`NAN`], and a
+    /// coefficient that's too large for a decimal64 (which the standard says to treat as
+    /// zero) becomes zero.
+    ///
+    /// This is only available when the `num-bigint` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let one = 0x31c0_0000_0000_0001u64; // 1 × 10⁰
+    /// assert!(Double::from_decimal64_bytes(one.to_le_bytes()) == dd!(1));
+    ///
+    /// let tenth = 0x31a0_0000_0000_0001u64; // 1 × 10⁻¹
+    /// let x = Double::from_decimal64_bytes(tenth.to_le_bytes());
+    /// assert!(x.to_decimal64_bytes() == tenth.to_le_bytes());
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn from_decimal64_bytes(bytes: [u8; 8]) -> Double {
+        let parts = b::from_bits(u64::from_le_bytes(bytes) as u128, 2, &b::DECIMAL64);
+        Double(parts[0], parts[1])
+    }
+
+    /// Converts the `Double` into the bytes of an IEEE 754 decimal128 number, in the binary
+    /// integer decimal (BID) encoding and in little-endian order.
+    ///
+    /// A decimal128 has 34 significant digits, a few more than a `Double` has, so most
+    /// `Double`s will make the round trip through a decimal128 unchanged. The conversion is
+    /// correctly rounded all the same, with ties going to the one whose last digit is even,
+    /// since a `Double` whose components are far apart in magnitude has more digits than
+    /// that. Every finite `Double` is well within the range of a decimal128, so there's no
+    /// overflow or underflow.
+    ///
+    /// The coefficient of the result has no trailing zeros, and zero always has an exponent
+    /// of zero and keeps its sign. `NaN` becomes the decimal128 quiet `NaN`, and the
+    /// infinities carry over.
+    ///
+    /// This is only available when the `num-bigint` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let bits = u128::from_le_bytes(dd!(1.5).to_decimal128_bytes());
+    /// assert!(bits == 0x303e_0000_0000_0000_0000_0000_0000_000f); // 15 × 10⁻¹
+    ///
+    /// let x = dd!(1) / dd!(3);
+    /// assert!(Double::from_decimal128_bytes(x.to_decimal128_bytes()) == x);
+    /// ```
+    pub fn to_decimal128_bytes(self) -> [u8; 16] {
+        b::to_bits(&[self.0, self.1], &b::DECIMAL128).to_le_bytes()
+    }
+
+    /// Generates a `Double` from the bytes of an IEEE 754 decimal128 number, in the binary
+    /// integer decimal (BID) encoding and in little-endian order.
+    ///
+    /// A decimal128 has a few more significant digits than a `Double`, so it's rounded: the
+    /// first component is the nearest `f64` to the decimal128, and the second is the
+    /// nearest `f64` to what's left. One that's too large becomes [`INFINITY`] or
+    /// [`NEG_INFINITY`], and one that's too small is rounded to the nearest `f64` subnormal
+    /// or to zero. Any decimal128 `NaN` becomes [`NAN`], and a coefficient that's too large
+    /// for a decimal128 (which the standard says to treat as zero) becomes zero.
+    ///
+    /// This is only available when the `num-bigint` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let one = 0x3040_0000_0000_0000_0000_0000_0000_0001u128; // 1 × 10⁰
+    /// assert!(Double::from_decimal128_bytes(one.to_le_bytes()) == dd!(1));
+    ///
+    /// let pi = 0x2ffe_9ae4_7957_96a7_babe_5564_e6f3_9f92u128;
+    /// assert!(Double::from_decimal128_bytes(pi.to_le_bytes()) == Double::PI);
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn from_decimal128_bytes(bytes: [u8; 16]) -> Double {
+        let parts = b::from_bits(u128::from_le_bytes(bytes), 2, &b::DECIMAL128);
+        Double(parts[0], parts[1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bits64(x: Double) -> u64 {
+        u64::from_le_bytes(x.to_decimal64_bytes())
+    }
+
+    fn bits128(x: Double) -> u128 {
+        u128::from_le_bytes(x.to_decimal128_bytes())
+    }
+
+    fn from64(bits: u64) -> Double {
+        Double::from_decimal64_bytes(bits.to_le_bytes())
+    }
+
+    fn from128(bits: u128) -> Double {
+        Double::from_decimal128_bytes(bits.to_le_bytes())
+    }
+
+    // to_decimal64_bytes tests
+    test!(to_decimal64_exact: {
+        assert!(bits64(dd!(1)) == 0x31c0_0000_0000_0001);
+        assert!(bits64(dd!(-2)) == 0xb1c0_0000_0000_0002);
+        assert!(bits64(dd!(100)) == 0x3200_0000_0000_0001);
+        assert!(bits64(dd!(1.5)) == 0x31a0_0000_0000_000f);
+        // Coefficients of more than 53 bits use the other encoding
+        assert!(bits64(dd!(9999999999999999i64)) == 0x6c73_86f2_6fc0_ffff);
+    });
+    test!(to_decimal64_rounded: {
+        assert!(bits64(Double::PI) == 0x2feb_2943_0a25_6d21);
+        assert!(bits64(dd!(1) / dd!(3)) == 0x2fcb_d7a6_2540_5555);
+        assert!(bits64(dd!(1e300)) == 0x5740_0000_0000_0001);
+        assert!(bits64(Double(f64::from_bits(1), 0.0)) == 0x0771_8d80_3929_31b1);
+    });
+    test!(to_decimal64_ties: {
+        // Exactly halfway goes to the even last digit
+        assert!(bits64(dd!(12345678901234565i64)) == 0x31e4_62d5_3c8a_bac0);
+        assert!(bits64(dd!(12345678901234575i64)) == 0x31e4_62d5_3c8a_bac2);
+        assert!(bits64(Double(12345678901234564.0, 0.5)) == 0x31e4_62d5_3c8a_bac0);
+        assert!(bits64(Double(12345678901234566.0, -1.0)) == 0x31e4_62d5_3c8a_bac0);
+        assert!(bits64(Double(12345678901234564.0, 1.5)) == 0x31e4_62d5_3c8a_bac1);
+    });
+    test!(to_decimal64_special: {
+        assert!(bits64(Double::ZERO) == 0x31c0_0000_0000_0000);
+        assert!(bits64(Double::NEG_ZERO) == 0xb1c0_0000_0000_0000);
+        assert!(bits64(Double::INFINITY) == 0x7800_0000_0000_0000);
+        assert!(bits64(Double::NEG_INFINITY) == 0xf800_0000_0000_0000);
+        assert!(bits64(Double::NAN) == 0x7c00_0000_0000_0000);
+    });
+
+    // from_decimal64_bytes tests
+    test!(from_decimal64_values: {
+        assert!(from64(0x31c0_0000_0000_0001) == dd!(1));
+        assert!(from64(0xb1c0_0000_0000_0002) == dd!(-2));
+        assert!(from64(0x31a0_0000_0000_000f) == dd!(1.5));
+        assert!(from64(0x6c73_86f2_6fc0_ffff) == dd!(9999999999999999i64));
+        assert!(from64(0x31a0_0000_0000_0001) == Double(0.1, -5.551115123125783e-18));
+        assert!(from64(0x2feb_2943_0a25_6d21) == Double(Double::PI.0, -1.1599796346854417e-16));
+    });
+    test!(from_decimal64_range: {
+        // 9999999999999999 × 10³⁶⁹, the largest decimal64
+        assert!(from64(0x77fb_86f2_6fc0_ffff) == Double::INFINITY);
+        assert!(from64(0xf7fb_86f2_6fc0_ffff) == Double::NEG_INFINITY);
+        // 1 × 10⁻³⁹⁸, the smallest
+        assert!(from64(0x0000_0000_0000_0001) == Double::ZERO);
+        assert!(from64(0x8000_0000_0000_0001).is_sign_negative());
+        assert!(from64(0x07e4_62ed_05ae_d35b) == Double(1.2347e-320, 0.0));
+    });
+    test!(from_decimal64_special: {
+        assert!(from64(0x31c0_0000_0000_0000) == Double::ZERO);
+        assert!(from64(0xb1c0_0000_0000_0000).is_sign_negative());
+        assert!(from64(0x7800_0000_0000_0000) == Double::INFINITY);
+        assert!(from64(0xf800_0000_0000_0000) == Double::NEG_INFINITY);
+        assert!(from64(0x7c00_0000_0000_0000).is_nan());
+        assert!(from64(0xfe00_0000_0000_1234).is_nan());
+        // A non-canonical coefficient, too large for 16 digits
+        assert!(from64(0x6c77_ffff_ffff_ffff) == Double::ZERO);
+    });
+
+    // decimal128 tests
+    test!(to_decimal128: {
+        assert!(bits128(dd!(1)) == 0x3040_0000_0000_0000_0000_0000_0000_0001);
+        assert!(bits128(dd!(-1.5)) == 0xb03e_0000_0000_0000_0000_0000_0000_000f);
+        assert!(bits128(Double::PI) == 0x2ffe_9ae4_7957_96a7_babe_5564_e6f3_9f92);
+        assert!(bits128(Double::ZERO) == 0x3040_0000_0000_0000_0000_0000_0000_0000);
+        assert!(bits128(Double::NAN) == 0x7c00_0000_0000_0000_0000_0000_0000_0000);
+        assert!(bits128(Double::NEG_INFINITY) == 0xf800_0000_0000_0000_0000_0000_0000_0000);
+        assert!(
+            bits128(Double(f64::from_bits(1), 0.0)) == 0x2d76_f397_da03_af06_aa83_3fd2_5715_f6e6
+        );
+    });
+    test!(from_decimal128: {
+        assert!(from128(0x3040_0000_0000_0000_0000_0000_0000_0001) == dd!(1));
+        assert!(from128(0xb03e_0000_0000_0000_0000_0000_0000_000f) == dd!(-1.5));
+        assert!(from128(0x2ffe_9ae4_7957_96a7_babe_5564_e6f3_9f92) == Double::PI);
+        assert!(from128(0x7800_0000_0000_0000_0000_0000_0000_0000) == Double::INFINITY);
+        assert!(from128(0x7c00_0000_0000_0000_0000_0000_0000_0000).is_nan());
+        // 1 × 10⁶¹¹¹ and 1 × 10⁻⁶¹⁷⁶
+        assert!(from128(0x5ffe_0000_0000_0000_0000_0000_0000_0001) == Double::INFINITY);
+        assert!(from128(0x0000_0000_0000_0000_0000_0000_0000_0001) == Double::ZERO);
+    });
+
+    // round trip tests
+    test!(decimal_round_trip: {
+        for x in [Double::PI, -Double::E, dd!(1) / dd!(3), Double::LN_2 * dd!(1e200)] {
+            assert!(from128(bits128(x)) == x);
+        }
+        for bits in [0x31a0_0000_0000_0001u64, 0x2feb_2943_0a25_6d21, 0x6c73_86f2_6fc0_ffff] {
+            assert!(bits64(from64(bits)) == bits);
+        }
+    });
+}
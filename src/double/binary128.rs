@@ -0,0 +1,169 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::binary128 as b;
+use crate::double::Double;
+
+impl Double {
+    /// Converts the `Double` into the bytes of an IEEE 754 binary128 (quadruple precision)
+    /// number, in little-endian order.
+    ///
+    /// This is the layout of a Fortran `REAL(16)` or a C `__float128`, so it can be used to
+    /// write data that those languages can read. A binary128 has 113 bits of precision,
+    /// which is enough to hold most `Double`s exactly. The rest (those whose components are
+    /// far enough apart in magnitude) are correctly rounded to the nearest binary128, with
+    /// ties going to the one with an even last bit. `NaN` becomes the binary128 quiet `NaN`,
+    /// and the infinities and the sign of zero carry over.
+    ///
+    /// The bytes of a big-endian binary128 are the same bytes in reverse order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let bytes = dd!(1).to_binary128_bytes();
+    /// assert!(u128::from_le_bytes(bytes) == 0x3fff_0000_0000_0000_0000_0000_0000_0000);
+    ///
+    /// let x = dd!(1) / dd!(3);
+    /// assert!(Double::from_binary128_bytes(x.to_binary128_bytes()) == x);
+    /// ```
+    pub fn to_binary128_bytes(self) -> [u8; 16] {
+        b::to_bits(&[self.0, self.1]).to_le_bytes()
+    }
+
+    /// Generates a `Double` from the bytes of an IEEE 754 binary128 (quadruple precision)
+    /// number, in little-endian order.
+    ///
+    /// This is the layout of a Fortran `REAL(16)` or a C `__float128`, so it can be used to
+    /// read data written by those languages. A binary128 has 113 bits of precision, which is
+    /// more than a `Double` has, so it's correctly rounded to the nearest `Double`. One
+    /// that's too large becomes [`INFINITY`] or [`NEG_INFINITY`], and one that's too small
+    /// (which includes all of the binary128 subnormals) is rounded to the nearest `f64`
+    /// subnormal or to zero. Any binary128 `NaN` becomes [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let one = 0x3fff_0000_0000_0000_0000_0000_0000_0000u128;
+    /// assert!(Double::from_binary128_bytes(one.to_le_bytes()) == dd!(1));
+    ///
+    /// let pi = 0x4000_921f_b544_42d1_8469_898c_c517_01b8u128;
+    /// assert!(Double::from_binary128_bytes(pi.to_le_bytes()) == Double::PI);
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn from_binary128_bytes(bytes: [u8; 16]) -> Double {
+        let parts = b::from_bits(u128::from_le_bytes(bytes), 2);
+        Double(parts[0], parts[1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pow2(n: i32) -> f64 {
+        2f64.powi(n)
+    }
+
+    fn bits(x: Double) -> u128 {
+        u128::from_le_bytes(x.to_binary128_bytes())
+    }
+
+    fn from_bits(bits: u128) -> Double {
+        Double::from_binary128_bytes(bits.to_le_bytes())
+    }
+
+    // to_binary128_bytes tests
+    test!(to_binary128_exact: {
+        assert!(bits(dd!(1)) == 0x3fff_0000_0000_0000_0000_0000_0000_0000);
+        assert!(bits(dd!(-2)) == 0xc000_0000_0000_0000_0000_0000_0000_0000);
+        assert!(bits(Double(1.0, pow2(-112))) == 0x3fff_0000_0000_0000_0000_0000_0000_0001);
+        assert!(bits(Double(2.0, -pow2(-112))) == 0x3fff_ffff_ffff_ffff_ffff_ffff_ffff_ffff);
+        assert!(bits(Double(f64::MAX, 0.0)) == 0x43fe_ffff_ffff_ffff_f000_0000_0000_0000);
+        assert!(bits(Double(f64::from_bits(1), 0.0)) == 0x3bcd_0000_0000_0000_0000_0000_0000_0000);
+    });
+    test!(to_binary128_rounded: {
+        // Components too far apart to fit in 113 bits
+        assert!(bits(Double(1.0, pow2(-113))) == 0x3fff_0000_0000_0000_0000_0000_0000_0000);
+        assert!(bits(Double(1.0, 3.0 * pow2(-113))) == 0x3fff_0000_0000_0000_0000_0000_0000_0002);
+        assert!(bits(Double(1.0, pow2(-200))) == 0x3fff_0000_0000_0000_0000_0000_0000_0000);
+        assert!(bits(Double(1.0, -pow2(-200))) == 0x3fff_0000_0000_0000_0000_0000_0000_0000);
+    });
+    test!(to_binary128_special: {
+        assert!(bits(Double::ZERO) == 0);
+        assert!(bits(Double::NEG_ZERO) == 1 << 127);
+        assert!(bits(Double::INFINITY) == 0x7fff_0000_0000_0000_0000_0000_0000_0000);
+        assert!(bits(Double::NEG_INFINITY) == 0xffff_0000_0000_0000_0000_0000_0000_0000);
+        assert!(bits(Double::NAN) == 0x7fff_8000_0000_0000_0000_0000_0000_0000);
+    });
+
+    // from_binary128_bytes tests
+    test_all_exact!(
+        from_binary128_one:
+            dd!(1),
+            from_bits(0x3fff_0000_0000_0000_0000_0000_0000_0000);
+        from_binary128_neg:
+            dd!(-2),
+            from_bits(0xc000_0000_0000_0000_0000_0000_0000_0000);
+        from_binary128_two_parts:
+            Double(1.0, pow2(-60)),
+            from_bits(0x3fff_0000_0000_0000_0010_0000_0000_0000);
+        from_binary128_pi:
+            Double::PI,
+            from_bits(0x4000_921f_b544_42d1_8469_898c_c517_01b8);
+        from_binary128_max:
+            Double(f64::MAX, 0.0),
+            from_bits(0x43fe_ffff_ffff_ffff_f000_0000_0000_0000);
+        from_binary128_overflow:
+            Double::INFINITY,
+            from_bits(0x43ff_0000_0000_0000_0000_0000_0000_0000);
+        from_binary128_neg_overflow:
+            Double::NEG_INFINITY,
+            from_bits(0xc3ff_0000_0000_0000_0000_0000_0000_0000);
+        from_binary128_underflow:
+            Double::ZERO,
+            from_bits(0x3bcb_0000_0000_0000_0000_0000_0000_0000);
+        from_binary128_inf:
+            Double::INFINITY,
+            from_bits(0x7fff_0000_0000_0000_0000_0000_0000_0000);
+    );
+    test!(from_binary128_rounded: {
+        // These need more than the 53 bits of the second component past the first, so the
+        // last bits are rounded away, with ties going to even
+        assert!(from_bits(0x3fff_0000_0000_0000_0000_0000_0000_0001) == Double(1.0, pow2(-112)));
+        assert!(from_bits(0x3fff_0000_0000_0000_0400_0000_0000_0001) == Double(1.0, pow2(-54)));
+        assert!(from_bits(0x3fff_0000_0000_0000_0400_0000_0000_0020) == Double(1.0, pow2(-54)));
+        assert!(
+            from_bits(0x3fff_0000_0000_0000_0400_0000_0000_0060)
+                == Double(1.0, pow2(-54) + pow2(-105))
+        );
+        assert!(
+            from_bits(0x3fff_0000_0000_0000_0400_0000_0000_0021)
+                == Double(1.0, pow2(-54) + pow2(-106))
+        );
+    });
+    test_all_assert!(
+        from_binary128_nan:
+            from_bits(0x7fff_8000_0000_0000_0000_0000_0000_0000).is_nan();
+        from_binary128_neg_zero:
+            from_bits(1 << 127).is_sign_negative();
+    );
+
+    // round trip tests
+    test!(binary128_round_trip: {
+        for x in [
+            Double::PI,
+            -Double::E,
+            Double::LN_2 * dd!(1e200),
+            Double::SQRT_2 / dd!(1e200),
+            Double::MAX,
+            Double(1.5, pow2(-60)),
+        ] {
+            assert!(from_bits(bits(x)) == x);
+        }
+    });
+}
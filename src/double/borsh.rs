@@ -0,0 +1,104 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::double::Double;
+use borsh::io::{Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+impl BorshSerialize for Double {
+    /// Serializes the `Double` as its 2 components, in order, each as the 8 bytes of an `f64` in
+    /// little-endian order.
+    ///
+    /// This is the layout of [`to_le_bytes`], which is always exactly 16 bytes, so a `Double`
+    /// can be part of a fixed-size borsh structure. It reproduces the `Double` exactly. Unlike
+    /// borsh's own encoding of `f64`s, `NaN` is allowed, so any `Double` can be serialized.
+    ///
+    /// This is only available when the `borsh` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let bytes = borsh::to_vec(&Double::PI).unwrap();
+    /// assert!(bytes == Double::PI.to_le_bytes());
+    /// ```
+    ///
+    /// [`to_le_bytes`]: #method.to_le_bytes
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(&self.to_le_bytes())
+    }
+}
+
+impl BorshDeserialize for Double {
+    /// Deserializes a `Double` from the 16 bytes written by its `BorshSerialize`
+    /// implementation.
+    ///
+    /// The components are taken as they are, as with [`from_le_bytes`], so the original
+    /// `Double` comes back exactly.
+    ///
+    /// This is only available when the `borsh` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let bytes = borsh::to_vec(&(7u8, Double::E)).unwrap();
+    /// assert!(bytes.len() == 17);
+    ///
+    /// let (tag, x): (u8, Double) = borsh::from_slice(&bytes).unwrap();
+    /// assert!(tag == 7 && x == Double::E);
+    /// ```
+    ///
+    /// [`from_le_bytes`]: #method.from_le_bytes
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Double> {
+        let mut bytes = [0; 16];
+        reader.read_exact(&mut bytes)?;
+        Ok(Double::from_le_bytes(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn same(a: Double, b: Double) -> bool {
+        (0..2).all(|i| a[i].to_bits() == b[i].to_bits())
+    }
+
+    // borsh tests
+    test!(borsh_layout: {
+        let bytes = borsh::to_vec(&Double::PI).unwrap();
+        assert!(bytes.len() == 16);
+        assert!(bytes[..8] == Double::PI[0].to_le_bytes());
+        assert!(bytes[8..16] == Double::PI[1].to_le_bytes());
+    });
+    test!(borsh_round_trip: {
+        for x in [
+            Double::PI,
+            -Double::E,
+            Double::NEG_ZERO,
+            Double::MAX,
+            Double::MIN_POSITIVE,
+            Double::NEG_INFINITY,
+            dd!(1) / dd!(3),
+        ] {
+            let bytes = borsh::to_vec(&x).unwrap();
+            assert!(same(borsh::from_slice(&bytes).unwrap(), x));
+        }
+    });
+    test!(borsh_nan: {
+        let bytes = borsh::to_vec(&Double::NAN).unwrap();
+        assert!(borsh::from_slice::<Double>(&bytes).unwrap().is_nan());
+    });
+    test!(borsh_vec: {
+        let values = vec![Double::PI, Double::E, Double::LN_2];
+        let bytes = borsh::to_vec(&values).unwrap();
+        // A four-byte length, then the values
+        assert!(bytes.len() == 4 + 3 * 16);
+        assert!(borsh::from_slice::<Vec<Double>>(&bytes).unwrap() == values);
+    });
+    test!(borsh_short: {
+        let bytes = borsh::to_vec(&Double::PI).unwrap();
+        assert!(borsh::from_slice::<Double>(&bytes[..15]).is_err());
+    });
+}
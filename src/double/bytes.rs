@@ -0,0 +1,325 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Views of `Double`s as raw bytes, and conversions to and from fixed byte layouts (including
+// one that sorts the same way as the numbers do). The `zerocopy` traits are derived on the
+// struct itself; the `bytemuck` ones are implemented here.
+
+use crate::common::utils as u;
+use crate::double::Double;
+use std::convert::TryInto;
+
+impl Double {
+    /// Returns the memory representation of the `Double` as a byte array in little-endian
+    /// byte order.
+    ///
+    /// The layout is the 2 components of the `Double` in order, each one as the 8 bytes of
+    /// its `f64` in little-endian order, for 16 bytes in all. That's the same as the way a
+    /// `Double` is laid out in memory on a little-endian machine. Nothing is rounded or
+    /// normalized, so [`from_le_bytes`] always gives back exactly the same `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let bytes = Double::PI.to_le_bytes();
+    /// assert!(bytes[..8] == Double::PI[0].to_le_bytes());
+    /// assert!(bytes[8..16] == Double::PI[1].to_le_bytes());
+    /// assert!(Double::from_le_bytes(bytes) == Double::PI);
+    /// ```
+    ///
+    /// [`from_le_bytes`]: #method.from_le_bytes
+    pub fn to_le_bytes(self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        for (chunk, x) in bytes.chunks_exact_mut(8).zip([self.0, self.1]) {
+            chunk.copy_from_slice(&x.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Returns the memory representation of the `Double` as a byte array in big-endian
+    /// (network) byte order.
+    ///
+    /// The layout is the 2 components of the `Double` in order, each one as the 8 bytes of
+    /// its `f64` in big-endian order, for 16 bytes in all. That's the same as the way a
+    /// `Double` is laid out in memory on a big-endian machine, and it means that the most
+    /// significant byte comes first. Nothing is rounded or normalized, so
+    /// [`from_be_bytes`] always gives back exactly the same `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let bytes = Double::PI.to_be_bytes();
+    /// assert!(bytes[..8] == Double::PI[0].to_be_bytes());
+    /// assert!(bytes[8..16] == Double::PI[1].to_be_bytes());
+    /// assert!(Double::from_be_bytes(bytes) == Double::PI);
+    /// ```
+    ///
+    /// [`from_be_bytes`]: #method.from_be_bytes
+    pub fn to_be_bytes(self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        for (chunk, x) in bytes.chunks_exact_mut(8).zip([self.0, self.1]) {
+            chunk.copy_from_slice(&x.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Creates a `Double` from its memory representation as a byte array in little-endian
+    /// byte order.
+    ///
+    /// This is the inverse of [`to_le_bytes`], so the layout is the same: the 2
+    /// components in order, each as the 8 bytes of an `f64` in little-endian order. Like
+    /// [`new`], this takes the components as they are, without normalizing them.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let mut bytes = [0; 16];
+    /// bytes[..8].copy_from_slice(&1.5f64.to_le_bytes());
+    /// assert!(Double::from_le_bytes(bytes) == dd!(1.5));
+    /// ```
+    ///
+    /// [`to_le_bytes`]: #method.to_le_bytes
+    /// [`new`]: #method.new
+    pub fn from_le_bytes(bytes: [u8; 16]) -> Double {
+        let mut parts = bytes
+            .chunks_exact(8)
+            .map(|chunk| f64::from_le_bytes(chunk.try_into().unwrap()));
+        Double(parts.next().unwrap(), parts.next().unwrap())
+    }
+
+    /// Creates a `Double` from its memory representation as a byte array in big-endian
+    /// (network) byte order.
+    ///
+    /// This is the inverse of [`to_be_bytes`], so the layout is the same: the 2
+    /// components in order, each as the 8 bytes of an `f64` in big-endian order. Like
+    /// [`new`], this takes the components as they are, without normalizing them.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let mut bytes = [0; 16];
+    /// bytes[..8].copy_from_slice(&1.5f64.to_be_bytes());
+    /// assert!(Double::from_be_bytes(bytes) == dd!(1.5));
+    /// ```
+    ///
+    /// [`to_be_bytes`]: #method.to_be_bytes
+    /// [`new`]: #method.new
+    pub fn from_be_bytes(bytes: [u8; 16]) -> Double {
+        let mut parts = bytes
+            .chunks_exact(8)
+            .map(|chunk| f64::from_be_bytes(chunk.try_into().unwrap()));
+        Double(parts.next().unwrap(), parts.next().unwrap())
+    }
+    /// Returns a byte array whose lexicographic order is the same as the numeric order of
+    /// the `Double`s that they came from.
+    ///
+    /// This makes `Double`s usable as keys in anything that sorts its keys as plain byte
+    /// strings, like the range scans of LMDB or RocksDB. The 2 components each become 8
+    /// bytes, most significant first, with the bits of each one adjusted so that negative
+    /// numbers come before positive ones and larger magnitudes of negative numbers come
+    /// first. [`NEG_INFINITY`] gives the lowest key and [`INFINITY`] the highest, except for
+    /// `NaN`, which comes after everything else.
+    ///
+    /// Numbers that are equal have equal keys. That means that [`NEG_ZERO`] gives the same
+    /// key as [`ZERO`], and every `NaN` gives the same key, so [`from_sortable_bytes`]
+    /// gives back a positive zero and a plain [`NAN`] for them. The order of the keys is
+    /// only guaranteed to match for normalized `Double`s, which is all of them that come out of
+    /// `Double` arithmetic.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let mut values = vec![dd!(1.5), -Double::PI, Double::INFINITY, dd!(0), dd!(-1e-300)];
+    /// let mut keys: Vec<_> = values.iter().map(|x| x.to_sortable_bytes()).collect();
+    /// keys.sort();
+    /// values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    ///
+    /// let sorted: Vec<_> = keys.into_iter().map(Double::from_sortable_bytes).collect();
+    /// assert!(sorted == values);
+    /// ```
+    ///
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_ZERO`]: #associatedconstant.NEG_ZERO
+    /// [`ZERO`]: #associatedconstant.ZERO
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`from_sortable_bytes`]: #method.from_sortable_bytes
+    pub fn to_sortable_bytes(self) -> [u8; 16] {
+        // Whatever is in the second component of an infinity or `NaN` doesn't change its
+        // value, so it's left out of the key
+        let x = if self.0.is_finite() {
+            self
+        } else {
+            Double::from(self.0)
+        };
+        let mut bytes = [0; 16];
+        for (chunk, a) in bytes.chunks_exact_mut(8).zip([x.0, x.1]) {
+            chunk.copy_from_slice(&u::to_sortable_bits(a).to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Creates a `Double` from a byte array generated by [`to_sortable_bytes`].
+    ///
+    /// This gives back exactly the `Double` that the bytes came from, except that a negative
+    /// zero comes back as a positive zero and any `NaN` comes back as [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let key = Double::PI.to_sortable_bytes();
+    /// assert!(Double::from_sortable_bytes(key) == Double::PI);
+    /// ```
+    ///
+    /// [`to_sortable_bytes`]: #method.to_sortable_bytes
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn from_sortable_bytes(bytes: [u8; 16]) -> Double {
+        let mut parts = bytes
+            .chunks_exact(8)
+            .map(|chunk| u::from_sortable_bits(u64::from_be_bytes(chunk.try_into().unwrap())));
+        Double(parts.next().unwrap(), parts.next().unwrap())
+    }
+}
+// SAFETY: `Double` is `#[repr(C)]` and made up of nothing but two `f64`s, so it has no
+// padding, and every bit pattern is a valid `Double` (though not necessarily a normalized one).
+// All zeros is `Double::ZERO`.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Double {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Double {}
+
+#[cfg(test)]
+mod tests {
+    use crate::double::Double;
+
+    // byte order tests
+    test!(le_bytes: {
+        let bytes = Double::PI.to_le_bytes();
+        for i in 0..2 {
+            assert!(bytes[i * 8..i * 8 + 8] == Double::PI[i].to_le_bytes());
+        }
+        assert!(Double::from_le_bytes(bytes) == Double::PI);
+    });
+    test!(be_bytes: {
+        let bytes = Double::PI.to_be_bytes();
+        for i in 0..2 {
+            assert!(bytes[i * 8..i * 8 + 8] == Double::PI[i].to_be_bytes());
+        }
+        assert!(Double::from_be_bytes(bytes) == Double::PI);
+        // The sign bit of the first component is the first bit
+        assert!((-Double::PI).to_be_bytes()[0] & 0x80 == 0x80);
+    });
+    test!(bytes_round_trip: {
+        for x in values() {
+            assert!(Double::from_le_bytes(x.to_le_bytes()) == x);
+            assert!(Double::from_be_bytes(x.to_be_bytes()) == x);
+        }
+    });
+    test!(bytes_special: {
+        assert!(Double::from_le_bytes(Double::NAN.to_le_bytes()).is_nan());
+        assert!(Double::from_be_bytes(Double::INFINITY.to_be_bytes()) == Double::INFINITY);
+        let neg_zero = Double::from_le_bytes(Double::NEG_ZERO.to_le_bytes());
+        assert!(neg_zero.is_zero() && neg_zero.is_sign_negative());
+    });
+    test!(bytes_unnormalized: {
+        // Components are taken as they are
+        let mut bytes = [0; 16];
+        bytes[..8].copy_from_slice(&1.0f64.to_le_bytes());
+        bytes[8..16].copy_from_slice(&1.0f64.to_le_bytes());
+        let x = Double::from_le_bytes(bytes);
+        assert!(x[0] == 1.0 && x[1] == 1.0);
+    });
+
+    fn values() -> [Double; 3] {
+        [Double::PI, -Double::E, Double::new(0.0, 0.0)]
+    }
+
+    // sortable bytes tests
+    test!(sortable_order: {
+        let values = [
+            Double::NEG_INFINITY,
+            -Double::MAX,
+            dd!(-1e300),
+            -Double::PI,
+            Double::from(-3.0) - Double::from(1e-25),
+            dd!(-3),
+            Double::from(-3.0) + Double::from(1e-25),
+            dd!(-1e-300),
+            Double::ZERO,
+            dd!(1e-300),
+            Double::from(1.0) - Double::from(1e-30),
+            dd!(1),
+            Double::from(1.0) + Double::from(1e-30),
+            Double::PI,
+            dd!(1e300),
+            Double::MAX,
+            Double::INFINITY,
+            Double::NAN,
+        ];
+        for pair in values.windows(2) {
+            assert!(pair[0].to_sortable_bytes() < pair[1].to_sortable_bytes());
+        }
+    });
+    test!(sortable_round_trip: {
+        for x in values() {
+            assert!(Double::from_sortable_bytes(x.to_sortable_bytes()) == x);
+        }
+        assert!(Double::from_sortable_bytes(Double::NEG_INFINITY.to_sortable_bytes()) == Double::NEG_INFINITY);
+    });
+    test!(sortable_zero: {
+        assert!(Double::NEG_ZERO.to_sortable_bytes() == Double::ZERO.to_sortable_bytes());
+        let zero = Double::from_sortable_bytes(Double::NEG_ZERO.to_sortable_bytes());
+        assert!(zero.is_zero() && zero.is_sign_positive());
+    });
+    test!(sortable_nan: {
+        assert!((-Double::NAN).to_sortable_bytes() == Double::NAN.to_sortable_bytes());
+        assert!(Double::from_sortable_bytes(Double::NAN.to_sortable_bytes()).is_nan());
+    });
+
+    // bytemuck tests
+    #[cfg(feature = "bytemuck")]
+    test!(bytemuck_zeroed: {
+        let zero: Double = bytemuck::Zeroable::zeroed();
+        assert!(zero == Double::ZERO && zero.is_sign_positive());
+    });
+    #[cfg(feature = "bytemuck")]
+    test!(bytemuck_cast_slice: {
+        let values = values();
+        let floats: &[f64] = bytemuck::cast_slice(&values);
+        assert!(floats.len() == 6);
+        assert!(floats[0] == Double::PI[0] && floats[1] == Double::PI[1]);
+        assert!(floats[2] == -Double::E[0]);
+
+        let back: &[Double] = bytemuck::cast_slice(floats);
+        assert!(back == values);
+    });
+    #[cfg(feature = "bytemuck")]
+    test!(bytemuck_bytes: {
+        let bytes = bytemuck::bytes_of(&Double::PI);
+        assert!(bytes.len() == 16);
+        assert!(bytes[..8] == Double::PI[0].to_ne_bytes());
+        assert!(bytemuck::pod_read_unaligned::<Double>(bytes) == Double::PI);
+    });
+
+    // zerocopy tests
+    #[cfg(feature = "zerocopy")]
+    test!(zerocopy_bytes: {
+        use zerocopy::{FromBytes, IntoBytes};
+        let values = values();
+        let bytes = values.as_bytes();
+        assert!(bytes.len() == 48);
+        assert!(bytes[16..24] == (-Double::E[0]).to_ne_bytes());
+
+        let back = <[Double]>::ref_from_bytes(bytes).unwrap();
+        assert!(back == values);
+        assert!(Double::read_from_bytes(&bytes[..16]).unwrap() == Double::PI);
+    });
+    #[cfg(feature = "zerocopy")]
+    test!(zerocopy_zeroed: {
+        let zero: Double = zerocopy::FromZeros::new_zeroed();
+        assert!(zero == Double::ZERO && zero.is_sign_positive());
+    });
+}
@@ -3,7 +3,12 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::primitive as p;
+use crate::common::utils as u;
 use crate::double::Double;
+use crate::quad::Quad;
+use crate::round::RoundMode;
+use std::cmp::Ordering;
 
 /// Helper function that efficiently multiplies a Double by a power of 2. This is -much-
 /// faster than regular multiplication but only works with powers of 2.
@@ -12,6 +17,79 @@ pub fn mul_pwr2(a: Double, b: f64) -> Double {
     Double(a.0 * b, a.1 * b)
 }
 
+/// Converts a `Double` into a `Quad` without any of the parsing that `Quad::from(Double)`
+/// does. The product of two `Double`s fits exactly into the 212 bits of a `Quad`, so working
+/// in `Quad`s lets products be added before any rounding happens.
+#[inline]
+pub fn to_quad(x: Double) -> Quad {
+    Quad::from((x.0, x.1))
+}
+
+/// Rounds a `Quad` back down into a `Double`.
+#[inline]
+pub fn from_quad(x: Quad) -> Double {
+    let (a, b) = u::renorm2(x[0], x[1] + x[2]);
+    Double(a, b)
+}
+
+/// Moves `x`, the result of an operation on finite numbers rounded to nearest, in the
+/// direction given by `mode` until it's on the right side of the exact result. `cmp`
+/// compares a candidate with the exact result, exactly.
+///
+/// Each step adds about the size returned by `eps_at` to the last component and
+/// renormalizes, which always moves the candidate to a different value. A candidate that
+/// takes more than a few steps has them doubled each time after that, and one that still
+/// isn't a bound after `STEP_LIMIT` of those is replaced by the infinity in the direction
+/// it was going, which always is one. A result that overflowed (to an infinity or `NaN`)
+/// starts again from `Double::MAX` or `Double::MIN`, so that rounding toward zero gives a
+/// finite bound.
+pub fn round_directed(x: Double, mode: RoundMode, cmp: impl Fn(Double) -> Ordering) -> Double {
+    if mode == RoundMode::Nearest {
+        return x;
+    }
+    // The sign of the exact result, which isn't necessarily the sign of `x` if `x`
+    // underflowed to zero
+    let sign = cmp(Double::ZERO).reverse();
+    let up = match mode {
+        RoundMode::Up => true,
+        RoundMode::Down => false,
+        _ => sign == Ordering::Less,
+    };
+
+    let mut x = if x.is_finite() {
+        x
+    } else if sign == Ordering::Less {
+        Double::MIN
+    } else {
+        Double::MAX
+    };
+    for i in 0..STEP_LIMIT {
+        match cmp(x) {
+            Ordering::Less if up => {}
+            Ordering::Greater if !up => {}
+            _ => return x,
+        }
+        let base = x.eps_at().0 * 2f64.powi((i - SLOW_STEPS).max(0));
+        let last = x.1.abs();
+        let size = base.max(f64::from_bits(last.to_bits() + 1) - last);
+        let (a, b) = p::two_sum(x.0, if up { x.1 + size } else { x.1 - size });
+        if !a.is_finite() {
+            break;
+        }
+        x = Double(a, b);
+    }
+    if up {
+        Double::INFINITY
+    } else {
+        Double::NEG_INFINITY
+    }
+}
+
+// The number of steps of the same size that `round_directed` takes before it starts to
+// double them, and the number that it takes in all before it gives up.
+const SLOW_STEPS: i32 = 8;
+const STEP_LIMIT: i32 = 2200;
+
 /// Table of the reciprocals of factorials. This starts with 1/3!, as the inverse factorials
 /// before that are trivial (1/1! is 1 and 1/2! is 1/2). These are used in Taylor series
 /// calculations for exp, sin, and cos. 
@@ -48,3 +126,31 @@ pub const COSINES: [Double; 4] = [
     Double(8.314696123025452e-1, 1.4073856984728008e-18),
     Double(7.071067811865476e-1, -4.8336466567264573e-17),
 ];
+
+/// Table of the even-indexed Bernoulli numbers, B<sub>2k</sub> for k in [0, 20] (so
+/// B<sub>0</sub> through B<sub>40</sub>). The odd-indexed Bernoulli numbers past B<sub>1</sub>
+/// are all zero, so they aren't stored. These are for asymptotic series such as Stirling's
+/// series, and they back the public `bernoulli` function.
+pub const BERNOULLI: [Double; 21] = [
+    Double(1e0, 0e0),
+    Double(1.6666666666666666e-1, 9.25185853854297e-18),
+    Double(-3.333333333333333e-2, -4.625929269271486e-19),
+    Double(2.3809523809523808e-2, 1.32169407693471e-18),
+    Double(-3.333333333333333e-2, -4.625929269271486e-19),
+    Double(7.575757575757576e-2, -2.10269512239613e-18),
+    Double(-2.531135531135531e-1, -1.1061562736192037e-17),
+    Double(1.1666666666666667e0, -7.401486830834377e-17),
+    Double(-7.092156862745098e0, -3.274069468698501e-16),
+    Double(5.4971177944862156e1, -1.9588897477095493e-16),
+    Double(-5.291242424242424e2, 6.890111377067638e-16),
+    Double(6.192123188405797e3, 9.226757844073186e-14),
+    Double(-8.658025311355312e4, 3.5926706461242705e-12),
+    Double(1.4255171666666667e6, -7.761021455128987e-11),
+    Double(-2.7298231067816094e7, 1.610010519795034e-9),
+    Double(6.015808739006424e8, -2.6635227381825164e-8),
+    Double(-1.5116315767092157e10, 5.011465035232843e-7),
+    Double(4.296146430611667e11, -2.0345052083333332e-5),
+    Double(-1.3711655205088332e13, -7.409090879485616e-4),
+    Double(4.883323189735932e14, -2.0833333333333332e-2),
+    Double(-1.9296579341940068e16, -1.4863266814486326e-1),
+];
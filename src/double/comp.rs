@@ -10,6 +10,7 @@
 
 use crate::double::Double;
 use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 
 impl PartialEq for Double {
     /// Implements the `==` and `!= operators, testing two `Double`s for equality and
@@ -61,6 +62,354 @@ impl PartialOrd for Double {
     }
 }
 
+impl PartialEq<f64> for Double {
+    /// Implements the `==` and `!=` operators between a `Double` and an `f64`.
+    ///
+    /// The `f64` is taken at its exact binary value, so the two are equal only if the `Double`
+    /// has exactly that value. Just as with two `Double`s, zero equals negative zero and `NaN`
+    /// equals nothing.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(1.5) == 1.5);
+    /// assert!(dd!(0) == -0.0);
+    /// // `dd!` parses 0.1 as a decimal, which isn't the `f64` closest to it
+    /// assert!(dd!(0.1) != 0.1);
+    /// assert!(Double::NAN != f64::NAN);
+    /// ```
+    #[inline]
+    fn eq(&self, other: &f64) -> bool {
+        self.eq(&Double(*other, 0.0))
+    }
+}
+
+impl PartialEq<Double> for f64 {
+    /// Implements the `==` and `!=` operators between an `f64` and a `Double`.
+    ///
+    /// The `f64` is taken at its exact binary value, so the two are equal only if the `Double`
+    /// has exactly that value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(1.5 == dd!(1.5));
+    /// assert!(std::f64::consts::PI != Double::PI);
+    /// ```
+    #[inline]
+    fn eq(&self, other: &Double) -> bool {
+        Double(*self, 0.0).eq(other)
+    }
+}
+
+impl PartialOrd<f64> for Double {
+    /// Implements the `<`, `>`, `<=`, and `>=` operators between a `Double` and an `f64`.
+    ///
+    /// The `f64` is taken at its exact binary value, so a `Double` that's only a tiny bit
+    /// larger than an `f64` still compares as larger.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::PI > 3.0);
+    /// // The `f64` closest to π is a little smaller than π
+    /// assert!(Double::PI > std::f64::consts::PI);
+    /// assert!(!(Double::NAN < 1.0));
+    /// assert!(dd!(-0.5) <= 0.0);
+    /// ```
+    #[inline]
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        self.partial_cmp(&Double(*other, 0.0))
+    }
+}
+
+impl PartialOrd<Double> for f64 {
+    /// Implements the `<`, `>`, `<=`, and `>=` operators between an `f64` and a `Double`.
+    ///
+    /// The `f64` is taken at its exact binary value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(3.0 < Double::PI);
+    /// assert!(std::f64::consts::PI < Double::PI);
+    /// assert!(!(f64::NAN >= dd!(1)));
+    /// ```
+    #[inline]
+    fn partial_cmp(&self, other: &Double) -> Option<Ordering> {
+        Double(*self, 0.0).partial_cmp(other)
+    }
+}
+
+// Every `i32`, `i64`, and `u64` converts exactly into a `Double`, so comparing against one is
+// just comparing against the converted value.
+macro_rules! int_cmp_impl {
+    ($($t:ty)*) => ($(
+        impl PartialEq<$t> for Double {
+            /// Implements the `==` and `!=` operators between a `Double` and an integer,
+            /// which are equal only if the `Double` has exactly the integer's value.
+            #[inline]
+            fn eq(&self, other: &$t) -> bool {
+                self.eq(&Double::from(*other))
+            }
+        }
+
+        impl PartialEq<Double> for $t {
+            /// Implements the `==` and `!=` operators between an integer and a `Double`,
+            /// which are equal only if the `Double` has exactly the integer's value.
+            #[inline]
+            fn eq(&self, other: &Double) -> bool {
+                Double::from(*self).eq(other)
+            }
+        }
+
+        impl PartialOrd<$t> for Double {
+            /// Implements the `<`, `>`, `<=`, and `>=` operators between a `Double` and an
+            /// integer.
+            #[inline]
+            fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                self.partial_cmp(&Double::from(*other))
+            }
+        }
+
+        impl PartialOrd<Double> for $t {
+            /// Implements the `<`, `>`, `<=`, and `>=` operators between an integer and a
+            /// `Double`.
+            #[inline]
+            fn partial_cmp(&self, other: &Double) -> Option<Ordering> {
+                Double::from(*self).partial_cmp(other)
+            }
+        }
+    )*);
+}
+
+int_cmp_impl! { i32 i64 u64 }
+
+impl Hash for Double {
+    /// Feeds the `Double` into a hasher, consistently with `==`.
+    ///
+    /// Two `Double`s that are equal always hash the same, which means that zero and negative
+    /// zero do. The hash is that of the bit patterns returned by [`canonical_bits`].
+    ///
+    /// `Double` doesn't implement `Eq` (no more than `f64` does, as `NaN` isn't equal to
+    /// itself), so it can't be used directly as a `HashMap` key. A wrapper type that
+    /// implements `Eq` can use this to implement `Hash`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use std::collections::HashSet;
+    ///
+    /// #[derive(Hash, PartialEq)]
+    /// struct Key(Double);
+    /// impl Eq for Key {} // fine as long as no key is ever NaN
+    ///
+    /// let mut set = HashSet::new();
+    /// set.insert(Key(dd!(0)));
+    /// assert!(set.contains(&Key(dd!(-0.0))));
+    /// assert!(!set.contains(&Key(Double::PI)));
+    /// ```
+    ///
+    /// [`canonical_bits`]: #method.canonical_bits
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical_bits().hash(state);
+    }
+}
+
+impl Double {
+    /// Determines whether two `Double`s are approximately equal, given a relative and an
+    /// absolute tolerance.
+    ///
+    /// This has the same semantics as Python's `math.isclose`. The two numbers are close if
+    /// the magnitude of their difference is no greater than either `rel_tol` times the
+    /// larger of their magnitudes or `abs_tol`. The relative tolerance is the useful one for
+    /// most numbers, but it can't match anything to zero; the absolute tolerance covers
+    /// that case.
+    ///
+    /// Equal numbers are always close, including two infinities of the same sign. An
+    /// infinity is not close to anything else, and [`NAN`] is not close to anything,
+    /// including itself. Negative tolerances are never met.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::PI;
+    /// let y = Double::PI + dd!(1e-40);
+    /// assert!(x.is_close(y, dd!(1e-30), Double::ZERO));
+    /// assert!(!x.is_close(Double::E, dd!(1e-30), Double::ZERO));
+    ///
+    /// // Use an absolute tolerance to compare with zero
+    /// assert!(!Double::PI.sin().is_close(Double::ZERO, dd!(1e-30), Double::ZERO));
+    /// assert!(Double::PI.sin().is_close(Double::ZERO, dd!(1e-30), dd!(1e-30)));
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn is_close(self, other: Double, rel_tol: Double, abs_tol: Double) -> bool {
+        if self == other {
+            true
+        } else if !self.is_finite() || !other.is_finite() {
+            false
+        } else {
+            let diff = (self - other).abs();
+            let (a, b) = (self.abs(), other.abs());
+            let largest = if a > b { a } else { b };
+            diff <= rel_tol * largest || diff <= abs_tol
+        }
+    }
+
+    /// Determines whether two `Double`s are approximately equal, using default tolerances
+    /// suited to the precision of a `Double`.
+    ///
+    /// This is [`is_close`] with both the relative and the absolute tolerance set to 32
+    /// times [`EPSILON`], or about 1.6 × 10<sup>-30</sup>. That allows for rounding error in
+    /// the last five bits or so of numbers that are expected to be equal, and for a little
+    /// calculated noise in numbers that are expected to be zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = dd!(0.1) + dd!(0.2);
+    /// assert!(x != dd!(0.3));
+    /// assert!(x.is_close_default(dd!(0.3)));
+    /// assert!(Double::PI.sin().is_close_default(Double::ZERO));
+    /// ```
+    ///
+    /// [`is_close`]: #method.is_close
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    #[inline]
+    pub fn is_close_default(self, other: Double) -> bool {
+        self.is_close(other, DEFAULT_TOL, DEFAULT_TOL)
+    }
+    /// Determines whether two `Double`s are within `max_ulps` units in the last place of
+    /// each other.
+    ///
+    /// This is for the case where two numbers are expected to be identical but may have
+    /// picked up different rounding errors in their last bits along the way. Unlike
+    /// [`is_close`], the tolerance is a number of representable steps away, so it scales
+    /// with the numbers themselves. The size of a step is the one returned by [`eps_at`],
+    /// which depends on the exponent of the first component. Steps are counted at the
+    /// spacing on each side of a power of two when the two numbers straddle one.
+    ///
+    /// Equal numbers are always within any number of steps, including two zeros of
+    /// different signs and two infinities of the same sign. Otherwise numbers of different
+    /// signs never are, no matter how small they are. An infinity is not within any number
+    /// of steps of anything else, and [`NAN`] is not within any number of steps of
+    /// anything, including itself.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = dd!(1);
+    /// let y = x + Double::EPSILON / dd!(2);
+    /// assert!(x != y);
+    /// assert!(x.approx_eq_ulps(y, 1));
+    /// assert!(!x.approx_eq_ulps(y + y.eps_at(), 1));
+    ///
+    /// assert!(!Double::MIN_POSITIVE.approx_eq_ulps(-Double::MIN_POSITIVE, u64::MAX));
+    /// ```
+    ///
+    /// [`is_close`]: #method.is_close
+    /// [`eps_at`]: #method.eps_at
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn approx_eq_ulps(self, other: Double, max_ulps: u64) -> bool {
+        if self == other {
+            true
+        } else if !self.is_finite()
+            || !other.is_finite()
+            || self.is_sign_negative() != other.is_sign_negative()
+        {
+            false
+        } else {
+            let (a, b) = if self.abs() < other.abs() {
+                (self.abs(), other.abs())
+            } else {
+                (other.abs(), self.abs())
+            };
+            let (step_a, step_b) = (a.eps_at(), b.eps_at());
+            let limit = Double::from(max_ulps) * step_a;
+
+            if step_a == step_b {
+                b - a <= limit
+            } else if step_b == step_a * Double(2.0, 0.0) {
+                // The steps above the power of two between the numbers are twice as large
+                // as those below it, so they count half as much measured in `step_a`s
+                let edge = step_b.ldexp(104);
+                (edge - a) + (b - edge) / Double(2.0, 0.0) <= limit
+            } else {
+                // There's an entire binade between the two, which is 2¹⁰⁴ steps, so they
+                // can't be within any `u64` steps of each other
+                false
+            }
+        }
+    }
+
+    /// Returns the bit patterns of the components of the `Double`, normalized so that they're
+    /// the same for any two `Double`s that are equal.
+    ///
+    /// This is the raw memory representation of each component, as with `f64::to_bits`,
+    /// except that negative zero components are turned into positive zeros (zero equals
+    /// negative zero) and every `NaN` has the same bits. That makes these bits suitable for
+    /// hashing, and it's what the `Hash` implementation uses.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::ZERO.canonical_bits() == Double::NEG_ZERO.canonical_bits());
+    /// assert!(Double::NAN.canonical_bits() == (-Double::NAN).canonical_bits());
+    /// assert!(dd!(1).canonical_bits()[0] == 1f64.to_bits());
+    /// ```
+    pub fn canonical_bits(self) -> [u64; 2] {
+        if self.0.is_nan() || self.1.is_nan() {
+            [f64::NAN.to_bits(), 0]
+        } else {
+            // Adding zero turns a negative zero into a positive one and leaves everything
+            // else alone
+            [(self.0 + 0.0).to_bits(), (self.1 + 0.0).to_bits()]
+        }
+    }
+
+    /// Returns the ordering between `self` and `other` according to the `totalOrder`
+    /// predicate of IEEE 754, as `f64::total_cmp` does.
+    ///
+    /// Unlike `partial_cmp`, this always produces an ordering. Negative `NaN` sorts before
+    /// everything else, followed by negative infinity, the negative numbers, negative zero,
+    /// positive zero, the positive numbers, positive infinity, and finally positive `NaN`.
+    /// Within the finite numbers, the ordering is that of their values; the second component of
+    /// a nonzero number counts only for its value, so its sign doesn't matter if it's zero.
+    ///
+    /// This is what `Ord` would be if `Double` implemented it. It doesn't, for the same reason
+    /// that `f64` doesn't: this ordering disagrees with `==`, which has zero equal to
+    /// negative zero and `NaN` equal to nothing. Slices can be sorted with
+    /// `sort_by(Double::total_cmp)`, and a wrapper type whose `Ord` calls this can serve as a
+    /// key in a `BTreeMap`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut v = vec![dd!(2), Double::NAN, Double::NEG_INFINITY, dd!(-0.0), dd!(0), dd!(-1)];
+    /// v.sort_by(Double::total_cmp);
+    /// assert!(v[0] == Double::NEG_INFINITY && v[1] == dd!(-1) && v[4] == dd!(2));
+    /// assert!(v[2].is_sign_negative() && v[3].is_sign_positive());
+    /// assert!(v[5].is_nan());
+    ///
+    /// assert!(Double::NAN.total_cmp(&Double::NAN) == Ordering::Equal);
+    /// assert!(dd!(-0.0).total_cmp(&dd!(0)) == Ordering::Less);
+    /// ```
+    pub fn total_cmp(&self, other: &Double) -> Ordering {
+        match self.0.total_cmp(&other.0) {
+            Ordering::Equal if self.0.is_finite() && self.0 != 0.0 => {
+                (self.1 + 0.0).total_cmp(&(other.1 + 0.0))
+            }
+            x => x,
+        }
+    }
+}
+
+/// The relative and absolute tolerance used by `is_close_default`.
+const DEFAULT_TOL: Double = Double(Double::EPSILON.0 * 32.0, 0.0);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -130,4 +479,246 @@ mod tests {
         gte_nan_nan:
             !(Double::NAN >= Double::NAN);
     );
+
+    // is_close tests
+    test_all_assert!(
+        is_close_near:
+            Double::PI.is_close(Double::PI + dd!(1e-40), dd!(1e-30), Double::ZERO);
+        is_close_far:
+            !Double::PI.is_close(Double::E, dd!(1e-30), Double::ZERO);
+        is_close_rel_large:
+            dd!(1e20).is_close(dd!(1e20) + dd!(1e-12), dd!(1e-30), Double::ZERO);
+        is_close_rel_boundary:
+            dd!(100).is_close(dd!(101), dd!(0.01), Double::ZERO);
+        is_close_rel_symmetric:
+            dd!(101).is_close(dd!(100), dd!(0.01), Double::ZERO);
+        is_close_rel_too_far:
+            !dd!(100).is_close(dd!(102), dd!(0.01), Double::ZERO);
+        is_close_abs:
+            dd!(1e-35).is_close(Double::ZERO, dd!(1e-30), dd!(1e-30));
+        is_close_abs_needed:
+            !dd!(1e-35).is_close(Double::ZERO, dd!(1e-30), Double::ZERO);
+        is_close_abs_too_far:
+            !dd!(1e-20).is_close(Double::ZERO, dd!(1e-30), dd!(1e-30));
+        is_close_neg_tol:
+            !dd!(1).is_close(dd!(1.5), dd!(-1), dd!(-1));
+        is_close_equal_zero_tol:
+            Double::PI.is_close(Double::PI, Double::ZERO, Double::ZERO);
+        is_close_zero_neg_zero:
+            Double::ZERO.is_close(Double::NEG_ZERO, Double::ZERO, Double::ZERO);
+        is_close_inf_inf:
+            Double::INFINITY.is_close(Double::INFINITY, Double::ZERO, Double::ZERO);
+        is_close_neg_inf_neg_inf:
+            Double::NEG_INFINITY.is_close(Double::NEG_INFINITY, Double::ZERO, Double::ZERO);
+        is_close_inf_neg_inf:
+            !Double::INFINITY.is_close(Double::NEG_INFINITY, Double::ONE, Double::INFINITY);
+        is_close_inf_max:
+            !Double::INFINITY.is_close(Double::MAX, Double::ONE, Double::INFINITY);
+        is_close_max_inf:
+            !Double::MAX.is_close(Double::INFINITY, Double::ONE, Double::INFINITY);
+        is_close_nan_nan:
+            !Double::NAN.is_close(Double::NAN, Double::ONE, Double::INFINITY);
+        is_close_nan_one:
+            !Double::NAN.is_close(Double::ONE, Double::ONE, Double::INFINITY);
+        is_close_one_nan:
+            !Double::ONE.is_close(Double::NAN, Double::ONE, Double::INFINITY);
+    );
+
+    // is_close_default tests
+    test_all_assert!(
+        is_close_default_sqrt:
+            (dd!(2).sqrt().sqr()).is_close_default(dd!(2));
+        is_close_default_sum:
+            (dd!(0.1) + dd!(0.2)).is_close_default(dd!(0.3));
+        is_close_default_sum_not_equal:
+            dd!(0.1) + dd!(0.2) != dd!(0.3);
+        is_close_default_far:
+            !Double::PI.is_close_default(Double::PI + dd!(1e-25));
+        is_close_default_sin_pi:
+            Double::PI.sin().is_close_default(Double::ZERO);
+        is_close_default_tiny:
+            !dd!(1e-20).is_close_default(Double::ZERO);
+        is_close_default_inf:
+            Double::INFINITY.is_close_default(Double::INFINITY);
+        is_close_default_nan:
+            !Double::NAN.is_close_default(Double::NAN);
+    );
+
+    // approx_eq_ulps tests
+    test_all_assert!(
+        approx_eq_ulps_equal:
+            Double::PI.approx_eq_ulps(Double::PI, 0);
+        approx_eq_ulps_one_up:
+            Double::PI.approx_eq_ulps(Double::PI + Double::PI.eps_at(), 1);
+        approx_eq_ulps_one_up_zero:
+            !Double::PI.approx_eq_ulps(Double::PI + Double::PI.eps_at(), 0);
+        approx_eq_ulps_one_down:
+            (Double::PI - Double::PI.eps_at()).approx_eq_ulps(Double::PI, 1);
+        approx_eq_ulps_one_down_zero:
+            !(Double::PI - Double::PI.eps_at()).approx_eq_ulps(Double::PI, 0);
+        approx_eq_ulps_two:
+            !Double::PI.approx_eq_ulps(Double::PI + dd!(2) * Double::PI.eps_at(), 1);
+        approx_eq_ulps_two_enough:
+            Double::PI.approx_eq_ulps(Double::PI + dd!(2) * Double::PI.eps_at(), 2);
+        approx_eq_ulps_neg:
+            (-Double::PI).approx_eq_ulps(-Double::PI - Double::PI.eps_at(), 1);
+        approx_eq_ulps_neg_zero:
+            !(-Double::PI).approx_eq_ulps(-Double::PI - Double::PI.eps_at(), 0);
+        approx_eq_ulps_large:
+            dd!(1e300).approx_eq_ulps(dd!(1e300) + dd!(1e300).eps_at(), 1);
+        approx_eq_ulps_subnormal:
+            Double::ZERO.approx_eq_ulps(Double::ZERO.eps_at() * dd!(3), 3);
+        approx_eq_ulps_subnormal_far:
+            !Double::ZERO.approx_eq_ulps(Double::ZERO.eps_at() * dd!(3), 2);
+        approx_eq_ulps_straddle:
+            (dd!(1) - dd!(2f64.powi(-53)))
+                .approx_eq_ulps(dd!(1) + dd!(8) * dd!(1).eps_at(), (1 << 52) + 8);
+        approx_eq_ulps_straddle_far:
+            !(dd!(1) - dd!(2f64.powi(-53)))
+                .approx_eq_ulps(dd!(1) + dd!(8) * dd!(1).eps_at(), (1 << 52) + 7);
+        approx_eq_ulps_binade_apart:
+            !dd!(1).approx_eq_ulps(dd!(4), u64::MAX);
+        approx_eq_ulps_zero_neg_zero:
+            Double::ZERO.approx_eq_ulps(Double::NEG_ZERO, 0);
+        approx_eq_ulps_signs:
+            !Double::MIN_POSITIVE.approx_eq_ulps(-Double::MIN_POSITIVE, u64::MAX);
+        approx_eq_ulps_signs_zero:
+            !Double::NEG_ZERO.approx_eq_ulps(Double::ZERO.eps_at(), u64::MAX);
+        approx_eq_ulps_signs_one:
+            !dd!(1).approx_eq_ulps(dd!(-1), u64::MAX);
+        approx_eq_ulps_inf_inf:
+            Double::INFINITY.approx_eq_ulps(Double::INFINITY, 0);
+        approx_eq_ulps_inf_max:
+            !Double::INFINITY.approx_eq_ulps(Double::MAX, u64::MAX);
+        approx_eq_ulps_nan:
+            !Double::NAN.approx_eq_ulps(Double::NAN, u64::MAX);
+    );
+
+    // primitive tests
+    test_all_assert!(
+        prim_eq_f64:
+            dd!(1.5) == 1.5 && 1.5 == dd!(1.5);
+        prim_ne_f64:
+            Double::PI != std::f64::consts::PI;
+        prim_ne_f64_rev:
+            std::f64::consts::PI != Double::PI;
+        prim_eq_f64_zero:
+            Double::ZERO == -0.0 && Double::NEG_ZERO == 0.0;
+        prim_eq_f64_inf:
+            Double::INFINITY == f64::INFINITY && f64::NEG_INFINITY == Double::NEG_INFINITY;
+        prim_ne_f64_nan:
+            !Double::NAN.eq(&f64::NAN) && !f64::NAN.eq(&Double::NAN) && !Double::ONE.eq(&f64::NAN);
+        prim_gt_f64:
+            Double::PI > 3.0 && Double::PI > std::f64::consts::PI;
+        prim_lt_f64:
+            3.0 < Double::PI && std::f64::consts::PI < Double::PI;
+        prim_lte_gte_f64:
+            dd!(2) <= 2.0 && dd!(2) >= 2.0 && 2.0 <= dd!(2) && 2.0 >= dd!(2);
+        prim_low_component_f64:
+            Double::ONE + Double::EPSILON > 1.0 && Double::ONE - Double::EPSILON < 1.0;
+        prim_nan_f64:
+            Double::NAN.partial_cmp(&1.0).is_none() && 1.0.partial_cmp(&Double::NAN).is_none();
+        prim_eq_i32:
+            dd!(3) == 3 && 3 == dd!(3) && dd!(-7) == -7i32;
+        prim_ne_i32:
+            dd!(3.5) != 3 && Double::NAN != 0;
+        prim_ord_i32:
+            dd!(3.5) > 3 && dd!(3.5) < 4 && -1 < Double::ZERO && 0 <= Double::NEG_ZERO;
+        prim_eq_i64:
+            Double::from(i64::MAX) == i64::MAX && i64::MIN == Double::from(i64::MIN);
+        prim_ord_i64:
+            Double::from(i64::MAX) - dd!(1) < i64::MAX && i64::MAX > Double::from(i64::MAX) - dd!(0.5);
+        prim_eq_u64:
+            Double::from(u64::MAX) == u64::MAX && u64::MAX == Double::from(u64::MAX);
+        prim_ord_u64:
+            Double::from(u64::MAX) + dd!(0.5) > u64::MAX && u64::MAX < Double::from(u64::MAX) + dd!(1);
+        prim_ne_u64_rounded:
+            // `u64::MAX` isn't an `f64`, so this wouldn't work with a conversion through one
+            dd!(2).powi(64) != u64::MAX && dd!(2).powi(64) > u64::MAX;
+    );
+
+    // canonical_bits tests
+    test_all_assert!(
+        canonical_bits_zero:
+            Double::ZERO.canonical_bits() == [0; 2];
+        canonical_bits_neg_zero:
+            Double::NEG_ZERO.canonical_bits() == [0; 2];
+        canonical_bits_nan:
+            Double::NAN.canonical_bits() == (-Double::NAN).canonical_bits();
+        canonical_bits_nan_payload:
+            Double::NAN.canonical_bits() == (Double::NAN * Double::PI).canonical_bits();
+        canonical_bits_pi:
+            Double::PI.canonical_bits() == [Double::PI.0.to_bits(), Double::PI.1.to_bits()];
+        canonical_bits_neg:
+            Double::PI.canonical_bits() != (-Double::PI).canonical_bits();
+        canonical_bits_inf:
+            Double::INFINITY.canonical_bits() != Double::NEG_INFINITY.canonical_bits();
+    );
+
+    // hash tests
+    fn hash_of(x: Double) -> u64 {
+        use std::collections::hash_map::DefaultHasher;
+        let mut hasher = DefaultHasher::new();
+        x.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    test_all_assert!(
+        hash_equal:
+            hash_of(Double::PI) == hash_of(Double::PI);
+        hash_zero_neg_zero:
+            hash_of(Double::ZERO) == hash_of(Double::NEG_ZERO);
+        hash_computed_zero:
+            hash_of(dd!(-1) + dd!(1) - dd!(0)) == hash_of(-Double::ZERO * dd!(3));
+        hash_nan:
+            hash_of(Double::NAN) == hash_of(-Double::NAN);
+        hash_different:
+            hash_of(Double::PI) != hash_of(Double::E);
+    );
+
+    // total_cmp tests
+    test!(total_cmp_order: {
+        let ordered = [
+            -Double::NAN,
+            Double::NEG_INFINITY,
+            -Double::MAX,
+            -Double::PI,
+            dd!(-1),
+            -Double::MIN_POSITIVE,
+            Double::NEG_ZERO,
+            Double::ZERO,
+            Double::MIN_POSITIVE,
+            dd!(1),
+            Double::E,
+            Double::PI,
+            Double::MAX,
+            Double::INFINITY,
+            Double::NAN,
+        ];
+        for (i, a) in ordered.iter().enumerate() {
+            for (j, b) in ordered.iter().enumerate() {
+                assert!(a.total_cmp(b) == i.cmp(&j), "{:?} vs {:?}", a, b);
+            }
+        }
+    });
+    test!(total_cmp_sort: {
+        let mut v = [Double::PI, Double::NAN, dd!(-1), Double::NEG_ZERO, Double::ZERO, Double::E];
+        v.sort_by(Double::total_cmp);
+        assert!(v[0] == dd!(-1));
+        assert!(v[1].is_zero() && v[1].is_sign_negative());
+        assert!(v[2].is_zero() && v[2].is_sign_positive());
+        assert!(v[3] == Double::E);
+        assert!(v[4] == Double::PI);
+        assert!(v[5].is_nan());
+    });
+    test_all_assert!(
+        total_cmp_low_less:
+            Double(1.0, -1e-20).total_cmp(&Double(1.0, 0.0)) == Ordering::Less;
+        total_cmp_low_greater:
+            Double(1.0, 1e-20).total_cmp(&Double(1.0, 0.0)) == Ordering::Greater;
+        total_cmp_low_neg_zero:
+            Double(1.0, -0.0).total_cmp(&Double(1.0, 0.0)) == Ordering::Equal;
+        total_cmp_neg_low_neg_zero:
+            Double(-1.0, 0.0).total_cmp(&Double(-1.0, -0.0)) == Ordering::Equal;
+    );
 }
@@ -0,0 +1,376 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+#[cfg(feature = "bigdecimal")]
+use crate::common::bigint as b;
+#[cfg(feature = "rust_decimal")]
+use crate::double::common as c;
+#[cfg(feature = "bigdecimal")]
+use crate::double::from::from_quotient;
+use crate::double::Double;
+use crate::error::TryFromDoubleError;
+#[cfg(feature = "bigdecimal")]
+use crate::error::TryFromErrorKind;
+#[cfg(feature = "rust_decimal")]
+use crate::quad::Quad;
+#[cfg(feature = "bigdecimal")]
+use bigdecimal::BigDecimal;
+#[cfg(feature = "bigdecimal")]
+use num_bigint::{BigInt, Sign};
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::Decimal;
+use std::convert::TryFrom;
+
+#[cfg(feature = "rust_decimal")]
+impl From<Decimal> for Double {
+    /// Generates a `Double` from a [`Decimal`].
+    ///
+    /// A `Decimal` is a 96-bit integer divided by a power of ten no larger than 10²⁸. The
+    /// division is done with the full precision of a [`Quad`] and the quotient is then
+    /// rounded to a `Double`, so the result is as close to the `Decimal` as a `Double` can
+    /// be, give or take the last bit. A `Decimal` with a scale of 0 converts exactly.
+    ///
+    /// This is only available when the `rust_decimal` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let x = Double::from(Decimal::new(-15, 1));
+    /// assert!(x == dd!(-1.5));
+    ///
+    /// // 0.3333333333333333333333333333, which is as close to 1/3 as a `Decimal` gets
+    /// let third = Decimal::ONE / Decimal::from(3);
+    /// assert!((Double::from(third) - dd!(1) / dd!(3)).abs() < dd!(1e-28));
+    /// ```
+    ///
+    /// [`Decimal`]: https://docs.rs/rust_decimal/1/rust_decimal/struct.Decimal.html
+    /// [`Quad`]: struct.Quad.html
+    fn from(d: Decimal) -> Double {
+        c::from_quad(Quad::from(d))
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl TryFrom<Double> for Decimal {
+    type Error = TryFromDoubleError;
+
+    /// Converts a `Double` into the nearest [`Decimal`], or returns an error if the `Double`
+    /// is `NaN`, infinite, or too large in magnitude.
+    ///
+    /// A `Decimal` has a 96-bit mantissa and at most 28 digits after the decimal point, so it
+    /// holds 28 or 29 significant digits, slightly fewer than a `Double`. The `Double` is
+    /// rounded to as many decimal places as will fit, with ties going to the value whose
+    /// last digit is even (the same rounding that `Decimal` does itself), and trailing zeros
+    /// are removed. A `Double` too small to have a non-zero digit in the first 28 places
+    /// becomes 0, while one of magnitude 2⁹⁶ (about 7.9 × 10²⁸) or more is out of range.
+    ///
+    /// This is only available when the `rust_decimal` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use rust_decimal::Decimal;
+    /// use std::convert::TryFrom;
+    ///
+    /// let x = Decimal::try_from(dd!(-1.5)).unwrap();
+    /// assert!(x == Decimal::new(-15, 1));
+    ///
+    /// let pi = Decimal::try_from(Double::PI).unwrap();
+    /// assert!(pi.to_string() == "3.1415926535897932384626433833");
+    ///
+    /// assert!(Decimal::try_from(dd!(1e30)).is_err());
+    /// assert!(Decimal::try_from(Double::NAN).is_err());
+    /// ```
+    ///
+    /// [`Decimal`]: https://docs.rs/rust_decimal/1/rust_decimal/struct.Decimal.html
+    fn try_from(x: Double) -> Result<Decimal, TryFromDoubleError> {
+        // Multiplying a `Double` by a power of ten in quad-double precision is exact, so
+        // this rounds only once
+        Decimal::try_from(c::to_quad(x)).map_err(|e| TryFromDoubleError { kind: e.kind })
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl From<&BigDecimal> for Double {
+    /// Generates a `Double` from a reference to a [`BigDecimal`].
+    ///
+    /// A `BigDecimal` is an integer times a power of ten, and that exact value is rounded to
+    /// the nearest `Double` one component at a time, just like a [`BigInt`] (or a quotient
+    /// of them, if the power is negative). A number too large in magnitude to be
+    /// represented becomes [`INFINITY`] or [`NEG_INFINITY`], and one too small becomes zero.
+    ///
+    /// This is only available when the `bigdecimal` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use bigdecimal::BigDecimal;
+    ///
+    /// let x: BigDecimal = "-1.5".parse().unwrap();
+    /// assert!(Double::from(&x) == dd!(-1.5));
+    ///
+    /// let s = "2.71828182845904523536028747135266";
+    /// let e: BigDecimal = s.parse().unwrap();
+    /// assert!(Double::from(&e) == s.parse::<Double>().unwrap());
+    /// ```
+    ///
+    /// [`BigDecimal`]: https://docs.rs/bigdecimal/0.4/bigdecimal/struct.BigDecimal.html
+    /// [`BigInt`]: https://docs.rs/num-bigint/0.4/num_bigint/struct.BigInt.html
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    fn from(d: &BigDecimal) -> Double {
+        let (n, scale) = d.as_bigint_and_exponent();
+        let negative = n.sign() == Sign::Minus;
+        // A rough base-10 exponent, so that huge powers of ten don't get calculated for
+        // numbers that are far out of range anyway
+        let exp = n.bits() as f64 * std::f64::consts::LOG10_2 - scale as f64;
+        if n.sign() == Sign::NoSign || exp < -400.0 {
+            if negative {
+                Double::NEG_ZERO
+            } else {
+                Double::ZERO
+            }
+        } else if exp > 400.0 {
+            if negative {
+                Double::NEG_INFINITY
+            } else {
+                Double::INFINITY
+            }
+        } else if scale <= 0 {
+            Double::from(&(n * BigInt::from(10).pow((-scale) as u32)))
+        } else {
+            from_quotient(&n, &BigInt::from(10).pow(scale as u32))
+        }
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl TryFrom<Double> for BigDecimal {
+    type Error = TryFromDoubleError;
+
+    /// Converts a `Double` into a [`BigDecimal`], exactly, or returns an error if the
+    /// `Double` is `NaN` or infinite.
+    ///
+    /// Every finite `Double` is a sum of `f64`s, each of which is an integer times a power of
+    /// two, so it has a decimal expansion that terminates. The result has its trailing zeros
+    /// removed. Zero becomes 0, whatever its sign.
+    ///
+    /// This is only available when the `bigdecimal` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use bigdecimal::BigDecimal;
+    /// use std::convert::TryFrom;
+    ///
+    /// let x = BigDecimal::try_from(dd!(-2.375)).unwrap();
+    /// assert!(x == "-2.375".parse::<BigDecimal>().unwrap());
+    ///
+    /// // No `Double` is exactly 0.1, so its decimal expansion goes on for a while
+    /// let tenth = BigDecimal::try_from(dd!(0.1)).unwrap();
+    /// assert!(tenth != "0.1".parse::<BigDecimal>().unwrap());
+    /// assert!((Double::from(&tenth) - dd!(0.1)).abs() < dd!(1e-31));
+    /// ```
+    ///
+    /// [`BigDecimal`]: https://docs.rs/bigdecimal/0.4/bigdecimal/struct.BigDecimal.html
+    fn try_from(x: Double) -> Result<BigDecimal, TryFromDoubleError> {
+        if x.is_nan() {
+            Err(TryFromDoubleError {
+                kind: TryFromErrorKind::NaN,
+            })
+        } else if x.is_infinite() {
+            Err(TryFromDoubleError {
+                kind: TryFromErrorKind::Infinite,
+            })
+        } else {
+            Ok(b::to_bigdecimal(&[x.0, x.1]))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::TryFromErrorKind;
+
+    #[cfg(feature = "rust_decimal")]
+    fn dec(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    fn big(s: &str) -> BigDecimal {
+        s.parse().unwrap()
+    }
+
+    // from_decimal tests
+    #[cfg(feature = "rust_decimal")]
+    test_all_exact!(
+        from_decimal_frac:
+            dd!(-1.5),
+            Double::from(Decimal::new(-15, 1));
+        from_decimal_zero:
+            Double::ZERO,
+            Double::from(Decimal::ZERO);
+        from_decimal_max:
+            dd!(2).powi(96) - dd!(1),
+            Double::from(Decimal::MAX);
+        from_decimal_min:
+            dd!(1) - dd!(2).powi(96),
+            Double::from(Decimal::MIN);
+    );
+    #[cfg(feature = "rust_decimal")]
+    test!(from_decimal_inexact: {
+        near!(dd!(1) / dd!(10).powi(28), Double::from(dec("0.0000000000000000000000000001")));
+        near!(dd!(-123456789) / dd!(1000), Double::from(dec("-123456.789")));
+        let third = Double::from(Decimal::ONE / Decimal::from(3));
+        assert!((third - dd!(1) / dd!(3)).abs() < dd!(1e-28));
+    });
+
+    // to_decimal tests
+    #[cfg(feature = "rust_decimal")]
+    test!(to_decimal_exact: {
+        assert_eq!(Decimal::try_from(dd!(-1.5)), Ok(Decimal::new(-15, 1)));
+        assert_eq!(Decimal::try_from(dd!(0)), Ok(Decimal::ZERO));
+        assert_eq!(Decimal::try_from(Double::NEG_ZERO), Ok(Decimal::ZERO));
+        assert_eq!(Decimal::try_from(dd!(2).powi(96) - dd!(1)), Ok(Decimal::MAX));
+        assert_eq!(Decimal::try_from(dd!(1) - dd!(2).powi(96)), Ok(Decimal::MIN));
+        let x = dd!(12345678901234567890u64) + dd!(0.125);
+        assert_eq!(Decimal::try_from(x), Ok(dec("12345678901234567890.125")));
+    });
+    #[cfg(feature = "rust_decimal")]
+    test!(to_decimal_rounded: {
+        let round = |x: Double| Decimal::try_from(x).unwrap().to_string();
+        assert_eq!(round(Double::PI), "3.1415926535897932384626433833");
+        assert_eq!(round(dd!(1) / dd!(3)), "0.3333333333333333333333333333");
+        assert_eq!(round(dd!(-2) / dd!(3)), "-0.6666666666666666666666666667");
+        assert_eq!(round(Double::E * dd!(1e20)), "271828182845904523536.02874714");
+        assert_eq!(round(dd!(6e-29)), "0.0000000000000000000000000001");
+        assert_eq!(round(dd!(4e-29)), "0");
+    });
+    #[cfg(feature = "rust_decimal")]
+    test!(to_decimal_ties: {
+        // Each of these is exactly halfway between two `Decimal`s
+        let round = |x: Double| Decimal::try_from(x).unwrap().to_string();
+        assert_eq!(round(dd!(2).powi(-29)), "0.0000000018626451492309570312");
+        assert_eq!(round(dd!(3) * dd!(2).powi(-29)), "0.0000000055879354476928710938");
+        assert_eq!(round(-dd!(2).powi(-30)), "-0.0000000009313225746154785156");
+        let big = Double::from(dec("70000000000000000000000000000"));
+        assert_eq!(round(big + dd!(0.5)), "70000000000000000000000000000");
+        assert_eq!(round(big + dd!(1.5)), "70000000000000000000000000002");
+    });
+    #[cfg(feature = "rust_decimal")]
+    test!(to_decimal_round_trip: {
+        for s in ["0.0000000000000000000000000001", "-123456.789", "3.1415926535897932384626433833"] {
+            assert_eq!(Decimal::try_from(Double::from(dec(s))), Ok(dec(s)));
+        }
+        for d in [Decimal::MAX, Decimal::MIN, Decimal::ONE / Decimal::from(7)] {
+            assert_eq!(Decimal::try_from(Double::from(d)), Ok(d));
+        }
+    });
+    #[cfg(feature = "rust_decimal")]
+    test!(to_decimal_errors: {
+        let kind = |x: Double| Decimal::try_from(x).unwrap_err().kind;
+        assert_eq!(kind(Double::NAN), TryFromErrorKind::NaN);
+        assert_eq!(kind(Double::INFINITY), TryFromErrorKind::Infinite);
+        assert_eq!(kind(Double::NEG_INFINITY), TryFromErrorKind::Infinite);
+        assert_eq!(kind(dd!(2).powi(96)), TryFromErrorKind::OutOfRange);
+        assert_eq!(kind(-dd!(2).powi(96)), TryFromErrorKind::OutOfRange);
+        assert_eq!(kind(dd!(1e300)), TryFromErrorKind::OutOfRange);
+        // This one only reaches 2⁹⁶ when it's rounded
+        assert_eq!(kind(dd!(2).powi(96) - dd!(0.25)), TryFromErrorKind::OutOfRange);
+
+        let message = Decimal::try_from(Double::NAN).unwrap_err().to_string();
+        assert_eq!(message, "cannot convert NaN double-double");
+    });
+
+    // from_bigdecimal tests
+    #[cfg(feature = "bigdecimal")]
+    test_all_exact!(
+        from_bigdecimal_frac:
+            dd!(-1.5),
+            Double::from(&big("-1.5"));
+        from_bigdecimal_zero:
+            Double::ZERO,
+            Double::from(&big("0"));
+        from_bigdecimal_int:
+            Double::from(&BigInt::from(10).pow(40)),
+            Double::from(&big("1e40"));
+        from_bigdecimal_overflow:
+            Double::INFINITY,
+            Double::from(&big("1e400"));
+        from_bigdecimal_neg_overflow:
+            Double::NEG_INFINITY,
+            Double::from(&big("-1e400"));
+        from_bigdecimal_underflow:
+            Double::ZERO,
+            Double::from(&big("1e-400"));
+        from_bigdecimal_huge_exp:
+            Double::INFINITY,
+            Double::from(&BigDecimal::new(BigInt::from(1), -1_000_000_000_000));
+        from_bigdecimal_tiny_exp:
+            Double::ZERO,
+            Double::from(&BigDecimal::new(BigInt::from(1), 1_000_000_000_000));
+    );
+    #[cfg(feature = "bigdecimal")]
+    test!(from_bigdecimal_inexact: {
+        near!(Double::PI, Double::from(&big("3.14159265358979323846264338327950288")));
+        near!(dd!(1) / dd!(3), Double::from(&big("0.33333333333333333333333333333333333")));
+        near!(dd!(1) / dd!(10).powi(30), Double::from(&big("1e-30")));
+        let x = Double::from(&big("-1e-400"));
+        assert!(x.is_zero() && x.is_sign_negative());
+    });
+    #[cfg(feature = "bigdecimal")]
+    test!(from_bigdecimal_nearest: {
+        // Parsing is correctly rounded, so both have to find the same nearest `Double`
+        for s in &["0.1", "-2.5e-300", "1e-320", "123456789012345678901234567890.123456789e50"] {
+            assert!(Double::from(&big(s)) == s.parse::<Double>().unwrap());
+        }
+    });
+
+    // to_bigdecimal tests
+    #[cfg(feature = "bigdecimal")]
+    test!(to_bigdecimal_exact: {
+        let exact = |x: Double| BigDecimal::try_from(x).unwrap();
+        assert_eq!(exact(dd!(-2.375)), big("-2.375"));
+        assert_eq!(exact(Double::NEG_ZERO), big("0"));
+        assert_eq!(exact(dd!(-12345)), big("-12345"));
+        assert_eq!(exact(dd!(2).powi(100)), BigDecimal::from(BigInt::from(1) << 100));
+        assert_eq!(
+            exact(Double(0.1, 0.0)),
+            big("0.1000000000000000055511151231257827021181583404541015625")
+        );
+        assert_eq!(
+            exact(Double::MIN_POSITIVE),
+            BigDecimal::new(BigInt::from(5).pow(1022), 1022)
+        );
+    });
+    #[cfg(feature = "bigdecimal")]
+    test!(to_bigdecimal_components: {
+        let x = Double(2f64.powi(60), -0.5);
+        assert_eq!(BigDecimal::try_from(x).unwrap(), big("1152921504606846975.5"));
+        let x = Double(1.5, 2f64.powi(-60));
+        assert_eq!(
+            BigDecimal::try_from(x).unwrap(),
+            big("1.500000000000000000867361737988403547205962240695953369140625")
+        );
+    });
+    #[cfg(feature = "bigdecimal")]
+    test!(to_bigdecimal_round_trip: {
+        for x in [Double::PI, -Double::E, Double::LN_2 * dd!(1e200), Double::SQRT_2 / dd!(1e200)] {
+            let d = BigDecimal::try_from(x).unwrap();
+            near!(x, Double::from(&d));
+        }
+    });
+    #[cfg(feature = "bigdecimal")]
+    test!(to_bigdecimal_errors: {
+        let kind = |x: Double| BigDecimal::try_from(x).unwrap_err().kind;
+        assert_eq!(kind(Double::NAN), TryFromErrorKind::NaN);
+        assert_eq!(kind(Double::INFINITY), TryFromErrorKind::Infinite);
+        assert_eq!(kind(Double::NEG_INFINITY), TryFromErrorKind::Infinite);
+    });
+}
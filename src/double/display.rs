@@ -4,22 +4,42 @@
 // https://opensource.org/licenses/MIT
 
 use crate::common::display as d;
+use crate::digits::DecimalDigits;
 use crate::double::Double;
+use crate::options::FormatOptions;
+use crate::round::RoundingMode;
 use std::char;
-use std::fmt::{Debug, Display, Formatter, LowerExp, Result, UpperExp};
+use std::fmt::{Debug, Display, Formatter, LowerExp, LowerHex, Result, UpperExp, UpperHex, Write};
 
 const TEN: Double = Double(10.0, 0.0);
 const MAX_ACCURACY: usize = 31;
 
+// Digits are generated this many at a time. A chunk is less than 10^9, which is small
+// enough that it's exact in the first component, and an integer part that big fits
+// easily into a `u32`.
+const CHUNK_DIGITS: usize = 9;
+const CHUNK_SCALE: f64 = 1e9;
+
+// Room for the digit before the point and enough chunks after it to reach one past the
+// accuracy, which is the most that digit extraction ever produces.
+const DIGITS_LEN: usize = MAX_ACCURACY + CHUNK_DIGITS + 1;
+
 impl Display for Double {
     /// Formats a `Double` for display.
     ///
     /// All formatting options that are shown in [`std::fmt`] are supported *except* for
-    /// ones that are typically meant only for integers (hexadecimal, binary, octal, and
-    /// pointer formats). Because of this, the "alternate" (`#`) flag is only recognized
-    /// along with `?`, pretty-printing the `Debug` output.
+    /// ones that are typically meant only for integers (binary, octal, and pointer formats).
+    /// Width, fill, alignment, the `+` flag, and sign-aware zero fill work exactly as they do
+    /// for `f64`. That means that zero fill goes after the sign and overrides any fill and
+    /// alignment, and that `NaN` is never given a sign. The "alternate" (`#`) flag is the
+    /// one difference. It has no effect on an `f64`, but `{:#}` follows the decimal value
+    /// with the raw components of the `Double` in parentheses (after any padding, which only
+    /// applies to the decimal value), and `{:#?}` pretty-prints the `Debug` output.
     ///
     /// By default, `Double`s are printed with 31 digits but drop trailing zeros.
+    /// A precision of fewer places than those digits reach rounds the exact value of the
+    /// `Double` instead, with ties going to even, so that `format!("{:.1}", dd!(2.25))` is
+    /// `2.2`, just as it is for an `f64`. A longer precision pads the digits with zeros.
     ///
     /// This function also provides the formatting for [`to_string`], which renders the
     /// `Double` as if formatted with an empty format specifier (`"{}"`).
@@ -36,13 +56,17 @@ impl Display for Double {
     /// // to_string renders as if formatted with "{}"
     /// assert!(Double::PI.to_string() == "3.14159265358979323846264338328");
     ///
+    /// // alternate
+    /// assert!(format!("{:#}", dd!(1.5)) == "1.5 (1.5e0, 0e0)");
+    ///
     /// // debug
     /// assert!(format!("{:?}", Double::PI) ==
     ///     "Double(3.141592653589793e0, 1.2246467991473532e-16)");
     /// assert!(format!("{:#?}", Double::PI) ==
     /// "Double(
-    ///     3.141592653589793e0,
-    ///     1.2246467991473532e-16
+    ///     3.141592653589793e0 (0x1.921fb54442d18p+1),
+    ///     1.2246467991473532e-16 (0x1.1a62633145c07p-53),
+    ///     normalized: true,
     /// )");
     ///
     /// // precision and exponents
@@ -70,6 +94,8 @@ impl Display for Double {
     /// assert!(format!("{:+}", value) == "+123456");
     /// assert!(format!("{:0>10}", -value) == "000-123456");
     /// assert!(format!("{:010}", -value) == "-000123456");
+    /// assert!(format!("{:<010}", -value) == "-000123456");
+    /// assert!(format!("{:+010}", Double::NAN) == "0000000NaN");
     /// assert!(format!("{:+012e}", value) == "+001.23456e5");
     /// ```
     ///
@@ -90,6 +116,10 @@ impl Display for Double {
         }
         d::align_and_fill(&mut result, signed, f);
 
+        if f.alternate() {
+            result.extend(format!(" ({:e}, {:e})", self.0, self.1).chars());
+        }
+
         write!(f, "{}", result.into_iter().collect::<String>())
     }
 }
@@ -100,6 +130,7 @@ impl LowerExp for Double {
     /// See [`Display::fmt`](#method.fmt-1) for more information.
     fn fmt(&self, f: &mut Formatter) -> Result {
         let mut result = vec![];
+        let mut exp = 0;
         let signed = push_sign(&mut result, self, f);
 
         if self.is_nan() {
@@ -109,15 +140,10 @@ impl LowerExp for Double {
         } else if self.is_zero() {
             d::push_zero(&mut result, f);
         } else {
-            push_digits_exp(&mut result, self, f);
+            exp = push_digits_exp(&mut result, self, f);
         }
 
         if self.is_finite() {
-            let exp = if self.is_zero() {
-                0
-            } else {
-                self.0.abs().log10().floor() as i32
-            };
             d::push_exp(&mut result, 'e', exp)
         }
 
@@ -133,6 +159,7 @@ impl UpperExp for Double {
     /// See [`Display::fmt`](#method.fmt-1) for more information.
     fn fmt(&self, f: &mut Formatter) -> Result {
         let mut result = vec![];
+        let mut exp = 0;
         let signed = push_sign(&mut result, self, f);
 
         if self.is_nan() {
@@ -142,15 +169,10 @@ impl UpperExp for Double {
         } else if self.is_zero() {
             d::push_zero(&mut result, f);
         } else {
-            push_digits_exp(&mut result, self, f);
+            exp = push_digits_exp(&mut result, self, f);
         }
 
         if self.is_finite() {
-            let exp = if self.is_zero() {
-                0
-            } else {
-                self.0.abs().log10().floor() as i32
-            };
             d::push_exp(&mut result, 'E', exp)
         }
 
@@ -160,33 +182,548 @@ impl UpperExp for Double {
     }
 }
 
+impl LowerHex for Double {
+    /// Formats a `Double` as a hexadecimal floating-point number when the "`x`" formatting
+    /// option is specified.
+    ///
+    /// The output is in the form that C99 uses for hex floats (and that `printf`'s `%a`
+    /// produces), like `0x1.8p+3` for 12: a leading `1`, a point and hex digits if there are
+    /// any, and a `p` followed by the power of two in decimal. It's the exact value of the
+    /// `Double`, which is the sum of its components, with as many digits as that takes. Since
+    /// it's exact, it parses back into exactly the same `Double`, and it's a way to pass exact
+    /// values to and from other software that reads and writes hex floats.
+    ///
+    /// Zero is `0x0p+0` and keeps its sign, and `NaN` and the infinities are the same as they
+    /// are for [`Display`](#method.fmt-1). Precision is ignored, as the output is always
+    /// exact, but the sign, width, fill, and alignment options work as they do there.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(format!("{:x}", dd!(12)) == "0x1.8p+3");
+    /// assert!(format!("{:x}", dd!(-0.0625)) == "-0x1p-4");
+    ///
+    /// let x = dd!(1) / dd!(3);
+    /// assert!(format!("{:x}", x).parse::<Double>().unwrap() == x);
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        fmt_hex(self, f, false)
+    }
+}
+
+impl UpperHex for Double {
+    /// Formats a `Double` as a hexadecimal floating-point number when the "`X`" formatting
+    /// option is specified.
+    ///
+    /// This is the same as the [`LowerHex`](#impl-LowerHex-for-Double) output, except that
+    /// the letters are uppercase (as they are for `printf`'s `%A`).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(format!("{:X}", dd!(-0.75)) == "-0X1.8P-1");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        fmt_hex(self, f, true)
+    }
+}
+
 impl Debug for Double {
     /// Formats a `Double` for display when the "`?`" formatting option is specified.
     ///
+    /// This shows the components of the `Double` in scientific notation. With the alternate
+    /// flag (`{:#?}`), each component goes on its own line, both in scientific notation
+    /// and as an exact hexadecimal float (the same as [`LowerHex`] writes), which shows
+    /// bit for bit what's stored. That's followed by whether the components are
+    /// normalized, meaning that each one is small enough that adding it to the one before
+    /// it leaves that one unchanged, as they should be after any arithmetic.
+    ///
     /// See [`Display::fmt`](#method.fmt-1) for more information.
+    ///
+    /// [`LowerHex`]: #impl-LowerHex-for-Double
     fn fmt(&self, f: &mut Formatter) -> Result {
-        let alt = f.alternate();
-        let mut str = String::from("Double(");
-        if alt {
-            str.push_str("\n    ");
+        if f.alternate() {
+            d::write_debug_alt(f, "Double", &[self.0, self.1])
+        } else {
+            write!(f, "Double({:e}, {:e})", self.0, self.1)
+        }
+    }
+}
+
+impl Double {
+    /// Writes the `Double` to `w` exactly as `{}` formats it, without allocating any memory.
+    ///
+    /// This produces the same string as [`to_string`], but it hands the characters
+    /// straight to `w` instead of building a `String`, and formatting it doesn't use the
+    /// heap at all. That makes it suitable for logging or writing to a fixed buffer in
+    /// places where allocation is unwelcome. Any error from `w` is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use std::fmt::Write;
+    ///
+    /// let mut s = String::new();
+    /// Double::PI.write_to(&mut s).unwrap();
+    /// write!(s, " and ").unwrap();
+    /// dd!(-0.25).write_to(&mut s).unwrap();
+    /// assert!(s == "3.14159265358979323846264338328 and -0.25");
+    /// ```
+    ///
+    /// [`to_string`]: #impl-ToString-for-Double
+    pub fn write_to<W: Write>(self, w: &mut W) -> Result {
+        if self.is_nan() {
+            return w.write_str("NaN");
         }
-        str.push_str(format!("{:e},", self.0).as_str());
-        if alt {
-            str.push_str("\n    ");
+        if self.is_sign_negative() {
+            w.write_char('-')?;
+        }
+        if self.is_infinite() {
+            w.write_str("inf")
+        } else if self.is_zero() {
+            w.write_char('0')
         } else {
-            str.push(' ');
+            let mut digits = [0; DIGITS_LEN];
+            let (len, exp) = fill_digits(&self.abs(), &mut digits);
+            d::write_fixed(w, &digits[..len], exp)
+        }
+    }
+
+    /// Writes the `Double` into the start of `buf` exactly as `{}` formats it, without
+    /// allocating any memory, and returns the number of bytes written.
+    ///
+    /// This does what [`write_to`] does, but into a plain byte slice, for when there's no
+    /// `fmt::Write` to write to. The bytes are always ASCII. No `Double` takes more than 327
+    /// bytes, so a buffer of that size is always big enough.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is too small to hold the formatted `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let mut buf = [0; 64];
+    /// let len = dd!(1.5).format_into(&mut buf);
+    /// assert!(&buf[..len] == b"1.5");
+    ///
+    /// let len = Double::NEG_INFINITY.format_into(&mut buf);
+    /// assert!(&buf[..len] == b"-inf");
+    /// ```
+    ///
+    /// [`write_to`]: #method.write_to
+    pub fn format_into(self, buf: &mut [u8]) -> usize {
+        let mut w = d::ByteWriter::new(buf);
+        self.write_to(&mut w)
+            .expect("buffer too small for formatted Double");
+        w.written()
+    }
+
+    /// Formats the `Double` as the shortest decimal string that parses back into exactly
+    /// the same `Double`.
+    ///
+    /// This is analogous to how `f64`'s `Display` works, and it differs from the `Double`
+    /// `Display` implementation, which always produces up to 31 significant digits. The
+    /// digits are generated from the exact value of the `Double` (the sum of its
+    /// components), and the result is the shortest string of them, with the last digit
+    /// rounded to the nearest, that [`parse`] turns back into this `Double`. Since parsing
+    /// is correctly rounded, there always is one. Most `Double`s need 32 or 33 significant
+    /// digits to be told apart from their neighbors, one or two more than `Display` prints,
+    /// and values that are short in decimal get short strings. A `Double` whose low
+    /// component is much smaller than usual (relative to the high one) can have neighbors
+    /// very close to it, and the string for it can be correspondingly long.
+    ///
+    /// Like `f64`'s `Display`, the string is in fixed notation. `NaN`, infinities, and
+    /// zeros are rendered the same as they are by `Display`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(0.5).to_shortest_string() == "0.5");
+    /// assert!(dd!(1234500).to_shortest_string() == "1234500");
+    /// assert!(dd!("2.5e-10").to_shortest_string() == "0.00000000025");
+    ///
+    /// let x = dd!(1) / dd!(3);
+    /// assert!(x.to_shortest_string().parse::<Double>().unwrap() == x);
+    /// ```
+    ///
+    /// [`parse`]: #impl-FromStr-for-Double
+    pub fn to_shortest_string(self) -> String {
+        if !self.is_finite() || self.is_zero() {
+            return self.to_string();
+        }
+
+        let abs = self.abs();
+        let mut result = vec![];
+        if self.is_sign_negative() {
+            result.push('-');
+        }
+        d::push_radix(&mut result, &[self.0, self.1], 10, |s| {
+            s.parse::<Double>().ok() == Some(abs)
+        });
+        result.into_iter().collect()
+    }
+
+    /// Formats the `Double` as the exact decimal expansion of the value that it stores.
+    ///
+    /// Every finite `Double` is the sum of its components, each of which is a dyadic
+    /// rational (an integer times a power of two), so its value has a decimal expansion
+    /// that ends. This writes out every digit of it in fixed notation, which can mean
+    /// hundreds of digits (over a thousand, for a number with a component near the
+    /// smallest subnormal `f64`). That's much more than it takes to identify the `Double`,
+    /// which [`to_shortest_string`] does, but it shows exactly what's stored, which is
+    /// useful for looking into questions of normalization and rounding.
+    ///
+    /// `NaN`, infinities, and zeros are rendered the same as they are by `Display`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(0.375).to_exact_decimal() == "0.375");
+    /// assert!((dd!(1) / dd!(1024)).to_exact_decimal() == "0.0009765625");
+    ///
+    /// // One third is stored as a number a little less than it
+    /// let third = (dd!(1) / dd!(3)).to_exact_decimal();
+    /// assert!(third.starts_with("0.333333333333333333333333333333332306"));
+    /// assert!(dd!(-1e22).to_exact_decimal() == "-10000000000000000000000");
+    /// ```
+    ///
+    /// [`to_shortest_string`]: #method.to_shortest_string
+    pub fn to_exact_decimal(self) -> String {
+        if !self.is_finite() || self.is_zero() {
+            return self.to_string();
+        }
+
+        let mut result = vec![];
+        if self.is_sign_negative() {
+            result.push('-');
+        }
+        d::push_exact(&mut result, &[self.0, self.1]);
+        result.into_iter().collect()
+    }
+
+    /// Returns an iterator over the exact decimal digits of the `Double`, most significant
+    /// first.
+    ///
+    /// These are the same digits that [`to_exact_decimal`] writes out, from the first one
+    /// that isn't zero to the last one that isn't zero, without a sign or a decimal point.
+    /// The iterator's [`exp`] is the power of ten of the first digit, which is all that it
+    /// takes to put them in their places. The digits are worked out one at a time as
+    /// they're asked for, so a caller that needs only a few of them (a formatter with its
+    /// own rules, a conversion to some other number type, or anything else that can stop
+    /// early) doesn't pay for the hundreds that a full expansion can have.
+    ///
+    /// Zero, the infinities, and `NaN` have no digits.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let digits = dd!(-0.0625).digits();
+    /// assert!(digits.exp() == -2);
+    /// assert!(digits.collect::<Vec<_>>() == vec![6, 2, 5]);
+    ///
+    /// let pi: Vec<u8> = Double::PI.digits().take(6).collect();
+    /// assert!(pi == vec![3, 1, 4, 1, 5, 9]);
+    /// assert!(Double::ZERO.digits().next().is_none());
+    /// ```
+    ///
+    /// [`to_exact_decimal`]: #method.to_exact_decimal
+    /// [`exp`]: struct.DecimalDigits.html#method.exp
+    pub fn digits(self) -> DecimalDigits {
+        DecimalDigits::new(&[self.0, self.1])
+    }
+
+    /// Formats the `Double` in engineering notation, which is scientific notation with an
+    /// exponent that's a multiple of three.
+    ///
+    /// There are one to three digits before the decimal point, so the exponent always
+    /// matches one of the SI prefixes (kilo, milli, and so on), which makes the magnitudes
+    /// in reports easy to read at a glance. The digits are the same ones that the `LowerExp`
+    /// format (`{:e}`) produces, up to 31 significant digits with trailing zeros
+    /// dropped. `NaN`, infinities, and zeros are rendered the same as they are by
+    /// `LowerExp`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(1234.5).to_engineering_string() == "1.2345e3");
+    /// assert!(dd!(123450).to_engineering_string() == "123.45e3");
+    /// assert!(dd!("-0.00025").to_engineering_string() == "-250e-6");
+    /// assert!(dd!(1e6).to_engineering_string() == "1e6");
+    /// ```
+    pub fn to_engineering_string(self) -> String {
+        d::engineering(&format!("{:e}", self))
+    }
+
+    /// Formats the `Double` in fixed notation with the digit group separator, decimal
+    /// separator, and precision in `options`.
+    ///
+    /// This is the `Display` output (with the precision in `options`, if there is one),
+    /// with the group separator put between each group of three digits in the integer part
+    /// and the decimal separator in place of the period. No separators are put into the
+    /// fractional part. [`from_str_with`] can parse the result with the same separators.
+    /// `NaN` and the infinities are rendered the same as they are by `Display`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, FormatOptions, Double};
+    /// let options = FormatOptions {
+    ///     group_separator: Some(','),
+    ///     precision: Some(2),
+    ///     ..FormatOptions::default()
+    /// };
+    /// assert!(dd!(1234567.128).to_string_with(&options) == "1,234,567.13");
+    /// assert!(dd!(-999).to_string_with(&options) == "-999.00");
+    ///
+    /// let options = FormatOptions {
+    ///     group_separator: Some(' '),
+    ///     decimal_separator: ',',
+    ///     precision: None,
+    /// };
+    /// assert!(dd!(1e6).to_string_with(&options) == "1 000 000");
+    /// ```
+    ///
+    /// [`from_str_with`]: #method.from_str_with
+    pub fn to_string_with(self, options: &FormatOptions) -> String {
+        let s = match options.precision {
+            Some(prec) => format!("{:.*}", prec, self),
+            None => self.to_string(),
+        };
+        d::separate(&s, options)
+    }
+
+    /// Formats the `Double` in fixed notation with exactly `places` digits after the decimal
+    /// point, rounded with the given mode.
+    ///
+    /// The rounding is done on the exact value of the `Double` (the sum of its components),
+    /// so a number like 2.5, which is exactly halfway between 2 and 3, is a tie that the
+    /// mode decides, while one that's just above halfway always rounds up with the modes
+    /// that round to the nearest. With `HalfEven`, this gives the same digits as a
+    /// precision in a format string, like `format!("{:.2}", x)`, does. There are as many
+    /// digits before the point as the number needs, and there are no trailing zeros
+    /// dropped, which makes this suitable for currency amounts and other output that has to
+    /// line up.
+    ///
+    /// `NaN` and the infinities are rendered the same as they are by `Display`. A negative
+    /// number that rounds to zero keeps its minus sign, as it does with `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, RoundingMode};
+    /// assert!(dd!(2.5).to_fixed(0, RoundingMode::HalfEven) == "2");
+    /// assert!(dd!(2.5).to_fixed(0, RoundingMode::HalfAwayFromZero) == "3");
+    /// assert!(dd!(-2.25).to_fixed(1, RoundingMode::HalfEven) == "-2.2");
+    /// assert!(dd!(1234.5678).to_fixed(2, RoundingMode::TowardZero) == "1234.56");
+    /// assert!(dd!(0.001).to_fixed(2, RoundingMode::Up) == "0.01");
+    /// assert!(dd!(7).to_fixed(3, RoundingMode::HalfEven) == "7.000");
+    /// ```
+    pub fn to_fixed(self, places: usize, mode: RoundingMode) -> String {
+        if !self.is_finite() {
+            return self.to_string();
+        }
+
+        // Any places past the exact value's last digit are zeros, which are added at the end
+        let exact = places.min(d::EXACT_DIGITS);
+        let negative = self.is_sign_negative();
+        let digits = d::round_decimal(&[self.0, self.1], exact as i32, negative, mode);
+        let mut result = vec![];
+        if negative {
+            result.push('-');
+        }
+        d::push_decimal(&mut result, &digits, exact as i32);
+        result.extend((exact..places).map(|_| '0'));
+        result.into_iter().collect()
+    }
+
+    /// Formats the `Double` in fixed notation with exactly `digits` significant digits,
+    /// rounded with the given mode.
+    ///
+    /// As with [`to_fixed`], the rounding is done on the exact value of the `Double`. Trailing
+    /// zeros are kept when they're significant, so 1.5 with three digits is `1.50`. When
+    /// the number is large enough that the last significant digit is before the decimal
+    /// point, the digits after it are zeros, and there's no decimal point at all. Zero has
+    /// `digits - 1` zeros after its decimal point. `NaN` and the infinities are rendered
+    /// the same as they are by `Display`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `digits` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, RoundingMode};
+    /// assert!(Double::PI.to_precision(5, RoundingMode::HalfEven) == "3.1416");
+    /// assert!(Double::PI.to_precision(5, RoundingMode::TowardZero) == "3.1415");
+    /// assert!(dd!(1.5).to_precision(3, RoundingMode::HalfEven) == "1.50");
+    /// assert!(dd!(0.000123456).to_precision(2, RoundingMode::HalfAwayFromZero) == "0.00012");
+    /// assert!(dd!(123456).to_precision(2, RoundingMode::HalfEven) == "120000");
+    /// assert!(dd!(99.96).to_precision(3, RoundingMode::HalfEven) == "100");
+    /// ```
+    ///
+    /// [`to_fixed`]: #method.to_fixed
+    pub fn to_precision(self, digits: usize, mode: RoundingMode) -> String {
+        assert!(
+            digits > 0,
+            "to_precision: the number of digits must be at least 1"
+        );
+        if !self.is_finite() {
+            return self.to_string();
         }
-        str.push_str(format!("{:e}", self.1).as_str());
-        if alt {
-            str.push('\n');
+
+        let parts = [self.0, self.1];
+        let negative = self.is_sign_negative();
+        // As in `to_fixed`, digits past the exact value's last one are zeros added at the end
+        let exact = digits.min(d::EXACT_DIGITS);
+        let mut places = exact as i32 - 1 - d::leading_exp(&parts).unwrap_or(0);
+        let mut rounded = d::round_decimal(&parts, places, negative, mode);
+
+        // Rounding up can carry into a new leading digit (as it does when 99.96 is rounded
+        // to three digits), in which case the last digit is a zero that isn't significant
+        if rounded.len() > exact {
+            rounded.pop();
+            places -= 1;
+        }
+        let mut result = vec![];
+        if negative {
+            result.push('-');
+        }
+        d::push_decimal(&mut result, &rounded, places);
+        result.extend((exact..digits).map(|_| '0'));
+        result.into_iter().collect()
+    }
+
+    /// Formats the `Double` as a string in the given radix, which can be anything from 2 to 36.
+    ///
+    /// This mirrors `to_str_radix` for big integers (and `from_str_radix` for all integers).
+    /// Digits past 9 are the lowercase letters `a` through `z`, and there's no exponent,
+    /// since `e` is a digit in the larger radices. The result is the shortest string in the
+    /// radix that [`from_str_radix`] parses back into exactly the same `Double`, with the last
+    /// digit rounded to the nearest. In an even radix, every `Double` has an exact finite
+    /// expansion, so the string is never longer than that. In an odd radix, only integers
+    /// do, and since the neighbors of a `Double` can be as close to it as the smallest
+    /// subnormal `f64` (the lower components can be that small), the shortest string that
+    /// parses back can have hundreds of digits.
+    ///
+    /// `NaN`, infinities, and zeros are rendered the same as they are by `Display`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(-10.75).to_string_radix(2) == "-1010.11");
+    /// assert!(dd!(255.5).to_string_radix(16) == "ff.8");
+    /// assert!(dd!(35).to_string_radix(36) == "z");
+    ///
+    /// let x = dd!(1) / dd!(3);
+    /// assert!(Double::from_str_radix(&x.to_string_radix(2), 2).unwrap() == x);
+    /// ```
+    ///
+    /// [`from_str_radix`]: #method.from_str_radix
+    pub fn to_string_radix(self, radix: u32) -> String {
+        assert!(
+            (2..=36).contains(&radix),
+            "to_string_radix: radix must lie in the range `[2, 36]`, found {}",
+            radix
+        );
+        if !self.is_finite() || self.is_zero() {
+            return self.to_string();
+        }
+
+        let abs = self.abs();
+        let mut result = vec![];
+        if self.is_sign_negative() {
+            result.push('-');
         }
-        str.push(')');
-        write!(f, "{}", str)
+        d::push_radix(&mut result, &[self.0, self.1], radix, |s| {
+            Double::from_str_radix(s, radix) == Ok(abs)
+        });
+        result.into_iter().collect()
     }
+
+    /// Formats the `Double` as a canonical decimal string, which writes out its two
+    /// components exactly, in the form `[-]d.ddd...e±dd±d.ddd...e±dd`.
+    ///
+    /// Unlike the output of `Display`, which drops trailing zeros and switches between
+    /// notations, the canonical string always has the same shape. That makes it suitable
+    /// for text formats where output has to be stable from run to run. Each component has
+    /// exactly 17 significant digits, which is always enough to read back the same `f64`,
+    /// and its exponent has at least two digits and always carries a sign. The second
+    /// component always has a sign too, which separates it from the first. `NaN` and the
+    /// infinities are rendered as the fixed tokens `NaN`, `inf`, and `-inf`, and negative
+    /// zero keeps its sign.
+    ///
+    /// [`from_canonical`] parses these strings back into exactly the same `Double`s. No
+    /// fixed number of digits of the value itself could do that, since the second
+    /// component of a `Double` can be far smaller than the last bit of the first.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(1.5).to_canonical() == "1.5000000000000000e+00+0.0000000000000000e+00");
+    /// assert!(dd!(-0.125).to_canonical() == "-1.2500000000000000e-01+0.0000000000000000e+00");
+    /// assert!(Double::PI.to_canonical() == "3.1415926535897931e+00+1.2246467991473532e-16");
+    /// ```
+    ///
+    /// [`from_canonical`]: #method.from_canonical
+    pub fn to_canonical(self) -> String {
+        if !self.is_finite() {
+            return self.to_string();
+        }
+        let mut result = canonical_component(self.0);
+        let low = canonical_component(self.1);
+        if !low.starts_with('-') {
+            result.push('+');
+        }
+        result.push_str(&low);
+        result
+    }
+}
+
+// Writes an `f64` in scientific notation with exactly 17 significant digits and an exponent
+// that has a sign and at least two digits. That's the shape of each component of a canonical
+// string.
+fn canonical_component(x: f64) -> String {
+    let s = format!("{:.16e}", x);
+    let (mantissa, exp) = s.split_at(s.find('e').unwrap());
+    let exp = exp[1..].parse::<i32>().unwrap();
+    let exp_sign = if exp < 0 { '-' } else { '+' };
+    format!("{}e{}{:02}", mantissa, exp_sign, exp.abs())
+}
+
+fn fmt_hex(value: &Double, f: &mut Formatter, upper: bool) -> Result {
+    let mut result = vec![];
+    let signed = push_sign(&mut result, value, f);
+
+    if value.is_nan() {
+        d::push_nan(&mut result);
+    } else if value.is_infinite() {
+        d::push_inf(&mut result);
+    } else {
+        d::push_hex(&mut result, &[value.0, value.1], upper);
+    }
+
+    // Sign-aware zero padding goes between the `0x` and the digits, as it does for integers
+    match f.width() {
+        Some(width) if f.sign_aware_zero_pad() && value.is_finite() => {
+            let index = signed as usize + 2;
+            while result.len() < width {
+                result.insert(index, '0');
+            }
+        }
+        _ => d::align_and_fill(&mut result, signed, f),
+    }
+
+    write!(f, "{}", result.into_iter().collect::<String>())
 }
 
 fn push_sign(chars: &mut Vec<char>, value: &Double, f: &Formatter) -> bool {
-    if value.is_sign_negative() {
+    // Like `f64`, `NaN` is never printed with a sign, even with the `+` flag
+    if value.is_nan() {
+        false
+    } else if value.is_sign_negative() {
         chars.push('-');
         true
     } else if f.sign_plus() {
@@ -199,26 +736,32 @@ fn push_sign(chars: &mut Vec<char>, value: &Double, f: &Formatter) -> bool {
 
 fn push_digits_fixed(chars: &mut Vec<char>, value: &Double, f: &mut Formatter) {
     let value = value.abs();
-    let exp = value.0.log10().floor() as i32;
     let prec = f.precision();
 
-    let mut digits = extract_digits(&value, exp);
+    let (mut digits, exp) = extract_digits(&value);
     d::adjust_zeros(&mut digits, exp);
-    d::adjust_prec(&mut digits, exp, prec);
+    d::adjust_prec_exact(&mut digits, exp, prec, &[value.0, value.1], 0);
 
     chars.append(&mut d::place_decimal(digits, exp));
 }
 
-fn push_digits_exp(chars: &mut Vec<char>, value: &Double, f: &mut Formatter) {
+// Pushes the mantissa of the number in scientific notation and returns its exponent. If
+// rounding carries all the way up to a new leading digit, that digit is a 1 followed by
+// zeros, so it becomes the only digit before the point and the exponent goes up by one.
+fn push_digits_exp(chars: &mut Vec<char>, value: &Double, f: &mut Formatter) -> i32 {
     let value = value.abs();
-    let exp = value.0.log10().floor() as i32;
     let prec = f.precision();
 
-    let mut digits = extract_digits(&value, exp);
+    let (mut digits, mut exp) = extract_digits(&value);
     d::adjust_zeros(&mut digits, 0);
-    d::adjust_prec(&mut digits, 0, prec);
+    d::adjust_prec_exact(&mut digits, 0, prec, &[value.0, value.1], exp);
+    if digits[0] == 10 {
+        digits[0] = 1;
+        exp += 1;
+    }
 
     chars.append(&mut d::place_decimal(digits, 0));
+    exp
 }
 
 // Extracts the decimal digits of `value` into an array of unsigned integers.
@@ -226,24 +769,65 @@ fn push_digits_exp(chars: &mut Vec<char>, value: &Double, f: &mut Formatter) {
 // This function assumes that `value` is positive. Zero and non-finite values are handled
 // before we get to this function, and the sign is already pushed to the output vector. With
 // that assumption, this function will return a vector of numbers from 0-9 - digits.
-fn extract_digits(value: &Double, exp: i32) -> Vec<u8> {
+fn extract_digits(value: &Double) -> (Vec<u8>, i32) {
+    let mut digits = [0; DIGITS_LEN];
+    let (len, exp) = fill_digits(value, &mut digits);
+    (digits[..len].to_vec(), exp)
+}
+
+// Does the work of `extract_digits` without allocating, putting the digits at the front of
+// `digits` and returning how many of them there are along with the exponent.
+fn fill_digits(value: &Double, digits: &mut [u8; DIGITS_LEN]) -> (usize, i32) {
     // Normalize the number to have an exponent of 0 (i.e., one digit before the decimal
     // point). We don't actually otherwise need the exponent in this function, as all we're
     // doing is parsing digits from the mantissa. This normalization makes the math involved
     // much faster. It also ensures that really large numbers don't overflow on
     // multiplication by ten.
-    let divisor = TEN.powi(exp);
-
-    let mut value = value / divisor;
-    let mut digits = vec![];
-
-    for _ in 0..(MAX_ACCURACY + 1) {
-        let digit = value.0.trunc();
-
-        value -= Double(digit, 0.0);
-        value *= TEN;
+    let mut exp = value.0.log10().floor() as i32;
+    let max = d::POWERS_OF_TEN.len() as i32;
+    let mut value = if exp == 0 {
+        *value
+    } else if exp > 0 && exp < max {
+        value / d::POWERS_OF_TEN[exp as usize]
+    } else if exp < 0 && -exp < max {
+        value * d::POWERS_OF_TEN[-exp as usize]
+    } else if exp > 0 {
+        value / TEN.powi(exp)
+    } else if exp >= -308 {
+        // A power of ten this small isn't accurate, since its lower components are
+        // subnormal, so this multiplies by the reciprocal and not dividing by it
+        value * TEN.powi(-exp)
+    } else {
+        // The reciprocal overflows when the number is subnormal, so an exact 10^22 (which
+        // brings the number into the normal range) takes some of it
+        value * d::POWERS_OF_TEN[max as usize - 1] * TEN.powi(-exp - max + 1)
+    };
+
+    // The exponent of the first component can be one more or one less than the exponent of
+    // the whole number, either because `log10` isn't exact or because the lower components
+    // take the number across a power of ten, and this puts it right
+    if value < Double::ONE {
+        value *= 10.0;
+        exp -= 1;
+    } else if value >= TEN {
+        value /= 10.0;
+        exp += 1;
+    }
 
-        digits.push(digit as u8);
+    // The digit before the decimal point comes off by itself, and the rest come off a chunk
+    // at a time. Scaling by a power of ten with an `f64` is much cheaper than a full
+    // multiplication, and doing it once per chunk rather than once per digit cuts the
+    // number of operations by almost the size of a chunk.
+    digits[0] = take_integer(&mut value) as u8;
+    let mut len = 1;
+    while len < MAX_ACCURACY + 1 {
+        value *= CHUNK_SCALE;
+        let mut chunk = take_integer(&mut value);
+        for digit in digits[len..(len + CHUNK_DIGITS)].iter_mut().rev() {
+            *digit = (chunk % 10) as u8;
+            chunk /= 10;
+        }
+        len += CHUNK_DIGITS;
     }
 
     // We will not record digits after the 323rd (308 for the largest negative exponent,
@@ -254,10 +838,30 @@ fn extract_digits(value: &Double, exp: i32) -> Vec<u8> {
     // is at position exp + 1 in a negative exponent number.
     //
     // If this isn't an issue, we still truncate by one because we produced an extra digit
-    // for rounding.
-    d::round_and_trunc(&mut digits, (324 + exp).min(MAX_ACCURACY as i32) as usize);
+    // for rounding. A subnormal below 10^-323 still keeps its leading digit.
+    let len = (324 + exp).clamp(1, MAX_ACCURACY as i32) as usize;
+    d::round_digits(digits, len);
+
+    // If rounding carried all the way up to a new leading digit, that digit is a 10 followed
+    // by nothing but zeros, which is a 1 with an exponent one higher
+    if digits[0] == 10 {
+        digits[0] = 1;
+        exp += 1;
+    }
+    (len, exp)
+}
 
-    digits
+// Removes the integer part from a non-negative `Double` and returns it. The first component
+// can be a whole number even when the components after it add up to something negative,
+// so the integer part isn't always the whole part of the first component.
+fn take_integer(value: &mut Double) -> u32 {
+    let mut int = value.0.trunc();
+    *value -= int;
+    if value.0 < 0.0 {
+        int -= 1.0;
+        *value += 1.0;
+    }
+    int as u32
 }
 
 #[cfg(test)]
@@ -273,12 +877,115 @@ mod tests {
             "Double(3.141592653589793e0, 1.2246467991473532e-16)",
             format!("{:?}", Double::PI);
         debug_alt_zero:
-            "Double(\n    0e0,\n    0e0\n)",
+            "Double(\n    0e0 (0x0p+0),\n    0e0 (0x0p+0),\n    normalized: true,\n)",
             format!("{:#?}", Double::ZERO);
         debug_alt_pi:
-            "Double(\n    3.141592653589793e0,\n    1.2246467991473532e-16\n)",
+            concat!(
+                "Double(\n",
+                "    3.141592653589793e0 (0x1.921fb54442d18p+1),\n",
+                "    1.2246467991473532e-16 (0x1.1a62633145c07p-53),\n",
+                "    normalized: true,\n",
+                ")"
+            ),
             format!("{:#?}", Double::PI);
+        debug_alt_neg_zero:
+            "Double(\n    -0e0 (-0x0p+0),\n    -0e0 (-0x0p+0),\n    normalized: true,\n)",
+            format!("{:#?}", Double(-0.0, -0.0));
+        debug_alt_nan:
+            "Double(\n    NaN (NaN),\n    0e0 (0x0p+0),\n    normalized: true,\n)",
+            format!("{:#?}", Double::NAN);
+        debug_alt_inf:
+            "Double(\n    -inf (-inf),\n    0e0 (0x0p+0),\n    normalized: true,\n)",
+            format!("{:#?}", Double::NEG_INFINITY);
+        debug_alt_unnormalized:
+            concat!(
+                "Double(\n",
+                "    1e0 (0x1p+0),\n",
+                "    -1.1102230246251565e-16 (-0x1p-53),\n",
+                "    normalized: false,\n",
+                ")"
+            ),
+            format!("{:#?}", Double(1.0, -f64::EPSILON / 2.0));
+    );
+    test_all_assert!(
+        debug_alt_normalized_half_ulp:
+            format!("{:#?}", Double(1.0, f64::EPSILON / 2.0)).contains("normalized: true");
+        debug_alt_after_zero:
+            format!("{:#?}", Double(0.0, 1e-300)).contains("normalized: false");
+        debug_alt_after_nan:
+            format!("{:#?}", Double(f64::NAN, 1.0)).contains("normalized: false");
+        debug_alt_arithmetic:
+            format!("{:#?}", Double::E * Double::PI - Double::LN_2).contains("normalized: true");
+    );
+
+    // alternate tests
+    test_all_eq!(
+        alt_pi:
+            "3.14159265358979323846264338328 (3.141592653589793e0, 1.2246467991473532e-16)",
+            format!("{:#}", Double::PI);
+        alt_zero:
+            "0 (0e0, 0e0)",
+            format!("{:#}", Double::ZERO);
+        alt_neg_zero:
+            "-0 (-0e0, 0e0)",
+            format!("{:#}", Double::NEG_ZERO);
+        alt_inf:
+            "inf (inf, 0e0)",
+            format!("{:#}", Double::INFINITY);
+        alt_nan:
+            "NaN (NaN, 0e0)",
+            format!("{:#}", Double::NAN);
+        alt_neg:
+            "-1.5 (-1.5e0, -0e0)",
+            format!("{:#}", -dd!(1.5));
+        alt_width:
+            "   1.5 (1.5e0, 0e0)",
+            format!("{:>#6}", dd!(1.5));
+        alt_zero_fill:
+            "-001.5 (-1.5e0, -0e0)",
+            format!("{:#06}", -dd!(1.5));
+        alt_precision:
+            "3.1416 (3.141592653589793e0, 1.2246467991473532e-16)",
+            format!("{:#.4}", Double::PI);
+        alt_exp:
+            "1.5e0",
+            format!("{:#e}", dd!(1.5));
     );
+    test!(alt_differs: {
+        let plain = format!("{}", Double::E);
+        let alt = format!("{:#}", Double::E);
+        assert!(plain != alt);
+        assert!(alt.starts_with(&plain));
+        assert!(alt.contains(&format!("{:e}", Double::E.0)));
+        assert!(alt.contains(&format!("{:e}", Double::E.1)));
+    });
+
+    // f64 compatibility tests
+    test!(flags_match_f64: {
+        let values = [
+            1.5,
+            -1.5,
+            0.0,
+            -0.0,
+            123_456.0,
+            -0.25,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NAN,
+            -f64::NAN,
+        ];
+        macro_rules! assert_same {
+            ($($spec:literal),*) => {
+                for &x in &values {
+                    $(assert_eq!(format!($spec, Double::from(x)), format!($spec, x));)*
+                }
+            };
+        }
+        assert_same!("{}", "{:10}", "{:<10}", "{:^10}", "{:>10}", "{:*^11}", "{:+}", "{:+10}");
+        assert_same!("{:010}", "{:+010}", "{:<010}", "{:^+010}", "{:_>010}");
+        assert_same!("{:+.3}", "{:<+12.3}", "{:e}", "{:10e}", "{:<+12e}", "{:012E}", "{:#e}");
+        assert_same!("{:^+012.2e}", "{:x<+14.3E}", "{:+010e}");
+    });
 
     // special number tests
     test_all_eq!(
@@ -286,13 +993,13 @@ mod tests {
             "NaN",
             format!("{}", Double::NAN);
         neg_nan:
-            "-NaN",
+            "NaN",
             format!("{}", -Double::NAN);
         plus_nan:
-            "+NaN",
+            "NaN",
             format!("{:+}", Double::NAN);
         plus_neg_nan:
-            "-NaN",
+            "NaN",
             format!("{:+}", -Double::NAN);
         inf:
             "inf",
@@ -382,6 +1089,141 @@ mod tests {
             format!("{:+}", dd!("1.234567890123456789e-308"));
     );
 
+    // digit generation tests
+    test!(digits_match_exact: {
+        // The digits of `Display` are the exact digits of the number, rounded half up,
+        // including when a lower component is negative and when the digits cross from one
+        // chunk to the next
+        fn significand(s: &str) -> String {
+            let s = s.split('e').next().unwrap().replace(['-', '.'], "");
+            s.trim_start_matches('0').trim_end_matches('0').to_string()
+        }
+        for &x in &[
+            Double::PI,
+            Double::E * dd!(1e20),
+            Double::LN_2 / dd!(1e30),
+            dd!("123456789.123456789"),
+            dd!(1) / dd!(7),
+            Double(5.0, -1e-20),
+            Double(1e10, -1e-10),
+            Double(-0.5, -1e-25),
+        ] {
+            let exact = x.to_precision(31, RoundingMode::HalfAwayFromZero);
+            assert!(significand(&format!("{:e}", x)) == significand(&exact), "{:?}", x);
+            assert!(significand(&format!("{}", x)) == significand(&exact), "{:?}", x);
+        }
+    });
+
+    test_all_eq!(
+        digits_carry_exp:
+            "1e1",
+            format!("{:.0e}", dd!(9.5));
+        digits_carry_exp_prec:
+            "1.0e2",
+            format!("{:.1e}", dd!(99.5));
+        digits_carry_exp_neg:
+            "-1.00e-2",
+            format!("{:.2e}", dd!(-0.009999));
+        digits_carry_exp_upper:
+            "1E3",
+            format!("{:.0E}", dd!(999.9));
+        digits_lower_crosses_power:
+            "9.9999999999e9",
+            format!("{:.10e}", Double(1e10, -1e-1));
+    );
+
+    // Subnormals get as many digits as reach the place of 10^-323, but always at least one
+    test_all_eq!(
+        digits_subnormal_min:
+            format!("0.{}5", "0".repeat(323)),
+            format!("{}", Double(f64::from_bits(1), 0.0));
+        digits_subnormal_min_exp:
+            "5e-324",
+            format!("{:e}", Double(f64::from_bits(1), 0.0));
+        digits_subnormal_min_prec:
+            "0.000",
+            format!("{:.3}", Double(f64::from_bits(1), 0.0));
+        digits_subnormal_neg:
+            format!("-0.{}1", "0".repeat(319)),
+            format!("{}", Double(-1e-320, 0.0));
+        digits_subnormal_neg_exp:
+            "-1e-320",
+            format!("{:e}", Double(-1e-320, 0.0));
+        digits_subnormal_exp:
+            "2.5e-310",
+            format!("{:e}", Double(2.5e-310, 0.0));
+        digits_subnormal_exp_upper:
+            "2.5E-310",
+            format!("{:E}", Double(2.5e-310, 0.0));
+    );
+
+    // writer tests
+    fn format_into(x: Double) -> String {
+        let mut buf = [0; 327];
+        let len = x.format_into(&mut buf);
+        String::from_utf8(buf[..len].to_vec()).unwrap()
+    }
+
+    test!(write_to_matches_display: {
+        for &x in &[
+            Double::PI,
+            -Double::E * dd!(1e300),
+            Double::LN_2 / dd!(1e300),
+            Double::MAX,
+            -Double::MIN_POSITIVE,
+            Double(f64::from_bits(1), 0.0),
+            Double(-1e-320, 0.0),
+            Double(2.5e-310, 0.0),
+            dd!(1) / dd!(7),
+            dd!(1234.5),
+            Double::ZERO,
+            Double::NEG_ZERO,
+            Double::INFINITY,
+            Double::NEG_INFINITY,
+            Double::NAN,
+        ] {
+            let mut s = String::new();
+            x.write_to(&mut s).unwrap();
+            assert!(s == x.to_string(), "{:?}", x);
+            assert!(format_into(x) == x.to_string(), "{:?}", x);
+        }
+    });
+    test!(format_into_longest: {
+        // The smallest normal number with every digit takes a lot of room, but a subnormal
+        // below 10^-323 has one more zero before its only digit and takes the most
+        let x = -Double::MIN_POSITIVE;
+        let mut buf = [0; 327];
+        assert!(x.format_into(&mut buf) == x.to_string().len());
+        assert!(x.to_string().len() == 326);
+        let x = Double(-f64::from_bits(1), 0.0);
+        assert!(x.format_into(&mut buf) == 327);
+        assert!(x.to_string().len() == 327);
+    });
+    test!(format_into_exact_fit: {
+        let mut buf = [0; 4];
+        assert!(dd!(-2.5).format_into(&mut buf) == 4);
+        assert!(&buf == b"-2.5");
+    });
+    #[test]
+    #[should_panic(expected = "buffer too small for formatted Double")]
+    fn format_into_too_small() {
+        let mut buf = [0; 3];
+        dd!(-2.5).format_into(&mut buf);
+    }
+
+    // The digits round up to a power of ten with one more digit before the point
+    test_all_eq!(
+        carry_to_one:
+            "1",
+            format!("{}", dd!("0.999999999999999999999999999999999"));
+        carry_to_ten:
+            "-10",
+            format!("{}", dd!("-9.999999999999999999999999999999999"));
+        carry_small:
+            "0.0001",
+            format!("{}", dd!("9.999999999999999999999999999999999e-5"));
+    );
+
     // precision tests
     test_all_eq!(
         prec_zero:
@@ -536,6 +1378,21 @@ mod tests {
             format!("{:10.5}", Double::PI);
     );
 
+    test_all_eq!(
+        prec_tie_even_down_exp:
+            "2.2e0",
+            format!("{:.1e}", dd!(2.25));
+        prec_tie_even_down_neg_exp:
+            "-2.2e0",
+            format!("{:.1e}", dd!(-2.25));
+        prec_tie_small_exp:
+            "1.2e-1",
+            format!("{:.1e}", dd!(0.125));
+        prec_above_tie_exp:
+            "2.3e0",
+            format!("{:.1e}", Double(2.25, 1e-20));
+    );
+
     // right-align tests
     test_all_eq!(
         zero_right:
@@ -712,13 +1569,13 @@ mod tests {
             "NaN",
             format!("{:e}", Double::NAN);
         neg_nan_lexp:
-            "-NaN",
+            "NaN",
             format!("{:e}", -Double::NAN);
         plus_nan_lexp:
-            "+NaN",
+            "NaN",
             format!("{:+e}", Double::NAN);
         plus_neg_nan_lexp:
-            "-NaN",
+            "NaN",
             format!("{:+e}", -Double::NAN);
         inf_lexp:
             "inf",
@@ -752,13 +1609,13 @@ mod tests {
             "NaN",
             format!("{:E}", Double::NAN);
         neg_nan_uexp:
-            "-NaN",
+            "NaN",
             format!("{:E}", -Double::NAN);
         plus_nan_uexp:
-            "+NaN",
+            "NaN",
             format!("{:+E}", Double::NAN);
         plus_neg_nan_uexp:
-            "-NaN",
+            "NaN",
             format!("{:+E}", -Double::NAN);
         inf_uexp:
             "inf",
@@ -916,6 +1773,51 @@ mod tests {
     // not much need to continue to test them both. Further tests are being done solely with
     // LowerExp.
 
+    test_all_eq!(
+        prec_tie_even_down:
+            "2.2",
+            format!("{:.1}", dd!(2.25));
+        prec_tie_even_down_neg:
+            "-2.2",
+            format!("{:.1}", dd!(-2.25));
+        prec_tie_even_up:
+            "2.8",
+            format!("{:.1}", dd!(2.75));
+        prec_tie_small:
+            "0.12",
+            format!("{:.2}", dd!(0.125));
+        prec_tie_zero_places:
+            "2",
+            format!("{:.0}", dd!(2.5));
+        prec_tie_zero_places_up:
+            "4",
+            format!("{:.0}", dd!(3.5));
+        prec_tie_half:
+            "0",
+            format!("{:.0}", dd!(0.5));
+        prec_tie_carry:
+            "10",
+            format!("{:.0}", dd!(9.5));
+        prec_above_tie:
+            "2.3",
+            format!("{:.1}", Double(2.25, 1e-20));
+        prec_below_tie:
+            "2.2",
+            format!("{:.1}", Double(2.25, -1e-20));
+    );
+    test!(prec_matches_f64: {
+        // Every `f64` is a `Double` with the same value, so with a precision short enough
+        // for every digit to be exact, both have to round to the same digits
+        for &x in &[2.25, -2.25, 0.125, 0.375, 2.5, 1.5, 1e-5, 1234.5678, 0.1, 2.0 / 3.0] {
+            for prec in 0..12 {
+                let expected = format!("{:.*}", prec, x);
+                assert!(format!("{:.*}", prec, dd!(x)) == expected, "{} at {}", x, prec);
+                let expected = format!("{:.*e}", prec, x);
+                assert!(format!("{:.*e}", prec, dd!(x)) == expected, "{} at {}", x, prec);
+            }
+        }
+    });
+
     // precision exp tests
     test_all_eq!(
         prec_zero_exp:
@@ -1236,4 +2138,733 @@ mod tests {
             "03.14159e0",
             format!("{:010.5e}", Double::PI);
     );
+
+    // hex tests
+    fn pow2(n: i32) -> f64 {
+        2f64.powi(n)
+    }
+
+    test_all_eq!(
+        hex_one:
+            "0x1p+0",
+            format!("{:x}", Double::ONE);
+        hex_twelve:
+            "0x1.8p+3",
+            format!("{:x}", dd!(12));
+        hex_neg_fraction:
+            "-0x1p-4",
+            format!("{:x}", dd!(-0.0625));
+        hex_zero:
+            "0x0p+0",
+            format!("{:x}", Double::ZERO);
+        hex_neg_zero:
+            "-0x0p+0",
+            format!("{:x}", Double::NEG_ZERO);
+        hex_inf:
+            "inf",
+            format!("{:x}", Double::INFINITY);
+        hex_neg_inf:
+            "-inf",
+            format!("{:x}", Double::NEG_INFINITY);
+        hex_nan:
+            "NaN",
+            format!("{:x}", Double::NAN);
+        hex_pi:
+            "0x1.921fb54442d18469898cc51701cp+1",
+            format!("{:x}", Double::PI);
+        hex_e:
+            "0x1.5bf0a8b1457695355fb8ac404ep+1",
+            format!("{:x}", Double::E);
+        hex_neg_ln_2:
+            "-0x1.62e42fefa39ef35793c76730088p-1",
+            format!("{:x}", -Double::LN_2);
+        hex_max:
+            "0x1.fffffffffffff7ffffffffffffcp+1023",
+            format!("{:x}", Double::MAX);
+        hex_min_positive:
+            "0x1p-1022",
+            format!("{:x}", Double::MIN_POSITIVE);
+        hex_subnormal:
+            "0x1p-1074",
+            format!("{:x}", Double(f64::from_bits(1), 0.0));
+        hex_neg_second:
+            "0x1.fffffffffffffffffffep-1",
+            format!("{:x}", Double(1.0, -pow2(-80)));
+        hex_gap:
+            "0x1.00000000000000000000000000000000000000000000000001p+0",
+            format!("{:x}", Double(1.0, pow2(-200)));
+        hex_upper:
+            "-0X1.921FB54442D18469898CC51701CP+1",
+            format!("{:X}", -Double::PI);
+        hex_upper_zero:
+            "0X0P+0",
+            format!("{:X}", Double::ZERO);
+        hex_plus:
+            "+0x1.8p+3",
+            format!("{:+x}", dd!(12));
+        hex_width:
+            "____0x1.8p+3",
+            format!("{:_>12x}", dd!(12));
+        hex_left:
+            "0x1.8p+3    ",
+            format!("{:<12x}", dd!(12));
+        hex_zero_pad:
+            "-0x0001.8p+3",
+            format!("{:012x}", dd!(-12));
+        hex_zero_pad_inf:
+            "-00000000inf",
+            format!("{:012x}", Double::NEG_INFINITY);
+        hex_precision:
+            "0x1.8p+3",
+            format!("{:.2x}", dd!(12));
+    );
+    test!(hex_round_trip: {
+        for x in [
+            Double::PI,
+            -Double::E,
+            dd!(1) / dd!(3),
+            Double::LN_2 * dd!(1e300),
+            Double::SQRT_2 / dd!(1e300),
+            Double::MAX,
+            Double::MIN_POSITIVE,
+            Double(1.0, -pow2(-80)),
+        ] {
+            assert!(format!("{:x}", x).parse::<Double>().unwrap() == x);
+            assert!(format!("{:X}", x).parse::<Double>().unwrap() == x);
+        }
+    });
+
+    // radix string tests
+    test_all_eq!(
+        radix_string_binary:
+            "-1010.11",
+            dd!(-10.75).to_string_radix(2);
+        radix_string_hex:
+            "ff.8",
+            dd!(255.5).to_string_radix(16);
+        radix_string_36:
+            "z",
+            dd!(35).to_string_radix(36);
+        radix_string_six:
+            "0.3",
+            dd!(0.5).to_string_radix(6);
+        radix_string_decimal:
+            "0.001",
+            dd!(0.001).to_string_radix(10);
+        radix_string_integer:
+            "100000",
+            dd!(243).to_string_radix(3);
+        radix_string_two_parts:
+            format!("1.{}1", "0".repeat(79)),
+            Double(1.0, pow2(-80)).to_string_radix(2);
+        radix_string_neg_second:
+            format!("0.{}", "1".repeat(80)),
+            Double(1.0, -pow2(-80)).to_string_radix(2);
+        radix_string_max:
+            format!("fffffffffffffbffffffffffffe{}", "0".repeat(229)),
+            Double::MAX.to_string_radix(16);
+        radix_string_min_positive:
+            format!("0.{}1", "0".repeat(1021)),
+            Double::MIN_POSITIVE.to_string_radix(2);
+        radix_string_zero:
+            "0",
+            Double::ZERO.to_string_radix(2);
+        radix_string_neg_zero:
+            "-0",
+            Double::NEG_ZERO.to_string_radix(16);
+        radix_string_inf:
+            "inf",
+            Double::INFINITY.to_string_radix(8);
+        radix_string_neg_inf:
+            "-inf",
+            Double::NEG_INFINITY.to_string_radix(8);
+        radix_string_nan:
+            "NaN",
+            Double::NAN.to_string_radix(36);
+    );
+    test!(radix_string_round_trip: {
+        for &radix in &[2, 3, 5, 7, 8, 10, 12, 16, 31, 36] {
+            for &x in &[
+                Double::PI,
+                -Double::E,
+                dd!(1) / dd!(3),
+                Double::LN_2 * dd!(1e100),
+                Double::SQRT_2 / dd!(1e100),
+                Double::MAX,
+            ] {
+                let s = x.to_string_radix(radix);
+                assert!(Double::from_str_radix(&s, radix).unwrap() == x, "{} in radix {}", x, radix);
+            }
+        }
+    });
+    test!(radix_string_shortest: {
+        // None of these strings still parses into the same number once its last digit is
+        // dropped
+        for &radix in &[3, 10, 36] {
+            for &x in &[Double::PI, dd!(1) / dd!(3), dd!(1.5)] {
+                let s = x.to_string_radix(radix);
+                let shorter = &s[..s.len() - 1];
+                assert!(Double::from_str_radix(shorter, radix).unwrap() != x);
+            }
+        }
+    });
+    #[test]
+    #[should_panic(expected = "to_string_radix: radix must lie in the range `[2, 36]`, found 37")]
+    fn radix_string_too_large() {
+        Double::ONE.to_string_radix(37);
+    }
+
+    // shortest string tests
+    test_all_eq!(
+        shortest_half:
+            "0.5",
+            dd!(0.5).to_shortest_string();
+        shortest_neg_half:
+            "-0.5",
+            dd!(-0.5).to_shortest_string();
+        shortest_int:
+            "1234500",
+            dd!(1_234_500).to_shortest_string();
+        shortest_tenth:
+            "0.1",
+            dd!(0.1).to_shortest_string();
+        shortest_small:
+            "0.000125",
+            dd!(0.000125).to_shortest_string();
+        shortest_ln2:
+            "0.69314718055994530941723212145819",
+            Double::LN_2.to_shortest_string();
+        shortest_zero:
+            "0",
+            Double::ZERO.to_shortest_string();
+        shortest_neg_zero:
+            "-0",
+            Double::NEG_ZERO.to_shortest_string();
+        shortest_inf:
+            "inf",
+            Double::INFINITY.to_shortest_string();
+        shortest_neg_inf:
+            "-inf",
+            Double::NEG_INFINITY.to_shortest_string();
+        shortest_nan:
+            "NaN",
+            Double::NAN.to_shortest_string();
+    );
+
+    test!(shortest_round_trip: {
+        // A simple linear congruential generator gives a repeatable spread of values, each
+        // built by parsing a random decimal string of up to 16 digits.
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+        let mut next = || {
+            seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+            seed >> 11
+        };
+        for _ in 0..500 {
+            let digits = next() % 10u64.pow((next() % 16) as u32 + 1);
+            let exp = (next() % 61) as i32 - 30;
+            let x: Double = format!("{}e{}", digits, exp).parse().unwrap();
+
+            // Anything that parses from at most 16 digits can be written back in at most 16
+            let shortest = x.to_shortest_string();
+            let sig = shortest.trim_start_matches(['0', '.']).replace('.', "");
+            assert!(sig.trim_end_matches('0').len() <= 16, "{} is too long", shortest);
+            assert!(shortest.parse::<Double>().unwrap() == x, "{} did not round trip", shortest);
+        }
+    });
+
+    test!(shortest_never_longer: {
+        let mut seed = 0x9e37_79b9_7f4a_7c15u64;
+        let mut next = || {
+            seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+            seed >> 11
+        };
+        for _ in 0..200 {
+            let x = Double::from(next()) / Double::from(next() | 1);
+            let shortest = x.to_shortest_string();
+            assert!(shortest.parse::<Double>().unwrap() == x, "{} did not round trip", shortest);
+
+            // If the 31 digits of `Display` are enough to round trip, the shortest string
+            // can't be any longer than that
+            let full = x.to_string();
+            if full.parse::<Double>().unwrap() == x {
+                assert!(shortest.len() <= full.len(), "{} is longer than {}", shortest, full);
+            }
+        }
+    });
+    test!(shortest_wide_components: {
+        // The neighbors of a `Double` with a tiny low component are very close to it, so it
+        // takes a lot of digits to tell it apart from them
+        let x = Double::ONE + Double::from(2f64.powi(-200));
+        let shortest = x.to_shortest_string();
+        assert!(shortest == "1.0000000000000000000000000000000000000000000000000000000000006223015277861142");
+        assert!(shortest.parse::<Double>().unwrap() == x);
+        assert!(Double::MAX.to_shortest_string().parse::<Double>().unwrap() == Double::MAX);
+    });
+
+    // engineering string tests
+    test_all_eq!(
+        engineering_one:
+            "1.5e0",
+            dd!(1.5).to_engineering_string();
+        engineering_tens:
+            "12e0",
+            dd!(12).to_engineering_string();
+        engineering_thousands:
+            "123.456e3",
+            dd!(123_456).to_engineering_string();
+        engineering_neg_millions:
+            "-10e6",
+            dd!(-1e7).to_engineering_string();
+        engineering_milli:
+            "1e-3",
+            dd!("0.001").to_engineering_string();
+        engineering_small:
+            "12.5e-3",
+            dd!(0.0125).to_engineering_string();
+        engineering_tiny:
+            "250e-9",
+            dd!("2.5e-7").to_engineering_string();
+        engineering_subnormal:
+            "250e-312",
+            Double(2.5e-310, 0.0).to_engineering_string();
+        engineering_subnormal_min:
+            "5e-324",
+            Double(f64::from_bits(1), 0.0).to_engineering_string();
+        engineering_subnormal_tens:
+            "10e-321",
+            Double(1e-320, 0.0).to_engineering_string();
+        engineering_zero:
+            "0e0",
+            Double::ZERO.to_engineering_string();
+        engineering_neg_zero:
+            "-0e0",
+            Double::NEG_ZERO.to_engineering_string();
+        engineering_inf:
+            "inf",
+            Double::INFINITY.to_engineering_string();
+        engineering_neg_inf:
+            "-inf",
+            Double::NEG_INFINITY.to_engineering_string();
+        engineering_nan:
+            "NaN",
+            Double::NAN.to_engineering_string();
+    );
+    test!(engineering_digits: {
+        // The digits are the same as for `LowerExp`, only with the point moved
+        let x = Double::PI * dd!(10_000);
+        let exp = format!("{:e}", x);
+        let eng = x.to_engineering_string();
+        assert!(eng.replace('.', "")[..20] == exp.replace('.', "")[..20]);
+        assert!(eng.ends_with("e3"));
+        assert!(eng.starts_with("31.415926"));
+    });
+
+    // separator string tests
+    test_all_eq!(
+        separated_default:
+            Double::PI.to_string(),
+            Double::PI.to_string_with(&FormatOptions::default());
+        separated_short:
+            "123",
+            dd!(123).to_string_with(&commas(None));
+        separated_one_group:
+            "1,234",
+            dd!(1234).to_string_with(&commas(None));
+        separated_groups:
+            "1,234,567",
+            dd!(1_234_567).to_string_with(&commas(None));
+        separated_neg_fraction:
+            "-123,456.75",
+            dd!(-123_456.75).to_string_with(&commas(None));
+        separated_small:
+            "0.000125",
+            dd!(0.000125).to_string_with(&commas(None));
+        separated_precision:
+            "1,000,000.00",
+            dd!(1e6).to_string_with(&commas(Some(2)));
+        separated_precision_zero:
+            "12,346",
+            dd!(12_345.75).to_string_with(&commas(Some(0)));
+        separated_decimal_comma:
+            "1.234.567,500",
+            dd!(1_234_567.5).to_string_with(&FormatOptions {
+                group_separator: Some('.'),
+                decimal_separator: ',',
+                precision: Some(3),
+            });
+        separated_no_groups:
+            "1234567,5",
+            dd!(1_234_567.5).to_string_with(&FormatOptions {
+                decimal_separator: ',',
+                ..FormatOptions::default()
+            });
+        separated_neg_zero:
+            "-0",
+            Double::NEG_ZERO.to_string_with(&commas(None));
+        separated_inf:
+            "-inf",
+            Double::NEG_INFINITY.to_string_with(&commas(Some(2)));
+        separated_nan:
+            "NaN",
+            Double::NAN.to_string_with(&commas(None));
+    );
+    test!(separated_round_trip: {
+        use crate::options::ParseOptions;
+        let format = FormatOptions {
+            group_separator: Some(' '),
+            decimal_separator: ',',
+            precision: None,
+        };
+        let parse = ParseOptions {
+            group_separator: Some(' '),
+            decimal_separator: ',',
+        };
+        for &x in &[Double::PI * dd!(1e10), -Double::E * dd!(1e20), dd!(1) / dd!(3)] {
+            let s = x.to_string_with(&format);
+            assert!(Double::from_str_with(&s, &parse).unwrap() == x.to_string().parse::<Double>().unwrap());
+        }
+    });
+
+    fn commas(precision: Option<usize>) -> FormatOptions {
+        FormatOptions {
+            group_separator: Some(','),
+            precision,
+            ..FormatOptions::default()
+        }
+    }
+
+    // fixed string tests
+    test_all_eq!(
+        fixed_half_even:
+            "2",
+            dd!(2.5).to_fixed(0, RoundingMode::HalfEven);
+        fixed_half_even_odd:
+            "4",
+            dd!(3.5).to_fixed(0, RoundingMode::HalfEven);
+        fixed_half_even_neg:
+            "-0.12",
+            dd!(-0.125).to_fixed(2, RoundingMode::HalfEven);
+        fixed_half_away:
+            "3",
+            dd!(2.5).to_fixed(0, RoundingMode::HalfAwayFromZero);
+        fixed_half_away_neg:
+            "-0.13",
+            dd!(-0.125).to_fixed(2, RoundingMode::HalfAwayFromZero);
+        fixed_down:
+            "1.23",
+            dd!(1.2399).to_fixed(2, RoundingMode::Down);
+        fixed_down_neg:
+            "-1.24",
+            dd!(-1.2301).to_fixed(2, RoundingMode::Down);
+        fixed_up:
+            "1.24",
+            dd!(1.2301).to_fixed(2, RoundingMode::Up);
+        fixed_up_neg:
+            "-1.23",
+            dd!(-1.2399).to_fixed(2, RoundingMode::Up);
+        fixed_toward_zero:
+            "-1.23",
+            dd!(-1.2399).to_fixed(2, RoundingMode::TowardZero);
+        fixed_exact:
+            "1.25",
+            dd!(1.25).to_fixed(2, RoundingMode::Up);
+        fixed_padded:
+            "1.250000",
+            dd!(1.25).to_fixed(6, RoundingMode::HalfEven);
+        fixed_carry:
+            "10",
+            dd!(9.5).to_fixed(0, RoundingMode::HalfEven);
+        fixed_carry_fraction:
+            "1.0",
+            dd!(0.96875).to_fixed(1, RoundingMode::HalfEven);
+        fixed_small_up:
+            "0.001",
+            dd!(1e-10).to_fixed(3, RoundingMode::Up);
+        fixed_small_down:
+            "0.000",
+            dd!(1e-10).to_fixed(3, RoundingMode::Down);
+        fixed_small_neg:
+            "-0.000",
+            dd!(-1e-10).to_fixed(3, RoundingMode::HalfEven);
+        fixed_large:
+            "123456789012345678901234567890",
+            dd!("123456789012345678901234567890").to_fixed(0, RoundingMode::HalfEven);
+        fixed_zero:
+            "0.00",
+            Double::ZERO.to_fixed(2, RoundingMode::Up);
+        fixed_neg_zero:
+            "-0",
+            Double::NEG_ZERO.to_fixed(0, RoundingMode::HalfEven);
+        fixed_inf:
+            "inf",
+            Double::INFINITY.to_fixed(2, RoundingMode::HalfEven);
+        fixed_neg_inf:
+            "-inf",
+            Double::NEG_INFINITY.to_fixed(2, RoundingMode::HalfEven);
+        fixed_nan:
+            "NaN",
+            Double::NAN.to_fixed(2, RoundingMode::HalfEven);
+    );
+    test!(fixed_exact_value: {
+        // Just past halfway is not a tie, even if it's too close to halfway to show up in
+        // the 31 digits that `Display` works from
+        let x = Double(0.5, 2f64.powi(-100));
+        assert!(x.to_fixed(0, RoundingMode::HalfEven) == "1");
+        assert!(x.to_fixed(0, RoundingMode::TowardZero) == "0");
+        assert!(Double::MAX.to_fixed(0, RoundingMode::HalfEven).len() == 309);
+        assert!(Double::MAX.to_fixed(0, RoundingMode::HalfEven).starts_with("1797693134862315807937"));
+    });
+    test!(fixed_past_exact: {
+        // The smallest subnormal's last digit is 1074 places after the decimal point, and
+        // any places past that are zeros, however many of them there are
+        let x = Double(1.0, f64::from_bits(1));
+        let exact = x.to_fixed(1074, RoundingMode::HalfEven);
+        assert!(exact.ends_with('5'));
+        assert!(x.to_fixed(1080, RoundingMode::Up) == exact.clone() + "000000");
+        assert!(x.to_fixed(3000, RoundingMode::HalfEven) == exact + &"0".repeat(1926));
+    });
+
+    // precision string tests
+    test_all_eq!(
+        precision_pi:
+            "3.1416",
+            Double::PI.to_precision(5, RoundingMode::HalfEven);
+        precision_pi_toward_zero:
+            "3.1415",
+            Double::PI.to_precision(5, RoundingMode::TowardZero);
+        precision_one_digit:
+            "-3",
+            (-Double::PI).to_precision(1, RoundingMode::Up);
+        precision_one_digit_down:
+            "-4",
+            (-Double::PI).to_precision(1, RoundingMode::Down);
+        precision_padded:
+            "1.50",
+            dd!(1.5).to_precision(3, RoundingMode::HalfEven);
+        precision_small:
+            "0.00012",
+            dd!(0.000123456).to_precision(2, RoundingMode::HalfAwayFromZero);
+        precision_large:
+            "120000",
+            dd!(123_456).to_precision(2, RoundingMode::HalfEven);
+        precision_tie:
+            "120000",
+            dd!(125_000).to_precision(2, RoundingMode::HalfEven);
+        precision_tie_up:
+            "130000",
+            dd!(125_000).to_precision(2, RoundingMode::HalfAwayFromZero);
+        precision_carry:
+            "100",
+            dd!(99.96).to_precision(3, RoundingMode::HalfEven);
+        precision_carry_small:
+            "0.0010",
+            dd!(0.000999).to_precision(2, RoundingMode::Up);
+        precision_integer:
+            "7.00",
+            dd!(7).to_precision(3, RoundingMode::HalfEven);
+        precision_zero:
+            "0.00",
+            Double::ZERO.to_precision(3, RoundingMode::HalfEven);
+        precision_neg_zero:
+            "-0",
+            Double::NEG_ZERO.to_precision(1, RoundingMode::HalfEven);
+        precision_inf:
+            "inf",
+            Double::INFINITY.to_precision(3, RoundingMode::HalfEven);
+        precision_nan:
+            "NaN",
+            Double::NAN.to_precision(3, RoundingMode::HalfEven);
+    );
+    test!(precision_long: {
+        // More digits than the type holds gives its exact value
+        assert!(dd!(0.1).to_precision(60, RoundingMode::HalfEven).len() == 63);
+        assert!(dd!(0.375).to_precision(8, RoundingMode::HalfEven) == "0.37500000");
+    });
+    test!(precision_past_exact: {
+        // 308 digits before the decimal point and 1074 after it is as many significant
+        // digits as the type can have, and any more are zeros
+        let x = Double(2f64.powi(1023), f64::from_bits(1));
+        let exact = x.to_precision(1382, RoundingMode::HalfEven);
+        assert!(exact.ends_with('5') && exact.find('.') == Some(308));
+        assert!(x.to_precision(3000, RoundingMode::Down) == exact + &"0".repeat(1618));
+    });
+    #[test]
+    #[should_panic(expected = "to_precision: the number of digits must be at least 1")]
+    fn precision_zero_digits() {
+        Double::ONE.to_precision(0, RoundingMode::HalfEven);
+    }
+
+    // exact decimal tests
+    test_all_eq!(
+        exact_decimal_int:
+            "1234500",
+            dd!(1_234_500).to_exact_decimal();
+        exact_decimal_fraction:
+            "-0.375",
+            dd!(-0.375).to_exact_decimal();
+        exact_decimal_f64:
+            "0.1000000000000000055511151231257827021181583404541015625",
+            Double(f64::from_bits(0x3fb9_9999_9999_999a), 0.0).to_exact_decimal();
+        exact_decimal_zero:
+            "0",
+            Double::ZERO.to_exact_decimal();
+        exact_decimal_neg_zero:
+            "-0",
+            Double::NEG_ZERO.to_exact_decimal();
+        exact_decimal_inf:
+            "inf",
+            Double::INFINITY.to_exact_decimal();
+        exact_decimal_neg_inf:
+            "-inf",
+            Double::NEG_INFINITY.to_exact_decimal();
+        exact_decimal_nan:
+            "NaN",
+            Double::NAN.to_exact_decimal();
+    );
+    test!(exact_decimal_values: {
+        assert!(
+            Double::PI.to_exact_decimal()
+                == concat!(
+                    "3.14159265358979323846264338327950587896697911771466046256921246775800637962",
+                    "5612680683843791484832763671875"
+                )
+        );
+        // 2^-200 in full
+        let digits = concat!(
+            "6223015277861141707144064053780124240590252168721167133101116614789698",
+            "8340353834411839448231257136169569665895551224821247160434722900390625"
+        );
+        assert!(
+            Double(1.0, 2f64.powi(-200)).to_exact_decimal()
+                == format!("1.{}{}", "0".repeat(60), digits)
+        );
+        // The smallest subnormal has 1074 digits after the point
+        let tiny = Double(f64::from_bits(1), 0.0).to_exact_decimal();
+        assert!(tiny.len() == 1076);
+        assert!(tiny.starts_with(&format!("0.{}494065645841246544", "0".repeat(323))));
+        assert!(tiny.ends_with("265533447265625"));
+
+        // Anything exact parses back into the same number
+        for &x in &[Double::PI, -Double::E, dd!(1) / dd!(3), Double::MAX, Double::LN_2 / dd!(1e200)] {
+            assert!(x.to_exact_decimal().parse::<Double>().unwrap() == x);
+        }
+        assert!(Double::MAX.to_exact_decimal() == Double::MAX.to_fixed(0, RoundingMode::HalfEven));
+    });
+
+    // digits tests
+    fn all_digits(x: Double) -> (Vec<u8>, i32) {
+        let digits = x.digits();
+        let exp = digits.exp();
+        (digits.collect(), exp)
+    }
+
+    test_all_eq!(
+        digits_int:
+            (vec![1, 2, 3, 4, 5], 3),
+            all_digits(dd!(-1234.5));
+        digits_fraction:
+            (vec![6, 2, 5], -2),
+            all_digits(dd!(0.0625));
+        digits_trailing_zeros:
+            (vec![1], 22),
+            all_digits(dd!(1e22));
+        digits_inner_zeros:
+            (vec![1, 0, 0, 0, 5], 3),
+            all_digits(dd!(1000.5));
+        digits_zero:
+            (vec![], 0),
+            all_digits(Double::ZERO);
+        digits_neg_zero:
+            (vec![], 0),
+            all_digits(Double::NEG_ZERO);
+        digits_inf:
+            (vec![], 0),
+            all_digits(Double::INFINITY);
+        digits_nan:
+            (vec![], 0),
+            all_digits(Double::NAN);
+    );
+    test!(digits_match_exact_decimal: {
+        for &x in &[
+            Double::PI,
+            -Double::E * dd!(1e300),
+            Double::LN_2 / dd!(1e300),
+            dd!(1) / dd!(3),
+            Double::MAX,
+            Double::MIN_POSITIVE,
+        ] {
+            let (digits, exp) = all_digits(x);
+            let expected: Vec<u8> = x
+                .to_exact_decimal()
+                .bytes()
+                .filter(u8::is_ascii_digit)
+                .map(|b| b - b'0')
+                .skip_while(|&d| d == 0)
+                .collect();
+            let zeros = expected.iter().rev().take_while(|&&d| d == 0).count();
+            assert!(digits == expected[..expected.len() - zeros], "{:?}", x);
+            assert!(exp == format!("{:e}", x).split('e').nth(1).unwrap().parse::<i32>().unwrap());
+        }
+    });
+    test!(digits_subnormal: {
+        // 2^-1074 is 4.940656458412465...e-324, with 751 significant digits
+        let digits = Double::from(f64::from_bits(1)).digits();
+        assert!(digits.exp() == -324);
+        assert!(digits.clone().take(7).collect::<Vec<_>>() == vec![4, 9, 4, 0, 6, 5, 6]);
+        assert!(digits.count() == 751);
+    });
+
+    // canonical string tests
+    test_all_eq!(
+        canonical_one:
+            "1.0000000000000000e+00+0.0000000000000000e+00",
+            Double::ONE.to_canonical();
+        canonical_neg:
+            "-1.2500000000000000e-01+0.0000000000000000e+00",
+            dd!(-0.125).to_canonical();
+        canonical_pi:
+            "3.1415926535897931e+00+1.2246467991473532e-16",
+            Double::PI.to_canonical();
+        canonical_large:
+            "1.7976931348623157e+308+9.9792015476735980e+291",
+            Double::MAX.to_canonical();
+        canonical_small:
+            "2.2250738585072014e-308+0.0000000000000000e+00",
+            Double::MIN_POSITIVE.to_canonical();
+        canonical_tiny:
+            "4.9406564584124654e-324+0.0000000000000000e+00",
+            Double(f64::from_bits(1), 0.0).to_canonical();
+        canonical_neg_low:
+            "1.0000000000000000e+01-1.0000000000000001e-31",
+            (dd!(10) - Double(1e-31, 0.0)).to_canonical();
+        canonical_gap:
+            "1.0000000000000000e+00+1.0000000000000000e-300",
+            Double(1.0, 1e-300).to_canonical();
+        canonical_zero:
+            "0.0000000000000000e+00+0.0000000000000000e+00",
+            Double::ZERO.to_canonical();
+        canonical_neg_zero:
+            "-0.0000000000000000e+00+0.0000000000000000e+00",
+            Double::NEG_ZERO.to_canonical();
+        canonical_inf:
+            "inf",
+            Double::INFINITY.to_canonical();
+        canonical_neg_inf:
+            "-inf",
+            Double::NEG_INFINITY.to_canonical();
+        canonical_nan:
+            "NaN",
+            Double::NAN.to_canonical();
+    );
+    test!(canonical_shape: {
+        for x in [Double::E, dd!(-1e100), dd!(7e-300), Double::LN_2, dd!(123_456_789)] {
+            let s = x.to_canonical();
+            let unsigned = s.strip_prefix('-').unwrap_or(&s);
+            let exp = unsigned.find('e').unwrap() + 2;
+            let mid = exp + unsigned[exp..].find(|c: char| !c.is_ascii_digit()).unwrap();
+            let (high, low) = unsigned.split_at(mid);
+            assert!(high.len() >= 22 && &high[1..2] == "." && &high[18..19] == "e", "{}", s);
+            assert!(low.len() >= 23 && &low[2..3] == "." && &low[19..20] == "e", "{}", s);
+        }
+    });
 }
@@ -3,9 +3,12 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::binary128 as b;
 use crate::common::primitive as p;
 use crate::common::utils as u;
+use crate::double::common as c;
 use crate::double::Double;
+use crate::round::RoundMode;
 use std::ops::{Div, DivAssign};
 
 // Helper function needed to avoid the only place in this arithmetic where Double::from must
@@ -38,18 +41,7 @@ impl Div for Double {
     fn div(self, other: Double) -> Double {
         match self.pre_div(&other) {
             Some(r) => r,
-            None => {
-                let q1 = self.0 / other.0;
-                let mut r = self - mul_f64(other, q1);
-
-                let q2 = r.0 / other.0;
-                r -= mul_f64(other, q2);
-
-                let q3 = r.0 / other.0;
-
-                let (a, b) = u::renorm3(q1, q2, q3);
-                Double(a, b)
-            }
+            None => self.div_unchecked(other),
         }
     }
 }
@@ -168,6 +160,303 @@ impl DivAssign<&Double> for Double {
     }
 }
 
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Div<f64> for Double {
+    type Output = Double;
+
+    /// Divides this `Double` by an `f64`, producing a new `Double` as a result.
+    ///
+    /// This implements the `/` operator between a `Double` and an `f64`.
+    ///
+    /// The `f64` is taken at its exact binary value, as with `Double::from((x, 0.0))`, and
+    /// is worked directly into the components rather than first being promoted to a
+    /// `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::PI / 2.5;
+    /// let expected = dd!("1.256637061435917295385057353312");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    fn div(self, other: f64) -> Double {
+        match self.pre_div(&Double(other, 0.0)) {
+            Some(r) => r,
+            None => {
+                let q1 = self.0 / other;
+                let (p1, p2) = p::two_prod(q1, other);
+                let (s, e) = p::two_diff(self.0, p1);
+                let q2 = (s + (e + self.1 - p2)) / other;
+                let (a, b) = u::renorm2(q1, q2);
+                Double(a, b)
+            }
+        }
+    }
+}
+
+impl Div<f64> for &Double {
+    type Output = Double;
+
+    /// Divides a reference to this `Double` by an `f64`, producing a new `Double` as a result.
+    ///
+    /// This implements the `/` operator between a reference to a `Double` and an `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = &Double::PI / 2.5;
+    /// let expected = dd!("1.256637061435917295385057353312");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn div(self, other: f64) -> Double {
+        (*self).div(other)
+    }
+}
+
+impl Div<Double> for f64 {
+    type Output = Double;
+
+    /// Divides this `f64` by a `Double`, producing a new `Double` as a result.
+    ///
+    /// This implements the `/` operator between an `f64` and a `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = 2.5 / Double::PI;
+    /// let expected = dd!("0.7957747154594766788444188168626");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn div(self, other: Double) -> Double {
+        Double(self, 0.0).div(other)
+    }
+}
+
+impl Div<&Double> for f64 {
+    type Output = Double;
+
+    /// Divides this `f64` by a reference to a `Double`, producing a new `Double` as a result.
+    ///
+    /// This implements the `/` operator between an `f64` and a reference to a `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = 2.5 / &Double::PI;
+    /// let expected = dd!("0.7957747154594766788444188168626");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn div(self, other: &Double) -> Double {
+        self.div(*other)
+    }
+}
+
+impl DivAssign<f64> for Double {
+    /// Divides this `Double` by an `f64`, modifying this one to equal the result.
+    ///
+    /// This implements the `/=` operator between a `Double` and an `f64`. As with the
+    /// non-assigning operator, the `f64` is taken at its exact binary value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let mut x = Double::PI;
+    /// x /= 2.5;
+    /// let expected = dd!("1.256637061435917295385057353312");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: f64) {
+        let r = self.div(other);
+        self.0 = r.0;
+        self.1 = r.1;
+    }
+}
+
+macro_rules! div_int_impl {
+    ($($t:ty)*) => ($(
+        impl Div<$t> for Double {
+            type Output = Double;
+
+            /// Divides this `Double` by an integer, producing a new `Double` as a result.
+            ///
+            /// This implements the `/` operator between a `Double` and an integer. Every
+            /// integer of this type converts exactly to an `f64`, so this takes the same
+            /// fast path as the `f64` operator.
+            #[inline]
+            fn div(self, other: $t) -> Double {
+                self.div(f64::from(other))
+            }
+        }
+
+        impl Div<$t> for &Double {
+            type Output = Double;
+
+            /// Divides a reference to this `Double` by an integer, producing a new `Double`
+            /// as a result.
+            ///
+            /// This implements the `/` operator between a reference to a `Double` and an
+            /// integer.
+            #[inline]
+            fn div(self, other: $t) -> Double {
+                (*self).div(f64::from(other))
+            }
+        }
+
+        impl Div<Double> for $t {
+            type Output = Double;
+
+            /// Divides this integer by a `Double`, producing a new `Double` as a result.
+            ///
+            /// This implements the `/` operator between an integer and a `Double`.
+            #[inline]
+            fn div(self, other: Double) -> Double {
+                f64::from(self).div(other)
+            }
+        }
+
+        impl DivAssign<$t> for Double {
+            /// Divides this `Double` by an integer, modifying this one to equal the result.
+            ///
+            /// This implements the `/=` operator between a `Double` and an integer.
+            #[inline]
+            fn div_assign(&mut self, other: $t) {
+                self.div_assign(f64::from(other));
+            }
+        }
+    )*);
+}
+
+div_int_impl! { i32 u32 }
+
+impl Double {
+    /// Divides this `Double` by another, rounding the quotient in the direction given by
+    /// `mode`.
+    ///
+    /// The quotient is first calculated as it is by the `/` operator. The sign of its
+    /// residual is then found exactly to tell which side of the exact quotient it's on, and
+    /// if it's on the wrong side for `mode`, it's moved to the next value after it in that
+    /// direction (by adding about the size returned by [`eps_at`] to its last component)
+    /// until it isn't, as in [`add_round`]. The result with [`RoundMode::Up`] is never less
+    /// than the exact quotient and the result with [`RoundMode::Down`] is never greater, so
+    /// the two always bracket it. [`RoundMode::Nearest`] gives the same result as `/`.
+    ///
+    /// If either `Double` is infinite or `NaN`, or if `other` is zero, the result is the
+    /// same as it is with `/`. When a quotient of finite `Double`s overflows, the result is
+    /// an infinity if `mode` rounds it away from zero and [`MAX`] or [`MIN`] if `mode`
+    /// rounds it toward zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, RoundMode};
+    /// let up = dd!(1).div_round(dd!(3), RoundMode::Up);
+    /// let down = dd!(1).div_round(dd!(3), RoundMode::Down);
+    /// assert!(up > down);
+    ///
+    /// // Exact quotients aren't moved
+    /// assert!(dd!(1).div_round(dd!(4), RoundMode::Up) == dd!(0.25));
+    /// ```
+    ///
+    /// [`add_round`]: #method.add_round
+    /// [`eps_at`]: #method.eps_at
+    /// [`MAX`]: #associatedconstant.MAX
+    /// [`MIN`]: #associatedconstant.MIN
+    /// [`RoundMode::Up`]: enum.RoundMode.html#variant.Up
+    /// [`RoundMode::Down`]: enum.RoundMode.html#variant.Down
+    /// [`RoundMode::Nearest`]: enum.RoundMode.html#variant.Nearest
+    pub fn div_round(self, other: Double, mode: RoundMode) -> Double {
+        let q = self / other;
+        if !(self.is_finite() && other.is_finite()) || other.is_zero() {
+            return q;
+        }
+        // A candidate is above the exact quotient when its product with `other` is above
+        // `self`, as long as `other` is positive
+        c::round_directed(q, mode, |x| {
+            let products = [
+                (x.0, other.0),
+                (x.0, other.1),
+                (x.1, other.0),
+                (x.1, other.1),
+            ];
+            let residual = b::sum_sign(&[-self.0, -self.1], &products);
+            if other.is_sign_negative() {
+                residual.reverse()
+            } else {
+                residual
+            }
+        })
+    }
+
+    /// Divides this `Double` by another, returning `None` if the quotient overflows or if
+    /// `other` is zero.
+    ///
+    /// This is the same as the `/` operator, except that a quotient that is infinite or
+    /// `NaN` is turned into `None` when both `Double`s were finite. That includes division
+    /// by zero, which gives an infinity (or `NaN`, for zero divided by zero). As in
+    /// [`checked_add`], infinite and `NaN` arguments still produce `Some`, so a finite
+    /// number divided by an infinity is `Some` zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(6).checked_div(dd!(3)) == Some(dd!(2)));
+    /// assert!(dd!(1).checked_div(Double::ZERO) == None);
+    /// assert!(Double::MAX.checked_div(dd!(0.5)) == None);
+    /// assert!(dd!(1).checked_div(Double::INFINITY) == Some(Double::ZERO));
+    /// ```
+    ///
+    /// [`checked_add`]: #method.checked_add
+    pub fn checked_div(self, other: Double) -> Option<Double> {
+        let r = self / other;
+        if r.is_finite() || !(self.is_finite() && other.is_finite()) {
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    /// Divides this `Double` by another without checking for special cases first.
+    ///
+    /// This skips the checks for zeros, infinities, and `NaN` that the `/` operator makes
+    /// before dividing, and the ones made by the subtractions inside the division, for
+    /// speed when the dividend is known to be finite and the divisor is known to be finite
+    /// and nonzero. Like [`add_unchecked`], it isn't unsafe, but the result is meaningless
+    /// for any other arguments. For those, it's exactly the same as `/`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// assert!(Double::PI.div_unchecked(Double::E) == Double::PI / Double::E);
+    /// ```
+    ///
+    /// [`add_unchecked`]: #method.add_unchecked
+    pub fn div_unchecked(self, other: Double) -> Double {
+        let q1 = self.0 / other.0;
+        let mut r = self.add_unchecked(-mul_f64(other, q1));
+
+        let q2 = r.0 / other.0;
+        r = r.add_unchecked(-mul_f64(other, q2));
+
+        let q3 = r.0 / other.0;
+
+        let (a, b) = u::renorm3(q1, q2, q3);
+        Double(a, b)
+    }
+}
+
 impl Double {
     // precalc functions
     //
@@ -213,6 +502,7 @@ impl Double {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::quad::Quad;
 
     // div tests
     test_all_near!(
@@ -324,6 +614,214 @@ mod tests {
             Double::ONE / Double::NAN;
     );
 
+    // f64 tests
+    test_all_exact!(
+        f64_int:
+            dd!(3),
+            dd!(6) / 2.0;
+        f64_rev_int:
+            dd!(3),
+            6.0 / dd!(2);
+        f64_id:
+            Double::PI,
+            Double::PI / 1.0;
+        f64_zero:
+            Double::INFINITY,
+            Double::PI / 0.0;
+        f64_zero_zero:
+            Double::NAN,
+            Double::ZERO / 0.0;
+        f64_inf:
+            Double::ZERO,
+            Double::PI / f64::INFINITY;
+        f64_nan:
+            Double::NAN,
+            Double::ONE / f64::NAN;
+        f64_rev_nan:
+            Double::NAN,
+            f64::NAN / Double::ONE;
+    );
+    test_all_near!(
+        f64_num:
+            Double::PI / Double(1.1, 0.0),
+            Double::PI / 1.1;
+        f64_ref:
+            Double::PI / Double(1.1, 0.0),
+            &Double::PI / 1.1;
+        f64_rev_num:
+            Double(1.1, 0.0) / Double::PI,
+            1.1 / Double::PI;
+        f64_rev_ref:
+            Double(1.1, 0.0) / Double::PI,
+            1.1 / &Double::PI;
+        f64_third:
+            Double::E / Double(3.0, 0.0),
+            Double::E / 3.0;
+    );
+
+    // div_round tests
+    fn exact_quotient(a: Double, b: Double) -> Quad {
+        c::to_quad(a) / c::to_quad(b)
+    }
+
+    test!(div_round_third: {
+        let up = dd!(1).div_round(dd!(3), RoundMode::Up);
+        let down = dd!(1).div_round(dd!(3), RoundMode::Down);
+        let exact = exact_quotient(dd!(1), dd!(3));
+        assert!(up > down);
+        assert!(c::to_quad(down) < exact && exact < c::to_quad(up));
+        assert!(up - down <= dd!(1).div_round(dd!(3), RoundMode::Nearest).eps_at());
+    });
+    test!(div_round_bracket: {
+        let values = [Double::PI, Double::E, -Double::LN_2, dd!(7), dd!(-1e100), dd!(3e-200)];
+        for &a in &values {
+            for &b in &values {
+                let exact = exact_quotient(a, b);
+                let up = c::to_quad(a.div_round(b, RoundMode::Up));
+                let down = c::to_quad(a.div_round(b, RoundMode::Down));
+                let zero = c::to_quad(a.div_round(b, RoundMode::TowardZero));
+                assert!(down <= exact && exact <= up, "{} / {} is not bracketed", a, b);
+                assert!(zero.abs() <= exact.abs(), "{} / {} is not toward zero", a, b);
+            }
+        }
+    });
+    test_all_exact!(
+        div_round_nearest:
+            Double::PI / Double::E,
+            Double::PI.div_round(Double::E, RoundMode::Nearest);
+        div_round_exact_up:
+            dd!(0.25),
+            dd!(1).div_round(dd!(4), RoundMode::Up);
+        div_round_exact_down:
+            dd!(-0.25),
+            dd!(1).div_round(dd!(-4), RoundMode::Down);
+        div_round_toward_zero_pos:
+            dd!(1).div_round(dd!(3), RoundMode::Down),
+            dd!(1).div_round(dd!(3), RoundMode::TowardZero);
+        div_round_toward_zero_neg:
+            dd!(-1).div_round(dd!(3), RoundMode::Up),
+            dd!(-1).div_round(dd!(3), RoundMode::TowardZero);
+        div_round_inf:
+            Double::INFINITY,
+            dd!(1).div_round(Double::ZERO, RoundMode::Down);
+        div_round_nan:
+            Double::NAN,
+            Double::NAN.div_round(dd!(3), RoundMode::Up);
+    );
+    test_all_exact!(
+        div_round_overflow_down:
+            Double::MAX,
+            Double::MAX.div_round(dd!(0.5), RoundMode::Down);
+        div_round_overflow_up:
+            Double::INFINITY,
+            Double::MAX.div_round(dd!(0.5), RoundMode::Up);
+        div_round_overflow_toward_zero:
+            Double::MIN,
+            Double::MAX.div_round(dd!(-0.5), RoundMode::TowardZero);
+    );
+    test!(div_round_tiny_quotient: {
+        // A step added to a quotient this small used to be lost in renormalization, so
+        // this never finished
+        let a = Double(2.6198942952389247e-225, 2.9482721005993594e-242);
+        let b = Double(-2.6894671305042835e62, 1.4847707234981429e46);
+        let exact = exact_quotient(a, b);
+        let up = a.div_round(b, RoundMode::Up);
+        let down = a.div_round(b, RoundMode::Down);
+        assert!(down < up);
+        assert!(c::to_quad(down) <= exact && exact <= c::to_quad(up));
+    });
+    test!(div_round_subnormal_residual: {
+        // Part of the residual here is too small for a Quad to hold, which used to leave
+        // the result on the wrong side
+        let a = Double(-2.2987884749714326e-299, -3.4973853e-316);
+        let b = Double(-0.0009556108693304151, -5.349363059722957e-20);
+        let exact = exact_quotient(a, b);
+        let up = a.div_round(b, RoundMode::Up);
+        let down = a.div_round(b, RoundMode::Down);
+        assert!(down < up);
+        assert!(c::to_quad(down) <= exact && exact <= c::to_quad(up));
+    });
+    test!(div_round_underflow: {
+        let a = Double(f64::from_bits(1), 0.0);
+        assert!(a.div_round(dd!(3), RoundMode::Down) == Double::ZERO);
+        assert!(a.div_round(dd!(3), RoundMode::Up) == a);
+        let zero = a.div_round(dd!(-3), RoundMode::TowardZero);
+        assert!(zero == Double::ZERO);
+        assert!(a.div_round(dd!(-3), RoundMode::Down) == -a);
+    });
+
+    // checked_div tests
+    test_all_eq!(
+        checked_div_finite:
+            Some(dd!(2)),
+            dd!(6).checked_div(dd!(3));
+        checked_div_overflow:
+            None,
+            Double::MAX.checked_div(dd!(0.5));
+        checked_div_zero:
+            None,
+            dd!(1).checked_div(Double::ZERO);
+        checked_div_neg_zero:
+            None,
+            dd!(1).checked_div(Double::NEG_ZERO);
+        checked_div_zero_zero:
+            None,
+            Double::ZERO.checked_div(Double::ZERO);
+        checked_div_by_inf:
+            Some(Double::ZERO),
+            dd!(1).checked_div(Double::INFINITY);
+        checked_div_inf:
+            Some(Double::INFINITY),
+            Double::INFINITY.checked_div(dd!(2));
+    );
+    test_all_assert!(
+        checked_div_nan:
+            Double::NAN.checked_div(dd!(1)).unwrap().is_nan();
+        checked_div_inf_inf:
+            Double::INFINITY.checked_div(Double::INFINITY).unwrap().is_nan();
+    );
+
+    // div_unchecked tests
+    test_all_exact!(
+        div_unchecked_num:
+            Double::PI / Double::E,
+            Double::PI.div_unchecked(Double::E);
+        div_unchecked_neg:
+            Double::PI / -Double::E,
+            Double::PI.div_unchecked(-Double::E);
+        div_unchecked_small:
+            Double::E / dd!(3.7e-200),
+            Double::E.div_unchecked(dd!(3.7e-200));
+        div_unchecked_int:
+            dd!(6) / dd!(3),
+            dd!(6).div_unchecked(dd!(3));
+    );
+
+    // integer tests
+    test_all_exact!(
+        int_i32:
+            dd!(1.5),
+            dd!(3) / 2;
+        int_rev_i32:
+            dd!(2),
+            6 / dd!(3);
+        int_u32:
+            dd!(1.5),
+            dd!(3) / 2u32;
+        int_rev_u32:
+            dd!(2),
+            6u32 / dd!(3);
+        int_neg:
+            dd!(-1.5),
+            dd!(3) / -2;
+        int_max:
+            dd!(2147483648.0) / dd!(4294967295.0),
+            dd!(2147483648.0) / u32::MAX;
+        int_ref:
+            Double::PI / Double::from(2.0),
+            &Double::PI / 2;
+    );
+
     // Assign tests. Assign code delegates to div code, so there's no need to re-test all
     // of the cases above.
     test_all!(
@@ -337,6 +835,17 @@ mod tests {
             b /= &Double::E;
             near!(dd!("1.1557273497909217179100931833126961"), b);
         }
+        assign_f64: {
+            let mut c = Double::PI;
+            c /= 1.1;
+            near!(Double::PI / Double(1.1, 0.0), c);
+        }
+        assign_int: {
+            let mut e = Double::PI;
+            e /= 2;
+            e /= 3u32;
+            exact!(Double::PI / 2.0 / 3.0, e);
+        }
     );
 
     test!(chain_tens: {
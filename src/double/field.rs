@@ -0,0 +1,578 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Implementations of the `simba` scalar traits, which are what `nalgebra` requires of the
+// elements of its vectors and matrices. Most of the methods are the inherent ones of the same
+// names; the rest come from the `num-traits` `Float` implementation.
+
+use crate::double::common as c;
+use crate::double::Double;
+use num_traits::Float;
+use simba::scalar::{ComplexField, Field, RealField, SubsetOf};
+use simba::simd::{PrimitiveSimdValue, SimdValue};
+
+impl SimdValue for Double {
+    const LANES: usize = 1;
+    type Element = Double;
+    type SimdBool = bool;
+
+    #[inline]
+    fn splat(val: Double) -> Double {
+        val
+    }
+
+    #[inline]
+    fn extract(&self, _: usize) -> Double {
+        *self
+    }
+
+    #[inline]
+    unsafe fn extract_unchecked(&self, _: usize) -> Double {
+        *self
+    }
+
+    #[inline]
+    fn replace(&mut self, _: usize, val: Double) {
+        *self = val;
+    }
+
+    #[inline]
+    unsafe fn replace_unchecked(&mut self, _: usize, val: Double) {
+        *self = val;
+    }
+
+    #[inline]
+    fn select(self, cond: bool, other: Double) -> Double {
+        if cond {
+            self
+        } else {
+            other
+        }
+    }
+}
+
+impl PrimitiveSimdValue for Double {}
+
+impl Field for Double {}
+
+impl SubsetOf<Double> for Double {
+    #[inline]
+    fn to_superset(&self) -> Double {
+        *self
+    }
+
+    #[inline]
+    fn from_superset_unchecked(element: &Double) -> Double {
+        *element
+    }
+
+    #[inline]
+    fn is_in_subset(_: &Double) -> bool {
+        true
+    }
+}
+
+impl SubsetOf<Double> for f64 {
+    #[inline]
+    fn to_superset(&self) -> Double {
+        Double(*self, 0.0)
+    }
+
+    /// Converts a `Double` to an `f64` by taking its first component, which is the `f64`
+    /// nearest to it.
+    #[inline]
+    fn from_superset_unchecked(element: &Double) -> f64 {
+        element.0
+    }
+
+    #[inline]
+    fn is_in_subset(_: &Double) -> bool {
+        true
+    }
+}
+
+impl SubsetOf<Double> for f32 {
+    #[inline]
+    fn to_superset(&self) -> Double {
+        Double(*self as f64, 0.0)
+    }
+
+    #[inline]
+    fn from_superset_unchecked(element: &Double) -> f32 {
+        f32::from(*element)
+    }
+
+    #[inline]
+    fn is_in_subset(_: &Double) -> bool {
+        true
+    }
+}
+
+impl ComplexField for Double {
+    type RealField = Double;
+
+    #[inline]
+    fn from_real(re: Double) -> Double {
+        re
+    }
+
+    #[inline]
+    fn real(self) -> Double {
+        self
+    }
+
+    #[inline]
+    fn imaginary(self) -> Double {
+        Double::ZERO
+    }
+
+    #[inline]
+    fn modulus(self) -> Double {
+        self.abs()
+    }
+
+    #[inline]
+    fn modulus_squared(self) -> Double {
+        self.sqr()
+    }
+
+    #[inline]
+    fn argument(self) -> Double {
+        if self >= Double::ZERO {
+            Double::ZERO
+        } else {
+            Double::PI
+        }
+    }
+
+    #[inline]
+    fn norm1(self) -> Double {
+        self.abs()
+    }
+
+    #[inline]
+    fn scale(self, factor: Double) -> Double {
+        self * factor
+    }
+
+    #[inline]
+    fn unscale(self, factor: Double) -> Double {
+        self / factor
+    }
+
+    #[inline]
+    fn floor(self) -> Double {
+        self.floor()
+    }
+
+    #[inline]
+    fn ceil(self) -> Double {
+        self.ceil()
+    }
+
+    #[inline]
+    fn round(self) -> Double {
+        self.round()
+    }
+
+    #[inline]
+    fn trunc(self) -> Double {
+        self.trunc()
+    }
+
+    #[inline]
+    fn fract(self) -> Double {
+        self.fract()
+    }
+
+    #[inline]
+    fn mul_add(self, a: Double, b: Double) -> Double {
+        Float::mul_add(self, a, b)
+    }
+
+    #[inline]
+    fn abs(self) -> Double {
+        self.abs()
+    }
+
+    #[inline]
+    fn hypot(self, other: Double) -> Double {
+        Float::hypot(self, other)
+    }
+
+    #[inline]
+    fn recip(self) -> Double {
+        self.recip()
+    }
+
+    #[inline]
+    fn conjugate(self) -> Double {
+        self
+    }
+
+    #[inline]
+    fn sin(self) -> Double {
+        self.sin()
+    }
+
+    #[inline]
+    fn cos(self) -> Double {
+        self.cos()
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Double, Double) {
+        self.sin_cos()
+    }
+
+    #[inline]
+    fn sinh_cosh(self) -> (Double, Double) {
+        self.sinh_cosh()
+    }
+
+    #[inline]
+    fn tan(self) -> Double {
+        self.tan()
+    }
+
+    #[inline]
+    fn asin(self) -> Double {
+        self.asin()
+    }
+
+    #[inline]
+    fn acos(self) -> Double {
+        self.acos()
+    }
+
+    #[inline]
+    fn atan(self) -> Double {
+        self.atan()
+    }
+
+    #[inline]
+    fn sinh(self) -> Double {
+        self.sinh()
+    }
+
+    #[inline]
+    fn cosh(self) -> Double {
+        self.cosh()
+    }
+
+    #[inline]
+    fn tanh(self) -> Double {
+        self.tanh()
+    }
+
+    #[inline]
+    fn asinh(self) -> Double {
+        self.asinh()
+    }
+
+    #[inline]
+    fn acosh(self) -> Double {
+        self.acosh()
+    }
+
+    #[inline]
+    fn atanh(self) -> Double {
+        self.atanh()
+    }
+
+    #[inline]
+    fn log(self, base: Double) -> Double {
+        self.log(base)
+    }
+
+    #[inline]
+    fn log2(self) -> Double {
+        self.log2()
+    }
+
+    #[inline]
+    fn log10(self) -> Double {
+        self.log10()
+    }
+
+    #[inline]
+    fn ln(self) -> Double {
+        self.ln()
+    }
+
+    #[inline]
+    fn ln_1p(self) -> Double {
+        Float::ln_1p(self)
+    }
+
+    #[inline]
+    fn sqrt(self) -> Double {
+        self.sqrt()
+    }
+
+    #[inline]
+    fn exp(self) -> Double {
+        self.exp()
+    }
+
+    #[inline]
+    fn exp2(self) -> Double {
+        Float::exp2(self)
+    }
+
+    #[inline]
+    fn exp_m1(self) -> Double {
+        Float::exp_m1(self)
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> Double {
+        self.powi(n)
+    }
+
+    #[inline]
+    fn powf(self, n: Double) -> Double {
+        self.powf(n)
+    }
+
+    /// Raises the `Double` to a `Double` power. For real numbers, this is the same as
+    /// [`powf`].
+    ///
+    /// [`powf`]: #method.powf
+    #[inline]
+    fn powc(self, n: Double) -> Double {
+        self.powf(n)
+    }
+
+    #[inline]
+    fn cbrt(self) -> Double {
+        self.cbrt()
+    }
+
+    #[inline]
+    fn is_finite(&self) -> bool {
+        Double::is_finite(*self)
+    }
+
+    /// Calculates the square root of the `Double`, or returns `None` if it's negative.
+    #[inline]
+    fn try_sqrt(self) -> Option<Double> {
+        if self >= Double::ZERO {
+            Some(self.sqrt())
+        } else {
+            None
+        }
+    }
+}
+
+impl RealField for Double {
+    #[inline]
+    fn is_sign_positive(&self) -> bool {
+        Double::is_sign_positive(*self)
+    }
+
+    #[inline]
+    fn is_sign_negative(&self) -> bool {
+        Double::is_sign_negative(*self)
+    }
+
+    #[inline]
+    fn copysign(self, sign: Double) -> Double {
+        Float::copysign(self, sign)
+    }
+
+    #[inline]
+    fn max(self, other: Double) -> Double {
+        Float::max(self, other)
+    }
+
+    #[inline]
+    fn min(self, other: Double) -> Double {
+        Float::min(self, other)
+    }
+
+    #[inline]
+    fn clamp(self, min: Double, max: Double) -> Double {
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    fn atan2(self, other: Double) -> Double {
+        self.atan2(other)
+    }
+
+    /// Returns [`MIN`], the smallest finite `Double`.
+    ///
+    /// [`MIN`]: #associatedconstant.MIN
+    #[inline]
+    fn min_value() -> Option<Double> {
+        Some(Double::MIN)
+    }
+
+    /// Returns [`MAX`], the largest finite `Double`.
+    ///
+    /// [`MAX`]: #associatedconstant.MAX
+    #[inline]
+    fn max_value() -> Option<Double> {
+        Some(Double::MAX)
+    }
+
+    #[inline]
+    fn pi() -> Double {
+        Double::PI
+    }
+
+    #[inline]
+    fn two_pi() -> Double {
+        c::mul_pwr2(Double::PI, 2.0)
+    }
+
+    #[inline]
+    fn frac_pi_2() -> Double {
+        Double::FRAC_PI_2
+    }
+
+    #[inline]
+    fn frac_pi_3() -> Double {
+        Double::FRAC_PI_3
+    }
+
+    #[inline]
+    fn frac_pi_4() -> Double {
+        Double::FRAC_PI_4
+    }
+
+    #[inline]
+    fn frac_pi_6() -> Double {
+        Double::FRAC_PI_6
+    }
+
+    #[inline]
+    fn frac_pi_8() -> Double {
+        Double::FRAC_PI_8
+    }
+
+    #[inline]
+    fn frac_1_pi() -> Double {
+        Double::FRAC_1_PI
+    }
+
+    #[inline]
+    fn frac_2_pi() -> Double {
+        Double::FRAC_2_PI
+    }
+
+    #[inline]
+    fn frac_2_sqrt_pi() -> Double {
+        Double::FRAC_2_SQRT_PI
+    }
+
+    #[inline]
+    fn e() -> Double {
+        Double::E
+    }
+
+    #[inline]
+    fn log2_e() -> Double {
+        Double::LOG2_E
+    }
+
+    #[inline]
+    fn log10_e() -> Double {
+        Double::LOG10_E
+    }
+
+    #[inline]
+    fn ln_2() -> Double {
+        Double::LN_2
+    }
+
+    #[inline]
+    fn ln_10() -> Double {
+        Double::LN_10
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{Matrix3, Rotation2, Vector2, Vector3};
+
+    // A generic function of the sort that these traits are meant to support
+    fn circumference<T: RealField>(r: T) -> T {
+        T::two_pi() * r
+    }
+
+    // Checks that two matrices are equal to within `tol` in every element
+    fn close3(expected: &Matrix3<Double>, actual: &Matrix3<Double>, tol: Double) {
+        for (e, a) in expected.iter().zip(actual.iter()) {
+            assert!((*e - *a).abs() < tol, "expected {}, actual {}", e, a);
+        }
+    }
+
+    #[rustfmt::skip]
+    fn matrix() -> Matrix3<Double> {
+        Matrix3::new(
+            dd!(4), dd!(-2), dd!(1),
+            dd!(3), dd!(6), dd!(-4),
+            dd!(2), dd!(1), dd!(8),
+        )
+    }
+
+    // real_field tests
+    test!(real_field_generic: {
+        exact!(Double::PI * dd!(4), circumference(dd!(2)));
+        exact!(Double::PI, <Double as RealField>::pi());
+        exact!(Double::MAX, <Double as RealField>::max_value().unwrap());
+        exact!(dd!(2), RealField::clamp(dd!(5), dd!(-2), dd!(2)));
+        exact!(dd!(-3), RealField::copysign(dd!(3), dd!(-1)));
+        exact!(dd!(1), RealField::max(Double::NAN, dd!(1)));
+    });
+    test!(complex_field_methods: {
+        exact!(Double::ZERO, ComplexField::argument(dd!(2)));
+        exact!(Double::PI, ComplexField::argument(dd!(-2)));
+        exact!(dd!(9), ComplexField::modulus_squared(dd!(-3)));
+        assert!(ComplexField::try_sqrt(dd!(-1)).is_none());
+        near!(dd!(3), ComplexField::try_sqrt(dd!(9)).unwrap());
+    });
+    test!(subset_of: {
+        let x: Double = simba::scalar::SupersetOf::<f64>::from_subset(&1.5);
+        exact!(dd!(1.5), x);
+        let y: f64 = simba::scalar::SupersetOf::<f64>::to_subset_unchecked(&Double::PI);
+        exact!(Double::PI.0, y);
+    });
+
+    // nalgebra tests
+    test!(nalgebra_inverse: {
+        let m = matrix();
+        let inv = m.try_inverse().unwrap();
+        close3(&Matrix3::identity(), &(m * inv), dd!(1e-30));
+    });
+    test!(nalgebra_lu_solve: {
+        let m = matrix();
+        let x = Vector3::new(dd!(1) / dd!(3), Double::PI, -Double::E);
+        let b = m * x;
+        let solved = m.lu().solve(&b).unwrap();
+        for (e, a) in x.iter().zip(solved.iter()) {
+            assert!((*e - *a).abs() < dd!(1e-30), "expected {}, actual {}", e, a);
+        }
+    });
+    test!(nalgebra_determinant: {
+        // 4(48 + 4) + 2(24 + 8) + (3 - 12)
+        near!(dd!(263), matrix().determinant());
+    });
+    test!(nalgebra_rotation: {
+        let rot = Rotation2::new(Double::FRAC_PI_6);
+        let v = rot * Vector2::new(dd!(2), Double::ZERO);
+        near!(dd!(3).sqrt(), v[0]);
+        near!(dd!(1), v[1]);
+    });
+}
@@ -3,8 +3,18 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+#[cfg(feature = "num-bigint")]
+use crate::common::bigint as b;
 use crate::common::utils as u;
 use crate::double::Double;
+use crate::error::{ParseDoubleError, TryFromDoubleError, TryFromErrorKind};
+#[cfg(feature = "half")]
+use half::{bf16, f16};
+#[cfg(any(feature = "num-rational", feature = "bigdecimal"))]
+use num_bigint::Sign;
+#[cfg(feature = "num-bigint")]
+use num_bigint::{BigInt, BigUint};
+use std::convert::TryFrom;
 use std::f64;
 
 #[inline]
@@ -46,7 +56,8 @@ fn from_i64(a: i64) -> Double {
 //
 // The exceptions are `i64` and `u64`, which don't fit into `f64`s. They get their own
 // separate (non-macro) functions that split them into two 32-bit parts which are then
-// renormalized into a proper `Double`.
+// renormalized into a proper `Double`. `i128` and `u128` don't necessarily fit into a
+// `Double` at all, so they're rounded (see below).
 
 macro_rules! from_int_impl {
     ($(
@@ -159,6 +170,133 @@ impl From<i64> for Double {
     }
 }
 
+// The 128-bit integers can have more bits than a `Double` can hold, so these are rounded.
+// Conversions from integers to `f64` round to the nearest, so the first component is just
+// the integer converted, and the second is what's left of it, converted the same way. What's
+// left after that is the rounding error, which is zero if the conversion was exact.
+//
+// The first component can round up to 2^128, which doesn't fit back into a `u128`. The
+// subtraction wraps instead, which gives the right (negative) answer since what's left is
+// always much smaller than that.
+fn split_u128(a: u128) -> (f64, f64, i128) {
+    let hi = a as f64;
+    let hi_int = if hi == 2f64.powi(128) { 0 } else { hi as u128 };
+    let rest = a.wrapping_sub(hi_int) as i128;
+    let lo = rest as f64;
+    (hi, lo, rest - lo as i128)
+}
+
+fn split_i128(a: i128) -> (f64, f64, i128) {
+    let (hi, lo, err) = split_u128(a.unsigned_abs());
+    if a < 0 {
+        (-hi, -lo, err)
+    } else {
+        (hi, lo, err)
+    }
+}
+
+impl From<u128> for Double {
+    /// Generates a `Double` from a `u128`.
+    ///
+    /// A `u128` can have more significant bits than the 106 that a `Double` holds, so this
+    /// rounds to the nearest `Double` when it has to. Any `u128` with no more than 106
+    /// significant bits (which includes any that fits into a `u64`) converts exactly. Use
+    /// [`from_u128_exact`] to find out whether any rounding was done.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let x = 1u128 << 120;
+    /// let a = Double::from(x);
+    /// assert!(a == Double::from(2).powi(120));
+    ///
+    /// // The last bit doesn't fit, so it's rounded away
+    /// let x = (1u128 << 120) + (1 << 60) + 1;
+    /// assert!(Double::from(x) == Double::from(x - 1));
+    /// ```
+    ///
+    /// [`from_u128_exact`]: #method.from_u128_exact
+    fn from(a: u128) -> Double {
+        let (hi, lo, _) = split_u128(a);
+        Double(hi, lo)
+    }
+}
+
+impl From<i128> for Double {
+    /// Generates a `Double` from an `i128`.
+    ///
+    /// An `i128` can have more significant bits than the 106 that a `Double` holds, so this
+    /// rounds to the nearest `Double` when it has to. Any `i128` with no more than 106
+    /// significant bits (which includes any that fits into an `i64`) converts exactly. Use
+    /// [`from_i128_exact`] to find out whether any rounding was done.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let x = i128::MIN;
+    /// let a = Double::from(x);
+    /// assert!(a == -Double::from(2).powi(127));
+    ///
+    /// // The last bit doesn't fit, so it's rounded away
+    /// let x = -(1i128 << 120) - (1 << 60) - 1;
+    /// assert!(Double::from(x) == Double::from(x + 1));
+    /// ```
+    ///
+    /// [`from_i128_exact`]: #method.from_i128_exact
+    fn from(a: i128) -> Double {
+        let (hi, lo, _) = split_i128(a);
+        Double(hi, lo)
+    }
+}
+
+impl Double {
+    /// Generates a `Double` from a `u128`, or returns `None` if the `u128` can't be
+    /// represented exactly.
+    ///
+    /// This is the fallible version of the `From<u128>` conversion. It fails whenever that
+    /// conversion would have to round, which can only happen when there are more than 106
+    /// bits between the highest and lowest set bits of the `u128`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let x = Double::from(2).powi(128) - Double::from(1);
+    /// assert!(Double::from_u128_exact(u128::MAX) == Some(x));
+    ///
+    /// let x = (1u128 << 120) + (1 << 60) + 1;
+    /// assert!(Double::from_u128_exact(x).is_none());
+    /// ```
+    pub fn from_u128_exact(a: u128) -> Option<Double> {
+        match split_u128(a) {
+            (hi, lo, 0) => Some(Double(hi, lo)),
+            _ => None,
+        }
+    }
+
+    /// Generates a `Double` from an `i128`, or returns `None` if the `i128` can't be
+    /// represented exactly.
+    ///
+    /// This is the fallible version of the `From<i128>` conversion. It fails whenever that
+    /// conversion would have to round, which can only happen when there are more than 106
+    /// bits between the highest and lowest set bits of the `i128`'s absolute value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let x = -Double::from(2).powi(127);
+    /// assert!(Double::from_i128_exact(i128::MIN) == Some(x));
+    ///
+    /// let x = -(1i128 << 120) - (1 << 60) - 1;
+    /// assert!(Double::from_i128_exact(x).is_none());
+    /// ```
+    pub fn from_i128_exact(a: i128) -> Option<Double> {
+        match split_i128(a) {
+            (hi, lo, 0) => Some(Double(hi, lo)),
+            _ => None,
+        }
+    }
+}
+
 // FROM FLOAT IMPLEMENTATIONS
 //
 // The Rust conversion from f32 to f64 is a bit-for-bit translation. It does not attempt to
@@ -262,6 +400,102 @@ from_float_impl! {
     f64
 }
 
+#[cfg(feature = "num-bigint")]
+impl From<&BigInt> for Double {
+    /// Generates a `Double` from a reference to a [`BigInt`].
+    ///
+    /// The integer is rounded to the nearest `f64`, and what's left of it is rounded to the
+    /// nearest `f64` again to give the next component, and so on, with ties going to even
+    /// each time. That's the nearest `Double` to the integer, and an integer with no more than
+    /// 106 significant bits converts exactly. Integers too large in magnitude to be
+    /// represented become [`INFINITY`] or [`NEG_INFINITY`].
+    ///
+    /// This is only available when the `num-bigint` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use num_bigint::BigInt;
+    ///
+    /// let n = BigInt::from(1) << 100;
+    /// assert!(Double::from(&n) == dd!(2).powi(100));
+    ///
+    /// let m = BigInt::from(10).pow(40) + 1;
+    /// assert!(Double::from(&m) == dd!(10).powi(40));
+    /// ```
+    ///
+    /// [`BigInt`]: https://docs.rs/num-bigint/0.4/num_bigint/struct.BigInt.html
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    fn from(n: &BigInt) -> Double {
+        let parts = b::split_fraction(n.clone(), BigInt::from(1), 2);
+        Double(parts[0], parts[1])
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl From<&BigUint> for Double {
+    /// Generates a `Double` from a reference to a [`BigUint`].
+    ///
+    /// The integer is rounded one component at a time, just like a [`BigInt`], which gives
+    /// the nearest `Double` to it. Integers too large to be represented become [`INFINITY`].
+    ///
+    /// This is only available when the `num-bigint` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = BigUint::from(1u32) << 100;
+    /// assert!(Double::from(&n) == dd!(2).powi(100));
+    /// ```
+    ///
+    /// [`BigUint`]: https://docs.rs/num-bigint/0.4/num_bigint/struct.BigUint.html
+    /// [`BigInt`]: https://docs.rs/num-bigint/0.4/num_bigint/struct.BigInt.html
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    fn from(n: &BigUint) -> Double {
+        let parts = b::split_fraction(BigInt::from(n.clone()), BigInt::from(1), 2);
+        Double(parts[0], parts[1])
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl Double {
+    /// Returns the integer part of the `Double` as a [`BigInt`], or `None` if the `Double` is
+    /// `NaN` or infinite.
+    ///
+    /// The fractional part is discarded, as with [`trunc`]. Every integer that a `Double` can
+    /// hold is converted exactly, no matter how large it is, so this is the inverse of the
+    /// conversion from a `BigInt` for any integer with no more than 106 significant bits.
+    ///
+    /// This is only available when the `num-bigint` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use num_bigint::BigInt;
+    ///
+    /// assert!(dd!(-12345.75).to_bigint() == Some(BigInt::from(-12345)));
+    ///
+    /// let x = dd!(2).powi(100) + dd!(3);
+    /// assert!(x.to_bigint() == Some((BigInt::from(1) << 100) + 3));
+    ///
+    /// assert!(Double::NAN.to_bigint().is_none());
+    /// ```
+    ///
+    /// [`BigInt`]: https://docs.rs/num-bigint/0.4/num_bigint/struct.BigInt.html
+    /// [`trunc`]: #method.trunc
+    pub fn to_bigint(self) -> Option<BigInt> {
+        if self.is_finite() {
+            let t = self.trunc();
+            Some(b::from_integral(t.0) + b::from_integral(t.1))
+        } else {
+            None
+        }
+    }
+}
+
 impl From<(f64, f64)> for Double {
     /// Generates a `Double` from a 2-tuple of `f64`s.
     ///
@@ -289,34 +523,38 @@ impl From<(f64, f64)> for Double {
     }
 }
 
-impl From<&str> for Double {
-    /// Parses a string to create a `Double`.
-    ///
-    /// The parser works pretty similarly to parsers for `f32` and `f64`. It will fail if
-    /// characters are present that are not digits, decimal points, signs, or exponent
-    /// markers. It will also fail if there are multiples of these or if they're in the
-    /// wrong places; two decimal points or a negative sign after the number will both be
-    /// rejected, for instance.
+impl TryFrom<&str> for Double {
+    type Error = ParseDoubleError;
+
+    /// Parses a string to create a `Double`, returning an error if the string isn't a valid
+    /// number.
     ///
-    /// Failure will return [`NAN`]. This can be an issue because parsing the string `"nan"`
-    /// *also* produces [`NAN`]. For this reason it's suggested to use [`from_str`] (or its
-    /// associated `parse` function) instead of this function if there is any chance that
-    /// the parsed string will be legitimately [`NAN`].
+    /// This is the same as [`from_str`] (and `parse`), and is meant for strings that come
+    /// from outside of the program, like user input, files, and network requests. The
+    /// [`dd!`] macro also takes a string, but it panics if the string doesn't parse, since
+    /// it's meant for literals written into the code.
     ///
     /// # Examples
     /// ```
     /// # use qd::{dd, Double};
-    /// let expected = (dd!(3).powi(15) - dd!(1)) / dd!(3).powi(15);
-    /// let x = Double::from("0.9999999303082806237436760862691");
+    /// use qd::error::{ErrorKind, ParseDoubleError};
+    /// use std::convert::TryFrom;
     ///
+    /// let expected = (dd!(3).powi(15) - dd!(1)) / dd!(3).powi(15);
+    /// let x = Double::try_from("0.9999999303082806237436760862691").unwrap();
     /// let diff = (x - expected).abs();
     /// assert!(diff < dd!(1e-30));
+    ///
+    /// let err = Double::try_from("1.2.3").unwrap_err();
+    /// assert!(err == ParseDoubleError { kind: ErrorKind::TooManyPoints, position: 3 });
+    /// assert!(Double::try_from("").unwrap_err().kind == ErrorKind::Empty);
     /// ```
     ///
-    /// [`NAN`]: #associatedconstant.NAN
     /// [`from_str`]: #method.from_str
-    fn from(s: &str) -> Double {
-        s.parse().unwrap_or(Double::NAN)
+    /// [`dd!`]: macro.dd.html
+    #[inline]
+    fn try_from(s: &str) -> Result<Double, ParseDoubleError> {
+        s.parse()
     }
 }
 
@@ -326,10 +564,10 @@ impl From<Double> for f64 {
     /// This will lose precision if the second component of the `Double` is not 0, but it
     /// will not lose range.
     ///
-    /// No other conversions from `Double` to numeric types are provided, as every other one
-    /// has the capability of losing range (for example, no other type could be used to
-    /// represent `dd!(1e308)`). Casts can be made from the `f64` provided by this function
-    /// to other numeric types as needed.
+    /// The only other conversions from `Double` to primitive numeric types are the one into
+    /// `f32` and the `TryFrom` conversions into integers, as every other one has the
+    /// capability of losing range (for example, no integer type could be used to represent
+    /// `dd!(1e308)`).
     ///
     /// # Examples
     /// ```
@@ -346,6 +584,138 @@ impl From<Double> for f64 {
     }
 }
 
+impl From<Double> for f32 {
+    /// Converts a `Double` into an `f32`, correctly rounded.
+    ///
+    /// The whole `Double` is rounded to the nearest `f32`, with ties going to the one with an
+    /// even last bit. That's not always the same as rounding just its first component, since
+    /// the second component can decide which way a tie goes. This will lose range as well as
+    /// precision; a `Double` too large in magnitude for an `f32` becomes an infinity, and one
+    /// too small becomes an `f32` subnormal or zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(f32::from(Double::PI) == std::f32::consts::PI);
+    ///
+    /// // Exactly halfway between two `f32`s in the first component, but not in the whole
+    /// let x = dd!(1) + dd!(2).powi(-24) + dd!(2).powi(-80);
+    /// assert!(f32::from(x) == 1.0000001);
+    /// assert!(f64::from(x) as f32 == 1.0);
+    ///
+    /// assert!(f32::from(dd!(1e300)) == f32::INFINITY);
+    /// ```
+    #[inline]
+    fn from(a: Double) -> f32 {
+        u::round_to_odd(a.0, a.1) as f32
+    }
+}
+
+#[cfg(feature = "half")]
+impl From<f16> for Double {
+    /// Generates a `Double` from a [`half::f16`], exactly.
+    ///
+    /// Every `f16` is also an `f64`, so it becomes the first component of the `Double` as it
+    /// is. Unlike the conversion from an `f32`, this doesn't treat the `f16` as the shortest
+    /// decimal number that would round to it; few `f16`s are close enough to a short decimal
+    /// for that to be useful.
+    ///
+    /// This is only available when the `half` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use half::f16;
+    ///
+    /// assert!(Double::from(f16::from_f32(-1.5)) == dd!(-1.5));
+    /// assert!(Double::from(f16::MAX) == dd!(65504));
+    /// ```
+    ///
+    /// [`half::f16`]: https://docs.rs/half/2/half/struct.f16.html
+    #[inline]
+    fn from(a: f16) -> Double {
+        Double(a.to_f64(), 0.0)
+    }
+}
+
+#[cfg(feature = "half")]
+impl From<bf16> for Double {
+    /// Generates a `Double` from a [`half::bf16`], exactly.
+    ///
+    /// Every `bf16` is also an `f64`, so it becomes the first component of the `Double` as it
+    /// is.
+    ///
+    /// This is only available when the `half` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use half::bf16;
+    ///
+    /// assert!(Double::from(bf16::from_f32(0.375)) == dd!(0.375));
+    /// ```
+    ///
+    /// [`half::bf16`]: https://docs.rs/half/2/half/struct.bf16.html
+    #[inline]
+    fn from(a: bf16) -> Double {
+        Double(a.to_f64(), 0.0)
+    }
+}
+
+#[cfg(feature = "half")]
+impl From<Double> for f16 {
+    /// Converts a `Double` into a [`half::f16`], correctly rounded.
+    ///
+    /// The whole `Double` is rounded to the nearest `f16`, with ties going to the one with an
+    /// even last bit. A `Double` too large in magnitude for an `f16` (anything from 65520 on)
+    /// becomes an infinity, and one too small becomes an `f16` subnormal or zero.
+    ///
+    /// This is only available when the `half` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use half::f16;
+    ///
+    /// assert!(f16::from(dd!(1) / dd!(3)) == f16::from_f32(0.33325195));
+    /// assert!(f16::from(dd!(65519)) == f16::MAX);
+    /// assert!(f16::from(dd!(65520)) == f16::INFINITY);
+    /// ```
+    ///
+    /// [`half::f16`]: https://docs.rs/half/2/half/struct.f16.html
+    #[inline]
+    fn from(a: Double) -> f16 {
+        // The rounded value is exactly an `f16` (or is too large for one), so `from_f64`
+        // doesn't have to round it again
+        f16::from_f64(u::round_to_format(u::round_to_odd(a.0, a.1), 11, -24))
+    }
+}
+
+#[cfg(feature = "half")]
+impl From<Double> for bf16 {
+    /// Converts a `Double` into a [`half::bf16`], correctly rounded.
+    ///
+    /// The whole `Double` is rounded to the nearest `bf16`, with ties going to the one with an
+    /// even last bit. A `Double` too large in magnitude for a `bf16` becomes an infinity, and
+    /// one too small becomes a `bf16` subnormal or zero.
+    ///
+    /// This is only available when the `half` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use half::bf16;
+    ///
+    /// assert!(bf16::from(Double::PI) == bf16::from_f32(3.140625));
+    /// ```
+    ///
+    /// [`half::bf16`]: https://docs.rs/half/2/half/struct.bf16.html
+    #[inline]
+    fn from(a: Double) -> bf16 {
+        bf16::from_f64(u::round_to_format(u::round_to_odd(a.0, a.1), 8, -133))
+    }
+}
+
 impl From<Double> for (f64, f64) {
     /// Converts a `Double` into a tuple of `f64`s.
     ///
@@ -368,9 +738,372 @@ impl From<Double> for (f64, f64) {
     }
 }
 
+impl From<[f64; 2]> for Double {
+    /// Generates a `Double` from an array of two `f64`s.
+    ///
+    /// This is the same as [`from_array`]. Like the conversion from a tuple, it uses the
+    /// elements of the array as the components of the `Double` as they are, without
+    /// renormalizing them or accounting for rounding error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let d = Double::from([3.141592653589793e0, 1.2246467991473532e-16]);
+    /// assert!(d == Double::PI);
+    /// ```
+    ///
+    /// [`from_array`]: #method.from_array
+    #[inline]
+    fn from(a: [f64; 2]) -> Double {
+        Double::from_array(a)
+    }
+}
+
+impl From<Double> for [f64; 2] {
+    /// Converts a `Double` into an array of its two components.
+    ///
+    /// This is the same as [`to_array`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let a = <[f64; 2]>::from(Double::PI);
+    /// assert!(a == [3.141592653589793e0, 1.2246467991473532e-16]);
+    /// ```
+    ///
+    /// [`to_array`]: #method.to_array
+    #[inline]
+    fn from(a: Double) -> [f64; 2] {
+        a.to_array()
+    }
+}
+
+impl AsRef<[f64; 2]> for Double {
+    /// Borrows the components of the `Double` as an array.
+    ///
+    /// No copying is involved, since a `Double` has the same layout in memory as an array of
+    /// two `f64`s. A reference to the array can be passed along to FFI or SIMD code that
+    /// wants a pointer to two `f64`s.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let x = Double::PI;
+    /// let a: &[f64; 2] = x.as_ref();
+    /// assert!(a[0] == 3.141592653589793e0);
+    /// assert!(a[1] == 1.2246467991473532e-16);
+    /// ```
+    #[inline]
+    fn as_ref(&self) -> &[f64; 2] {
+        // SAFETY: `Double` is `#[repr(C)]` and made up of nothing but two `f64`s, so it has
+        // the same size, alignment, and layout as `[f64; 2]`.
+        unsafe { &*(self as *const Double as *const [f64; 2]) }
+    }
+}
+
+// TO INTEGER IMPLEMENTATIONS
+//
+// These truncate toward zero, the same way that casting an `f64` to an integer does, but
+// they fail instead of saturating when the result doesn't fit into the target type. The
+// truncated `Double`'s components are both integers, so their sum just needs to be checked
+// against the range of the target type before it's calculated.
+
+fn to_int(x: Double, min: Double, max: Double) -> Result<i128, TryFromDoubleError> {
+    if x.is_nan() {
+        Err(TryFromDoubleError {
+            kind: TryFromErrorKind::NaN,
+        })
+    } else if x.is_infinite() {
+        Err(TryFromDoubleError {
+            kind: TryFromErrorKind::Infinite,
+        })
+    } else {
+        let t = x.trunc();
+        if t < min || t > max {
+            Err(TryFromDoubleError {
+                kind: TryFromErrorKind::OutOfRange,
+            })
+        } else {
+            Ok(u::wrapping_i128(t.0).wrapping_add(u::wrapping_i128(t.1)))
+        }
+    }
+}
+
+macro_rules! try_from_double_impl {
+    ($(
+        $(#[$m:meta])*
+        $t:ident
+    )*) => ($(
+        impl TryFrom<Double> for $t {
+            type Error = TryFromDoubleError;
+
+            $(#[$m])*
+            fn try_from(x: Double) -> Result<$t, TryFromDoubleError> {
+                to_int(x, Double::from($t::MIN), Double::from($t::MAX)).map(|n| n as $t)
+            }
+        }
+    )*);
+}
+
+try_from_double_impl! {
+    /// Converts a `Double` into an `i8`, truncating any fractional part.
+    ///
+    /// This fails with an error of the appropriate kind if the `Double` is `NaN`, infinite,
+    /// or outside the range of an `i8` once it's been truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(i8::try_from(dd!(-128.9)) == Ok(-128));
+    /// assert!(i8::try_from(dd!(128)).is_err());
+    /// ```
+    i8
+    /// Converts a `Double` into a `u8`, truncating any fractional part.
+    ///
+    /// This fails with an error of the appropriate kind if the `Double` is `NaN`, infinite,
+    /// or outside the range of a `u8` once it's been truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(u8::try_from(dd!(-0.5)) == Ok(0));
+    /// assert!(u8::try_from(dd!(-1)).is_err());
+    /// ```
+    u8
+    /// Converts a `Double` into an `i16`, truncating any fractional part.
+    ///
+    /// This fails with an error of the appropriate kind if the `Double` is `NaN`, infinite,
+    /// or outside the range of an `i16` once it's been truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(i16::try_from(dd!(32767.5)) == Ok(32767));
+    /// assert!(i16::try_from(dd!(-32769)).is_err());
+    /// ```
+    i16
+    /// Converts a `Double` into a `u16`, truncating any fractional part.
+    ///
+    /// This fails with an error of the appropriate kind if the `Double` is `NaN`, infinite,
+    /// or outside the range of a `u16` once it's been truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(u16::try_from(dd!(65535.5)) == Ok(65535));
+    /// assert!(u16::try_from(dd!(65536)).is_err());
+    /// ```
+    u16
+    /// Converts a `Double` into an `i32`, truncating any fractional part.
+    ///
+    /// This fails with an error of the appropriate kind if the `Double` is `NaN`, infinite,
+    /// or outside the range of an `i32` once it's been truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(i32::try_from(dd!(-2.5)) == Ok(-2));
+    /// assert!(i32::try_from(Double::NAN).is_err());
+    /// ```
+    i32
+    /// Converts a `Double` into a `u32`, truncating any fractional part.
+    ///
+    /// This fails with an error of the appropriate kind if the `Double` is `NaN`, infinite,
+    /// or outside the range of a `u32` once it's been truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(u32::try_from(dd!(4294967295.9)) == Ok(u32::MAX));
+    /// assert!(u32::try_from(Double::INFINITY).is_err());
+    /// ```
+    u32
+    /// Converts a `Double` into an `i64`, truncating any fractional part.
+    ///
+    /// This fails with an error of the appropriate kind if the `Double` is `NaN`, infinite,
+    /// or outside the range of an `i64` once it's been truncated. Unlike a conversion
+    /// through an `f64`, every digit of an `i64` is kept.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use std::convert::TryFrom;
+    ///
+    /// let x = Double::from(i64::MAX) - dd!(0.5);
+    /// assert!(i64::try_from(x) == Ok(i64::MAX - 1));
+    /// assert!(i64::try_from(Double::from(i64::MAX) + dd!(1)).is_err());
+    /// ```
+    i64
+    /// Converts a `Double` into a `u64`, truncating any fractional part.
+    ///
+    /// This fails with an error of the appropriate kind if the `Double` is `NaN`, infinite,
+    /// or outside the range of a `u64` once it's been truncated. Unlike a conversion through
+    /// an `f64`, every digit of a `u64` is kept.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(u64::try_from(Double::from(u64::MAX)) == Ok(u64::MAX));
+    /// assert!(u64::try_from(Double::from(u64::MAX) * Double::from(2)).is_err());
+    /// ```
+    u64
+    /// Converts a `Double` into an `i128`, truncating any fractional part.
+    ///
+    /// This fails with an error of the appropriate kind if the `Double` is `NaN`, infinite,
+    /// or outside the range of an `i128` once it's been truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(i128::try_from(dd!(2).powi(100) + dd!(0.5)) == Ok(1 << 100));
+    /// assert!(i128::try_from(dd!(2).powi(127)).is_err());
+    /// ```
+    i128
+    /// Converts a `Double` into a `u128`, truncating any fractional part.
+    ///
+    /// This fails with an error of the appropriate kind if the `Double` is `NaN`, infinite,
+    /// or outside the range of a `u128` once it's been truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(u128::try_from(Double::from(u128::MAX)) == Ok(u128::MAX));
+    /// assert!(u128::try_from(dd!(2).powi(128)).is_err());
+    /// ```
+    u128
+}
+
+impl Double {
+    /// Converts the `Double` into an `i64`, truncating any fractional part and saturating at
+    /// the bounds of an `i64`.
+    ///
+    /// This works the way that casting an `f64` to an `i64` does: a `Double` too large in
+    /// either direction (including an infinity) becomes [`i64::MAX`] or [`i64::MIN`], and
+    /// `NaN` becomes 0. Use `i64::try_from` instead to get an error in those cases.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::from(i64::MAX) - dd!(0.5);
+    /// assert!(x.to_i64_trunc() == i64::MAX - 1);
+    /// assert!(dd!(-1e30).to_i64_trunc() == i64::MIN);
+    /// assert!(Double::NAN.to_i64_trunc() == 0);
+    /// ```
+    ///
+    /// [`i64::MAX`]: https://doc.rust-lang.org/std/primitive.i64.html#associatedconstant.MAX
+    /// [`i64::MIN`]: https://doc.rust-lang.org/std/primitive.i64.html#associatedconstant.MIN
+    pub fn to_i64_trunc(self) -> i64 {
+        saturate(self, i64::try_from(self), i64::MIN, i64::MAX)
+    }
+
+    /// Converts the `Double` into a `u64`, truncating any fractional part and saturating at
+    /// the bounds of a `u64`.
+    ///
+    /// This works the way that casting an `f64` to a `u64` does: a `Double` too large
+    /// (including positive infinity) becomes [`u64::MAX`], one that's negative becomes 0, and
+    /// `NaN` becomes 0. Use `u64::try_from` instead to get an error in those cases.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::from(u64::MAX).to_u64_trunc() == u64::MAX);
+    /// assert!(dd!(-3.5).to_u64_trunc() == 0);
+    /// assert!(Double::INFINITY.to_u64_trunc() == u64::MAX);
+    /// ```
+    ///
+    /// [`u64::MAX`]: https://doc.rust-lang.org/std/primitive.u64.html#associatedconstant.MAX
+    pub fn to_u64_trunc(self) -> u64 {
+        saturate(self, u64::try_from(self), u64::MIN, u64::MAX)
+    }
+
+    /// Converts the `Double` into an `i128`, truncating any fractional part and saturating
+    /// at the bounds of an `i128`.
+    ///
+    /// This works the way that casting an `f64` to an `i128` does: a `Double` too large in
+    /// either direction (including an infinity) becomes [`i128::MAX`] or [`i128::MIN`], and
+    /// `NaN` becomes 0. Use `i128::try_from` instead to get an error in those cases.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!((dd!(2).powi(100) + dd!(3.75)).to_i128_trunc() == (1 << 100) + 3);
+    /// assert!(Double::NEG_INFINITY.to_i128_trunc() == i128::MIN);
+    /// ```
+    ///
+    /// [`i128::MAX`]: https://doc.rust-lang.org/std/primitive.i128.html#associatedconstant.MAX
+    /// [`i128::MIN`]: https://doc.rust-lang.org/std/primitive.i128.html#associatedconstant.MIN
+    pub fn to_i128_trunc(self) -> i128 {
+        saturate(self, i128::try_from(self), i128::MIN, i128::MAX)
+    }
+
+    /// Converts the `Double` into a `u128`, truncating any fractional part and saturating
+    /// at the bounds of a `u128`.
+    ///
+    /// This works the way that casting an `f64` to a `u128` does: a `Double` too large
+    /// (including positive infinity) becomes [`u128::MAX`], one that's negative becomes 0,
+    /// and `NaN` becomes 0. Use `u128::try_from` instead to get an error in those cases.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!((dd!(2).powi(120) - dd!(0.5)).to_u128_trunc() == (1 << 120) - 1);
+    /// assert!(dd!(1e300).to_u128_trunc() == u128::MAX);
+    /// ```
+    ///
+    /// [`u128::MAX`]: https://doc.rust-lang.org/std/primitive.u128.html#associatedconstant.MAX
+    pub fn to_u128_trunc(self) -> u128 {
+        saturate(self, u128::try_from(self), u128::MIN, u128::MAX)
+    }
+}
+
+fn saturate<T>(x: Double, result: Result<T, TryFromDoubleError>, min: T, max: T) -> T
+where
+    T: Default,
+{
+    match result {
+        Ok(n) => n,
+        Err(_) if x.is_nan() => T::default(),
+        Err(_) if x.is_sign_negative() => min,
+        Err(_) => max,
+    }
+}
+
+// Divides one integer by another, giving the nearest `Double` to the exact quotient. Each
+// component is the nearest `f64` to what's left of the quotient once the ones before it have
+// been taken off. A zero divisor gives an infinity or NaN, as dividing by zero does.
+#[cfg(any(feature = "num-rational", feature = "bigdecimal"))]
+pub(super) fn from_quotient(n: &BigInt, d: &BigInt) -> Double {
+    let (n, d) = match d.sign() {
+        Sign::NoSign => return Double::from(n) / Double::ZERO,
+        Sign::Minus => (-n, -d),
+        Sign::Plus => (n.clone(), d.clone()),
+    };
+    let parts = b::split_fraction(n, d, 2);
+    Double(parts[0], parts[1])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::ErrorKind;
 
     // f32 tests
     test_all_exact!(
@@ -431,6 +1164,78 @@ mod tests {
         assert_ne!(dd!(1.1).1, 0.0);
     });
 
+    // to f32 tests
+    test!(to_f32_rounded: {
+        assert!(f32::from(dd!(1.5)) == 1.5);
+        assert!(f32::from(Double::PI) == std::f32::consts::PI);
+        assert!(f32::from(-Double::E) == -std::f32::consts::E);
+        assert!(f32::from(dd!(1) / dd!(3)) == 1.0 / 3.0);
+    });
+    test!(to_f32_ties: {
+        // 1 + 2⁻²⁴ is exactly halfway between two `f32`s, so the rest decides
+        assert!(f32::from(Double(1.0 + 2f64.powi(-24), 0.0)) == 1.0);
+        assert!(f32::from(Double(1.0 + 2f64.powi(-23) + 2f64.powi(-24), 0.0)) == 1.0000002);
+        assert!(f32::from(Double(1.0 + 2f64.powi(-24), 2f64.powi(-80))) == 1.0000001);
+        assert!(f32::from(Double(1.0 + 2f64.powi(-24), -2f64.powi(-80))) == 1.0);
+        assert!(f32::from(Double(-1.0 - 2f64.powi(-24), -2f64.powi(-80))) == -1.0000001);
+    });
+    test!(to_f32_range: {
+        assert!(f32::from(dd!(1e300)) == f32::INFINITY);
+        assert!(f32::from(dd!(-1e300)) == f32::NEG_INFINITY);
+        assert!(f32::from(dd!(f32::MAX)) == f32::MAX);
+        assert!(f32::from(dd!(2).powi(-149)) == f32::from_bits(1));
+        assert!(f32::from(Double(2f64.powi(-150), 2f64.powi(-210))) == f32::from_bits(1));
+        assert!(f32::from(dd!(2).powi(-150)) == 0.0);
+        assert!(f32::from(dd!(1e-300)) == 0.0);
+    });
+    test!(to_f32_special: {
+        assert!(f32::from(Double::ZERO) == 0.0);
+        assert!(f32::from(Double::NEG_ZERO).is_sign_negative());
+        assert!(f32::from(Double::INFINITY) == f32::INFINITY);
+        assert!(f32::from(Double::NEG_INFINITY) == f32::NEG_INFINITY);
+        assert!(f32::from(Double::NAN).is_nan());
+    });
+
+    // half tests
+    #[cfg(feature = "half")]
+    test!(from_half: {
+        assert!(Double::from(f16::from_f32(-1.5)) == dd!(-1.5));
+        assert!(Double::from(f16::MAX) == dd!(65504));
+        assert!(Double::from(f16::from_bits(1)) == dd!(2).powi(-24));
+        assert!(Double::from(f16::INFINITY) == Double::INFINITY);
+        assert!(Double::from(f16::NAN).is_nan());
+        assert!(Double::from(bf16::from_f32(0.375)) == dd!(0.375));
+        assert!(Double::from(bf16::MAX) == dd!(bf16::MAX.to_f64()));
+        assert!(Double::from(bf16::NEG_INFINITY) == Double::NEG_INFINITY);
+    });
+    #[cfg(feature = "half")]
+    test!(to_f16: {
+        assert!(f16::from(dd!(1) / dd!(3)) == f16::from_bits(0x3555));
+        assert!(f16::from(Double::PI) == f16::from_bits(0x4248));
+        // 1 + 2⁻¹¹ is exactly halfway between two `f16`s
+        assert!(f16::from(dd!(1) + dd!(2).powi(-11)) == f16::ONE);
+        assert!(f16::from(Double(1.0 + 2f64.powi(-11), 2f64.powi(-80))) == f16::from_bits(0x3c01));
+        assert!(f16::from(dd!(65519)) == f16::MAX);
+        assert!(f16::from(dd!(65520)) == f16::INFINITY);
+        assert!(f16::from(dd!(-1e10)) == f16::NEG_INFINITY);
+        assert!(f16::from(dd!(2).powi(-24)) == f16::from_bits(1));
+        assert!(f16::from(Double(2f64.powi(-25), 2f64.powi(-90))) == f16::from_bits(1));
+        assert!(f16::from(dd!(2).powi(-25)) == f16::ZERO);
+        assert!(f16::from(Double::NEG_ZERO).is_sign_negative());
+        assert!(f16::from(Double::NAN).is_nan());
+    });
+    #[cfg(feature = "half")]
+    test!(to_bf16: {
+        assert!(bf16::from(Double::PI) == bf16::from_f32(3.140625));
+        // 1 + 2⁻⁸ is exactly halfway between two `bf16`s
+        assert!(bf16::from(dd!(1) + dd!(2).powi(-8)) == bf16::ONE);
+        assert!(bf16::from(Double(1.0 + 2f64.powi(-8), 2f64.powi(-80))) == bf16::from_bits(0x3f81));
+        assert!(bf16::from(dd!(1e300)) == bf16::INFINITY);
+        assert!(bf16::from(dd!(2).powi(-133)) == bf16::from_bits(1));
+        assert!(bf16::from(dd!(2).powi(-134)) == bf16::ZERO);
+        assert!(bf16::from(Double::NAN).is_nan());
+    });
+
     // integer tests
     test_all_eq!(
         i8_min: i8::MIN.to_string(), dd!(i8::MIN).to_string();
@@ -442,4 +1247,301 @@ mod tests {
         i64_min: i64::MIN.to_string(), dd!(i64::MIN).to_string();
         u64_max: u64::MAX.to_string(), dd!(u64::MAX).to_string();
     );
+    test_all_exact!(
+        i128_min:
+            Double(-2f64.powi(127), 0.0),
+            dd!(i128::MIN);
+        i128_max:
+            Double(2f64.powi(127), -1.0),
+            dd!(i128::MAX);
+        u128_max:
+            Double(2f64.powi(128), -1.0),
+            dd!(u128::MAX);
+        u128_two_parts:
+            Double(2f64.powi(100), 1.0),
+            dd!((1u128 << 100) + 1);
+        u128_round_down:
+            Double(2f64.powi(110), 2f64.powi(56)),
+            dd!((1u128 << 110) + (1 << 56) + (1 << 2));
+        u128_round_up:
+            Double(2f64.powi(110), 2f64.powi(56) + 2f64.powi(4)),
+            dd!((1u128 << 110) + (1 << 56) + (1 << 3) + 1);
+        u128_tie_even:
+            Double(2f64.powi(110), 2f64.powi(56)),
+            dd!((1u128 << 110) + (1 << 56) + (1 << 3));
+        u128_tie_odd:
+            Double(2f64.powi(110), 2f64.powi(56) + 2f64.powi(5)),
+            dd!((1u128 << 110) + (1 << 56) + (1 << 4) + (1 << 3));
+        u128_carry:
+            Double(2f64.powi(110), -1.0),
+            dd!((1u128 << 110) - 1);
+        i128_neg:
+            Double(-2f64.powi(110), -2f64.powi(56) - 2f64.powi(4)),
+            dd!(-(1i128 << 110) - (1 << 56) - (1 << 3) - 1);
+    );
+    test_all_assert!(
+        u128_exact:
+            Double::from_u128_exact((1 << 120) + (1 << 15))
+                == Some(Double(2f64.powi(120), 2f64.powi(15)));
+        u128_exact_zero:
+            Double::from_u128_exact(0) == Some(Double::ZERO);
+        u128_exact_max:
+            Double::from_u128_exact(u128::MAX) == Some(Double(2f64.powi(128), -1.0));
+        u128_inexact:
+            Double::from_u128_exact((1 << 120) + (1 << 60) + 1).is_none();
+        i128_exact:
+            Double::from_i128_exact(i128::MIN) == Some(Double(-2f64.powi(127), 0.0));
+        i128_exact_neg:
+            Double::from_i128_exact(-(1 << 110) - 1) == Some(Double(-2f64.powi(110), -1.0));
+        i128_inexact:
+            Double::from_i128_exact(-(1 << 110) - (1 << 56) - 1).is_none();
+    );
+
+    // to integer tests
+    fn kind<T: TryFrom<Double, Error = TryFromDoubleError>>(x: Double) -> TryFromErrorKind {
+        match T::try_from(x) {
+            Err(e) => e.kind,
+            Ok(_) => panic!("conversion should have failed"),
+        }
+    }
+
+    test!(to_int_small: {
+        assert_eq!(i8::try_from(dd!(-128.9)), Ok(-128));
+        assert_eq!(i8::try_from(dd!(127.9)), Ok(127));
+        assert_eq!(u8::try_from(dd!(255.5)), Ok(255));
+        assert_eq!(u8::try_from(dd!(-0.5)), Ok(0));
+        assert_eq!(i16::try_from(dd!(-32768)), Ok(-32768));
+        assert_eq!(u16::try_from(dd!(65535)), Ok(65535));
+        assert_eq!(i32::try_from(dd!(-2.5)), Ok(-2));
+        assert_eq!(u32::try_from(dd!(4294967295.5)), Ok(u32::MAX));
+    });
+    test!(to_int_wide: {
+        assert_eq!(i64::try_from(dd!(i64::MIN)), Ok(i64::MIN));
+        assert_eq!(i64::try_from(dd!(i64::MAX)), Ok(i64::MAX));
+        assert_eq!(i64::try_from(dd!(i64::MAX) - dd!(0.5)), Ok(i64::MAX - 1));
+        assert_eq!(u64::try_from(dd!(u64::MAX)), Ok(u64::MAX));
+        assert_eq!(i128::try_from(dd!(i128::MIN)), Ok(i128::MIN));
+        assert_eq!(i128::try_from(dd!(i128::MAX)), Ok(i128::MAX));
+        assert_eq!(u128::try_from(dd!(u128::MAX)), Ok(u128::MAX));
+        assert_eq!(i128::try_from(-dd!(2).powi(100) - dd!(3.5)), Ok(-(1 << 100) - 3));
+        // The first component is rounded up past the largest u128, and the second brings it
+        // back into range
+        assert_eq!(
+            u128::try_from(Double(2f64.powi(128), -2f64.powi(20))),
+            Ok(u128::MAX - (1 << 20) + 1)
+        );
+    });
+    test!(to_int_errors: {
+        assert_eq!(kind::<i8>(dd!(128)), TryFromErrorKind::OutOfRange);
+        assert_eq!(kind::<i8>(dd!(-129)), TryFromErrorKind::OutOfRange);
+        assert_eq!(kind::<u8>(dd!(-1)), TryFromErrorKind::OutOfRange);
+        assert_eq!(kind::<u32>(dd!(4294967296u64)), TryFromErrorKind::OutOfRange);
+        assert_eq!(kind::<i64>(dd!(i64::MAX) + dd!(1)), TryFromErrorKind::OutOfRange);
+        assert_eq!(kind::<i128>(dd!(2).powi(127)), TryFromErrorKind::OutOfRange);
+        assert_eq!(kind::<u128>(dd!(2).powi(128)), TryFromErrorKind::OutOfRange);
+        assert_eq!(kind::<i32>(Double::NAN), TryFromErrorKind::NaN);
+        assert_eq!(kind::<u64>(Double::INFINITY), TryFromErrorKind::Infinite);
+        assert_eq!(kind::<i128>(Double::NEG_INFINITY), TryFromErrorKind::Infinite);
+    });
+    test!(to_int_trunc: {
+        assert_eq!((dd!(i64::MAX) - dd!(0.5)).to_i64_trunc(), i64::MAX - 1);
+        assert_eq!(dd!(-1e30).to_i64_trunc(), i64::MIN);
+        assert_eq!(Double::INFINITY.to_i64_trunc(), i64::MAX);
+        assert_eq!(Double::NAN.to_i64_trunc(), 0);
+        assert_eq!(dd!(-3.5).to_u64_trunc(), 0);
+        assert_eq!(dd!(1e30).to_u64_trunc(), u64::MAX);
+        assert_eq!(Double::NEG_INFINITY.to_i128_trunc(), i128::MIN);
+        assert_eq!((dd!(2).powi(100) + dd!(3.75)).to_i128_trunc(), (1 << 100) + 3);
+        assert_eq!(dd!(1e300).to_u128_trunc(), u128::MAX);
+        assert_eq!(Double::NAN.to_u128_trunc(), 0);
+    });
+
+    // string tests
+    test!(str_try_from: {
+        exact!(dd!(1.5), Double::try_from("1.5").unwrap());
+        exact!(dd!(-12325), Double::try_from("-123.25e2").unwrap());
+        exact!(Double::INFINITY, Double::try_from("inf").unwrap());
+        assert!(Double::try_from("nan").unwrap().is_nan());
+        assert!(Double::try_from("0.1") == "0.1".parse::<Double>());
+    });
+    test!(str_try_from_error: {
+        let error = |kind, position| Err(ParseDoubleError { kind, position });
+        assert!(Double::try_from("1.2.3") == error(ErrorKind::TooManyPoints, 3));
+        assert!(Double::try_from("1.5-") == error(ErrorKind::Invalid, 3));
+        assert!(Double::try_from("abc") == error(ErrorKind::InvalidDigit, 0));
+        assert!(Double::try_from("") == error(ErrorKind::Empty, 0));
+    });
+    test!(str_macro: {
+        exact!(dd!(2.25), dd!("2.25"));
+        assert!(dd!("nan").is_nan());
+    });
+    #[test]
+    #[should_panic(
+        expected = "too many decimal points in double-double literal at byte 3: \"1.2.3\""
+    )]
+    fn str_macro_invalid() {
+        dd!("1.2.3");
+    }
+    #[test]
+    #[should_panic(expected = "cannot parse double-double from empty string")]
+    fn str_macro_empty() {
+        dd!("");
+    }
+
+    // array tests
+    test!(array_from: {
+        let x = Double::from([3.0, 1e-17]);
+        assert!(x[0] == 3.0);
+        assert!(x[1] == 1e-17);
+        // Like tuple conversion, there's no renormalization
+        let y = Double::from([1.0, 1.0]);
+        assert!(y[0] == 1.0);
+        assert!(y[1] == 1.0);
+    });
+    test!(array_to: {
+        assert!(Double::PI.to_array() == [Double::PI[0], Double::PI[1]]);
+        assert!(<[f64; 2]>::from(Double::E) == [Double::E[0], Double::E[1]]);
+        assert!(Double::NEG_ZERO.to_array()[0].is_sign_negative());
+        assert!(Double::NAN.to_array()[0].is_nan());
+    });
+    test!(array_as_ref: {
+        let x = Double::LN_2;
+        let a: &[f64; 2] = x.as_ref();
+        assert!(*a == x.to_array());
+        assert!(a.as_ptr() == &x as *const Double as *const f64);
+    });
+    test!(array_round_trip: {
+        for x in [Double::PI, -Double::E, Double::MAX, Double::INFINITY, Double::ZERO] {
+            exact!(x, Double::from(x.to_array()));
+            exact!(x, Double::from(*x.as_ref()));
+            exact!(x, Double::from_array(x.to_array()));
+        }
+    });
+
+    // BigInt tests
+    #[cfg(feature = "num-bigint")]
+    mod bigint {
+        use super::*;
+        use num_bigint::{BigInt, BigUint};
+
+        fn pow2(n: u32) -> BigInt {
+            BigInt::from(1) << n
+        }
+
+        test_all_exact!(
+            bigint_zero:
+                Double::ZERO,
+                Double::from(&BigInt::from(0));
+            bigint_small:
+                dd!(-12345),
+                Double::from(&BigInt::from(-12345));
+            bigint_pow2:
+                dd!(2).powi(100),
+                Double::from(&pow2(100));
+            bigint_exact:
+                Double(2f64.powi(105), 1.0),
+                Double::from(&(pow2(105) + 1));
+            bigint_sparse:
+                Double(2f64.powi(400), 2f64.powi(294)),
+                Double::from(&(pow2(400) + pow2(294)));
+            bigint_round_down:
+                Double(2f64.powi(400), 2f64.powi(300)),
+                Double::from(&(pow2(400) + pow2(300) + pow2(246)));
+            bigint_round_up:
+                Double(2f64.powi(400), 2f64.powi(300) + 2f64.powi(248)),
+                Double::from(&(pow2(400) + pow2(300) + pow2(247) + 1));
+            bigint_tie_even:
+                Double(2f64.powi(400), 2f64.powi(300)),
+                Double::from(&(pow2(400) + pow2(300) + pow2(247)));
+            bigint_tie_odd:
+                Double(2f64.powi(400), 2f64.powi(300) + 2f64.powi(249)),
+                Double::from(&(pow2(400) + pow2(300) + pow2(248) + pow2(247)));
+            bigint_carry:
+                Double(2f64.powi(401), -1.0),
+                Double::from(&(pow2(401) - 1));
+            bigint_below_pow2:
+                Double(2f64.powi(107), -1.0),
+                Double::from(&(pow2(107) - 1));
+            bigint_neg:
+                -Double(2f64.powi(400), 2f64.powi(300) + 2f64.powi(248)),
+                Double::from(&(-pow2(400) - pow2(300) - pow2(247) - 1u32));
+            bigint_near_max:
+                Double(2f64.powi(1023), 2f64.powi(930)),
+                Double::from(&(pow2(1023) + pow2(930)));
+            bigint_overflow:
+                Double::INFINITY,
+                Double::from(&(pow2(1024) - 1));
+            bigint_neg_overflow:
+                Double::NEG_INFINITY,
+                Double::from(&-pow2(2000));
+        );
+        test!(bigint_nearest: {
+            // 3²⁵⁰ has 397 bits. The error of the conversion, calculated exactly, has to be
+            // no more than half of the value of the 106th bit.
+            let n = BigInt::from(3).pow(250);
+            let x = Double::from(&n);
+            let back = to_bigint(x.0) + to_bigint(x.1);
+            let error = (n - back) * BigInt::from(2);
+            assert!(error.magnitude() <= pow2(397 - 106).magnitude());
+        });
+
+        test_all_exact!(
+            biguint_zero:
+                Double::ZERO,
+                Double::from(&BigUint::from(0u32));
+            biguint_round_up:
+                Double(2f64.powi(400), 2f64.powi(300) + 2f64.powi(248)),
+                Double::from((pow2(400) + pow2(300) + pow2(247) + 1u32).magnitude());
+            biguint_overflow:
+                Double::INFINITY,
+                Double::from(&(pow2(1024).magnitude() - 1u32));
+        );
+
+        // to_bigint tests
+        test!(to_bigint_integers: {
+            assert_eq!(Double::ZERO.to_bigint(), Some(BigInt::from(0)));
+            assert_eq!(dd!(-12345).to_bigint(), Some(BigInt::from(-12345)));
+            assert_eq!(Double(2f64.powi(105), 1.0).to_bigint(), Some(pow2(105) + 1));
+            let n = -pow2(400) - pow2(295);
+            assert_eq!(Double::from(&n).to_bigint(), Some(n));
+        });
+        test!(to_bigint_fractions: {
+            assert_eq!(dd!(0.75).to_bigint(), Some(BigInt::from(0)));
+            assert_eq!(dd!(-0.75).to_bigint(), Some(BigInt::from(0)));
+            assert_eq!(dd!(-2.5).to_bigint(), Some(BigInt::from(-2)));
+            // The fractional part is only in the second component
+            assert_eq!(Double(2f64.powi(60), 0.5).to_bigint(), Some(pow2(60)));
+            assert_eq!(Double(2f64.powi(60), -0.5).to_bigint(), Some(pow2(60) - 1));
+        });
+        test!(to_bigint_special: {
+            assert!(Double::NAN.to_bigint().is_none());
+            assert!(Double::INFINITY.to_bigint().is_none());
+            assert!(Double::NEG_INFINITY.to_bigint().is_none());
+            let max = ((pow2(53) - 1) << 971) + ((pow2(53) - 1) << 917);
+            assert_eq!(Double::MAX.to_bigint(), Some(max));
+        });
+        test!(to_bigint_round_trip: {
+            // 3⁶⁶ has 105 bits, so it fits
+            let n = BigInt::from(3).pow(66);
+            assert_eq!(Double::from(&n).to_bigint(), Some(n.clone()));
+            assert_eq!(Double::from(&-n.clone()).to_bigint(), Some(-n));
+        });
+
+        // Converts an integral `f64` to a `BigInt` exactly
+        fn to_bigint(x: f64) -> BigInt {
+            let bits = x.to_bits();
+            let exp = ((bits >> 52) & 0x7ff) as i32 - 1075;
+            let mantissa = BigInt::from((bits & 0xf_ffff_ffff_ffff) | (1 << 52));
+            let mag = if exp >= 0 {
+                mantissa << exp as u32
+            } else {
+                mantissa >> (-exp) as u32
+            };
+            if x < 0.0 {
+                -mag
+            } else {
+                mag
+            }
+        }
+    }
 }
@@ -3,12 +3,12 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::parse;
 use crate::double::Double;
 use crate::error::{ErrorKind, ParseDoubleError};
+use crate::options::ParseOptions;
 use std::str::FromStr;
 
-const TEN: Double = Double(10.0, 0.0);
-
 impl FromStr for Double {
     type Err = ParseDoubleError;
 
@@ -20,7 +20,22 @@ impl FromStr for Double {
     /// wrong places; two decimal points or a negative sign after the number will both be
     /// rejected, for instance.
     ///
-    /// Failure will return a [`ParseDoubleError`] of some kind.
+    /// Failure will return a [`ParseDoubleError`] that gives the kind of the problem and the
+    /// byte offset in the string where it was found.
+    ///
+    /// The result is correctly rounded. Its first component is the `f64` nearest to the
+    /// exact value of the decimal string, and its second is the `f64` nearest to what's
+    /// left once the first is subtracted, with ties going to the one with an even last bit.
+    /// This is done with exact integer arithmetic, so it holds for strings of any length
+    /// and any exponent; a string that's an exact expansion of a `Double` always parses
+    /// back to exactly that `Double`. A number too large for a `Double` becomes an
+    /// infinity, and one too small becomes a subnormal or zero.
+    ///
+    /// A string that starts with `0x` or `0X` (after any sign) is instead read as a C99-style
+    /// hexadecimal float, like `0x1.8p+3` (which is 12), with an optional exponent that's
+    /// a power of two. It's rounded in the same way. This is the format written by the
+    /// [`LowerHex`] implementation, which is exact, so it's a way to get exact binary
+    /// values in and out of a `Double` as text.
     ///
     /// # Examples
     /// ```
@@ -44,98 +59,256 @@ impl FromStr for Double {
     /// ```
     ///
     /// [`ParseDoubleError`]: error/struct.ParseDoubleError.html
+    /// [`LowerHex`]: #impl-LowerHex-for-Double
     fn from_str(s: &str) -> Result<Double, ParseDoubleError> {
-        let mut result = Double::ZERO;
-        let mut digits = 0;
-        let mut point = -1;
-        let mut sign = 0;
-        let mut exp = 0;
+        Double::from_str_with(s, &ParseOptions::default())
+    }
+}
 
-        let s = s.trim().to_ascii_lowercase();
+impl Double {
+    /// Parses a string to create a `Double`, using the given options for the separators in
+    /// decimal strings.
+    ///
+    /// This works exactly like [`from_str`], except that the group separator in `options`
+    /// (if there is one) is ignored among the digits, in the same way that underscores
+    /// are, and that the decimal separator in `options` takes the place of the period. That
+    /// makes it possible to read numbers like `1,000,000.5` or `1.000.000,5` without
+    /// preprocessing them. Hex strings, `NaN`, and the infinities are read the same way
+    /// that [`from_str`] reads them, and positions in errors are still byte offsets in
+    /// the original string.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, ParseOptions};
+    /// let options = ParseOptions {
+    ///     group_separator: Some(' '),
+    ///     decimal_separator: ',',
+    /// };
+    /// assert!(Double::from_str_with("1 000 000,5", &options).unwrap() == dd!(1000000.5));
+    /// assert!(Double::from_str_with("2,5e-3", &options).unwrap() == dd!("2.5e-3"));
+    /// assert!(Double::from_str_with("2.5", &options).is_err());
+    /// ```
+    ///
+    /// [`from_str`]: #method.from_str
+    pub fn from_str_with(s: &str, options: &ParseOptions) -> Result<Double, ParseDoubleError> {
+        // Positions in errors are byte offsets in the original string, so they have to
+        // account for any whitespace that's trimmed off of the front
+        let start = s.len() - s.trim_start().len();
+        let s = s.trim();
 
-        match pre_from_str(&s) {
+        match pre_from_str(s) {
             Some(r) => r,
             None => {
-                for (index, ch) in s.chars().enumerate() {
-                    match ch.to_digit(10) {
-                        Some(d) => {
-                            result *= TEN;
-                            result += Double(d as f64, 0.0);
-                            digits += 1;
-                        }
-                        None => match ch {
-                            '.' => {
-                                if point >= 0 {
-                                    return Err(ParseDoubleError {
-                                        kind: ErrorKind::Invalid,
-                                    });
-                                }
-                                point = digits;
-                            }
-                            '-' => {
-                                if sign != 0 || digits > 0 {
-                                    return Err(ParseDoubleError {
-                                        kind: ErrorKind::Invalid,
-                                    });
-                                }
-                                sign = -1;
-                            }
-                            '+' => {
-                                if sign != 0 || digits > 0 {
-                                    return Err(ParseDoubleError {
-                                        kind: ErrorKind::Invalid,
-                                    });
-                                }
-                                sign = 1;
-                            }
-                            'e' => {
-                                let end = &s[(index + 1)..];
-                                match end.parse::<i32>() {
-                                    Ok(e) => {
-                                        exp = e;
-                                        break;
-                                    }
-                                    Err(_) => {
-                                        return Err(ParseDoubleError {
-                                            kind: ErrorKind::Invalid,
-                                        });
-                                    }
-                                }
-                            }
-                            '_' => {
-                                // just continue; _ is a no-op but not an error
-                            }
-                            _ => {
-                                return Err(ParseDoubleError {
-                                    kind: ErrorKind::Invalid,
-                                });
-                            }
-                        },
-                    }
-                }
+                let parts = if parse::is_hex(s) {
+                    parse::hex(s, start, 2)
+                } else {
+                    parse::decimal(s, start, 2, options)
+                };
+                parts
+                    .map(|p| Double(p[0], p[1]))
+                    .map_err(|(kind, position)| ParseDoubleError { kind, position })
+            }
+        }
+    }
 
-                if point >= 0 {
-                    exp -= digits - point;
-                }
-                if exp != 0 {
-                    // Do this in two stages if the exponent is too small. For exmaple, a
-                    // number with 30 digits could have an exponent as low as -337 and still
-                    // not overflow, but doing the -337 all at once WOULD overflow
-                    if exp < -307 {
-                        let adjust = exp + 307;
-                        result *= TEN.powi(adjust);
-                        exp -= adjust;
+    /// Parses a byte slice of ASCII text to create a `Double`.
+    ///
+    /// This is [`from_str`] for input that comes in as bytes, like a field read from a file
+    /// or a network buffer, so that it doesn't have to be turned into a `&str` first. It
+    /// accepts exactly what [`from_str`] accepts and gives exactly the same result. Neither
+    /// one allocates, which makes this suitable for parsing large amounts of data. Bytes
+    /// that aren't valid UTF-8 are reported as an invalid digit at the position of the first
+    /// one of them.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::from_ascii(b"2.5E-3").unwrap() == dd!("2.5e-3"));
+    /// assert!(Double::from_ascii(b"-0X1.8P1").unwrap() == dd!(-3));
+    /// assert!(Double::from_ascii(b"NaN").unwrap().is_nan());
+    /// assert!(Double::from_ascii(b"1.5\xff").is_err());
+    /// ```
+    ///
+    /// [`from_str`]: #method.from_str
+    pub fn from_ascii(bytes: &[u8]) -> Result<Double, ParseDoubleError> {
+        match std::str::from_utf8(bytes) {
+            Ok(s) => s.parse(),
+            Err(e) => Err(ParseDoubleError {
+                kind: ErrorKind::InvalidDigit,
+                position: e.valid_up_to(),
+            }),
+        }
+    }
+
+    /// Parses a string in the given radix, which can be anything from 2 to 36, to create a
+    /// `Double`.
+    ///
+    /// This mirrors the `from_str_radix` functions of the integer types. The string is an
+    /// optional sign followed by digits in the radix, where the digits past 9 are the
+    /// letters `a` through `z` (in either case), with an optional point among them.
+    /// Underscores are ignored, and leading and trailing whitespace is trimmed. There's no
+    /// exponent, since `e` is a digit in the larger radices, and there are no special
+    /// tokens for `NaN` or the infinities. The exception is radix 10, for which this does
+    /// exactly what [`from_str`] does.
+    ///
+    /// The result is correctly rounded in the same way as it is by [`from_str`], and a
+    /// string produced by [`to_string_radix`] always parses back into exactly the `Double`
+    /// that produced it. Failure returns a [`ParseDoubleError`] with the kind of the problem and
+    /// the byte offset where it was found, as it does for [`from_str`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::from_str_radix("-1010.11", 2).unwrap() == dd!(-10.75));
+    /// assert!(Double::from_str_radix("FF.8", 16).unwrap() == dd!(255.5));
+    /// assert!(Double::from_str_radix("z", 36).unwrap() == dd!(35));
+    /// assert!(Double::from_str_radix("12", 2).is_err());
+    /// ```
+    ///
+    /// [`from_str`]: #method.from_str
+    /// [`to_string_radix`]: #method.to_string_radix
+    /// [`ParseDoubleError`]: error/struct.ParseDoubleError.html
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Double, ParseDoubleError> {
+        assert!(
+            (2..=36).contains(&radix),
+            "from_str_radix: radix must lie in the range `[2, 36]`, found {}",
+            radix
+        );
+        if radix == 10 {
+            return s.parse();
+        }
+
+        let start = s.len() - s.trim_start().len();
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseDoubleError {
+                kind: ErrorKind::Empty,
+                position: 0,
+            });
+        }
+        parse::radix(s, start, radix, 2)
+            .map(|p| Double(p[0], p[1]))
+            .map_err(|(kind, position)| ParseDoubleError { kind, position })
+    }
+
+    /// Parses a canonical decimal string, as produced by [`to_canonical`], to create a
+    /// `Double`.
+    ///
+    /// Only strings in exactly the canonical form are accepted: the two components, each
+    /// written as one digit, a decimal point, 16 more digits, and an `e` followed by a sign
+    /// and at least two digits of exponent, with an optional minus sign before the first
+    /// component and a required sign before the second. The tokens `NaN`, `inf`, and `-inf`
+    /// are accepted too. Anything else, including strings that `parse` would accept and
+    /// components written in some other way that reads as the same `f64`, returns a
+    /// [`ParseDoubleError`].
+    ///
+    /// The components are read exactly and used as they are, so any `Double` passed through
+    /// [`to_canonical`] comes back exactly the same, component for component.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::from_canonical("1.5000000000000000e+00+0.0000000000000000e+00").unwrap();
+    /// assert!(x == dd!(1.5));
+    ///
+    /// let pi = Double::from_canonical(&Double::PI.to_canonical()).unwrap();
+    /// assert!(pi == Double::PI);
+    ///
+    /// assert!(Double::from_canonical("1.5").is_err());
+    /// ```
+    ///
+    /// [`to_canonical`]: #method.to_canonical
+    /// [`ParseDoubleError`]: error/struct.ParseDoubleError.html
+    pub fn from_canonical(s: &str) -> Result<Double, ParseDoubleError> {
+        let invalid = |position| {
+            Err(ParseDoubleError {
+                kind: ErrorKind::Invalid,
+                position,
+            })
+        };
+        match s {
+            "" => Err(ParseDoubleError {
+                kind: ErrorKind::Empty,
+                position: 0,
+            }),
+            "NaN" => Ok(Double::NAN),
+            "inf" => Ok(Double::INFINITY),
+            "-inf" => Ok(Double::NEG_INFINITY),
+            _ => match canonical_shape(s) {
+                Err(position) => invalid(position),
+                Ok(mid) => {
+                    // The shape guarantees that both halves parse
+                    let high = s[..mid].parse::<f64>().unwrap();
+                    let low = s[mid..].parse::<f64>().unwrap();
+                    if !high.is_finite() || !low.is_finite() {
+                        let position = if high.is_finite() { mid } else { 0 };
+                        return Err(ParseDoubleError {
+                            kind: ErrorKind::ExponentOverflow,
+                            position,
+                        });
+                    }
+                    // Catch anything of the right shape that isn't how `to_canonical` would
+                    // have written these components, like a zero with an exponent
+                    let x = Double(high, low);
+                    let expected = x.to_canonical();
+                    match s.bytes().zip(expected.bytes()).position(|(a, b)| a != b) {
+                        Some(position) => invalid(position),
+                        None if s.len() != expected.len() => invalid(s.len().min(expected.len())),
+                        None => Ok(x),
                     }
-                    result *= TEN.powi(exp);
-                }
-                if sign == -1 {
-                    result = -result;
                 }
+            },
+        }
+    }
+}
 
-                Ok(result)
-            }
+// Checks that a string has the shape of a finite canonical string: an optional minus sign,
+// a component, and then a sign and another component. Each component is a digit, a decimal
+// point, 16 digits, and an exponent with a sign and at least two digits. This returns where
+// the second component's sign is, or the first place where the string departs from that
+// shape (which is its length if it's only too short).
+fn canonical_shape(s: &str) -> Result<usize, usize> {
+    let bytes = s.as_bytes();
+    let start = if s.starts_with('-') { 1 } else { 0 };
+    let mid = component_shape(bytes, start)?;
+    match bytes.get(mid) {
+        Some(b'+') | Some(b'-') => {}
+        _ => return Err(mid),
+    }
+    let end = component_shape(bytes, mid + 1)?;
+    if end == bytes.len() {
+        Ok(mid)
+    } else {
+        Err(end)
+    }
+}
+
+// Checks the shape of one component of a canonical string, starting at `start`, returning
+// where it ends or where it goes wrong.
+fn component_shape(bytes: &[u8], start: usize) -> Result<usize, usize> {
+    for i in 0..22 {
+        let valid = match bytes.get(start + i) {
+            None => return Err(start + i),
+            Some(&b) => match i {
+                1 => b == b'.',
+                18 => b == b'e',
+                19 => b == b'+' || b == b'-',
+                _ => b.is_ascii_digit(),
+            },
+        };
+        if !valid {
+            return Err(start + i);
         }
     }
+    let digits = bytes[start + 22..]
+        .iter()
+        .take_while(|b| b.is_ascii_digit())
+        .count();
+    Ok(start + 22 + digits)
 }
 
 #[inline]
@@ -143,12 +316,13 @@ fn pre_from_str(s: &str) -> Option<Result<Double, ParseDoubleError>> {
     if s.is_empty() {
         Some(Err(ParseDoubleError {
             kind: ErrorKind::Empty,
+            position: 0,
         }))
-    } else if s == "nan" {
+    } else if s.eq_ignore_ascii_case("nan") {
         Some(Ok(Double::NAN))
-    } else if s == "inf" || s == "infinity" {
+    } else if s.eq_ignore_ascii_case("inf") || s.eq_ignore_ascii_case("infinity") {
         Some(Ok(Double::INFINITY))
-    } else if s == "-inf" || s == "-infinity" {
+    } else if s.eq_ignore_ascii_case("-inf") || s.eq_ignore_ascii_case("-infinity") {
         Some(Ok(Double::NEG_INFINITY))
     } else {
         None
@@ -158,6 +332,7 @@ fn pre_from_str(s: &str) -> Option<Result<Double, ParseDoubleError>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::quad::Quad;
 
     macro_rules! single {
         ($e:expr, $a:expr) => {
@@ -170,31 +345,103 @@ mod tests {
         s.parse().unwrap()
     }
 
-    fn parse_err(s: &str) -> ErrorKind {
-        s.parse::<Double>().unwrap_err().kind
+    fn parse_err(s: &str) -> (ErrorKind, usize) {
+        let err = s.parse::<Double>().unwrap_err();
+        (err.kind, err.position)
     }
 
     // error tests
     test_all_eq!(
         empty:
-            ErrorKind::Empty,
+            (ErrorKind::Empty, 0),
             parse_err("");
+        blank:
+            (ErrorKind::Empty, 0),
+            parse_err("   ");
+        sign_only:
+            (ErrorKind::Empty, 1),
+            parse_err("-");
+        plus_only:
+            (ErrorKind::Empty, 1),
+            parse_err("+");
+        padded_sign_only:
+            (ErrorKind::Empty, 3),
+            parse_err("  - ");
+        point_only:
+            (ErrorKind::Invalid, 1),
+            parse_err(".");
+        sign_point_only:
+            (ErrorKind::Invalid, 2),
+            parse_err("-.");
+        exp_only:
+            (ErrorKind::Invalid, 0),
+            parse_err("e5");
+        point_exp_only:
+            (ErrorKind::Invalid, 1),
+            parse_err(".e1");
+        sign_exp_only:
+            (ErrorKind::Invalid, 1),
+            parse_err("-E5");
+        underscore_only:
+            (ErrorKind::Invalid, 1),
+            parse_err("_");
         double_sign:
-            ErrorKind::Invalid,
+            (ErrorKind::Invalid, 1),
             parse_err("++2317");
         double_point:
-            ErrorKind::Invalid,
+            (ErrorKind::TooManyPoints, 4),
             parse_err("2.31.7");
         mid_sign:
-            ErrorKind::Invalid,
+            (ErrorKind::Invalid, 1),
             parse_err("2-317");
         end_letter:
-            ErrorKind::Invalid,
+            (ErrorKind::InvalidDigit, 6),
             parse_err("2.317err");
         mid_letter:
-            ErrorKind::Invalid,
+            (ErrorKind::InvalidDigit, 3),
             parse_err("2.3j7");
+        non_ascii:
+            (ErrorKind::InvalidDigit, 1),
+            parse_err("2\u{e9}5");
+        leading_space:
+            (ErrorKind::InvalidDigit, 5),
+            parse_err("  2.3j7  ");
+        exp_empty:
+            (ErrorKind::Invalid, 4),
+            parse_err("2.5e");
+        exp_sign_only:
+            (ErrorKind::Invalid, 5),
+            parse_err("2.5e-");
+        exp_double_sign:
+            (ErrorKind::Invalid, 5),
+            parse_err("2.5e+-3");
+        exp_point:
+            (ErrorKind::Invalid, 5),
+            parse_err("2.5e1.5");
+        exp_letter:
+            (ErrorKind::InvalidDigit, 6),
+            parse_err("2.5e12x");
+        exp_overflow:
+            (ErrorKind::ExponentOverflow, 4),
+            parse_err("2.5e99999999999");
+        exp_neg_overflow:
+            (ErrorKind::ExponentOverflow, 4),
+            parse_err("2.5e-99999999999");
     );
+    test!(error_display: {
+        assert_eq!(
+            "2.31.7".parse::<Double>().unwrap_err().to_string(),
+            "too many decimal points in double-double literal at byte 4"
+        );
+        assert_eq!(
+            "2.5e".parse::<Double>().unwrap_err().to_string(),
+            "invalid double-double literal at byte 4"
+        );
+        assert_eq!(
+            "".parse::<Double>().unwrap_err().to_string(),
+            "cannot parse double-double from empty string"
+        );
+    });
 
     // zero tests
     test_all_exact!(
@@ -295,4 +542,708 @@ mod tests {
         let x = ((t - dd!(1)) / t) * dd!(10).powi(-100);
         near!(x, s);
     });
+
+    // rounding tests
+    //
+    // Most of these are exact decimal values of numbers halfway between two neighboring
+    // `Double`s, which round to the one whose last component has an even last bit, or are
+    // within a digit of being halfway.
+    fn pow2(n: i32) -> f64 {
+        2f64.powi(n)
+    }
+
+    test_all_exact!(
+        round_tie_down:
+            Double(1.0, pow2(-60)),
+            parse(concat!(
+                "1.0000000000000000008673617379884036435024594600577460219395221292463659",
+                "2690508241076940976199693977832794189453125"
+            ));
+        round_tie_up:
+            Double(1.0, pow2(-60) + pow2(-111)),
+            parse(concat!(
+                "1.0000000000000000008673617379884038360954538987813313275373163877390977",
+                "8071524723230822928599081933498382568359375"
+            ));
+        round_above_tie:
+            Double(1.0, pow2(-60) + pow2(-112)),
+            parse(concat!(
+                "1.0000000000000000008673617379884036435024594600577460219395221292463659",
+                "26905082410769409761996939778327941894531251"
+            ));
+        round_below_tie:
+            Double(1.0, pow2(-60) + pow2(-112)),
+            parse(concat!(
+                "1.0000000000000000008673617379884038360954538987813313275373163877390977",
+                "80715247232308229285990819334983825683593749"
+            ));
+        round_first_tie:
+            Double(1.0, pow2(-53)),
+            parse("1.00000000000000011102230246251565404236316680908203125");
+        round_negative_exponent:
+            Double(1e-300, -2.5059094e-317),
+            parse("1e-300");
+        round_tenth:
+            Double(0.1, -5.551115123125783e-18),
+            parse("0.1");
+        round_overflow:
+            Double::INFINITY,
+            parse("1.7976931348623158079372897140530342e308");
+        round_underflow:
+            Double::ZERO,
+            parse("2.4703282292062327208828439643411068e-324");
+    );
+    test_all_assert!(
+        // Displaying a subnormal doesn't work, so this can't use `exact!`
+        round_min_subnormal:
+            parse("2.4703282292062327208828439643411069e-324") == Double(f64::from_bits(1), 0.0);
+    );
+    test!(round_long_input: {
+        // Far more digits than are kept, so what's cut off has to be accounted for
+        let tie = "1.00000000000000011102230246251565404236316680908203125";
+        let zeros = "0".repeat(3000);
+        exact!(Double(1.0, pow2(-53)), parse(&format!("{}{}", tie, zeros)));
+        exact!(
+            Double(1.0 + pow2(-52), -pow2(-53)),
+            parse(&format!("{}{}1", tie, zeros))
+        );
+        // The same tie, with the digits in front of the decimal point instead
+        exact!(
+            Double(1.0, pow2(-53)),
+            parse(&format!("{}{}e-3053", tie.replace('.', ""), zeros))
+        );
+    });
+    test!(round_near_double: {
+        // So close to an `f64` that finding the second component takes many more bits than
+        // usual
+        exact!(Double(1.0, 1e-80), parse(&format!("1.{}1", "0".repeat(79))));
+        exact!(Double(1.0, -1e-80), parse(&format!("0.{}", "9".repeat(80))));
+        exact!(Double(1e20, 1e-70), parse(&format!("1{}.{}1", "0".repeat(20), "0".repeat(69))));
+    });
+    test!(round_consistent: {
+        // Each component is rounded the same way no matter how many there are
+        for s in random_strings() {
+            let x = parse(&s);
+            let q = s.parse::<Quad>().unwrap();
+            assert!(x[0] == q[0] && x[1] == q[1], "{}", s);
+        }
+    });
+
+    // A simple linear congruential generator makes repeatable random decimal strings, with
+    // up to 80 digits and exponents from well below the smallest subnormal to past the
+    // largest finite number
+    fn random_strings() -> Vec<String> {
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+        let mut next = |n: u64| {
+            seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+            (seed >> 33) % n
+        };
+        (0..500)
+            .map(|_| {
+                let len = next(80) + 1;
+                let digits: String = (0..len).map(|_| (b'0' + next(10) as u8) as char).collect();
+                format!("{}e{}", digits, next(680) as i64 - 370)
+            })
+            .collect()
+    }
+    test!(round_first_component: {
+        // The standard library's parser is correctly rounded, so the first component has to
+        // be the same as what it produces
+        for s in random_strings() {
+            assert!(parse(&s)[0] == s.parse::<f64>().unwrap(), "{}", s);
+        }
+        for s in [
+            "2.4703282292062327208828439643411068e-324",
+            "2.4703282292062327208828439643411069e-324",
+            "1.7976931348623158079372897140530341e308",
+            "1.7976931348623158079372897140530342e308",
+            "4.9406564584124654e-324",
+            "2.2250738585072011e-308",
+        ] {
+            assert!(parse(s)[0] == s.parse::<f64>().unwrap(), "{}", s);
+        }
+    });
+    #[cfg(feature = "num-bigint")]
+    test!(round_random_bigint: {
+        use crate::common::bigint as b;
+        use num_bigint::BigInt;
+
+        for s in random_strings() {
+            let (digits, exp) = s.split_at(s.find('e').unwrap());
+            let num: BigInt = digits.parse().unwrap();
+            let exp: i32 = exp[1..].parse().unwrap();
+            let ten = BigInt::from(10);
+            let (num, den) = if exp >= 0 {
+                (num * ten.pow(exp as u32), BigInt::from(1))
+            } else {
+                (num, ten.pow(-exp as u32))
+            };
+            let parts = b::split_fraction(num, den, 2);
+            let x = parse(&s);
+            for (i, &part) in parts.iter().enumerate() {
+                assert!(x[i] == part, "{}", s);
+            }
+        }
+    });
+
+    // hex tests
+    test_all_exact!(
+        hex_integer:
+            dd!(12),
+            parse("0x1.8p+3");
+        hex_no_exponent:
+            dd!(26),
+            parse("0x1a");
+        hex_no_integer:
+            dd!(0.5),
+            parse("0x.8");
+        hex_trailing_point:
+            dd!(16),
+            parse("0x10.");
+        hex_upper:
+            dd!(-0.0625),
+            parse("-0X1P-4");
+        hex_plus:
+            dd!(2),
+            parse("+0x1p1");
+        hex_mixed_case:
+            dd!(2748),
+            parse("0xAbC");
+        hex_e_digit:
+            dd!(-485),
+            parse("-0x1e5");
+        hex_underscores:
+            dd!(65535),
+            parse("0xff_ff");
+        hex_whitespace:
+            dd!(1),
+            parse("  0x1p0  ");
+        hex_zero:
+            Double::ZERO,
+            parse("0x0p+0");
+        hex_neg_zero:
+            Double::NEG_ZERO,
+            parse("-0x0.000p-10");
+        hex_leading_zeros:
+            dd!(10),
+            parse("0x00000000000000000000000000000000000000000a");
+        hex_two_parts:
+            Double(1.0, pow2(-80)),
+            parse("0x1.00000000000000000001");
+        hex_neg_second:
+            Double(1.0, -pow2(-80)),
+            parse("0x1.fffffffffffffffffffep-1");
+        hex_pi:
+            Double::PI,
+            parse("0x1.921fb54442d18469898cc51701cp+1");
+        hex_max:
+            Double::MAX,
+            parse("0x1.fffffffffffff7ffffffffffffcp+1023");
+        hex_overflow:
+            Double::INFINITY,
+            parse("0x1p1024");
+        hex_neg_overflow:
+            Double::NEG_INFINITY,
+            parse("-0x1p99999");
+        hex_underflow:
+            Double::ZERO,
+            parse("0x1p-1076");
+        hex_half_subnormal:
+            Double::ZERO,
+            parse("0x1p-1075");
+    );
+    test_all_exact!(
+        // The second component of a `Double` that starts at 2^-60 ends at 2^-112, so these
+        // are ties (or near ties) in the bit after that
+        hex_round_tie_down:
+            Double(1.0, pow2(-60)),
+            parse("0x1.00000000000000100000000000008");
+        hex_round_tie_up:
+            Double(1.0, pow2(-60) + pow2(-111)),
+            parse("0x1.00000000000000100000000000018");
+        hex_round_above_tie:
+            Double(1.0, pow2(-60) + pow2(-112)),
+            parse("0x1.000000000000001000000000000080000000000000000000000000000000000000000000001");
+        hex_round_below_tie:
+            Double(1.0, pow2(-60) + pow2(-112)),
+            parse("0x1.00000000000000100000000000017ffffffffffffffffffffffffffffffffffffffffffffff");
+        hex_round_long:
+            Double(1.0, pow2(-60) + pow2(-112)),
+            parse(format!("0x1.00000000000000100000000000008{}1", "0".repeat(3000)).as_str());
+        hex_round_overflow:
+            Double::INFINITY,
+            parse("0x1.fffffffffffff8p1023");
+    );
+    test_all_assert!(
+        // Subnormals can't be displayed, so these can't be tested with `exact!`
+        hex_min_subnormal:
+            parse("0x1p-1074") == Double(f64::from_bits(1), 0.0);
+        hex_round_subnormal:
+            parse("0x1.0000000000001p-1075") == Double(f64::from_bits(1), 0.0);
+    );
+    test_all_eq!(
+        hex_empty:
+            (ErrorKind::Invalid, 2),
+            parse_err("0x");
+        hex_no_digits:
+            (ErrorKind::Invalid, 2),
+            parse_err("0xp3");
+        hex_point_only:
+            (ErrorKind::Invalid, 3),
+            parse_err("0x.");
+        hex_double_point:
+            (ErrorKind::TooManyPoints, 5),
+            parse_err("0x1.2.3");
+        hex_bad_digit:
+            (ErrorKind::InvalidDigit, 3),
+            parse_err("0x1g");
+        hex_bad_digit_after_point:
+            (ErrorKind::InvalidDigit, 6),
+            parse_err("-0x1.8q5");
+        hex_inner_sign:
+            (ErrorKind::Invalid, 2),
+            parse_err("0x-1");
+        hex_double_sign:
+            (ErrorKind::Invalid, 1),
+            parse_err("--0x1");
+        hex_exp_empty:
+            (ErrorKind::Invalid, 4),
+            parse_err("0x1p");
+        hex_exp_hex:
+            (ErrorKind::InvalidDigit, 4),
+            parse_err("0x1pa");
+        hex_exp_overflow:
+            (ErrorKind::ExponentOverflow, 4),
+            parse_err("0x1p99999999999");
+        hex_leading_space:
+            (ErrorKind::InvalidDigit, 5),
+            parse_err("  0x1g  ");
+    );
+    test!(hex_matches_decimal: {
+        // Each of these pairs is a hex string and its exact decimal expansion, neither of
+        // which is exactly a `Double`, so they have to round to the same one
+        let pairs = [
+            (
+                "0x1.999999999999999999999999999ap+0",
+                "1.600000000000000000000000000000000077037197775489434122239117703397092741524065928615527809597551822662353515625",
+            ),
+            (
+                "0x1.ffffffffffffffffffffffffffffp+96",
+                "158456325028528675187087900671.9999847412109375",
+            ),
+            (
+                "0x3.243f6a8885a308d313198a2e0370734",
+                "3.14159265358979323846264338327950288418353141478922216077851131738713791456774700357190699406828571227379143238067626953125",
+            ),
+        ];
+        for (hex, decimal) in pairs.iter() {
+            assert!(parse(hex) == parse(decimal));
+        }
+    });
+
+    // radix tests
+    fn radix(s: &str, radix: u32) -> Double {
+        Double::from_str_radix(s, radix).unwrap()
+    }
+
+    fn radix_err(s: &str, radix: u32) -> (ErrorKind, usize) {
+        let err = Double::from_str_radix(s, radix).unwrap_err();
+        (err.kind, err.position)
+    }
+
+    test_all_exact!(
+        radix_binary:
+            dd!(-10.75),
+            radix("-1010.11", 2);
+        radix_hex:
+            dd!(255.5),
+            radix("ff.8", 16);
+        radix_hex_upper:
+            dd!(255.5),
+            radix("FF.8", 16);
+        radix_36:
+            dd!(1295),
+            radix("zz", 36);
+        radix_octal_fraction:
+            dd!(0.125),
+            radix("0.1", 8);
+        radix_plus:
+            dd!(5),
+            radix("+101", 2);
+        radix_whitespace:
+            dd!(5),
+            radix("  101  ", 2);
+        radix_underscores:
+            dd!(255),
+            radix("1111_1111", 2);
+        radix_trailing_point:
+            dd!(7),
+            radix("7.", 8);
+        radix_leading_point:
+            dd!(0.5),
+            radix(".1", 2);
+        radix_zero:
+            Double::ZERO,
+            radix("0.000", 3);
+        radix_neg_zero:
+            Double::NEG_ZERO,
+            radix("-0", 7);
+        radix_ten:
+            dd!(1500),
+            radix("1.5e3", 10);
+        radix_two_parts:
+            Double(1.0, pow2(-80)),
+            radix(&format!("1.{}1", "0".repeat(79)), 2);
+        radix_large:
+            Double(pow2(200), 0.0),
+            radix(&format!("1{}", "0".repeat(50)), 16);
+        radix_overflow:
+            Double::INFINITY,
+            radix(&format!("1{}", "0".repeat(1024)), 2);
+        radix_underflow:
+            Double::ZERO,
+            radix(&format!("0.{}1", "0".repeat(1075)), 2);
+    );
+    test!(radix_rounded: {
+        // A third has no finite expansion in binary, but it's exactly 0.1 in radix 3 (and
+        // 0.4 in radix 12), so these have to round to the same `Double` as the decimal
+        // expansion does
+        let third = parse(concat!(
+            "0.333333333333333333333333333333333333333333333333333333333333333333333333333",
+            "33333333333333333333333333333333333333333333333333333333333333333333333333333"
+        ));
+        assert!(radix("0.1", 3) == third);
+        assert!(radix("0.4", 12) == third);
+        assert!(radix("-0.1", 3) == -third);
+        // 0.1 in decimal is 0.2 in radix 20
+        assert!(radix("0.2", 20) == parse("0.1"));
+    });
+    test_all_eq!(
+        radix_empty:
+            (ErrorKind::Empty, 0),
+            radix_err("", 2);
+        radix_blank:
+            (ErrorKind::Empty, 0),
+            radix_err("  ", 16);
+        radix_sign_only:
+            (ErrorKind::Invalid, 1),
+            radix_err("-", 2);
+        radix_point_only:
+            (ErrorKind::Invalid, 1),
+            radix_err(".", 2);
+        radix_digit_too_big:
+            (ErrorKind::InvalidDigit, 1),
+            radix_err("12", 2);
+        radix_letter_too_big:
+            (ErrorKind::InvalidDigit, 1),
+            radix_err("yz", 35);
+        radix_double_point:
+            (ErrorKind::TooManyPoints, 3),
+            radix_err("1.0.1", 2);
+        radix_mid_sign:
+            (ErrorKind::Invalid, 2),
+            radix_err("10-1", 2);
+        radix_double_sign:
+            (ErrorKind::Invalid, 1),
+            radix_err("+-1", 2);
+        radix_no_exponent:
+            (ErrorKind::InvalidDigit, 1),
+            radix_err("1e3", 8);
+        radix_no_special:
+            (ErrorKind::InvalidDigit, 0),
+            radix_err("inf", 16);
+        radix_leading_space:
+            (ErrorKind::InvalidDigit, 3),
+            radix_err("  12", 2);
+    );
+    #[test]
+    #[should_panic(expected = "from_str_radix: radix must lie in the range `[2, 36]`, found 1")]
+    fn radix_too_small() {
+        let _ = Double::from_str_radix("0", 1);
+    }
+    #[test]
+    #[should_panic(expected = "from_str_radix: radix must lie in the range `[2, 36]`, found 37")]
+    fn radix_too_large() {
+        let _ = Double::from_str_radix("0", 37);
+    }
+
+    // separator tests
+    fn parse_with(s: &str, group: Option<char>, decimal: char) -> Double {
+        let options = ParseOptions {
+            group_separator: group,
+            decimal_separator: decimal,
+        };
+        Double::from_str_with(s, &options).unwrap()
+    }
+
+    fn parse_with_err(s: &str, group: Option<char>, decimal: char) -> (ErrorKind, usize) {
+        let options = ParseOptions {
+            group_separator: group,
+            decimal_separator: decimal,
+        };
+        let err = Double::from_str_with(s, &options).unwrap_err();
+        (err.kind, err.position)
+    }
+
+    test_all_exact!(
+        sep_underscore:
+            dd!(1000000.5),
+            parse("1_000_000.5");
+        sep_default:
+            dd!(1000000.5),
+            Double::from_str_with("1_000_000.5", &ParseOptions::default()).unwrap();
+        sep_comma:
+            dd!(-1234567.25),
+            parse_with("-1,234,567.25", Some(','), '.');
+        sep_space:
+            dd!(1234567.25),
+            parse_with("1 234 567.25", Some(' '), '.');
+        sep_apostrophe:
+            dd!(1234567.25),
+            parse_with("1'234'567.25", Some('\''), '.');
+        sep_european:
+            dd!(1234567.25),
+            parse_with("1.234.567,25", Some('.'), ',');
+        sep_european_space:
+            dd!(-1234567.25),
+            parse_with("  -1 234 567,25  ", Some(' '), ',');
+        sep_decimal_comma:
+            dd!(2.5),
+            parse_with("2,5", None, ',');
+        sep_exponent:
+            dd!(0.0025),
+            parse_with("2,5e-3", None, ',');
+        sep_fraction:
+            dd!(0.123456),
+            parse_with("0.123,456", Some(','), '.');
+        sep_leading:
+            dd!(1000),
+            parse_with(",1,000", Some(','), '.');
+        sep_underscore_too:
+            dd!(1000000),
+            parse_with("1,000_000", Some(','), '.');
+        sep_same:
+            dd!(1.5),
+            parse_with("1,5", Some(','), ',');
+        sep_hex:
+            dd!(12),
+            parse_with("0x1.8p3", Some(','), ',');
+        sep_inf:
+            Double::NEG_INFINITY,
+            parse_with("-inf", Some(','), ',');
+    );
+    test_all_eq!(
+        sep_not_allowed:
+            (ErrorKind::InvalidDigit, 1),
+            parse_err("1,000");
+        sep_wrong_group:
+            (ErrorKind::InvalidDigit, 1),
+            parse_with_err("1 000", Some(','), '.');
+        sep_period_with_comma:
+            (ErrorKind::InvalidDigit, 1),
+            parse_with_err("2.5", None, ',');
+        sep_two_decimals:
+            (ErrorKind::TooManyPoints, 3),
+            parse_with_err("2,5,5", None, ',');
+        sep_in_exponent:
+            (ErrorKind::InvalidDigit, 3),
+            parse_with_err("1e1,000", Some(','), '.');
+        sep_leading_space:
+            (ErrorKind::InvalidDigit, 5),
+            parse_with_err("  1,0x", Some(','), '.');
+        sep_empty:
+            (ErrorKind::Empty, 0),
+            parse_with_err("", Some(','), '.');
+    );
+
+    // ascii tests
+    fn ascii_err(bytes: &[u8]) -> (ErrorKind, usize) {
+        let err = Double::from_ascii(bytes).unwrap_err();
+        (err.kind, err.position)
+    }
+
+    test_all_exact!(
+        ascii_decimal:
+            parse("1234.5678"),
+            Double::from_ascii(b"1234.5678").unwrap();
+        ascii_whitespace:
+            dd!(-2.5),
+            Double::from_ascii(b"  -2.5  ").unwrap();
+        ascii_pi:
+            Double::PI,
+            Double::from_ascii(Double::PI.to_exact_decimal().as_bytes()).unwrap();
+        ascii_upper_exp:
+            parse("1.5e-300"),
+            Double::from_ascii(b"1.5E-300").unwrap();
+        ascii_upper_hex:
+            dd!(-12),
+            Double::from_ascii(b"-0X1.8P3").unwrap();
+        ascii_mixed_hex:
+            dd!(255.5),
+            Double::from_ascii(b"0xFf.8p0").unwrap();
+        ascii_upper_inf:
+            Double::INFINITY,
+            Double::from_ascii(b"INF").unwrap();
+        ascii_title_inf:
+            Double::NEG_INFINITY,
+            Double::from_ascii(b"-Infinity").unwrap();
+    );
+    test_all_assert!(
+        ascii_upper_nan:
+            Double::from_ascii(b"NAN").unwrap().is_nan();
+        ascii_neg_zero:
+            Double::from_ascii(b"-0").unwrap().is_sign_negative();
+    );
+    test_all_eq!(
+        ascii_empty:
+            (ErrorKind::Empty, 0),
+            ascii_err(b"");
+        ascii_bad_digit:
+            (ErrorKind::InvalidDigit, 3),
+            ascii_err(b" 1.x");
+        ascii_not_utf8:
+            (ErrorKind::InvalidDigit, 3),
+            ascii_err(b"1.5\xff");
+        ascii_not_utf8_first:
+            (ErrorKind::InvalidDigit, 0),
+            ascii_err(b"\xc3\x28");
+    );
+
+    // canonical tests
+    fn canonical(s: &str) -> Double {
+        Double::from_canonical(s).unwrap()
+    }
+
+    fn canonical_err(s: &str) -> (ErrorKind, usize) {
+        let err = Double::from_canonical(s).unwrap_err();
+        (err.kind, err.position)
+    }
+
+    test_all_exact!(
+        canonical_one:
+            Double::ONE,
+            canonical("1.0000000000000000e+00+0.0000000000000000e+00");
+        canonical_neg:
+            dd!(-0.125),
+            canonical("-1.2500000000000000e-01+0.0000000000000000e+00");
+        canonical_zero:
+            Double::ZERO,
+            canonical("0.0000000000000000e+00+0.0000000000000000e+00");
+        canonical_neg_zero:
+            Double::NEG_ZERO,
+            canonical("-0.0000000000000000e+00+0.0000000000000000e+00");
+        canonical_inf:
+            Double::INFINITY,
+            canonical("inf");
+        canonical_neg_inf:
+            Double::NEG_INFINITY,
+            canonical("-inf");
+        canonical_nan:
+            Double::NAN,
+            canonical("NaN");
+        canonical_min_positive:
+            Double::MIN_POSITIVE,
+            canonical("2.2250738585072014e-308+0.0000000000000000e+00");
+        canonical_pi:
+            Double::PI,
+            canonical("3.1415926535897931e+00+1.2246467991473532e-16");
+        canonical_neg_low:
+            Double(10.0, -1e-31),
+            canonical("1.0000000000000000e+01-1.0000000000000001e-31");
+        canonical_gap:
+            Double(1.0, 1e-300),
+            canonical("1.0000000000000000e+00+1.0000000000000000e-300");
+        canonical_three_digit_exp:
+            Double(1.5e300, 0.0),
+            canonical("1.5000000000000001e+300+0.0000000000000000e+00");
+    );
+    test_all_eq!(
+        canonical_err_empty:
+            (ErrorKind::Empty, 0),
+            canonical_err("");
+        canonical_err_short:
+            (ErrorKind::Invalid, 3),
+            canonical_err("1.5e+00");
+        canonical_err_one_component:
+            (ErrorKind::Invalid, 22),
+            canonical_err("1.0000000000000000e+00");
+        canonical_err_no_point:
+            (ErrorKind::Invalid, 1),
+            canonical_err("10000000000000000e+00+0.0000000000000000e+00");
+        canonical_err_exp_sign:
+            (ErrorKind::Invalid, 19),
+            canonical_err("1.0000000000000000e00+0.0000000000000000e+00");
+        canonical_err_exp_short:
+            (ErrorKind::Invalid, 21),
+            canonical_err("1.0000000000000000e+0+0.0000000000000000e+00");
+        canonical_err_plus:
+            (ErrorKind::Invalid, 0),
+            canonical_err("+1.0000000000000000e+00+0.0000000000000000e+00");
+        canonical_err_low_sign:
+            (ErrorKind::Invalid, 23),
+            canonical_err("1.0000000000000000e+000.0000000000000000e+00");
+        canonical_err_upper:
+            (ErrorKind::Invalid, 18),
+            canonical_err("1.0000000000000000E+00+0.0000000000000000e+00");
+        canonical_err_trailing:
+            (ErrorKind::Invalid, 45),
+            canonical_err("1.0000000000000000e+00+0.0000000000000000e+00 ");
+        canonical_err_not_nearest:
+            (ErrorKind::Invalid, 17),
+            canonical_err("1.0000000000000001e+00+0.0000000000000000e+00");
+        canonical_err_zero_exp:
+            (ErrorKind::Invalid, 42),
+            canonical_err("1.0000000000000000e+00+0.0000000000000000e-05");
+        canonical_err_overflow:
+            (ErrorKind::ExponentOverflow, 0),
+            canonical_err("1.0000000000000000e+309+0.0000000000000000e+00");
+        canonical_err_low_overflow:
+            (ErrorKind::ExponentOverflow, 22),
+            canonical_err("1.0000000000000000e+00+1.0000000000000000e+309");
+        canonical_err_token:
+            (ErrorKind::Invalid, 0),
+            canonical_err("infinity");
+        canonical_err_space:
+            (ErrorKind::Invalid, 0),
+            canonical_err(" 1.0000000000000000e+00+0.0000000000000000e+00");
+    );
+
+    test!(canonical_round_trip: {
+        let mut values = vec![
+            Double::PI,
+            Double::E,
+            -Double::LN_2,
+            Double::ONE / dd!(3),
+            Double::MAX,
+            -Double::MAX,
+            Double::MIN_POSITIVE * dd!(2).powi(54),
+            dd!(1e-290),
+            dd!(2).powi(-960),
+            dd!(1.7e308),
+            dd!(123_456_789),
+            Double(1.0, 1e-300),
+            Double(-1.0, 5e-324),
+            Double(f64::from_bits(1), 0.0),
+            Double(-1e-310, 0.0),
+            Double::NEG_ZERO,
+        ];
+        // A simple linear congruential generator adds a repeatable spread of values across
+        // most of the range, with full-precision second components
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+        let mut next = || {
+            seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+            seed >> 11
+        };
+        for _ in 0..500 {
+            let x = Double::from(next()) / Double::from(next() | 1);
+            let exp = (next() % 561) as i32 - 280;
+            values.push(x * dd!(10).powi(exp));
+        }
+
+        for x in values {
+            let s = x.to_canonical();
+            let y = canonical(&s);
+            assert!(y == x && y.to_canonical() == s, "{} did not round trip", s);
+        }
+    });
 }
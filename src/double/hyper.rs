@@ -692,11 +692,14 @@ mod tests {
             dd!("5.7037713633599001905278554895391333"),
             dd!(150).acosh();
     );
+    // The argument is the `Double` nearest to 1.00001, not 1.00001 itself, so the expected
+    // value is the arccosine of that. Calculating x² - 1 this close to 1 cancels away some of
+    // the leading digits, which leaves the result good to about 27 digits.
     test_all_prec!(
         acosh_small:
-            dd!("0.0044721322282280021231284466331917651"),
+            dd!("0.0044721322282280021231284466318136857"),
             dd!("1.00001").acosh(),
-            28;
+            27;
     );
     test_all_exact!(
         acosh_neg_pi:
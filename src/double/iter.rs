@@ -8,7 +8,9 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::double::common as c;
 use crate::double::Double;
+use crate::quad::Quad;
 use std::iter::{Product, Sum};
 
 impl Sum for Double {
@@ -91,9 +93,160 @@ impl<'a> Product<&'a Double> for Double {
     }
 }
 
+impl Double {
+    /// Calculates the dot product of two slices of `Double`s, along with an upper bound on
+    /// the absolute error of that result.
+    ///
+    /// The dot product is calculated with ordinary `Double` arithmetic, as though by
+    /// multiplying the corresponding elements and summing the products. Along the way, the
+    /// largest possible rounding error of each multiplication and addition is added to the
+    /// bound. The bounds used for the individual operations (relative errors of 2⁻¹⁰³ for
+    /// multiplication and 2⁻¹⁰⁴ for addition) are rounded up from the proven bounds for
+    /// double-double arithmetic, and the bound itself is rounded upward, so the true error of
+    /// the result (compared to the exact dot product of the inputs) is never larger than
+    /// the returned bound. It will generally be quite a bit smaller.
+    ///
+    /// If the slices are of different lengths, the extra elements in the longer one are
+    /// ignored. If the dot product overflows or is `NaN`, the error bound is not finite.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let a = [Double::PI, Double::E];
+    /// let b = [Double::LN_2, Double::SQRT_2];
+    /// let (dot, bound) = Double::dot_with_bound(&a, &b);
+    ///
+    /// let expected = dd!("6.021817118462718955364360535664");
+    /// assert!((dot - expected).abs() < dd!(1e-30));
+    /// assert!(bound < dd!(1e-30));
+    /// ```
+    pub fn dot_with_bound(a: &[Double], b: &[Double]) -> (Double, Double) {
+        // The unit roundoff of an `f64`, squared. All of the error bounds are multiples of
+        // this.
+        const U2: f64 = 1.232595164407831e-32; // 2⁻¹⁰⁶
+
+        let mut sum = Double::ZERO;
+        let mut bound = 0.0;
+        let mut n = 0;
+
+        for (x, y) in a.iter().zip(b.iter()) {
+            let product = x * y;
+            sum += product;
+            // Only the first components are used in calculating the bound; the margin added
+            // at the end more than covers the difference
+            bound += 8.0 * U2 * product.0.abs() + 4.0 * U2 * sum.0.abs();
+            n += 1;
+        }
+
+        // Covers both the rounding in the calculation of the bound itself and any error
+        // caused by ignoring second components, plus a bit of room for underflow.
+        let margin = 1.0 + (n as f64 + 2.0) * f64::EPSILON;
+        let bound = bound * margin + n as f64 * f64::MIN_POSITIVE;
+        (sum, Double(bound, 0.0))
+    }
+
+    /// Sums a slice of `Double`s in quad-double precision, returning the result as a
+    /// [`Quad`].
+    ///
+    /// Each `Double` is widened to a `Quad` without any loss of precision before being added,
+    /// so unless the slice is enormous or its values span an extraordinary range, the result
+    /// is essentially the exact sum of the inputs. This makes it useful as a reference
+    /// against which to check sums calculated in `Double` precision.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, qd, Double, Quad};
+    /// let data = [dd!(1e40), Double::PI, dd!(-1e40)];
+    ///
+    /// // Most of the precision of π is lost in `Double` precision...
+    /// let diff = (data.iter().sum::<Double>() - Double::PI).abs();
+    /// assert!(diff > dd!(1e-20));
+    ///
+    /// // ...but not in `Quad` precision
+    /// let sum = Double::exact_sum_to_quad(&data);
+    /// assert!((sum - Quad::PI).abs() < qd!(1e-31));
+    /// ```
+    ///
+    /// [`Quad`]: struct.Quad.html
+    pub fn exact_sum_to_quad(data: &[Double]) -> Quad {
+        data.iter().fold(Quad::ZERO, |s, x| s + c::to_quad(*x))
+    }
+
+    /// Creates an iterator of evenly spaced `Double`s, starting at `start` and moving by
+    /// `step` until reaching (but not including) `stop`.
+    ///
+    /// Each value is calculated directly as `start + i * step` rather than by repeatedly
+    /// adding `step` to the previous value, so rounding errors don't accumulate over the
+    /// course of the iteration.
+    ///
+    /// If `step` is zero or `NaN`, or if it points away from `stop`, the iterator is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let values: Vec<Double> = Double::arange(dd!(0), dd!(1), dd!(0.25)).collect();
+    /// assert!(values == vec![dd!(0), dd!(0.25), dd!(0.5), dd!(0.75)]);
+    ///
+    /// let values: Vec<Double> = Double::arange(dd!(1), dd!(0), dd!(-0.5)).collect();
+    /// assert!(values == vec![dd!(1), dd!(0.5)]);
+    /// ```
+    pub fn arange(start: Double, stop: Double, step: Double) -> impl Iterator<Item = Double> {
+        let count = if step.is_zero() || step.is_nan() {
+            0
+        } else {
+            u64::MAX
+        };
+        let forward = step.is_sign_positive();
+
+        (0..count)
+            .map(move |i| start + step * Double::from(i))
+            .take_while(move |x| if forward { *x < stop } else { *x > stop })
+    }
+
+    /// Creates an iterator of `n` `Double`s spaced evenly on a logarithmic scale, from
+    /// 10<sup>`start`</sup> to 10<sup>`stop`</sup> inclusive.
+    ///
+    /// The exponents are spaced evenly between `start` and `stop`, with the last one being
+    /// exactly `stop`. Integral exponents are raised with [`powi`], so powers of ten land
+    /// exactly where they should.
+    ///
+    /// If `n` is `0`, the iterator is empty, and if it's `1`, the iterator contains only
+    /// 10<sup>`start`</sup>.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let values: Vec<Double> = Double::logspace(dd!(0), dd!(3), 4).collect();
+    /// assert!(values == vec![dd!(1), dd!(10), dd!(100), dd!(1000)]);
+    /// ```
+    ///
+    /// [`powi`]: #method.powi
+    pub fn logspace(start: Double, stop: Double, n: usize) -> impl Iterator<Item = Double> {
+        let step = if n > 1 {
+            (stop - start) / Double::from(n as u64 - 1)
+        } else {
+            Double::ZERO
+        };
+
+        (0..n).map(move |i| {
+            let exp = if i == n - 1 && n > 1 {
+                stop
+            } else {
+                start + step * Double::from(i as u64)
+            };
+            if exp == exp.trunc() && exp.abs() <= Double::from(i32::MAX) {
+                Double::from(10).powi(exp.0 as i32)
+            } else {
+                Double::from(10).powf(exp)
+            }
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::quad::Quad;
 
     // sum tests
     test_all_near!(
@@ -168,4 +321,152 @@ mod tests {
             Double::NAN,
             vec![dd!(1), dd!(2), Double::NAN].iter().product::<Double>();
     );
+    // dot_with_bound tests
+    fn dot_error(a: &[Double], b: &[Double], dot: Double) -> Quad {
+        // A `Quad` holds each product exactly, so this is accurate far past the precision of
+        // a `Double`
+        let q = |x: &Double| Quad::from((x.0, x.1));
+        let exact = a
+            .iter()
+            .zip(b.iter())
+            .fold(Quad::ZERO, |s, (x, y)| s + q(x) * q(y));
+        (q(&dot) - exact).abs()
+    }
+
+    test!(dot_with_bound_small: {
+        let a = [Double::PI, Double::E, -Double::LN_2, Double::SQRT_2];
+        let b = [Double::LN_10, Double::FRAC_1_PI, Double::SQRT_2, Double::E];
+        let (dot, bound) = Double::dot_with_bound(&a, &b);
+        near!(dd!("10.963013276538299532857591253698962"), dot);
+        assert!(dot_error(&a, &b, dot) <= Quad::from((bound.0, bound.1)));
+        assert!(bound < dd!(1e-29));
+    });
+    test!(dot_with_bound_cancel: {
+        // Huge terms that cancel leave a result whose error is large compared to itself, so
+        // the bound has to account for the sizes of the intermediate sums
+        let a = [dd!(1e20), Double::PI, dd!(-1e20)];
+        let b = [Double::E, Double::ONE, Double::E];
+        let (dot, bound) = Double::dot_with_bound(&a, &b);
+        let error = dot_error(&a, &b, dot);
+        assert!(error > Quad::ZERO);
+        assert!(error <= Quad::from((bound.0, bound.1)));
+    });
+    test!(dot_with_bound_long: {
+        let a: Vec<Double> = (1..=100).map(|i| Double::ONE / Double::from(i)).collect();
+        let b: Vec<Double> = (1..=100).map(|i| Double::from(i).sqrt()).collect();
+        let (dot, bound) = Double::dot_with_bound(&a, &b);
+        assert!(dot_error(&a, &b, dot) <= Quad::from((bound.0, bound.1)));
+    });
+    test_all_exact!(
+        dot_with_bound_ints:
+            dd!(32),
+            Double::dot_with_bound(&[dd!(1), dd!(2), dd!(3)], &[dd!(4), dd!(5), dd!(6)]).0;
+        dot_with_bound_empty:
+            Double::ZERO,
+            Double::dot_with_bound(&[], &[]).0;
+        dot_with_bound_empty_bound:
+            Double::ZERO,
+            Double::dot_with_bound(&[], &[]).1;
+    );
+    test_all_assert!(
+        dot_with_bound_nan:
+            Double::dot_with_bound(&[Double::NAN], &[Double::ONE]).1.is_nan();
+        dot_with_bound_inf:
+            !Double::dot_with_bound(&[Double::INFINITY], &[Double::ONE]).1.is_finite();
+    );
+
+    // exact_sum_to_quad tests
+    test!(exact_sum_to_quad_manual: {
+        let data = [Double::PI, Double::E, -Double::LN_2, Double::SQRT_2, dd!(1e-20)];
+        let manual = data
+            .iter()
+            .fold(Quad::ZERO, |s, x| s + Quad::from((x.0, x.1)));
+        assert!(Double::exact_sum_to_quad(&data) == manual);
+    });
+    test!(exact_sum_to_quad_ill_conditioned: {
+        // Most of the precision of the small terms is lost against the large ones in `Double`
+        // precision, but a `Quad` has room for all of it
+        let data = [dd!(1e40), Double::PI, dd!(-1e40), Double::E];
+        let exact = Quad::from((Double::PI.0, Double::PI.1))
+            + Quad::from((Double::E.0, Double::E.1));
+        let quad_sum = Double::exact_sum_to_quad(&data);
+        let double_sum: Double = data.iter().sum();
+
+        let quad_error = (quad_sum - exact).abs();
+        let double_error = (Quad::from((double_sum.0, double_sum.1)) - exact).abs();
+        assert!(quad_error == Quad::ZERO);
+        assert!(double_error > Quad::from(1e-20));
+    });
+    test_all_assert!(
+        exact_sum_to_quad_empty:
+            Double::exact_sum_to_quad(&[]) == Quad::ZERO;
+        exact_sum_to_quad_ints:
+            Double::exact_sum_to_quad(&[dd!(1), dd!(2), dd!(3), dd!(4), dd!(5)]) == Quad::from(15);
+        exact_sum_to_quad_nan:
+            Double::exact_sum_to_quad(&[dd!(1), Double::NAN]).is_nan();
+        exact_sum_to_quad_inf:
+            Double::exact_sum_to_quad(&[dd!(1), Double::INFINITY]).is_infinite();
+    );
+
+    // arange tests
+    test_all_eq!(
+        arange_quarters:
+            vec![dd!(0), dd!(0.25), dd!(0.5), dd!(0.75)],
+            Double::arange(dd!(0), dd!(1), dd!(0.25)).collect::<Vec<Double>>();
+        arange_uneven:
+            vec![dd!(0), dd!(0.375), dd!(0.75)],
+            Double::arange(dd!(0), dd!(1), dd!(0.375)).collect::<Vec<Double>>();
+        arange_negative:
+            vec![dd!(1), dd!(0.5), dd!(0), dd!(-0.5)],
+            Double::arange(dd!(1), dd!(-1), dd!(-0.5)).collect::<Vec<Double>>();
+        arange_zero_step:
+            Vec::<Double>::new(),
+            Double::arange(dd!(0), dd!(1), Double::ZERO).collect::<Vec<Double>>();
+        arange_nan_step:
+            Vec::<Double>::new(),
+            Double::arange(dd!(0), dd!(1), Double::NAN).collect::<Vec<Double>>();
+        arange_wrong_sign:
+            Vec::<Double>::new(),
+            Double::arange(dd!(0), dd!(1), dd!(-0.25)).collect::<Vec<Double>>();
+        arange_wrong_sign_neg:
+            Vec::<Double>::new(),
+            Double::arange(dd!(1), dd!(0), dd!(0.25)).collect::<Vec<Double>>();
+        arange_empty_range:
+            Vec::<Double>::new(),
+            Double::arange(dd!(1), dd!(1), dd!(0.25)).collect::<Vec<Double>>();
+        arange_nan_stop:
+            Vec::<Double>::new(),
+            Double::arange(dd!(0), Double::NAN, dd!(0.25)).collect::<Vec<Double>>();
+    );
+    test!(arange_no_drift: {
+        // A tenth can't be represented exactly, so adding it up 1000 times would leave the
+        // last value noticeably off from 99.9
+        let values: Vec<Double> = Double::arange(dd!(0), dd!(100), dd!(0.1)).collect();
+        assert!(values.len() == 1000);
+        exact!(dd!(0.1) * dd!(999), values[999]);
+        near!(dd!("99.9"), values[999]);
+    });
+
+    // logspace tests
+    test_all_eq!(
+        logspace_powers:
+            vec![dd!(1), dd!(10), dd!(100), dd!(1000)],
+            Double::logspace(dd!(0), dd!(3), 4).collect::<Vec<Double>>();
+        logspace_negative:
+            vec![dd!(100), dd!(1), dd!("0.01")],
+            Double::logspace(dd!(2), dd!(-2), 3).collect::<Vec<Double>>();
+        logspace_empty:
+            Vec::<Double>::new(),
+            Double::logspace(dd!(0), dd!(3), 0).collect::<Vec<Double>>();
+        logspace_single:
+            vec![dd!(10)],
+            Double::logspace(dd!(1), dd!(3), 1).collect::<Vec<Double>>();
+    );
+    test!(logspace_endpoints: {
+        let values: Vec<Double> = Double::logspace(dd!(-5), dd!(10), 7).collect();
+        assert!(values.len() == 7);
+        exact!(dd!("1e-5"), values[0]);
+        exact!(dd!("1e10"), values[6]);
+        near!(dd!("3.162277660168379331998893544433e-3"), values[1]);
+    });
 }
@@ -3,8 +3,11 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::display as d;
+use crate::common::parse as p;
 use crate::common::utils as u;
 use crate::double::Double;
+use crate::round::RoundingMode;
 use std::f64;
 use std::num::FpCategory;
 
@@ -105,6 +108,170 @@ impl Double {
         }
     }
 
+    /// Returns the nearest integer value to the `Double`. Half-way cases are rounded up,
+    /// toward positive infinity.
+    ///
+    /// This is the "commercial" rounding common in financial calculations. It differs from
+    /// [`round`] only for negative numbers exactly half-way between two integers, where
+    /// `round` goes away from zero (down) and this goes toward zero (up). The whole value
+    /// is used to decide whether it's half-way, so a number whose first component is a half
+    /// but whose second component takes it a little below one is rounded down.
+    ///
+    /// The "up" is toward positive infinity, not away from zero, so this is not the same
+    /// as rounding to no places with [`RoundingMode::HalfAwayFromZero`], which does what
+    /// `round` does.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// assert!(dd!(2.5).round_half_up() == dd!(3));
+    /// assert!(dd!(-2.5).round_half_up() == dd!(-2));
+    /// assert!(dd!(-2.5).round() == dd!(-3));
+    ///
+    /// let x = dd!(0.5) - dd!(1e-40);
+    /// assert!(x.round_half_up() == dd!(0));
+    /// ```
+    ///
+    /// [`round`]: #method.round
+    /// [`RoundingMode::HalfAwayFromZero`]: enum.RoundingMode.html#variant.HalfAwayFromZero
+    pub fn round_half_up(self) -> Double {
+        if !self.is_finite() {
+            self
+        } else {
+            let f = self.floor();
+            let r = if self - f >= Double(0.5, 0.0) {
+                f + Double::ONE
+            } else {
+                f
+            };
+            if r.is_zero() && self.is_sign_negative() {
+                Double::NEG_ZERO
+            } else {
+                r
+            }
+        }
+    }
+
+    /// Rounds the `Double` to the given number of significant decimal digits.
+    ///
+    /// This is rounding to a number of digits counted from the first nonzero one rather
+    /// than from the decimal point, so `123456` rounded to three significant figures is
+    /// `123000` and `0.00012345` rounded to two is `0.00012`. Halfway cases are rounded
+    /// away from zero, as in [`round`]. The scaling by powers of ten is done at full
+    /// precision, so the result is as close as a `Double` can get to the rounded decimal
+    /// number.
+    ///
+    /// Zeros, infinities, and `NaN` are returned unchanged, as are all numbers when more
+    /// than 33 figures are requested, since that's more digits than a `Double` can hold.
+    /// Rounding to zero significant figures is undefined and produces [`NAN`]. A number
+    /// near the top of the range that rounds up past [`MAX`] becomes an infinity with the
+    /// same sign.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(123456).round_to_sig_figs(3) == dd!(123000));
+    /// assert!(dd!(-987.65).round_to_sig_figs(2) == dd!(-990));
+    ///
+    /// let x = dd!(0.00012345).round_to_sig_figs(2);
+    /// let diff = (x - dd!(0.00012)).abs();
+    /// assert!(diff < dd!(1e-35));
+    /// ```
+    ///
+    /// [`round`]: #method.round
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`MAX`]: #associatedconstant.MAX
+    pub fn round_to_sig_figs(self, figs: u32) -> Double {
+        if figs == 0 {
+            return Double::NAN;
+        }
+        if figs > 33 || self.is_zero() || !self.is_finite() {
+            return self;
+        }
+
+        let figs = figs as i32;
+        let x = self.abs();
+        let lower = Double::from(10).powi(figs - 1);
+        let upper = lower * Double::from(10);
+
+        // The logarithm of the high component is all that's needed to find the exponent,
+        // but it may be off by one near a power of ten, so the scaled number is checked to
+        // make sure it has exactly `figs` digits before the decimal point
+        let mut exp = x.0.log10().floor() as i32;
+        let mut scaled = scale10(x, figs - 1 - exp);
+        if scaled >= upper {
+            exp += 1;
+            scaled = scale10(x, figs - 1 - exp);
+        } else if scaled < lower {
+            exp -= 1;
+            scaled = scale10(x, figs - 1 - exp);
+        }
+
+        let mut r = scale10(scaled.round(), exp - figs + 1);
+        if !r.is_finite() {
+            // Multiplication that overflows the high component gives `NaN`, even when the
+            // rounded number is just small enough to fit, so near the top of the range the
+            // rounding is instead done on the exact decimal value, the way `round_dp` does
+            // it, which also produces the infinity when it really doesn't fit
+            let places = figs - 1 - exp;
+            let digits =
+                d::round_decimal(&[x.0, x.1], places, false, RoundingMode::HalfAwayFromZero);
+            let parts = p::split_digits(&digits, -(places as i64), 2);
+            r = Double(parts[0], parts[1]);
+        }
+        if self.is_sign_negative() {
+            -r
+        } else {
+            r
+        }
+    }
+
+    /// Rounds the `Double` to the given number of decimal places, using the given rounding
+    /// mode.
+    ///
+    /// The rounding is done on the exact value of the `Double` (the sum of its components),
+    /// and the rounded decimal is then converted back to the nearest `Double`. A number
+    /// that's exactly halfway between two decimals, like 2.5 or 0.125, is a tie that the
+    /// mode decides, which makes [`HalfEven`] the banker's rounding that financial
+    /// calculations often call for. Most decimals with places can't be stored exactly in
+    /// binary, so a result like 0.1 is only as close as a `Double` can get to it, but it's
+    /// the same `Double` that parsing the rounded decimal would give.
+    ///
+    /// A number that rounds to zero keeps its sign, as it does with [`round`]. Infinities
+    /// and `NaN` are returned unchanged, as are all numbers when the places are enough to
+    /// hold every digit of the exact value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, RoundingMode};
+    /// assert!(dd!(2.5).round_dp(0, RoundingMode::HalfEven) == dd!(2));
+    /// assert!(dd!(2.5).round_dp(0, RoundingMode::HalfAwayFromZero) == dd!(3));
+    /// assert!(dd!(-0.125).round_dp(2, RoundingMode::HalfEven) == dd!("-0.12"));
+    /// assert!(dd!("1234.5678").round_dp(2, RoundingMode::Down) == dd!("1234.56"));
+    /// assert!(dd!("-1234.5678").round_dp(2, RoundingMode::Down) == dd!("-1234.57"));
+    /// assert!(dd!("0.001").round_dp(2, RoundingMode::Up) == dd!("0.01"));
+    /// ```
+    ///
+    /// [`HalfEven`]: enum.RoundingMode.html#variant.HalfEven
+    /// [`round`]: #method.round
+    pub fn round_dp(self, places: usize, mode: RoundingMode) -> Double {
+        // The smallest subnormal `f64` has 1074 decimal places, and no finite `Double` has
+        // more than that
+        if !self.is_finite() || places >= 1074 {
+            return self;
+        }
+
+        let negative = self.is_sign_negative();
+        let digits = d::round_decimal(&[self.0, self.1], places as i32, negative, mode);
+        let parts = p::split_digits(&digits, -(places as i64), 2);
+        let result = Double(parts[0], parts[1]);
+        if negative {
+            -result
+        } else {
+            result
+        }
+    }
+
     /// Returns the integer part of the `Double`.
     ///
     /// # Examples
@@ -172,6 +339,82 @@ impl Double {
         }
     }
 
+    /// Returns the spacing between representable `Double`s at the magnitude of this one.
+    ///
+    /// This is [`EPSILON`] scaled to the binary exponent of the `Double`, so it's 2⁻¹⁰⁴ for
+    /// numbers in [1, 2), 2⁻¹⁰³ for numbers in [2, 4), and so on. It's calculated directly
+    /// from the exponent of the first component, which makes it cheap enough to use freely
+    /// for things like convergence checks.
+    ///
+    /// For zero and for values so small that the spacing would fall below the smallest
+    /// positive subnormal `f64`, that smallest subnormal is returned instead. The spacing at
+    /// an infinity is [`INFINITY`], and at `NaN` is [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let eps = dd!(1).eps_at();
+    /// assert!((eps - Double::EPSILON).abs() < dd!(1e-45));
+    ///
+    /// assert!(dd!(1000).eps_at() == eps * dd!(512));
+    /// assert!(dd!(-0.25).eps_at() == eps / dd!(4));
+    /// ```
+    ///
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn eps_at(self) -> Double {
+        if self.is_nan() {
+            Double::NAN
+        } else if self.is_infinite() {
+            Double::INFINITY
+        } else {
+            // The smallest positive subnormal f64, 2⁻¹⁰⁷⁴
+            let min = f64::from_bits(1);
+            if self.is_zero() {
+                Double(min, 0.0)
+            } else {
+                // Taking the exponent straight from the bits avoids any rounding problems
+                // with `log2`. Subnormals show up with an exponent of -1023, which is small
+                // enough that they end up with `min` either way.
+                let exp = ((self.0.to_bits() >> 52) & 0x7ff) as i32 - 1023;
+                Double(2f64.powi(exp - 104).max(min), 0.0)
+            }
+        }
+    }
+
+    /// Calculates the floor of the base-2 logarithm of the `Double`, as an integer.
+    ///
+    /// This is taken directly from the exponent of the first component, so it doesn't need
+    /// to calculate a logarithm. When the first component is exactly a power of two and the
+    /// second is negative, the number is just under that power of two, and the result is
+    /// adjusted down to match.
+    ///
+    /// As with the `ilog2` functions of the integer types, the result is unsigned. It is
+    /// `None` for numbers less than one (including zero and negative numbers), whose
+    /// logarithms are negative, and for infinities and `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(8).ilog2() == Some(3));
+    /// assert!(dd!(7).ilog2() == Some(2));
+    /// assert!((dd!(8) - dd!(1e-20)).ilog2() == Some(2));
+    /// assert!(dd!(0).ilog2() == None);
+    /// ```
+    pub fn ilog2(self) -> Option<u32> {
+        if !self.is_finite() || self < Double::ONE {
+            return None;
+        }
+        let exp = ((self.0.to_bits() >> 52) & 0x7ff) as u32 - 1023;
+        let power_of_two = self.0.to_bits() & 0xf_ffff_ffff_ffff == 0;
+        if power_of_two && self.1 < 0.0 {
+            Some(exp - 1)
+        } else {
+            Some(exp)
+        }
+    }
+
     /// Returns the floating point category of the `Double`.
     ///
     /// The possible return values are the members of [`FpCategory`], as follows:
@@ -338,6 +581,166 @@ impl Double {
     pub fn is_subnormal(self) -> bool {
         self.classify() == FpCategory::Subnormal
     }
+
+    /// Calculates the coefficients of the simple continued fraction of the `Double`.
+    ///
+    /// The continued fraction of x is a₀ + 1/(a₁ + 1/(a₂ + ...)), where a₀ is an integer
+    /// and all of the other coefficients are positive integers. They're calculated in the
+    /// standard way, by repeatedly taking the floor of the number and inverting the
+    /// fractional part that remains.
+    ///
+    /// Every `Double` is a rational number, so its continued fraction is finite, but the
+    /// later coefficients quickly become meaningless as rounding error is magnified by each
+    /// inversion. The expansion stops after `max_terms` coefficients, when the fractional
+    /// part is zero to within the accumulated rounding error, when that rounding error
+    /// grows too large for further coefficients to mean anything, or when the fraction that
+    /// the coefficients so far represent is already as close to the `Double` as its own
+    /// precision allows, whichever comes first. It also stops early if the fraction's
+    /// numerator or denominator would no longer fit into an `i64`. The continued fraction
+    /// of an infinity or of `NaN` has no terms.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(3.25).to_continued_fraction(10) == vec![3, 4]);
+    /// assert!(dd!(-3.25).to_continued_fraction(10) == vec![-4, 1, 3]);
+    /// assert!(Double::PI.to_continued_fraction(5) == vec![3, 7, 15, 1, 292]);
+    /// ```
+    pub fn to_continued_fraction(self, max_terms: usize) -> Vec<i64> {
+        let mut terms = vec![];
+        if !self.is_finite() {
+            return terms;
+        }
+
+        let limit = Double::from(i64::MAX);
+        let tolerance = self.eps_at();
+
+        // The numerators and denominators of the last two convergents
+        let (mut p0, mut q0) = (1i64, 0i64);
+        let (mut p1, mut q1) = (0i64, 1i64);
+
+        // A running estimate of how far `x` might be from its true value. Each inversion
+        // scales the existing error by 1/frac² and adds a new rounding error.
+        let mut x = self;
+        let mut error = tolerance.0;
+
+        while terms.len() < max_terms {
+            // If `x` is within its error of an integer, it's taken to be that integer. This
+            // keeps something like 3.9999... from being turned into [3, 1] instead of [4].
+            // That only makes sense while the error is small, though; once it isn't, the
+            // remaining terms are just noise.
+            let nearest = x.round();
+            let exact = (x - nearest).abs().0 <= error;
+            if exact && error > 1e-10 {
+                break;
+            }
+            let a = if exact { nearest } else { x.floor() };
+            if a.abs() >= limit {
+                break;
+            }
+            let term = a.0 as i64 + a.1 as i64;
+
+            let p = term.checked_mul(p0).and_then(|n| n.checked_add(p1));
+            let q = term.checked_mul(q0).and_then(|n| n.checked_add(q1));
+            match (p, q) {
+                (Some(p), Some(q)) => {
+                    p1 = p0;
+                    q1 = q0;
+                    p0 = p;
+                    q0 = q;
+                }
+                _ => break,
+            }
+            terms.push(term);
+
+            let frac = x - a;
+            if exact || (Double::from(p0) / Double::from(q0) - self).abs() < tolerance {
+                break;
+            }
+            x = frac.recip();
+            error = error / (frac.0 * frac.0) + x.eps_at().0;
+        }
+        terms
+    }
+
+    /// Calculates the fraction closest to the `Double` whose denominator is no larger than
+    /// `max_denom`, returning it as a numerator and denominator.
+    ///
+    /// The fraction is found from the convergents of the [continued fraction] of the
+    /// `Double`, along with the intermediate fractions (semiconvergents) between the last
+    /// two convergents that fit within `max_denom`. Among those, it's always one of the
+    /// last two candidates that is the closest. The numerator carries the sign of the
+    /// `Double`, and the denominator is always positive. If the `Double` is itself a
+    /// fraction with a small enough denominator, that exact fraction is returned, in lowest
+    /// terms.
+    ///
+    /// A `max_denom` less than `1` is treated as `1`, which rounds the `Double` to an
+    /// integer. The result for `NaN` is `(0, 0)`, and the result for an infinity or for a
+    /// number too large to fit into an `i64` is `(1, 0)` or `(-1, 0)`, depending on sign.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::PI.to_rational_approx(1000) == (355, 113));
+    /// assert!(dd!(0.5).to_rational_approx(100) == (1, 2));
+    /// assert!(dd!(-0.75).to_rational_approx(100) == (-3, 4));
+    /// ```
+    ///
+    /// [continued fraction]: #method.to_continued_fraction
+    pub fn to_rational_approx(self, max_denom: i64) -> (i64, i64) {
+        if self.is_nan() {
+            return (0, 0);
+        }
+        let terms = self.to_continued_fraction(usize::MAX);
+        if terms.is_empty() {
+            return (if self.is_sign_negative() { -1 } else { 1 }, 0);
+        }
+
+        let max_denom = max_denom.max(1);
+        let distance = |p: i64, q: i64| (Double::from(p) / Double::from(q) - self).abs();
+
+        // The numerators and denominators of the last two convergents. None of the
+        // arithmetic here can overflow, since `to_continued_fraction` only returns terms
+        // whose convergents fit into an `i64`.
+        let (mut p0, mut q0) = (1i64, 0i64);
+        let (mut p1, mut q1) = (0i64, 1i64);
+
+        for a in terms {
+            let q = a * q0 + q1;
+            if q > max_denom {
+                // The best semiconvergent that still fits. The first denominator is always
+                // 1, so this can't be reached before there's a convergent to compare with.
+                let k = (max_denom - q1) / q0;
+                let (ps, qs) = (k * p0 + p1, k * q0 + q1);
+                if distance(ps, qs) < distance(p0, q0) {
+                    return (ps, qs);
+                }
+                break;
+            }
+            let p = a * p0 + p1;
+            p1 = p0;
+            q1 = q0;
+            p0 = p;
+            q0 = q;
+        }
+        (p0, q0)
+    }
+}
+
+// Multiplies `x` by 10 raised to the power `n`. Negative powers divide by the positive power
+// instead, since powers of ten up to 10^45 are exact but their reciprocals are not. Powers
+// that would overflow on their own are split into two steps.
+fn scale10(x: Double, n: i32) -> Double {
+    if n.abs() > 300 {
+        let half = n / 2;
+        return scale10(scale10(x, half), n - half);
+    }
+    let p = Double::from(10).powi(n.abs());
+    if n < 0 {
+        x / p
+    } else {
+        x * p
+    }
 }
 
 #[cfg(test)]
@@ -481,6 +884,255 @@ mod tests {
             Double::NAN.round();
     );
 
+    // round_half_up tests
+    test_all_exact!(
+        round_half_up_half:
+            dd!(1),
+            dd!(0.5).round_half_up();
+        round_half_up_neg_half:
+            Double::ZERO,
+            dd!(-0.5).round_half_up();
+        round_half_up_two_half:
+            dd!(3),
+            dd!(2.5).round_half_up();
+        round_half_up_neg_two_half:
+            dd!(-2),
+            dd!(-2.5).round_half_up();
+        round_half_up_pi:
+            dd!(3),
+            Double::PI.round_half_up();
+        round_half_up_neg_pi:
+            dd!(-3),
+            (-Double::PI).round_half_up();
+        round_half_up_int:
+            dd!(-7),
+            dd!(-7).round_half_up();
+        round_half_up_below_half:
+            Double::ZERO,
+            (dd!(0.5) - dd!(1e-40)).round_half_up();
+        round_half_up_above_half:
+            dd!(1),
+            (dd!(0.5) + dd!(1e-40)).round_half_up();
+        round_half_up_neg_below_half:
+            dd!(-1),
+            (dd!(-0.5) - dd!(1e-40)).round_half_up();
+        round_half_up_large_below_half:
+            dd!(2f64.powi(60)),
+            (dd!(2f64.powi(60)) + (dd!(0.5) - dd!(2f64.powi(-54)))).round_half_up();
+        round_half_up_large_half:
+            dd!(2f64.powi(60)) + dd!(1),
+            (dd!(2f64.powi(60)) + dd!(0.5)).round_half_up();
+
+        round_half_up_zero:
+            Double::ZERO,
+            Double::ZERO.round_half_up();
+        round_half_up_inf:
+            Double::INFINITY,
+            Double::INFINITY.round_half_up();
+        round_half_up_neg_inf:
+            Double::NEG_INFINITY,
+            Double::NEG_INFINITY.round_half_up();
+        round_half_up_nan:
+            Double::NAN,
+            Double::NAN.round_half_up();
+    );
+    test_all_assert!(
+        round_half_up_neg_half_sign:
+            dd!(-0.5).round_half_up().is_sign_negative();
+        round_half_up_neg_zero_sign:
+            Double::NEG_ZERO.round_half_up().is_sign_negative();
+    );
+
+    // round_to_sig_figs tests
+    test_all_exact!(
+        sig_figs_int:
+            dd!(123000),
+            dd!(123456).round_to_sig_figs(3);
+        sig_figs_neg:
+            dd!(-990),
+            dd!(-987.65).round_to_sig_figs(2);
+        sig_figs_carry:
+            dd!(10),
+            dd!(9.99).round_to_sig_figs(2);
+        sig_figs_half:
+            dd!(3),
+            dd!(2.5).round_to_sig_figs(1);
+        sig_figs_neg_half:
+            dd!(-3),
+            dd!(-2.5).round_to_sig_figs(1);
+        sig_figs_power_of_ten:
+            dd!(1000),
+            dd!(1000).round_to_sig_figs(1);
+        sig_figs_just_under_power:
+            dd!(1000),
+            (dd!(1000) - dd!(1e-20)).round_to_sig_figs(5);
+        sig_figs_exact:
+            dd!(0.5),
+            dd!(0.5).round_to_sig_figs(3);
+        sig_figs_large:
+            dd!("123456789000000000000"),
+            dd!("123456789012345678901").round_to_sig_figs(9);
+        sig_figs_tiny:
+            dd!(123) / dd!(10).powi(302),
+            (dd!(123456) / dd!(10).powi(305)).round_to_sig_figs(3);
+        sig_figs_many:
+            Double::PI,
+            Double::PI.round_to_sig_figs(34);
+        sig_figs_zero:
+            Double::ZERO,
+            Double::ZERO.round_to_sig_figs(3);
+        sig_figs_neg_zero:
+            Double::NEG_ZERO,
+            Double::NEG_ZERO.round_to_sig_figs(3);
+        sig_figs_inf:
+            Double::INFINITY,
+            Double::INFINITY.round_to_sig_figs(3);
+        sig_figs_neg_inf:
+            Double::NEG_INFINITY,
+            Double::NEG_INFINITY.round_to_sig_figs(3);
+        sig_figs_nan:
+            Double::NAN,
+            Double::NAN.round_to_sig_figs(3);
+        sig_figs_no_figs:
+            Double::NAN,
+            Double::PI.round_to_sig_figs(0);
+        sig_figs_max:
+            Double::INFINITY,
+            Double::MAX.round_to_sig_figs(3);
+        sig_figs_neg_max:
+            Double::NEG_INFINITY,
+            Double::MIN.round_to_sig_figs(3);
+        sig_figs_past_max:
+            Double::INFINITY,
+            dd!(1.7e308).round_to_sig_figs(1);
+        sig_figs_neg_past_max:
+            Double::NEG_INFINITY,
+            dd!(-1.7e308).round_to_sig_figs(1);
+        sig_figs_max_all:
+            Double::MAX,
+            Double::MAX.round_to_sig_figs(33);
+        sig_figs_max_down:
+            dd!("1.7976931348623158079372897140530e308"),
+            Double::MAX.round_to_sig_figs(32);
+        sig_figs_neg_max_down:
+            dd!("-1.7976931348623158079372897140530e308"),
+            Double::MIN.round_to_sig_figs(32);
+        sig_figs_max_up:
+            dd!("1.7976931348623158e308"),
+            Double::MAX.round_to_sig_figs(17);
+    );
+    test_all_near!(
+        sig_figs_small:
+            dd!("0.00012"),
+            dd!(0.00012345).round_to_sig_figs(2);
+        sig_figs_pi:
+            dd!("3.1416"),
+            Double::PI.round_to_sig_figs(5);
+        sig_figs_pi_30:
+            dd!("3.14159265358979323846264338328"),
+            Double::PI.round_to_sig_figs(30);
+        sig_figs_huge:
+            dd!(123) * dd!(10).powi(298),
+            (dd!(123456) * dd!(10).powi(295)).round_to_sig_figs(3);
+        sig_figs_top:
+            dd!("1.7e308"),
+            dd!(1.7e308).round_to_sig_figs(2);
+    );
+    // round_dp tests
+    test_all_exact!(
+        round_dp_half_even_down:
+            dd!(2),
+            dd!(2.5).round_dp(0, RoundingMode::HalfEven);
+        round_dp_half_even_up:
+            dd!(4),
+            dd!(3.5).round_dp(0, RoundingMode::HalfEven);
+        round_dp_half_even_places:
+            dd!("0.12"),
+            dd!(0.125).round_dp(2, RoundingMode::HalfEven);
+        round_dp_half_even_neg:
+            dd!("-0.38"),
+            dd!(-0.375).round_dp(2, RoundingMode::HalfEven);
+        round_dp_half_away:
+            dd!("0.13"),
+            dd!(0.125).round_dp(2, RoundingMode::HalfAwayFromZero);
+        round_dp_half_away_neg:
+            dd!("-0.13"),
+            dd!(-0.125).round_dp(2, RoundingMode::HalfAwayFromZero);
+        round_dp_down:
+            dd!("1234.56"),
+            dd!("1234.5678").round_dp(2, RoundingMode::Down);
+        round_dp_down_neg:
+            dd!("-1234.57"),
+            dd!("-1234.5678").round_dp(2, RoundingMode::Down);
+        round_dp_up:
+            dd!("1234.57"),
+            dd!("1234.5612").round_dp(2, RoundingMode::Up);
+        round_dp_up_neg:
+            dd!("-1234.56"),
+            dd!("-1234.5678").round_dp(2, RoundingMode::Up);
+        round_dp_toward_zero:
+            dd!("-1234.56"),
+            dd!("-1234.5678").round_dp(2, RoundingMode::TowardZero);
+        round_dp_carry:
+            dd!(10),
+            dd!("9.996").round_dp(2, RoundingMode::HalfEven);
+        round_dp_pi:
+            dd!("3.1415926536"),
+            Double::PI.round_dp(10, RoundingMode::HalfEven);
+        round_dp_large:
+            dd!(1e300),
+            dd!(1e300).round_dp(5, RoundingMode::HalfEven);
+        round_dp_exact:
+            Double::E,
+            Double::E.round_dp(1074, RoundingMode::Up);
+        round_dp_inf:
+            Double::INFINITY,
+            Double::INFINITY.round_dp(2, RoundingMode::HalfEven);
+        round_dp_neg_inf:
+            Double::NEG_INFINITY,
+            Double::NEG_INFINITY.round_dp(2, RoundingMode::Down);
+        round_dp_nan:
+            Double::NAN,
+            Double::NAN.round_dp(2, RoundingMode::HalfEven);
+    );
+    test_all_assert!(
+        round_dp_to_zero:
+            dd!(0.004).round_dp(2, RoundingMode::HalfEven).is_zero();
+        round_dp_to_neg_zero:
+            dd!(-0.004).round_dp(2, RoundingMode::HalfEven).is_sign_negative();
+        round_dp_neg_zero:
+            Double::NEG_ZERO.round_dp(2, RoundingMode::Up).is_sign_negative();
+        round_dp_up_from_zero:
+            dd!(0.001).round_dp(2, RoundingMode::Up) == dd!("0.01");
+        round_dp_down_from_zero:
+            dd!(-0.001).round_dp(2, RoundingMode::Down) == dd!("-0.01");
+    );
+    test!(round_dp_matches_to_fixed: {
+        // Rounding to places gives the same number as parsing the string that `to_fixed`
+        // writes, which rounds in the same way
+        let modes = [
+            RoundingMode::HalfEven,
+            RoundingMode::HalfAwayFromZero,
+            RoundingMode::Down,
+            RoundingMode::Up,
+            RoundingMode::TowardZero,
+        ];
+        for &x in &[
+            Double::PI,
+            -Double::E * dd!(1e10),
+            Double::LN_2 / dd!(1e20),
+            dd!(1) / dd!(3),
+            dd!("-2.675"),
+        ] {
+            for &mode in &modes {
+                for places in [0, 1, 2, 5, 15, 40] {
+                    let expected: Double = x.to_fixed(places, mode).parse().unwrap();
+                    assert!(x.round_dp(places, mode) == expected, "{:?} {:?} {}", x, mode, places);
+                }
+            }
+        }
+    });
+
     // trunc tests
     test_all_exact!(
         trunc_pi:
@@ -606,6 +1258,120 @@ mod tests {
             Double::NAN.signum();
     );
 
+    // eps_at tests
+    test_all_exact!(
+        eps_at_one:
+            Double(2f64.powi(-104), 0.0),
+            dd!(1).eps_at();
+        eps_at_neg_one:
+            Double(2f64.powi(-104), 0.0),
+            dd!(-1).eps_at();
+        eps_at_just_under_two:
+            Double(2f64.powi(-104), 0.0),
+            dd!(1.75).eps_at();
+        eps_at_two:
+            Double(2f64.powi(-103), 0.0),
+            dd!(2).eps_at();
+        eps_at_pi:
+            Double(2f64.powi(-103), 0.0),
+            Double::PI.eps_at();
+        eps_at_large:
+            Double(2f64.powi(-4), 0.0),
+            dd!(2).powi(100).eps_at();
+        eps_at_small:
+            Double(2f64.powi(-204), 0.0),
+            dd!(2).powi(-100).eps_at();
+        eps_at_max:
+            Double(2f64.powi(1023 - 104), 0.0),
+            Double::MAX.eps_at();
+        eps_at_inf:
+            Double::INFINITY,
+            Double::INFINITY.eps_at();
+        eps_at_neg_inf:
+            Double::INFINITY,
+            Double::NEG_INFINITY.eps_at();
+        eps_at_nan:
+            Double::NAN,
+            Double::NAN.eps_at();
+    );
+    // `exact!` can't be used with subnormals, which `Display` doesn't handle
+    test_all_assert!(
+        eps_at_epsilon:
+            (dd!(1).eps_at() - Double::EPSILON).abs() < Double::EPSILON * dd!(1e-14);
+        eps_at_tiny:
+            Double::MIN_POSITIVE.eps_at() == Double(f64::from_bits(1), 0.0);
+        eps_at_zero:
+            Double::ZERO.eps_at() == Double(f64::from_bits(1), 0.0);
+        eps_at_neg_zero:
+            Double::NEG_ZERO.eps_at() == Double(f64::from_bits(1), 0.0);
+    );
+    test!(eps_at_scaling: {
+        for x in &[dd!(1), Double::PI, dd!(1e10), dd!(-3.5e-20), dd!(7.25e100)] {
+            let eps = x.eps_at();
+            assert!(eps > Double::ZERO);
+            assert!(eps <= x.abs() * Double(2f64.powi(-104), 0.0));
+            assert!(eps > x.abs() * Double(2f64.powi(-105), 0.0));
+            exact!(eps * dd!(1024), (*x * dd!(1024)).eps_at());
+            exact!(eps / dd!(1024), (*x / dd!(1024)).eps_at());
+        }
+    });
+
+    // ilog2 tests
+    test_all_eq!(
+        ilog2_one:
+            Some(0),
+            dd!(1).ilog2();
+        ilog2_eight:
+            Some(3),
+            dd!(8).ilog2();
+        ilog2_seven:
+            Some(2),
+            dd!(7).ilog2();
+        ilog2_pi:
+            Some(1),
+            Double::PI.ilog2();
+        ilog2_large:
+            Some(200),
+            dd!(2).powi(200).ilog2();
+        ilog2_max:
+            Some(1023),
+            Double::MAX.ilog2();
+        ilog2_under_power:
+            Some(2),
+            Double(8.0, -1e-20).ilog2();
+        ilog2_over_power:
+            Some(3),
+            Double(8.0, 1e-20).ilog2();
+        ilog2_under_one:
+            None,
+            Double(1.0, -1e-20).ilog2();
+        ilog2_half:
+            None,
+            dd!(0.5).ilog2();
+        ilog2_zero:
+            None,
+            Double::ZERO.ilog2();
+        ilog2_neg:
+            None,
+            dd!(-8).ilog2();
+        ilog2_inf:
+            None,
+            Double::INFINITY.ilog2();
+        ilog2_nan:
+            None,
+            Double::NAN.ilog2();
+    );
+    test!(ilog2_powers: {
+        for n in 0..1000 {
+            let x = dd!(2).powi(n);
+            assert!(x.ilog2() == Some(n as u32));
+            assert!((x * dd!(1.5)).ilog2() == Some(n as u32));
+            if n > 0 {
+                assert!((x - Double::EPSILON).ilog2() == Some(n as u32 - 1));
+            }
+        }
+    });
+
     // classify tests
     test_all_eq!(
         classify_pi:
@@ -790,4 +1556,114 @@ mod tests {
         is_subnormal_sub:
             dd!(1e-308).is_subnormal();
     );
+    // to_continued_fraction tests
+    test_all_eq!(
+        cf_3_25:
+            vec![3, 4],
+            dd!(3.25).to_continued_fraction(10);
+        cf_neg_3_25:
+            vec![-4, 1, 3],
+            dd!(-3.25).to_continued_fraction(10);
+        cf_int:
+            vec![42],
+            dd!(42).to_continued_fraction(10);
+        cf_zero:
+            vec![0],
+            Double::ZERO.to_continued_fraction(10);
+        cf_fraction:
+            vec![0, 1, 2, 3, 4],
+            (dd!(30) / dd!(43)).to_continued_fraction(10);
+        cf_pi:
+            vec![3, 7, 15, 1, 292, 1, 1, 1, 2, 1, 3, 1, 14, 2, 1, 1, 2, 2, 2, 2],
+            Double::PI.to_continued_fraction(20);
+        cf_e:
+            vec![2, 1, 2, 1, 1, 4, 1, 1, 6, 1, 1, 8, 1, 1, 10, 1, 1, 12, 1, 1],
+            Double::E.to_continued_fraction(20);
+        cf_sqrt_2:
+            [vec![1], vec![2; 19]].concat(),
+            Double::SQRT_2.to_continued_fraction(20);
+        cf_max_terms:
+            vec![3, 7, 15],
+            Double::PI.to_continued_fraction(3);
+        cf_no_terms:
+            Vec::<i64>::new(),
+            Double::PI.to_continued_fraction(0);
+        cf_inf:
+            Vec::<i64>::new(),
+            Double::INFINITY.to_continued_fraction(10);
+        cf_nan:
+            Vec::<i64>::new(),
+            Double::NAN.to_continued_fraction(10);
+        cf_huge:
+            Vec::<i64>::new(),
+            dd!(1e30).to_continued_fraction(10);
+    );
+    test!(cf_golden_ratio: {
+        let phi = (Double::ONE + dd!(5).sqrt()) / dd!(2);
+        let terms = phi.to_continued_fraction(1000);
+        assert!(terms.len() > 50, "only {} terms", terms.len());
+        assert!(terms.iter().all(|t| *t == 1), "{:?}", terms);
+    });
+
+    // to_rational_approx tests
+    test_all_eq!(
+        rational_pi_1000:
+            (355, 113),
+            Double::PI.to_rational_approx(1000);
+        rational_pi_100:
+            (311, 99),
+            Double::PI.to_rational_approx(100);
+        rational_pi_10:
+            (22, 7),
+            Double::PI.to_rational_approx(10);
+        rational_neg_pi:
+            (-355, 113),
+            (-Double::PI).to_rational_approx(1000);
+        rational_e_1000:
+            (1457, 536),
+            Double::E.to_rational_approx(1000);
+        rational_half:
+            (1, 2),
+            dd!(0.5).to_rational_approx(100);
+        rational_neg_half:
+            (-1, 2),
+            dd!(-0.5).to_rational_approx(100);
+        rational_third:
+            (1, 3),
+            (dd!(1) / dd!(3)).to_rational_approx(1_000_000);
+        rational_exact:
+            (30, 43),
+            (dd!(30) / dd!(43)).to_rational_approx(1_000_000);
+        rational_int:
+            (42, 1),
+            dd!(42).to_rational_approx(100);
+        rational_zero:
+            (0, 1),
+            Double::ZERO.to_rational_approx(100);
+        rational_denom_1:
+            (3, 1),
+            Double::PI.to_rational_approx(1);
+        rational_denom_0:
+            (4, 1),
+            dd!(3.75).to_rational_approx(0);
+        rational_nan:
+            (0, 0),
+            Double::NAN.to_rational_approx(100);
+        rational_inf:
+            (1, 0),
+            Double::INFINITY.to_rational_approx(100);
+        rational_neg_inf:
+            (-1, 0),
+            Double::NEG_INFINITY.to_rational_approx(100);
+        rational_huge:
+            (-1, 0),
+            dd!(-1e30).to_rational_approx(100);
+    );
+    test!(rational_large_denom: {
+        let (p, q) = Double::PI.to_rational_approx(i64::MAX);
+        assert!(q > 1_000_000_000);
+        let diff = (Double::from(p) / Double::from(q) - Double::PI).abs();
+        // A convergent is always within 1/q² of the number it approximates
+        assert!(diff < Double::from(q).sqr().recip(), "{}/{} is off by {}", p, q, diff);
+    });
 }
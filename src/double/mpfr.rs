@@ -0,0 +1,164 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::mpfr as m;
+use crate::double::Double;
+use rug::Float;
+
+impl From<Double> for Float {
+    /// Converts a `Double` into a [`Float`], exactly.
+    ///
+    /// The `Float` is given just enough precision to hold the sum of the components of the
+    /// `Double`. That's usually 106 or 107 bits, but it can be more for a `Double` whose
+    /// components are far apart in magnitude, and it's never less than 53. `NaN`, the
+    /// infinities, and the sign of zero all carry over.
+    ///
+    /// This is only available when the `rug` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use rug::Float;
+    ///
+    /// let x = Float::from(dd!(1.5));
+    /// assert!(x == 1.5);
+    ///
+    /// let pi = Float::from(Double::PI);
+    /// assert!(pi.prec() >= 106);
+    /// assert!(Double::from(&pi) == Double::PI);
+    /// ```
+    ///
+    /// [`Float`]: https://docs.rs/rug/1/rug/struct.Float.html
+    fn from(x: Double) -> Float {
+        m::to_float(&[x.0, x.1])
+    }
+}
+
+impl From<&Float> for Double {
+    /// Generates a `Double` from a reference to a [`Float`], correctly rounded.
+    ///
+    /// The first component is the `Float` rounded to the nearest `f64`, and the second is
+    /// the rest of the `Float` rounded to the nearest `f64`, so the result is the `Double`
+    /// closest to the `Float`. A `Float` with no more than 106 bits of precision converts
+    /// exactly, as long as it's in range. One too large in magnitude becomes [`INFINITY`] or
+    /// [`NEG_INFINITY`], and `NaN` stays `NaN`.
+    ///
+    /// This is only available when the `rug` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use rug::Float;
+    ///
+    /// let x = Float::with_val(53, 1.5);
+    /// assert!(Double::from(&x) == dd!(1.5));
+    ///
+    /// let third = Float::with_val(300, 1) / 3;
+    /// assert!((Double::from(&third) - dd!(1) / dd!(3)).abs() < dd!(1e-31));
+    /// ```
+    ///
+    /// [`Float`]: https://docs.rs/rug/1/rug/struct.Float.html
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    fn from(f: &Float) -> Double {
+        let parts = m::split(f, 2);
+        Double(parts[0], parts[1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pow2(n: i32) -> f64 {
+        2f64.powi(n)
+    }
+
+    // to_float tests
+    test!(to_float_exact: {
+        assert!(Float::from(dd!(1.5)) == 1.5);
+        assert!(Float::from(dd!(-12345)) == -12345);
+        let pi = Float::from(Double::PI);
+        assert!(pi.to_f64() == Double::PI.0);
+        assert!(Float::with_val(pi.prec(), &pi - Double::PI.0) == Double::PI.1);
+    });
+    test!(to_float_prec: {
+        assert!(Float::from(dd!(1.5)).prec() == 53);
+        let x = Float::from(Double(1.0, pow2(-200)));
+        assert!(x.prec() >= 201);
+        assert!(Float::with_val(x.prec(), &x - 1) == pow2(-200));
+        let x = Float::from(Double(f64::MAX, f64::from_bits(1)));
+        assert!(x.prec() >= 2098);
+        assert!(Double::from(&x) == Double(f64::MAX, f64::from_bits(1)));
+    });
+    test!(to_float_special: {
+        assert!(Float::from(Double::NAN).is_nan());
+        let inf = Float::from(Double::INFINITY);
+        assert!(inf.is_infinite() && inf.is_sign_positive());
+        let neg_inf = Float::from(Double::NEG_INFINITY);
+        assert!(neg_inf.is_infinite() && neg_inf.is_sign_negative());
+        let zero = Float::from(Double::ZERO);
+        assert!(zero.is_zero() && zero.is_sign_positive());
+        let neg_zero = Float::from(Double::NEG_ZERO);
+        assert!(neg_zero.is_zero() && neg_zero.is_sign_negative());
+    });
+
+    // from_float tests
+    test_all_exact!(
+        from_float_small:
+            dd!(1.5),
+            Double::from(&Float::with_val(53, 1.5));
+        from_float_two_parts:
+            Double(1.0, pow2(-100)),
+            Double::from(&(Float::with_val(101, 1) + pow2(-100)));
+        from_float_overflow:
+            Double::INFINITY,
+            Double::from(&(Float::with_val(53, f64::MAX) * 2));
+        from_float_neg_overflow:
+            Double::NEG_INFINITY,
+            Double::from(&(Float::with_val(53, f64::MAX) * -2));
+        from_float_underflow:
+            Double::ZERO,
+            Double::from(&(Float::with_val(53, 1e-300) * 1e-300));
+    );
+    test!(from_float_rounded: {
+        // Just under half of the last place of the second component is rounded away, and
+        // just over half of it is rounded up
+        let pi = Float::from(Double::PI);
+        let next = f64::from_bits(Double::PI.1.to_bits() + 1);
+        let ulp = next - Double::PI.1;
+        let under = Float::with_val(300, &pi + ulp * 0.4999);
+        assert!(Double::from(&under) == Double::PI);
+        let over = Float::with_val(300, &pi + ulp * 0.5001);
+        assert!(Double::from(&over) == Double(Double::PI.0, next));
+    });
+    test!(from_float_third: {
+        let third = Float::with_val(400, 1) / 3;
+        near!(dd!(1) / dd!(3), Double::from(&third));
+        // Off by no more than half of the last place of the second component
+        let rest = Float::with_val(400, &third - &Float::from(Double::from(&third)));
+        assert!(rest.abs() <= pow2(-108));
+    });
+    test!(from_float_special: {
+        assert!(Double::from(&Float::with_val(53, f64::NAN)).is_nan());
+        let neg_zero = Double::from(&Float::with_val(53, -0.0));
+        assert!(neg_zero.is_zero() && neg_zero.is_sign_negative());
+    });
+
+    // round trip tests
+    test!(round_trip: {
+        for x in [
+            Double::PI,
+            -Double::E,
+            Double::LN_2 * dd!(1e200),
+            Double::SQRT_2 / dd!(1e200),
+            Double::MAX,
+            Double::MIN_POSITIVE,
+            Double(1.5, pow2(-600)),
+        ] {
+            assert!(Double::from(&Float::from(x)) == x);
+        }
+    });
+}
@@ -3,9 +3,13 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::binary128 as b;
 use crate::common::primitive as p;
 use crate::common::utils as u;
+use crate::double::common as c;
 use crate::double::Double;
+use crate::quad::Quad;
+use crate::round::RoundMode;
 use std::ops::{Mul, MulAssign};
 
 #[allow(clippy::suspicious_arithmetic_impl)]
@@ -28,11 +32,7 @@ impl Mul for Double {
     fn mul(self, other: Double) -> Double {
         match self.pre_mul(&other) {
             Some(r) => r,
-            None => {
-                let (p, e) = p::two_prod(self.0, other.0);
-                let (a, b) = u::renorm2(p, e + self.0 * other.1 + self.1 * other.0);
-                Double(a, b)
-            }
+            None => self.mul_unchecked(other),
         }
     }
 }
@@ -153,6 +153,476 @@ impl MulAssign<&Double> for Double {
     }
 }
 
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Mul<f64> for Double {
+    type Output = Double;
+
+    /// Multiplies this `Double` by an `f64`, producing a new `Double` as a result.
+    ///
+    /// This implements the `*` operator between a `Double` and an `f64`.
+    ///
+    /// The `f64` is taken at its exact binary value, as with `Double::from((x, 0.0))`, and
+    /// is worked directly into the components rather than first being promoted to a
+    /// `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::PI * 2.5;
+    /// let expected = dd!("7.853981633974483096156608458199");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    fn mul(self, other: f64) -> Double {
+        match self.pre_mul(&Double(other, 0.0)) {
+            Some(r) => r,
+            None => {
+                let (p, e) = p::two_prod(self.0, other);
+                let (a, b) = u::renorm2(p, e + self.1 * other);
+                Double(a, b)
+            }
+        }
+    }
+}
+
+impl Mul<f64> for &Double {
+    type Output = Double;
+
+    /// Multiplies a reference to this `Double` by an `f64`, producing a new `Double` as a result.
+    ///
+    /// This implements the `*` operator between a reference to a `Double` and an `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = &Double::PI * 2.5;
+    /// let expected = dd!("7.853981633974483096156608458199");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn mul(self, other: f64) -> Double {
+        (*self).mul(other)
+    }
+}
+
+impl Mul<Double> for f64 {
+    type Output = Double;
+
+    /// Multiplies this `f64` by a `Double`, producing a new `Double` as a result.
+    ///
+    /// This implements the `*` operator between an `f64` and a `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = 2.5 * Double::PI;
+    /// let expected = dd!("7.853981633974483096156608458199");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn mul(self, other: Double) -> Double {
+        other.mul(self)
+    }
+}
+
+impl Mul<&Double> for f64 {
+    type Output = Double;
+
+    /// Multiplies this `f64` by a reference to a `Double`, producing a new `Double` as a result.
+    ///
+    /// This implements the `*` operator between an `f64` and a reference to a `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = 2.5 * &Double::PI;
+    /// let expected = dd!("7.853981633974483096156608458199");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn mul(self, other: &Double) -> Double {
+        self.mul(*other)
+    }
+}
+
+impl MulAssign<f64> for Double {
+    /// Multiplies this `Double` by an `f64`, modifying this one to equal the result.
+    ///
+    /// This implements the `*=` operator between a `Double` and an `f64`. As with the
+    /// non-assigning operator, the `f64` is taken at its exact binary value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let mut x = Double::PI;
+    /// x *= 2.5;
+    /// let expected = dd!("7.853981633974483096156608458199");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: f64) {
+        let r = self.mul(other);
+        self.0 = r.0;
+        self.1 = r.1;
+    }
+}
+
+macro_rules! mul_int_impl {
+    ($($t:ty)*) => ($(
+        impl Mul<$t> for Double {
+            type Output = Double;
+
+            /// Multiplies this `Double` by an integer, producing a new `Double` as a
+            /// result.
+            ///
+            /// This implements the `*` operator between a `Double` and an integer. Every
+            /// integer of this type converts exactly to an `f64`, so this takes the same
+            /// fast path as the `f64` operator.
+            #[inline]
+            fn mul(self, other: $t) -> Double {
+                self.mul(f64::from(other))
+            }
+        }
+
+        impl Mul<$t> for &Double {
+            type Output = Double;
+
+            /// Multiplies a reference to this `Double` by an integer, producing a new
+            /// `Double` as a result.
+            ///
+            /// This implements the `*` operator between a reference to a `Double` and an
+            /// integer.
+            #[inline]
+            fn mul(self, other: $t) -> Double {
+                (*self).mul(f64::from(other))
+            }
+        }
+
+        impl Mul<Double> for $t {
+            type Output = Double;
+
+            /// Multiplies this integer by a `Double`, producing a new `Double` as a result.
+            ///
+            /// This implements the `*` operator between an integer and a `Double`.
+            #[inline]
+            fn mul(self, other: Double) -> Double {
+                f64::from(self).mul(other)
+            }
+        }
+
+        impl MulAssign<$t> for Double {
+            /// Multiplies this `Double` by an integer, modifying this one to equal the
+            /// result.
+            ///
+            /// This implements the `*=` operator between a `Double` and an integer.
+            #[inline]
+            fn mul_assign(&mut self, other: $t) {
+                self.mul_assign(f64::from(other));
+            }
+        }
+    )*);
+}
+
+mul_int_impl! { i32 u32 }
+
+impl Double {
+    /// Calculates `self * a + b` with only a single rounding at the end.
+    ///
+    /// This is the `Double` version of the fused multiply-add of [`f64::mul_add`]. The
+    /// product is never rounded; its error-free terms are added to `b` directly and the result
+    /// is normalized only once, so it's more accurate than `self * a + b`, most of all when
+    /// the product and `b` nearly cancel. It's also a little faster, since it skips the
+    /// normalization of the product.
+    ///
+    /// Infinite and `NaN` arguments give the same results as `self * a + b`, as does a
+    /// product that overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let a = Double::ONE + Double::EPSILON;
+    /// let b = Double::ONE - Double::EPSILON;
+    ///
+    /// // a * b is 1 - ε², which rounds to 1
+    /// assert!(a * b - Double::ONE == Double::ZERO);
+    /// assert!(a.mul_add(b, -Double::ONE) == -Double::EPSILON * Double::EPSILON);
+    /// ```
+    ///
+    /// [`f64::mul_add`]: https://doc.rust-lang.org/std/primitive.f64.html#method.mul_add
+    pub fn mul_add(self, a: Double, b: Double) -> Double {
+        if !(self.is_finite() && a.is_finite() && b.is_finite()) {
+            return self * a + b;
+        }
+        if (self.is_zero() || a.is_zero()) && b.is_zero() {
+            // Only the signs of the zeros matter, and f64 arithmetic has those right
+            return Double(self.0 * a.0 + b.0, 0.0);
+        }
+
+        // The same terms as in multiplication, except that the low word of the smallest
+        // one is kept too, since it matters when the product and `b` cancel
+        let (p0, e0) = p::two_prod(self.0, a.0);
+        let (p1, e1) = p::two_prod(self.0, a.1);
+        let (p2, e2) = p::two_prod(self.1, a.0);
+        let (p3, e3) = p::two_prod(self.1, a.1);
+        let (r1, t0, t1) = u::three_three_sum(p1, p2, e0);
+        let (r2, t2, t3) = u::six_three_sum(t0, t1, e1, e2, p3, e3);
+
+        let mut x = [0.0; 3];
+        u::merge_sum(&[p0, r1, r2, t2 + t3], &[b.0, b.1], &mut x);
+        let (h, l) = p::quick_two_sum(x[0], x[1] + x[2]);
+        if h.is_finite() {
+            Double(h, l)
+        } else {
+            // The product overflowed
+            self * a + b
+        }
+    }
+
+    /// Calculates `a * b + c * d` with only a single rounding at the end.
+    ///
+    /// Calculating this expression directly rounds each product before they're added. When
+    /// the two products nearly cancel, the rounding errors of the products can be as large
+    /// as the sum itself, leaving a result with few or no correct digits. This function
+    /// instead keeps the full, exact products and adds them before rounding, so it gives an
+    /// accurate result even then. It's useful for things like complex multiplication and
+    /// 2 × 2 determinants (by passing `-c` for `c`).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let a = Double::ONE + dd!(2).powi(-60);
+    /// let b = Double::ONE - dd!(2).powi(-60);
+    ///
+    /// // a * b is 1 - 2⁻¹²⁰, which rounds to 1
+    /// assert!(a * b - Double::ONE == Double::ZERO);
+    /// assert!(Double::sum_of_products(a, b, Double::ONE, -Double::ONE) == -dd!(2).powi(-120));
+    /// ```
+    pub fn sum_of_products(a: Double, b: Double, c: Double, d: Double) -> Double {
+        let r = c::to_quad(a) * c::to_quad(b) + c::to_quad(c) * c::to_quad(d);
+        if r.is_finite() {
+            c::from_quad(r)
+        } else {
+            // Lets the normal arithmetic sort out infinities and NaNs
+            a * b + c * d
+        }
+    }
+
+    /// Calculates the sum of the products of corresponding elements of two slices, with
+    /// only a single rounding at the end.
+    ///
+    /// This is the dot product of `a` and `b`, calculated the same way as
+    /// [`sum_of_products`]: every product is kept exactly and they are all added together
+    /// before the final result is rounded. If the slices are of different lengths, the
+    /// extra elements in the longer one are ignored. The sum of products of empty slices
+    /// is `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let a = [dd!(1), dd!(2), dd!(3)];
+    /// let b = [dd!(4), dd!(5), dd!(6)];
+    /// assert!(Double::sum_of_products_slice(&a, &b) == dd!(32));
+    /// ```
+    ///
+    /// [`sum_of_products`]: #method.sum_of_products
+    pub fn sum_of_products_slice(a: &[Double], b: &[Double]) -> Double {
+        let r = a
+            .iter()
+            .zip(b.iter())
+            .fold(Quad::ZERO, |s, (x, y)| s + c::to_quad(*x) * c::to_quad(*y));
+        if r.is_finite() {
+            c::from_quad(r)
+        } else {
+            a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+        }
+    }
+
+    /// Linearly interpolates between this `Double` and `other`, by the fraction `t`.
+    ///
+    /// This is `self + (other - self) * t`, but calculated in a way that guarantees that a
+    /// `t` of `0` gives exactly this `Double` and a `t` of `1` gives exactly `other`. The
+    /// result is also monotonic in `t` and never overshoots `other` for `t` between `0` and
+    /// `1`, which the simple formula can't promise because of rounding. This follows the
+    /// same approach as C++'s `std::lerp`: when the endpoints have different signs (or one
+    /// is zero), the weighted sum `t * other + (1 - t) * self` is used instead, which is
+    /// exact at both ends, and otherwise the result is clamped against `other`.
+    ///
+    /// Values of `t` outside of `[0, 1]` extrapolate. If any argument is `NaN`, the result
+    /// is `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let a = Double::PI;
+    /// let b = Double::E * dd!(1e10);
+    ///
+    /// assert!(a.lerp(b, dd!(0)) == a);
+    /// assert!(a.lerp(b, dd!(1)) == b);
+    /// assert!(dd!(2).lerp(dd!(4), dd!(0.5)) == dd!(3));
+    /// ```
+    pub fn lerp(self, other: Double, t: Double) -> Double {
+        if self.is_nan() || other.is_nan() || t.is_nan() {
+            return Double::NAN;
+        }
+        if self.is_sign_negative() != other.is_sign_negative() || self.is_zero() || other.is_zero()
+        {
+            return t * other + (Double::ONE - t) * self;
+        }
+        if t == Double::ONE {
+            return other;
+        }
+        let x = self + t * (other - self);
+        // Past `other` in the direction of travel is only allowed if `t` is past 1
+        if (t > Double::ONE) == (other > self) {
+            if x > other {
+                x
+            } else {
+                other
+            }
+        } else if x < other {
+            x
+        } else {
+            other
+        }
+    }
+
+    /// Multiplies this `Double` by another, clamping the result to the range of finite
+    /// `Double`s instead of overflowing.
+    ///
+    /// If both `Double`s are finite but their product is too large in magnitude to represent,
+    /// the result is [`MAX`] or [`MIN`], whichever has the sign the product would have had.
+    /// Otherwise the result is the same as it is with the `*` operator, so infinite
+    /// arguments still give infinite results and `NaN` propagates as usual.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::MAX.saturating_mul(dd!(2)) == Double::MAX);
+    /// assert!(Double::MAX.saturating_mul(dd!(-2)) == Double::MIN);
+    /// assert!(dd!(2).saturating_mul(dd!(3)) == dd!(2) * dd!(3));
+    /// ```
+    ///
+    /// [`MAX`]: #associatedconstant.MAX
+    /// [`MIN`]: #associatedconstant.MIN
+    pub fn saturating_mul(self, other: Double) -> Double {
+        let r = self * other;
+        if !r.is_finite() && self.is_finite() && other.is_finite() {
+            // The overflowed result can be `NaN` rather than infinite, so the sign is taken
+            // from the product of the first components instead
+            if (self.0 * other.0).is_sign_negative() {
+                Double::MIN
+            } else {
+                Double::MAX
+            }
+        } else {
+            r
+        }
+    }
+
+    /// Multiplies this `Double` by another, returning `None` if the product overflows.
+    ///
+    /// This is the same as the `*` operator, except that a product that is infinite or
+    /// `NaN` is turned into `None` when both `Double`s were finite. A product that
+    /// underflows to zero is finite, so it isn't an error. As in [`checked_add`], infinite
+    /// and `NaN` arguments still produce `Some`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(2).checked_mul(dd!(3)) == Some(dd!(6)));
+    /// assert!(Double::MAX.checked_mul(dd!(2)) == None);
+    /// assert!(Double::INFINITY.checked_mul(dd!(2)) == Some(Double::INFINITY));
+    /// ```
+    ///
+    /// [`checked_add`]: #method.checked_add
+    pub fn checked_mul(self, other: Double) -> Option<Double> {
+        let r = self * other;
+        if r.is_finite() || !(self.is_finite() && other.is_finite()) {
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    /// Multiplies this `Double` by another without checking for special cases first.
+    ///
+    /// This skips the checks for zeros, infinities, and `NaN` that the `*` operator makes
+    /// before multiplying, for speed when the arguments are known to be finite. Like
+    /// [`add_unchecked`], it isn't unsafe, but the result is meaningless if either `Double`
+    /// is infinite or `NaN`. For finite `Double`s it's the same as `*`, except that a zero
+    /// result may not have the right sign.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// assert!(Double::PI.mul_unchecked(Double::E) == Double::PI * Double::E);
+    /// ```
+    ///
+    /// [`add_unchecked`]: #method.add_unchecked
+    pub fn mul_unchecked(self, other: Double) -> Double {
+        let (p, e) = p::two_prod(self.0, other.0);
+        let (a, b) = u::renorm2(p, e + self.0 * other.1 + self.1 * other.0);
+        Double(a, b)
+    }
+
+    /// Multiplies this `Double` by another, rounding the product in the direction given by
+    /// `mode`.
+    ///
+    /// The product is first calculated as it is by the `*` operator. It's then compared
+    /// exactly with the sum of the products of each pair of components, however small they
+    /// are, and if it's on the wrong side of that for `mode`, it's stepped (by about the size
+    /// returned by [`eps_at`]) until it isn't, as in [`add_round`]. The result with
+    /// [`RoundMode::Up`] is never less than the exact product and the result with
+    /// [`RoundMode::Down`] is never greater, so the two always bracket it.
+    /// [`RoundMode::Nearest`] gives the same result as `*`.
+    ///
+    /// If either `Double` is infinite or `NaN`, the result is the same as it is with `*`.
+    /// When a product of finite `Double`s overflows, the result is an infinity if `mode`
+    /// rounds it away from zero and [`MAX`] or [`MIN`] if `mode` rounds it toward zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, RoundMode};
+    /// let x = dd!(1) / dd!(3);
+    /// assert!(x.mul_round(x, RoundMode::Up) > x.mul_round(x, RoundMode::Down));
+    ///
+    /// // Exact products aren't moved
+    /// assert!(dd!(1.5).mul_round(dd!(-2.5), RoundMode::Up) == dd!(-3.75));
+    /// ```
+    ///
+    /// [`add_round`]: #method.add_round
+    /// [`eps_at`]: #method.eps_at
+    /// [`MAX`]: #associatedconstant.MAX
+    /// [`MIN`]: #associatedconstant.MIN
+    /// [`RoundMode::Up`]: enum.RoundMode.html#variant.Up
+    /// [`RoundMode::Down`]: enum.RoundMode.html#variant.Down
+    /// [`RoundMode::Nearest`]: enum.RoundMode.html#variant.Nearest
+    pub fn mul_round(self, other: Double, mode: RoundMode) -> Double {
+        let r = self * other;
+        if !(self.is_finite() && other.is_finite()) {
+            return r;
+        }
+        c::round_directed(r, mode, |x| {
+            let products = [
+                (-self.0, other.0),
+                (-self.0, other.1),
+                (-self.1, other.0),
+                (-self.1, other.1),
+            ];
+            b::sum_sign(&[x.0, x.1], &products)
+        })
+    }
+}
+
 impl Double {
     // Precalc functions
     //
@@ -300,6 +770,410 @@ mod tests {
             Double::ONE * Double::NAN;
     );
 
+    // f64 tests
+    test_all_exact!(
+        f64_int:
+            dd!(6),
+            dd!(3) * 2.0;
+        f64_rev_int:
+            dd!(6),
+            2.0 * dd!(3);
+        f64_id:
+            Double::PI,
+            Double::PI * 1.0;
+        f64_zero:
+            Double::ZERO,
+            Double::PI * 0.0;
+        f64_inf:
+            Double::NEG_INFINITY,
+            Double::PI * f64::NEG_INFINITY;
+        f64_inf_zero:
+            Double::NAN,
+            Double::INFINITY * 0.0;
+        f64_nan:
+            Double::NAN,
+            Double::ONE * f64::NAN;
+        f64_rev_nan:
+            Double::NAN,
+            f64::NAN * Double::ONE;
+    );
+    test_all_near!(
+        f64_num:
+            Double::PI * Double(1.1, 0.0),
+            Double::PI * 1.1;
+        f64_ref:
+            Double::PI * Double(1.1, 0.0),
+            &Double::PI * 1.1;
+        f64_rev_num:
+            Double::PI * Double(1.1, 0.0),
+            1.1 * Double::PI;
+        f64_rev_ref:
+            Double::PI * Double(1.1, 0.0),
+            1.1 * &Double::PI;
+        f64_small:
+            Double::E * Double(3.7e-200, 0.0),
+            Double::E * 3.7e-200;
+    );
+
+    // mul_add tests
+    test_all_exact!(
+        mul_add_ints:
+            dd!(10),
+            dd!(2).mul_add(dd!(3), dd!(4));
+        mul_add_cancel:
+            -Double(2f64.powi(-120), 0.0),
+            Double(1.0, 2f64.powi(-60)).mul_add(Double(1.0, -2f64.powi(-60)), -Double::ONE);
+        mul_add_product_error:
+            Double(-2.537747394891901e-32, 1.1809080002640656e-48),
+            Double::PI.mul_add(Double::E, -(Double::PI * Double::E));
+        mul_add_zero:
+            Double::ZERO,
+            Double::PI.mul_add(Double::ZERO, Double::ZERO);
+        mul_add_inf:
+            Double::INFINITY,
+            Double::INFINITY.mul_add(dd!(2), dd!(1));
+        mul_add_inf_zero:
+            Double::NAN,
+            Double::INFINITY.mul_add(Double::ZERO, dd!(1));
+        mul_add_inf_inf:
+            Double::NAN,
+            Double::INFINITY.mul_add(dd!(2), Double::NEG_INFINITY);
+        mul_add_nan:
+            Double::NAN,
+            Double::NAN.mul_add(dd!(2), dd!(1));
+        mul_add_overflow:
+            Double::MAX * dd!(2) + dd!(1),
+            Double::MAX.mul_add(dd!(2), dd!(1));
+    );
+    test_all_near!(
+        mul_add_pi_e:
+            dd!("9.5397342226735670654635508695465744950348885357651149618796011301792"),
+            Double::PI.mul_add(Double::E, Double::ONE);
+        mul_add_neg:
+            dd!("-5.3981415690837738270009074862670716108377191363900091409046565379"),
+            Double::PI.mul_add(-Double::E, Double::PI);
+    );
+    test_all_assert!(
+        mul_add_cancel_sign:
+            Double::ONE.mul_add(Double::ONE, -Double::ONE).is_sign_positive();
+        mul_add_neg_zero:
+            Double::NEG_ZERO.mul_add(Double::ONE, Double::NEG_ZERO).is_sign_negative();
+        mul_add_mixed_zero:
+            Double::NEG_ZERO.mul_add(Double::ONE, Double::ZERO).is_sign_positive();
+    );
+
+    // sum_of_products tests
+    test_all_exact!(
+        sop_cancel:
+            -Double(2f64.powi(-120), 0.0),
+            Double::sum_of_products(
+                Double(1.0, 2f64.powi(-60)),
+                Double(1.0, -2f64.powi(-60)),
+                Double::ONE,
+                -Double::ONE,
+            );
+        sop_exact:
+            Double::ZERO,
+            Double::sum_of_products(Double::PI, Double::E, Double::E, -Double::PI);
+        sop_ints:
+            dd!(26),
+            Double::sum_of_products(dd!(2), dd!(3), dd!(4), dd!(5));
+        sop_inf:
+            Double::INFINITY,
+            Double::sum_of_products(Double::INFINITY, dd!(3), dd!(4), dd!(5));
+        sop_inf_inf:
+            Double::NAN,
+            Double::sum_of_products(Double::INFINITY, dd!(3), Double::NEG_INFINITY, dd!(5));
+        sop_nan:
+            Double::NAN,
+            Double::sum_of_products(Double::NAN, dd!(3), dd!(4), dd!(5));
+        sop_slice:
+            dd!(32),
+            Double::sum_of_products_slice(&[dd!(1), dd!(2), dd!(3)], &[dd!(4), dd!(5), dd!(6)]);
+        sop_slice_uneven:
+            dd!(14),
+            Double::sum_of_products_slice(&[dd!(1), dd!(2)], &[dd!(4), dd!(5), dd!(6)]);
+        sop_slice_empty:
+            Double::ZERO,
+            Double::sum_of_products_slice(&[], &[]);
+        sop_slice_cancel:
+            Double(2f64.powi(-120), 0.0),
+            Double::sum_of_products_slice(
+                &[Double::ONE, Double(1.0, 2f64.powi(-60)), Double::PI],
+                &[Double::ONE, -Double(1.0, -2f64.powi(-60)), Double::ZERO],
+            );
+        sop_slice_nan:
+            Double::NAN,
+            Double::sum_of_products_slice(&[Double::ONE, Double::NAN], &[Double::ONE; 2]);
+    );
+    test_all_near!(
+        sop_add:
+            dd!("9.519992366142114257177452593181819025472"),
+            Double::sum_of_products(Double::PI, Double::E, Double::LN_2, Double::SQRT_2);
+        sop_sub:
+            dd!("7.559476079205019873749649145911359614695"),
+            Double::sum_of_products(Double::PI, Double::E, -Double::LN_2, Double::SQRT_2);
+    );
+    test!(sop_improvement: {
+        // (1 + 2⁻⁶⁰)(1 - 2⁻⁶⁰) = 1 - 2⁻¹²⁰, which is rounded to 1 when calculated on its own.
+        // The naive sum then gets nothing right, while the fused sum is exact.
+        let a = Double(1.0, 2f64.powi(-60));
+        let b = Double(1.0, -2f64.powi(-60));
+        let expected = -Double(2f64.powi(-120), 0.0);
+
+        let naive = a * b + Double::ONE * -Double::ONE;
+        let fused = Double::sum_of_products(a, b, Double::ONE, -Double::ONE);
+        assert!((fused - expected).abs() < (naive - expected).abs());
+        exact!(expected, fused);
+    });
+
+    // saturating_mul tests
+    test_all_exact!(
+        saturating_mul_max:
+            Double::MAX,
+            Double::MAX.saturating_mul(Double::MAX);
+        saturating_mul_max_two:
+            Double::MAX,
+            Double::MAX.saturating_mul(dd!(2));
+        saturating_mul_min:
+            Double::MAX,
+            Double::MIN.saturating_mul(dd!(-1e10));
+        saturating_mul_neg:
+            Double::MIN,
+            Double::MAX.saturating_mul(dd!(-1.5));
+        saturating_mul_finite:
+            Double::PI * Double::E,
+            Double::PI.saturating_mul(Double::E);
+        saturating_mul_large_finite:
+            Double::MAX / dd!(2) * dd!(2),
+            (Double::MAX / dd!(2)).saturating_mul(dd!(2));
+        saturating_mul_inf:
+            Double::NEG_INFINITY,
+            Double::INFINITY.saturating_mul(dd!(-2));
+        saturating_mul_nan:
+            Double::NAN,
+            Double::MAX.saturating_mul(Double::NAN);
+        saturating_mul_inf_zero:
+            Double::NAN,
+            Double::INFINITY.saturating_mul(Double::ZERO);
+    );
+
+    // lerp tests
+    test_all_exact!(
+        lerp_start:
+            Double::PI,
+            Double::PI.lerp(Double::E * dd!(1e10), Double::ZERO);
+        lerp_end:
+            Double::E * dd!(1e10),
+            Double::PI.lerp(Double::E * dd!(1e10), Double::ONE);
+        lerp_end_small:
+            Double::LN_2,
+            dd!(1e20).lerp(Double::LN_2, Double::ONE);
+        lerp_start_mixed:
+            -Double::PI,
+            (-Double::PI).lerp(Double::E, Double::ZERO);
+        lerp_end_mixed:
+            Double::E,
+            (-Double::PI).lerp(Double::E, Double::ONE);
+        lerp_end_neg:
+            -Double::E,
+            (-Double::PI).lerp(-Double::E, Double::ONE);
+        lerp_mid:
+            dd!(3),
+            dd!(2).lerp(dd!(4), dd!(0.5));
+        lerp_mid_mixed:
+            dd!(1),
+            dd!(-2).lerp(dd!(4), dd!(0.5));
+        lerp_same:
+            Double::PI,
+            Double::PI.lerp(Double::PI, dd!(0.3));
+        lerp_extrapolate:
+            dd!(6),
+            dd!(2).lerp(dd!(4), dd!(2));
+        lerp_extrapolate_back:
+            dd!(0),
+            dd!(2).lerp(dd!(4), dd!(-1));
+        lerp_nan:
+            Double::NAN,
+            dd!(2).lerp(dd!(4), Double::NAN);
+        lerp_nan_start:
+            Double::NAN,
+            Double::NAN.lerp(dd!(4), dd!(0.5));
+        lerp_nan_end:
+            Double::NAN,
+            dd!(2).lerp(Double::NAN, dd!(0.5));
+    );
+    test_all_near!(
+        lerp_third:
+            dd!("2.8593854368359612363944061086616"),
+            Double::E.lerp(Double::PI, dd!(1) / dd!(3));
+    );
+    test!(lerp_monotonic: {
+        for &(a, b) in &[
+            (Double::PI, Double::E * dd!(1e10)),
+            (dd!(1e10), Double::LN_2),
+            (-Double::PI, Double::E),
+            (dd!(-5), dd!(-1e-5)),
+        ] {
+            let mut prev = a;
+            for i in 1..=1000 {
+                let x = a.lerp(b, Double::from(i) / dd!(1000));
+                if b > a {
+                    assert!(x >= prev && x <= b, "lerp({}, {}) is not monotonic at {}", a, b, i);
+                } else {
+                    assert!(x <= prev && x >= b, "lerp({}, {}) is not monotonic at {}", a, b, i);
+                }
+                prev = x;
+            }
+            exact!(b, prev);
+        }
+    });
+
+    // checked_mul tests
+    test_all_eq!(
+        checked_mul_finite:
+            Some(dd!(6)),
+            dd!(2).checked_mul(dd!(3));
+        checked_mul_overflow:
+            None,
+            Double::MAX.checked_mul(dd!(2));
+        checked_mul_neg_overflow:
+            None,
+            Double::MAX.checked_mul(Double::MIN);
+        checked_mul_underflow:
+            Some(Double::ZERO),
+            dd!(1e-200).checked_mul(dd!(1e-200));
+        checked_mul_inf:
+            Some(Double::NEG_INFINITY),
+            Double::INFINITY.checked_mul(dd!(-2));
+    );
+    test_all_assert!(
+        checked_mul_nan:
+            Double::NAN.checked_mul(dd!(1)).unwrap().is_nan();
+        checked_mul_inf_zero:
+            Double::INFINITY.checked_mul(Double::ZERO).unwrap().is_nan();
+    );
+
+    // mul_unchecked tests
+    test_all_exact!(
+        mul_unchecked_num:
+            Double::PI * Double::E,
+            Double::PI.mul_unchecked(Double::E);
+        mul_unchecked_neg:
+            Double::PI * -Double::E,
+            Double::PI.mul_unchecked(-Double::E);
+        mul_unchecked_small:
+            Double::E * dd!(3.7e-200),
+            Double::E.mul_unchecked(dd!(3.7e-200));
+        mul_unchecked_int:
+            dd!(6) * dd!(3),
+            dd!(6).mul_unchecked(dd!(3));
+    );
+
+    // mul_round tests
+    test!(mul_round_inexact: {
+        let x = dd!(1) / dd!(3);
+        let y = dd!(1) / dd!(7);
+        let up = x.mul_round(y, RoundMode::Up);
+        let down = x.mul_round(y, RoundMode::Down);
+        let exact = c::to_quad(x) * c::to_quad(y);
+        assert!(up > down);
+        assert!(c::to_quad(down) < exact && exact < c::to_quad(up));
+        assert!(up - down <= (x * y).eps_at());
+    });
+    test!(mul_round_bracket: {
+        let values = [Double::PI, Double::E, -Double::LN_2, dd!(7), dd!(-1e10), dd!(3e-20)];
+        for &a in &values {
+            for &b in &values {
+                let exact = c::to_quad(a) * c::to_quad(b);
+                let up = c::to_quad(a.mul_round(b, RoundMode::Up));
+                let down = c::to_quad(a.mul_round(b, RoundMode::Down));
+                let zero = c::to_quad(a.mul_round(b, RoundMode::TowardZero));
+                assert!(down <= exact && exact <= up, "{} * {} is not bracketed", a, b);
+                assert!(zero.abs() <= exact.abs(), "{} * {} is not toward zero", a, b);
+            }
+        }
+    });
+    test_all_exact!(
+        mul_round_nearest:
+            Double::PI * Double::E,
+            Double::PI.mul_round(Double::E, RoundMode::Nearest);
+        mul_round_exact_up:
+            dd!(-3.75),
+            dd!(1.5).mul_round(dd!(-2.5), RoundMode::Up);
+        mul_round_exact_down:
+            dd!(-3.75),
+            dd!(1.5).mul_round(dd!(-2.5), RoundMode::Down);
+        mul_round_toward_zero_pos:
+            Double::PI.mul_round(Double::E, RoundMode::Down),
+            Double::PI.mul_round(Double::E, RoundMode::TowardZero);
+        mul_round_toward_zero_neg:
+            (-Double::PI).mul_round(Double::E, RoundMode::Up),
+            (-Double::PI).mul_round(Double::E, RoundMode::TowardZero);
+        mul_round_inf:
+            Double::INFINITY,
+            Double::INFINITY.mul_round(dd!(1), RoundMode::Down);
+    );
+    test!(mul_round_subnormal: {
+        // Products deep in the subnormals are still compared exactly, so they go no
+        // further than the nearest subnormal on either side
+        let x = Double(f64::from_bits(3), 0.0);
+        assert!(x.mul_round(dd!(0.5), RoundMode::Up) == Double(f64::from_bits(2), 0.0));
+        assert!(x.mul_round(dd!(0.5), RoundMode::Down) == Double(f64::from_bits(1), 0.0));
+    });
+    test!(mul_round_underflow: {
+        // The product is positive but rounds to zero, so rounding it toward zero has to go
+        // down rather than following the sign of the zero
+        let x = Double(4.9778520017710715e-144, 1.9366701126721576e-160);
+        let y = Double(1.599735479669982e-210, -1.3062100352300007e-226);
+        let zero = x.mul_round(y, RoundMode::TowardZero);
+        assert!(zero == Double::ZERO && zero.is_sign_positive());
+        assert!(x.mul_round(y, RoundMode::Down) == Double::ZERO);
+        assert!(x.mul_round(y, RoundMode::Up) == Double(f64::from_bits(1), 0.0));
+        assert!((-x).mul_round(y, RoundMode::TowardZero) == Double::ZERO);
+        assert!((-x).mul_round(y, RoundMode::Down) == Double(-f64::from_bits(1), 0.0));
+    });
+    test_all_exact!(
+        mul_round_overflow_down:
+            Double::MAX,
+            Double::MAX.mul_round(dd!(2), RoundMode::Down);
+        mul_round_overflow_up:
+            Double::INFINITY,
+            Double::MAX.mul_round(dd!(2), RoundMode::Up);
+        mul_round_overflow_toward_zero:
+            Double::MIN,
+            Double::MAX.mul_round(dd!(-2), RoundMode::TowardZero);
+    );
+    test_all_assert!(
+        mul_round_nan:
+            Double::NAN.mul_round(dd!(1), RoundMode::Up).is_nan();
+    );
+
+    // integer tests
+    test_all_exact!(
+        int_i32:
+            dd!(6),
+            dd!(3) * 2;
+        int_rev_i32:
+            dd!(6),
+            2 * dd!(3);
+        int_u32:
+            dd!(6),
+            dd!(3) * 2u32;
+        int_rev_u32:
+            dd!(6),
+            2u32 * dd!(3);
+        int_neg:
+            dd!(-6),
+            dd!(3) * -2;
+        int_max:
+            dd!(12884901885.0),
+            dd!(3) * u32::MAX;
+        int_ref:
+            Double::PI * Double::from(2.0),
+            &Double::PI * 2;
+    );
+
     // Assign tests. Assign code delegates to mul code, so there's no need to re-test all
     // of the cases above.
     test_all!(
@@ -313,6 +1187,17 @@ mod tests {
             b *= &Double::E;
             near!(dd!("8.5397342226735670654635508695465707"), b);
         }
+        assign_f64: {
+            let mut c = Double::PI;
+            c *= 1.1;
+            near!(Double::PI * Double(1.1, 0.0), c);
+        }
+        assign_int: {
+            let mut e = Double::PI;
+            e *= 2;
+            e *= 3u32;
+            exact!(Double::PI * 2.0 * 3.0, e);
+        }
     );
 
     test!(chain_tens: {
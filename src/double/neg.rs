@@ -50,6 +50,26 @@ impl Neg for &Double {
     }
 }
 
+impl Double {
+    /// Negates this `Double` in place.
+    ///
+    /// This has the same result as `x = -x`, but it flips the signs of the components
+    /// directly rather than producing a new `Double` and moving it back into place.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let mut x = Double::PI;
+    /// x.negate();
+    /// assert!(x == -Double::PI);
+    /// ```
+    #[inline]
+    pub fn negate(&mut self) {
+        self.0 = -self.0;
+        self.1 = -self.1;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,4 +105,69 @@ mod tests {
             Double::NAN,
             -Double::NAN;
     );
+
+    // negate tests
+    test_all!(
+        negate_pi: {
+            let mut x = Double::PI;
+            x.negate();
+            exact!(-Double::PI, x);
+            x.negate();
+            exact!(Double::PI, x);
+        }
+        negate_zero: {
+            let mut x = Double::ZERO;
+            x.negate();
+            assert!(x.is_zero() && x.is_sign_negative());
+            x.negate();
+            assert!(x.is_zero() && x.is_sign_positive());
+        }
+        negate_inf: {
+            let mut x = Double::INFINITY;
+            x.negate();
+            exact!(Double::NEG_INFINITY, x);
+        }
+        negate_nan: {
+            let mut x = Double::NAN;
+            x.negate();
+            assert!(x.is_nan() && x.is_sign_negative());
+        }
+    );
+
+    // Negation and subtraction from zero must agree. The only difference is in the sign of
+    // a zero result: as with `f64`, 0 - 0 is +0 while -0 is -0. Signs are checked
+    // separately because `==` doesn't distinguish between zeros.
+    test!(neg_sub_consistency: {
+        let values = [
+            Double::PI,
+            -Double::PI,
+            Double::E,
+            -Double::E,
+            Double::MAX,
+            Double::MIN,
+            Double::MIN_POSITIVE,
+            -Double::MIN_POSITIVE,
+            Double::INFINITY,
+            Double::NEG_INFINITY,
+        ];
+        for x in values.iter() {
+            let neg = -*x;
+            let sub = Double::ZERO - *x;
+            exact!(neg, sub);
+            assert_eq!(neg.is_sign_negative(), sub.is_sign_negative());
+        }
+    });
+    test!(neg_sub_zero: {
+        exact!(-Double::NEG_ZERO, Double::ZERO - Double::NEG_ZERO);
+        assert!((-Double::NEG_ZERO).is_sign_positive());
+        assert!((Double::ZERO - Double::NEG_ZERO).is_sign_positive());
+
+        exact!(-Double::ZERO, Double::ZERO - Double::ZERO);
+        assert!((-Double::ZERO).is_sign_negative());
+        assert!((Double::ZERO - Double::ZERO).is_sign_positive());
+    });
+    test!(neg_sub_nan: {
+        assert!((-Double::NAN).is_nan());
+        assert!((Double::ZERO - Double::NAN).is_nan());
+    });
 }
@@ -0,0 +1,1234 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::double::Double;
+use crate::error::ParseDoubleError;
+use crate::geometry;
+use num_traits::{
+    Bounded, Float, FloatConst, FromPrimitive, Inv, MulAdd, MulAddAssign, Num, One, Pow, Signed,
+    ToPrimitive, Zero,
+};
+use std::num::FpCategory;
+
+// 2¹²⁷ and 2¹²⁸, the bounds of the 128-bit integer types
+const TWO_127: f64 = 1.7014118346046923e38;
+const TWO_128: f64 = 3.402823669209385e38;
+
+impl Zero for Double {
+    #[inline]
+    fn zero() -> Double {
+        Double::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        Double::is_zero(*self)
+    }
+}
+
+impl One for Double {
+    #[inline]
+    fn one() -> Double {
+        Double::ONE
+    }
+}
+
+impl Num for Double {
+    type FromStrRadixErr = ParseDoubleError;
+
+    /// Parses a `Double` from a string in the given radix, from 2 to 36.
+    ///
+    /// This is the same as the inherent [`from_str_radix`], so it panics if the radix is
+    /// out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use num_traits::Num;
+    ///
+    /// assert!(<Double as Num>::from_str_radix("2.5", 10) == Ok(dd!(2.5)));
+    /// assert!(<Double as Num>::from_str_radix("ff", 16) == Ok(dd!(255)));
+    /// ```
+    ///
+    /// [`from_str_radix`]: #method.from_str_radix
+    fn from_str_radix(s: &str, radix: u32) -> Result<Double, ParseDoubleError> {
+        Double::from_str_radix(s, radix)
+    }
+}
+
+impl ToPrimitive for Double {
+    /// Converts the `Double` into an `i64`, truncating any fractional part.
+    ///
+    /// `None` is returned if the truncated value is outside the range of an `i64` or if the
+    /// `Double` is `NaN`. Since both components are used, integers that need more than the
+    /// 53 bits of an `f64` convert exactly.
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.to_i128().and_then(|n| n.to_i64())
+    }
+
+    /// Converts the `Double` into a `u64`, truncating any fractional part.
+    ///
+    /// `None` is returned if the truncated value is outside the range of a `u64` or if the
+    /// `Double` is `NaN`.
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.to_u128().and_then(|n| n.to_u64())
+    }
+
+    /// Converts the `Double` into an `i128`, truncating any fractional part.
+    ///
+    /// `None` is returned if the truncated value is outside the range of an `i128` or if the
+    /// `Double` is `NaN`. Integers bigger than 2<sup>106</sup> have their low bits rounded
+    /// away, as they would for any `Double`.
+    fn to_i128(&self) -> Option<i128> {
+        let t = self.trunc();
+        if !(t.0 >= -TWO_127 && t.0 <= TWO_127) {
+            None
+        } else if t.0 == TWO_127 {
+            // The first component rounds up to 2¹²⁷ while the value itself might be just
+            // short of it, in which case the second component is negative
+            if t.1 < 0.0 {
+                Some(i128::MAX + (t.1 as i128 + 1))
+            } else {
+                None
+            }
+        } else if t.0 == -TWO_127 && t.1 < 0.0 {
+            None
+        } else {
+            Some(t.0 as i128 + t.1 as i128)
+        }
+    }
+
+    /// Converts the `Double` into a `u128`, truncating any fractional part.
+    ///
+    /// `None` is returned if the truncated value is outside the range of a `u128` or if the
+    /// `Double` is `NaN`.
+    fn to_u128(&self) -> Option<u128> {
+        let t = self.trunc();
+        if !(t.0 >= 0.0 && t.0 <= TWO_128) {
+            None
+        } else if t.0 == TWO_128 {
+            if t.1 < 0.0 {
+                Some(u128::MAX - (-t.1 as u128 - 1))
+            } else {
+                None
+            }
+        } else if t.0 == 0.0 {
+            Some(0)
+        } else {
+            (t.0 as u128).checked_add_signed(t.1 as i128)
+        }
+    }
+
+    /// Converts the `Double` into an `f64`. This is its first component.
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.0)
+    }
+
+    /// Converts the `Double` into an `f32`. The whole `Double` is correctly rounded, rather than
+    /// just its first component.
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some(f32::from(*self))
+    }
+}
+
+impl FromPrimitive for Double {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Double> {
+        Some(From::from(n))
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Double> {
+        Some(From::from(n))
+    }
+
+    /// Creates a `Double` from an `i128`.
+    ///
+    /// Integers of up to 106 bits are always converted exactly. Others are rounded to the
+    /// nearest `Double` if they can't be represented.
+    #[inline]
+    fn from_i128(n: i128) -> Option<Double> {
+        Some(from_i128(n))
+    }
+
+    /// Creates a `Double` from a `u128`.
+    ///
+    /// Integers of up to 106 bits are always converted exactly. Others are rounded to the
+    /// nearest `Double` if they can't be represented.
+    #[inline]
+    fn from_u128(n: u128) -> Option<Double> {
+        Some(from_u128(n))
+    }
+
+    #[inline]
+    fn from_f32(n: f32) -> Option<Double> {
+        Some(From::from(n))
+    }
+
+    #[inline]
+    fn from_f64(n: f64) -> Option<Double> {
+        Some(From::from(n))
+    }
+}
+
+impl num_traits::NumCast for Double {
+    /// Creates a `Double` from any number that can be converted into a primitive.
+    ///
+    /// Integers that fit into an `i128` or a `u128` are converted as by [`from_i128`] and
+    /// [`from_u128`], so that those of up to 106 bits are exact; other numbers go through
+    /// `f64`. `None` is returned only if the number can't be converted into any of those.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x: Double = num_traits::cast(u64::MAX).unwrap();
+    /// assert!(x == dd!(u64::MAX));
+    /// assert!(x != dd!(u64::MAX as f64));
+    ///
+    /// let y: Double = num_traits::cast(1.5f32).unwrap();
+    /// assert!(y == dd!(1.5));
+    /// ```
+    ///
+    /// [`from_i128`]: #method.from_i128
+    /// [`from_u128`]: #method.from_u128
+    fn from<T: ToPrimitive>(n: T) -> Option<Double> {
+        let f = n.to_f64();
+        // `to_i128` and `to_u128` truncate floats, so they're only used for a number that
+        // turns out to be the same integer when it's converted to `f64`
+        match (n.to_i128(), n.to_u128(), f) {
+            (Some(i), _, Some(f)) if i as f64 == f => Some(from_i128(i)),
+            (_, Some(u), Some(f)) if u as f64 == f => Some(from_u128(u)),
+            (_, _, f) => f.map(From::from),
+        }
+    }
+}
+
+// Converts a 128-bit integer by splitting it into 64-bit halves, each of which converts
+// exactly. Only the final addition rounds.
+fn from_i128(n: i128) -> Double {
+    let hi: Double = From::from((n >> 64) as i64);
+    let lo: Double = From::from(n as u64);
+    hi.ldexp(64) + lo
+}
+
+fn from_u128(n: u128) -> Double {
+    let hi: Double = From::from((n >> 64) as u64);
+    let lo: Double = From::from(n as u64);
+    hi.ldexp(64) + lo
+}
+
+/// This gives `Double` the same interface as `f64` in code that's generic over `Float`.
+///
+/// Nearly all of these methods defer to the `Double` methods of the same names. The rest are
+/// calculated from those, to the full precision of a `Double` except where noted.
+impl Float for Double {
+    #[inline]
+    fn nan() -> Double {
+        Double::NAN
+    }
+
+    #[inline]
+    fn infinity() -> Double {
+        Double::INFINITY
+    }
+
+    #[inline]
+    fn neg_infinity() -> Double {
+        Double::NEG_INFINITY
+    }
+
+    #[inline]
+    fn neg_zero() -> Double {
+        Double::NEG_ZERO
+    }
+
+    #[inline]
+    fn min_value() -> Double {
+        Double::MIN
+    }
+
+    #[inline]
+    fn min_positive_value() -> Double {
+        Double::MIN_POSITIVE
+    }
+
+    #[inline]
+    fn epsilon() -> Double {
+        Double::EPSILON
+    }
+
+    #[inline]
+    fn max_value() -> Double {
+        Double::MAX
+    }
+
+    #[inline]
+    fn is_nan(self) -> bool {
+        self.is_nan()
+    }
+
+    #[inline]
+    fn is_infinite(self) -> bool {
+        self.is_infinite()
+    }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        self.is_finite()
+    }
+
+    #[inline]
+    fn is_normal(self) -> bool {
+        self.is_normal()
+    }
+
+    #[inline]
+    fn is_subnormal(self) -> bool {
+        self.is_subnormal()
+    }
+
+    #[inline]
+    fn classify(self) -> FpCategory {
+        self.classify()
+    }
+
+    #[inline]
+    fn floor(self) -> Double {
+        self.floor()
+    }
+
+    #[inline]
+    fn ceil(self) -> Double {
+        self.ceil()
+    }
+
+    #[inline]
+    fn round(self) -> Double {
+        self.round()
+    }
+
+    #[inline]
+    fn trunc(self) -> Double {
+        self.trunc()
+    }
+
+    #[inline]
+    fn fract(self) -> Double {
+        self.fract()
+    }
+
+    #[inline]
+    fn abs(self) -> Double {
+        self.abs()
+    }
+
+    #[inline]
+    fn signum(self) -> Double {
+        self.signum()
+    }
+
+    #[inline]
+    fn is_sign_positive(self) -> bool {
+        self.is_sign_positive()
+    }
+
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        self.is_sign_negative()
+    }
+
+    #[inline]
+    fn mul_add(self, a: Double, b: Double) -> Double {
+        self.mul_add(a, b)
+    }
+
+    #[inline]
+    fn recip(self) -> Double {
+        self.recip()
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> Double {
+        self.powi(n)
+    }
+
+    #[inline]
+    fn powf(self, n: Double) -> Double {
+        self.powf(n)
+    }
+
+    #[inline]
+    fn sqrt(self) -> Double {
+        self.sqrt()
+    }
+
+    #[inline]
+    fn exp(self) -> Double {
+        self.exp()
+    }
+
+    /// Calculates 2<sup>x</sup>.
+    ///
+    /// The integer part of the exponent is applied exactly, so that only the fractional part
+    /// goes through [`exp`].
+    ///
+    /// [`exp`]: #method.exp
+    fn exp2(self) -> Double {
+        if self.is_nan() {
+            Double::NAN
+        } else if self >= Double(1024.0, 0.0) {
+            Double::INFINITY
+        } else if self < Double(-1075.0, 0.0) {
+            Double::ZERO
+        } else {
+            let n = self.round();
+            let k = n.0 as i32;
+            // Split in two so that neither power of two overflows or underflows on its own
+            ((self - n) * Double::LN_2)
+                .exp()
+                .ldexp(k / 2)
+                .ldexp(k - k / 2)
+        }
+    }
+
+    #[inline]
+    fn ln(self) -> Double {
+        self.ln()
+    }
+
+    #[inline]
+    fn log(self, base: Double) -> Double {
+        self.log(base)
+    }
+
+    #[inline]
+    fn log2(self) -> Double {
+        self.log2()
+    }
+
+    #[inline]
+    fn log10(self) -> Double {
+        self.log10()
+    }
+
+    #[inline]
+    fn to_degrees(self) -> Double {
+        self * Double(180.0, 0.0) / Double::PI
+    }
+
+    #[inline]
+    fn to_radians(self) -> Double {
+        self * Double::PI / Double(180.0, 0.0)
+    }
+
+    /// Returns the larger of the two numbers. If one of them is `NaN`, the other is
+    /// returned.
+    #[inline]
+    fn max(self, other: Double) -> Double {
+        if self.is_nan() || other > self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the smaller of the two numbers. If one of them is `NaN`, the other is
+    /// returned.
+    #[inline]
+    fn min(self, other: Double) -> Double {
+        if self.is_nan() || other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    fn abs_sub(self, other: Double) -> Double {
+        self.abs_sub(other)
+    }
+
+    #[inline]
+    fn cbrt(self) -> Double {
+        self.cbrt()
+    }
+
+    /// Calculates √(x² + y²) without overflow or underflow in the squares.
+    #[inline]
+    fn hypot(self, other: Double) -> Double {
+        geometry::hypot(self, other)
+    }
+
+    #[inline]
+    fn sin(self) -> Double {
+        self.sin()
+    }
+
+    #[inline]
+    fn cos(self) -> Double {
+        self.cos()
+    }
+
+    #[inline]
+    fn tan(self) -> Double {
+        self.tan()
+    }
+
+    #[inline]
+    fn asin(self) -> Double {
+        self.asin()
+    }
+
+    #[inline]
+    fn acos(self) -> Double {
+        self.acos()
+    }
+
+    #[inline]
+    fn atan(self) -> Double {
+        self.atan()
+    }
+
+    #[inline]
+    fn atan2(self, other: Double) -> Double {
+        self.atan2(other)
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Double, Double) {
+        self.sin_cos()
+    }
+
+    /// Calculates e<sup>x</sup> - 1 accurately even when *x* is close to zero.
+    fn exp_m1(self) -> Double {
+        if self.is_zero() {
+            self
+        } else if self.abs() < Double(0.1, 0.0) {
+            // Sum the Taylor series x + x²/2! + x³/3! + ..., which leaves out the 1 that
+            // would otherwise cancel
+            let threshold = (self * Double::EPSILON).abs();
+            let mut s = self;
+            let mut t = self;
+            let mut n = 1.0;
+            while t.abs() > threshold {
+                n += 1.0;
+                t = t * self / Double(n, 0.0);
+                s += t;
+            }
+            s
+        } else {
+            self.exp() - Double::ONE
+        }
+    }
+
+    /// Calculates ln(1 + x) accurately even when *x* is close to zero.
+    fn ln_1p(self) -> Double {
+        if self.is_zero() || self.is_nan() {
+            self
+        } else if self.abs() < Double(0.5, 0.0) {
+            // `ln` is only accurate to within an absolute error, which isn't good enough
+            // near 1. Instead, use Newton's iteration to solve eʸ - 1 = x, starting from the
+            // `f64` approximation; each iteration doubles the number of correct bits.
+            let mut y = Double(self.0.ln_1p(), 0.0);
+            for _ in 0..2 {
+                let e = Float::exp_m1(y);
+                y -= (e - self) / (e + Double::ONE);
+            }
+            y
+        } else {
+            (Double::ONE + self).ln()
+        }
+    }
+
+    #[inline]
+    fn sinh(self) -> Double {
+        self.sinh()
+    }
+
+    #[inline]
+    fn cosh(self) -> Double {
+        self.cosh()
+    }
+
+    #[inline]
+    fn tanh(self) -> Double {
+        self.tanh()
+    }
+
+    #[inline]
+    fn asinh(self) -> Double {
+        self.asinh()
+    }
+
+    #[inline]
+    fn acosh(self) -> Double {
+        self.acosh()
+    }
+
+    #[inline]
+    fn atanh(self) -> Double {
+        self.atanh()
+    }
+
+    /// Returns the mantissa, exponent, and sign of the first component of the `Double`.
+    ///
+    /// The return type has room for only 64 bits of mantissa, so this cannot describe a
+    /// whole `Double`. It gives the same result as it would for the `f64` returned by
+    /// `to_f64`.
+    #[inline]
+    fn integer_decode(self) -> (u64, i16, i8) {
+        Float::integer_decode(self.0)
+    }
+}
+
+/// This makes the `Double` constants available to code that's generic over `FloatConst`.
+///
+/// These are the same values as the associated constants of the same names.
+#[allow(non_snake_case)]
+impl FloatConst for Double {
+    #[inline]
+    fn E() -> Double {
+        Double::E
+    }
+
+    #[inline]
+    fn FRAC_1_PI() -> Double {
+        Double::FRAC_1_PI
+    }
+
+    #[inline]
+    fn FRAC_1_SQRT_2() -> Double {
+        Double::FRAC_1_SQRT_2
+    }
+
+    #[inline]
+    fn FRAC_2_PI() -> Double {
+        Double::FRAC_2_PI
+    }
+
+    #[inline]
+    fn FRAC_2_SQRT_PI() -> Double {
+        Double::FRAC_2_SQRT_PI
+    }
+
+    #[inline]
+    fn FRAC_PI_2() -> Double {
+        Double::FRAC_PI_2
+    }
+
+    #[inline]
+    fn FRAC_PI_3() -> Double {
+        Double::FRAC_PI_3
+    }
+
+    #[inline]
+    fn FRAC_PI_4() -> Double {
+        Double::FRAC_PI_4
+    }
+
+    #[inline]
+    fn FRAC_PI_6() -> Double {
+        Double::FRAC_PI_6
+    }
+
+    #[inline]
+    fn FRAC_PI_8() -> Double {
+        Double::FRAC_PI_8
+    }
+
+    #[inline]
+    fn LN_10() -> Double {
+        Double::LN_10
+    }
+
+    #[inline]
+    fn LN_2() -> Double {
+        Double::LN_2
+    }
+
+    #[inline]
+    fn LOG10_E() -> Double {
+        Double::LOG10_E
+    }
+
+    #[inline]
+    fn LOG2_E() -> Double {
+        Double::LOG2_E
+    }
+
+    #[inline]
+    fn PI() -> Double {
+        Double::PI
+    }
+
+    #[inline]
+    fn SQRT_2() -> Double {
+        Double::SQRT_2
+    }
+
+    #[inline]
+    fn TAU() -> Double {
+        Double::TAU
+    }
+
+    #[inline]
+    fn LOG10_2() -> Double {
+        Double::LOG10_2
+    }
+
+    #[inline]
+    fn LOG2_10() -> Double {
+        Double::LOG2_10
+    }
+}
+
+impl Pow<i32> for Double {
+    type Output = Double;
+
+    /// Raises the `Double` to an integer power. This is the same as [`powi`].
+    ///
+    /// [`powi`]: #method.powi
+    #[inline]
+    fn pow(self, n: i32) -> Double {
+        self.powi(n)
+    }
+}
+
+impl Pow<Double> for Double {
+    type Output = Double;
+
+    /// Raises the `Double` to a `Double` power. This is the same as [`powf`].
+    ///
+    /// [`powf`]: #method.powf
+    #[inline]
+    fn pow(self, n: Double) -> Double {
+        self.powf(n)
+    }
+}
+
+impl Inv for Double {
+    type Output = Double;
+
+    /// Calculates the reciprocal of the `Double`. This is the same as [`recip`].
+    ///
+    /// [`recip`]: #method.recip
+    #[inline]
+    fn inv(self) -> Double {
+        self.recip()
+    }
+}
+
+impl Signed for Double {
+    #[inline]
+    fn abs(&self) -> Double {
+        Double::abs(*self)
+    }
+
+    #[inline]
+    fn abs_sub(&self, other: &Double) -> Double {
+        Double::abs_sub(*self, *other)
+    }
+
+    #[inline]
+    fn signum(&self) -> Double {
+        Double::signum(*self)
+    }
+
+    /// Determines whether the `Double` has a positive sign. As with the `Signed`
+    /// implementation for `f64`, this includes positive zero and positive infinity.
+    #[inline]
+    fn is_positive(&self) -> bool {
+        Double::is_sign_positive(*self)
+    }
+
+    /// Determines whether the `Double` has a negative sign. As with the `Signed`
+    /// implementation for `f64`, this includes negative zero and negative infinity.
+    #[inline]
+    fn is_negative(&self) -> bool {
+        Double::is_sign_negative(*self)
+    }
+}
+
+impl Bounded for Double {
+    #[inline]
+    fn min_value() -> Double {
+        Double::MIN
+    }
+
+    #[inline]
+    fn max_value() -> Double {
+        Double::MAX
+    }
+}
+
+impl MulAdd for Double {
+    type Output = Double;
+
+    /// Calculates `self * a + b` with only one rounding, as [`Float::mul_add`] does.
+    ///
+    /// [`Float::mul_add`]: #method.mul_add
+    #[inline]
+    fn mul_add(self, a: Double, b: Double) -> Double {
+        Float::mul_add(self, a, b)
+    }
+}
+
+impl MulAddAssign for Double {
+    /// Assigns `self * a + b` to the `Double`, calculated as by [`Float::mul_add`].
+    ///
+    /// [`Float::mul_add`]: #method.mul_add
+    #[inline]
+    fn mul_add_assign(&mut self, a: Double, b: Double) {
+        *self = Float::mul_add(*self, a, b);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A generic function of the sort this is meant to support. It would be written as
+    // `x.hypot(y)`, if not for `Double` having inherent methods that take precedence.
+    fn norm<F: Float>(x: F, y: F) -> F {
+        Float::hypot(x, y)
+    }
+
+    fn newton_sqrt<F: Float>(a: F) -> F {
+        let two = F::one() + F::one();
+        let mut x = a;
+        for _ in 0..100 {
+            x = (x + a / x) / two;
+        }
+        x
+    }
+
+    // generic tests
+    test_all_near!(
+        generic_norm:
+            dd!(5),
+            norm(dd!(3), dd!(4));
+        generic_newton_sqrt:
+            Double::SQRT_2,
+            newton_sqrt(dd!(2));
+    );
+
+    // num tests
+    test_all_exact!(
+        num_zero:
+            Double::ZERO,
+            <Double as Zero>::zero();
+        num_one:
+            Double::ONE,
+            <Double as One>::one();
+        num_from_str_radix:
+            dd!(2.5),
+            Double::from_str_radix("2.5", 10).unwrap();
+    );
+    test_all_assert!(
+        num_is_zero:
+            Zero::is_zero(&Double::NEG_ZERO);
+        num_is_not_zero:
+            !Zero::is_zero(&Double::EPSILON);
+        num_from_str_radix_hex:
+            <Double as Num>::from_str_radix("ff", 16) == Ok(dd!(255));
+    );
+
+    // to_primitive tests
+    test_all_eq!(
+        to_i64_trunc:
+            Some(-3),
+            (-Double::PI).to_i64();
+        to_i64_exact:
+            Some(i64::MAX),
+            dd!(i64::MAX).to_i64();
+        to_i64_min:
+            Some(i64::MIN),
+            dd!(i64::MIN).to_i64();
+        to_i64_too_big:
+            None,
+            (dd!(i64::MAX) + Double::ONE).to_i64();
+        to_i64_nan:
+            None,
+            Double::NAN.to_i64();
+        to_i64_inf:
+            None,
+            Double::INFINITY.to_i64();
+        to_u64_exact:
+            Some(u64::MAX),
+            dd!(u64::MAX).to_u64();
+        to_u64_neg:
+            None,
+            dd!(-1).to_u64();
+        to_u64_neg_fract:
+            Some(0),
+            dd!(-0.5).to_u64();
+        to_i128_large:
+            Some((1i128 << 100) + 12345),
+            (dd!(2).powi(100) + dd!(12345)).to_i128();
+        to_i128_below_max:
+            Some(i128::MAX - (1 << 30) + 1),
+            (dd!(2).powi(127) - dd!(2).powi(30)).to_i128();
+        to_i128_max:
+            None,
+            dd!(2).powi(127).to_i128();
+        to_i128_min:
+            Some(i128::MIN),
+            (-dd!(2).powi(127)).to_i128();
+        to_i128_below_min:
+            None,
+            (-dd!(2).powi(127) - Double::ONE).to_i128();
+        to_u128_below_max:
+            Some(u128::MAX - (1 << 30) + 1),
+            (dd!(2).powi(128) - dd!(2).powi(30)).to_u128();
+        to_u128_max:
+            None,
+            dd!(2).powi(128).to_u128();
+        to_f64:
+            Some(std::f64::consts::PI),
+            Double::PI.to_f64();
+        to_f32:
+            Some(std::f32::consts::PI),
+            Double::PI.to_f32();
+    );
+    test_all_assert!(
+        to_f64_nan:
+            Double::NAN.to_f64().unwrap().is_nan();
+    );
+
+    // num_cast tests
+    test_all_exact!(
+        num_cast_i64:
+            dd!(i64::MIN),
+            <Double as num_traits::NumCast>::from(i64::MIN).unwrap();
+        num_cast_u64:
+            dd!(u64::MAX),
+            <Double as num_traits::NumCast>::from(u64::MAX).unwrap();
+        num_cast_f32:
+            dd!(1.5),
+            <Double as num_traits::NumCast>::from(1.5f32).unwrap();
+        num_cast_neg_f64:
+            dd!(-2.75),
+            <Double as num_traits::NumCast>::from(-2.75).unwrap();
+        num_cast_large_f64:
+            dd!(1e300),
+            <Double as num_traits::NumCast>::from(1e300).unwrap();
+        num_cast_inf:
+            Double::INFINITY,
+            <Double as num_traits::NumCast>::from(f64::INFINITY).unwrap();
+        num_cast_nan:
+            Double::NAN,
+            <Double as num_traits::NumCast>::from(f64::NAN).unwrap();
+    );
+
+    // from_primitive tests
+    test_all_exact!(
+        from_i64_min:
+            dd!(i64::MIN),
+            Double::from_i64(i64::MIN).unwrap();
+        from_u64_max:
+            dd!(u64::MAX),
+            Double::from_u64(u64::MAX).unwrap();
+        from_i128_exact:
+            dd!(2).powi(100) + dd!(1),
+            Double::from_i128((1 << 100) + 1).unwrap();
+        from_i128_neg_exact:
+            -(dd!(2).powi(105) + dd!(3)),
+            Double::from_i128(-(1 << 105) - 3).unwrap();
+        from_i128_min:
+            -dd!(2).powi(127),
+            Double::from_i128(i128::MIN).unwrap();
+        from_i128_max:
+            dd!(2).powi(127) - dd!(1),
+            Double::from_i128(i128::MAX).unwrap();
+        from_u128_rounded:
+            dd!(2).powi(120),
+            Double::from_u128((1 << 120) + (1 << 60) + 1).unwrap() - dd!(2).powi(60);
+        from_u128_exact:
+            dd!(2).powi(105) + dd!(2).powi(64) + dd!(5),
+            Double::from_u128((1 << 105) + (1 << 64) + 5).unwrap();
+        from_u128_max:
+            dd!(2).powi(128) - dd!(1),
+            Double::from_u128(u128::MAX).unwrap();
+        from_f32:
+            dd!(-0.375),
+            Double::from_f32(-0.375).unwrap();
+        from_f64:
+            Double::from(std::f64::consts::PI),
+            Double::from_f64(std::f64::consts::PI).unwrap();
+        from_f64_inf:
+            Double::NEG_INFINITY,
+            Double::from_f64(f64::NEG_INFINITY).unwrap();
+        from_f64_nan:
+            Double::NAN,
+            Double::from_f64(f64::NAN).unwrap();
+    );
+    test!(from_i128_round_trip: {
+        for n in [0, 1, -1, 12345678901234567890123456789, -(1 << 105) + 7, i128::MIN, i128::MAX] {
+            assert_eq!(Some(n), Double::from_i128(n).unwrap().to_i128());
+        }
+    });
+
+    // narrow conversion tests
+    test_all_eq!(
+        to_i8:
+            Some(-128),
+            dd!(-128.9).to_i8();
+        to_i8_out_of_range:
+            None,
+            dd!(128).to_i8();
+        to_u8:
+            Some(255),
+            dd!(255.5).to_u8();
+        to_u8_neg:
+            None,
+            dd!(-1).to_u8();
+        to_i32_nan:
+            None,
+            Double::NAN.to_i32();
+        to_u32_inf:
+            None,
+            Double::INFINITY.to_u32();
+        to_usize:
+            Some(1 << 40),
+            dd!(2).powi(40).to_usize();
+        to_f32_large:
+            Some(f32::INFINITY),
+            dd!(1e300).to_f32();
+        num_cast_i128:
+            Some(-(1 << 105) - 3),
+            num_traits::cast::<i128, Double>(-(1 << 105) - 3).and_then(|x| x.to_i128());
+        num_cast_to_i16:
+            Some(-300i16),
+            num_traits::cast::<Double, i16>(dd!(-300.7));
+        num_cast_to_i16_out_of_range:
+            None,
+            num_traits::cast::<Double, i16>(dd!(40000));
+        num_cast_to_u64_nan:
+            None,
+            num_traits::cast::<Double, u64>(Double::NAN);
+    );
+
+    // float tests
+    test_all_exact!(
+        float_nan:
+            Double::NAN,
+            <Double as Float>::nan();
+        float_epsilon:
+            Double::EPSILON,
+            <Double as Float>::epsilon();
+        float_min_value:
+            Double::MIN,
+            <Double as Float>::min_value();
+        float_max_value:
+            Double::MAX,
+            <Double as Float>::max_value();
+        float_max:
+            dd!(2),
+            Float::max(dd!(1), dd!(2));
+        float_max_nan:
+            dd!(1),
+            Float::max(Double::NAN, dd!(1));
+        float_min:
+            dd!(1),
+            Float::min(dd!(1), dd!(2));
+        float_min_nan:
+            dd!(1),
+            Float::min(dd!(1), Double::NAN);
+        float_exp2_int:
+            dd!(1024),
+            Float::exp2(dd!(10));
+        float_exp2_neg_int:
+            dd!(0.125),
+            Float::exp2(dd!(-3));
+        float_exp2_overflow:
+            Double::INFINITY,
+            Float::exp2(dd!(1024));
+        float_exp2_underflow:
+            Double::ZERO,
+            Float::exp2(dd!(-1100));
+        float_exp_m1_zero:
+            Double::ZERO,
+            Float::exp_m1(Double::ZERO);
+        float_ln_1p_zero:
+            Double::ZERO,
+            Float::ln_1p(Double::ZERO);
+        float_ln_1p_tiny:
+            dd!(1e-40),
+            Float::ln_1p(dd!(1e-40));
+        float_ln_1p_neg_one:
+            Double::NEG_INFINITY,
+            Float::ln_1p(dd!(-1));
+        float_mul_add_inf:
+            Double::INFINITY,
+            Float::mul_add(Double::INFINITY, dd!(2), dd!(1));
+    );
+    test_all_near!(
+        float_exp2_fract:
+            Double::SQRT_2,
+            Float::exp2(dd!(0.5));
+        float_exp2_large:
+            dd!(2).powi(1000) * Double::SQRT_2,
+            Float::exp2(dd!(1000.5));
+        float_exp_m1_small:
+            dd!("1.0000000004656612874522995475108919898300261803522513042850246784017").ldexp(-30),
+            Float::exp_m1(Double::ONE.ldexp(-30));
+        float_exp_m1_neg:
+            dd!("-0.06058693718652421388028917537769491547531910945055817799050733794646"),
+            Float::exp_m1(dd!(-0.0625));
+        float_exp_m1_one:
+            Double::E - Double::ONE,
+            Float::exp_m1(dd!(1));
+        float_ln_1p_small:
+            dd!("0.99999999953433871298138132131501945748253833483505987275396388124").ldexp(-30),
+            Float::ln_1p(Double::ONE.ldexp(-30));
+        float_ln_1p_neg:
+            dd!("-0.06453852113757117167292391568399292812890862534975384283537781286190"),
+            Float::ln_1p(dd!(-0.0625));
+        float_ln_1p_one:
+            Double::LN_2,
+            Float::ln_1p(dd!(1));
+        float_to_degrees:
+            dd!(180),
+            Float::to_degrees(Double::PI);
+        float_to_radians:
+            Double::FRAC_PI_2,
+            Float::to_radians(dd!(90));
+        float_hypot:
+            dd!(1e300) * Double::SQRT_2,
+            Float::hypot(dd!(1e300), dd!(1e300));
+    );
+    test!(float_mul_add: {
+        // (1 + ε)(1 - ε) - 1 = -ε², which is lost entirely without the single rounding
+        let e = Double::EPSILON;
+        let x = Float::mul_add(Double::ONE + e, Double::ONE - e, -Double::ONE);
+        exact!(-e * e, x);
+        exact!(Double::ZERO, (Double::ONE + e) * (Double::ONE - e) - Double::ONE);
+    });
+    test!(float_integer_decode: {
+        assert_eq!(
+            Float::integer_decode(Double::PI),
+            Float::integer_decode(std::f64::consts::PI)
+        );
+    });
+
+    // float_const tests
+    test!(float_const: {
+        exact!(Double::E, <Double as FloatConst>::E());
+        exact!(Double::FRAC_1_PI, <Double as FloatConst>::FRAC_1_PI());
+        exact!(Double::FRAC_1_SQRT_2, <Double as FloatConst>::FRAC_1_SQRT_2());
+        exact!(Double::FRAC_2_PI, <Double as FloatConst>::FRAC_2_PI());
+        exact!(Double::FRAC_2_SQRT_PI, <Double as FloatConst>::FRAC_2_SQRT_PI());
+        exact!(Double::FRAC_PI_2, <Double as FloatConst>::FRAC_PI_2());
+        exact!(Double::FRAC_PI_3, <Double as FloatConst>::FRAC_PI_3());
+        exact!(Double::FRAC_PI_4, <Double as FloatConst>::FRAC_PI_4());
+        exact!(Double::FRAC_PI_6, <Double as FloatConst>::FRAC_PI_6());
+        exact!(Double::FRAC_PI_8, <Double as FloatConst>::FRAC_PI_8());
+        exact!(Double::LN_10, <Double as FloatConst>::LN_10());
+        exact!(Double::LN_2, <Double as FloatConst>::LN_2());
+        exact!(Double::LOG10_E, <Double as FloatConst>::LOG10_E());
+        exact!(Double::LOG2_E, <Double as FloatConst>::LOG2_E());
+        exact!(Double::PI, <Double as FloatConst>::PI());
+        exact!(Double::SQRT_2, <Double as FloatConst>::SQRT_2());
+        exact!(Double::TAU, <Double as FloatConst>::TAU());
+        exact!(Double::LOG10_2, <Double as FloatConst>::LOG10_2());
+        exact!(Double::LOG2_10, <Double as FloatConst>::LOG2_10());
+    });
+    test!(float_const_generic: {
+        fn area<F: Float + FloatConst>(r: F) -> F {
+            F::PI() * r * r
+        }
+        exact!(Double::PI * dd!(4), area(dd!(2)));
+    });
+
+    // pow tests
+    test_all_exact!(
+        pow_int:
+            dd!(-8),
+            Pow::pow(dd!(-2), 3);
+        pow_neg_int:
+            dd!(0.25),
+            Pow::pow(dd!(2), -2);
+        pow_dd:
+            dd!(8),
+            Pow::pow(dd!(4), dd!(1.5));
+        pow_nan:
+            Double::NAN,
+            Pow::pow(Double::NAN, 2);
+    );
+
+    // inv tests
+    test_all_exact!(
+        inv_four:
+            dd!(0.25),
+            Inv::inv(dd!(4));
+        inv_zero:
+            Double::INFINITY,
+            Inv::inv(Double::ZERO);
+        inv_neg_zero:
+            Double::NEG_INFINITY,
+            Inv::inv(Double::NEG_ZERO);
+    );
+
+    // signed tests
+    test_all_exact!(
+        signed_abs:
+            Double::PI,
+            Signed::abs(&-Double::PI);
+        signed_abs_sub:
+            dd!(2),
+            Signed::abs_sub(&dd!(5), &dd!(3));
+        signed_abs_sub_less:
+            Double::ZERO,
+            Signed::abs_sub(&dd!(3), &dd!(5));
+        signed_signum:
+            Double::NEG_ONE,
+            Signed::signum(&-Double::E);
+        signed_signum_nan:
+            Double::NAN,
+            Signed::signum(&Double::NAN);
+    );
+    test_all_assert!(
+        signed_is_positive:
+            Signed::is_positive(&Double::ZERO);
+        signed_is_not_positive:
+            !Signed::is_positive(&Double::NEG_ZERO);
+        signed_is_negative:
+            Signed::is_negative(&Double::NEG_INFINITY);
+        signed_is_not_negative:
+            !Signed::is_negative(&Double::PI);
+    );
+
+    // bounded tests
+    test_all_exact!(
+        bounded_min:
+            Double::MIN,
+            <Double as Bounded>::min_value();
+        bounded_max:
+            Double::MAX,
+            <Double as Bounded>::max_value();
+    );
+
+    // mul_add tests
+    test!(mul_add_trait: {
+        fn horner<F: MulAdd<Output = F> + Copy>(x: F, coeffs: &[F]) -> F {
+            let mut r = coeffs[0];
+            for &c in &coeffs[1..] {
+                r = r.mul_add(x, c);
+            }
+            r
+        }
+        // 2x² - 3x + 1 at x = 4
+        exact!(dd!(21), horner(dd!(4), &[dd!(2), dd!(-3), dd!(1)]));
+    });
+    test!(mul_add_assign: {
+        let mut x = dd!(3);
+        MulAddAssign::mul_add_assign(&mut x, dd!(4), dd!(5));
+        exact!(dd!(17), x);
+    });
+}
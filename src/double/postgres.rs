@@ -0,0 +1,185 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::postgres as p;
+use crate::double::Double;
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use std::error::Error;
+
+impl ToSql for Double {
+    /// Writes the `Double` as a Postgres `NUMERIC`, exactly.
+    ///
+    /// Every `Double` has a finite decimal expansion, so the `NUMERIC` has exactly the same
+    /// value, with just as many decimal places as it takes to write it out. That can be a lot
+    /// more than 31, since those digits are what it takes to write the binary fraction
+    /// that the `Double` actually is. `NaN` and the infinities are written as the `NUMERIC`
+    /// special values, which need Postgres 14 or later for the infinities; the sign of a
+    /// zero is lost, as `NUMERIC` has no negative zero.
+    ///
+    /// This is only available when the `postgres` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use bytes::BytesMut;
+    /// use postgres_types::{ToSql, Type};
+    ///
+    /// let mut buf = BytesMut::new();
+    /// dd!(12345.5).to_sql(&Type::NUMERIC, &mut buf).unwrap();
+    /// // 3 digits, weight 1, positive, 1 decimal place, then 1 2345 5000 in base 10000
+    /// assert!(buf[..] == [0, 3, 0, 1, 0, 0, 0, 1, 0, 1, 0x09, 0x29, 0x13, 0x88]);
+    /// ```
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        p::to_numeric(&[self.0, self.1], out);
+        Ok(IsNull::No)
+    }
+
+    accepts!(NUMERIC);
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Double {
+    /// Reads a Postgres `NUMERIC` into a `Double`.
+    ///
+    /// The `NUMERIC` is rounded to the nearest `f64` for the first component, and what's left
+    /// of it is rounded the same way for each of the others. That's exact for any `NUMERIC`
+    /// that was written from a `Double` in the first place, so a `Double` makes the round trip
+    /// through the database without losing anything. One too large in magnitude becomes
+    /// [`INFINITY`] or [`NEG_INFINITY`], and the `NUMERIC` special values become `NaN` and
+    /// the infinities. An error is returned if the data isn't a valid `NUMERIC`.
+    ///
+    /// This is only available when the `postgres` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use postgres_types::{FromSql, Type};
+    ///
+    /// // -1.5, as 2 digits, weight 0, negative, 1 decimal place, then 1 5000
+    /// let raw = [0, 2, 0, 0, 0x40, 0, 0, 1, 0, 1, 0x13, 0x88];
+    /// assert!(Double::from_sql(&Type::NUMERIC, &raw).unwrap() == dd!(-1.5));
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Double, Box<dyn Error + Sync + Send>> {
+        let parts = p::from_numeric(raw, 2)?;
+        Ok(Double(parts[0], parts[1]))
+    }
+
+    accepts!(NUMERIC);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(x: Double) -> Vec<u8> {
+        let mut buf = BytesMut::new();
+        x.to_sql(&Type::NUMERIC, &mut buf).unwrap();
+        buf.to_vec()
+    }
+
+    fn decode(raw: &[u8]) -> Double {
+        Double::from_sql(&Type::NUMERIC, raw).unwrap()
+    }
+
+    // to_sql tests
+    test!(to_sql_integer: {
+        assert!(encode(dd!(1)) == [0, 1, 0, 0, 0, 0, 0, 0, 0, 1]);
+        assert!(encode(dd!(-10000)) == [0, 1, 0, 1, 0x40, 0, 0, 0, 0, 1]);
+        // 2⁷⁰ = 1180591620717411303424
+        assert!(
+            encode(dd!(2).powi(70))
+                == [
+                    0, 6, 0, 5, 0, 0, 0, 0, 0, 11, 0x1f, 0x7b, 0x06, 0x54, 0x1c, 0x06, 0x04, 0x6a,
+                    0x0d, 0x60
+                ]
+        );
+    });
+    test!(to_sql_fraction: {
+        // 12345.5 is 1 2345 5000, with a weight of 1
+        assert!(encode(dd!(12345.5)) == [0, 3, 0, 1, 0, 0, 0, 1, 0, 1, 0x09, 0x29, 0x13, 0x88]);
+        // 0.0625 is 0625, with a weight of -1
+        assert!(encode(dd!(0.0625)) == [0, 1, 0xff, 0xff, 0, 0, 0, 4, 0x02, 0x71]);
+        // 2⁻³⁰ = 0.000000000931322574615478515625, so 0000 0000 0931 3225 7461 5478 5156 25
+        assert!(
+            encode(dd!(2).powi(-30))
+                == [
+                    0, 6, 0xff, 0xfd, 0, 0, 0, 30, 0x03, 0xa3, 0x0c, 0x99, 0x1d, 0x25, 0x15, 0x66,
+                    0x14, 0x24, 0x09, 0xc4
+                ]
+        );
+    });
+    test!(to_sql_exact: {
+        // The f64 closest to 0.1 has 55 decimal places
+        let raw = encode(Double(0.1, 0.0));
+        assert!(raw[6..8] == [0, 55]);
+        assert!(decode(&raw)[0] == 0.1);
+        let third = dd!(1) / dd!(3);
+        assert!(decode(&encode(third)) == third);
+    });
+    test!(to_sql_special: {
+        assert!(encode(Double::ZERO) == [0, 0, 0, 0, 0, 0, 0, 0]);
+        assert!(encode(Double::NEG_ZERO) == [0, 0, 0, 0, 0, 0, 0, 0]);
+        assert!(encode(Double::NAN) == [0, 0, 0, 0, 0xc0, 0, 0, 0]);
+        assert!(encode(Double::INFINITY) == [0, 0, 0, 0, 0xd0, 0, 0, 0]);
+        assert!(encode(Double::NEG_INFINITY) == [0, 0, 0, 0, 0xf0, 0, 0, 0]);
+    });
+    test!(to_sql_type: {
+        let mut buf = BytesMut::new();
+        assert!(dd!(1).to_sql_checked(&Type::NUMERIC, &mut buf).is_ok());
+        assert!(dd!(1).to_sql_checked(&Type::FLOAT8, &mut buf).is_err());
+        assert!(<Double as FromSql>::accepts(&Type::NUMERIC));
+        assert!(!<Double as FromSql>::accepts(&Type::TEXT));
+    });
+
+    // from_sql tests
+    test!(from_sql_values: {
+        assert!(decode(&[0, 1, 0, 0, 0, 0, 0, 0, 0, 1]) == dd!(1));
+        assert!(decode(&[0, 2, 0, 0, 0x40, 0, 0, 1, 0, 1, 0x13, 0x88]) == dd!(-1.5));
+        // 1 with a weight of 2 is 10000²
+        assert!(decode(&[0, 1, 0, 2, 0, 0, 0, 0, 0, 1]) == dd!(100000000));
+        // 1000 with a weight of -1 is 0.1
+        let tenth = decode(&[0, 1, 0xff, 0xff, 0, 0, 0, 1, 0x03, 0xe8]);
+        near!(dd!(1) / dd!(10), tenth);
+    });
+    test!(from_sql_special: {
+        assert!(decode(&[0, 0, 0, 0, 0, 0, 0, 0]) == Double::ZERO);
+        assert!(decode(&[0, 0, 0, 0, 0xc0, 0, 0, 0]).is_nan());
+        assert!(decode(&[0, 0, 0, 0, 0xd0, 0, 0, 0]) == Double::INFINITY);
+        assert!(decode(&[0, 0, 0, 0, 0xf0, 0, 0, 0]) == Double::NEG_INFINITY);
+        // 1 with a weight of 100 is 10⁴⁰⁰
+        assert!(decode(&[0, 1, 0, 100, 0, 0, 0, 0, 0, 1]) == Double::INFINITY);
+        assert!(decode(&[0, 1, 0, 100, 0x40, 0, 0, 0, 0, 1]) == Double::NEG_INFINITY);
+        // 1 with a weight of -100 is 10⁻⁴⁰⁰
+        assert!(decode(&[0, 1, 0xff, 0x9c, 0, 0, 0, 0, 0, 1]) == Double::ZERO);
+        assert!(decode(&[0, 1, 0xff, 0x9c, 0x40, 0, 0, 0, 0, 1]).is_sign_negative());
+    });
+    test!(from_sql_invalid: {
+        let from = |raw: &[u8]| Double::from_sql(&Type::NUMERIC, raw);
+        assert!(from(&[0, 0, 0, 0]).is_err());
+        assert!(from(&[0, 2, 0, 0, 0, 0, 0, 0, 0, 1]).is_err());
+        assert!(from(&[0, 1, 0, 0, 0, 0, 0, 0, 0x27, 0x10]).is_err());
+        assert!(from(&[0, 0, 0, 0, 0x12, 0x34, 0, 0]).is_err());
+    });
+
+    // round trip tests
+    test!(round_trip: {
+        for x in [
+            Double::PI,
+            -Double::E,
+            Double::LN_2 * dd!(1e200),
+            Double::SQRT_2 / dd!(1e200),
+            Double::MAX,
+            Double::from(f64::MIN_POSITIVE),
+            Double(1.0, 2f64.powi(-200)),
+            Double(f64::from_bits(1), 0.0),
+        ] {
+            assert!(decode(&encode(x)) == x);
+        }
+    });
+}
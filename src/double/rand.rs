@@ -0,0 +1,231 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::utils as u;
+use crate::double::Double;
+use rand::distributions::{Distribution, Open01, Standard};
+use rand::Rng;
+
+// 2⁻⁵³ and 2⁻¹⁰⁶, the weights of the random bits in the first and second components
+const FRAC_53: f64 = 1.0 / (1u64 << 53) as f64;
+const FRAC_106: f64 = FRAC_53 * FRAC_53;
+
+impl Distribution<Double> for Standard {
+    /// Generates a random `Double` uniformly distributed over the half-open range [0, 1).
+    ///
+    /// All 106 bits of the mantissa are random, not just the 53 that fit into the first
+    /// component. The result is a multiple of 2⁻¹⁰⁶, each possible one being equally likely.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use rand::Rng;
+    ///
+    /// let x: Double = rand::thread_rng().gen();
+    /// assert!(x >= dd!(0) && x < dd!(1));
+    /// ```
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Double {
+        let hi = bits(rng, 53) * FRAC_53;
+        let lo = bits(rng, 53) * FRAC_106;
+        // The two parts don't overlap, so adding them is exact
+        Double(hi, 0.0) + Double(lo, 0.0)
+    }
+}
+
+impl Distribution<Double> for Open01 {
+    /// Generates a random `Double` uniformly distributed over the open range (0, 1).
+    ///
+    /// As with [`Standard`], all of the bits of the mantissa are random, except that the
+    /// last one is always set to keep the result away from 0. The result is an odd multiple
+    /// of 2⁻¹⁰⁶.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use rand::distributions::Open01;
+    /// use rand::Rng;
+    ///
+    /// let x: Double = rand::thread_rng().sample(Open01);
+    /// assert!(x > dd!(0) && x < dd!(1));
+    /// ```
+    ///
+    /// [`Standard`]: #impl-Distribution%3CDouble%3E-for-Standard
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Double {
+        let hi = bits(rng, 53) * FRAC_53;
+        let lo = (bits(rng, 52) * 2.0 + 1.0) * FRAC_106;
+        Double(hi, 0.0) + Double(lo, 0.0)
+    }
+}
+
+impl Double {
+    /// Converts the `Double` into an `f64` with stochastic rounding, using `rng` as the
+    /// source of randomness.
+    ///
+    /// Rather than always going to the nearest `f64`, as [`From`] does, a `Double` that
+    /// falls between two `f64`s goes to one or the other at random, with each one chosen
+    /// with a probability proportional to how close the `Double` is to it. A `Double` a
+    /// quarter of the way from one `f64` to the next becomes the nearer one three times out
+    /// of four and the farther one the rest of the time. The expected value of the result
+    /// is then the `Double` itself, so rounding errors don't pile up in one direction over a
+    /// long computation. That's what makes this useful for studying how rounding errors
+    /// accumulate, as is often done in machine learning and climate modeling.
+    ///
+    /// A `Double` that's exactly an `f64` always becomes that `f64`. The infinities and
+    /// `NaN` carry over, and a finite `Double` never becomes an infinity.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let mut rng = rand::thread_rng();
+    ///
+    /// let x = Double::ONE + Double::from(f64::EPSILON) / dd!(4);
+    /// let y = x.to_f64_stochastic(&mut rng);
+    /// assert!(y == 1.0 || y == 1.0 + f64::EPSILON);
+    ///
+    /// assert!(dd!(1.5).to_f64_stochastic(&mut rng) == 1.5);
+    /// ```
+    ///
+    /// [`From`]: #impl-From%3CDouble%3E-for-f64
+    pub fn to_f64_stochastic<R: Rng + ?Sized>(self, rng: &mut R) -> f64 {
+        u::round_stochastic(self.0, self.1, bits(rng, 53) * FRAC_53)
+    }
+}
+
+// Generates an integer made up of `n` random bits (n <= 53), returned as an `f64`. This is
+// always exactly representable.
+#[inline]
+fn bits<R: Rng + ?Sized>(rng: &mut R, n: u32) -> f64 {
+    (rng.next_u64() >> (64 - n)) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    // Returns the number of units of 2⁻¹⁰⁶ in the `Double`, which has to be a whole number
+    // less than 2¹⁰⁶
+    fn units(x: Double) -> u128 {
+        let scaled = x.ldexp(106);
+        assert!(scaled.trunc() == scaled, "{:?} does not lie on the grid", x);
+        (scaled.0 as i128 + scaled.1 as i128) as u128
+    }
+
+    // standard tests
+    test!(standard_range: {
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..10_000 {
+            let x: Double = rng.gen();
+            assert!(x >= Double::ZERO && x < Double::ONE);
+            assert!(units(x) < 1 << 106);
+        }
+    });
+    test!(standard_low_bits: {
+        // Each of the 53 bits below the first component should be set about half of the
+        // time; an f64 promoted to a Double would never set any of them
+        let mut rng = StdRng::seed_from_u64(2);
+        let mut counts = [0; 53];
+        for _ in 0..4000 {
+            let n = units(rng.gen());
+            for (i, count) in counts.iter_mut().enumerate() {
+                *count += (n >> i) as u32 & 1;
+            }
+        }
+        for count in counts.iter() {
+            assert!(*count > 1700 && *count < 2300, "bit count {}", count);
+        }
+    });
+    test!(standard_mean: {
+        let mut rng = StdRng::seed_from_u64(3);
+        let sum = (0..10_000).fold(Double::ZERO, |acc, _| acc + rng.gen::<Double>());
+        let mean = sum / dd!(10_000);
+        assert!((mean - dd!(0.5)).abs() < dd!(0.01));
+    });
+
+    // open01 tests
+    test!(open01_range: {
+        let mut rng = StdRng::seed_from_u64(4);
+        for _ in 0..10_000 {
+            let x: Double = rng.sample(Open01);
+            assert!(x > Double::ZERO && x < Double::ONE);
+            assert!(units(x) % 2 == 1);
+        }
+    });
+    test!(open01_low_bits: {
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut counts = [0; 52];
+        for _ in 0..4000 {
+            let n = units(rng.sample(Open01));
+            for (i, count) in counts.iter_mut().enumerate() {
+                *count += (n >> (i + 1)) as u32 & 1;
+            }
+        }
+        for count in counts.iter() {
+            assert!(*count > 1700 && *count < 2300, "bit count {}", count);
+        }
+    });
+
+    // stochastic tests
+    // Returns how many of `n` stochastic roundings of `x` go to `up`, checking that all of the
+    // others go to `down`
+    fn count_up(x: Double, down: f64, up: f64, n: usize, seed: u64) -> usize {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut count = 0;
+        for _ in 0..n {
+            let y = x.to_f64_stochastic(&mut rng);
+            assert!(y == down || y == up, "{} is not {} or {}", y, down, up);
+            count += (y == up) as usize;
+        }
+        count
+    }
+
+    test_all_assert!(
+        stochastic_exact:
+            count_up(dd!(1.5), 1.5, 1.5, 1000, 6) == 1000;
+        stochastic_zero:
+            Double::NEG_ZERO.to_f64_stochastic(&mut StdRng::seed_from_u64(7)).is_sign_negative();
+        stochastic_inf:
+            Double::INFINITY.to_f64_stochastic(&mut StdRng::seed_from_u64(8)) == f64::INFINITY;
+        stochastic_neg_inf:
+            Double::NEG_INFINITY.to_f64_stochastic(&mut StdRng::seed_from_u64(9))
+                == f64::NEG_INFINITY;
+        stochastic_nan:
+            Double::NAN.to_f64_stochastic(&mut StdRng::seed_from_u64(10)).is_nan();
+        stochastic_max:
+            count_up(Double(f64::MAX, f64::MAX * f64::EPSILON / 4.0), f64::MAX, f64::MAX, 1000, 11)
+                == 1000;
+    );
+    test!(stochastic_quarter: {
+        // A quarter of the way from 1 to the next f64 up goes up a quarter of the time
+        let x = Double(1.0, f64::EPSILON / 4.0);
+        let count = count_up(x, 1.0, 1.0 + f64::EPSILON, 20_000, 12);
+        assert!(count > 4700 && count < 5300, "count {}", count);
+    });
+    test!(stochastic_below: {
+        // Three quarters of the way from the next f64 down to 2 goes down a quarter of the
+        // time, and the gap below a power of two is half the size of the one above it
+        let below = 2.0 - f64::EPSILON;
+        let x = Double(2.0, -f64::EPSILON / 4.0);
+        let count = count_up(x, 2.0, below, 20_000, 13);
+        assert!(count > 4700 && count < 5300, "count {}", count);
+    });
+    test!(stochastic_negative: {
+        let x = Double(-1.0, -f64::EPSILON * 0.375);
+        let count = count_up(x, -1.0, -1.0 - f64::EPSILON, 20_000, 14);
+        assert!(count > 7100 && count < 7900, "count {}", count);
+    });
+    test!(stochastic_mean: {
+        // The mean of the rounded numbers is the number itself, not its nearest f64
+        let x = Double::PI;
+        let mut rng = StdRng::seed_from_u64(15);
+        let n = 20_000;
+        let sum = (0..n).fold(Double::ZERO, |acc, _| acc + Double(x.to_f64_stochastic(&mut rng), 0.0));
+        let ulp = Double(f64::EPSILON * 2.0, 0.0);
+        let mean = sum / Double(n as f64, 0.0);
+        assert!((mean - x).abs() < ulp / dd!(20));
+        assert!((mean - Double(x.0, 0.0)).abs() > ulp / dd!(20));
+    });
+}
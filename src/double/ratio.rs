@@ -0,0 +1,199 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::bigint as b;
+use crate::double::from::from_quotient;
+use crate::double::Double;
+use num_bigint::BigInt;
+use num_rational::Ratio;
+
+impl From<Ratio<i64>> for Double {
+    /// Generates a `Double` from a [`Ratio`] of `i64`s.
+    ///
+    /// Both the numerator and the denominator are exactly representable as `Double`s, so the
+    /// only rounding is in the division, which is done with the full precision of a `Double`.
+    ///
+    /// This is only available when the `num-rational` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use num_rational::Ratio;
+    ///
+    /// let x = Double::from(Ratio::new(-22, 7));
+    /// assert!(x == dd!(-22) / dd!(7));
+    /// ```
+    ///
+    /// [`Ratio`]: https://docs.rs/num-rational/0.4/num_rational/struct.Ratio.html
+    fn from(r: Ratio<i64>) -> Double {
+        Double::from(*r.numer()) / Double::from(*r.denom())
+    }
+}
+
+impl From<&Ratio<BigInt>> for Double {
+    /// Generates a `Double` from a reference to a [`Ratio`] of [`BigInt`]s.
+    ///
+    /// The exact quotient is rounded to the nearest `f64`, and what's left of it is
+    /// rounded to the nearest `f64` again to give the next component, and so on. That's the
+    /// nearest `Double` to the ratio. Only the quotient has to be in range, so a ratio converts
+    /// fine even if its numerator or denominator is far too large to be a `Double` on its own.
+    /// A quotient too large in magnitude to be represented becomes [`INFINITY`] or
+    /// [`NEG_INFINITY`].
+    ///
+    /// This is only available when the `num-rational` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use num_bigint::BigInt;
+    /// use num_rational::Ratio;
+    ///
+    /// let r = Ratio::new(BigInt::from(1), BigInt::from(3));
+    /// assert!(Double::from(&r) == dd!(1) / dd!(3));
+    ///
+    /// // Neither 10⁴⁰⁰ nor 3 × 10³⁹⁹ fits into a `Double`
+    /// let r = Ratio::new(BigInt::from(10).pow(400), BigInt::from(3) * BigInt::from(10).pow(399));
+    /// let expected = Ratio::new(BigInt::from(10), BigInt::from(3));
+    /// assert!(Double::from(&r) == Double::from(&expected));
+    /// ```
+    ///
+    /// [`Ratio`]: https://docs.rs/num-rational/0.4/num_rational/struct.Ratio.html
+    /// [`BigInt`]: https://docs.rs/num-bigint/0.4/num_bigint/struct.BigInt.html
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    fn from(r: &Ratio<BigInt>) -> Double {
+        from_quotient(r.numer(), r.denom())
+    }
+}
+
+impl Double {
+    /// Converts the `Double` into a [`Ratio`] of [`BigInt`]s, exactly, or returns `None` if
+    /// the `Double` is `NaN` or infinite.
+    ///
+    /// Every finite `Double` is a sum of `f64`s, each of which is an integer times a power of
+    /// two, so the denominator of the result is always a power of two. Zero becomes 0/1,
+    /// whatever its sign.
+    ///
+    /// This is only available when the `num-rational` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use num_bigint::BigInt;
+    /// use num_rational::Ratio;
+    ///
+    /// let r = dd!(-2.375).to_ratio().unwrap();
+    /// assert!(r == Ratio::new(BigInt::from(-19), BigInt::from(8)));
+    ///
+    /// let pi = Double::PI.to_ratio().unwrap();
+    /// assert!(Double::from(&pi) == Double::PI);
+    /// ```
+    ///
+    /// [`Ratio`]: https://docs.rs/num-rational/0.4/num_rational/struct.Ratio.html
+    /// [`BigInt`]: https://docs.rs/num-bigint/0.4/num_bigint/struct.BigInt.html
+    pub fn to_ratio(self) -> Option<Ratio<BigInt>> {
+        if self.is_finite() {
+            Some(b::to_ratio(&[self.0, self.1]))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pow2(n: u32) -> BigInt {
+        BigInt::from(1) << n
+    }
+
+    fn big(n: &BigInt, d: &BigInt) -> Ratio<BigInt> {
+        Ratio::new(n.clone(), d.clone())
+    }
+
+    // ratio_i64 tests
+    test_all_exact!(
+        ratio_i64_third:
+            dd!(1) / dd!(3),
+            Double::from(Ratio::new(1i64, 3));
+        ratio_i64_neg:
+            dd!(-22) / dd!(7),
+            Double::from(Ratio::new(22i64, -7));
+        ratio_i64_integer:
+            dd!(-12345),
+            Double::from(Ratio::from_integer(-12345i64));
+        ratio_i64_extremes:
+            dd!(i64::MIN) / dd!(i64::MAX),
+            Double::from(Ratio::new(i64::MIN, i64::MAX));
+    );
+
+    // ratio_bigint tests
+    test_all_exact!(
+        ratio_bigint_third:
+            dd!(1) / dd!(3),
+            Double::from(&big(&BigInt::from(1), &BigInt::from(3)));
+        ratio_bigint_pow2:
+            dd!(2),
+            Double::from(&big(&pow2(2000), &pow2(1999)));
+        ratio_bigint_tiny:
+            dd!(2).powi(-1000),
+            Double::from(&big(&BigInt::from(1), &pow2(1000)));
+        ratio_bigint_overflow:
+            Double::INFINITY,
+            Double::from(&big(&pow2(2000), &BigInt::from(3)));
+        ratio_bigint_neg_overflow:
+            Double::NEG_INFINITY,
+            Double::from(&big(&-pow2(2000), &BigInt::from(3)));
+        ratio_bigint_underflow:
+            Double::ZERO,
+            Double::from(&big(&BigInt::from(3), &pow2(2000)));
+    );
+    test!(ratio_bigint_large: {
+        // (10⁴⁰⁰ + 1) / (7 × 10³⁹⁹)
+        let ten = BigInt::from(10).pow(399);
+        let r = big(&(&ten * 10 + 1), &(&ten * 7));
+        near!(dd!(10) / dd!(7), Double::from(&r));
+    });
+    test!(ratio_bigint_nearest: {
+        // Parsing is correctly rounded, so both have to find the same nearest `Double`
+        let r = big(&BigInt::from(1), &BigInt::from(10));
+        assert!(Double::from(&r) == "0.1".parse::<Double>().unwrap());
+        let r = big(&-BigInt::from(3), &BigInt::from(10).pow(40));
+        assert!(Double::from(&r) == "-3e-40".parse::<Double>().unwrap());
+        let r = big(&(pow2(107) - 1), &BigInt::from(1));
+        assert!(Double::from(&r) == Double(2f64.powi(107), -1.0));
+    });
+
+    // to_ratio tests
+    test!(to_ratio_exact: {
+        assert_eq!(dd!(0).to_ratio(), Some(Ratio::from_integer(BigInt::from(0))));
+        assert_eq!(Double::NEG_ZERO.to_ratio(), Some(Ratio::from_integer(BigInt::from(0))));
+        assert_eq!(dd!(-12345).to_ratio(), Some(Ratio::from_integer(BigInt::from(-12345))));
+        assert_eq!(dd!(0.75).to_ratio(), Some(big(&BigInt::from(3), &BigInt::from(4))));
+        assert_eq!(Double(f64::from_bits(1), 0.0).to_ratio(), Some(big(&BigInt::from(1), &pow2(1074))));
+        assert_eq!(dd!(2).powi(1000).to_ratio(), Some(Ratio::from_integer(pow2(1000))));
+    });
+    test!(to_ratio_components: {
+        // Both components contribute, with a denominator from the smaller one
+        let x = Double(2f64.powi(60), -0.5);
+        assert_eq!(x.to_ratio(), Some(big(&(pow2(61) - 1), &BigInt::from(2))));
+        let x = Double(1.5, 2f64.powi(-60));
+        assert_eq!(x.to_ratio(), Some(big(&(BigInt::from(3) * pow2(59) + 1), &pow2(60))));
+    });
+    test!(to_ratio_round_trip: {
+        for x in [Double::PI, -Double::E, Double::LN_2 * dd!(1e200), dd!(1) / dd!(3)] {
+            let r = x.to_ratio().unwrap();
+            near!(x, Double::from(&r));
+        }
+        let x = Double(1.5, 2f64.powi(-60));
+        assert_eq!(Double::from(&x.to_ratio().unwrap()), x);
+    });
+    test!(to_ratio_special: {
+        assert!(Double::NAN.to_ratio().is_none());
+        assert!(Double::INFINITY.to_ratio().is_none());
+        assert!(Double::NEG_INFINITY.to_ratio().is_none());
+    });
+}
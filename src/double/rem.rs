@@ -3,16 +3,27 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::binary128 as b;
 use crate::double::Double;
-use std::ops::{Div, Rem, RemAssign};
+use std::ops::{Rem, RemAssign};
 
 impl Rem for Double {
     type Output = Double;
 
     /// Divides this `Double` by another, producing a new `Double` of the remainder as a
-    /// result. This operation uses floored division.
+    /// result. This uses truncated division, so the remainder has the same sign as the
+    /// dividend.
     ///
-    /// This implements the `%` operator between two `Double`s.
+    /// This implements the `%` operator between two `Double`s. It works the same way that
+    /// `%` does for `f64`s: the remainder is this `Double` minus the divisor times the
+    /// quotient truncated toward zero. It's found by long division, so it's exact however
+    /// large the quotient is, and it's smaller in magnitude than the divisor. The only
+    /// exception is a remainder with too many bits to be a `Double`, which can happen when
+    /// the components of this `Double` or of the divisor are very far apart. That one is
+    /// rounded to the nearest `Double`, which might be the divisor itself. A zero remainder
+    /// has the sign of this `Double`. A finite `Double` divided by an infinity is its own
+    /// remainder, and the remainder is `NaN` if this `Double` is infinite or if the divisor
+    /// is zero.
     ///
     /// # Examples
     /// ```
@@ -24,15 +35,16 @@ impl Rem for Double {
     /// assert!(diffx < dd!(1e-30));
     ///
     /// let y = Double::PI % -Double::E;
-    /// let ypected = dd!("-2.2949710033282972322579315594258");
+    /// let ypected = dd!("0.4233108251307480031023559119268");
     ///
     /// let diffy = (y - ypected).abs();
     /// assert!(diffy < dd!(1e-30));
+    ///
+    /// assert!(dd!(-7.5) % dd!(2) == dd!(-1.5));
+    /// assert!(dd!(7.5) % Double::INFINITY == dd!(7.5));
     /// ```
-    #[inline]
     fn rem(self, other: Double) -> Double {
-        let n = self.div(other).floor();
-        self - other * n
+        self.div_rem_int(other).1
     }
 }
 
@@ -40,7 +52,8 @@ impl Rem for &Double {
     type Output = Double;
 
     /// Divides a reference to this `Double` by another, producing a new `Double` of the
-    /// remainder as a result. This operation uses floored division.
+    /// remainder as a result. This uses truncated division, so the remainder has the same
+    /// sign as the dividend.
     ///
     /// This implements the `%` operator between two references to `Double`s.
     ///
@@ -54,15 +67,14 @@ impl Rem for &Double {
     /// assert!(diffx < dd!(1e-30));
     ///
     /// let y = &Double::PI % -Double::E;
-    /// let ypected = dd!("-2.2949710033282972322579315594258");
+    /// let ypected = dd!("0.4233108251307480031023559119268");
     ///
     /// let diffy = (y - ypected).abs();
     /// assert!(diffy < dd!(1e-30));
     /// ```
     #[inline]
     fn rem(self, other: &Double) -> Double {
-        let n = self.div(*other).floor();
-        *self - *other * n
+        (*self).rem(*other)
     }
 }
 
@@ -70,7 +82,8 @@ impl Rem<&Double> for Double {
     type Output = Double;
 
     /// Divides this `Double` by a reference to another, producing a new `Double` of the
-    /// remainder as a result. This operation uses floored division.
+    /// remainder as a result. This uses truncated division, so the remainder has the same
+    /// sign as the dividend.
     ///
     /// This implements the `%` operator between a `Double` and a reference to a `Double`.
     ///
@@ -84,15 +97,14 @@ impl Rem<&Double> for Double {
     /// assert!(diffx < dd!(1e-30));
     ///
     /// let y = Double::PI % -&Double::E;
-    /// let ypected = dd!("-2.2949710033282972322579315594258");
+    /// let ypected = dd!("0.4233108251307480031023559119268");
     ///
     /// let diffy = (y - ypected).abs();
     /// assert!(diffy < dd!(1e-30));
     /// ```
     #[inline]
     fn rem(self, other: &Double) -> Double {
-        let n = self.div(*other).floor();
-        self - *other * n
+        self.rem(*other)
     }
 }
 
@@ -100,7 +112,8 @@ impl Rem<Double> for &Double {
     type Output = Double;
 
     /// Divides a reference to this `Double` by another `Double`, producing a new `Double`
-    /// of the remainder as a result. This operation uses floored division.
+    /// of the remainder as a result. This uses truncated division, so the remainder has the
+    /// same sign as the dividend.
     ///
     /// This implements the `%` operator between a reference to a `Double`s and a `Double`.
     ///
@@ -114,21 +127,20 @@ impl Rem<Double> for &Double {
     /// assert!(diffx < dd!(1e-30));
     ///
     /// let y = &Double::PI % -&Double::E;
-    /// let ypected = dd!("-2.2949710033282972322579315594258");
+    /// let ypected = dd!("0.4233108251307480031023559119268");
     ///
     /// let diffy = (y - ypected).abs();
     /// assert!(diffy < dd!(1e-30));
     /// ```
     #[inline]
     fn rem(self, other: Double) -> Double {
-        let n = self.div(other).floor();
-        *self - other * n
+        (*self).rem(other)
     }
 }
 
 impl RemAssign for Double {
     /// Divides this `Double` by another, modifying this one to equal the remainder. This
-    /// operation uses floored division.
+    /// uses truncated division, so the remainder has the same sign as the dividend.
     ///
     /// This implements the `%=` operator between two `Double`s.
     ///
@@ -144,7 +156,7 @@ impl RemAssign for Double {
     ///
     /// let mut y = Double::PI;
     /// y %= -Double::E;
-    /// let ypected = dd!("-2.2949710033282972322579315594258");
+    /// let ypected = dd!("0.4233108251307480031023559119268");
     ///
     /// let diffy = (y - ypected).abs();
     /// assert!(diffy < dd!(1e-30));
@@ -159,7 +171,8 @@ impl RemAssign for Double {
 
 impl RemAssign<&Double> for Double {
     /// Divides this `Double` by a reference to another, modifying this one to equal the
-    /// remainder. This operation uses floored division.
+    /// remainder. This uses truncated division, so the remainder has the same sign as the
+    /// dividend.
     ///
     /// This implements the `%=` operator between a `Double` and a reference to a `Double`.
     ///
@@ -175,7 +188,7 @@ impl RemAssign<&Double> for Double {
     ///
     /// let mut y = Double::PI;
     /// y %= -&Double::E;
-    /// let ypected = dd!("-2.2949710033282972322579315594258");
+    /// let ypected = dd!("0.4233108251307480031023559119268");
     ///
     /// let diffy = (y - ypected).abs();
     /// assert!(diffy < dd!(1e-30));
@@ -188,9 +201,228 @@ impl RemAssign<&Double> for Double {
     }
 }
 
+impl Rem<f64> for Double {
+    type Output = Double;
+
+    /// Divides this `Double` by an `f64`, producing a new `Double` of the remainder as a result.
+    /// This uses truncated division, so the remainder has the same sign as the dividend.
+    ///
+    /// This implements the `%` operator between a `Double` and an `f64`. The `f64` is taken at
+    /// its exact binary value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::PI % 2.5;
+    /// let expected = dd!("0.6415926535897932384626433832795");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn rem(self, other: f64) -> Double {
+        self.rem(Double(other, 0.0))
+    }
+}
+
+impl Rem<f64> for &Double {
+    type Output = Double;
+
+    /// Divides a reference to this `Double` by an `f64`, producing a new `Double` of the remainder
+    /// as a result. This uses truncated division, so the remainder has the same sign as the
+    /// dividend.
+    ///
+    /// This implements the `%` operator between a reference to a `Double` and an `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = &Double::PI % 2.5;
+    /// let expected = dd!("0.6415926535897932384626433832795");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn rem(self, other: f64) -> Double {
+        (*self).rem(other)
+    }
+}
+
+impl Rem<Double> for f64 {
+    type Output = Double;
+
+    /// Divides this `f64` by a `Double`, producing a new `Double` of the remainder as a result.
+    /// This uses truncated division, so the remainder has the same sign as the dividend.
+    ///
+    /// This implements the `%` operator between an `f64` and a `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = 4.5 % Double::PI;
+    /// let expected = dd!("1.3584073464102067615373566167205");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn rem(self, other: Double) -> Double {
+        Double(self, 0.0).rem(other)
+    }
+}
+
+impl Rem<&Double> for f64 {
+    type Output = Double;
+
+    /// Divides this `f64` by a reference to a `Double`, producing a new `Double` of the remainder
+    /// as a result. This uses truncated division, so the remainder has the same sign as the
+    /// dividend.
+    ///
+    /// This implements the `%` operator between an `f64` and a reference to a `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = 4.5 % &Double::PI;
+    /// let expected = dd!("1.3584073464102067615373566167205");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn rem(self, other: &Double) -> Double {
+        self.rem(*other)
+    }
+}
+
+impl RemAssign<f64> for Double {
+    /// Divides this `Double` by an `f64`, modifying this one to equal the remainder. This uses
+    /// truncated division, so the remainder has the same sign as the dividend.
+    ///
+    /// This implements the `%=` operator between a `Double` and an `f64`. As with the
+    /// non-assigning operator, the `f64` is taken at its exact binary value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let mut x = Double::PI;
+    /// x %= 2.5;
+    /// let expected = dd!("0.6415926535897932384626433832795");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn rem_assign(&mut self, other: f64) {
+        let r = self.rem(other);
+        self.0 = r.0;
+        self.1 = r.1;
+    }
+}
+
+macro_rules! rem_int_impl {
+    ($($t:ty)*) => ($(
+        impl Rem<$t> for Double {
+            type Output = Double;
+
+            /// Divides this `Double` by an integer, producing a new `Double` of the remainder as
+            /// a result. This uses truncated division, so the remainder has the same sign
+            /// as the dividend.
+            ///
+            /// This implements the `%` operator between a `Double` and an integer.
+            #[inline]
+            fn rem(self, other: $t) -> Double {
+                self.rem(f64::from(other))
+            }
+        }
+
+        impl Rem<$t> for &Double {
+            type Output = Double;
+
+            /// Divides a reference to this `Double` by an integer, producing a new `Double` of
+            /// the remainder as a result.
+            ///
+            /// This implements the `%` operator between a reference to a `Double` and an
+            /// integer.
+            #[inline]
+            fn rem(self, other: $t) -> Double {
+                (*self).rem(f64::from(other))
+            }
+        }
+
+        impl Rem<Double> for $t {
+            type Output = Double;
+
+            /// Divides this integer by a `Double`, producing a new `Double` of the remainder as
+            /// a result.
+            ///
+            /// This implements the `%` operator between an integer and a `Double`.
+            #[inline]
+            fn rem(self, other: Double) -> Double {
+                f64::from(self).rem(other)
+            }
+        }
+
+        impl RemAssign<$t> for Double {
+            /// Divides this `Double` by an integer, modifying this one to equal the
+            /// remainder.
+            ///
+            /// This implements the `%=` operator between a `Double` and an integer.
+            #[inline]
+            fn rem_assign(&mut self, other: $t) {
+                self.rem_assign(f64::from(other));
+            }
+        }
+    )*);
+}
+
+rem_int_impl! { i32 u32 }
+
+impl Double {
+    /// Divides this `Double` by another, returning both the integer quotient, truncated
+    /// toward zero, and the remainder.
+    ///
+    /// This uses truncated division, just like the `%` operator, so the remainder has the
+    /// same sign as this `Double` (or is zero) and is smaller in magnitude than `other`.
+    /// Both are found by exact long division. The remainder is exact whenever it can be
+    /// represented as a `Double`, and the quotient is the nearest `Double` to the exact
+    /// integer quotient. That means that `quotient * other + remainder` is equal to this
+    /// `Double` as long as the quotient has no more than 106 significant bits. A quotient too
+    /// large to be represented at all is infinite.
+    ///
+    /// A finite `Double` divided by an infinity has a quotient of zero (negative if the signs
+    /// differ) and is its own remainder. If `other` is zero, or if this `Double` is infinite
+    /// or either is `NaN`, both the quotient and the remainder are `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// // 3661 seconds is 61 minutes and 1 second
+    /// assert!(dd!(3661).div_rem_int(dd!(60)) == (dd!(61), dd!(1)));
+    /// assert!(dd!(-7.5).div_rem_int(dd!(2)) == (dd!(-3), dd!(-1.5)));
+    /// ```
+    pub fn div_rem_int(self, other: Double) -> (Double, Double) {
+        if self.is_finite() && other.is_infinite() {
+            let q = if self.is_sign_negative() == other.is_sign_negative() {
+                Double::ZERO
+            } else {
+                Double::NEG_ZERO
+            };
+            return (q, self);
+        }
+        if !self.is_finite() || !other.is_finite() || other.is_zero() {
+            return (Double::NAN, Double::NAN);
+        }
+        let (q, r) = b::div_rem(&[self.0, self.1], &[other.0, other.1], 2);
+        (Double(q[0], q[1]), Double(r[0], r[1]))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::double::common as c;
 
     // rem tests
     test_all_near!(
@@ -207,16 +439,16 @@ mod tests {
             dd!("0.42331082513074800310235591192684125"),
             &Double::PI % &Double::E;
         num_neg_num:
-            dd!("-2.2949710033282972322579315594258203"),
+            dd!("0.42331082513074800310235591192684125"),
             Double::PI % -Double::E;
         num_neg_ref:
-            dd!("-2.2949710033282972322579315594258203"),
+            dd!("0.42331082513074800310235591192684125"),
             Double::PI % -&Double::E;
         ref_neg_num:
-            dd!("-2.2949710033282972322579315594258203"),
+            dd!("0.42331082513074800310235591192684125"),
             &Double::PI % -Double::E;
         ref_neg_ref:
-            dd!("-2.2949710033282972322579315594258203"),
+            dd!("0.42331082513074800310235591192684125"),
             &Double::PI % -&Double::E;
         num_id:
             dd!("0.1415926535897932384626433832795028"),
@@ -245,26 +477,26 @@ mod tests {
             Double::NAN,
             Double::INFINITY % Double::ZERO;
         zero_inf:
-            Double::NAN,
+            Double::ZERO,
             Double::ZERO % Double::INFINITY;
         neg_inf_zero:
             Double::NAN,
             Double::NEG_INFINITY % Double::ZERO;
         zero_neg_inf:
-            Double::NAN,
+            Double::ZERO,
             Double::ZERO % Double::NEG_INFINITY;
 
         inf_one:
             Double::NAN,
             Double::INFINITY % Double::ONE;
         one_inf:
-            Double::NAN,
+            Double::ONE,
             Double::ONE % Double::INFINITY;
         neg_inf_one:
             Double::NAN,
             Double::NEG_INFINITY % Double::ONE;
         one_neg_inf:
-            Double::NAN,
+            Double::ONE,
             Double::ONE % Double::NEG_INFINITY;
         inf_inf:
             Double::NAN,
@@ -290,6 +522,122 @@ mod tests {
             Double::ONE % Double::NAN;
     );
 
+    // sign tests
+    test_all_near!(
+        neg_num_num:
+            dd!("-0.42331082513074800310235591192684125"),
+            -Double::PI % Double::E;
+        neg_num_neg_num:
+            dd!("-0.42331082513074800310235591192684125"),
+            -Double::PI % -Double::E;
+    );
+    test_all_exact!(
+        frac_pos_pos:
+            dd!(1.5),
+            dd!(7.5) % dd!(2);
+        frac_neg_pos:
+            dd!(-1.5),
+            dd!(-7.5) % dd!(2);
+        frac_pos_neg:
+            dd!(1.5),
+            dd!(7.5) % dd!(-2);
+        frac_neg_neg:
+            dd!(-1.5),
+            dd!(-7.5) % dd!(-2);
+        smaller_dividend:
+            dd!(-2),
+            dd!(-2) % dd!(3);
+        large_quotient:
+            dd!(2),
+            (dd!(2).powi(100) + dd!(1)) % dd!(3);
+        huge_quotient:
+            Double(-7.442191562079915e-29, 5.812339508585323e-46),
+            Double(-243382.7479728619, -1.4018639747645545e-12)
+                % Double(1.8731174138441228e-28, -7.070159614350036e-45);
+        far_quotient:
+            Double(2.463160253492268e-121, 1.1000239088214007e-137),
+            Double::PI.ldexp(600) % Double::E.ldexp(-400);
+        inf_divisor:
+            dd!(-7.5),
+            dd!(-7.5) % Double::NEG_INFINITY;
+    );
+    test_all_assert!(
+        zero_rem_sign:
+            (dd!(4) % dd!(2)).is_sign_positive();
+        neg_zero_rem_sign:
+            (dd!(-4) % dd!(2)).is_sign_negative();
+        neg_zero_dividend:
+            (Double::NEG_ZERO % dd!(3)).is_sign_negative();
+    );
+    test!(rem_range: {
+        let values = [Double::PI, -Double::E, dd!(1e20) / dd!(3), Double::LN_2, dd!(-12345.678)];
+        for &a in &values {
+            for &b in &values {
+                let r = a % b;
+                assert!(r.abs() < b.abs(), "{} % {} gave {}", a, b, r);
+                assert!(r.is_zero() || r.is_sign_negative() == a.is_sign_negative());
+            }
+        }
+    });
+
+    // f64 tests
+    test_all_exact!(
+        f64_int:
+            dd!(1.5),
+            dd!(7.5) % 2.0;
+        f64_rev_int:
+            dd!(-1.5),
+            -7.5 % dd!(2);
+        f64_inf:
+            dd!(7.5),
+            dd!(7.5) % f64::INFINITY;
+        f64_zero:
+            Double::NAN,
+            Double::PI % 0.0;
+        f64_nan:
+            Double::NAN,
+            Double::ONE % f64::NAN;
+        f64_rev_nan:
+            Double::NAN,
+            f64::NAN % Double::ONE;
+    );
+    test_all_near!(
+        f64_num:
+            Double::PI % Double(1.1, 0.0),
+            Double::PI % 1.1;
+        f64_ref:
+            Double::PI % Double(1.1, 0.0),
+            &Double::PI % 1.1;
+        f64_rev_num:
+            Double(4.5, 0.0) % Double::PI,
+            4.5 % Double::PI;
+        f64_rev_ref:
+            Double(4.5, 0.0) % Double::PI,
+            4.5 % &Double::PI;
+    );
+
+    // integer tests
+    test_all_exact!(
+        int_i32:
+            dd!(1.5),
+            dd!(7.5) % 2;
+        int_neg_i32:
+            dd!(1.5),
+            dd!(7.5) % -2;
+        int_rev_i32:
+            dd!(-1),
+            -7 % dd!(2);
+        int_u32:
+            dd!(1.5),
+            dd!(7.5) % 2u32;
+        int_rev_u32:
+            dd!(1),
+            7u32 % dd!(3);
+        int_ref:
+            dd!(1.5),
+            &dd!(7.5) % 2;
+    );
+
     test_all!(
         assign_num: {
             let mut a = Double::PI;
@@ -301,5 +649,96 @@ mod tests {
             b %= &Double::E;
             near!(dd!("0.42331082513074800310235591192684125"), b);
         }
+        assign_f64: {
+            let mut c = Double::PI;
+            c %= 2.5;
+            near!(Double::PI - dd!(2.5), c);
+        }
+        assign_int: {
+            let mut d = dd!(-7.5);
+            d %= 2;
+            exact!(dd!(-1.5), d);
+            d %= 1u32;
+            exact!(dd!(-0.5), d);
+        }
+    );
+
+    // div_rem_int tests
+    test_all_eq!(
+        div_rem_int_time:
+            (dd!(61), dd!(1)),
+            dd!(3661).div_rem_int(dd!(60));
+        div_rem_int_hours:
+            (dd!(1), dd!(1)),
+            dd!(61).div_rem_int(dd!(60));
+        div_rem_int_fractional:
+            (dd!(3), dd!(1.5)),
+            dd!(7.5).div_rem_int(dd!(2));
+        div_rem_int_neg_dividend:
+            (dd!(-3), dd!(-1.5)),
+            dd!(-7.5).div_rem_int(dd!(2));
+        div_rem_int_neg_divisor:
+            (dd!(-3), dd!(1.5)),
+            dd!(7.5).div_rem_int(dd!(-2));
+        div_rem_int_neg_both:
+            (dd!(3), dd!(-1.5)),
+            dd!(-7.5).div_rem_int(dd!(-2));
+        div_rem_int_exact:
+            (dd!(4), dd!(0)),
+            dd!(12).div_rem_int(dd!(3));
+        div_rem_int_small:
+            (dd!(0), dd!(2)),
+            dd!(2).div_rem_int(dd!(3));
+        div_rem_int_by_inf:
+            (dd!(0), dd!(2)),
+            dd!(2).div_rem_int(Double::INFINITY);
+        div_rem_int_by_neg_inf:
+            (dd!(0), dd!(-2)),
+            dd!(-2).div_rem_int(Double::NEG_INFINITY);
+        div_rem_int_huge_quotient:
+            (
+                Double(-1.299345925535855e33, -4.008641509983555e16),
+                Double(-7.442191562079915e-29, 5.812339508585323e-46)
+            ),
+            Double(-243382.7479728619, -1.4018639747645545e-12)
+                .div_rem_int(Double(1.8731174138441228e-28, -7.070159614350036e-45));
+    );
+    test!(div_rem_int_pi: {
+        let (q, r) = (dd!(100) * Double::PI).div_rem_int(Double::PI);
+        assert!(q == dd!(100) || q == dd!(99));
+        assert!(r >= Double::ZERO && r < Double::PI);
+
+        let (q, r) = Double::PI.div_rem_int(Double::E);
+        exact!(Double::ONE, q);
+        near!(Double::PI - Double::E, r);
+    });
+    test!(div_rem_int_reconstruct: {
+        // The remainder should be exact, so the quotient and remainder together give back
+        // the dividend, as long as the multiplication is done without rounding
+        let values = [Double::PI, -Double::E, dd!(1e20) / dd!(3), Double::LN_2, dd!(-12345.678)];
+        for &a in &values {
+            for &b in &values {
+                let (q, r) = a.div_rem_int(b);
+                exact!(q, q.trunc());
+                assert!(r.abs() < b.abs(), "{} % {} gave {}", a, b, r);
+                assert!(r.is_zero() || r.is_sign_negative() == a.is_sign_negative());
+                let back = c::to_quad(q) * c::to_quad(b) + c::to_quad(r);
+                assert!(back == c::to_quad(a), "{} % {} does not reconstruct", a, b);
+            }
+        }
+    });
+    test_all_assert!(
+        div_rem_int_zero_quotient:
+            dd!(1).div_rem_int(Double::ZERO).0.is_nan();
+        div_rem_int_zero_rem:
+            dd!(1).div_rem_int(Double::ZERO).1.is_nan();
+        div_rem_int_nan:
+            Double::NAN.div_rem_int(dd!(2)).1.is_nan();
+        div_rem_int_inf:
+            Double::INFINITY.div_rem_int(dd!(2)).1.is_nan();
+        div_rem_int_by_inf_nan:
+            Double::NAN.div_rem_int(Double::INFINITY).1.is_nan();
+        div_rem_int_by_inf_zero:
+            dd!(-2).div_rem_int(Double::INFINITY).0.is_sign_negative();
     );
 }
@@ -0,0 +1,196 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::double::Double;
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Unexpected, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+use std::fmt;
+
+impl Serialize for Double {
+    /// Serializes the `Double` in a form that depends on the format.
+    ///
+    /// Human-readable formats (like JSON or YAML) get the decimal string from
+    /// [`to_shortest_string`], which is the shortest one that parses back into exactly the
+    /// same `Double`. Binary formats get the two components as a tuple of two `f64`s, which
+    /// reproduces the `Double` exactly as well.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let json = serde_json::to_string(&dd!(1.5)).unwrap();
+    /// assert!(json == "\"1.5\"");
+    /// ```
+    ///
+    /// [`to_shortest_string`]: #method.to_shortest_string
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_shortest_string())
+        } else {
+            let mut tuple = serializer.serialize_tuple(2)?;
+            tuple.serialize_element(&self.0)?;
+            tuple.serialize_element(&self.1)?;
+            tuple.end()
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Double {
+    /// Deserializes a `Double` from either of the forms that [`serialize`] produces.
+    ///
+    /// Human-readable formats accept any string that `parse` does, as well as a sequence of
+    /// two numbers taken as the components. The string is parsed exactly as `parse` parses
+    /// it, correctly rounded, so a `Double` that was serialized comes back unchanged.
+    /// Binary formats expect the tuple of two `f64`s.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x: Double = serde_json::from_str("\"1.5\"").unwrap();
+    /// assert!(x == dd!(1.5));
+    ///
+    /// let json = serde_json::to_string(&Double::PI).unwrap();
+    /// let pi: Double = serde_json::from_str(&json).unwrap();
+    /// assert!(pi == Double::PI);
+    /// ```
+    ///
+    /// [`serialize`]: #method.serialize
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Double, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(DoubleVisitor)
+        } else {
+            deserializer.deserialize_tuple(2, DoubleVisitor)
+        }
+    }
+}
+
+struct DoubleVisitor;
+
+impl<'de> Visitor<'de> for DoubleVisitor {
+    type Value = Double;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a decimal string or a tuple of two f64 components")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Double, E> {
+        v.parse::<Double>()
+            .map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Double, A::Error> {
+        let a = match seq.next_element()? {
+            Some(a) => a,
+            None => return Err(de::Error::invalid_length(0, &self)),
+        };
+        let b = match seq.next_element()? {
+            Some(b) => b,
+            None => return Err(de::Error::invalid_length(1, &self)),
+        };
+        Ok(Double(a, b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_test::{
+        assert_de_tokens, assert_de_tokens_error, assert_ser_tokens, assert_tokens, Configure,
+        Readable, Token,
+    };
+
+    // readable tests
+    test!(readable_tokens: {
+        assert_tokens(&dd!(1.5).readable(), &[Token::Str("1.5")]);
+        assert_ser_tokens(
+            &Double::PI.readable(),
+            &[Token::Str("3.1415926535897932384626433832795")],
+        );
+        assert_tokens(&Double::INFINITY.readable(), &[Token::Str("inf")]);
+        assert_tokens(&Double::NEG_INFINITY.readable(), &[Token::Str("-inf")]);
+    });
+    test!(readable_lenient: {
+        assert_de_tokens(&dd!(1.5).readable(), &[Token::Str("1.5")]);
+        assert_de_tokens(&dd!(-1234).readable(), &[Token::Str("-1.234e3")]);
+        assert_de_tokens(
+            &Double::PI.readable(),
+            &[
+                Token::Tuple { len: 2 },
+                Token::F64(Double::PI.0),
+                Token::F64(Double::PI.1),
+                Token::TupleEnd,
+            ],
+        );
+    });
+    test!(readable_nan: {
+        let json = serde_json::to_string(&Double::NAN).unwrap();
+        assert!(json == "\"NaN\"");
+        assert!(serde_json::from_str::<Double>(&json).unwrap().is_nan());
+    });
+    test!(readable_neg_zero: {
+        let json = serde_json::to_string(&Double::NEG_ZERO).unwrap();
+        let x: Double = serde_json::from_str(&json).unwrap();
+        assert!(x.is_zero() && x.is_sign_negative());
+    });
+    test!(readable_round_trip: {
+        for x in [Double::PI, -Double::E, Double::LN_2 * dd!(1e200), Double::SQRT_2 / dd!(1e200)] {
+            let json = serde_json::to_string(&x).unwrap();
+            let y: Double = serde_json::from_str(&json).unwrap();
+            assert!(y == x);
+        }
+    });
+    test!(readable_round_trip_random: {
+        let mut x = dd!(1) / dd!(7);
+        for i in 0..2000 {
+            x = x * Double::PI + dd!(i) / dd!(3);
+            x = if i % 2 == 0 { x / dd!(1e50) } else { -x * dd!(1e49) };
+            let json = serde_json::to_string(&x).unwrap();
+            let y: Double = serde_json::from_str(&json).unwrap();
+            assert!(y == x, "{:?} came back as {:?}", x, y);
+        }
+    });
+    test!(readable_round_trip_subnormal_low: {
+        // A low component that's far smaller than usual needs many more digits
+        let x = Double(1.0, 1e-300);
+        let json = serde_json::to_string(&x).unwrap();
+        assert!(serde_json::from_str::<Double>(&json).unwrap() == x);
+    });
+    test!(readable_errors: {
+        assert_de_tokens_error::<Readable<Double>>(
+            &[Token::Str("1.5.1")],
+            "invalid value: string \"1.5.1\", expected a decimal string or a tuple of two f64 \
+             components",
+        );
+        assert_de_tokens_error::<Readable<Double>>(
+            &[Token::Tuple { len: 1 }, Token::F64(1.5), Token::TupleEnd],
+            "invalid length 1, expected a decimal string or a tuple of two f64 components",
+        );
+    });
+
+    // compact tests
+    test!(compact_tokens: {
+        assert_tokens(
+            &dd!(1.5).compact(),
+            &[
+                Token::Tuple { len: 2 },
+                Token::F64(1.5),
+                Token::F64(0.0),
+                Token::TupleEnd,
+            ],
+        );
+    });
+    test!(compact_exact: {
+        for x in [Double::E, Double::MAX, Double::MIN_POSITIVE, dd!(1) / dd!(3)] {
+            assert_tokens(
+                &x.compact(),
+                &[
+                    Token::Tuple { len: 2 },
+                    Token::F64(x.0),
+                    Token::F64(x.1),
+                    Token::TupleEnd,
+                ],
+            );
+        }
+    });
+}
@@ -0,0 +1,324 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::double::common as c;
+use crate::double::Double;
+use crate::quad::Quad;
+
+/// The point at which the Bessel functions switch from the power series to the asymptotic
+/// expansion. Past this the smallest term of the asymptotic expansion is below the precision
+/// of a `Double`; below it the power series converges, though with enough cancellation
+/// that it has to be summed in quad-double precision.
+const BESSEL_THRESHOLD: f64 = 40.0;
+
+impl Double {
+    /// Calculates the Bessel function of the first kind of order zero, J<sub>0</sub>, of the
+    /// `Double`.
+    ///
+    /// For arguments less than 40 in magnitude, this is calculated from the power series
+    ///
+    /// J<sub>0</sub>(x) = Σ (-1)<sup>k</sup> (x²/4)<sup>k</sup> / (k!)²
+    ///
+    /// which alternates with terms large enough to cancel away much of a `Double`'s
+    /// precision, so it is summed internally as a [`Quad`]. Larger arguments use the
+    /// asymptotic expansion for the amplitude and phase of the function.
+    ///
+    /// J<sub>0</sub> is an even function. Its value at an infinity is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::ZERO.bessel_j0() == Double::ONE);
+    ///
+    /// let x = dd!(10).bessel_j0();
+    /// let expected = dd!("-0.2459357644513483351977608624853287");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`Quad`]: struct.Quad.html
+    pub fn bessel_j0(self) -> Double {
+        match self.pre_bessel_j0() {
+            Some(r) => r,
+            None => {
+                let x = self.abs();
+                if x.0 < BESSEL_THRESHOLD {
+                    bessel_series(x, 0)
+                } else {
+                    bessel_asymptotic(x, 0)
+                }
+            }
+        }
+    }
+
+    /// Calculates the Bessel function of the first kind of order one, J<sub>1</sub>, of the
+    /// `Double`.
+    ///
+    /// For arguments less than 40 in magnitude, this is calculated from the power series
+    ///
+    /// J<sub>1</sub>(x) = (x/2) Σ (-1)<sup>k</sup> (x²/4)<sup>k</sup> / (k! (k + 1)!)
+    ///
+    /// which alternates with terms large enough to cancel away much of a `Double`'s
+    /// precision, so it is summed internally as a [`Quad`]. Larger arguments use the
+    /// asymptotic expansion for the amplitude and phase of the function.
+    ///
+    /// J<sub>1</sub> is an odd function. Its value at an infinity is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::ZERO.bessel_j1() == Double::ZERO);
+    ///
+    /// let x = dd!(10).bessel_j1();
+    /// let expected = dd!("0.04347274616886143666974876802585929");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`Quad`]: struct.Quad.html
+    pub fn bessel_j1(self) -> Double {
+        match self.pre_bessel_j1() {
+            Some(r) => r,
+            None => {
+                let x = self.abs();
+                let r = if x.0 < BESSEL_THRESHOLD {
+                    bessel_series(x, 1)
+                } else {
+                    bessel_asymptotic(x, 1)
+                };
+                if self.is_sign_negative() {
+                    -r
+                } else {
+                    r
+                }
+            }
+        }
+    }
+
+    // Precalc functions
+    //
+    // This series of functions returns `Some` with a value that is to be returned, if it
+    // turns out that the function doesn't have to be calculated because a shortcut result
+    // is known. They return `None` if the value has to be calculated normally.
+    //
+    // This keeps the public functions from being mucked up with code that does validation
+    // rather than calculation.
+
+    #[inline]
+    fn pre_bessel_j0(&self) -> Option<Double> {
+        if self.is_nan() {
+            Some(Double::NAN)
+        } else if self.is_infinite() {
+            Some(Double::ZERO)
+        } else if self.is_zero() {
+            Some(Double::ONE)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_bessel_j1(&self) -> Option<Double> {
+        if self.is_nan() {
+            Some(Double::NAN)
+        } else if self.is_infinite() {
+            Some(Double::ZERO)
+        } else if self.is_zero() {
+            Some(*self)
+        } else {
+            None
+        }
+    }
+}
+
+// Sums the power series of the Bessel function of order `n` (0 or 1) at the non-negative
+// `x`. The terms are calculated and summed as `Quad`s, because for `x` near the threshold
+// the largest of them is about 10¹⁵ times the size of the result.
+fn bessel_series(x: Double, n: u32) -> Double {
+    let x = c::to_quad(x);
+    let y = -(x * x) / Quad::from(4);
+
+    let mut t = if n == 0 { Quad::ONE } else { x / Quad::from(2) };
+    let mut s = t;
+    let mut k = 1;
+    while t.abs() > Quad::EPSILON * s.abs() {
+        t = t * y / Quad::from(k * (k + n));
+        s += t;
+        k += 1;
+    }
+    c::from_quad(s)
+}
+
+// Calculates the Bessel function of order `n` (0 or 1) at `x`, which must be at least
+// `BESSEL_THRESHOLD`, from the asymptotic expansion
+//
+//      Jₙ(x) = √(2/πx) (P cos χ - Q sin χ),    χ = x - (2n + 1)π/4
+//
+// The terms of the series P and Q first shrink and then grow without bound, so they're
+// summed only until they either start to grow again or become negligible. Past the
+// threshold, the smallest term is well below the precision of a `Double`. Everything is done
+// as `Quad`s because the phase is sensitive to the error in reducing a large `x`, and
+// because P cos χ and Q sin χ nearly cancel close to each zero of the function.
+fn bessel_asymptotic(x: Double, n: u32) -> Double {
+    let x = c::to_quad(x);
+    let mu = Quad::from(4 * n * n);
+    let x8 = x * Quad::from(8);
+
+    let mut p = Quad::ONE;
+    let mut q = Quad::ZERO;
+    let mut t = Quad::ONE;
+    let mut k = 1;
+    loop {
+        let odd = Quad::from(2 * k - 1);
+        let next = t * (mu - odd * odd) / (Quad::from(k) * x8);
+        if next.abs() < Quad::EPSILON || next.abs() > t.abs() {
+            break;
+        }
+        t = next;
+        // The terms go into Q and P in turn, with alternating signs in each
+        match k % 4 {
+            0 => p += t,
+            1 => q += t,
+            2 => p -= t,
+            _ => q -= t,
+        }
+        k += 1;
+    }
+
+    // With a -π/4 phase shift, cos χ = (cos x + sin x) / √2 and sin χ = (sin x - cos x) / √2.
+    // With a -3π/4 shift, cos χ = (sin x - cos x) / √2 and sin χ = -(sin x + cos x) / √2.
+    // The factors of √2 are folded into the amplitude.
+    let (sin_x, cos_x) = x.sin_cos();
+    let (sin_chi, cos_chi) = if n == 0 {
+        (sin_x - cos_x, cos_x + sin_x)
+    } else {
+        (-(sin_x + cos_x), sin_x - cos_x)
+    };
+    c::from_quad((p * cos_chi - q * sin_chi) / (Quad::PI * x).sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // bessel_j0 tests
+    test_all_near!(
+        bessel_j0_small:
+            dd!("0.99999999997500000000015624999999956597"),
+            dd!(1e-5).bessel_j0();
+        bessel_j0_1:
+            dd!("0.76519768655796655144971752610266322091"),
+            dd!(1).bessel_j0();
+        bessel_j0_10:
+            dd!("-0.24593576445134833519776086248532875383"),
+            dd!(10).bessel_j0();
+        bessel_j0_below_threshold:
+            dd!("0.067268098509753859673255600481308651088"),
+            dd!(39.5).bessel_j0();
+        bessel_j0_threshold:
+            dd!("0.0073668905842372895535317356914380713783"),
+            dd!(40).bessel_j0();
+        bessel_j0_50:
+            dd!("0.055812327669251815004750478529433968177"),
+            dd!(50).bessel_j0();
+        bessel_j0_100:
+            dd!("0.019985850304223122424228390950848990681"),
+            dd!(100).bessel_j0();
+        bessel_j0_1000:
+            dd!("0.024786686152420174561330731115693708786"),
+            dd!(1000).bessel_j0();
+        bessel_j0_neg:
+            dd!("0.76519768655796655144971752610266322091"),
+            dd!(-1).bessel_j0();
+    );
+    test_all_exact!(
+        bessel_j0_zero:
+            Double::ONE,
+            Double::ZERO.bessel_j0();
+        bessel_j0_neg_zero:
+            Double::ONE,
+            Double::NEG_ZERO.bessel_j0();
+        bessel_j0_inf:
+            Double::ZERO,
+            Double::INFINITY.bessel_j0();
+        bessel_j0_neg_inf:
+            Double::ZERO,
+            Double::NEG_INFINITY.bessel_j0();
+        bessel_j0_nan:
+            Double::NAN,
+            Double::NAN.bessel_j0();
+    );
+    test!(bessel_j0_zeros: {
+        for z in [
+            "2.4048255576957727686216318793264546",
+            "5.5200781102863106495966041128130274",
+            "8.6537279129110122169541987126609467",
+            "62.048469190227169882852500264650952",
+        ] {
+            let x = dd!(z).bessel_j0();
+            assert!(x.abs() < dd!(1e-30), "J0({}) = {}", z, x);
+        }
+    });
+
+    // bessel_j1 tests
+    test_all_near!(
+        bessel_j1_small:
+            dd!("4.9999999999375000000002604166666666124e-6"),
+            dd!(1e-5).bessel_j1();
+        bessel_j1_1:
+            dd!("0.44005058574493351595968220371891491313"),
+            dd!(1).bessel_j1();
+        bessel_j1_10:
+            dd!("0.043472746168861436669748768025859288306"),
+            dd!(10).bessel_j1();
+        bessel_j1_below_threshold:
+            dd!("0.10851994640114157979732927056484274338"),
+            dd!(39.5).bessel_j1();
+        bessel_j1_threshold:
+            dd!("0.12603831803758499920560272183917187669"),
+            dd!(40).bessel_j1();
+        bessel_j1_50:
+            dd!("-0.097511828125175137661458953873701614040"),
+            dd!(50).bessel_j1();
+        bessel_j1_100:
+            dd!("-0.077145352014112158032685494927230210212"),
+            dd!(100).bessel_j1();
+        bessel_j1_1000:
+            dd!("0.0047283119070895239175760719012162862854"),
+            dd!(1000).bessel_j1();
+        bessel_j1_neg:
+            dd!("-0.44005058574493351595968220371891491313"),
+            dd!(-1).bessel_j1();
+    );
+    test_all_exact!(
+        bessel_j1_zero:
+            Double::ZERO,
+            Double::ZERO.bessel_j1();
+        bessel_j1_neg_zero:
+            Double::NEG_ZERO,
+            Double::NEG_ZERO.bessel_j1();
+        bessel_j1_inf:
+            Double::ZERO,
+            Double::INFINITY.bessel_j1();
+        bessel_j1_neg_inf:
+            Double::ZERO,
+            Double::NEG_INFINITY.bessel_j1();
+        bessel_j1_nan:
+            Double::NAN,
+            Double::NAN.bessel_j1();
+    );
+    test!(bessel_j1_zeros: {
+        for z in [
+            "3.8317059702075123156144358863081608",
+            "7.0155866698156187535370499814765247",
+            "63.611356698481232631039762417873626",
+        ] {
+            let x = dd!(z).bessel_j1();
+            assert!(x.abs() < dd!(1e-30), "J1({}) = {}", z, x);
+        }
+    });
+}
@@ -0,0 +1,583 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::bignum::{bits, div_small, mul_small, Fixed};
+use crate::common::parse as p;
+use crate::double::common as c;
+use crate::double::Double;
+
+/// The point at which `ln_factorial` switches from taking the logarithm of the exact
+/// factorial to using Stirling's series. Below this the factorial is small enough for `ln`
+/// to be fully accurate; above it Stirling's series converges to full precision within the
+/// terms in `STIRLING`.
+const STIRLING_THRESHOLD: u32 = 100;
+
+/// The largest integer whose factorial can be represented by a `Double`.
+const MAX_FACTORIAL: u32 = 170;
+
+/// ln(2π) / 2, the constant term of Stirling's series.
+const HALF_LN_TAU: Double = Double(9.189385332046728e-1, -3.8782941580672414e-17);
+
+/// Coefficients of the terms of Stirling's series, B<sub>2k</sub> / (2k(2k - 1)), where
+/// B<sub>2k</sub> is the 2k-th Bernoulli number and k is in [1, 10].
+const STIRLING: [Double; 10] = [
+    Double(8.333333333333333e-2, 4.625929269271485e-18),
+    Double(-2.777777777777778e-3, 1.0601087908747154e-19),
+    Double(7.936507936507937e-4, 6.883823317368282e-22),
+    Double(-5.952380952380953e-4, 5.36938218754726e-20),
+    Double(8.417508417508417e-4, 3.6870174889237694e-20),
+    Double(-1.9175269175269176e-3, 1.0675702776872475e-19),
+    Double(6.41025641025641e-3, 2.2240044563805217e-19),
+    Double(-2.9550653594771242e-2, 4.861760957508855e-19),
+    Double(1.7964437236883057e-1, -6.401600482710946e-19),
+    Double(-1.3924322169059011e0, 1.5837056989230303e-17),
+];
+
+impl Double {
+    /// Calculates the factorial of `n`, n! = 1 · 2 · ... · n.
+    ///
+    /// The product is taken with full double-double multiplication, so the result is exact
+    /// for as long as n! fits into the 106-bit mantissa of a `Double` (which, thanks to the
+    /// many factors of 2 in a factorial, is true through n = 30). Past that the result is
+    /// rounded, and past n = 170 it is too large to represent and [`INFINITY`] is returned.
+    /// [`ln_factorial`] can be used when the factorial itself is too large.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::factorial(0) == Double::ONE);
+    /// assert!(Double::factorial(10) == dd!(3_628_800));
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`ln_factorial`]: #method.ln_factorial
+    pub fn factorial(n: u32) -> Double {
+        if n > MAX_FACTORIAL {
+            Double::INFINITY
+        } else {
+            (2..=n).map(Double::from).product()
+        }
+    }
+
+    /// Calculates the natural logarithm of the factorial of `n`, ln(n!).
+    ///
+    /// For small `n`, this is simply the logarithm of [`factorial`]. For larger `n` it is
+    /// calculated with Stirling's series, which means that it remains accurate long after
+    /// n! itself has overflowed.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::ln_factorial(1000);
+    /// let expected = dd!("5912.128178488163348878130886725");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-27));
+    /// ```
+    ///
+    /// [`factorial`]: #method.factorial
+    pub fn ln_factorial(n: u32) -> Double {
+        if n < 2 {
+            Double::ZERO
+        } else if n < STIRLING_THRESHOLD {
+            Double::factorial(n).ln()
+        } else {
+            // Strategy: Stirling's series for the log-gamma function, evaluated at n + 1.
+            //
+            //      ln(n!) = (n + 1/2) ln(n) - n + ln(2π)/2 + Σ B₂ₖ / (2k(2k - 1) n²ᵏ⁻¹)
+            //
+            // The series is asymptotic rather than convergent, but for n >= 100 the terms
+            // fall below the precision of a `Double` well before they start growing again.
+            let x = Double::from(n);
+            let inv = x.recip();
+            let inv2 = inv.sqr();
+
+            let mut p = inv;
+            let mut s = Double::ZERO;
+            for c in STIRLING.iter() {
+                s += *c * p;
+                p *= inv2;
+            }
+
+            (x + Double(0.5, 0.0)) * x.ln() - x + HALF_LN_TAU + s
+        }
+    }
+
+    /// Calculates the binomial coefficient of `n` and `k`, the number of ways to choose `k`
+    /// items from a set of `n`.
+    ///
+    /// The coefficient is built up exactly as an integer, one factor at a time, alternately
+    /// multiplying and dividing so that every intermediate value is itself a binomial
+    /// coefficient. It's only rounded once, at the end, so the result is always the
+    /// `Double` nearest to the true coefficient, and it's exact as long as the coefficient
+    /// fits into the 106-bit mantissa of a `Double`. A coefficient too large for a `Double`
+    /// is infinite. If `k > n`, the result is `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::binomial(5, 2) == dd!(10));
+    /// assert!(Double::binomial(52, 5) == dd!(2_598_960));
+    /// assert!(Double::binomial(2, 5) == Double::ZERO);
+    /// assert!(Double::binomial(2000, 1000) == Double::INFINITY);
+    /// ```
+    pub fn binomial(n: u32, k: u32) -> Double {
+        if k > n {
+            return Double::ZERO;
+        }
+        // C(n, k) = C(n, n - k), and the smaller of the two needs fewer steps
+        let k = k.min(n - k);
+        let base = n - k;
+
+        // Each step turns C(base + i - 1, i - 1) into C(base + i, i), and since `i` is no
+        // more than `base`, those never get smaller. Once one has more bits than the
+        // largest `Double`, the result will too.
+        let mut r = Fixed::pow2(0);
+        for i in 1..=k {
+            mul_small(&mut r, base + i, 0);
+            div_small(&mut r, i);
+            if bits(&r) > 1024 {
+                return Double::INFINITY;
+            }
+        }
+        let len = bits(&r).div_ceil(4);
+        let parts = p::split_hex(r, len, 0, 2);
+        Double(parts[0], parts[1])
+    }
+
+    /// Returns the `n`th Bernoulli number, B<sub>n</sub>, if it's available.
+    ///
+    /// The Bernoulli numbers appear in many series expansions, such as those for `tan`,
+    /// the Euler-Maclaurin formula, and Stirling's series. This function uses the convention
+    /// that B<sub>1</sub> = -1/2. Every other odd-indexed Bernoulli number is zero. Even
+    /// indices are looked up from a precalculated table that goes through B<sub>40</sub>;
+    /// `None` is returned for larger even indices.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::bernoulli(0) == Some(Double::ONE));
+    /// assert!(Double::bernoulli(3) == Some(Double::ZERO));
+    /// assert!(Double::bernoulli(42) == None);
+    ///
+    /// let b2 = Double::bernoulli(2).unwrap();
+    /// assert!((b2 - dd!(1) / dd!(6)).abs() < dd!(1e-32));
+    /// ```
+    pub fn bernoulli(n: usize) -> Option<Double> {
+        if n == 1 {
+            Some(Double(-0.5, 0.0))
+        } else if n % 2 == 1 {
+            Some(Double::ZERO)
+        } else {
+            c::BERNOULLI.get(n / 2).copied()
+        }
+    }
+
+    /// Calculates the mean and the population variance of a slice of `Double`s in a single
+    /// pass.
+    ///
+    /// This uses Welford's algorithm, which updates the mean and the sum of squared
+    /// deviations from it as each value is seen. Unlike calculating the variance as the
+    /// mean of the squares minus the square of the mean, this doesn't suffer from
+    /// catastrophic cancellation when the values are large compared to their spread.
+    ///
+    /// The variance returned is the population variance, the sum of squared deviations
+    /// divided by the number of values. [`mean_sample_variance`] returns the sample variance
+    /// instead. The result for an empty slice is `(NAN, NAN)`, and the variance of a single
+    /// value is `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let data = [2, 4, 4, 4, 5, 5, 7, 9].iter().map(|x| dd!(*x)).collect::<Vec<Double>>();
+    /// let (mean, variance) = Double::mean_variance(&data);
+    /// assert!((mean - dd!(5)).abs() < dd!(1e-30));
+    /// assert!((variance - dd!(4)).abs() < dd!(1e-30));
+    /// ```
+    ///
+    /// [`mean_sample_variance`]: #method.mean_sample_variance
+    pub fn mean_variance(data: &[Double]) -> (Double, Double) {
+        match welford(data) {
+            None => (Double::NAN, Double::NAN),
+            Some((mean, m2)) => (mean, m2 / Double::from(data.len() as u64)),
+        }
+    }
+
+    /// Calculates the mean and the sample variance of a slice of `Double`s in a single
+    /// pass.
+    ///
+    /// This is the same as [`mean_variance`] except that the sum of squared deviations is
+    /// divided by one less than the number of values (Bessel's correction), giving an
+    /// unbiased estimate of the variance of the population that the values were sampled
+    /// from. The result for an empty slice is `(NAN, NAN)`, and the variance of a single
+    /// value is `NAN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let data = [2, 4, 4, 4, 5, 5, 7, 9].iter().map(|x| dd!(*x)).collect::<Vec<Double>>();
+    /// let (mean, variance) = Double::mean_sample_variance(&data);
+    /// assert!((mean - dd!(5)).abs() < dd!(1e-30));
+    /// assert!((variance - dd!(32) / dd!(7)).abs() < dd!(1e-30));
+    /// ```
+    ///
+    /// [`mean_variance`]: #method.mean_variance
+    pub fn mean_sample_variance(data: &[Double]) -> (Double, Double) {
+        match welford(data) {
+            None => (Double::NAN, Double::NAN),
+            Some((mean, _)) if data.len() == 1 => (mean, Double::NAN),
+            Some((mean, m2)) => (mean, m2 / Double::from(data.len() as u64 - 1)),
+        }
+    }
+
+    /// Rescales a slice of `Double`s in place so that its smallest value becomes `0` and its
+    /// largest becomes `1`.
+    ///
+    /// One pass over the data finds the smallest and largest values, and a second maps
+    /// each value `x` to `(x - min) / (max - min)`. The smallest and largest values map to
+    /// exactly `0` and `1`, and the values in between are scaled linearly. If all of the
+    /// values are equal, which would make that a division by zero, they all become `0`
+    /// instead. An empty slice is left alone.
+    ///
+    /// `NaN`s are skipped when finding the extremes and are left as `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let mut data = [dd!(2), dd!(6), dd!(3), dd!(10)];
+    /// Double::normalize_minmax(&mut data);
+    /// assert!(data == [dd!(0), dd!(0.5), dd!(0.125), dd!(1)]);
+    /// ```
+    pub fn normalize_minmax(data: &mut [Double]) {
+        let mut min = Double::NAN;
+        let mut max = Double::NAN;
+        for x in data.iter() {
+            if *x < min || min.is_nan() {
+                min = *x;
+            }
+            if *x > max || max.is_nan() {
+                max = *x;
+            }
+        }
+
+        let range = max - min;
+        for x in data.iter_mut() {
+            if !x.is_nan() {
+                *x = if range.is_zero() {
+                    Double::ZERO
+                } else {
+                    (*x - min) / range
+                };
+            }
+        }
+    }
+}
+
+// Runs Welford's algorithm over `data`, returning the mean and the sum of squared deviations
+// from the mean, or `None` if there's no data.
+fn welford(data: &[Double]) -> Option<(Double, Double)> {
+    if data.is_empty() {
+        return None;
+    }
+    let mut mean = Double::ZERO;
+    let mut m2 = Double::ZERO;
+    for (i, x) in data.iter().enumerate() {
+        let delta = *x - mean;
+        mean += delta / Double::from(i as u64 + 1);
+        m2 += delta * (*x - mean);
+    }
+    Some((mean, m2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // factorial tests
+    test_all_exact!(
+        factorial_zero:
+            Double::ONE,
+            Double::factorial(0);
+        factorial_one:
+            Double::ONE,
+            Double::factorial(1);
+        factorial_ten:
+            dd!(3_628_800),
+            Double::factorial(10);
+        factorial_twenty:
+            dd!(2_432_902_008_176_640_000u64),
+            Double::factorial(20);
+        factorial_thirty:
+            dd!("265252859812191058636308480000000"),
+            Double::factorial(30);
+        factorial_large:
+            Double::INFINITY,
+            Double::factorial(171);
+    );
+    test_all_near!(
+        factorial_fifty:
+            dd!("3.0414093201713378043612608166064768844e64"),
+            Double::factorial(50);
+    );
+
+    // ln_factorial tests
+    test_all_exact!(
+        ln_factorial_zero:
+            Double::ZERO,
+            Double::ln_factorial(0);
+        ln_factorial_one:
+            Double::ZERO,
+            Double::ln_factorial(1);
+    );
+    test_all_near!(
+        ln_factorial_fifty:
+            dd!("148.4777669517730320675371938508795234"),
+            Double::ln_factorial(50);
+        ln_factorial_hundred:
+            dd!("363.7393755555634901440799933696556380"),
+            Double::ln_factorial(100);
+        ln_factorial_thousand:
+            dd!("5912.128178488163348878130886725493882"),
+            Double::ln_factorial(1000);
+    );
+
+    // binomial tests
+    test_all_exact!(
+        binomial_5_2:
+            dd!(10),
+            Double::binomial(5, 2);
+        binomial_52_5:
+            dd!(2_598_960),
+            Double::binomial(52, 5);
+        binomial_n_0:
+            Double::ONE,
+            Double::binomial(52, 0);
+        binomial_n_n:
+            Double::ONE,
+            Double::binomial(52, 52);
+        binomial_0_0:
+            Double::ONE,
+            Double::binomial(0, 0);
+        binomial_k_gt_n:
+            Double::ZERO,
+            Double::binomial(5, 6);
+        binomial_100_50:
+            dd!("100891344545564193334812497256"),
+            Double::binomial(100, 50);
+        binomial_200_100:
+            Double(9.054851465610328e58, 4.849294334462962e41),
+            Double::binomial(200, 100);
+        binomial_1000_500:
+            Double(2.7028824094543655e299, 1.8496308785162572e283),
+            Double::binomial(1000, 500);
+        binomial_near_max:
+            Double(1.429820686498904e308, 9.937791196516122e291),
+            Double::binomial(1029, 514);
+        binomial_overflow:
+            Double::INFINITY,
+            Double::binomial(1030, 515);
+        binomial_overflow_large:
+            Double::INFINITY,
+            Double::binomial(u32::MAX, u32::MAX / 2);
+        binomial_large_n:
+            dd!(u32::MAX),
+            Double::binomial(u32::MAX, u32::MAX - 1);
+    );
+    test!(binomial_pascal: {
+        // Every coefficient that fits into a `u128` has to be the exact value rounded to
+        // the nearest `Double`, which is what converting the `u128` gives. That's all of
+        // them through row 131, many of which have more than 106 bits.
+        let mut row = vec![1u128];
+        for n in 1..=131u32 {
+            let mut next = vec![1u128; row.len() + 1];
+            for k in 1..row.len() {
+                next[k] = row[k - 1] + row[k];
+            }
+            row = next;
+            for (k, &c) in row.iter().enumerate() {
+                let actual = Double::binomial(n, k as u32);
+                assert!(actual == Double::from(c), "C({}, {}) is {}", n, k, actual);
+            }
+        }
+    });
+
+    // bernoulli tests
+    test_all_exact!(
+        bernoulli_0:
+            Double::ONE,
+            Double::bernoulli(0).unwrap();
+        bernoulli_1:
+            dd!(-0.5),
+            Double::bernoulli(1).unwrap();
+        bernoulli_3:
+            Double::ZERO,
+            Double::bernoulli(3).unwrap();
+        bernoulli_41:
+            Double::ZERO,
+            Double::bernoulli(41).unwrap();
+    );
+    test_all_near!(
+        bernoulli_2:
+            dd!(1) / dd!(6),
+            Double::bernoulli(2).unwrap();
+        bernoulli_4:
+            dd!(-1) / dd!(30),
+            Double::bernoulli(4).unwrap();
+        bernoulli_6:
+            dd!(1) / dd!(42),
+            Double::bernoulli(6).unwrap();
+        bernoulli_20:
+            dd!(-174_611) / dd!(330),
+            Double::bernoulli(20).unwrap();
+        bernoulli_40:
+            dd!("-261082718496449122051") / dd!(13_530),
+            Double::bernoulli(40).unwrap();
+    );
+    test_all_assert!(
+        bernoulli_42:
+            Double::bernoulli(42).is_none();
+        bernoulli_large:
+            Double::bernoulli(usize::MAX - 1).is_none();
+        bernoulli_large_odd:
+            Double::bernoulli(usize::MAX) == Some(Double::ZERO);
+    );
+    test!(bernoulli_stirling: {
+        // The Stirling's series coefficients are B₂ₖ / (2k(2k - 1))
+        for (k, coeff) in STIRLING.iter().enumerate() {
+            let k = k + 1;
+            let b = Double::bernoulli(2 * k).unwrap();
+            near!(b / Double::from((2 * k * (2 * k - 1)) as u32), *coeff);
+        }
+    });
+
+    // mean_variance tests
+    fn dataset() -> Vec<Double> {
+        [2, 4, 4, 4, 5, 5, 7, 9].iter().map(|x| dd!(*x)).collect()
+    }
+
+    test_all_exact!(
+        mean_variance_variance:
+            dd!(4),
+            Double::mean_variance(&dataset()).1;
+        mean_variance_single_mean:
+            Double::PI,
+            Double::mean_variance(&[Double::PI]).0;
+        mean_variance_single_variance:
+            Double::ZERO,
+            Double::mean_variance(&[Double::PI]).1;
+        mean_variance_empty_mean:
+            Double::NAN,
+            Double::mean_variance(&[]).0;
+        mean_variance_empty_variance:
+            Double::NAN,
+            Double::mean_variance(&[]).1;
+        mean_variance_nan:
+            Double::NAN,
+            Double::mean_variance(&[Double::ONE, Double::NAN]).1;
+    );
+    test_all_near!(
+        mean_variance_mean:
+            dd!(5),
+            Double::mean_variance(&dataset()).0;
+        mean_variance_fractions_mean:
+            dd!(0.3),
+            Double::mean_variance(&[dd!(0.1), dd!(0.2), dd!(0.3), dd!(0.4), dd!(0.5)]).0;
+        mean_variance_fractions_variance:
+            dd!(0.02),
+            Double::mean_variance(&[dd!(0.1), dd!(0.2), dd!(0.3), dd!(0.4), dd!(0.5)]).1;
+    );
+    test!(mean_variance_two_pass: {
+        // Compare against the mean and variance calculated the long way
+        let data: Vec<Double> = (1..=50).map(|i| dd!(i).sqrt() * Double::PI).collect();
+        let n = Double::from(data.len() as u64);
+        let mean: Double = data.iter().sum::<Double>() / n;
+        let variance: Double = data.iter().map(|x| (*x - mean).sqr()).sum::<Double>() / n;
+
+        let (m, v) = Double::mean_variance(&data);
+        near!(mean, m);
+        near!(variance, v);
+    });
+    test!(mean_variance_offset: {
+        // With a large offset, the squares of the values lose all of the digits that would
+        // be needed to find the variance as the mean of the squares minus the square of the
+        // mean
+        let offset = dd!(1e20);
+        let data: Vec<Double> = [4, 7, 13, 16].iter().map(|x| offset + dd!(*x)).collect();
+        let (mean, variance) = Double::mean_variance(&data);
+        exact!(offset + dd!(10), mean);
+        exact!(dd!(22.5), variance);
+
+        let n = Double::from(data.len() as u64);
+        let mean_sq = data.iter().map(|x| x.sqr()).sum::<Double>() / n;
+        let naive = mean_sq - (data.iter().sum::<Double>() / n).sqr();
+        assert!((naive - dd!(22.5)).abs() > Double::ONE);
+    });
+
+    // mean_sample_variance tests
+    test_all_exact!(
+        mean_sample_variance_single_mean:
+            Double::PI,
+            Double::mean_sample_variance(&[Double::PI]).0;
+        mean_sample_variance_single_variance:
+            Double::NAN,
+            Double::mean_sample_variance(&[Double::PI]).1;
+        mean_sample_variance_empty_mean:
+            Double::NAN,
+            Double::mean_sample_variance(&[]).0;
+        mean_sample_variance_empty_variance:
+            Double::NAN,
+            Double::mean_sample_variance(&[]).1;
+    );
+    test_all_near!(
+        mean_sample_variance_mean:
+            dd!(5),
+            Double::mean_sample_variance(&dataset()).0;
+        mean_sample_variance_variance:
+            dd!(32) / dd!(7),
+            Double::mean_sample_variance(&dataset()).1;
+        mean_sample_variance_pair:
+            dd!(0.5),
+            Double::mean_sample_variance(&[dd!(1), dd!(2)]).1;
+    );
+    // normalize_minmax tests
+    test!(normalize_minmax_basic: {
+        let mut data = [dd!(2), dd!(6), dd!(3), dd!(10), dd!(-2)];
+        Double::normalize_minmax(&mut data);
+        exact!(dd!(1) / dd!(3), data[0]);
+        exact!(dd!(2) / dd!(3), data[1]);
+        exact!(dd!(5) / dd!(12), data[2]);
+        exact!(Double::ONE, data[3]);
+        exact!(Double::ZERO, data[4]);
+    });
+    test!(normalize_minmax_inexact: {
+        let mut data = [Double::PI, Double::E, Double::LN_2, Double::PI * dd!(1e10), Double::LN_10];
+        let original = data;
+        Double::normalize_minmax(&mut data);
+        exact!(Double::ZERO, data[2]);
+        exact!(Double::ONE, data[3]);
+        let (min, max) = (original[2], original[3]);
+        for i in [0, 1, 4] {
+            near!((original[i] - min) / (max - min), data[i]);
+            assert!(data[i] > Double::ZERO && data[i] < Double::ONE);
+        }
+    });
+    test!(normalize_minmax_equal: {
+        let mut data = [Double::PI, Double::PI, Double::PI];
+        Double::normalize_minmax(&mut data);
+        assert!(data == [Double::ZERO; 3]);
+    });
+    test!(normalize_minmax_nan: {
+        let mut data = [dd!(1), Double::NAN, dd!(5)];
+        Double::normalize_minmax(&mut data);
+        exact!(Double::ZERO, data[0]);
+        assert!(data[1].is_nan());
+        exact!(Double::ONE, data[2]);
+    });
+    test!(normalize_minmax_empty: {
+        let mut data: [Double; 0] = [];
+        Double::normalize_minmax(&mut data);
+        assert!(data.is_empty());
+    });
+}
@@ -3,9 +3,12 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::binary128 as b;
 use crate::common::primitive as p;
 use crate::common::utils as u;
+use crate::double::common as c;
 use crate::double::Double;
+use crate::round::RoundMode;
 use std::ops::{Sub, SubAssign};
 
 #[allow(clippy::suspicious_arithmetic_impl)]
@@ -157,6 +160,322 @@ impl SubAssign<&Double> for Double {
     }
 }
 
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Sub<f64> for Double {
+    type Output = Double;
+
+    /// Subtracts an `f64` from this `Double`, producing a new `Double` as a result.
+    ///
+    /// This implements the `-` operator between a `Double` and an `f64`.
+    ///
+    /// The `f64` is taken at its exact binary value, as with `Double::from((x, 0.0))`, and
+    /// is worked directly into the components rather than first being promoted to a
+    /// `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::PI - 1.5;
+    /// let expected = dd!("1.641592653589793238462643383280");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    fn sub(self, other: f64) -> Double {
+        match self.pre_sub(&Double(other, 0.0)) {
+            Some(r) => r,
+            None => {
+                let (s, e) = p::two_diff(self.0, other);
+                let (a, b) = u::renorm2(s, e + self.1);
+                Double(a, b)
+            }
+        }
+    }
+}
+
+impl Sub<f64> for &Double {
+    type Output = Double;
+
+    /// Subtracts an `f64` from a reference to this `Double`, producing a new `Double` as a result.
+    ///
+    /// This implements the `-` operator between a reference to a `Double` and an `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = &Double::PI - 1.5;
+    /// let expected = dd!("1.641592653589793238462643383280");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn sub(self, other: f64) -> Double {
+        (*self).sub(other)
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Sub<Double> for f64 {
+    type Output = Double;
+
+    /// Subtracts a `Double` from this `f64`, producing a new `Double` as a result.
+    ///
+    /// This implements the `-` operator between an `f64` and a `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = 1.5 - Double::PI;
+    /// let expected = dd!("-1.641592653589793238462643383280");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    fn sub(self, other: Double) -> Double {
+        match Double(self, 0.0).pre_sub(&other) {
+            Some(r) => r,
+            None => {
+                let (s, e) = p::two_diff(self, other.0);
+                let (a, b) = u::renorm2(s, e - other.1);
+                Double(a, b)
+            }
+        }
+    }
+}
+
+impl Sub<&Double> for f64 {
+    type Output = Double;
+
+    /// Subtracts a reference to a `Double` from this `f64`, producing a new `Double` as a result.
+    ///
+    /// This implements the `-` operator between an `f64` and a reference to a `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = 1.5 - &Double::PI;
+    /// let expected = dd!("-1.641592653589793238462643383280");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn sub(self, other: &Double) -> Double {
+        self.sub(*other)
+    }
+}
+
+impl SubAssign<f64> for Double {
+    /// Subtracts an `f64` from this `Double`, modifying this one to equal the result.
+    ///
+    /// This implements the `-=` operator between a `Double` and an `f64`. As with the
+    /// non-assigning operator, the `f64` is taken at its exact binary value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let mut x = Double::PI;
+    /// x -= 1.5;
+    /// let expected = dd!("1.641592653589793238462643383280");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: f64) {
+        let r = self.sub(other);
+        self.0 = r.0;
+        self.1 = r.1;
+    }
+}
+
+macro_rules! sub_int_impl {
+    ($($t:ty)*) => ($(
+        impl Sub<$t> for Double {
+            type Output = Double;
+
+            /// Subtracts an integer from this `Double`, producing a new `Double` as a
+            /// result.
+            ///
+            /// This implements the `-` operator between a `Double` and an integer. Every
+            /// integer of this type converts exactly to an `f64`, so this takes the same
+            /// fast path as the `f64` operator.
+            #[inline]
+            fn sub(self, other: $t) -> Double {
+                self.sub(f64::from(other))
+            }
+        }
+
+        impl Sub<$t> for &Double {
+            type Output = Double;
+
+            /// Subtracts an integer from a reference to this `Double`, producing a new
+            /// `Double` as a result.
+            ///
+            /// This implements the `-` operator between a reference to a `Double` and an
+            /// integer.
+            #[inline]
+            fn sub(self, other: $t) -> Double {
+                (*self).sub(f64::from(other))
+            }
+        }
+
+        impl Sub<Double> for $t {
+            type Output = Double;
+
+            /// Subtracts a `Double` from this integer, producing a new `Double` as a
+            /// result.
+            ///
+            /// This implements the `-` operator between an integer and a `Double`.
+            #[inline]
+            fn sub(self, other: Double) -> Double {
+                f64::from(self).sub(other)
+            }
+        }
+
+        impl SubAssign<$t> for Double {
+            /// Subtracts an integer from this `Double`, modifying this one to equal the
+            /// result.
+            ///
+            /// This implements the `-=` operator between a `Double` and an integer.
+            #[inline]
+            fn sub_assign(&mut self, other: $t) {
+                self.sub_assign(f64::from(other));
+            }
+        }
+    )*);
+}
+
+sub_int_impl! { i32 u32 }
+
+impl Double {
+    /// Subtracts another `Double` from this one, returning both the difference and the
+    /// number of leading bits that cancelled in the subtraction.
+    ///
+    /// The number of cancelled bits is the difference between the binary exponent of the
+    /// larger operand and that of the result. When two nearly equal values are subtracted,
+    /// this is large, and the difference carries that many fewer bits of information than
+    /// the operands did. This makes it useful for flagging catastrophic cancellation in
+    /// ill-conditioned calculations.
+    ///
+    /// If the difference is exactly zero, all 106 bits of a `Double`'s precision are
+    /// reported as cancelled. If either operand or the result is infinite or `NaN`, no
+    /// bits are reported as cancelled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let (diff, bits) = dd!(1).sub_with_cancellation(dd!(0.999999));
+    /// assert!((diff - dd!(1e-6)).abs() < dd!(1e-30));
+    /// assert!(bits == 20);
+    ///
+    /// let (_, bits) = Double::PI.sub_with_cancellation(Double::LN_2);
+    /// assert!(bits == 0);
+    /// ```
+    pub fn sub_with_cancellation(self, other: Double) -> (Double, u32) {
+        let diff = self - other;
+        if !self.is_finite() || !other.is_finite() || !diff.is_finite() {
+            (diff, 0)
+        } else if diff.is_zero() {
+            (diff, if self.is_zero() { 0 } else { 106 })
+        } else {
+            let exp = |x: f64| x.abs().log2().floor() as i32;
+            let larger = exp(self.0).max(exp(other.0));
+            (diff, (larger - exp(diff.0)).max(0) as u32)
+        }
+    }
+
+    /// Calculates the positive difference between this `Double` and another, which is the
+    /// difference if this one is larger and zero otherwise.
+    ///
+    /// This is the same as C's `fdim`. Unlike `(a - b).max(Double::ZERO)`, it never returns
+    /// negative zero; if this `Double` is less than or equal to `other`, the result is
+    /// positive zero. If either `Double` is `NaN`, the result is `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(5).abs_sub(dd!(3)) == dd!(2));
+    /// assert!(dd!(3).abs_sub(dd!(5)) == dd!(0));
+    /// assert!(Double::NAN.abs_sub(dd!(1)).is_nan());
+    /// ```
+    pub fn abs_sub(self, other: Double) -> Double {
+        if self.is_nan() || other.is_nan() {
+            Double::NAN
+        } else if self <= other {
+            Double::ZERO
+        } else {
+            self - other
+        }
+    }
+
+    /// Subtracts another `Double` from this one, returning `None` if the difference overflows.
+    ///
+    /// This is the same as the `-` operator, except that a difference that is infinite or
+    /// `NaN` is turned into `None` when both `Double`s were finite. Infinite and `NaN`
+    /// arguments are handled the same way as they are in [`checked_add`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(5).checked_sub(dd!(3)) == Some(dd!(2)));
+    /// assert!(Double::MAX.checked_sub(Double::MIN) == None);
+    /// assert!(Double::INFINITY.checked_sub(dd!(1)) == Some(Double::INFINITY));
+    /// ```
+    ///
+    /// [`checked_add`]: #method.checked_add
+    pub fn checked_sub(self, other: Double) -> Option<Double> {
+        let r = self - other;
+        if r.is_finite() || !(self.is_finite() && other.is_finite()) {
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    /// Subtracts another `Double` from this one, rounding the difference in the direction
+    /// given by `mode`.
+    ///
+    /// This works the same way as [`add_round`]: the difference from the `-` operator is
+    /// compared exactly with the true difference of the components, and stepped (by about
+    /// the size returned by [`eps_at`]) until it's on the side of it that `mode` asks for.
+    /// [`RoundMode::Up`] and [`RoundMode::Down`] always bracket the exact difference, and
+    /// [`RoundMode::Nearest`] gives the same result as `-`.
+    ///
+    /// If either `Double` is infinite or `NaN`, the result is the same as it is with `-`.
+    /// When a difference of finite `Double`s overflows, the result is an infinity if `mode`
+    /// rounds it away from zero and [`MAX`] or [`MIN`] if `mode` rounds it toward zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, RoundMode};
+    /// let x = dd!(1) / dd!(3);
+    /// let y = dd!(1e-40);
+    /// assert!(x.sub_round(y, RoundMode::Up) > x.sub_round(y, RoundMode::Down));
+    ///
+    /// // Exact differences aren't moved
+    /// assert!(dd!(3.75).sub_round(dd!(2.25), RoundMode::Up) == dd!(1.5));
+    /// ```
+    ///
+    /// [`add_round`]: #method.add_round
+    /// [`eps_at`]: #method.eps_at
+    /// [`MAX`]: #associatedconstant.MAX
+    /// [`MIN`]: #associatedconstant.MIN
+    /// [`RoundMode::Up`]: enum.RoundMode.html#variant.Up
+    /// [`RoundMode::Down`]: enum.RoundMode.html#variant.Down
+    /// [`RoundMode::Nearest`]: enum.RoundMode.html#variant.Nearest
+    pub fn sub_round(self, other: Double, mode: RoundMode) -> Double {
+        let r = self - other;
+        if !(self.is_finite() && other.is_finite()) {
+            return r;
+        }
+        c::round_directed(r, mode, |x| {
+            b::sum_sign(&[x.0, x.1, -self.0, -self.1, other.0, other.1], &[])
+        })
+    }
+}
+
 impl Double {
     // Precalc functions
     //
@@ -204,6 +523,7 @@ impl Double {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::f64;
 
     // sub tests
     test_all_near!(
@@ -293,6 +613,242 @@ mod tests {
             Double::NEG_INFINITY - Double::NEG_INFINITY;
     );
 
+    // f64 tests
+    test_all_exact!(
+        f64_int:
+            dd!(1),
+            dd!(3) - 2.0;
+        f64_rev_int:
+            dd!(-1),
+            2.0 - dd!(3);
+        f64_id:
+            Double::PI,
+            Double::PI - 0.0;
+        f64_inf:
+            Double::NEG_INFINITY,
+            Double::ONE - f64::INFINITY;
+        f64_rev_inf:
+            Double::INFINITY,
+            f64::INFINITY - Double::ONE;
+        f64_inf_inf:
+            Double::NAN,
+            Double::INFINITY - f64::INFINITY;
+        f64_nan:
+            Double::NAN,
+            Double::ONE - f64::NAN;
+        f64_rev_nan:
+            Double::NAN,
+            f64::NAN - Double::ONE;
+    );
+    test_all_near!(
+        f64_num:
+            Double::PI - Double(1.1, 0.0),
+            Double::PI - 1.1;
+        f64_ref:
+            Double::PI - Double(1.1, 0.0),
+            &Double::PI - 1.1;
+        f64_rev_num:
+            Double(1.1, 0.0) - Double::PI,
+            1.1 - Double::PI;
+        f64_rev_ref:
+            Double(1.1, 0.0) - Double::PI,
+            1.1 - &Double::PI;
+        f64_cancel:
+            Double::E - Double(f64::consts::E, 0.0),
+            Double::E - f64::consts::E;
+    );
+
+    // sub_with_cancellation tests
+    test_all_eq!(
+        cancel_near:
+            40,
+            dd!(1).sub_with_cancellation(Double(1.0 - 2f64.powi(-40), 0.0)).1;
+        cancel_near_neg:
+            40,
+            Double(-1.0 + 2f64.powi(-40), 0.0).sub_with_cancellation(dd!(-1)).1;
+        cancel_low_component:
+            54,
+            Double::PI.sub_with_cancellation(Double(Double::PI.0, 0.0)).1;
+        cancel_dissimilar:
+            0,
+            Double::PI.sub_with_cancellation(Double::LN_2).1;
+        cancel_opposite_signs:
+            0,
+            Double::PI.sub_with_cancellation(-Double::E).1;
+        cancel_zero_operand:
+            0,
+            Double::PI.sub_with_cancellation(Double::ZERO).1;
+        cancel_exact:
+            106,
+            Double::PI.sub_with_cancellation(Double::PI).1;
+        cancel_zeros:
+            0,
+            Double::ZERO.sub_with_cancellation(Double::ZERO).1;
+        cancel_inf:
+            0,
+            Double::INFINITY.sub_with_cancellation(Double::PI).1;
+        cancel_inf_inf:
+            0,
+            Double::INFINITY.sub_with_cancellation(Double::INFINITY).1;
+        cancel_nan:
+            0,
+            Double::NAN.sub_with_cancellation(Double::PI).1;
+    );
+    test_all_exact!(
+        cancel_diff:
+            Double(2f64.powi(-40), 0.0),
+            dd!(1).sub_with_cancellation(Double(1.0 - 2f64.powi(-40), 0.0)).0;
+        cancel_diff_matches_sub:
+            Double::PI - Double::E,
+            Double::PI.sub_with_cancellation(Double::E).0;
+    );
+
+    // abs_sub tests
+    test_all_exact!(
+        abs_sub_greater:
+            dd!(2),
+            dd!(5).abs_sub(dd!(3));
+        abs_sub_less:
+            Double::ZERO,
+            dd!(3).abs_sub(dd!(5));
+        abs_sub_equal:
+            Double::ZERO,
+            Double::PI.abs_sub(Double::PI);
+        abs_sub_close:
+            Double::PI - Double::E,
+            Double::PI.abs_sub(Double::E);
+        abs_sub_neg:
+            dd!(2),
+            dd!(-3).abs_sub(dd!(-5));
+        abs_sub_inf:
+            Double::INFINITY,
+            Double::INFINITY.abs_sub(Double::PI);
+        abs_sub_neg_inf:
+            Double::ZERO,
+            Double::NEG_INFINITY.abs_sub(Double::PI);
+        abs_sub_inf_inf:
+            Double::ZERO,
+            Double::INFINITY.abs_sub(Double::INFINITY);
+        abs_sub_nan:
+            Double::NAN,
+            Double::NAN.abs_sub(Double::PI);
+        abs_sub_nan_other:
+            Double::NAN,
+            Double::PI.abs_sub(Double::NAN);
+    );
+    test_all_assert!(
+        abs_sub_zero_sign:
+            dd!(-3).abs_sub(dd!(5)).is_sign_positive();
+        abs_sub_neg_zero_sign:
+            Double::NEG_ZERO.abs_sub(Double::ZERO).is_sign_positive();
+    );
+
+    // checked_sub tests
+    test_all_eq!(
+        checked_sub_finite:
+            Some(dd!(2)),
+            dd!(5).checked_sub(dd!(3));
+        checked_sub_overflow:
+            None,
+            Double::MAX.checked_sub(Double::MIN);
+        checked_sub_neg_overflow:
+            None,
+            Double::MIN.checked_sub(Double::MAX);
+        checked_sub_inf:
+            Some(Double::NEG_INFINITY),
+            dd!(1).checked_sub(Double::INFINITY);
+    );
+    test_all_assert!(
+        checked_sub_nan:
+            Double::NAN.checked_sub(dd!(1)).unwrap().is_nan();
+        checked_sub_inf_inf:
+            Double::INFINITY.checked_sub(Double::INFINITY).unwrap().is_nan();
+    );
+
+    // sub_round tests
+    test!(sub_round_inexact: {
+        let x = dd!(1) / dd!(3);
+        let y = dd!(1) / dd!(7e20);
+        let up = x.sub_round(y, RoundMode::Up);
+        let down = x.sub_round(y, RoundMode::Down);
+        let exact = c::to_quad(x) - c::to_quad(y);
+        assert!(up > down);
+        assert!(c::to_quad(down) < exact && exact < c::to_quad(up));
+        assert!(up - down <= (x - y).eps_at());
+    });
+    test!(sub_round_bracket: {
+        let values = [Double::PI, Double::E, -Double::LN_2, dd!(7), dd!(-1e10), dd!(3e-20)];
+        for &a in &values {
+            for &b in &values {
+                let exact = c::to_quad(a) - c::to_quad(b);
+                let up = c::to_quad(a.sub_round(b, RoundMode::Up));
+                let down = c::to_quad(a.sub_round(b, RoundMode::Down));
+                let zero = c::to_quad(a.sub_round(b, RoundMode::TowardZero));
+                assert!(down <= exact && exact <= up, "{} - {} is not bracketed", a, b);
+                assert!(zero.abs() <= exact.abs(), "{} - {} is not toward zero", a, b);
+            }
+        }
+    });
+    test_all_exact!(
+        sub_round_nearest:
+            Double::PI - Double::E,
+            Double::PI.sub_round(Double::E, RoundMode::Nearest);
+        sub_round_exact_up:
+            dd!(1.5),
+            dd!(3.75).sub_round(dd!(2.25), RoundMode::Up);
+        sub_round_exact_down:
+            dd!(1.5),
+            dd!(3.75).sub_round(dd!(2.25), RoundMode::Down);
+        sub_round_toward_zero_pos:
+            Double::PI.sub_round(Double::E, RoundMode::Down),
+            Double::PI.sub_round(Double::E, RoundMode::TowardZero);
+        sub_round_toward_zero_neg:
+            (-Double::PI).sub_round(Double::E, RoundMode::Up),
+            (-Double::PI).sub_round(Double::E, RoundMode::TowardZero);
+        sub_round_inf:
+            Double::INFINITY,
+            Double::INFINITY.sub_round(dd!(1), RoundMode::Down);
+    );
+    test_all_assert!(
+        sub_round_nan:
+            Double::NAN.sub_round(dd!(1), RoundMode::Up).is_nan();
+    );
+    test!(sub_round_subnormal: {
+        // Adding a step to the last component here used to be lost in renormalization,
+        // so this never finished
+        let x = Double(1.6960014408569572e-286, -1.1192707215280331e-302);
+        let y = Double(9.7247e-320, 0.0);
+        let down = x.sub_round(y, RoundMode::Down);
+        let up = x.sub_round(y, RoundMode::Up);
+        assert!(down == Double(1.6960014408569572e-286, -1.1192707215280332e-302));
+        assert!(up == x);
+    });
+
+    // integer tests
+    test_all_exact!(
+        int_i32:
+            dd!(1),
+            dd!(3) - 2;
+        int_rev_i32:
+            dd!(-1),
+            2 - dd!(3);
+        int_u32:
+            dd!(1),
+            dd!(3) - 2u32;
+        int_rev_u32:
+            dd!(-1),
+            2u32 - dd!(3);
+        int_neg:
+            dd!(5),
+            dd!(3) - -2;
+        int_min:
+            dd!(2147483651.0),
+            dd!(3) - i32::MIN;
+        int_ref:
+            Double::PI - Double::from(2.0),
+            &Double::PI - 2;
+    );
+
     // Assign tests. Assign code delegates to sub code, so there's no need to re-test all
     // of the cases above.
     test_all!(
@@ -306,5 +862,16 @@ mod tests {
             b -= &Double::E;
             near!(dd!("0.42331082513074800310235591192684125"), b);
         }
+        assign_f64: {
+            let mut c = Double::PI;
+            c -= 1.1;
+            near!(Double::PI - Double(1.1, 0.0), c);
+        }
+        assign_int: {
+            let mut e = Double::PI;
+            e -= 2;
+            e -= 3u32;
+            exact!(Double::PI - 2.0 - 3.0, e);
+        }
     );
 }
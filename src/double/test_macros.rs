@@ -5,8 +5,8 @@
 
 macro_rules! prec {
     ($expected:expr, $actual:expr, $digits:expr $(,)?) => {
-        let expected = Double::from($expected);
-        let actual = Double::from($actual);
+        let expected = dd!($expected);
+        let actual = dd!($actual);
         let mag = if expected.is_zero() {
             1
         } else {
@@ -41,8 +41,8 @@ macro_rules! near {
 
 macro_rules! exact {
     ($expected:expr, $actual:expr $(,)?) => {
-        let expected = Double::from($expected);
-        let actual = Double::from($actual);
+        let expected = dd!($expected);
+        let actual = dd!($actual);
         let message = format!(
             concat!(
                 "\n",
@@ -63,12 +63,6 @@ macro_rules! exact {
     };
 }
 
-macro_rules! test {
-    ($name:ident: { $($tt:tt)* }) => {
-        #[test] fn $name() { $($tt)* }
-    };
-}
-
 macro_rules! test_prec {
     ($name:ident: $expected:expr, $actual:expr, $digits:expr $(,)?) => {
         #[test]
@@ -118,16 +118,10 @@ impl Double {
 
     /// Calculates the natural logarithm, log<sub>*e*</sub>, of the `Double`.
     ///
-    /// This calculation relies upon the [`exp`] calculation, in the opposite direction. A
-    /// large positive logarithm, for example, will require the calculation of a large
-    /// negative exponential.
-    ///
-    /// For the same reasons that negative values of [`exp`] are limited to -600, the
-    /// accurate results of this function are limited to the number whose logarithm is 600,
-    /// which is around 2.65 &times; 10<sup>261</sup>. Take care with this; unlike in
-    /// [`exp`], [`INFINITY`] is *not* returned. In that function, exceeding the maximum
-    /// refers to actually overflowing an `f64`, which is appropriate to call [`INFINITY`];
-    /// here, it means `601`.
+    /// This calculation relies upon the [`exp`] calculation, in the opposite direction.
+    /// The power of two nearest to the number is factored out first, so that the
+    /// exponential never has to come anywhere near over- or underflowing, which keeps the
+    /// result accurate over the whole range of `Double`s, subnormals included.
     ///
     /// # Examples
     /// ```
@@ -140,7 +134,6 @@ impl Double {
     /// ```
     ///
     /// [`exp`]: #method.exp
-    /// [`INFINITY`]: #associatedconstant.INFINITY
     pub fn ln(self) -> Double {
         match self.pre_ln() {
             Some(r) => r,
@@ -163,30 +156,37 @@ impl Double {
                 //
                 // Because the derivative of exp(x) is exp(x), this is perhaps the simplest
                 // of all Newton iterations.
-                let mut x = Double(self.0.ln(), 0.0); // initial approximation
+                //
+                // The exponential of a large logarithm over- or underflows, so the power of
+                // two nearest to the number is taken out of it first, leaving a number
+                // between √½ and √2. The logarithm of the power is just a multiple of
+                // ln(2), since ln(a × 2ⁿ) = ln(a) + n ln(2). The scaling is done in two
+                // steps because 2⁻ⁿ doesn't fit into an `f64` for the smallest subnormals.
+                let n = self.0.log2().round() as i32;
+                let a = self.ldexp(-n / 2).ldexp(n / 2 - n);
+                let mut x = Double(a.0.ln(), 0.0); // initial approximation
 
                 let k = x.0.abs().log2().floor() as i32;
                 let eps = c::mul_pwr2(Double::EPSILON, 2f64.powi(k + 2));
 
                 let mut i = 0;
-                loop {
-                    let r = x + self * (-x).exp() - Double::ONE;
+                let r = loop {
+                    let r = x + a * (-x).exp() - Double::ONE;
                     if (x - r).abs() < eps || i > 5 {
-                        return r;
+                        break r;
                     }
                     x = r;
                     i += 1;
-                }
+                };
+                r + Double::LN_2 * Double::from(n)
             }
         }
     }
 
     /// Calculates the base-10 logarithm, log<sub>10</sub>, of the `Double`.
     ///
-    /// As with [`ln`], this has an upper usable range less than the size of the numbers
-    /// themselves. In this case, that upper limit is around 10<sup>261</sup>. Over this
-    /// number, the output is not reliable, but it does not return [`INFINITY`] because the
-    /// number 261 is so plainly not infinite.
+    /// This is the natural logarithm from [`ln`] divided by ln(10), so it's accurate over
+    /// the same range.
     ///
     /// # Examples
     /// ```
@@ -198,7 +198,6 @@ impl Double {
     /// assert!(diff < dd!(1e-30));
     /// ```
     ///
-    /// [`INFINITY`]: #associatedconstant.INFINITY
     /// [`ln`]: #method.ln
     #[inline]
     pub fn log10(self) -> Double {
@@ -471,6 +470,18 @@ mod tests {
         ln_neg_290:
             dd!("-667.7496769682732483652175218584658"),
             dd!("1e-290").ln();
+        ln_300:
+            dd!("690.77552789821370525790219666051368"),
+            Double(1e300, 0.0).ln();
+        ln_neg_300:
+            dd!("-690.77552789821370518033834457010050"),
+            Double(1e-300, 0.0).ln();
+        ln_max:
+            dd!("709.78271289338399678773454114191497"),
+            Double::MAX.ln();
+        ln_min_subnormal:
+            dd!("-744.44007192138126231410729844608163"),
+            Double(5e-324, 0.0).ln();
     );
     test_all_exact!(
         ln_neg_pi:
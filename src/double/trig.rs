@@ -191,6 +191,162 @@ impl Double {
         s / c
     }
 
+    /// Computes the sine (sin) of the `Double` along with its derivative, which is the
+    /// cosine (cos).
+    ///
+    /// This is the same calculation as [`sin_cos`], but it's named for use in code that
+    /// needs the values of functions along with their derivatives, such as forward-mode
+    /// automatic differentiation.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::FRAC_PI_3;
+    /// let (sin_x, deriv) = x.sin_with_deriv();
+    ///
+    /// let diff_sin = (sin_x - x.sin()).abs();
+    /// let diff_deriv = (deriv - dd!(0.5)).abs();
+    ///
+    /// assert!(diff_sin < dd!(1e-30));
+    /// assert!(diff_deriv < dd!(1e-30));
+    /// ```
+    ///
+    /// [`sin_cos`]: #method.sin_cos
+    #[inline]
+    pub fn sin_with_deriv(self) -> (Double, Double) {
+        self.sin_cos()
+    }
+
+    /// Computes the tangent (tan) of the `Double` along with its derivative, which is the
+    /// square of the secant (sec² = 1 / cos²).
+    ///
+    /// Both values come from a single calculation of the sine and cosine, which is more
+    /// efficient than calling [`tan`] and [`cos`] separately. The derivative is calculated
+    /// from the cosine rather than as 1 + tan², which would lose precision near the poles
+    /// of the tangent.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::FRAC_PI_4;
+    /// let (tan_x, deriv) = x.tan_with_deriv();
+    ///
+    /// let diff_tan = (tan_x - dd!(1)).abs();
+    /// let diff_deriv = (deriv - dd!(2)).abs();
+    ///
+    /// assert!(diff_tan < dd!(1e-30));
+    /// assert!(diff_deriv < dd!(1e-30));
+    /// ```
+    ///
+    /// [`tan`]: #method.tan
+    /// [`cos`]: #method.cos
+    pub fn tan_with_deriv(self) -> (Double, Double) {
+        let (s, c) = self.sin_cos();
+        (s / c, c.sqr().recip())
+    }
+
+    /// Computes the cotangent (cot) of the `Double`, the reciprocal of its tangent.
+    ///
+    /// The cotangent has poles at every multiple of π. Arguments close to a pole produce
+    /// very large results. An argument that is zero, or that is so close to a multiple of π
+    /// that its sine is calculated as zero, is taken to be at the pole and produces an
+    /// infinity. Since the cotangent is an odd function, that infinity is positive for
+    /// positive arguments and negative for negative ones (including negative zero).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::FRAC_PI_4.cot();
+    /// let expected = dd!(1);
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// assert!(Double::ZERO.cot() == Double::INFINITY);
+    /// ```
+    pub fn cot(self) -> Double {
+        match self.pre_cot() {
+            Some(r) => r,
+            None => {
+                let (s, c) = self.abs().sin_cos();
+                let r = if s.is_zero() { Double::INFINITY } else { c / s };
+                if self.is_sign_negative() {
+                    -r
+                } else {
+                    r
+                }
+            }
+        }
+    }
+
+    /// Computes the secant (sec) of the `Double`, the reciprocal of its cosine.
+    ///
+    /// The secant has poles at every odd multiple of π/2. Arguments close to a pole produce
+    /// very large results. An argument that is so close to an odd multiple of π/2 that its
+    /// cosine is calculated as zero is taken to be at the pole and produces positive
+    /// infinity, whatever its sign, since the secant is an even function.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::ZERO.sec() == Double::ONE);
+    ///
+    /// let x = Double::FRAC_PI_3.sec();
+    /// let expected = dd!(2);
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    pub fn sec(self) -> Double {
+        match self.pre_sec() {
+            Some(r) => r,
+            None => {
+                let c = self.abs().cos();
+                if c.is_zero() {
+                    Double::INFINITY
+                } else {
+                    c.recip()
+                }
+            }
+        }
+    }
+
+    /// Computes the cosecant (csc) of the `Double`, the reciprocal of its sine.
+    ///
+    /// The cosecant has poles at every multiple of π. Arguments close to a pole produce
+    /// very large results. An argument that is zero, or that is so close to a multiple of π
+    /// that its sine is calculated as zero, is taken to be at the pole and produces an
+    /// infinity. Since the cosecant is an odd function, that infinity is positive for
+    /// positive arguments and negative for negative ones (including negative zero).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::FRAC_PI_2.csc();
+    /// let expected = dd!(1);
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// assert!(Double::NEG_ZERO.csc() == Double::NEG_INFINITY);
+    /// ```
+    pub fn csc(self) -> Double {
+        match self.pre_csc() {
+            Some(r) => r,
+            None => {
+                let s = self.abs().sin();
+                let r = if s.is_zero() {
+                    Double::INFINITY
+                } else {
+                    s.recip()
+                };
+                if self.is_sign_negative() {
+                    -r
+                } else {
+                    r
+                }
+            }
+        }
+    }
+
     /// Computes the 2-argument inverse tangent (tan<sup>-1</sup>) of this `Double` and
     /// another `Double`.
     ///
@@ -378,6 +534,47 @@ impl Double {
         }
     }
 
+    #[inline]
+    fn pre_cot(&self) -> Option<Double> {
+        if self.is_zero() {
+            if self.is_sign_negative() {
+                Some(Double::NEG_INFINITY)
+            } else {
+                Some(Double::INFINITY)
+            }
+        } else if !self.is_finite() {
+            Some(Double::NAN)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_sec(&self) -> Option<Double> {
+        if self.is_zero() {
+            Some(Double::ONE)
+        } else if !self.is_finite() {
+            Some(Double::NAN)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_csc(&self) -> Option<Double> {
+        if self.is_zero() {
+            if self.is_sign_negative() {
+                Some(Double::NEG_INFINITY)
+            } else {
+                Some(Double::INFINITY)
+            }
+        } else if !self.is_finite() {
+            Some(Double::NAN)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn pre_atan2(&self, other: &Double) -> Option<Double> {
         if other.is_zero() {
@@ -918,6 +1115,241 @@ mod tests {
             Double::NAN.tan();
     );
 
+    // sin_with_deriv tests
+    test_all_exact!(
+        sin_with_deriv_zero:
+            Double::ZERO,
+            Double::ZERO.sin_with_deriv().0;
+        sin_with_deriv_zero_deriv:
+            Double::ONE,
+            Double::ZERO.sin_with_deriv().1;
+        sin_with_deriv_inf:
+            Double::NAN,
+            Double::INFINITY.sin_with_deriv().0;
+        sin_with_deriv_inf_deriv:
+            Double::NAN,
+            Double::INFINITY.sin_with_deriv().1;
+        sin_with_deriv_nan:
+            Double::NAN,
+            Double::NAN.sin_with_deriv().0;
+        sin_with_deriv_nan_deriv:
+            Double::NAN,
+            Double::NAN.sin_with_deriv().1;
+    );
+    test_all_near!(
+        sin_with_deriv_one:
+            dd!("0.84147098480789650665250232163029899962"),
+            Double::ONE.sin_with_deriv().0;
+        sin_with_deriv_one_deriv:
+            dd!("0.54030230586813971740093660744297660373"),
+            Double::ONE.sin_with_deriv().1;
+    );
+    test!(sin_with_deriv_finite_difference: {
+        let h = dd!(1e-10);
+        for x in [dd!(0.5), Double::ONE, Double::E, dd!(-2), dd!(10)] {
+            let (_, deriv) = x.sin_with_deriv();
+            let estimate = ((x + h).sin() - (x - h).sin()) / (h + h);
+            let diff = (deriv - estimate).abs();
+            assert!(diff < dd!(1e-18), "sin'({}) = {}, estimate {}", x, deriv, estimate);
+        }
+    });
+
+    // tan_with_deriv tests
+    test_all_exact!(
+        tan_with_deriv_zero:
+            Double::ZERO,
+            Double::ZERO.tan_with_deriv().0;
+        tan_with_deriv_zero_deriv:
+            Double::ONE,
+            Double::ZERO.tan_with_deriv().1;
+        tan_with_deriv_inf:
+            Double::NAN,
+            Double::INFINITY.tan_with_deriv().0;
+        tan_with_deriv_inf_deriv:
+            Double::NAN,
+            Double::INFINITY.tan_with_deriv().1;
+        tan_with_deriv_nan:
+            Double::NAN,
+            Double::NAN.tan_with_deriv().0;
+        tan_with_deriv_nan_deriv:
+            Double::NAN,
+            Double::NAN.tan_with_deriv().1;
+    );
+    test_all_near!(
+        tan_with_deriv_one:
+            dd!("1.55740772465490223050697480745836"),
+            Double::ONE.tan_with_deriv().0;
+        tan_with_deriv_one_deriv:
+            dd!("3.4255188208147597609416789335411366"),
+            Double::ONE.tan_with_deriv().1;
+        tan_with_deriv_pi_3_deriv:
+            dd!(4),
+            Double::FRAC_PI_3.tan_with_deriv().1;
+    );
+    test!(tan_with_deriv_finite_difference: {
+        let h = dd!(1e-10);
+        for x in [dd!(0.5), Double::ONE, Double::E, dd!(-2), dd!(10)] {
+            let (_, deriv) = x.tan_with_deriv();
+            let estimate = ((x + h).tan() - (x - h).tan()) / (h + h);
+            let diff = (deriv - estimate).abs();
+            assert!(diff < dd!(1e-18), "tan'({}) = {}, estimate {}", x, deriv, estimate);
+        }
+    });
+    test!(tan_with_deriv_matches_tan: {
+        for x in [dd!(0.5), Double::ONE, Double::E, dd!(-2), dd!(10)] {
+            let (tan_x, deriv) = x.tan_with_deriv();
+            exact!(x.tan(), tan_x);
+            near!(Double::ONE + tan_x.sqr(), deriv);
+        }
+    });
+
+    // cot tests
+    test_all_near!(
+        cot_one:
+            dd!("0.642092615934330703006419986594265620"),
+            Double::ONE.cot();
+        cot_neg_2_5:
+            dd!("1.338648128304151360210887023990245130"),
+            dd!(-2.5).cot();
+    );
+    test_all_prec!(
+        cot_ten:
+            dd!("1.542351045356920048277469355682429311"),
+            dd!(10).cot(),
+            30;
+        cot_pi_4:
+            Double::ONE,
+            Double::FRAC_PI_4.cot(),
+            30;
+        cot_neg_pi_4:
+            Double::NEG_ONE,
+            (-Double::FRAC_PI_4).cot(),
+            30;
+    );
+    test_all_exact!(
+        cot_zero:
+            Double::INFINITY,
+            Double::ZERO.cot();
+        cot_neg_zero:
+            Double::NEG_INFINITY,
+            Double::NEG_ZERO.cot();
+        cot_inf:
+            Double::NAN,
+            Double::INFINITY.cot();
+        cot_neg_inf:
+            Double::NAN,
+            Double::NEG_INFINITY.cot();
+        cot_nan:
+            Double::NAN,
+            Double::NAN.cot();
+    );
+    test_all_assert!(
+        cot_pi:
+            Double::PI.cot() > dd!(1e30);
+        cot_neg_pi:
+            (-Double::PI).cot() < dd!(-1e30);
+        cot_odd:
+            (-Double::E).cot() == -Double::E.cot();
+    );
+
+    // sec tests
+    test_all_near!(
+        sec_one:
+            dd!("1.850815717680925617911753241398650193"),
+            Double::ONE.sec();
+        sec_neg_2_5:
+            dd!("-1.24821565146881783090643327407488299"),
+            dd!(-2.5).sec();
+    );
+    test_all_prec!(
+        sec_ten:
+            dd!("-1.19179350668789581087969389842734659"),
+            dd!(10).sec(),
+            30;
+        sec_pi_3:
+            dd!(2),
+            Double::FRAC_PI_3.sec(),
+            30;
+        sec_3_pi_4:
+            -Double::SQRT_2,
+            Double::FRAC_3_PI_4.sec(),
+            30;
+    );
+    test_all_exact!(
+        sec_zero:
+            Double::ONE,
+            Double::ZERO.sec();
+        sec_neg_zero:
+            Double::ONE,
+            Double::NEG_ZERO.sec();
+        sec_inf:
+            Double::NAN,
+            Double::INFINITY.sec();
+        sec_neg_inf:
+            Double::NAN,
+            Double::NEG_INFINITY.sec();
+        sec_nan:
+            Double::NAN,
+            Double::NAN.sec();
+    );
+    test_all_assert!(
+        sec_pi_2:
+            Double::FRAC_PI_2.sec() > dd!(1e30);
+        sec_neg_pi_2:
+            (-Double::FRAC_PI_2).sec() > dd!(1e30);
+        sec_even:
+            (-Double::E).sec() == Double::E.sec();
+    );
+
+    // csc tests
+    test_all_near!(
+        csc_one:
+            dd!("1.188395105778121216261599452374551003"),
+            Double::ONE.csc();
+        csc_neg_2_5:
+            dd!("-1.67092154555867992794667687039599880"),
+            dd!(-2.5).csc();
+    );
+    test_all_prec!(
+        csc_ten:
+            dd!("-1.83816396088966558870523652376491459"),
+            dd!(10).csc(),
+            30;
+        csc_pi_2:
+            Double::ONE,
+            Double::FRAC_PI_2.csc(),
+            30;
+        csc_pi_6:
+            dd!(2),
+            Double::FRAC_PI_6.csc(),
+            30;
+    );
+    test_all_exact!(
+        csc_zero:
+            Double::INFINITY,
+            Double::ZERO.csc();
+        csc_neg_zero:
+            Double::NEG_INFINITY,
+            Double::NEG_ZERO.csc();
+        csc_inf:
+            Double::NAN,
+            Double::INFINITY.csc();
+        csc_neg_inf:
+            Double::NAN,
+            Double::NEG_INFINITY.csc();
+        csc_nan:
+            Double::NAN,
+            Double::NAN.csc();
+    );
+    test_all_assert!(
+        csc_pi:
+            Double::PI.csc().abs() > dd!(1e30);
+        csc_neg_pi:
+            (-Double::PI).csc() == -Double::PI.csc();
+        csc_odd:
+            (-Double::E).csc() == -Double::E.csc();
+    );
+
     // atan2 test
     test_all_near!(
         atan2_pos_pos:
@@ -0,0 +1,125 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::double::Double;
+use twofloat::TwoFloat;
+
+impl From<TwoFloat> for Double {
+    /// Generates a `Double` from a [`TwoFloat`], exactly.
+    ///
+    /// A `TwoFloat` is the same kind of unevaluated sum of a high and a low `f64` that a
+    /// `Double` is, so its two parts simply become the two components.
+    ///
+    /// This is only available when the `twofloat` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use twofloat::TwoFloat;
+    ///
+    /// let x = TwoFloat::new_add(1.0, 1e-20);
+    /// let d = Double::from(x);
+    /// assert!(d == Double::from((1.0, 1e-20)));
+    ///
+    /// // The `Double` can then use functions that `TwoFloat` doesn't have
+    /// let y = Double::from(TwoFloat::from(0.5)).asin();
+    /// assert!((y - Double::FRAC_PI_6).abs() < dd!(1e-30));
+    /// ```
+    ///
+    /// [`TwoFloat`]: https://docs.rs/twofloat/0.8/twofloat/struct.TwoFloat.html
+    #[inline]
+    fn from(x: TwoFloat) -> Double {
+        Double(x.hi(), x.lo())
+    }
+}
+
+impl From<Double> for TwoFloat {
+    /// Converts a `Double` into a [`TwoFloat`], exactly.
+    ///
+    /// The components of the `Double` become the high and low parts of the `TwoFloat`.
+    /// `NaN`, the infinities, and the sign of zero all carry over.
+    ///
+    /// This is only available when the `twofloat` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use twofloat::TwoFloat;
+    ///
+    /// let x = TwoFloat::from(Double::PI);
+    /// assert!(x.hi() == Double::PI[0] && x.lo() == Double::PI[1]);
+    /// assert!(Double::from(x) == Double::PI);
+    /// ```
+    ///
+    /// [`TwoFloat`]: https://docs.rs/twofloat/0.8/twofloat/struct.TwoFloat.html
+    #[inline]
+    fn from(x: Double) -> TwoFloat {
+        // `TwoFloat` has no way to set its parts directly, but for a normalized pair of
+        // components, the two-sum that `new_add` does gives them back unchanged. That isn't
+        // true of infinities or of negative zero, which have no second component anyway.
+        if x.1 == 0.0 {
+            TwoFloat::from(x.0)
+        } else {
+            TwoFloat::new_add(x.0, x.1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parts(x: TwoFloat) -> (f64, f64) {
+        (x.hi(), x.lo())
+    }
+
+    // from_twofloat tests
+    test_all_exact!(
+        from_twofloat_f64:
+            dd!(1.5),
+            Double::from(TwoFloat::from(1.5));
+        from_twofloat_pair:
+            Double(1.0, 1e-20),
+            Double::from(TwoFloat::new_add(1.0, 1e-20));
+        from_twofloat_neg_pair:
+            Double(-1.0, 1e-20),
+            Double::from(TwoFloat::new_add(-1.0, 1e-20));
+        from_twofloat_inf:
+            Double::INFINITY,
+            Double::from(TwoFloat::from(f64::INFINITY));
+        from_twofloat_neg_inf:
+            Double::NEG_INFINITY,
+            Double::from(TwoFloat::from(f64::NEG_INFINITY));
+    );
+    test!(from_twofloat_special: {
+        assert!(Double::from(TwoFloat::from(f64::NAN)).is_nan());
+        let neg_zero = Double::from(TwoFloat::from(-0.0));
+        assert!(neg_zero.is_zero() && neg_zero.is_sign_negative());
+    });
+    test!(from_twofloat_arithmetic: {
+        let x = TwoFloat::new_add(1.0, 1e-20) * TwoFloat::from(3.0);
+        exact!(Double::from((1.0, 1e-20)) * dd!(3), Double::from(x));
+    });
+
+    // to_twofloat tests
+    test!(to_twofloat_exact: {
+        for x in [Double::PI, -Double::E, Double::MAX, Double::MIN_POSITIVE, dd!(1) / dd!(3)] {
+            assert_eq!(parts(TwoFloat::from(x)), (x.0, x.1));
+            assert!(Double::from(TwoFloat::from(x)) == x);
+        }
+    });
+    test!(to_twofloat_special: {
+        assert!(TwoFloat::from(Double::NAN).hi().is_nan());
+        assert_eq!(parts(TwoFloat::from(Double::INFINITY)), (f64::INFINITY, 0.0));
+        assert_eq!(parts(TwoFloat::from(Double::NEG_INFINITY)), (f64::NEG_INFINITY, 0.0));
+        let neg_zero = TwoFloat::from(Double::NEG_ZERO);
+        assert!(neg_zero.hi() == 0.0 && neg_zero.hi().is_sign_negative());
+    });
+    test!(to_twofloat_valid: {
+        for x in [Double::PI, Double::LN_2 * dd!(1e200), Double::SQRT_2 / dd!(1e200)] {
+            assert!(TwoFloat::from(x).is_valid());
+        }
+    });
+}
@@ -0,0 +1,509 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::double::Double;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A dual number built from [`Double`]s, for calculating derivatives with forward-mode
+/// automatic differentiation.
+///
+/// A dual number has the form *a + bε*, where ε is a number with the property that
+/// ε² = 0. Applying a function to *x + ε* gives *f(x) + f'(x)ε*, so carrying the
+/// coefficient of ε through a calculation yields the derivative of the calculation
+/// alongside its value. The value is held in [`val`] and the derivative in [`eps`].
+///
+/// Every operation applies the chain rule to the derivative, and both parts are calculated
+/// with the full precision of a `Double`. There's no truncation error of the kind inherent
+/// in finite differences; the derivative is as accurate as the value is.
+///
+/// To differentiate a function, call it with an argument created by [`variable`]. Any
+/// other values that the function uses should be [`constant`]s.
+///
+/// # Examples
+/// ```
+/// # use qd::{dd, Double, Dual};
+/// // d/dx sin(x²) = 2x cos(x²)
+/// let x = dd!(1.5);
+/// let y = Dual::variable(x).sqr().sin();
+///
+/// let expected = dd!(2) * x * x.sqr().cos();
+/// let diff = (y.eps - expected).abs();
+/// assert!(diff < dd!(1e-30));
+/// ```
+///
+/// [`Double`]: struct.Double.html
+/// [`val`]: #structfield.val
+/// [`eps`]: #structfield.eps
+/// [`variable`]: #method.variable
+/// [`constant`]: #method.constant
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Dual {
+    /// The value of the dual number, its real part.
+    pub val: Double,
+    /// The derivative carried by the dual number, its coefficient of ε.
+    pub eps: Double,
+}
+
+impl Dual {
+    /// Creates a `Dual` with the given value and derivative.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, Dual};
+    /// let d = Dual::new(dd!(3), dd!(2));
+    /// assert!(d.val == dd!(3));
+    /// assert!(d.eps == dd!(2));
+    /// ```
+    #[inline]
+    pub const fn new(val: Double, eps: Double) -> Dual {
+        Dual { val, eps }
+    }
+
+    /// Creates a `Dual` representing the variable of differentiation, with the given value
+    /// and a derivative of `1`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, Dual};
+    /// let d = Dual::variable(dd!(3));
+    /// assert!(d.val == dd!(3));
+    /// assert!(d.eps == Double::ONE);
+    /// ```
+    #[inline]
+    pub const fn variable(val: Double) -> Dual {
+        Dual::new(val, Double::ONE)
+    }
+
+    /// Creates a `Dual` representing a constant, with the given value and a derivative of
+    /// `0`. This is the same as using [`from`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, Dual};
+    /// let d = Dual::constant(dd!(3));
+    /// assert!(d.val == dd!(3));
+    /// assert!(d.eps == Double::ZERO);
+    /// ```
+    ///
+    /// [`from`]: #impl-From<Double>
+    #[inline]
+    pub const fn constant(val: Double) -> Dual {
+        Dual::new(val, Double::ZERO)
+    }
+
+    // Applies a function whose value at `val` is `f` and whose derivative there is `df`.
+    #[inline]
+    fn chain(self, f: Double, df: Double) -> Dual {
+        Dual::new(f, df * self.eps)
+    }
+
+    /// Calculates the absolute value of the `Dual`.
+    ///
+    /// The derivative of the absolute value is undefined at zero. This function treats a
+    /// zero with a positive sign as positive and one with a negative sign as negative.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, Dual};
+    /// let d = Dual::variable(dd!(-3)).abs();
+    /// assert!(d.val == dd!(3));
+    /// assert!(d.eps == dd!(-1));
+    /// ```
+    pub fn abs(self) -> Dual {
+        if self.val.is_sign_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// Calculates the square of the `Dual`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, Dual};
+    /// let d = Dual::variable(dd!(3)).sqr();
+    /// assert!(d.val == dd!(9));
+    /// assert!(d.eps == dd!(6));
+    /// ```
+    pub fn sqr(self) -> Dual {
+        self.chain(self.val.sqr(), self.val + self.val)
+    }
+
+    /// Calculates the square root of the `Dual`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, Dual};
+    /// let d = Dual::variable(dd!(4)).sqrt();
+    /// assert!(d.val == dd!(2));
+    /// assert!(d.eps == dd!(0.25));
+    /// ```
+    pub fn sqrt(self) -> Dual {
+        let r = self.val.sqrt();
+        self.chain(r, (r + r).recip())
+    }
+
+    /// Calculates the reciprocal of the `Dual`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, Dual};
+    /// let d = Dual::variable(dd!(4)).recip();
+    /// assert!(d.val == dd!(0.25));
+    /// assert!(d.eps == dd!(-0.0625));
+    /// ```
+    pub fn recip(self) -> Dual {
+        let r = self.val.recip();
+        self.chain(r, -r.sqr())
+    }
+
+    /// Calculates the `Dual` raised to an integer power.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, Dual};
+    /// let d = Dual::variable(dd!(2)).powi(5);
+    /// assert!(d.val == dd!(32));
+    /// assert!(d.eps == dd!(80));
+    /// ```
+    pub fn powi(self, n: i32) -> Dual {
+        if n == 0 {
+            Dual::constant(Double::ONE)
+        } else {
+            let p = self.val.powi(n - 1);
+            self.chain(p * self.val, Double::from(n) * p)
+        }
+    }
+
+    /// Calculates the exponential function, e<sup>x</sup>, of the `Dual`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, Dual};
+    /// let d = Dual::variable(dd!(1)).exp();
+    /// assert!((d.val - Double::E).abs() < dd!(1e-30));
+    /// assert!((d.eps - Double::E).abs() < dd!(1e-30));
+    /// ```
+    pub fn exp(self) -> Dual {
+        let e = self.val.exp();
+        self.chain(e, e)
+    }
+
+    /// Calculates the natural logarithm of the `Dual`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, Dual};
+    /// let d = Dual::variable(Double::E).ln();
+    /// assert!((d.val - dd!(1)).abs() < dd!(1e-30));
+    /// assert!((d.eps - Double::E.recip()).abs() < dd!(1e-30));
+    /// ```
+    pub fn ln(self) -> Dual {
+        self.chain(self.val.ln(), self.val.recip())
+    }
+
+    /// Calculates the sine of the `Dual`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, Dual};
+    /// let d = Dual::variable(Double::FRAC_PI_3).sin();
+    /// assert!((d.eps - dd!(0.5)).abs() < dd!(1e-30));
+    /// ```
+    pub fn sin(self) -> Dual {
+        let (s, c) = self.val.sin_with_deriv();
+        self.chain(s, c)
+    }
+
+    /// Calculates the cosine of the `Dual`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, Dual};
+    /// let d = Dual::variable(Double::FRAC_PI_6).cos();
+    /// assert!((d.eps + dd!(0.5)).abs() < dd!(1e-30));
+    /// ```
+    pub fn cos(self) -> Dual {
+        let (s, c) = self.val.sin_cos();
+        self.chain(c, -s)
+    }
+
+    /// Calculates the tangent of the `Dual`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, Dual};
+    /// let d = Dual::variable(Double::FRAC_PI_4).tan();
+    /// assert!((d.eps - dd!(2)).abs() < dd!(1e-30));
+    /// ```
+    pub fn tan(self) -> Dual {
+        let (t, dt) = self.val.tan_with_deriv();
+        self.chain(t, dt)
+    }
+
+    /// Calculates the inverse tangent of the `Dual`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, Dual};
+    /// let d = Dual::variable(dd!(1)).atan();
+    /// assert!((d.eps - dd!(0.5)).abs() < dd!(1e-30));
+    /// ```
+    pub fn atan(self) -> Dual {
+        self.chain(self.val.atan(), (Double::ONE + self.val.sqr()).recip())
+    }
+
+    /// Calculates the hyperbolic sine of the `Dual`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, Dual};
+    /// let d = Dual::variable(dd!(1)).sinh();
+    /// assert!((d.eps - dd!(1).cosh()).abs() < dd!(1e-30));
+    /// ```
+    pub fn sinh(self) -> Dual {
+        let (s, c) = self.val.sinh_cosh();
+        self.chain(s, c)
+    }
+
+    /// Calculates the hyperbolic cosine of the `Dual`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, Dual};
+    /// let d = Dual::variable(dd!(1)).cosh();
+    /// assert!((d.eps - dd!(1).sinh()).abs() < dd!(1e-30));
+    /// ```
+    pub fn cosh(self) -> Dual {
+        let (s, c) = self.val.sinh_cosh();
+        self.chain(c, s)
+    }
+
+    /// Calculates the hyperbolic tangent of the `Dual`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, Dual};
+    /// let d = Dual::variable(dd!(0)).tanh();
+    /// assert!(d.eps == dd!(1));
+    /// ```
+    pub fn tanh(self) -> Dual {
+        let t = self.val.tanh();
+        self.chain(t, Double::ONE - t.sqr())
+    }
+}
+
+impl From<Double> for Dual {
+    /// Creates a constant `Dual` from a `Double`, with a derivative of `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, Dual};
+    /// let d = Dual::from(dd!(3));
+    /// assert!(d == Dual::constant(dd!(3)));
+    /// ```
+    #[inline]
+    fn from(val: Double) -> Dual {
+        Dual::constant(val)
+    }
+}
+
+impl Neg for Dual {
+    type Output = Dual;
+
+    /// Computes the negation of the `Dual`, negating its derivative as well.
+    #[inline]
+    fn neg(self) -> Dual {
+        Dual::new(-self.val, -self.eps)
+    }
+}
+
+impl Add for Dual {
+    type Output = Dual;
+
+    /// Computes the sum of two `Dual`s. The derivative is the sum of the derivatives.
+    #[inline]
+    fn add(self, other: Dual) -> Dual {
+        Dual::new(self.val + other.val, self.eps + other.eps)
+    }
+}
+
+impl Sub for Dual {
+    type Output = Dual;
+
+    /// Computes the difference of two `Dual`s. The derivative is the difference of the
+    /// derivatives.
+    #[inline]
+    fn sub(self, other: Dual) -> Dual {
+        Dual::new(self.val - other.val, self.eps - other.eps)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Dual;
+
+    /// Computes the product of two `Dual`s, with the derivative from the product rule.
+    #[inline]
+    fn mul(self, other: Dual) -> Dual {
+        Dual::new(
+            self.val * other.val,
+            self.val * other.eps + self.eps * other.val,
+        )
+    }
+}
+
+impl Div for Dual {
+    type Output = Dual;
+
+    /// Computes the quotient of two `Dual`s, with the derivative from the quotient rule.
+    #[inline]
+    fn div(self, other: Dual) -> Dual {
+        let q = self.val / other.val;
+        Dual::new(q, (self.eps - q * other.eps) / other.val)
+    }
+}
+
+impl AddAssign for Dual {
+    /// Adds another `Dual` to this one, in place.
+    #[inline]
+    fn add_assign(&mut self, other: Dual) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Dual {
+    /// Subtracts another `Dual` from this one, in place.
+    #[inline]
+    fn sub_assign(&mut self, other: Dual) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for Dual {
+    /// Multiplies this `Dual` by another, in place.
+    #[inline]
+    fn mul_assign(&mut self, other: Dual) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign for Dual {
+    /// Divides this `Dual` by another, in place.
+    #[inline]
+    fn div_assign(&mut self, other: Dual) {
+        *self = *self / other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dd;
+
+    fn near(expected: Double, actual: Double) {
+        let diff = (expected - actual).abs();
+        let scale = if expected.abs() > Double::ONE {
+            expected.abs()
+        } else {
+            Double::ONE
+        };
+        let tolerance = scale * dd!(1e-30);
+        assert!(
+            diff <= tolerance,
+            "expected {}, actual {}, difference {}",
+            expected,
+            actual,
+            diff
+        );
+    }
+
+    test!(constructors: {
+        let x = dd!(2.5);
+        assert!(Dual::variable(x) == Dual::new(x, Double::ONE));
+        assert!(Dual::constant(x) == Dual::new(x, Double::ZERO));
+        assert!(Dual::from(x) == Dual::constant(x));
+    });
+
+    test!(sin_of_square: {
+        // d/dx sin(x²) = 2x cos(x²)
+        for x in [dd!(0.5), dd!(1.5), dd!(-2), Double::PI] {
+            let y = Dual::variable(x).sqr().sin();
+            near(x.sqr().sin(), y.val);
+            near(dd!(2) * x * x.sqr().cos(), y.eps);
+        }
+    });
+
+    test!(arithmetic: {
+        let x = dd!(3);
+        let a = Dual::variable(x);
+        let c = Dual::constant(dd!(2));
+
+        // d/dx (x + 2) = 1, d/dx (x - 2) = 1, d/dx 2x = 2, d/dx x/2 = 1/2, d/dx 2/x = -2/x²
+        assert!((a + c).eps == Double::ONE);
+        assert!((a - c).eps == Double::ONE);
+        assert!((c - a).eps == Double::NEG_ONE);
+        assert!((a * c).eps == dd!(2));
+        assert!((a / c).eps == dd!(0.5));
+        near(dd!(-2) / dd!(9), (c / a).eps);
+        assert!((-a).eps == Double::NEG_ONE);
+
+        // d/dx x³ = 3x², d/dx x / x = 0
+        assert!((a * a * a).eps == dd!(27));
+        assert!((a / a).eps == Double::ZERO);
+    });
+
+    test!(assignment: {
+        let a = Dual::variable(dd!(3));
+        let mut b = a;
+        b += a;
+        assert!(b == Dual::new(dd!(6), dd!(2)));
+        b -= a;
+        assert!(b == a);
+        b *= a;
+        assert!(b == Dual::new(dd!(9), dd!(6)));
+        b /= a;
+        assert!(b == a);
+    });
+
+    test!(algebraic: {
+        let x = dd!(2);
+        let a = Dual::variable(x);
+        near(x.sqrt().recip() / dd!(2), a.sqrt().eps);
+        assert!(a.recip().eps == dd!(-0.25));
+        assert!(a.powi(10).eps == dd!(5120));
+        assert!(a.powi(-2).eps == dd!(-0.25));
+        assert!(a.powi(1) == a);
+        assert!(a.powi(0) == Dual::constant(Double::ONE));
+        assert!(Dual::variable(dd!(-2)).abs() == Dual::new(x, Double::NEG_ONE));
+        assert!(a.abs() == a);
+    });
+
+    test!(transcendental: {
+        let x = dd!(0.75);
+        let a = Dual::variable(x);
+        near(x.exp(), a.exp().eps);
+        near(x.recip(), a.ln().eps);
+        near(-x.sin(), a.cos().eps);
+        near(x.cos().sqr().recip(), a.tan().eps);
+        near((Double::ONE + x.sqr()).recip(), a.atan().eps);
+        near(x.cosh(), a.sinh().eps);
+        near(x.sinh(), a.cosh().eps);
+        near(x.cosh().sqr().recip(), a.tanh().eps);
+
+        // d/dx ln(exp(x)) = 1, d/dx sin² + cos² = 0
+        near(Double::ONE, a.exp().ln().eps);
+        near(Double::ZERO, (a.sin().sqr() + a.cos().sqr()).eps);
+    });
+
+    test!(chain_rule: {
+        // d/dx exp(sin(x)) / x = exp(sin(x)) (x cos(x) - 1) / x²
+        let x = dd!(1.25);
+        let a = Dual::variable(x);
+        let y = a.sin().exp() / a;
+        let expected = x.sin().exp() * (x * x.cos() - Double::ONE) / x.sqr();
+        near(expected, y.eps);
+    });
+}
@@ -3,55 +3,142 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
-//! Errors that may occur while parsing a string into a [`Double`] or a [`Quad`].
-//! 
+//! Errors that may occur while parsing a string into a [`Double`] or a [`Quad`], or while
+//! converting one of them into some other numeric type.
+//!
 //! [`Double`]: struct.Double.html
 //! [`Quad`]: struct.Quad.html
 
 use std::fmt::{Display, Formatter, Result};
 
 /// An error generated when a problem is encountered parsing a string into a [`Double`].
-/// 
+///
 /// [`Double`]: struct.Double.html
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParseDoubleError {
     pub kind: ErrorKind,
+    /// The byte offset in the string of the character that caused the error.
+    ///
+    /// This is zero for an empty string. If the string ended before it should have (as
+    /// with an exponent marker with no exponent after it), it's the length of the string.
+    /// For an exponent too large to parse, it's the offset of the start of the exponent.
+    pub position: usize,
 }
 
 /// An error generated when a problem is encountered parsing a string into a [`Quad`].
-/// 
+///
 /// [`Quad`]: struct.Quad.html
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParseQuadError {
     pub kind: ErrorKind,
+    /// The byte offset in the string of the character that caused the error.
+    ///
+    /// This is zero for an empty string. If the string ended before it should have (as
+    /// with an exponent marker with no exponent after it), it's the length of the string.
+    /// For an exponent too large to parse, it's the offset of the start of the exponent.
+    pub position: usize,
 }
 
 /// The different kinds of errors that might be generated during parsing.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ErrorKind {
-    /// An error indicating that an attempt was made to parse an empty string.
+    /// An error indicating that an attempt was made to parse an empty string (or one that's
+    /// nothing but a sign).
     Empty,
-    /// An error indicating that the format of a parsed string is not a legal number.
+    /// An error indicating that the format of a parsed string is not a legal number, for a
+    /// reason that none of the other kinds covers (like a sign in the wrong place or an
+    /// exponent marker with no exponent).
     Invalid,
+    /// An error indicating that a parsed string contains a character that isn't a digit and
+    /// isn't any of the other characters that can appear in a number.
+    InvalidDigit,
+    /// An error indicating that a parsed string has more than one decimal point.
+    TooManyPoints,
+    /// An error indicating that the exponent of a parsed string is too large in magnitude
+    /// to be parsed.
+    ExponentOverflow,
+}
+
+/// An error generated when a [`Double`] can't be converted into some other numeric type.
+///
+/// [`Double`]: struct.Double.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TryFromDoubleError {
+    pub kind: TryFromErrorKind,
+}
+
+/// An error generated when a [`Quad`] can't be converted into some other numeric type.
+///
+/// [`Quad`]: struct.Quad.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TryFromQuadError {
+    pub kind: TryFromErrorKind,
+}
+
+/// The different kinds of errors that might be generated during conversion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TryFromErrorKind {
+    /// An error indicating that an attempt was made to convert `NaN`.
+    NaN,
+    /// An error indicating that an attempt was made to convert an infinity.
+    Infinite,
+    /// An error indicating that the number is outside the range of the target type.
+    OutOfRange,
 }
 
 impl Display for ParseDoubleError {
-    /// Displays an English-language message describing the kind of the error.
+    /// Displays an English-language message describing the kind of the error and where in
+    /// the string it happened.
     fn fmt(&self, f: &mut Formatter) -> Result {
         let description = match self.kind {
-            ErrorKind::Empty => "cannot parse double-double from empty string",
+            ErrorKind::Empty => {
+                return "cannot parse double-double from empty string".fmt(f);
+            }
             ErrorKind::Invalid => "invalid double-double literal",
+            ErrorKind::InvalidDigit => "invalid digit in double-double literal",
+            ErrorKind::TooManyPoints => "too many decimal points in double-double literal",
+            ErrorKind::ExponentOverflow => "exponent too large in double-double literal",
         };
-        description.fmt(f)
+        write!(f, "{} at byte {}", description, self.position)
     }
 }
 
 impl Display for ParseQuadError {
-    /// Displays an English-language message describing the kind of the error.
+    /// Displays an English-language message describing the kind of the error and where in
+    /// the string it happened.
     fn fmt(&self, f: &mut Formatter) -> Result {
         let description = match self.kind {
-            ErrorKind::Empty => "cannot parse quad-double from empty string",
+            ErrorKind::Empty => {
+                return "cannot parse quad-double from empty string".fmt(f);
+            }
             ErrorKind::Invalid => "invalid quad-double literal",
+            ErrorKind::InvalidDigit => "invalid digit in quad-double literal",
+            ErrorKind::TooManyPoints => "too many decimal points in quad-double literal",
+            ErrorKind::ExponentOverflow => "exponent too large in quad-double literal",
+        };
+        write!(f, "{} at byte {}", description, self.position)
+    }
+}
+
+impl Display for TryFromDoubleError {
+    /// Displays an English-language message describing the kind of the error.
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let description = match self.kind {
+            TryFromErrorKind::NaN => "cannot convert NaN double-double",
+            TryFromErrorKind::Infinite => "cannot convert infinite double-double",
+            TryFromErrorKind::OutOfRange => "double-double out of range of target type",
+        };
+        description.fmt(f)
+    }
+}
+
+impl Display for TryFromQuadError {
+    /// Displays an English-language message describing the kind of the error.
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let description = match self.kind {
+            TryFromErrorKind::NaN => "cannot convert NaN quad-double",
+            TryFromErrorKind::Infinite => "cannot convert infinite quad-double",
+            TryFromErrorKind::OutOfRange => "quad-double out of range of target type",
         };
         description.fmt(f)
     }
@@ -0,0 +1,219 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Functions that treat pairs of numbers as two-dimensional vectors.
+
+use crate::double::Double;
+
+/// Converts the vector (*x*, *y*) into polar coordinates, returning its magnitude and its
+/// angle in that order.
+///
+/// The magnitude is √(x² + y²), calculated so that the squares can neither overflow nor
+/// underflow. Neither can the intermediate magnitude in [`atan2`], which gives the angle.
+/// The angle is in radians, in the range [-π, π], and measured counter-clockwise from the
+/// positive *x*-axis.
+///
+/// If either component is infinite, the magnitude is infinite and the angle is whatever
+/// [`atan2`] returns. The origin has a magnitude of zero but, as with [`atan2`], its angle is
+/// `NaN`.
+///
+/// # Examples
+/// ```
+/// # use qd::{dd, Double};
+/// use qd::geometry::to_polar;
+///
+/// let (r, theta) = to_polar(dd!(3), dd!(4));
+/// assert!(r == dd!(5));
+///
+/// let expected = dd!("0.9272952180016122324285124629224288");
+/// assert!((theta - expected).abs() < dd!(1e-30));
+///
+/// // 10³⁰⁰ squared would overflow
+/// let (r, theta) = to_polar(dd!(1e300), dd!(1e300));
+/// assert!((r / dd!(1e300) - Double::SQRT_2).abs() < dd!(1e-30));
+/// assert!((theta - Double::FRAC_PI_4).abs() < dd!(1e-30));
+/// ```
+///
+/// [`atan2`]: ../struct.Double.html#method.atan2
+pub fn to_polar(x: Double, y: Double) -> (Double, Double) {
+    (hypot(x, y), scaled_atan2(x, y))
+}
+
+/// Converts a magnitude and an angle in radians into the vector (*x*, *y*).
+///
+/// This is the inverse of [`to_polar`]; it returns (*r* cos θ, *r* sin θ).
+///
+/// # Examples
+/// ```
+/// # use qd::{dd, Double};
+/// use qd::geometry::{from_polar, to_polar};
+///
+/// let (x, y) = from_polar(dd!(2), Double::FRAC_PI_6);
+/// assert!((x - dd!(3).sqrt()).abs() < dd!(1e-30));
+/// assert!((y - dd!(1)).abs() < dd!(1e-30));
+///
+/// let (r, theta) = to_polar(dd!(-1.5), dd!(2.25));
+/// let (x, y) = from_polar(r, theta);
+/// assert!((x - dd!(-1.5)).abs() < dd!(1e-30));
+/// assert!((y - dd!(2.25)).abs() < dd!(1e-30));
+/// ```
+///
+/// [`to_polar`]: fn.to_polar.html
+pub fn from_polar(r: Double, theta: Double) -> (Double, Double) {
+    let (s, c) = theta.sin_cos();
+    (r * c, r * s)
+}
+
+// Calculates √(x² + y²) without undue overflow or underflow. Both components are scaled by
+// the same power of two (which is exact) so that the larger is near 1 before squaring, and
+// the result is scaled back afterwards.
+pub(crate) fn hypot(x: Double, y: Double) -> Double {
+    if x.is_infinite() || y.is_infinite() {
+        Double::INFINITY
+    } else if x.is_nan() || y.is_nan() {
+        Double::NAN
+    } else if x.is_zero() && y.is_zero() {
+        Double::ZERO
+    } else {
+        let n = scale(x, y);
+        let (x, y) = (x.ldexp(-n), y.ldexp(-n));
+        (x.sqr() + y.sqr()).sqrt().ldexp(n)
+    }
+}
+
+// Calculates the angle of (x, y). `atan2` squares its arguments to normalize them, so its
+// arguments are scaled first; the angle doesn't change when both are multiplied by the same
+// positive number.
+pub(crate) fn scaled_atan2(x: Double, y: Double) -> Double {
+    if x.is_finite() && y.is_finite() && !(x.is_zero() && y.is_zero()) {
+        let n = scale(x, y);
+        y.ldexp(-n).atan2(x.ldexp(-n))
+    } else {
+        y.atan2(x)
+    }
+}
+
+// Returns the binary exponent of the larger of the two (finite, not both zero) numbers. This
+// is taken from the bits of the high component of each; subnormals come out as -1023, which
+// scales them up far enough to be safe either way.
+pub(crate) fn scale(x: Double, y: Double) -> i32 {
+    let exp = |d: Double| ((f64::from(d).to_bits() >> 52) & 0x7ff) as i32 - 1023;
+    exp(x).max(exp(y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dd;
+
+    // Checks that `actual` is within `ulps` units in the last place of `expected`, where the
+    // ULP is taken at `scale` so that small components of a large vector aren't held to a
+    // tighter standard than the calculation that produced them.
+    fn close(expected: Double, actual: Double, scale: Double, ulps: f64) {
+        let diff = (expected - actual).abs();
+        let tolerance = scale.eps_at() * dd!(ulps);
+        assert!(
+            diff <= tolerance,
+            "expected {}, actual {}, difference {}",
+            expected,
+            actual,
+            diff
+        );
+    }
+
+    test!(to_polar_3_4: {
+        let (r, theta) = to_polar(dd!(3), dd!(4));
+        assert!(r == dd!(5));
+        close(
+            dd!("0.92729521800161223242851246292242880"),
+            theta,
+            theta,
+            4.0,
+        );
+    });
+
+    test!(to_polar_quadrants: {
+        let r = Double::SQRT_2;
+        for (x, y, expected) in [
+            (dd!(1), dd!(1), Double::FRAC_PI_4),
+            (dd!(-1), dd!(1), Double::FRAC_3_PI_4),
+            (dd!(-1), dd!(-1), -Double::FRAC_3_PI_4),
+            (dd!(1), dd!(-1), -Double::FRAC_PI_4),
+        ] {
+            let (mag, theta) = to_polar(x, y);
+            close(r, mag, r, 1.0);
+            close(expected, theta, expected, 4.0);
+        }
+    });
+
+    test!(to_polar_axes: {
+        assert!(to_polar(dd!(2), Double::ZERO) == (dd!(2), Double::ZERO));
+        assert!(to_polar(dd!(-2), Double::ZERO) == (dd!(2), Double::PI));
+        assert!(to_polar(Double::ZERO, dd!(2)) == (dd!(2), Double::FRAC_PI_2));
+        assert!(to_polar(Double::ZERO, dd!(-2)) == (dd!(2), -Double::FRAC_PI_2));
+    });
+
+    test!(to_polar_extremes: {
+        let big = dd!(1e300);
+        let (r, theta) = to_polar(big, big);
+        close(big * Double::SQRT_2, r, r, 2.0);
+        close(Double::FRAC_PI_4, theta, theta, 4.0);
+
+        let small = dd!(1e-300);
+        let (r, theta) = to_polar(small, -small);
+        close(small * Double::SQRT_2, r, r, 2.0);
+        close(-Double::FRAC_PI_4, theta, theta, 4.0);
+
+        let (r, theta) = to_polar(Double::MAX, Double::MAX);
+        assert!(r.is_infinite());
+        close(Double::FRAC_PI_4, theta, theta, 4.0);
+    });
+
+    test!(to_polar_special: {
+        let (r, theta) = to_polar(Double::ZERO, Double::ZERO);
+        assert!(r == Double::ZERO);
+        assert!(theta.is_nan());
+
+        let (r, _) = to_polar(Double::NEG_INFINITY, Double::NAN);
+        assert!(r == Double::INFINITY);
+
+        let (r, theta) = to_polar(Double::INFINITY, dd!(1));
+        assert!(r == Double::INFINITY);
+        assert!(theta == Double::ZERO);
+
+        let (r, theta) = to_polar(Double::NAN, dd!(1));
+        assert!(r.is_nan());
+        assert!(theta.is_nan());
+    });
+
+    test!(from_polar_axes: {
+        let (x, y) = from_polar(dd!(3), Double::ZERO);
+        assert!(x == dd!(3));
+        assert!(y == Double::ZERO);
+
+        let (x, y) = from_polar(dd!(3), Double::FRAC_PI_2);
+        close(Double::ZERO, x, dd!(3), 1.0);
+        close(dd!(3), y, dd!(3), 1.0);
+    });
+
+    test!(round_trip: {
+        // A handful of ULPs is as close as the composition of `atan2`, `sin_cos`, and the
+        // square root can be relied upon to get
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = || {
+            seed = seed
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            dd!((seed >> 11) as f64 / (1u64 << 53) as f64 * 200.0 - 100.0) / dd!(7)
+        };
+        for _ in 0..500 {
+            let (x, y) = (next(), next());
+            let (r, theta) = to_polar(x, y);
+            let (x2, y2) = from_polar(r, theta);
+            close(x, x2, r, 8.0);
+            close(y, y2, r, 8.0);
+        }
+    });
+}
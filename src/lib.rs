@@ -52,10 +52,10 @@
 //!
 //! `qd` provides a pair of macros, [`dd!`][2] and [`qd!`][3], which can be used to create
 //! double-doubles and quad-doubles, respectively. These macros will take any primitive
-//! number type (`dd!` cannot take `u128` or `i128`, as there would be a *loss* of precision
-//! to turn those into double-doubles) or a string containing a number that can be
-//! represented (if the string contains more digits than can be accurately represented by
-//! the type, the extra digits will be ignored).
+//! number type (`dd!` rounds a `u128` or `i128` with more significant bits than a
+//! double-double can hold) or a string containing a number that can be represented (if the
+//! string contains more digits than can be accurately represented by the type, the extra
+//! digits will be ignored).
 //!
 //! Once you have a double-double or a quad-double, you can use it just like you would an
 //! `f64`: all of the mathematical operators work on them, the vast majority of methods
@@ -109,11 +109,34 @@
 #![warn(clippy::all)]
 #![allow(clippy::needless_doctest_main)]
 
+#[cfg(test)]
+#[macro_use]
+mod test_macros;
+
 mod common;
+mod complex;
+mod digits;
 mod double;
+mod dual;
+mod options;
+mod ordered;
 mod quad;
+mod round;
 
 pub mod error;
+pub mod geometry;
+#[doc(hidden)]
+pub mod literal;
 
+pub use self::complex::{ComplexDouble, ComplexQuad};
+pub use self::digits::DecimalDigits;
+#[cfg(feature = "rkyv")]
+pub use self::double::ArchivedDouble;
 pub use self::double::Double;
+pub use self::dual::Dual;
+pub use self::options::{FormatOptions, ParseOptions};
+pub use self::ordered::{NotNanDouble, NotNanQuad, OrderedDouble, OrderedQuad};
+#[cfg(feature = "rkyv")]
+pub use self::quad::ArchivedQuad;
 pub use self::quad::Quad;
+pub use self::round::{RoundMode, RoundingMode};
@@ -0,0 +1,56 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Support for the `dd!` and `qd!` macros, which aren't able to call `from` directly
+//! because there's no `From<&str>` implementation for either type.
+//!
+//! A string that doesn't parse is a bug in the code that wrote the literal, so these
+//! conversions panic instead of returning an error. Strings that come from anywhere else
+//! should go through `TryFrom<&str>` or `parse`.
+
+use crate::double::Double;
+use crate::quad::Quad;
+
+/// Converts the argument of a `dd!` into a `Double`.
+pub trait IntoDouble {
+    fn into_double(self) -> Double;
+}
+
+impl<T: Into<Double>> IntoDouble for T {
+    #[inline]
+    fn into_double(self) -> Double {
+        self.into()
+    }
+}
+
+impl IntoDouble for &str {
+    fn into_double(self) -> Double {
+        match self.parse() {
+            Ok(x) => x,
+            Err(e) => panic!("{}: {:?}", e, self),
+        }
+    }
+}
+
+/// Converts the argument of a `qd!` into a `Quad`.
+pub trait IntoQuad {
+    fn into_quad(self) -> Quad;
+}
+
+impl<T: Into<Quad>> IntoQuad for T {
+    #[inline]
+    fn into_quad(self) -> Quad {
+        self.into()
+    }
+}
+
+impl IntoQuad for &str {
+    fn into_quad(self) -> Quad {
+        match self.parse() {
+            Ok(x) => x,
+            Err(e) => panic!("{}: {:?}", e, self),
+        }
+    }
+}
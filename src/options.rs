@@ -0,0 +1,104 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+/// Options for parsing decimal strings that are written with digit group separators or
+/// with a decimal separator other than a period, as they often are in data files and in
+/// other locales.
+///
+/// These are used by `Double::from_str_with` and `Quad::from_str_with`. The default
+/// options parse exactly the same strings that `parse` does: underscores (which are always
+/// ignored between digits) are the only group separators, and the decimal separator is a
+/// period.
+///
+/// # Examples
+/// ```
+/// # use qd::{dd, Double, ParseOptions};
+/// // A European-style number, with periods between groups and a decimal comma
+/// let options = ParseOptions {
+///     group_separator: Some('.'),
+///     decimal_separator: ',',
+/// };
+/// assert!(Double::from_str_with("1.000.000,5", &options).unwrap() == dd!(1000000.5));
+///
+/// // Commas between groups, as in many English-language data files
+/// let options = ParseOptions {
+///     group_separator: Some(','),
+///     ..ParseOptions::default()
+/// };
+/// assert!(Double::from_str_with("-1,234,567.25", &options).unwrap() == dd!(-1234567.25));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    /// A character that's ignored wherever it appears among the digits, in addition to
+    /// underscores, so that it can be used to separate groups of digits. This is usually
+    /// a comma, a period, a space, or an apostrophe. The default is `None`.
+    ///
+    /// Separators aren't allowed in an exponent. If this is the same character as the
+    /// decimal separator, it's taken to be the decimal separator.
+    pub group_separator: Option<char>,
+    /// The character that separates the integer part of a number from its fractional
+    /// part. The default is a period. When it's something else, a period is an error
+    /// unless it's the group separator.
+    pub decimal_separator: char,
+}
+
+impl Default for ParseOptions {
+    fn default() -> ParseOptions {
+        ParseOptions {
+            group_separator: None,
+            decimal_separator: '.',
+        }
+    }
+}
+
+/// Options for formatting numbers as decimal strings with digit group separators or with a
+/// decimal separator other than a period, as they often are in reports and in other
+/// locales.
+///
+/// These are used by `Double::to_string_with` and `Quad::to_string_with`. The default
+/// options produce exactly the same strings that `to_string` does: no group separators, a
+/// period as the decimal separator, and the default precision of `Display`.
+///
+/// # Examples
+/// ```
+/// # use qd::{dd, FormatOptions};
+/// // A European-style number, with periods between groups and a decimal comma
+/// let options = FormatOptions {
+///     group_separator: Some('.'),
+///     decimal_separator: ',',
+///     precision: Some(2),
+/// };
+/// assert!(dd!(1234567.5).to_string_with(&options) == "1.234.567,50");
+///
+/// // Commas between groups, as in many English-language reports
+/// let options = FormatOptions {
+///     group_separator: Some(','),
+///     ..FormatOptions::default()
+/// };
+/// assert!(dd!(-1234567.25).to_string_with(&options) == "-1,234,567.25");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FormatOptions {
+    /// A character that's put between each group of three digits in the integer part of
+    /// the number, counting from the decimal point. This is usually a comma, a period, a
+    /// space, or an apostrophe. The default is `None`, which doesn't group digits at all.
+    pub group_separator: Option<char>,
+    /// The character that separates the integer part of a number from its fractional
+    /// part. The default is a period.
+    pub decimal_separator: char,
+    /// The number of digits after the decimal point, as with the precision in a format
+    /// string like `{:.2}`. The default is `None`, which prints as many as `Display` does.
+    pub precision: Option<usize>,
+}
+
+impl Default for FormatOptions {
+    fn default() -> FormatOptions {
+        FormatOptions {
+            group_separator: None,
+            decimal_separator: '.',
+            precision: None,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+mod double;
+mod quad;
+
+pub use self::double::{NotNanDouble, OrderedDouble};
+pub use self::quad::{NotNanQuad, OrderedQuad};
@@ -0,0 +1,392 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::double::Double;
+use crate::error::{TryFromDoubleError, TryFromErrorKind};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt::{self, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+/// A [`Double`] with a total order, so that it can be used where `Eq` and `Ord` are
+/// required.
+///
+/// This works like `OrderedFloat` from the `ordered-float` crate. Every `NaN` is equal to
+/// every other `NaN` and greater than any other number, including infinity. Apart from
+/// that, the order is the same as that of the `Double`s themselves, so zero and negative
+/// zero are equal. Hashing agrees with that equality, which makes `OrderedDouble` usable
+/// as a key in a `BTreeMap` or a `HashMap`, as an element of a `BinaryHeap`, and in a `Vec`
+/// that needs to be sorted and deduplicated.
+///
+/// The wrapped `Double` is a public field, and the `OrderedDouble` dereferences to it.
+///
+/// # Examples
+/// ```
+/// # use qd::{dd, Double, OrderedDouble};
+/// use std::collections::BTreeSet;
+///
+/// let set: BTreeSet<_> = vec![dd!(2), Double::NAN, dd!(-1), dd!(0), dd!(-0.0), Double::NAN]
+///     .into_iter()
+///     .map(OrderedDouble)
+///     .collect();
+/// let v: Vec<Double> = set.into_iter().map(|x| x.0).collect();
+///
+/// assert!(v.len() == 4);
+/// assert!(v[0] == dd!(-1) && v[1] == dd!(0) && v[2] == dd!(2));
+/// assert!(v[3].is_nan());
+/// ```
+///
+/// [`Double`]: struct.Double.html
+#[derive(Clone, Copy, Debug)]
+pub struct OrderedDouble(pub Double);
+
+impl OrderedDouble {
+    /// Returns the wrapped `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{Double, OrderedDouble};
+    /// assert!(OrderedDouble(Double::PI).into_inner() == Double::PI);
+    /// ```
+    #[inline]
+    pub const fn into_inner(self) -> Double {
+        self.0
+    }
+}
+
+impl From<Double> for OrderedDouble {
+    /// Wraps a `Double` in an `OrderedDouble`.
+    #[inline]
+    fn from(a: Double) -> OrderedDouble {
+        OrderedDouble(a)
+    }
+}
+
+impl From<OrderedDouble> for Double {
+    /// Unwraps the `Double` in an `OrderedDouble`.
+    #[inline]
+    fn from(a: OrderedDouble) -> Double {
+        a.0
+    }
+}
+
+impl Deref for OrderedDouble {
+    type Target = Double;
+
+    #[inline]
+    fn deref(&self) -> &Double {
+        &self.0
+    }
+}
+
+impl PartialEq for OrderedDouble {
+    /// Compares two `OrderedDouble`s for equality. Unlike with `Double`s, `NaN` is equal to
+    /// itself.
+    #[inline]
+    fn eq(&self, other: &OrderedDouble) -> bool {
+        if self.0.is_nan() {
+            other.0.is_nan()
+        } else {
+            self.0 == other.0
+        }
+    }
+}
+
+impl Eq for OrderedDouble {}
+
+impl PartialOrd for OrderedDouble {
+    #[inline]
+    fn partial_cmp(&self, other: &OrderedDouble) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedDouble {
+    /// Compares two `OrderedDouble`s, putting `NaN` after every other number.
+    fn cmp(&self, other: &OrderedDouble) -> Ordering {
+        match (self.0.is_nan(), other.0.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            // Neither is `NaN`, so they're comparable
+            (false, false) => self.0.partial_cmp(&other.0).unwrap(),
+        }
+    }
+}
+
+impl Hash for OrderedDouble {
+    /// Feeds the `OrderedDouble` into a hasher. Its `Double` already hashes every `NaN` the
+    /// same way, and zero the same as negative zero, which is consistent with `==`.
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl Display for OrderedDouble {
+    /// Formats the wrapped `Double`, with all of the same options.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+/// A [`Double`] that is guaranteed not to be `NaN`, which gives it a total order.
+///
+/// This works like `NotNan` from the `ordered-float` crate. Because it can't hold `NaN`, its
+/// order is just the order of the `Double`s themselves, with zero equal to negative zero,
+/// and it implements `Eq`, `Ord`, and `Hash` (consistently with `==`). It can be used as a
+/// key in a `BTreeMap` or a `HashMap`, as an element of a `BinaryHeap`, and in a `Vec`
+/// that needs to be sorted and deduplicated. Infinities are allowed.
+///
+/// A `NotNanDouble` is created with [`new`] or `try_from`, which fail if the `Double` is
+/// `NaN`. It dereferences to the wrapped `Double`, which [`into_inner`] returns.
+///
+/// # Examples
+/// ```
+/// # use qd::{dd, Double, NotNanDouble};
+/// use std::collections::BinaryHeap;
+///
+/// let mut heap = BinaryHeap::new();
+/// for x in [dd!(2), Double::E, dd!(-1), Double::PI] {
+///     heap.push(NotNanDouble::new(x).unwrap());
+/// }
+/// assert!(heap.pop().unwrap().into_inner() == Double::PI);
+/// assert!(*heap.pop().unwrap() == Double::E);
+///
+/// assert!(NotNanDouble::new(Double::NAN).is_err());
+/// ```
+///
+/// [`Double`]: struct.Double.html
+/// [`new`]: #method.new
+/// [`into_inner`]: #method.into_inner
+#[derive(Clone, Copy, Debug)]
+pub struct NotNanDouble(Double);
+
+impl NotNanDouble {
+    /// Wraps a `Double` in a `NotNanDouble`, or returns an error if it's `NaN`.
+    ///
+    /// The error is the same one that converting `NaN` into an integer produces, with a
+    /// kind of [`TryFromErrorKind::NaN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, NotNanDouble};
+    /// use qd::error::TryFromErrorKind;
+    ///
+    /// assert!(NotNanDouble::new(dd!(1.5)).is_ok());
+    /// assert!(NotNanDouble::new(Double::INFINITY).is_ok());
+    /// assert!(NotNanDouble::new(Double::NAN).unwrap_err().kind == TryFromErrorKind::NaN);
+    /// ```
+    ///
+    /// [`TryFromErrorKind::NaN`]: error/enum.TryFromErrorKind.html#variant.NaN
+    #[inline]
+    pub fn new(a: Double) -> Result<NotNanDouble, TryFromDoubleError> {
+        if a.is_nan() {
+            Err(TryFromDoubleError {
+                kind: TryFromErrorKind::NaN,
+            })
+        } else {
+            Ok(NotNanDouble(a))
+        }
+    }
+
+    /// Returns the wrapped `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{Double, NotNanDouble};
+    /// assert!(NotNanDouble::new(Double::PI).unwrap().into_inner() == Double::PI);
+    /// ```
+    #[inline]
+    pub const fn into_inner(self) -> Double {
+        self.0
+    }
+}
+
+impl TryFrom<Double> for NotNanDouble {
+    type Error = TryFromDoubleError;
+
+    /// Wraps a `Double` in a `NotNanDouble`, or returns an error if it's `NaN`. This is the
+    /// same as [`NotNanDouble::new`].
+    ///
+    /// [`NotNanDouble::new`]: struct.NotNanDouble.html#method.new
+    #[inline]
+    fn try_from(a: Double) -> Result<NotNanDouble, TryFromDoubleError> {
+        NotNanDouble::new(a)
+    }
+}
+
+impl From<NotNanDouble> for Double {
+    /// Unwraps the `Double` in a `NotNanDouble`.
+    #[inline]
+    fn from(a: NotNanDouble) -> Double {
+        a.0
+    }
+}
+
+impl Deref for NotNanDouble {
+    type Target = Double;
+
+    #[inline]
+    fn deref(&self) -> &Double {
+        &self.0
+    }
+}
+
+impl PartialEq for NotNanDouble {
+    #[inline]
+    fn eq(&self, other: &NotNanDouble) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for NotNanDouble {}
+
+impl PartialOrd for NotNanDouble {
+    #[inline]
+    fn partial_cmp(&self, other: &NotNanDouble) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NotNanDouble {
+    #[inline]
+    fn cmp(&self, other: &NotNanDouble) -> Ordering {
+        // Neither can be `NaN`, so they're always comparable
+        self.0.partial_cmp(&other.0).unwrap()
+    }
+}
+
+impl Hash for NotNanDouble {
+    /// Feeds the `NotNanDouble` into a hasher. Zero hashes the same as negative zero, which
+    /// is consistent with `==`.
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl Display for NotNanDouble {
+    /// Formats the wrapped `Double`, with all of the same options.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dd;
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::{BTreeMap, HashSet};
+
+    fn hash_of<T: Hash>(x: T) -> u64 {
+        let mut h = DefaultHasher::new();
+        x.hash(&mut h);
+        h.finish()
+    }
+
+    test!(ordered_eq: {
+        assert!(OrderedDouble(Double::NAN) == OrderedDouble(Double::NAN));
+        assert!(OrderedDouble(Double::NAN) == OrderedDouble(-Double::NAN));
+        assert!(OrderedDouble(Double::ZERO) == OrderedDouble(Double::NEG_ZERO));
+        assert!(OrderedDouble(Double::PI) == OrderedDouble(Double::PI));
+        assert!(OrderedDouble(Double::PI) != OrderedDouble(Double::E));
+        assert!(OrderedDouble(Double::NAN) != OrderedDouble(Double::INFINITY));
+    });
+
+    test!(ordered_cmp: {
+        let nan = OrderedDouble(Double::NAN);
+        let inf = OrderedDouble(Double::INFINITY);
+        assert!(nan.cmp(&inf) == Ordering::Greater);
+        assert!(inf.cmp(&nan) == Ordering::Less);
+        assert!(nan.cmp(&nan) == Ordering::Equal);
+        assert!(OrderedDouble(Double::NEG_INFINITY) < OrderedDouble(dd!(-1)));
+        assert!(OrderedDouble(Double::E) < OrderedDouble(Double::PI));
+        // The second component matters too
+        assert!(OrderedDouble(Double::ONE) < OrderedDouble(Double::ONE + Double::EPSILON));
+        assert!(OrderedDouble(Double::ZERO).cmp(&OrderedDouble(Double::NEG_ZERO)) == Ordering::Equal);
+    });
+
+    test!(ordered_sort_dedup: {
+        let mut v: Vec<_> = vec![
+            Double::NAN,
+            dd!(3),
+            Double::NEG_ZERO,
+            Double::NAN,
+            dd!(-2),
+            Double::ZERO,
+            dd!(3),
+        ]
+        .into_iter()
+        .map(OrderedDouble)
+        .collect();
+        v.sort();
+        v.dedup();
+        assert!(v.len() == 4);
+        assert!(v[0].0 == dd!(-2));
+        assert!(v[1].is_zero());
+        assert!(v[2].0 == dd!(3));
+        assert!(v[3].is_nan());
+    });
+
+    test!(ordered_hash: {
+        assert!(hash_of(OrderedDouble(Double::ZERO)) == hash_of(OrderedDouble(Double::NEG_ZERO)));
+        assert!(hash_of(OrderedDouble(Double::NAN)) == hash_of(OrderedDouble(-Double::NAN)));
+        let set: HashSet<_> = vec![Double::NAN, Double::NAN, Double::PI, Double::PI]
+            .into_iter()
+            .map(OrderedDouble)
+            .collect();
+        assert!(set.len() == 2);
+    });
+
+    test!(ordered_conversions: {
+        let x = OrderedDouble::from(Double::PI);
+        assert!(x.0 == Double::PI);
+        assert!(x.into_inner() == Double::PI);
+        assert!(Double::from(x) == Double::PI);
+        // Methods of `Double` are available through `Deref`
+        assert!(x.floor() == dd!(3));
+        assert!(format!("{:.5}", x) == format!("{:.5}", Double::PI));
+    });
+
+    test!(not_nan_new: {
+        assert!(NotNanDouble::new(Double::PI).unwrap().into_inner() == Double::PI);
+        assert!(NotNanDouble::new(Double::INFINITY).is_ok());
+        assert!(NotNanDouble::new(Double::NEG_INFINITY).is_ok());
+        assert!(NotNanDouble::new(Double::NAN).unwrap_err().kind == TryFromErrorKind::NaN);
+        assert!(NotNanDouble::try_from(Double::E).map(Double::from) == Ok(Double::E));
+        assert!(NotNanDouble::try_from(-Double::NAN).is_err());
+    });
+
+    test!(not_nan_cmp: {
+        let a = NotNanDouble::new(Double::E).unwrap();
+        let b = NotNanDouble::new(Double::PI).unwrap();
+        assert!(a < b);
+        assert!(a.cmp(&b) == Ordering::Less);
+        assert!(a.max(b) == b);
+        let z = NotNanDouble::new(Double::ZERO).unwrap();
+        let nz = NotNanDouble::new(Double::NEG_ZERO).unwrap();
+        assert!(z == nz);
+        assert!(hash_of(z) == hash_of(nz));
+    });
+
+    test!(not_nan_map: {
+        let mut map = BTreeMap::new();
+        for (i, x) in [Double::PI, Double::NEG_INFINITY, dd!(1), Double::E]
+            .iter()
+            .enumerate()
+        {
+            map.insert(NotNanDouble::new(*x).unwrap(), i);
+        }
+        let keys: Vec<Double> = map.keys().map(|k| **k).collect();
+        assert!(keys == vec![Double::NEG_INFINITY, dd!(1), Double::E, Double::PI]);
+        assert!(map[&NotNanDouble::new(dd!(1)).unwrap()] == 2);
+        assert!(format!("{}", NotNanDouble::new(dd!(1.5)).unwrap()) == "1.5");
+    });
+}
@@ -0,0 +1,392 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::error::{TryFromErrorKind, TryFromQuadError};
+use crate::quad::Quad;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt::{self, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+/// A [`Quad`] with a total order, so that it can be used where `Eq` and `Ord` are
+/// required.
+///
+/// This works like `OrderedFloat` from the `ordered-float` crate. Every `NaN` is equal to
+/// every other `NaN` and greater than any other number, including infinity. Apart from
+/// that, the order is the same as that of the `Quad`s themselves, so zero and negative
+/// zero are equal. Hashing agrees with that equality, which makes `OrderedQuad` usable
+/// as a key in a `BTreeMap` or a `HashMap`, as an element of a `BinaryHeap`, and in a `Vec`
+/// that needs to be sorted and deduplicated.
+///
+/// The wrapped `Quad` is a public field, and the `OrderedQuad` dereferences to it.
+///
+/// # Examples
+/// ```
+/// # use qd::{qd, Quad, OrderedQuad};
+/// use std::collections::BTreeSet;
+///
+/// let set: BTreeSet<_> = vec![qd!(2), Quad::NAN, qd!(-1), qd!(0), qd!(-0.0), Quad::NAN]
+///     .into_iter()
+///     .map(OrderedQuad)
+///     .collect();
+/// let v: Vec<Quad> = set.into_iter().map(|x| x.0).collect();
+///
+/// assert!(v.len() == 4);
+/// assert!(v[0] == qd!(-1) && v[1] == qd!(0) && v[2] == qd!(2));
+/// assert!(v[3].is_nan());
+/// ```
+///
+/// [`Quad`]: struct.Quad.html
+#[derive(Clone, Copy, Debug)]
+pub struct OrderedQuad(pub Quad);
+
+impl OrderedQuad {
+    /// Returns the wrapped `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{Quad, OrderedQuad};
+    /// assert!(OrderedQuad(Quad::PI).into_inner() == Quad::PI);
+    /// ```
+    #[inline]
+    pub const fn into_inner(self) -> Quad {
+        self.0
+    }
+}
+
+impl From<Quad> for OrderedQuad {
+    /// Wraps a `Quad` in an `OrderedQuad`.
+    #[inline]
+    fn from(a: Quad) -> OrderedQuad {
+        OrderedQuad(a)
+    }
+}
+
+impl From<OrderedQuad> for Quad {
+    /// Unwraps the `Quad` in an `OrderedQuad`.
+    #[inline]
+    fn from(a: OrderedQuad) -> Quad {
+        a.0
+    }
+}
+
+impl Deref for OrderedQuad {
+    type Target = Quad;
+
+    #[inline]
+    fn deref(&self) -> &Quad {
+        &self.0
+    }
+}
+
+impl PartialEq for OrderedQuad {
+    /// Compares two `OrderedQuad`s for equality. Unlike with `Quad`s, `NaN` is equal to
+    /// itself.
+    #[inline]
+    fn eq(&self, other: &OrderedQuad) -> bool {
+        if self.0.is_nan() {
+            other.0.is_nan()
+        } else {
+            self.0 == other.0
+        }
+    }
+}
+
+impl Eq for OrderedQuad {}
+
+impl PartialOrd for OrderedQuad {
+    #[inline]
+    fn partial_cmp(&self, other: &OrderedQuad) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedQuad {
+    /// Compares two `OrderedQuad`s, putting `NaN` after every other number.
+    fn cmp(&self, other: &OrderedQuad) -> Ordering {
+        match (self.0.is_nan(), other.0.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            // Neither is `NaN`, so they're comparable
+            (false, false) => self.0.partial_cmp(&other.0).unwrap(),
+        }
+    }
+}
+
+impl Hash for OrderedQuad {
+    /// Feeds the `OrderedQuad` into a hasher. Its `Quad` already hashes every `NaN` the
+    /// same way, and zero the same as negative zero, which is consistent with `==`.
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl Display for OrderedQuad {
+    /// Formats the wrapped `Quad`, with all of the same options.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+/// A [`Quad`] that is guaranteed not to be `NaN`, which gives it a total order.
+///
+/// This works like `NotNan` from the `ordered-float` crate. Because it can't hold `NaN`, its
+/// order is just the order of the `Quad`s themselves, with zero equal to negative zero,
+/// and it implements `Eq`, `Ord`, and `Hash` (consistently with `==`). It can be used as a
+/// key in a `BTreeMap` or a `HashMap`, as an element of a `BinaryHeap`, and in a `Vec`
+/// that needs to be sorted and deduplicated. Infinities are allowed.
+///
+/// A `NotNanQuad` is created with [`new`] or `try_from`, which fail if the `Quad` is
+/// `NaN`. It dereferences to the wrapped `Quad`, which [`into_inner`] returns.
+///
+/// # Examples
+/// ```
+/// # use qd::{qd, Quad, NotNanQuad};
+/// use std::collections::BinaryHeap;
+///
+/// let mut heap = BinaryHeap::new();
+/// for x in [qd!(2), Quad::E, qd!(-1), Quad::PI] {
+///     heap.push(NotNanQuad::new(x).unwrap());
+/// }
+/// assert!(heap.pop().unwrap().into_inner() == Quad::PI);
+/// assert!(*heap.pop().unwrap() == Quad::E);
+///
+/// assert!(NotNanQuad::new(Quad::NAN).is_err());
+/// ```
+///
+/// [`Quad`]: struct.Quad.html
+/// [`new`]: #method.new
+/// [`into_inner`]: #method.into_inner
+#[derive(Clone, Copy, Debug)]
+pub struct NotNanQuad(Quad);
+
+impl NotNanQuad {
+    /// Wraps a `Quad` in a `NotNanQuad`, or returns an error if it's `NaN`.
+    ///
+    /// The error is the same one that converting `NaN` into an integer produces, with a
+    /// kind of [`TryFromErrorKind::NaN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad, NotNanQuad};
+    /// use qd::error::TryFromErrorKind;
+    ///
+    /// assert!(NotNanQuad::new(qd!(1.5)).is_ok());
+    /// assert!(NotNanQuad::new(Quad::INFINITY).is_ok());
+    /// assert!(NotNanQuad::new(Quad::NAN).unwrap_err().kind == TryFromErrorKind::NaN);
+    /// ```
+    ///
+    /// [`TryFromErrorKind::NaN`]: error/enum.TryFromErrorKind.html#variant.NaN
+    #[inline]
+    pub fn new(a: Quad) -> Result<NotNanQuad, TryFromQuadError> {
+        if a.is_nan() {
+            Err(TryFromQuadError {
+                kind: TryFromErrorKind::NaN,
+            })
+        } else {
+            Ok(NotNanQuad(a))
+        }
+    }
+
+    /// Returns the wrapped `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{Quad, NotNanQuad};
+    /// assert!(NotNanQuad::new(Quad::PI).unwrap().into_inner() == Quad::PI);
+    /// ```
+    #[inline]
+    pub const fn into_inner(self) -> Quad {
+        self.0
+    }
+}
+
+impl TryFrom<Quad> for NotNanQuad {
+    type Error = TryFromQuadError;
+
+    /// Wraps a `Quad` in a `NotNanQuad`, or returns an error if it's `NaN`. This is the
+    /// same as [`NotNanQuad::new`].
+    ///
+    /// [`NotNanQuad::new`]: struct.NotNanQuad.html#method.new
+    #[inline]
+    fn try_from(a: Quad) -> Result<NotNanQuad, TryFromQuadError> {
+        NotNanQuad::new(a)
+    }
+}
+
+impl From<NotNanQuad> for Quad {
+    /// Unwraps the `Quad` in a `NotNanQuad`.
+    #[inline]
+    fn from(a: NotNanQuad) -> Quad {
+        a.0
+    }
+}
+
+impl Deref for NotNanQuad {
+    type Target = Quad;
+
+    #[inline]
+    fn deref(&self) -> &Quad {
+        &self.0
+    }
+}
+
+impl PartialEq for NotNanQuad {
+    #[inline]
+    fn eq(&self, other: &NotNanQuad) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for NotNanQuad {}
+
+impl PartialOrd for NotNanQuad {
+    #[inline]
+    fn partial_cmp(&self, other: &NotNanQuad) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NotNanQuad {
+    #[inline]
+    fn cmp(&self, other: &NotNanQuad) -> Ordering {
+        // Neither can be `NaN`, so they're always comparable
+        self.0.partial_cmp(&other.0).unwrap()
+    }
+}
+
+impl Hash for NotNanQuad {
+    /// Feeds the `NotNanQuad` into a hasher. Zero hashes the same as negative zero, which
+    /// is consistent with `==`.
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl Display for NotNanQuad {
+    /// Formats the wrapped `Quad`, with all of the same options.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::qd;
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::{BTreeMap, HashSet};
+
+    fn hash_of<T: Hash>(x: T) -> u64 {
+        let mut h = DefaultHasher::new();
+        x.hash(&mut h);
+        h.finish()
+    }
+
+    test!(ordered_eq: {
+        assert!(OrderedQuad(Quad::NAN) == OrderedQuad(Quad::NAN));
+        assert!(OrderedQuad(Quad::NAN) == OrderedQuad(-Quad::NAN));
+        assert!(OrderedQuad(Quad::ZERO) == OrderedQuad(Quad::NEG_ZERO));
+        assert!(OrderedQuad(Quad::PI) == OrderedQuad(Quad::PI));
+        assert!(OrderedQuad(Quad::PI) != OrderedQuad(Quad::E));
+        assert!(OrderedQuad(Quad::NAN) != OrderedQuad(Quad::INFINITY));
+    });
+
+    test!(ordered_cmp: {
+        let nan = OrderedQuad(Quad::NAN);
+        let inf = OrderedQuad(Quad::INFINITY);
+        assert!(nan.cmp(&inf) == Ordering::Greater);
+        assert!(inf.cmp(&nan) == Ordering::Less);
+        assert!(nan.cmp(&nan) == Ordering::Equal);
+        assert!(OrderedQuad(Quad::NEG_INFINITY) < OrderedQuad(qd!(-1)));
+        assert!(OrderedQuad(Quad::E) < OrderedQuad(Quad::PI));
+        // The second component matters too
+        assert!(OrderedQuad(Quad::ONE) < OrderedQuad(Quad::ONE + Quad::EPSILON));
+        assert!(OrderedQuad(Quad::ZERO).cmp(&OrderedQuad(Quad::NEG_ZERO)) == Ordering::Equal);
+    });
+
+    test!(ordered_sort_dedup: {
+        let mut v: Vec<_> = vec![
+            Quad::NAN,
+            qd!(3),
+            Quad::NEG_ZERO,
+            Quad::NAN,
+            qd!(-2),
+            Quad::ZERO,
+            qd!(3),
+        ]
+        .into_iter()
+        .map(OrderedQuad)
+        .collect();
+        v.sort();
+        v.dedup();
+        assert!(v.len() == 4);
+        assert!(v[0].0 == qd!(-2));
+        assert!(v[1].is_zero());
+        assert!(v[2].0 == qd!(3));
+        assert!(v[3].is_nan());
+    });
+
+    test!(ordered_hash: {
+        assert!(hash_of(OrderedQuad(Quad::ZERO)) == hash_of(OrderedQuad(Quad::NEG_ZERO)));
+        assert!(hash_of(OrderedQuad(Quad::NAN)) == hash_of(OrderedQuad(-Quad::NAN)));
+        let set: HashSet<_> = vec![Quad::NAN, Quad::NAN, Quad::PI, Quad::PI]
+            .into_iter()
+            .map(OrderedQuad)
+            .collect();
+        assert!(set.len() == 2);
+    });
+
+    test!(ordered_conversions: {
+        let x = OrderedQuad::from(Quad::PI);
+        assert!(x.0 == Quad::PI);
+        assert!(x.into_inner() == Quad::PI);
+        assert!(Quad::from(x) == Quad::PI);
+        // Methods of `Quad` are available through `Deref`
+        assert!(x.floor() == qd!(3));
+        assert!(format!("{:.5}", x) == format!("{:.5}", Quad::PI));
+    });
+
+    test!(not_nan_new: {
+        assert!(NotNanQuad::new(Quad::PI).unwrap().into_inner() == Quad::PI);
+        assert!(NotNanQuad::new(Quad::INFINITY).is_ok());
+        assert!(NotNanQuad::new(Quad::NEG_INFINITY).is_ok());
+        assert!(NotNanQuad::new(Quad::NAN).unwrap_err().kind == TryFromErrorKind::NaN);
+        assert!(NotNanQuad::try_from(Quad::E).map(Quad::from) == Ok(Quad::E));
+        assert!(NotNanQuad::try_from(-Quad::NAN).is_err());
+    });
+
+    test!(not_nan_cmp: {
+        let a = NotNanQuad::new(Quad::E).unwrap();
+        let b = NotNanQuad::new(Quad::PI).unwrap();
+        assert!(a < b);
+        assert!(a.cmp(&b) == Ordering::Less);
+        assert!(a.max(b) == b);
+        let z = NotNanQuad::new(Quad::ZERO).unwrap();
+        let nz = NotNanQuad::new(Quad::NEG_ZERO).unwrap();
+        assert!(z == nz);
+        assert!(hash_of(z) == hash_of(nz));
+    });
+
+    test!(not_nan_map: {
+        let mut map = BTreeMap::new();
+        for (i, x) in [Quad::PI, Quad::NEG_INFINITY, qd!(1), Quad::E]
+            .iter()
+            .enumerate()
+        {
+            map.insert(NotNanQuad::new(*x).unwrap(), i);
+        }
+        let keys: Vec<Quad> = map.keys().map(|k| **k).collect();
+        assert!(keys == vec![Quad::NEG_INFINITY, qd!(1), Quad::E, Quad::PI]);
+        assert!(map[&NotNanQuad::new(qd!(1)).unwrap()] == 2);
+        assert!(format!("{}", NotNanQuad::new(qd!(1.5)).unwrap()) == "1.5");
+    });
+}
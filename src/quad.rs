@@ -9,10 +9,14 @@ use std::ops::Index;
 mod macros {
     /// Creates a new quad-double from another number or from a string.
     ///
-    /// The argument can be any expression that evaluates to a type that this library
-    /// defines a `From` implementation for. This includes `&str`, `Double`, `Quad`, any
+    /// The argument can be a `&str` or any expression that evaluates to a type that this
+    /// library defines a `From` implementation for. This includes `Double`, `Quad`, any
     /// primitive number, and 2-, 3-, and 4-tuples of any of those primitive number types.
     ///
+    /// A string is parsed, and the macro panics if it isn't a valid number. That makes it
+    /// fine for literals written into the code but not for strings from anywhere else; use
+    /// `try_from` or `parse` for those, which return an error instead.
+    ///
     /// # Examples
     /// ```
     /// # use qd::{qd, Quad};
@@ -26,7 +30,7 @@ mod macros {
     #[macro_export]
     macro_rules! qd {
         ($x:expr) => {
-            $crate::Quad::from($x)
+            $crate::literal::IntoQuad::into_quad($x)
         };
     }
 }
@@ -38,21 +42,52 @@ mod test_macros;
 mod aconsts; // "a" for "associated", or maybe just to make it appear first in docs
 mod add;
 mod alg;
+#[cfg(feature = "approx")]
+mod approx;
+#[cfg(feature = "rkyv")]
+mod archive;
+#[cfg(feature = "ndarray")]
+mod array;
+#[cfg(feature = "num-bigint")]
+mod bid;
+mod binary128;
+#[cfg(feature = "borsh")]
+mod borsh;
+mod bytes;
 mod common;
 mod comp;
+#[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+mod decimal;
 mod display;
 mod div;
+#[cfg(feature = "simba")]
+mod field;
 mod from;
 mod from_str;
 mod hyper;
 mod iter;
 mod misc;
+#[cfg(feature = "rug")]
+mod mpfr;
 mod mul;
 mod neg;
+#[cfg(feature = "num-traits")]
+mod num;
+#[cfg(feature = "postgres")]
+mod postgres;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "num-rational")]
+mod ratio;
 mod rem;
+#[cfg(feature = "serde")]
+mod serde;
+mod stats;
 mod sub;
 mod trans;
 mod trig;
+#[cfg(feature = "twofloat")]
+mod twofloat;
 
 /// A 256-bit floating-point number implemented as the unevaluated sum of four 64-bit
 /// floating-point numbers. Discarding the bits used for exponents, this makes for about
@@ -83,6 +118,14 @@ mod trig;
 /// Parsing from strings or from numbers that are not dyadic cannot take these shortcuts.
 /// The results will be precise, but at the cost of speed.
 ///
+/// In memory, a `Quad` is laid out exactly as a C struct of its four `f64` components in
+/// order, with no padding (it's `#[repr(C)]`), for a total of 32 bytes aligned to 8. That
+/// means that arrays and slices of them can be handed to anything that expects that layout,
+/// like GPU buffers, memory-mapped files, and FFI structs, without converting them one by
+/// one. The `bytemuck` and `zerocopy` features implement those crates' traits to make
+/// this safe. For files and network protocols that need a layout that doesn't depend on the
+/// machine, [`to_le_bytes`] and [`to_be_bytes`] give the same bytes in a fixed byte order.
+///
 /// See the [module-level documentation](index.html) for more information.
 ///
 /// [`new`]: #method.new
@@ -91,7 +134,24 @@ mod trig;
 /// [`from_str`]: #method.from_str
 /// [`qd!`]: macro.qd.html
 /// [*dyadic*]: https://en.wikipedia.org/wiki/Dyadic_rational
+/// [`to_le_bytes`]: #method.to_le_bytes
+/// [`to_be_bytes`]: #method.to_be_bytes
 #[derive(Clone, Copy)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Clone, Copy, Debug))
+)]
+#[repr(C)]
 pub struct Quad(f64, f64, f64, f64);
 
 impl Quad {
@@ -116,6 +176,128 @@ impl Quad {
     pub const fn new(a: f64, b: f64, c: f64, d: f64) -> Quad {
         Quad(a, b, c, d)
     }
+
+    /// Returns the four components of the `Quad` as an array.
+    ///
+    /// The components are in the same order as they are in the `Quad`, from the largest in
+    /// magnitude to the smallest, and they are exactly the same values that [`from_array`]
+    /// will turn back into the same `Quad`. This is meant for handing `Quad`s to code that
+    /// works on flat buffers of `f64`s, like FFI and GPU code.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let a = Quad::PI.to_array();
+    /// assert!(a[0] == 3.141592653589793e0);
+    /// assert!(a[1] == 1.2246467991473532e-16);
+    /// assert!(a[2] == -2.9947698097183397e-33);
+    /// assert!(a[3] == 1.1124542208633655e-49);
+    /// ```
+    ///
+    /// [`from_array`]: #method.from_array
+    pub const fn to_array(self) -> [f64; 4] {
+        [self.0, self.1, self.2, self.3]
+    }
+
+    /// Creates a `Quad` with the four elements of the array as the internal components.
+    ///
+    /// Like [`new`], this does not normalize its components, and the same warning applies.
+    /// It's meant for reconstructing a `Quad` from the array returned by [`to_array`],
+    /// which it does exactly.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let a = Quad::PI.to_array();
+    /// assert!(Quad::from_array(a) == Quad::PI);
+    /// ```
+    ///
+    /// [`new`]: #method.new
+    /// [`to_array`]: #method.to_array
+    pub const fn from_array(a: [f64; 4]) -> Quad {
+        Quad(a[0], a[1], a[2], a[3])
+    }
+
+    /// Returns the four components of the `Quad` as a tuple.
+    ///
+    /// This is the same as [`to_array`], except for the type of the result, and the same as
+    /// converting the `Quad` into a 4-tuple. It makes it easy to take a `Quad` apart into
+    /// named variables.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let (a, b, c, d) = Quad::PI.as_tuple();
+    /// assert!(a == 3.141592653589793e0);
+    /// assert!(b == 1.2246467991473532e-16);
+    /// assert!(c == -2.9947698097183397e-33);
+    /// assert!(d == 1.1124542208633655e-49);
+    /// ```
+    ///
+    /// [`to_array`]: #method.to_array
+    pub const fn as_tuple(self) -> (f64, f64, f64, f64) {
+        (self.0, self.1, self.2, self.3)
+    }
+
+    /// Returns the first component of the `Quad`.
+    ///
+    /// This is the largest component, and it's the `Quad` rounded to the nearest `f64`.
+    /// It's the same as `self[0]`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::PI.c0() == Quad::PI[0]);
+    /// ```
+    pub const fn c0(self) -> f64 {
+        self.0
+    }
+
+    /// Returns the second component of the `Quad`.
+    ///
+    /// This is the next component after [`c0`], which holds the rounding error of that
+    /// one. It's the same as `self[1]`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::PI.c1() == Quad::PI[1]);
+    /// ```
+    ///
+    /// [`c0`]: #method.c0
+    pub const fn c1(self) -> f64 {
+        self.1
+    }
+
+    /// Returns the third component of the `Quad`.
+    ///
+    /// This is the component after [`c1`], which holds what's left of the rounding error
+    /// of the first two. It's the same as `self[2]`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::PI.c2() == Quad::PI[2]);
+    /// ```
+    ///
+    /// [`c1`]: #method.c1
+    pub const fn c2(self) -> f64 {
+        self.2
+    }
+
+    /// Returns the fourth component of the `Quad`.
+    ///
+    /// This is the last and smallest component, which holds what's left of the rounding
+    /// error of the other three. It's the same as `self[3]`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::PI.c3() == Quad::PI[3]);
+    /// ```
+    pub const fn c3(self) -> f64 {
+        self.3
+    }
 }
 
 impl Index<usize> for Quad {
@@ -176,4 +358,65 @@ mod tests {
         index_two: Quad::PI[2], Quad::PI.2;
         index_three: Quad::PI[3], Quad::PI.3;
     );
+
+    test!(to_array: {
+        let a = Quad::PI.to_array();
+        exact!(a[0], Quad::PI.0);
+        exact!(a[1], Quad::PI.1);
+        exact!(a[2], Quad::PI.2);
+        exact!(a[3], Quad::PI.3);
+        for i in 1..4 {
+            assert!(a[i - 1].abs() > a[i].abs());
+        }
+    });
+
+    test!(array_round_trip: {
+        let special = [
+            Quad::PI,
+            Quad::E,
+            Quad::NEG_ZERO,
+            Quad::NAN,
+            Quad::INFINITY,
+            Quad::NEG_INFINITY,
+            Quad::new(0.0, 10.0, -3.0, 5.0),
+        ];
+        for q in special {
+            let r = Quad::from_array(q.to_array());
+            for i in 0..4 {
+                assert!(r[i].to_bits() == q[i].to_bits(), "component {} of {:?}", i, q);
+            }
+        }
+    });
+
+    test!(as_tuple: {
+        let (a, b, c, d) = Quad::PI.as_tuple();
+        exact!(a, Quad::PI.0);
+        exact!(b, Quad::PI.1);
+        exact!(c, Quad::PI.2);
+        exact!(d, Quad::PI.3);
+        assert!(Quad::PI.as_tuple() == <(f64, f64, f64, f64)>::from(Quad::PI));
+    });
+
+    test_all_exact!(
+        c0: Quad::E.c0(), Quad::E.0;
+        c1: Quad::E.c1(), Quad::E.1;
+        c2: Quad::E.c2(), Quad::E.2;
+        c3: Quad::E.c3(), Quad::E.3;
+    );
+    test_all_assert!(
+        c0_neg_zero: Quad::NEG_ZERO.c0().is_sign_negative();
+        c0_nan: Quad::NAN.c0().is_nan();
+    );
+
+    test!(layout: {
+        use std::mem;
+        assert!(mem::size_of::<Quad>() == 32);
+        assert!(mem::align_of::<Quad>() == 8);
+        let x = Quad::PI;
+        let base = &x as *const Quad as usize;
+        assert!(&x.0 as *const f64 as usize - base == 0);
+        assert!(&x.1 as *const f64 as usize - base == 8);
+        assert!(&x.2 as *const f64 as usize - base == 16);
+        assert!(&x.3 as *const f64 as usize - base == 24);
+    });
 }
@@ -3,8 +3,13 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::binary128 as b;
+use crate::common::primitive as p;
 use crate::common::utils as u;
+use crate::double::Double;
+use crate::quad::common as c;
 use crate::quad::Quad;
+use crate::round::RoundMode;
 use std::ops::{Add, AddAssign};
 
 // Utility function that returns the quad component with the specified index and then
@@ -40,66 +45,7 @@ impl Add for Quad {
     fn add(self, other: Quad) -> Quad {
         match self.pre_add(&other) {
             Some(r) => r,
-            None => {
-                let mut i = 0;
-                let mut j = 0;
-                let mut k = 0;
-
-                let mut x = [0.0; 4];
-
-                // These two assignments, along with the reassignments of the same variables
-                // in the `accumulate` call below, act as a merge sort. The largest
-                // component between the two quads is operated on first, then the second
-                // largest, and so on.
-                let u = if self[i].abs() > other[j].abs() {
-                    index_and_inc(self, &mut i)
-                } else {
-                    index_and_inc(other, &mut j)
-                };
-                let v = if self[i].abs() > other[j].abs() {
-                    index_and_inc(self, &mut i)
-                } else {
-                    index_and_inc(other, &mut j)
-                };
-                let (mut u, mut v) = u::renorm2(u, v);
-
-                while k < 4 {
-                    if i >= 4 && j >= 4 {
-                        x[k] = u;
-                        if k < 3 {
-                            k += 1;
-                            x[k] = v;
-                        }
-                        break;
-                    }
-
-                    let t = if i >= 4 {
-                        index_and_inc(other, &mut j)
-                    } else if j >= 4 || self[i].abs() > other[j].abs() {
-                        index_and_inc(self, &mut i)
-                    } else {
-                        index_and_inc(other, &mut j)
-                    };
-
-                    let (s, y, z) = u::accumulate(u, v, t);
-                    u = y;
-                    v = z;
-
-                    if s != 0.0 {
-                        x[k] = s;
-                        k += 1;
-                    }
-                }
-
-                for k in i..4 {
-                    x[3] += self[k];
-                }
-                for k in j..4 {
-                    x[3] += other[k];
-                }
-                let (a, b, c, d) = u::renorm4(x[0], x[1], x[2], x[3]);
-                Quad(a, b, c, d)
-            }
+            None => self.add_unchecked(other),
         }
     }
 }
@@ -222,6 +168,406 @@ impl AddAssign<&Quad> for Quad {
     }
 }
 
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Add<f64> for Quad {
+    type Output = Quad;
+
+    /// Adds an `f64` to this `Quad`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `+` operator between a `Quad` and an `f64`.
+    ///
+    /// The `f64` is taken at its exact binary value, as with `Quad::from((x, 0.0, 0.0,
+    /// 0.0))`, and is worked directly into the components rather than first being promoted
+    /// to a `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::PI + 1.5;
+    /// let expected = qd!("4.641592653589793238462643383279502884197169399375105820974944592");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    fn add(self, other: f64) -> Quad {
+        match self.pre_add(&Quad(other, 0.0, 0.0, 0.0)) {
+            Some(r) => r,
+            None => {
+                // The `f64` is added to the first component, and then each error term is
+                // carried down into the next component
+                let (s0, e0) = p::two_sum(self.0, other);
+                let (s1, e1) = p::two_sum(self.1, e0);
+                let (s2, e2) = p::two_sum(self.2, e1);
+                let (s3, e3) = p::two_sum(self.3, e2);
+                let (a, b, c, d) = u::renorm5(s0, s1, s2, s3, e3);
+                Quad(a, b, c, d)
+            }
+        }
+    }
+}
+
+impl Add<f64> for &Quad {
+    type Output = Quad;
+
+    /// Adds an `f64` to a reference to this `Quad`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `+` operator between a reference to a `Quad` and an `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = &Quad::PI + 1.5;
+    /// let expected = qd!("4.641592653589793238462643383279502884197169399375105820974944592");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn add(self, other: f64) -> Quad {
+        (*self).add(other)
+    }
+}
+
+impl Add<Quad> for f64 {
+    type Output = Quad;
+
+    /// Adds this `f64` to a `Quad`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `+` operator between an `f64` and a `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = 1.5 + Quad::PI;
+    /// let expected = qd!("4.641592653589793238462643383279502884197169399375105820974944592");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn add(self, other: Quad) -> Quad {
+        other.add(self)
+    }
+}
+
+impl Add<&Quad> for f64 {
+    type Output = Quad;
+
+    /// Adds this `f64` to a reference to a `Quad`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `+` operator between an `f64` and a reference to a `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = 1.5 + &Quad::PI;
+    /// let expected = qd!("4.641592653589793238462643383279502884197169399375105820974944592");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn add(self, other: &Quad) -> Quad {
+        self.add(*other)
+    }
+}
+
+impl AddAssign<f64> for Quad {
+    /// Adds an `f64` to this `Quad`, modifying this one to equal the result.
+    ///
+    /// This implements the `+=` operator between a `Quad` and an `f64`. As with the
+    /// non-assigning operator, the `f64` is taken at its exact binary value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let mut x = Quad::PI;
+    /// x += 1.5;
+    /// let expected = qd!("4.641592653589793238462643383279502884197169399375105820974944592");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: f64) {
+        let r = self.add(other);
+        self.0 = r.0;
+        self.1 = r.1;
+        self.2 = r.2;
+        self.3 = r.3;
+    }
+}
+
+impl Add<Double> for Quad {
+    type Output = Quad;
+
+    /// Adds a `Double` to this `Quad`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `+` operator between a `Quad` and a `Double`. The `Double` is
+    /// promoted to a `Quad` first, which is exact.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Double, Quad};
+    /// let x = Quad::E + Double::PI;
+    /// let expected = qd!("5.8598744820488384738229308546321683767242262114146200375361800955");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn add(self, other: Double) -> Quad {
+        self.add(Quad::from(other))
+    }
+}
+
+impl Add<Quad> for Double {
+    type Output = Quad;
+
+    /// Adds this `Double` to a `Quad`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `+` operator between a `Double` and a `Quad`. The `Double` is
+    /// promoted to a `Quad` first, which is exact.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Double, Quad};
+    /// let x = Double::PI + Quad::E;
+    /// let expected = qd!("5.8598744820488384738229308546321683767242262114146200375361800955");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn add(self, other: Quad) -> Quad {
+        Quad::from(self).add(other)
+    }
+}
+
+impl AddAssign<Double> for Quad {
+    /// Adds a `Double` to this `Quad`, modifying this one to equal the result.
+    ///
+    /// This implements the `+=` operator between a `Quad` and a `Double`. As with the
+    /// non-assigning operator, the `Double` is promoted to a `Quad` first.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Double, Quad};
+    /// let mut x = Quad::E;
+    /// x += Double::PI;
+    /// let expected = qd!("5.8598744820488384738229308546321683767242262114146200375361800955");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: Double) {
+        self.add_assign(Quad::from(other));
+    }
+}
+
+macro_rules! add_int_impl {
+    ($($t:ty)*) => ($(
+        impl Add<$t> for Quad {
+            type Output = Quad;
+
+            /// Adds an integer to this `Quad`, producing a new `Quad` as a result.
+            ///
+            /// This implements the `+` operator between a `Quad` and an integer. Every
+            /// integer of this type converts exactly to an `f64`, so this takes the same
+            /// fast path as the `f64` operator.
+            #[inline]
+            fn add(self, other: $t) -> Quad {
+                self.add(f64::from(other))
+            }
+        }
+
+        impl Add<$t> for &Quad {
+            type Output = Quad;
+
+            /// Adds an integer to a reference to this `Quad`, producing a new `Quad` as a
+            /// result.
+            ///
+            /// This implements the `+` operator between a reference to a `Quad` and an
+            /// integer.
+            #[inline]
+            fn add(self, other: $t) -> Quad {
+                (*self).add(f64::from(other))
+            }
+        }
+
+        impl Add<Quad> for $t {
+            type Output = Quad;
+
+            /// Adds this integer to a `Quad`, producing a new `Quad` as a result.
+            ///
+            /// This implements the `+` operator between an integer and a `Quad`.
+            #[inline]
+            fn add(self, other: Quad) -> Quad {
+                f64::from(self).add(other)
+            }
+        }
+
+        impl AddAssign<$t> for Quad {
+            /// Adds an integer to this `Quad`, modifying this one to equal the result.
+            ///
+            /// This implements the `+=` operator between a `Quad` and an integer.
+            #[inline]
+            fn add_assign(&mut self, other: $t) {
+                self.add_assign(f64::from(other));
+            }
+        }
+    )*);
+}
+
+add_int_impl! { i32 u32 }
+
+impl Quad {
+    /// Adds this `Quad` to another, returning `None` if the sum overflows.
+    ///
+    /// This is the same as the `+` operator, except that a sum that is infinite or `NaN` is
+    /// turned into `None` when both `Quad`s were finite. That makes it possible to catch an
+    /// overflow where it happens, instead of finding an infinity or a `NaN` in the result
+    /// of a long calculation. Arguments that are already infinite or `NaN` still produce
+    /// `Some`, with the same value that the `+` operator would produce.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(2).checked_add(qd!(3)) == Some(qd!(5)));
+    /// assert!(Quad::MAX.checked_add(Quad::MAX) == None);
+    /// assert!(Quad::INFINITY.checked_add(qd!(1)) == Some(Quad::INFINITY));
+    /// ```
+    pub fn checked_add(self, other: Quad) -> Option<Quad> {
+        let r = self + other;
+        if r.is_finite() || !(self.is_finite() && other.is_finite()) {
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    /// Adds this `Quad` to another without checking for special cases first.
+    ///
+    /// The `+` operator checks both `Quad`s for infinities and `NaN` before it adds them,
+    /// so that it can give the right results for them. This function skips those checks,
+    /// which makes it a little faster in inner loops where the arguments are known to be
+    /// finite. It isn't unsafe, but if either `Quad` is infinite or `NaN`, the result is
+    /// meaningless. For finite `Quad`s, it's exactly the same as `+`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::PI.add_unchecked(Quad::E) == Quad::PI + Quad::E);
+    /// ```
+    pub fn add_unchecked(self, other: Quad) -> Quad {
+        let mut i = 0;
+        let mut j = 0;
+        let mut k = 0;
+
+        let mut x = [0.0; 4];
+
+        // These two assignments, along with the reassignments of the same variables
+        // in the `accumulate` call below, act as a merge sort. The largest
+        // component between the two quads is operated on first, then the second
+        // largest, and so on.
+        let u = if self[i].abs() > other[j].abs() {
+            index_and_inc(self, &mut i)
+        } else {
+            index_and_inc(other, &mut j)
+        };
+        let v = if self[i].abs() > other[j].abs() {
+            index_and_inc(self, &mut i)
+        } else {
+            index_and_inc(other, &mut j)
+        };
+        let (mut u, mut v) = u::renorm2(u, v);
+
+        while k < 4 {
+            if i >= 4 && j >= 4 {
+                x[k] = u;
+                if k < 3 {
+                    k += 1;
+                    x[k] = v;
+                }
+                break;
+            }
+
+            let t = if i >= 4 {
+                index_and_inc(other, &mut j)
+            } else if j >= 4 || self[i].abs() > other[j].abs() {
+                index_and_inc(self, &mut i)
+            } else {
+                index_and_inc(other, &mut j)
+            };
+
+            let (s, y, z) = u::accumulate(u, v, t);
+            u = y;
+            v = z;
+
+            if s != 0.0 {
+                x[k] = s;
+                k += 1;
+            }
+        }
+
+        for k in i..4 {
+            x[3] += self[k];
+        }
+        for k in j..4 {
+            x[3] += other[k];
+        }
+        let (a, b, c, d) = u::renorm4(x[0], x[1], x[2], x[3]);
+        Quad(a, b, c, d)
+    }
+
+    /// Adds this `Quad` to another, rounding the sum in the direction given by `mode`.
+    ///
+    /// The sum is first calculated as it is by the `+` operator. It's then compared
+    /// exactly with the sum of the components of both `Quad`s, and if it's on the wrong
+    /// side of that for `mode`, it's moved a step at a time until it isn't. Each step adds
+    /// 2⁻²⁰⁹ times the binary magnitude of the first component (or the last bit of the last
+    /// component, if that's larger) to the last component, which always changes its value,
+    /// and the steps start doubling if it takes more than a few. The result with
+    /// [`RoundMode::Up`] is never less than the exact sum and the result with
+    /// [`RoundMode::Down`] is never greater, so the two always bracket it.
+    /// [`RoundMode::Nearest`] gives the same result as `+`.
+    ///
+    /// If either `Quad` is infinite or `NaN`, the result is the same as it is with `+`.
+    /// When a sum of finite `Quad`s overflows, the result is an infinity if `mode`
+    /// rounds it away from zero and [`MAX`] or [`MIN`] if `mode` rounds it toward zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad, RoundMode};
+    /// let x = qd!(1) / qd!(3);
+    /// let y = qd!(1) / qd!(7e40);
+    /// assert!(x.add_round(y, RoundMode::Up) > x.add_round(y, RoundMode::Down));
+    ///
+    /// // Exact sums aren't moved
+    /// assert!(qd!(1.5).add_round(qd!(2.25), RoundMode::Down) == qd!(3.75));
+    /// ```
+    ///
+    /// [`MAX`]: #associatedconstant.MAX
+    /// [`MIN`]: #associatedconstant.MIN
+    /// [`RoundMode::Up`]: enum.RoundMode.html#variant.Up
+    /// [`RoundMode::Down`]: enum.RoundMode.html#variant.Down
+    /// [`RoundMode::Nearest`]: enum.RoundMode.html#variant.Nearest
+    pub fn add_round(self, other: Quad, mode: RoundMode) -> Quad {
+        let r = self + other;
+        if !(self.is_finite() && other.is_finite()) {
+            return r;
+        }
+        c::round_directed(r, mode, |x| {
+            let terms = [
+                x.0, x.1, x.2, x.3, -self.0, -self.1, -self.2, -self.3, -other.0, -other.1,
+                -other.2, -other.3,
+            ];
+            b::sum_sign(&terms, &[])
+        })
+    }
+}
+
 impl Quad {
     // Precalc functions
     //
@@ -269,6 +615,7 @@ impl Quad {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::f64;
 
     // add tests
     test_all_near!(
@@ -360,6 +707,197 @@ mod tests {
             Quad::NAN + Quad::NAN;
     );
 
+    // mixed tests
+    test_all_near!(
+        quad_double:
+            qd!("5.859874482048838473822930854632168376724226211414620037536180095510"),
+            Quad::E + Double::PI;
+        double_quad:
+            qd!("5.859874482048838473822930854632168376724226211414620037536180095510"),
+            Double::PI + Quad::E;
+    );
+    test_all_exact!(
+        quad_double_inf:
+            Quad::INFINITY,
+            Quad::INFINITY + Double::ONE;
+        double_quad_inf:
+            Quad::INFINITY,
+            Double::INFINITY + Quad::ONE;
+        quad_double_nan:
+            Quad::NAN,
+            Quad::ONE + Double::NAN;
+        double_quad_nan:
+            Quad::NAN,
+            Double::NAN + Quad::ONE;
+    );
+
+    // f64 tests
+    test_all_exact!(
+        f64_int:
+            qd!(5),
+            qd!(3) + 2.0;
+        f64_rev_int:
+            qd!(5),
+            2.0 + qd!(3);
+        f64_id:
+            Quad::PI,
+            Quad::PI + 0.0;
+        f64_inf:
+            Quad::INFINITY,
+            Quad::ONE + f64::INFINITY;
+        f64_rev_inf:
+            Quad::NEG_INFINITY,
+            f64::NEG_INFINITY + Quad::ONE;
+        f64_inf_neg_inf:
+            Quad::NAN,
+            Quad::INFINITY + f64::NEG_INFINITY;
+        f64_nan:
+            Quad::NAN,
+            Quad::ONE + f64::NAN;
+        f64_rev_nan:
+            Quad::NAN,
+            f64::NAN + Quad::ONE;
+    );
+    test_all_near!(
+        f64_num:
+            Quad::PI + Quad(1.1, 0.0, 0.0, 0.0),
+            Quad::PI + 1.1;
+        f64_ref:
+            Quad::PI + Quad(1.1, 0.0, 0.0, 0.0),
+            &Quad::PI + 1.1;
+        f64_rev_num:
+            Quad::PI + Quad(1.1, 0.0, 0.0, 0.0),
+            1.1 + Quad::PI;
+        f64_rev_ref:
+            Quad::PI + Quad(1.1, 0.0, 0.0, 0.0),
+            1.1 + &Quad::PI;
+        f64_cancel:
+            Quad::E - Quad(f64::consts::E, 0.0, 0.0, 0.0),
+            Quad::E + -f64::consts::E;
+    );
+
+    // checked_add tests
+    test_all_eq!(
+        checked_add_finite:
+            Some(qd!(5)),
+            qd!(2).checked_add(qd!(3));
+        checked_add_overflow:
+            None,
+            Quad::MAX.checked_add(Quad::MAX);
+        checked_add_neg_overflow:
+            None,
+            Quad::MIN.checked_add(Quad::MIN);
+        checked_add_inf:
+            Some(Quad::INFINITY),
+            Quad::INFINITY.checked_add(qd!(1));
+    );
+    test_all_assert!(
+        checked_add_nan:
+            Quad::NAN.checked_add(qd!(1)).unwrap().is_nan();
+        checked_add_inf_inf:
+            Quad::INFINITY.checked_add(Quad::NEG_INFINITY).unwrap().is_nan();
+    );
+
+    // add_unchecked tests
+    test_all_exact!(
+        add_unchecked_num:
+            Quad::PI + Quad::E,
+            Quad::PI.add_unchecked(Quad::E);
+        add_unchecked_neg:
+            Quad::PI + -Quad::E,
+            Quad::PI.add_unchecked(-Quad::E);
+        add_unchecked_small:
+            Quad::E + qd!(3.7e-200),
+            Quad::E.add_unchecked(qd!(3.7e-200));
+        add_unchecked_int:
+            qd!(6) + qd!(3),
+            qd!(6).add_unchecked(qd!(3));
+    );
+
+    // add_round tests
+    test!(add_round_inexact: {
+        let x = qd!(1) / qd!(3);
+        let y = qd!(1) / qd!(7e40);
+        let up = x.add_round(y, RoundMode::Up);
+        let down = x.add_round(y, RoundMode::Down);
+        let nearest = x + y;
+        assert!(up > down);
+        assert!(down <= nearest && nearest <= up);
+        assert!(up - down <= nearest.abs() * qd!(2f64.powi(-205)));
+    });
+    test!(add_round_bracket: {
+        let values = [Quad::PI, Quad::E, -Quad::LN_2, qd!(7), qd!(-1e10), qd!(3e-20)];
+        for &a in &values {
+            for &b in &values {
+                let nearest = a + b;
+                let up = a.add_round(b, RoundMode::Up);
+                let down = a.add_round(b, RoundMode::Down);
+                assert!(down <= nearest && nearest <= up, "{} + {} is not bracketed", a, b);
+                assert!(up - down <= nearest.abs() * qd!(2f64.powi(-205)));
+            }
+        }
+    });
+    test_all_exact!(
+        add_round_nearest:
+            Quad::PI + Quad::E,
+            Quad::PI.add_round(Quad::E, RoundMode::Nearest);
+        add_round_exact_up:
+            qd!(3.75),
+            qd!(1.5).add_round(qd!(2.25), RoundMode::Up);
+        add_round_exact_down:
+            qd!(3.75),
+            qd!(1.5).add_round(qd!(2.25), RoundMode::Down);
+        add_round_toward_zero_pos:
+            Quad::PI.add_round(Quad::E, RoundMode::Down),
+            Quad::PI.add_round(Quad::E, RoundMode::TowardZero);
+        add_round_toward_zero_neg:
+            (-Quad::PI).add_round(-Quad::E, RoundMode::Up),
+            (-Quad::PI).add_round(-Quad::E, RoundMode::TowardZero);
+        add_round_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.add_round(qd!(1), RoundMode::Down);
+    );
+    test_all_assert!(
+        add_round_nan:
+            Quad::NAN.add_round(qd!(1), RoundMode::Up).is_nan();
+    );
+    test_all_exact!(
+        add_round_overflow_down:
+            Quad::MAX,
+            Quad::MAX.add_round(Quad::MAX, RoundMode::Down);
+        add_round_overflow_toward_zero:
+            Quad::MIN,
+            Quad::MIN.add_round(Quad::MIN, RoundMode::TowardZero);
+        add_round_neg_overflow_down:
+            Quad::NEG_INFINITY,
+            Quad::MIN.add_round(Quad::MIN, RoundMode::Down);
+    );
+
+    // integer tests
+    test_all_exact!(
+        int_i32:
+            qd!(5),
+            qd!(3) + 2;
+        int_rev_i32:
+            qd!(5),
+            2 + qd!(3);
+        int_u32:
+            qd!(5),
+            qd!(3) + 2u32;
+        int_rev_u32:
+            qd!(5),
+            2u32 + qd!(3);
+        int_neg:
+            qd!(1),
+            qd!(3) + -2;
+        int_max:
+            qd!(4294967298.0),
+            qd!(3) + u32::MAX;
+        int_ref:
+            Quad::PI + Quad::from(2.0),
+            &Quad::PI + 2;
+    );
+
     // Assign tests. Assign code delegates to add code, so there's no need to re-test all
     // of the cases above.
     test_all!(
@@ -373,5 +911,42 @@ mod tests {
             b += &Quad::E;
             near!(qd!("5.8598744820488384738229308546321653819544164930750653959419122200308"), b);
         }
+        assign_f64: {
+            let mut c = Quad::PI;
+            c += 1.1;
+            near!(Quad::PI + Quad(1.1, 0.0, 0.0, 0.0), c);
+        }
+        assign_int: {
+            let mut e = Quad::PI;
+            e += 2;
+            e += 3u32;
+            exact!(Quad::PI + 2.0 + 3.0, e);
+        }
+        assign_double: {
+            let mut d = Quad::PI;
+            d += Double::E;
+            exact!(Quad::PI + Quad::from(Double::E), d);
+        }
+        assign_f64_accumulate: {
+            // The `f64` closest to 0.1 is a little larger than 0.1, and ten of them add up to
+            // exactly 1 + 2⁻⁵⁴. An `f64` accumulator loses that along the way and comes up
+            // short of 1, but the low components of the `Quad` keep the sum exact.
+            let mut total = Quad::ZERO;
+            let mut naive = 0.0f64;
+            for _ in 0..10 {
+                total += 0.1;
+                naive += 0.1;
+            }
+            let expected = Quad(1.0, 2f64.powi(-54), 0.0, 0.0);
+            exact!(expected, total);
+            assert!((total - expected).abs() < (Quad::from((naive, 0.0, 0.0, 0.0)) - expected).abs());
+        }
+        assign_f64_accumulate_long: {
+            let mut total = Quad::ZERO;
+            for _ in 0..1000 {
+                total += 0.1;
+            }
+            exact!(Quad(100.0, 1000.0 * 2f64.powi(-54) / 10.0, 0.0, 0.0), total);
+        }
     );
 }
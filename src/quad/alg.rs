@@ -138,6 +138,32 @@ impl Quad {
         }
     }
 
+    /// Calculates the square root of the `Quad`, returning `None` if it's negative.
+    ///
+    /// This is the same as [`sqrt`], except that the `NaN` that the square root of a
+    /// negative number produces is turned into `None`. Arguments that are already infinite
+    /// or `NaN` still produce `Some`, with the same value that [`sqrt`] would produce, so
+    /// `None` always means that a finite `Quad` was negative. Negative zero isn't
+    /// negative for this purpose; its square root is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(4).checked_sqrt() == Some(qd!(2)));
+    /// assert!(qd!(-4).checked_sqrt() == None);
+    /// assert!(Quad::INFINITY.checked_sqrt() == Some(Quad::INFINITY));
+    /// ```
+    ///
+    /// [`sqrt`]: #method.sqrt
+    pub fn checked_sqrt(self) -> Option<Quad> {
+        let r = self.sqrt();
+        if r.is_nan() && self.is_finite() {
+            None
+        } else {
+            Some(r)
+        }
+    }
+
     /// Calculates the *n*th root of the `Quad`.
     ///
     /// # Examples
@@ -208,36 +234,45 @@ impl Quad {
     /// * `x.powi(n)` is ±0 for `x` = ±0 and any odd positive `n`
     /// * `x.powi(n)` is +0 for `x` = ±0 and any even positive `n`
     ///
+    /// A result too large in magnitude to be represented is an infinity, and one too
+    /// small is a zero, either one with the sign that the true result would have.
+    ///
     /// # Examples
     /// ```
-    /// # use qd::qd;
+    /// # use qd::{qd, Quad};
     /// let x = qd!(3);
     /// assert!(x.powi(3) == qd!(27));
+    /// assert!(qd!(-2).powi(2001) == Quad::NEG_INFINITY);
+    /// assert!(qd!(2).powi(i32::MIN) == Quad::ZERO);
     /// ```
     pub fn powi(self, n: i32) -> Quad {
         match self.pre_powi(n) {
             Some(r) => r,
             None => {
-                let mut r = self;
+                let mut r = self.abs();
                 let mut s = Quad::ONE;
-                let mut k = n.abs();
+                let mut k = n.unsigned_abs();
 
-                if k > 1 {
-                    while k > 0 {
-                        if k % 2 == 1 {
-                            s *= r;
-                        }
-                        k /= 2;
-                        if k > 0 {
-                            r = r.sqr();
-                        }
+                while k > 0 {
+                    if k % 2 == 1 {
+                        s *= r;
+                    }
+                    k /= 2;
+                    if k > 0 {
+                        r = r.sqr();
+                    }
+                    // Overflowing multiplication produces NaN rather than infinity. Since
+                    // `r` is only squared when it will be multiplied into `s` again, an
+                    // overflow in either means that the final result would overflow too.
+                    if !s.is_finite() || !r.is_finite() {
+                        s = Quad::INFINITY;
+                        break;
                     }
-                } else {
-                    s = r;
                 }
 
-                if n < 0 {
-                    s.recip()
+                let s = if n < 0 { s.recip() } else { s };
+                if self.is_sign_negative() && n % 2 != 0 {
+                    -s
                 } else {
                     s
                 }
@@ -294,6 +329,31 @@ impl Quad {
         Quad::ONE / self
     }
 
+    /// Calculates the reciprocal of the `Quad`, returning `None` if it overflows.
+    ///
+    /// This is the same as [`recip`], except that a reciprocal that is infinite is turned
+    /// into `None` when the `Quad` was finite. That happens when the `Quad` is zero, or so
+    /// close to zero that its reciprocal overflows. An argument that is already infinite or
+    /// `NaN` still produces `Some`, with the same value that [`recip`] would produce.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(4).checked_recip() == Some(qd!(0.25)));
+    /// assert!(Quad::ZERO.checked_recip() == None);
+    /// assert!(Quad::INFINITY.checked_recip() == Some(Quad::ZERO));
+    /// ```
+    ///
+    /// [`recip`]: #method.recip
+    pub fn checked_recip(self) -> Option<Quad> {
+        let r = self.recip();
+        if r.is_finite() || !self.is_finite() {
+            Some(r)
+        } else {
+            None
+        }
+    }
+
     // PRecalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -598,6 +658,34 @@ mod tests {
             Quad::NAN.sqrt();
     );
 
+    // checked_sqrt tests
+    test_all_eq!(
+        checked_sqrt_finite:
+            Some(qd!(2)),
+            qd!(4).checked_sqrt();
+        checked_sqrt_zero:
+            Some(Quad::ZERO),
+            Quad::ZERO.checked_sqrt();
+        checked_sqrt_neg_zero:
+            Some(Quad::ZERO),
+            Quad::NEG_ZERO.checked_sqrt();
+        checked_sqrt_neg:
+            None,
+            qd!(-4).checked_sqrt();
+        checked_sqrt_neg_tiny:
+            None,
+            qd!(-1e-300).checked_sqrt();
+        checked_sqrt_inf:
+            Some(Quad::INFINITY),
+            Quad::INFINITY.checked_sqrt();
+    );
+    test_all_assert!(
+        checked_sqrt_neg_inf:
+            Quad::NEG_INFINITY.checked_sqrt().unwrap().is_nan();
+        checked_sqrt_nan:
+            Quad::NAN.checked_sqrt().unwrap().is_nan();
+    );
+
     // nroot tests
     test_all_near!(
         nroot_pi_one:
@@ -870,6 +958,95 @@ mod tests {
             Quad::NAN,
             Quad::NAN.powi(2);
     );
+    test_all_exact!(
+        powi_neg_two_cubed:
+            qd!(-8),
+            qd!(-2).powi(3);
+        powi_neg_two_squared:
+            qd!(4),
+            qd!(-2).powi(2);
+        powi_neg_two_neg_cubed:
+            qd!(-0.125),
+            qd!(-2).powi(-3);
+        powi_neg_two_neg_squared:
+            qd!(0.25),
+            qd!(-2).powi(-2);
+        powi_neg_inf_neg_odd_exp:
+            Quad::NEG_ZERO,
+            Quad::NEG_INFINITY.powi(-3);
+        powi_neg_inf_neg_even_exp:
+            Quad::ZERO,
+            Quad::NEG_INFINITY.powi(-2);
+        powi_one_min:
+            Quad::ONE,
+            Quad::ONE.powi(i32::MIN);
+        powi_neg_one_min:
+            Quad::ONE,
+            qd!(-1).powi(i32::MIN);
+        powi_neg_one_max:
+            qd!(-1),
+            qd!(-1).powi(i32::MAX);
+        powi_overflow:
+            Quad::INFINITY,
+            qd!(2).powi(2000);
+        powi_overflow_max:
+            Quad::INFINITY,
+            qd!(2).powi(i32::MAX);
+        powi_underflow:
+            Quad::ZERO,
+            qd!(2).powi(-2000);
+        powi_underflow_min:
+            Quad::ZERO,
+            qd!(2).powi(i32::MIN);
+        powi_neg_overflow_odd:
+            Quad::NEG_INFINITY,
+            qd!(-2).powi(2001);
+        powi_neg_overflow_even:
+            Quad::INFINITY,
+            qd!(-2).powi(2000);
+        powi_neg_overflow_max:
+            Quad::NEG_INFINITY,
+            qd!(-2).powi(i32::MAX);
+        powi_neg_underflow_min:
+            Quad::ZERO,
+            qd!(-2).powi(i32::MIN);
+        powi_neg_underflow_odd:
+            Quad::NEG_ZERO,
+            qd!(-2).powi(-2001);
+        powi_neg_underflow_even:
+            Quad::ZERO,
+            qd!(-2).powi(-2000);
+    );
+    test_all_assert!(
+        powi_neg_zero_odd_sign:
+            Quad::NEG_ZERO.powi(5).is_sign_negative();
+        powi_neg_zero_even_sign:
+            Quad::NEG_ZERO.powi(6).is_sign_positive();
+        powi_neg_underflow_odd_sign:
+            qd!(-2).powi(-2001).is_sign_negative();
+        powi_neg_underflow_even_sign:
+            qd!(-2).powi(-2000).is_sign_positive();
+        powi_neg_underflow_min_sign:
+            qd!(-2).powi(i32::MIN).is_sign_positive();
+    );
+    test!(powi_sign_matrix: {
+        // The sign of the result depends only on the sign of the base and the parity of the
+        // exponent, and its magnitude should be exactly the same as for the positive base
+        let bases = [qd!(2), qd!(0.5), qd!(3), Quad::PI, qd!(1e-3), Quad::ONE];
+        let exps = [1, 2, 3, 4, 5, 10, 11, 63, 64, -1, -2, -3, -4, -5, -10, -11, -63, -64];
+        for &x in &bases {
+            for &n in &exps {
+                let pos = x.powi(n);
+                let neg = (-x).powi(n);
+                assert!(pos > Quad::ZERO, "{}^{} is not positive", x, n);
+                if n % 2 == 0 {
+                    assert!(neg == pos, "(-{})^{} is not {}", x, n, pos);
+                } else {
+                    assert!(neg == -pos, "(-{})^{} is not {}", x, n, -pos);
+                }
+            }
+        }
+    });
 
     // powf_tests
     test_all_near!(
@@ -1027,4 +1204,30 @@ mod tests {
             Quad::NAN,
             Quad::NAN.recip();
     );
+
+    // checked_recip tests
+    test_all_eq!(
+        checked_recip_finite:
+            Some(qd!(0.25)),
+            qd!(4).checked_recip();
+        checked_recip_zero:
+            None,
+            Quad::ZERO.checked_recip();
+        checked_recip_neg_zero:
+            None,
+            Quad::NEG_ZERO.checked_recip();
+        checked_recip_overflow:
+            None,
+            Quad::from(f64::from_bits(1)).checked_recip();
+        checked_recip_max:
+            Some(Quad::MAX.recip()),
+            Quad::MAX.checked_recip();
+        checked_recip_inf:
+            Some(Quad::ZERO),
+            Quad::INFINITY.checked_recip();
+    );
+    test_all_assert!(
+        checked_recip_nan:
+            Quad::NAN.checked_recip().unwrap().is_nan();
+    );
 }
@@ -0,0 +1,171 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::quad::Quad;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+impl AbsDiffEq for Quad {
+    type Epsilon = Quad;
+
+    /// Returns [`EPSILON`], the default tolerance for the `approx` macros.
+    ///
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    #[inline]
+    fn default_epsilon() -> Quad {
+        Quad::EPSILON
+    }
+
+    /// Determines whether two `Quad`s are within `epsilon` of each other.
+    ///
+    /// As with the `f64` implementation, infinities are not within any tolerance of
+    /// anything (even themselves), and neither is `NaN`.
+    #[inline]
+    fn abs_diff_eq(&self, other: &Quad, epsilon: Quad) -> bool {
+        (*self - *other).abs() <= epsilon
+    }
+}
+
+impl RelativeEq for Quad {
+    /// Returns [`EPSILON`], the default relative tolerance for the `approx` macros.
+    ///
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    #[inline]
+    fn default_max_relative() -> Quad {
+        Quad::EPSILON
+    }
+
+    /// Determines whether two `Quad`s are equal to within `epsilon` in absolute terms or to
+    /// within `max_relative` times the larger of their magnitudes.
+    ///
+    /// Equal numbers are always equal, including two infinities of the same sign. Other
+    /// infinities and `NaN` are not equal to anything.
+    fn relative_eq(&self, other: &Quad, epsilon: Quad, max_relative: Quad) -> bool {
+        if self == other {
+            true
+        } else if self.is_infinite() || other.is_infinite() {
+            false
+        } else {
+            let diff = (*self - *other).abs();
+            let largest = if self.abs() > other.abs() {
+                self.abs()
+            } else {
+                other.abs()
+            };
+            diff <= epsilon || diff <= largest * max_relative
+        }
+    }
+}
+
+impl UlpsEq for Quad {
+    /// Returns 4, the default number of units in the last place for the `approx` macros.
+    /// This is the same default as for `f64`.
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        4
+    }
+
+    /// Determines whether two `Quad`s are equal to within `epsilon` in absolute terms or,
+    /// failing that, within `max_ulps` units in the last place of each other. A unit is
+    /// measured at the smaller of the two numbers, and numbers of different signs are
+    /// never within any number of units of each other.
+    fn ulps_eq(&self, other: &Quad, epsilon: Quad, max_ulps: u32) -> bool {
+        if self == other || AbsDiffEq::abs_diff_eq(self, other, epsilon) {
+            true
+        } else if !self.is_finite()
+            || !other.is_finite()
+            || self.is_sign_negative() != other.is_sign_negative()
+        {
+            false
+        } else {
+            let smaller = if self.abs() < other.abs() {
+                *self
+            } else {
+                *other
+            };
+            (*self - *other).abs() <= Quad::from(max_ulps) * eps_at(smaller)
+        }
+    }
+}
+
+// Calculates the spacing between representable `Quad`s at the magnitude of a finite `x`.
+// This is 2⁻²⁰⁹ scaled to the binary exponent of the first component, though never less than
+// the smallest subnormal `f64`.
+fn eps_at(x: Quad) -> Quad {
+    let min = f64::from_bits(1);
+    if x.is_zero() {
+        Quad(min, 0.0, 0.0, 0.0)
+    } else {
+        let exp = ((x.0.to_bits() >> 52) & 0x7ff) as i32 - 1023;
+        Quad(2f64.powi(exp - 209).max(min), 0.0, 0.0, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::{
+        abs_diff_eq, abs_diff_ne, assert_relative_eq, assert_ulps_eq, relative_eq, relative_ne,
+        ulps_eq, ulps_ne,
+    };
+
+    // abs_diff_eq tests
+    test_all_assert!(
+        abs_diff_eq_equal:
+            abs_diff_eq!(Quad::PI, Quad::PI);
+        abs_diff_eq_close:
+            abs_diff_eq!(Quad::ONE, Quad::ONE + Quad::EPSILON / qd!(2));
+        abs_diff_eq_far:
+            abs_diff_ne!(Quad::ONE, Quad::ONE + Quad::EPSILON * qd!(2));
+        abs_diff_eq_epsilon:
+            abs_diff_eq!(Quad::PI, qd!(3), epsilon = qd!(0.2));
+        abs_diff_eq_inf:
+            abs_diff_ne!(Quad::INFINITY, Quad::INFINITY);
+        abs_diff_eq_nan:
+            abs_diff_ne!(Quad::NAN, Quad::NAN);
+    );
+
+    // relative_eq tests
+    test_all_assert!(
+        relative_eq_equal:
+            relative_eq!(Quad::E, Quad::E);
+        relative_eq_large:
+            relative_eq!(qd!(1e100) * Quad::PI, qd!(1e100) * (Quad::PI + Quad::EPSILON));
+        relative_eq_large_far:
+            relative_ne!(qd!(1e100) * Quad::PI, qd!(1e100) * (Quad::PI + qd!(1e-20)));
+        relative_eq_max_relative:
+            relative_eq!(qd!(1e100), qd!(1.001e100), max_relative = qd!(0.01));
+        relative_eq_inf:
+            relative_eq!(Quad::INFINITY, Quad::INFINITY);
+        relative_eq_inf_max:
+            relative_ne!(Quad::INFINITY, Quad::MAX, max_relative = qd!(1));
+        relative_eq_nan:
+            relative_ne!(Quad::NAN, Quad::NAN);
+    );
+
+    // ulps_eq tests
+    test_all_assert!(
+        ulps_eq_equal:
+            ulps_eq!(Quad::LN_2, Quad::LN_2);
+        ulps_eq_close:
+            ulps_eq!(qd!(1e100), qd!(1e100) + eps_at(qd!(1e100)) * qd!(3));
+        ulps_eq_far:
+            ulps_ne!(qd!(1e100), qd!(1e100) + eps_at(qd!(1e100)) * qd!(8));
+        ulps_eq_max_ulps:
+            ulps_eq!(qd!(1e100), qd!(1e100) + eps_at(qd!(1e100)) * qd!(8), max_ulps = 8);
+        ulps_eq_signs:
+            ulps_ne!(qd!(1e-300), qd!(-1e-300), epsilon = Quad::ZERO, max_ulps = u32::MAX);
+        ulps_eq_inf:
+            ulps_eq!(Quad::INFINITY, Quad::INFINITY);
+        ulps_eq_nan:
+            ulps_ne!(Quad::NAN, Quad::NAN);
+    );
+
+    // macro tests
+    test!(assert_macros: {
+        assert_relative_eq!(Quad::PI.sin(), Quad::ZERO, epsilon = qd!(1e-60));
+        assert_relative_eq!(qd!(2).sqrt().powi(2), qd!(2), max_relative = Quad::EPSILON * qd!(4));
+        assert_ulps_eq!(Quad::SQRT_2 * Quad::SQRT_2, qd!(2), max_ulps = 8);
+    });
+}
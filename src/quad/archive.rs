@@ -0,0 +1,89 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::quad::{ArchivedQuad, Quad};
+
+impl From<&ArchivedQuad> for Quad {
+    /// Converts an archived `Quad`, as it's laid out in an `rkyv` archive, back into a
+    /// `Quad`.
+    ///
+    /// This is a cheap conversion that copies the components directly, so it's a
+    /// convenient way to get at individual values while accessing an archive without
+    /// deserializing all of it. The result is bit-for-bit the `Quad` that was archived.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use qd::ArchivedQuad;
+    /// use rkyv::rancor::Error;
+    /// use rkyv::vec::ArchivedVec;
+    ///
+    /// let values = vec![Quad::PI, Quad::E, qd!(1) / qd!(3)];
+    /// let bytes = rkyv::to_bytes::<Error>(&values).unwrap();
+    ///
+    /// let archived = rkyv::access::<ArchivedVec<ArchivedQuad>, Error>(&bytes).unwrap();
+    /// assert!(Quad::from(&archived[1]) == Quad::E);
+    /// ```
+    #[inline]
+    fn from(a: &ArchivedQuad) -> Quad {
+        Quad(
+            a.0.to_native(),
+            a.1.to_native(),
+            a.2.to_native(),
+            a.3.to_native(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rkyv::rancor::Error;
+    use rkyv::vec::ArchivedVec;
+
+    fn values() -> Vec<Quad> {
+        vec![
+            Quad::PI,
+            -Quad::E,
+            Quad::NEG_ZERO,
+            Quad::MAX,
+            Quad::MIN_POSITIVE,
+            Quad::NEG_INFINITY,
+            qd!(1) / qd!(3),
+        ]
+    }
+
+    fn same(a: Quad, b: Quad) -> bool {
+        (0..4).all(|i| a[i].to_bits() == b[i].to_bits())
+    }
+
+    // archive tests
+    test!(archive_access: {
+        let values = values();
+        let bytes = rkyv::to_bytes::<Error>(&values).unwrap();
+        let archived = rkyv::access::<ArchivedVec<ArchivedQuad>, Error>(&bytes).unwrap();
+        assert!(archived.len() == values.len());
+        for (a, v) in archived.iter().zip(values.iter()) {
+            assert!(same(Quad::from(a), *v), "{:?}", v);
+        }
+    });
+    test!(archive_deserialize: {
+        let values = values();
+        let bytes = rkyv::to_bytes::<Error>(&values).unwrap();
+        let back = rkyv::from_bytes::<Vec<Quad>, Error>(&bytes).unwrap();
+        assert!(back.len() == values.len());
+        for (b, v) in back.iter().zip(values.iter()) {
+            assert!(same(*b, *v), "{:?}", v);
+        }
+    });
+    test!(archive_nan: {
+        let bytes = rkyv::to_bytes::<Error>(&Quad::NAN).unwrap();
+        let archived = rkyv::access::<ArchivedQuad, Error>(&bytes).unwrap();
+        assert!(Quad::from(archived).is_nan());
+    });
+    test!(archive_bad_bytes: {
+        assert!(rkyv::access::<ArchivedVec<ArchivedQuad>, Error>(&[0xff; 3]).is_err());
+    });
+}
@@ -0,0 +1,81 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Support for `Quad`s as `ndarray` elements. Elementwise arithmetic between arrays only
+// needs the operator traits, and `LinalgScalar` (which `dot` requires) is implemented by
+// `ndarray` for everything that has those and `num-traits`' `Zero` and `One`. All that's left
+// is `ScalarOperand`, which allows arithmetic between an array and a single `Quad`.
+
+use crate::quad::Quad;
+use ndarray::ScalarOperand;
+
+impl ScalarOperand for Quad {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{array, Array1, Array2, LinalgScalar};
+
+    fn linalg<T: LinalgScalar>() {}
+
+    #[rustfmt::skip]
+    fn matrix() -> Array2<Quad> {
+        array![
+            [qd!(4), qd!(-2), qd!(1)],
+            [qd!(3), qd!(6), qd!(-4)],
+            [qd!(2), qd!(1), qd!(8)],
+        ]
+    }
+
+    // elementwise tests
+    test!(elementwise_arrays: {
+        let a = matrix();
+        let b = a.mapv(|x| x / qd!(3));
+        let sum = &a + &b;
+        let product = &a * &b;
+        for ((x, s), p) in a.iter().zip(sum.iter()).zip(product.iter()) {
+            exact!(*x + *x / qd!(3), *s);
+            exact!(*x * (*x / qd!(3)), *p);
+        }
+    });
+    test!(elementwise_scalar: {
+        let a = matrix();
+        let scaled = &a * Quad::PI;
+        let shifted = a.clone() - qd!(1);
+        for ((x, s), t) in a.iter().zip(scaled.iter()).zip(shifted.iter()) {
+            exact!(*x * Quad::PI, *s);
+            exact!(*x - qd!(1), *t);
+        }
+    });
+    test!(elementwise_sum: {
+        exact!(qd!(19), matrix().sum());
+        let thirds = Array1::from_elem(3, qd!(1) / qd!(3));
+        near!(qd!(1), thirds.sum());
+    });
+
+    // linalg tests
+    test!(linalg_scalar: {
+        linalg::<Quad>();
+    });
+    test!(linalg_dot: {
+        let a = matrix();
+        let identity = Array2::<Quad>::eye(3);
+        assert!(a.dot(&identity) == a);
+        let expected = array![
+            [qd!(12), qd!(-19), qd!(20)],
+            [qd!(22), qd!(26), qd!(-53)],
+            [qd!(27), qd!(10), qd!(62)],
+        ];
+        assert!(a.dot(&a) == expected);
+    });
+    test!(linalg_dot_precision: {
+        // The 1 is lost entirely when this is done in f64
+        let a = array![qd!(1e20), qd!(1), qd!(-1e20)];
+        let b = Array1::from_elem(3, qd!(1));
+        exact!(qd!(1), a.dot(&b));
+        let af = array![1e20, 1.0, -1e20];
+        assert!(af.dot(&Array1::from_elem(3, 1.0)) == 0.0);
+    });
+}
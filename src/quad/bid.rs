@@ -0,0 +1,225 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::bid as b;
+use crate::quad::Quad;
+
+impl Quad {
+    /// Converts the `Quad` into the bytes of an IEEE 754 decimal64 number, in the binary
+    /// integer decimal (BID) encoding and in little-endian order.
+    ///
+    /// BID is the encoding used by Intel's decimal floating-point library, and through it
+    /// by many of the systems that store decimal64s. A decimal64 has 16 significant digits,
+    /// about a quarter of what a `Quad` has, so the `Quad` is correctly rounded to the
+    /// nearest decimal64, with ties going to the one whose last digit is even. Every finite
+    /// `Quad` is well within the range of a decimal64, so there's no overflow or underflow.
+    ///
+    /// The coefficient of the result has no trailing zeros, so a `Quad` that's an integer
+    /// small enough to have all of its digits in the coefficient has an exponent of at
+    /// least zero. Zero always has an exponent of zero, and it keeps its sign. `NaN`
+    /// becomes the decimal64 quiet `NaN`, and the infinities carry over.
+    ///
+    /// This is only available when the `num-bigint` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let bits = u64::from_le_bytes(qd!(1.5).to_decimal64_bytes());
+    /// assert!(bits == 0x31a0_0000_0000_000f); // 15 × 10⁻¹
+    ///
+    /// let bits = u64::from_le_bytes(Quad::PI.to_decimal64_bytes());
+    /// assert!(bits == 0x2feb_2943_0a25_6d21); // 3141592653589793 × 10⁻¹⁵
+    /// ```
+    pub fn to_decimal64_bytes(self) -> [u8; 8] {
+        (b::to_bits(&[self.0, self.1, self.2, self.3], &b::DECIMAL64) as u64).to_le_bytes()
+    }
+
+    /// Generates a `Quad` from the bytes of an IEEE 754 decimal64 number, in the binary
+    /// integer decimal (BID) encoding and in little-endian order.
+    ///
+    /// BID is the encoding used by Intel's decimal floating-point library, and through it
+    /// by many of the systems that store decimal64s. A `Quad` has plenty of precision to
+    /// hold every decimal64 within the normal range of an `f64` closely enough that it
+    /// converts back to the same decimal64. One that's too large becomes [`INFINITY`] or
+    /// [`NEG_INFINITY`], and one that's too small is rounded to the nearest `f64` subnormal
+    /// or to zero. Any decimal64 `NaN` becomes [`NAN`], and a coefficient that's too large
+    /// for a decimal64 (which the standard says to treat as zero) becomes zero.
+    ///
+    /// This is only available when the `num-bigint` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let one = 0x31c0_0000_0000_0001u64; // 1 × 10⁰
+    /// assert!(Quad::from_decimal64_bytes(one.to_le_bytes()) == qd!(1));
+    ///
+    /// let tenth = 0x31a0_0000_0000_0001u64; // 1 × 10⁻¹
+    /// let x = Quad::from_decimal64_bytes(tenth.to_le_bytes());
+    /// assert!((x - qd!(1) / qd!(10)).abs() < qd!(1e-63));
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn from_decimal64_bytes(bytes: [u8; 8]) -> Quad {
+        let parts = b::from_bits(u64::from_le_bytes(bytes) as u128, 4, &b::DECIMAL64);
+        Quad(parts[0], parts[1], parts[2], parts[3])
+    }
+
+    /// Converts the `Quad` into the bytes of an IEEE 754 decimal128 number, in the binary
+    /// integer decimal (BID) encoding and in little-endian order.
+    ///
+    /// A decimal128 has 34 significant digits, about half of what a `Quad` has, so the
+    /// `Quad` is correctly rounded to the nearest decimal128, with ties going to the one
+    /// whose last digit is even. Every finite `Quad` is well within the range of a
+    /// decimal128, so there's no overflow or underflow.
+    ///
+    /// The coefficient of the result has no trailing zeros, and zero always has an exponent
+    /// of zero and keeps its sign. `NaN` becomes the decimal128 quiet `NaN`, and the
+    /// infinities carry over.
+    ///
+    /// This is only available when the `num-bigint` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let bits = u128::from_le_bytes(qd!(1.5).to_decimal128_bytes());
+    /// assert!(bits == 0x303e_0000_0000_0000_0000_0000_0000_000f); // 15 × 10⁻¹
+    ///
+    /// // 3141592653589793238462643383279503 × 10⁻³³
+    /// let bits = u128::from_le_bytes(Quad::PI.to_decimal128_bytes());
+    /// assert!(bits == 0x2ffe_9ae4_7957_96a7_babe_5564_e6f3_9f8f);
+    /// ```
+    pub fn to_decimal128_bytes(self) -> [u8; 16] {
+        b::to_bits(&[self.0, self.1, self.2, self.3], &b::DECIMAL128).to_le_bytes()
+    }
+
+    /// Generates a `Quad` from the bytes of an IEEE 754 decimal128 number, in the binary
+    /// integer decimal (BID) encoding and in little-endian order.
+    ///
+    /// A `Quad` has plenty of precision to hold every decimal128 within the normal range of
+    /// an `f64` closely enough that it converts back to the same decimal128. One that's too
+    /// large becomes [`INFINITY`] or [`NEG_INFINITY`], and one that's too small is rounded
+    /// to the nearest `f64` subnormal or to zero. Any decimal128 `NaN` becomes [`NAN`], and
+    /// a coefficient that's too large for a decimal128 (which the standard says to treat as
+    /// zero) becomes zero.
+    ///
+    /// This is only available when the `num-bigint` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let one = 0x3040_0000_0000_0000_0000_0000_0000_0001u128; // 1 × 10⁰
+    /// assert!(Quad::from_decimal128_bytes(one.to_le_bytes()) == qd!(1));
+    ///
+    /// let pi = 0x2ffe_9ae4_7957_96a7_babe_5564_e6f3_9f8fu128;
+    /// let x = Quad::from_decimal128_bytes(pi.to_le_bytes());
+    /// assert!(x.to_decimal128_bytes() == pi.to_le_bytes());
+    /// assert!((x - Quad::PI).abs() < qd!(1e-33));
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn from_decimal128_bytes(bytes: [u8; 16]) -> Quad {
+        let parts = b::from_bits(u128::from_le_bytes(bytes), 4, &b::DECIMAL128);
+        Quad(parts[0], parts[1], parts[2], parts[3])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bits64(x: Quad) -> u64 {
+        u64::from_le_bytes(x.to_decimal64_bytes())
+    }
+
+    fn bits128(x: Quad) -> u128 {
+        u128::from_le_bytes(x.to_decimal128_bytes())
+    }
+
+    fn from64(bits: u64) -> Quad {
+        Quad::from_decimal64_bytes(bits.to_le_bytes())
+    }
+
+    fn from128(bits: u128) -> Quad {
+        Quad::from_decimal128_bytes(bits.to_le_bytes())
+    }
+
+    // decimal64 tests
+    test!(to_decimal64: {
+        assert!(bits64(qd!(1)) == 0x31c0_0000_0000_0001);
+        assert!(bits64(qd!(-1.5)) == 0xb1a0_0000_0000_000f);
+        assert!(bits64(Quad::PI) == 0x2feb_2943_0a25_6d21);
+        assert!(bits64(qd!(9999999999999999i64)) == 0x6c73_86f2_6fc0_ffff);
+        // Exactly halfway goes to the even last digit
+        assert!(bits64(qd!(12345678901234565i64)) == 0x31e4_62d5_3c8a_bac0);
+        assert!(bits64(Quad(12345678901234564.0, 1.5, 0.0, 0.0)) == 0x31e4_62d5_3c8a_bac1);
+        assert!(bits64(Quad::NEG_ZERO) == 0xb1c0_0000_0000_0000);
+        assert!(bits64(Quad::NAN) == 0x7c00_0000_0000_0000);
+        assert!(bits64(Quad::INFINITY) == 0x7800_0000_0000_0000);
+    });
+    test!(from_decimal64: {
+        assert!(from64(0x31c0_0000_0000_0001) == qd!(1));
+        assert!(from64(0xb1a0_0000_0000_000f) == qd!(-1.5));
+        assert!(
+            from64(0x31a0_0000_0000_0001)
+                == Quad(
+                    0.1,
+                    -5.551115123125783e-18,
+                    3.0814879110195775e-34,
+                    -1.7105694144590053e-50
+                )
+        );
+        assert!(from64(0x77fb_86f2_6fc0_ffff) == Quad::INFINITY);
+        assert!(from64(0x0000_0000_0000_0001) == Quad::ZERO);
+        assert!(from64(0xf800_0000_0000_0000) == Quad::NEG_INFINITY);
+        assert!(from64(0x7e00_0000_0000_0000).is_nan());
+        assert!(from64(0x6c77_ffff_ffff_ffff) == Quad::ZERO);
+    });
+
+    // decimal128 tests
+    test!(to_decimal128: {
+        assert!(bits128(qd!(1)) == 0x3040_0000_0000_0000_0000_0000_0000_0001);
+        assert!(bits128(qd!(-1.5)) == 0xb03e_0000_0000_0000_0000_0000_0000_000f);
+        assert!(bits128(Quad::PI) == 0x2ffe_9ae4_7957_96a7_babe_5564_e6f3_9f8f);
+        assert!(bits128(qd!(1) / qd!(3)) == 0x2ffc_a458_94e4_8295_67d9_da21_5555_5555);
+        assert!(bits128(Quad::ZERO) == 0x3040_0000_0000_0000_0000_0000_0000_0000);
+        assert!(bits128(Quad::NAN) == 0x7c00_0000_0000_0000_0000_0000_0000_0000);
+        assert!(bits128(Quad::NEG_INFINITY) == 0xf800_0000_0000_0000_0000_0000_0000_0000);
+    });
+    test!(from_decimal128: {
+        assert!(from128(0x3040_0000_0000_0000_0000_0000_0000_0001) == qd!(1));
+        assert!(from128(0xb03e_0000_0000_0000_0000_0000_0000_000f) == qd!(-1.5));
+        assert!(
+            from128(0x2ffe_9ae4_7957_96a7_babe_5564_e6f3_9f8f)
+                == Quad(
+                    Quad::PI.0,
+                    1.2246467991473532e-16,
+                    -2.8789669791177148e-33,
+                    9.116168860946077e-50
+                )
+        );
+        assert!(from128(0x5ffe_0000_0000_0000_0000_0000_0000_0001) == Quad::INFINITY);
+        assert!(from128(0x0000_0000_0000_0000_0000_0000_0000_0001) == Quad::ZERO);
+        assert!(from128(0x7c00_0000_0000_0000_0000_0000_0000_0000).is_nan());
+    });
+
+    // round trip tests
+    test!(decimal_round_trip: {
+        for bits in [
+            0x2ffe_9ae4_7957_96a7_babe_5564_e6f3_9f8fu128,
+            0x2ffc_a458_94e4_8295_67d9_da21_5555_5555,
+            0x3040_0000_0000_0000_0000_0000_0000_0001,
+            0xb03e_0000_0000_0000_0000_0000_0000_000f,
+        ] {
+            assert!(bits128(from128(bits)) == bits);
+        }
+        for bits in [0x31a0_0000_0000_0001u64, 0x2feb_2943_0a25_6d21, 0x6c73_86f2_6fc0_ffff] {
+            assert!(bits64(from64(bits)) == bits);
+        }
+    });
+}
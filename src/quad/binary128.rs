@@ -0,0 +1,184 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::binary128 as b;
+use crate::quad::Quad;
+
+impl Quad {
+    /// Converts the `Quad` into the bytes of an IEEE 754 binary128 (quadruple precision)
+    /// number, in little-endian order.
+    ///
+    /// This is the layout of a Fortran `REAL(16)` or a C `__float128`, so it can be used to
+    /// write data that those languages can read. A binary128 has 113 bits of precision,
+    /// which is less than a `Quad` has, so the `Quad` is correctly rounded to the nearest
+    /// binary128 (ties go to the one with an even last bit). Every finite `Quad` is well
+    /// within the range of a binary128, so there's no overflow or underflow. `NaN` becomes
+    /// the binary128 quiet `NaN`, and the infinities and the sign of zero carry over.
+    ///
+    /// The bytes of a big-endian binary128 are the same bytes in reverse order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let bytes = qd!(1).to_binary128_bytes();
+    /// assert!(u128::from_le_bytes(bytes) == 0x3fff_0000_0000_0000_0000_0000_0000_0000);
+    ///
+    /// let pi = u128::from_le_bytes(Quad::PI.to_binary128_bytes());
+    /// assert!(pi == 0x4000_921f_b544_42d1_8469_898c_c517_01b8);
+    /// ```
+    pub fn to_binary128_bytes(self) -> [u8; 16] {
+        b::to_bits(&[self.0, self.1, self.2, self.3]).to_le_bytes()
+    }
+
+    /// Generates a `Quad` from the bytes of an IEEE 754 binary128 (quadruple precision)
+    /// number, in little-endian order.
+    ///
+    /// This is the layout of a Fortran `REAL(16)` or a C `__float128`, so it can be used to
+    /// read data written by those languages. A binary128 has more range than a `Quad` but
+    /// less precision, so any one within the range of an `f64` converts exactly. One that's
+    /// too large becomes [`INFINITY`] or [`NEG_INFINITY`], and one that's too small (which
+    /// includes all of the binary128 subnormals) is rounded to the nearest `f64` subnormal or
+    /// to zero. Any binary128 `NaN` becomes [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let one = 0x3fff_0000_0000_0000_0000_0000_0000_0000u128;
+    /// assert!(Quad::from_binary128_bytes(one.to_le_bytes()) == qd!(1));
+    ///
+    /// let x = qd!(1) / qd!(3);
+    /// let y = Quad::from_binary128_bytes(x.to_binary128_bytes());
+    /// assert!((x - y).abs() < qd!(1e-34));
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn from_binary128_bytes(bytes: [u8; 16]) -> Quad {
+        let parts = b::from_bits(u128::from_le_bytes(bytes), 4);
+        Quad(parts[0], parts[1], parts[2], parts[3])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pow2(n: i32) -> f64 {
+        2f64.powi(n)
+    }
+
+    fn bits(x: Quad) -> u128 {
+        u128::from_le_bytes(x.to_binary128_bytes())
+    }
+
+    fn from_bits(bits: u128) -> Quad {
+        Quad::from_binary128_bytes(bits.to_le_bytes())
+    }
+
+    // to_binary128_bytes tests
+    test!(to_binary128_exact: {
+        assert!(bits(qd!(1)) == 0x3fff_0000_0000_0000_0000_0000_0000_0000);
+        assert!(bits(qd!(-2)) == 0xc000_0000_0000_0000_0000_0000_0000_0000);
+        assert!(bits(qd!(0.75)) == 0x3ffe_8000_0000_0000_0000_0000_0000_0000);
+        assert!(bits(Quad(1.0, pow2(-112), 0.0, 0.0)) == 0x3fff_0000_0000_0000_0000_0000_0000_0001);
+        assert!(bits(Quad(f64::MAX, 0.0, 0.0, 0.0)) == 0x43fe_ffff_ffff_ffff_f000_0000_0000_0000);
+        assert!(bits(Quad(f64::from_bits(1), 0.0, 0.0, 0.0)) == 0x3bcd_0000_0000_0000_0000_0000_0000_0000);
+    });
+    test!(to_binary128_rounded: {
+        assert!(bits(qd!(1) / qd!(3)) == 0x3ffd_5555_5555_5555_5555_5555_5555_5555);
+        assert!(bits(Quad::PI) == 0x4000_921f_b544_42d1_8469_898c_c517_01b8);
+        // A negative lower component borrows from the higher ones
+        assert!(bits(Quad(1.0, -pow2(-200), 0.0, 0.0)) == 0x3fff_0000_0000_0000_0000_0000_0000_0000);
+        assert!(bits(Quad(2.0, -pow2(-200), 0.0, 0.0)) == 0x4000_0000_0000_0000_0000_0000_0000_0000);
+        assert!(bits(Quad(2.0, -pow2(-112), 0.0, 0.0)) == 0x3fff_ffff_ffff_ffff_ffff_ffff_ffff_ffff);
+    });
+    test!(to_binary128_ties: {
+        // Exactly halfway goes to even, and anything past halfway goes up
+        assert!(bits(Quad(1.0, pow2(-113), 0.0, 0.0)) == 0x3fff_0000_0000_0000_0000_0000_0000_0000);
+        assert!(bits(Quad(1.0, 3.0 * pow2(-113), 0.0, 0.0)) == 0x3fff_0000_0000_0000_0000_0000_0000_0002);
+        assert!(bits(Quad(1.0, pow2(-113), pow2(-300), 0.0)) == 0x3fff_0000_0000_0000_0000_0000_0000_0001);
+        assert!(bits(Quad(1.0, pow2(-113), -pow2(-300), 0.0)) == 0x3fff_0000_0000_0000_0000_0000_0000_0000);
+        assert!(bits(Quad(1e300, pow2(-1074), 0.0, 0.0)) == bits(Quad(1e300, 0.0, 0.0, 0.0)));
+        // Rounding up can carry into the exponent
+        assert!(bits(Quad(2.0, -pow2(-115), 0.0, 0.0)) == 0x4000_0000_0000_0000_0000_0000_0000_0000);
+    });
+    test!(to_binary128_special: {
+        assert!(bits(Quad::ZERO) == 0);
+        assert!(bits(Quad::NEG_ZERO) == 1 << 127);
+        assert!(bits(Quad::INFINITY) == 0x7fff_0000_0000_0000_0000_0000_0000_0000);
+        assert!(bits(Quad::NEG_INFINITY) == 0xffff_0000_0000_0000_0000_0000_0000_0000);
+        assert!(bits(Quad::NAN) == 0x7fff_8000_0000_0000_0000_0000_0000_0000);
+    });
+
+    // from_binary128_bytes tests
+    test_all_exact!(
+        from_binary128_one:
+            qd!(1),
+            from_bits(0x3fff_0000_0000_0000_0000_0000_0000_0000);
+        from_binary128_neg:
+            qd!(-2),
+            from_bits(0xc000_0000_0000_0000_0000_0000_0000_0000);
+        from_binary128_last_bit:
+            Quad(1.0, pow2(-112), 0.0, 0.0),
+            from_bits(0x3fff_0000_0000_0000_0000_0000_0000_0001);
+        from_binary128_all_bits:
+            Quad(2.0, -pow2(-112), 0.0, 0.0),
+            from_bits(0x3fff_ffff_ffff_ffff_ffff_ffff_ffff_ffff);
+        from_binary128_three_parts:
+            Quad(1.0, pow2(-54), pow2(-112), 0.0),
+            from_bits(0x3fff_0000_0000_0000_0400_0000_0000_0001);
+        from_binary128_max:
+            Quad(f64::MAX, 0.0, 0.0, 0.0),
+            from_bits(0x43fe_ffff_ffff_ffff_f000_0000_0000_0000);
+        from_binary128_overflow:
+            Quad::INFINITY,
+            from_bits(0x43ff_0000_0000_0000_0000_0000_0000_0000);
+        from_binary128_neg_overflow:
+            Quad::NEG_INFINITY,
+            from_bits(0xc3ff_0000_0000_0000_0000_0000_0000_0000);
+        from_binary128_round_overflow:
+            Quad::INFINITY,
+            from_bits(0x43fe_ffff_ffff_ffff_ffff_ffff_ffff_ffff);
+        from_binary128_underflow:
+            Quad::ZERO,
+            from_bits(0x3bcb_0000_0000_0000_0000_0000_0000_0000);
+        from_binary128_subnormal:
+            Quad::ZERO,
+            from_bits(0x0000_0000_0000_0000_0000_0000_0000_0001);
+        from_binary128_inf:
+            Quad::INFINITY,
+            from_bits(0x7fff_0000_0000_0000_0000_0000_0000_0000);
+        from_binary128_neg_inf:
+            Quad::NEG_INFINITY,
+            from_bits(0xffff_0000_0000_0000_0000_0000_0000_0000);
+    );
+    test_all_assert!(
+        from_binary128_min:
+            from_bits(0x3bcd_0000_0000_0000_0000_0000_0000_0000)
+                == Quad(f64::from_bits(1), 0.0, 0.0, 0.0);
+        from_binary128_nan:
+            from_bits(0x7fff_8000_0000_0000_0000_0000_0000_0000).is_nan();
+        from_binary128_signaling_nan:
+            from_bits(0xffff_0000_0000_0000_0000_0000_0000_0001).is_nan();
+        from_binary128_neg_zero:
+            from_bits(1 << 127).is_sign_negative();
+    );
+
+    // round trip tests
+    test!(binary128_round_trip: {
+        for x in [
+            Quad::PI,
+            -Quad::E,
+            Quad::LN_2 * qd!(1e200),
+            Quad::SQRT_2 / qd!(1e200),
+            Quad(1.0, pow2(-60), 0.0, 0.0),
+        ] {
+            let y = from_bits(bits(x));
+            assert!((x - y).abs() <= x.abs() * qd!(pow2(-113)));
+            assert!(bits(y) == bits(x));
+        }
+    });
+}
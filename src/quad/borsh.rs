@@ -0,0 +1,104 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::quad::Quad;
+use borsh::io::{Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+impl BorshSerialize for Quad {
+    /// Serializes the `Quad` as its 4 components, in order, each as the 8 bytes of an `f64` in
+    /// little-endian order.
+    ///
+    /// This is the layout of [`to_le_bytes`], which is always exactly 32 bytes, so a `Quad`
+    /// can be part of a fixed-size borsh structure. It reproduces the `Quad` exactly. Unlike
+    /// borsh's own encoding of `f64`s, `NaN` is allowed, so any `Quad` can be serialized.
+    ///
+    /// This is only available when the `borsh` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let bytes = borsh::to_vec(&Quad::PI).unwrap();
+    /// assert!(bytes == Quad::PI.to_le_bytes());
+    /// ```
+    ///
+    /// [`to_le_bytes`]: #method.to_le_bytes
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(&self.to_le_bytes())
+    }
+}
+
+impl BorshDeserialize for Quad {
+    /// Deserializes a `Quad` from the 32 bytes written by its `BorshSerialize`
+    /// implementation.
+    ///
+    /// The components are taken as they are, as with [`from_le_bytes`], so the original
+    /// `Quad` comes back exactly.
+    ///
+    /// This is only available when the `borsh` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let bytes = borsh::to_vec(&(7u8, Quad::E)).unwrap();
+    /// assert!(bytes.len() == 33);
+    ///
+    /// let (tag, x): (u8, Quad) = borsh::from_slice(&bytes).unwrap();
+    /// assert!(tag == 7 && x == Quad::E);
+    /// ```
+    ///
+    /// [`from_le_bytes`]: #method.from_le_bytes
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Quad> {
+        let mut bytes = [0; 32];
+        reader.read_exact(&mut bytes)?;
+        Ok(Quad::from_le_bytes(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn same(a: Quad, b: Quad) -> bool {
+        (0..4).all(|i| a[i].to_bits() == b[i].to_bits())
+    }
+
+    // borsh tests
+    test!(borsh_layout: {
+        let bytes = borsh::to_vec(&Quad::PI).unwrap();
+        assert!(bytes.len() == 32);
+        assert!(bytes[..8] == Quad::PI[0].to_le_bytes());
+        assert!(bytes[8..16] == Quad::PI[1].to_le_bytes());
+    });
+    test!(borsh_round_trip: {
+        for x in [
+            Quad::PI,
+            -Quad::E,
+            Quad::NEG_ZERO,
+            Quad::MAX,
+            Quad::MIN_POSITIVE,
+            Quad::NEG_INFINITY,
+            qd!(1) / qd!(3),
+        ] {
+            let bytes = borsh::to_vec(&x).unwrap();
+            assert!(same(borsh::from_slice(&bytes).unwrap(), x));
+        }
+    });
+    test!(borsh_nan: {
+        let bytes = borsh::to_vec(&Quad::NAN).unwrap();
+        assert!(borsh::from_slice::<Quad>(&bytes).unwrap().is_nan());
+    });
+    test!(borsh_vec: {
+        let values = vec![Quad::PI, Quad::E, Quad::LN_2];
+        let bytes = borsh::to_vec(&values).unwrap();
+        // A four-byte length, then the values
+        assert!(bytes.len() == 4 + 3 * 32);
+        assert!(borsh::from_slice::<Vec<Quad>>(&bytes).unwrap() == values);
+    });
+    test!(borsh_short: {
+        let bytes = borsh::to_vec(&Quad::PI).unwrap();
+        assert!(borsh::from_slice::<Quad>(&bytes[..31]).is_err());
+    });
+}
@@ -0,0 +1,346 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Views of `Quad`s as raw bytes, and conversions to and from fixed byte layouts (including
+// one that sorts the same way as the numbers do). The `zerocopy` traits are derived on the
+// struct itself; the `bytemuck` ones are implemented here.
+
+use crate::common::utils as u;
+use crate::quad::Quad;
+use std::convert::TryInto;
+
+impl Quad {
+    /// Returns the memory representation of the `Quad` as a byte array in little-endian
+    /// byte order.
+    ///
+    /// The layout is the 4 components of the `Quad` in order, each one as the 8 bytes of
+    /// its `f64` in little-endian order, for 32 bytes in all. That's the same as the way a
+    /// `Quad` is laid out in memory on a little-endian machine. Nothing is rounded or
+    /// normalized, so [`from_le_bytes`] always gives back exactly the same `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let bytes = Quad::PI.to_le_bytes();
+    /// assert!(bytes[..8] == Quad::PI[0].to_le_bytes());
+    /// assert!(bytes[8..16] == Quad::PI[1].to_le_bytes());
+    /// assert!(Quad::from_le_bytes(bytes) == Quad::PI);
+    /// ```
+    ///
+    /// [`from_le_bytes`]: #method.from_le_bytes
+    pub fn to_le_bytes(self) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        for (chunk, x) in bytes
+            .chunks_exact_mut(8)
+            .zip([self.0, self.1, self.2, self.3])
+        {
+            chunk.copy_from_slice(&x.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Returns the memory representation of the `Quad` as a byte array in big-endian
+    /// (network) byte order.
+    ///
+    /// The layout is the 4 components of the `Quad` in order, each one as the 8 bytes of
+    /// its `f64` in big-endian order, for 32 bytes in all. That's the same as the way a
+    /// `Quad` is laid out in memory on a big-endian machine, and it means that the most
+    /// significant byte comes first. Nothing is rounded or normalized, so
+    /// [`from_be_bytes`] always gives back exactly the same `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let bytes = Quad::PI.to_be_bytes();
+    /// assert!(bytes[..8] == Quad::PI[0].to_be_bytes());
+    /// assert!(bytes[8..16] == Quad::PI[1].to_be_bytes());
+    /// assert!(Quad::from_be_bytes(bytes) == Quad::PI);
+    /// ```
+    ///
+    /// [`from_be_bytes`]: #method.from_be_bytes
+    pub fn to_be_bytes(self) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        for (chunk, x) in bytes
+            .chunks_exact_mut(8)
+            .zip([self.0, self.1, self.2, self.3])
+        {
+            chunk.copy_from_slice(&x.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Creates a `Quad` from its memory representation as a byte array in little-endian
+    /// byte order.
+    ///
+    /// This is the inverse of [`to_le_bytes`], so the layout is the same: the 4
+    /// components in order, each as the 8 bytes of an `f64` in little-endian order. Like
+    /// [`new`], this takes the components as they are, without normalizing them.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let mut bytes = [0; 32];
+    /// bytes[..8].copy_from_slice(&1.5f64.to_le_bytes());
+    /// assert!(Quad::from_le_bytes(bytes) == qd!(1.5));
+    /// ```
+    ///
+    /// [`to_le_bytes`]: #method.to_le_bytes
+    /// [`new`]: #method.new
+    pub fn from_le_bytes(bytes: [u8; 32]) -> Quad {
+        let mut parts = bytes
+            .chunks_exact(8)
+            .map(|chunk| f64::from_le_bytes(chunk.try_into().unwrap()));
+        Quad(
+            parts.next().unwrap(),
+            parts.next().unwrap(),
+            parts.next().unwrap(),
+            parts.next().unwrap(),
+        )
+    }
+
+    /// Creates a `Quad` from its memory representation as a byte array in big-endian
+    /// (network) byte order.
+    ///
+    /// This is the inverse of [`to_be_bytes`], so the layout is the same: the 4
+    /// components in order, each as the 8 bytes of an `f64` in big-endian order. Like
+    /// [`new`], this takes the components as they are, without normalizing them.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let mut bytes = [0; 32];
+    /// bytes[..8].copy_from_slice(&1.5f64.to_be_bytes());
+    /// assert!(Quad::from_be_bytes(bytes) == qd!(1.5));
+    /// ```
+    ///
+    /// [`to_be_bytes`]: #method.to_be_bytes
+    /// [`new`]: #method.new
+    pub fn from_be_bytes(bytes: [u8; 32]) -> Quad {
+        let mut parts = bytes
+            .chunks_exact(8)
+            .map(|chunk| f64::from_be_bytes(chunk.try_into().unwrap()));
+        Quad(
+            parts.next().unwrap(),
+            parts.next().unwrap(),
+            parts.next().unwrap(),
+            parts.next().unwrap(),
+        )
+    }
+    /// Returns a byte array whose lexicographic order is the same as the numeric order of
+    /// the `Quad`s that they came from.
+    ///
+    /// This makes `Quad`s usable as keys in anything that sorts its keys as plain byte
+    /// strings, like the range scans of LMDB or RocksDB. The 4 components each become 8
+    /// bytes, most significant first, with the bits of each one adjusted so that negative
+    /// numbers come before positive ones and larger magnitudes of negative numbers come
+    /// first. [`NEG_INFINITY`] gives the lowest key and [`INFINITY`] the highest, except for
+    /// `NaN`, which comes after everything else.
+    ///
+    /// Numbers that are equal have equal keys. That means that [`NEG_ZERO`] gives the same
+    /// key as [`ZERO`], and every `NaN` gives the same key, so [`from_sortable_bytes`]
+    /// gives back a positive zero and a plain [`NAN`] for them. The order of the keys is
+    /// only guaranteed to match for normalized `Quad`s, which is all of them that come out of
+    /// `Quad` arithmetic.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let mut values = vec![qd!(1.5), -Quad::PI, Quad::INFINITY, qd!(0), qd!(-1e-300)];
+    /// let mut keys: Vec<_> = values.iter().map(|x| x.to_sortable_bytes()).collect();
+    /// keys.sort();
+    /// values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    ///
+    /// let sorted: Vec<_> = keys.into_iter().map(Quad::from_sortable_bytes).collect();
+    /// assert!(sorted == values);
+    /// ```
+    ///
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_ZERO`]: #associatedconstant.NEG_ZERO
+    /// [`ZERO`]: #associatedconstant.ZERO
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`from_sortable_bytes`]: #method.from_sortable_bytes
+    pub fn to_sortable_bytes(self) -> [u8; 32] {
+        // Whatever is in the other components of an infinity or `NaN` doesn't change its
+        // value, so it's left out of the key
+        let x = if self.0.is_finite() {
+            self
+        } else {
+            Quad::from(self.0)
+        };
+        let mut bytes = [0; 32];
+        for (chunk, a) in bytes.chunks_exact_mut(8).zip([x.0, x.1, x.2, x.3]) {
+            chunk.copy_from_slice(&u::to_sortable_bits(a).to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Creates a `Quad` from a byte array generated by [`to_sortable_bytes`].
+    ///
+    /// This gives back exactly the `Quad` that the bytes came from, except that a negative
+    /// zero comes back as a positive zero and any `NaN` comes back as [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let key = Quad::PI.to_sortable_bytes();
+    /// assert!(Quad::from_sortable_bytes(key) == Quad::PI);
+    /// ```
+    ///
+    /// [`to_sortable_bytes`]: #method.to_sortable_bytes
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn from_sortable_bytes(bytes: [u8; 32]) -> Quad {
+        let mut parts = bytes
+            .chunks_exact(8)
+            .map(|chunk| u::from_sortable_bits(u64::from_be_bytes(chunk.try_into().unwrap())));
+        Quad(
+            parts.next().unwrap(),
+            parts.next().unwrap(),
+            parts.next().unwrap(),
+            parts.next().unwrap(),
+        )
+    }
+}
+// SAFETY: `Quad` is `#[repr(C)]` and made up of nothing but four `f64`s, so it has no
+// padding, and every bit pattern is a valid `Quad` (though not necessarily a normalized one).
+// All zeros is `Quad::ZERO`.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Quad {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Quad {}
+
+#[cfg(test)]
+mod tests {
+    use crate::quad::Quad;
+
+    // byte order tests
+    test!(le_bytes: {
+        let bytes = Quad::PI.to_le_bytes();
+        for i in 0..4 {
+            assert!(bytes[i * 8..i * 8 + 8] == Quad::PI[i].to_le_bytes());
+        }
+        assert!(Quad::from_le_bytes(bytes) == Quad::PI);
+    });
+    test!(be_bytes: {
+        let bytes = Quad::PI.to_be_bytes();
+        for i in 0..4 {
+            assert!(bytes[i * 8..i * 8 + 8] == Quad::PI[i].to_be_bytes());
+        }
+        assert!(Quad::from_be_bytes(bytes) == Quad::PI);
+        // The sign bit of the first component is the first bit
+        assert!((-Quad::PI).to_be_bytes()[0] & 0x80 == 0x80);
+    });
+    test!(bytes_round_trip: {
+        for x in values() {
+            assert!(Quad::from_le_bytes(x.to_le_bytes()) == x);
+            assert!(Quad::from_be_bytes(x.to_be_bytes()) == x);
+        }
+    });
+    test!(bytes_special: {
+        assert!(Quad::from_le_bytes(Quad::NAN.to_le_bytes()).is_nan());
+        assert!(Quad::from_be_bytes(Quad::INFINITY.to_be_bytes()) == Quad::INFINITY);
+        let neg_zero = Quad::from_le_bytes(Quad::NEG_ZERO.to_le_bytes());
+        assert!(neg_zero.is_zero() && neg_zero.is_sign_negative());
+    });
+    test!(bytes_unnormalized: {
+        // Components are taken as they are
+        let mut bytes = [0; 32];
+        bytes[..8].copy_from_slice(&1.0f64.to_le_bytes());
+        bytes[8..16].copy_from_slice(&1.0f64.to_le_bytes());
+        let x = Quad::from_le_bytes(bytes);
+        assert!(x[0] == 1.0 && x[1] == 1.0);
+    });
+
+    fn values() -> [Quad; 3] {
+        [Quad::PI, -Quad::E, Quad::new(0.0, 0.0, 0.0, 0.0)]
+    }
+
+    // sortable bytes tests
+    test!(sortable_order: {
+        let values = [
+            Quad::NEG_INFINITY,
+            -Quad::MAX,
+            qd!(-1e300),
+            -Quad::PI,
+            Quad::from(-3.0) - Quad::from(1e-25),
+            qd!(-3),
+            Quad::from(-3.0) + Quad::from(1e-25),
+            qd!(-1e-300),
+            Quad::ZERO,
+            qd!(1e-300),
+            Quad::from(1.0) - Quad::from(1e-30),
+            qd!(1),
+            Quad::from(1.0) + Quad::from(1e-30),
+            Quad::PI,
+            qd!(1e300),
+            Quad::MAX,
+            Quad::INFINITY,
+            Quad::NAN,
+        ];
+        for pair in values.windows(2) {
+            assert!(pair[0].to_sortable_bytes() < pair[1].to_sortable_bytes());
+        }
+    });
+    test!(sortable_round_trip: {
+        for x in values() {
+            assert!(Quad::from_sortable_bytes(x.to_sortable_bytes()) == x);
+        }
+        assert!(Quad::from_sortable_bytes(Quad::NEG_INFINITY.to_sortable_bytes()) == Quad::NEG_INFINITY);
+    });
+    test!(sortable_zero: {
+        assert!(Quad::NEG_ZERO.to_sortable_bytes() == Quad::ZERO.to_sortable_bytes());
+        let zero = Quad::from_sortable_bytes(Quad::NEG_ZERO.to_sortable_bytes());
+        assert!(zero.is_zero() && zero.is_sign_positive());
+    });
+    test!(sortable_nan: {
+        assert!((-Quad::NAN).to_sortable_bytes() == Quad::NAN.to_sortable_bytes());
+        assert!(Quad::from_sortable_bytes(Quad::NAN.to_sortable_bytes()).is_nan());
+    });
+
+    // bytemuck tests
+    #[cfg(feature = "bytemuck")]
+    test!(bytemuck_zeroed: {
+        let zero: Quad = bytemuck::Zeroable::zeroed();
+        assert!(zero == Quad::ZERO && zero.is_sign_positive());
+    });
+    #[cfg(feature = "bytemuck")]
+    test!(bytemuck_cast_slice: {
+        let values = values();
+        let floats: &[f64] = bytemuck::cast_slice(&values);
+        assert!(floats.len() == 12);
+        assert!(floats[0] == Quad::PI[0] && floats[1] == Quad::PI[1]);
+        assert!(floats[4] == -Quad::E[0]);
+
+        let back: &[Quad] = bytemuck::cast_slice(floats);
+        assert!(back == values);
+    });
+    #[cfg(feature = "bytemuck")]
+    test!(bytemuck_bytes: {
+        let bytes = bytemuck::bytes_of(&Quad::PI);
+        assert!(bytes.len() == 32);
+        assert!(bytes[..8] == Quad::PI[0].to_ne_bytes());
+        assert!(bytemuck::pod_read_unaligned::<Quad>(bytes) == Quad::PI);
+    });
+
+    // zerocopy tests
+    #[cfg(feature = "zerocopy")]
+    test!(zerocopy_bytes: {
+        use zerocopy::{FromBytes, IntoBytes};
+        let values = values();
+        let bytes = values.as_bytes();
+        assert!(bytes.len() == 96);
+        assert!(bytes[32..40] == (-Quad::E[0]).to_ne_bytes());
+
+        let back = <[Quad]>::ref_from_bytes(bytes).unwrap();
+        assert!(back == values);
+        assert!(Quad::read_from_bytes(&bytes[..32]).unwrap() == Quad::PI);
+    });
+    #[cfg(feature = "zerocopy")]
+    test!(zerocopy_zeroed: {
+        let zero: Quad = zerocopy::FromZeros::new_zeroed();
+        assert!(zero == Quad::ZERO && zero.is_sign_positive());
+    });
+}
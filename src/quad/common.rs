@@ -3,13 +3,74 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::primitive as p;
+use crate::common::utils as u;
 use crate::quad::Quad;
+use crate::round::RoundMode;
+use std::cmp::Ordering;
 
 #[inline]
 pub fn mul_pwr2(a: Quad, n: f64) -> Quad {
     Quad(a.0 * n, a.1 * n, a.2 * n, a.3 * n)
 }
 
+/// Moves `x`, the result of an operation on finite numbers rounded to nearest, in the
+/// direction given by `mode` until it's on the right side of the exact result. `cmp`
+/// compares a candidate with the exact result, exactly.
+///
+/// This works the same way as the `Double` version. Each step is 2⁻²⁰⁹ scaled to the
+/// binary exponent of the first component (but never less than the last bit of the last
+/// component), added to the last component before everything is renormalized.
+pub fn round_directed(x: Quad, mode: RoundMode, cmp: impl Fn(Quad) -> Ordering) -> Quad {
+    if mode == RoundMode::Nearest {
+        return x;
+    }
+    let sign = cmp(Quad::ZERO).reverse();
+    let up = match mode {
+        RoundMode::Up => true,
+        RoundMode::Down => false,
+        _ => sign == Ordering::Less,
+    };
+
+    let mut x = if x.is_finite() {
+        x
+    } else if sign == Ordering::Less {
+        Quad::MIN
+    } else {
+        Quad::MAX
+    };
+    for i in 0..STEP_LIMIT {
+        match cmp(x) {
+            Ordering::Less if up => {}
+            Ordering::Greater if !up => {}
+            _ => return x,
+        }
+        let exp = ((x.0.to_bits() >> 52) & 0x7ff) as i32 - 1023;
+        let base = 2f64.powi(exp - 209).max(f64::from_bits(1)) * 2f64.powi((i - SLOW_STEPS).max(0));
+        let last = x.3.abs();
+        let size = base.max(f64::from_bits(last.to_bits() + 1) - last);
+        let d = if up { x.3 + size } else { x.3 - size };
+        let (c, d) = p::two_sum(x.2, d);
+        let (b, c) = p::two_sum(x.1, c);
+        let (a, b) = p::two_sum(x.0, b);
+        if !a.is_finite() {
+            break;
+        }
+        let (a, b, c, d) = u::renorm4(a, b, c, d);
+        x = Quad(a, b, c, d);
+    }
+    if up {
+        Quad::INFINITY
+    } else {
+        Quad::NEG_INFINITY
+    }
+}
+
+// The number of steps of the same size that `round_directed` takes before it starts to
+// double them, and the number that it takes in all before it gives up.
+const SLOW_STEPS: i32 = 8;
+const STEP_LIMIT: i32 = 2200;
+
 /// Reciprocals of factorials, rendered as Quads. These are used in Taylor series
 /// calculations.
 pub const INV_FACTS: [Quad; 15] = [
@@ -3184,3 +3245,131 @@ pub const COSINES: [Quad; 256] = [
         2.467773495734176e-50,
     ),
 ];
+
+/// Table of the even-indexed Bernoulli numbers, B<sub>2k</sub> for k in [0, 20] (so
+/// B<sub>0</sub> through B<sub>40</sub>). The odd-indexed Bernoulli numbers past B<sub>1</sub>
+/// are all zero, so they aren't stored. These are for asymptotic series such as Stirling's
+/// series, and they back the public `bernoulli` function.
+pub const BERNOULLI: [Quad; 21] = [
+    Quad(1e0, 0e0, 0e0, 0e0),
+    Quad(
+        1.6666666666666666e-1,
+        9.25185853854297e-18,
+        5.135813185032629e-34,
+        2.850949024098342e-50,
+    ),
+    Quad(
+        -3.333333333333333e-2,
+        -4.625929269271486e-19,
+        -6.419766481290786e-36,
+        -8.909215700307319e-53,
+    ),
+    Quad(
+        2.3809523809523808e-2,
+        1.32169407693471e-18,
+        7.336875978618041e-35,
+        4.0727843201404884e-51,
+    ),
+    Quad(
+        -3.333333333333333e-2,
+        -4.625929269271486e-19,
+        -6.419766481290786e-36,
+        -8.909215700307319e-53,
+    ),
+    Quad(
+        7.575757575757576e-2,
+        -2.10269512239613e-18,
+        5.836151346627988e-35,
+        -1.6198574000558762e-51,
+    ),
+    Quad(
+        -2.531135531135531e-1,
+        -1.1061562736192037e-17,
+        7.607776014751631e-34,
+        -3.40860718485604e-50,
+    ),
+    Quad(
+        1.1666666666666667e0,
+        -7.401486830834377e-17,
+        -4.108650548026103e-33,
+        -2.2807592192786735e-49,
+    ),
+    Quad(
+        -7.092156862745098e0,
+        -3.274069468698501e-16,
+        2.0108219152692458e-32,
+        2.7905759859409653e-49,
+    ),
+    Quad(
+        5.4971177944862156e1,
+        -1.9588897477095493e-16,
+        -1.0874022503046528e-32,
+        -6.036295076587166e-49,
+    ),
+    Quad(
+        -5.291242424242424e2,
+        6.890111377067638e-16,
+        5.976218978947059e-34,
+        5.183543680178804e-52,
+    ),
+    Quad(
+        6.192123188405797e3,
+        9.226757844073186e-14,
+        -5.853576572074754e-30,
+        7.108035688604145e-47,
+    ),
+    Quad(
+        -8.658025311355312e4,
+        3.5926706461242705e-12,
+        -1.609669232303282e-28,
+        1.1070771164306834e-44,
+    ),
+    Quad(
+        1.4255171666666667e6,
+        -7.761021455128987e-11,
+        -4.308232357047019e-27,
+        -2.3915493791143543e-43,
+    ),
+    Quad(
+        -2.7298231067816094e7,
+        1.610010519795034e-9,
+        2.234338436206454e-26,
+        3.1007674708517147e-43,
+    ),
+    Quad(
+        6.015808739006424e8,
+        -2.6635227381825164e-8,
+        -2.310237711361619e-26,
+        -2.0038117964929656e-44,
+    ),
+    Quad(
+        -1.5116315767092157e10,
+        5.011465035232843e-7,
+        -1.9514974765172607e-23,
+        1.1985432596619402e-39,
+    ),
+    Quad(
+        4.296146430611667e11,
+        -2.0345052083333332e-5,
+        -1.1293772630057337e-21,
+        -6.269303204385533e-38,
+    ),
+    Quad(
+        -1.3711655205088332e13,
+        -7.409090879485616e-4,
+        -1.4575906467399005e-20,
+        7.261631404270225e-37,
+    ),
+    Quad(
+        4.883323189735932e14,
+        -2.0833333333333332e-2,
+        -1.1564823173178713e-18,
+        -6.419766481290786e-35,
+    ),
+    Quad(
+        -1.9296579341940068e16,
+        -1.4863266814486326e-1,
+        -1.0970940014219026e-17,
+        -1.5396051942714223e-34,
+    ),
+];
@@ -3,8 +3,10 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::double::Double;
 use crate::quad::Quad;
 use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 
 impl PartialEq for Quad {
     /// Implements the `==` and `!= operators, testing two `Quad`s for equality and
@@ -60,6 +62,307 @@ impl PartialOrd for Quad {
     }
 }
 
+impl PartialEq<Double> for Quad {
+    /// Implements the `==` and `!=` operators between a `Quad` and a `Double`.
+    ///
+    /// The `Double` is promoted to a `Quad` (which is exact) and the two are compared as
+    /// `Quad`s, so they're equal only if they have exactly the same value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, qd, Double, Quad};
+    /// assert!(qd!(1.5) == dd!(1.5));
+    /// assert!(Quad::PI != Double::PI);
+    /// assert!(Quad::from(Double::PI) == Double::PI);
+    /// ```
+    #[inline]
+    fn eq(&self, other: &Double) -> bool {
+        self.eq(&Quad::from(*other))
+    }
+}
+
+impl PartialEq<Quad> for Double {
+    /// Implements the `==` and `!=` operators between a `Double` and a `Quad`.
+    ///
+    /// The `Double` is promoted to a `Quad` (which is exact) and the two are compared as
+    /// `Quad`s, so they're equal only if they have exactly the same value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, qd, Double, Quad};
+    /// assert!(dd!(1.5) == qd!(1.5));
+    /// assert!(Double::PI != Quad::PI);
+    /// ```
+    #[inline]
+    fn eq(&self, other: &Quad) -> bool {
+        Quad::from(*self).eq(other)
+    }
+}
+
+impl PartialOrd<Double> for Quad {
+    /// Implements the `<`, `>`, `<=`, and `>=` operators between a `Quad` and a `Double`.
+    ///
+    /// The `Double` is promoted to a `Quad` (which is exact) and the two are compared as
+    /// `Quad`s.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, Quad};
+    /// assert!(Quad::PI > Double::E);
+    /// // `Double::PI` is a little larger than π
+    /// assert!(Quad::PI < Double::PI);
+    /// assert!(!(Quad::NAN >= dd!(1)));
+    /// ```
+    #[inline]
+    fn partial_cmp(&self, other: &Double) -> Option<Ordering> {
+        self.partial_cmp(&Quad::from(*other))
+    }
+}
+
+impl PartialOrd<Quad> for Double {
+    /// Implements the `<`, `>`, `<=`, and `>=` operators between a `Double` and a `Quad`.
+    ///
+    /// The `Double` is promoted to a `Quad` (which is exact) and the two are compared as
+    /// `Quad`s.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Double, Quad};
+    /// assert!(Double::E < Quad::PI);
+    /// assert!(Double::PI > Quad::PI);
+    /// assert!(!(Double::NAN <= qd!(1)));
+    /// ```
+    #[inline]
+    fn partial_cmp(&self, other: &Quad) -> Option<Ordering> {
+        Quad::from(*self).partial_cmp(other)
+    }
+}
+
+impl PartialEq<f64> for Quad {
+    /// Implements the `==` and `!=` operators between a `Quad` and an `f64`.
+    ///
+    /// The `f64` is taken at its exact binary value, so the two are equal only if the `Quad`
+    /// has exactly that value. Just as with two `Quad`s, zero equals negative zero and `NaN`
+    /// equals nothing.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(1.5) == 1.5);
+    /// assert!(qd!(0) == -0.0);
+    /// // `qd!` parses 0.1 as a decimal, which isn't the `f64` closest to it
+    /// assert!(qd!(0.1) != 0.1);
+    /// assert!(Quad::NAN != f64::NAN);
+    /// ```
+    #[inline]
+    fn eq(&self, other: &f64) -> bool {
+        self.eq(&Quad(*other, 0.0, 0.0, 0.0))
+    }
+}
+
+impl PartialEq<Quad> for f64 {
+    /// Implements the `==` and `!=` operators between an `f64` and a `Quad`.
+    ///
+    /// The `f64` is taken at its exact binary value, so the two are equal only if the `Quad`
+    /// has exactly that value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(1.5 == qd!(1.5));
+    /// assert!(std::f64::consts::PI != Quad::PI);
+    /// ```
+    #[inline]
+    fn eq(&self, other: &Quad) -> bool {
+        Quad(*self, 0.0, 0.0, 0.0).eq(other)
+    }
+}
+
+impl PartialOrd<f64> for Quad {
+    /// Implements the `<`, `>`, `<=`, and `>=` operators between a `Quad` and an `f64`.
+    ///
+    /// The `f64` is taken at its exact binary value, so a `Quad` that's only a tiny bit
+    /// larger than an `f64` still compares as larger.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::PI > 3.0);
+    /// // The `f64` closest to π is a little smaller than π
+    /// assert!(Quad::PI > std::f64::consts::PI);
+    /// assert!(!(Quad::NAN < 1.0));
+    /// assert!(qd!(-0.5) <= 0.0);
+    /// ```
+    #[inline]
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        self.partial_cmp(&Quad(*other, 0.0, 0.0, 0.0))
+    }
+}
+
+impl PartialOrd<Quad> for f64 {
+    /// Implements the `<`, `>`, `<=`, and `>=` operators between an `f64` and a `Quad`.
+    ///
+    /// The `f64` is taken at its exact binary value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(3.0 < Quad::PI);
+    /// assert!(std::f64::consts::PI < Quad::PI);
+    /// assert!(!(f64::NAN >= qd!(1)));
+    /// ```
+    #[inline]
+    fn partial_cmp(&self, other: &Quad) -> Option<Ordering> {
+        Quad(*self, 0.0, 0.0, 0.0).partial_cmp(other)
+    }
+}
+
+// Every `i32`, `i64`, and `u64` converts exactly into a `Quad`, so comparing against one is
+// just comparing against the converted value.
+macro_rules! int_cmp_impl {
+    ($($t:ty)*) => ($(
+        impl PartialEq<$t> for Quad {
+            /// Implements the `==` and `!=` operators between a `Quad` and an integer,
+            /// which are equal only if the `Quad` has exactly the integer's value.
+            #[inline]
+            fn eq(&self, other: &$t) -> bool {
+                self.eq(&Quad::from(*other))
+            }
+        }
+
+        impl PartialEq<Quad> for $t {
+            /// Implements the `==` and `!=` operators between an integer and a `Quad`,
+            /// which are equal only if the `Quad` has exactly the integer's value.
+            #[inline]
+            fn eq(&self, other: &Quad) -> bool {
+                Quad::from(*self).eq(other)
+            }
+        }
+
+        impl PartialOrd<$t> for Quad {
+            /// Implements the `<`, `>`, `<=`, and `>=` operators between a `Quad` and an
+            /// integer.
+            #[inline]
+            fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                self.partial_cmp(&Quad::from(*other))
+            }
+        }
+
+        impl PartialOrd<Quad> for $t {
+            /// Implements the `<`, `>`, `<=`, and `>=` operators between an integer and a
+            /// `Quad`.
+            #[inline]
+            fn partial_cmp(&self, other: &Quad) -> Option<Ordering> {
+                Quad::from(*self).partial_cmp(other)
+            }
+        }
+    )*);
+}
+
+int_cmp_impl! { i32 i64 u64 }
+
+impl Hash for Quad {
+    /// Feeds the `Quad` into a hasher, consistently with `==`.
+    ///
+    /// Two `Quad`s that are equal always hash the same, which means that zero and negative
+    /// zero do. The hash is that of the bit patterns returned by [`canonical_bits`].
+    ///
+    /// `Quad` doesn't implement `Eq` (no more than `f64` does, as `NaN` isn't equal to
+    /// itself), so it can't be used directly as a `HashMap` key. A wrapper type that
+    /// implements `Eq` can use this to implement `Hash`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use std::collections::HashSet;
+    ///
+    /// #[derive(Hash, PartialEq)]
+    /// struct Key(Quad);
+    /// impl Eq for Key {} // fine as long as no key is ever NaN
+    ///
+    /// let mut set = HashSet::new();
+    /// set.insert(Key(qd!(0)));
+    /// assert!(set.contains(&Key(qd!(-0.0))));
+    /// assert!(!set.contains(&Key(Quad::PI)));
+    /// ```
+    ///
+    /// [`canonical_bits`]: #method.canonical_bits
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical_bits().hash(state);
+    }
+}
+
+impl Quad {
+    /// Returns the bit patterns of the components of the `Quad`, normalized so that they're
+    /// the same for any two `Quad`s that are equal.
+    ///
+    /// This is the raw memory representation of each component, as with `f64::to_bits`,
+    /// except that negative zero components are turned into positive zeros (zero equals
+    /// negative zero) and every `NaN` has the same bits. That makes these bits suitable for
+    /// hashing, and it's what the `Hash` implementation uses.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::ZERO.canonical_bits() == Quad::NEG_ZERO.canonical_bits());
+    /// assert!(Quad::NAN.canonical_bits() == (-Quad::NAN).canonical_bits());
+    /// assert!(qd!(1).canonical_bits()[0] == 1f64.to_bits());
+    /// ```
+    pub fn canonical_bits(self) -> [u64; 4] {
+        if self.0.is_nan() || self.1.is_nan() || self.2.is_nan() || self.3.is_nan() {
+            [f64::NAN.to_bits(), 0, 0, 0]
+        } else {
+            // Adding zero turns a negative zero into a positive one and leaves everything
+            // else alone
+            [
+                (self.0 + 0.0).to_bits(),
+                (self.1 + 0.0).to_bits(),
+                (self.2 + 0.0).to_bits(),
+                (self.3 + 0.0).to_bits(),
+            ]
+        }
+    }
+
+    /// Returns the ordering between `self` and `other` according to the `totalOrder`
+    /// predicate of IEEE 754, as `f64::total_cmp` does.
+    ///
+    /// Unlike `partial_cmp`, this always produces an ordering. Negative `NaN` sorts before
+    /// everything else, followed by negative infinity, the negative numbers, negative zero,
+    /// positive zero, the positive numbers, positive infinity, and finally positive `NaN`.
+    /// Within the finite numbers, the ordering is that of their values; the other components of
+    /// a nonzero number counts only for its value, so its sign doesn't matter if it's zero.
+    ///
+    /// This is what `Ord` would be if `Quad` implemented it. It doesn't, for the same reason
+    /// that `f64` doesn't: this ordering disagrees with `==`, which has zero equal to
+    /// negative zero and `NaN` equal to nothing. Slices can be sorted with
+    /// `sort_by(Quad::total_cmp)`, and a wrapper type whose `Ord` calls this can serve as a
+    /// key in a `BTreeMap`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut v = vec![qd!(2), Quad::NAN, Quad::NEG_INFINITY, qd!(-0.0), qd!(0), qd!(-1)];
+    /// v.sort_by(Quad::total_cmp);
+    /// assert!(v[0] == Quad::NEG_INFINITY && v[1] == qd!(-1) && v[4] == qd!(2));
+    /// assert!(v[2].is_sign_negative() && v[3].is_sign_positive());
+    /// assert!(v[5].is_nan());
+    ///
+    /// assert!(Quad::NAN.total_cmp(&Quad::NAN) == Ordering::Equal);
+    /// assert!(qd!(-0.0).total_cmp(&qd!(0)) == Ordering::Less);
+    /// ```
+    pub fn total_cmp(&self, other: &Quad) -> Ordering {
+        match self.0.total_cmp(&other.0) {
+            Ordering::Equal if self.0.is_finite() && self.0 != 0.0 => (1..4)
+                .map(|i| (self[i] + 0.0).total_cmp(&(other[i] + 0.0)))
+                .find(|&o| o != Ordering::Equal)
+                .unwrap_or(Ordering::Equal),
+            x => x,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -129,4 +432,167 @@ mod tests {
         gte_nan_nan:
             !(Quad::NAN >= Quad::NAN);
     );
+
+    // mixed tests
+    test_all_assert!(
+        mixed_eq:
+            qd!(1.5) == Double::from(1.5);
+        mixed_eq_promoted:
+            Quad::from(Double::PI) == Double::PI;
+        mixed_eq_rev:
+            Double::PI == Quad::from(Double::PI);
+        mixed_ne:
+            Quad::PI != Double::PI;
+        mixed_ne_rev:
+            Double::PI != Quad::PI;
+        mixed_eq_zero_neg_zero:
+            Quad::ZERO == Double::NEG_ZERO;
+        mixed_ne_nan:
+            Quad::NAN != Double::NAN;
+        mixed_lt:
+            Quad::PI < Double::PI;
+        mixed_gt_rev:
+            Double::PI > Quad::PI;
+        mixed_gt:
+            Quad::PI > Double::E;
+        mixed_lt_rev:
+            Double::E < Quad::PI;
+        mixed_lte_gte:
+            Quad::from(Double::PI) <= Double::PI && Double::PI >= Quad::from(Double::PI);
+        mixed_low_component:
+            Quad(1.0, 0.0, 1e-40, 0.0) > Double::ONE && Double::ONE < Quad(1.0, 0.0, 1e-40, 0.0);
+        mixed_inf:
+            Double::NEG_INFINITY < Quad::MAX && Quad::INFINITY > Double::MAX;
+        mixed_nan:
+            Quad::NAN.partial_cmp(&Double::ONE).is_none()
+                && Double::NAN.partial_cmp(&Quad::ONE).is_none();
+    );
+
+    // primitive tests
+    test_all_assert!(
+        prim_eq_f64:
+            qd!(1.5) == 1.5 && 1.5 == qd!(1.5);
+        prim_ne_f64:
+            Quad::PI != std::f64::consts::PI;
+        prim_ne_f64_rev:
+            std::f64::consts::PI != Quad::PI;
+        prim_eq_f64_zero:
+            Quad::ZERO == -0.0 && Quad::NEG_ZERO == 0.0;
+        prim_eq_f64_inf:
+            Quad::INFINITY == f64::INFINITY && f64::NEG_INFINITY == Quad::NEG_INFINITY;
+        prim_ne_f64_nan:
+            !Quad::NAN.eq(&f64::NAN) && !f64::NAN.eq(&Quad::NAN) && !Quad::ONE.eq(&f64::NAN);
+        prim_gt_f64:
+            Quad::PI > 3.0 && Quad::PI > std::f64::consts::PI;
+        prim_lt_f64:
+            3.0 < Quad::PI && std::f64::consts::PI < Quad::PI;
+        prim_lte_gte_f64:
+            qd!(2) <= 2.0 && qd!(2) >= 2.0 && 2.0 <= qd!(2) && 2.0 >= qd!(2);
+        prim_low_component_f64:
+            Quad::ONE + Quad::EPSILON > 1.0 && Quad::ONE - Quad::EPSILON < 1.0;
+        prim_nan_f64:
+            Quad::NAN.partial_cmp(&1.0).is_none() && 1.0.partial_cmp(&Quad::NAN).is_none();
+        prim_eq_i32:
+            qd!(3) == 3 && 3 == qd!(3) && qd!(-7) == -7i32;
+        prim_ne_i32:
+            qd!(3.5) != 3 && Quad::NAN != 0;
+        prim_ord_i32:
+            qd!(3.5) > 3 && qd!(3.5) < 4 && -1 < Quad::ZERO && 0 <= Quad::NEG_ZERO;
+        prim_eq_i64:
+            Quad::from(i64::MAX) == i64::MAX && i64::MIN == Quad::from(i64::MIN);
+        prim_ord_i64:
+            Quad::from(i64::MAX) - qd!(1) < i64::MAX && i64::MAX > Quad::from(i64::MAX) - qd!(0.5);
+        prim_eq_u64:
+            Quad::from(u64::MAX) == u64::MAX && u64::MAX == Quad::from(u64::MAX);
+        prim_ord_u64:
+            Quad::from(u64::MAX) + qd!(0.5) > u64::MAX && u64::MAX < Quad::from(u64::MAX) + qd!(1);
+        prim_ne_u64_rounded:
+            // `u64::MAX` isn't an `f64`, so this wouldn't work with a conversion through one
+            qd!(2).powi(64) != u64::MAX && qd!(2).powi(64) > u64::MAX;
+    );
+
+    // canonical_bits tests
+    test_all_assert!(
+        canonical_bits_zero:
+            Quad::ZERO.canonical_bits() == [0; 4];
+        canonical_bits_neg_zero:
+            Quad::NEG_ZERO.canonical_bits() == [0; 4];
+        canonical_bits_nan:
+            Quad::NAN.canonical_bits() == (-Quad::NAN).canonical_bits();
+        canonical_bits_nan_payload:
+            Quad::NAN.canonical_bits() == (Quad::NAN * Quad::PI).canonical_bits();
+        canonical_bits_pi:
+            Quad::PI.canonical_bits() == [Quad::PI.0.to_bits(), Quad::PI.1.to_bits(), Quad::PI.2.to_bits(), Quad::PI.3.to_bits()];
+        canonical_bits_neg:
+            Quad::PI.canonical_bits() != (-Quad::PI).canonical_bits();
+        canonical_bits_inf:
+            Quad::INFINITY.canonical_bits() != Quad::NEG_INFINITY.canonical_bits();
+    );
+
+    // hash tests
+    fn hash_of(x: Quad) -> u64 {
+        use std::collections::hash_map::DefaultHasher;
+        let mut hasher = DefaultHasher::new();
+        x.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    test_all_assert!(
+        hash_equal:
+            hash_of(Quad::PI) == hash_of(Quad::PI);
+        hash_zero_neg_zero:
+            hash_of(Quad::ZERO) == hash_of(Quad::NEG_ZERO);
+        hash_computed_zero:
+            hash_of(qd!(-1) + qd!(1) - qd!(0)) == hash_of(-Quad::ZERO * qd!(3));
+        hash_nan:
+            hash_of(Quad::NAN) == hash_of(-Quad::NAN);
+        hash_different:
+            hash_of(Quad::PI) != hash_of(Quad::E);
+    );
+
+    // total_cmp tests
+    test!(total_cmp_order: {
+        let ordered = [
+            -Quad::NAN,
+            Quad::NEG_INFINITY,
+            -Quad::MAX,
+            -Quad::PI,
+            qd!(-1),
+            -Quad::MIN_POSITIVE,
+            Quad::NEG_ZERO,
+            Quad::ZERO,
+            Quad::MIN_POSITIVE,
+            qd!(1),
+            Quad::E,
+            Quad::PI,
+            Quad::MAX,
+            Quad::INFINITY,
+            Quad::NAN,
+        ];
+        for (i, a) in ordered.iter().enumerate() {
+            for (j, b) in ordered.iter().enumerate() {
+                assert!(a.total_cmp(b) == i.cmp(&j), "{:?} vs {:?}", a, b);
+            }
+        }
+    });
+    test!(total_cmp_sort: {
+        let mut v = [Quad::PI, Quad::NAN, qd!(-1), Quad::NEG_ZERO, Quad::ZERO, Quad::E];
+        v.sort_by(Quad::total_cmp);
+        assert!(v[0] == qd!(-1));
+        assert!(v[1].is_zero() && v[1].is_sign_negative());
+        assert!(v[2].is_zero() && v[2].is_sign_positive());
+        assert!(v[3] == Quad::E);
+        assert!(v[4] == Quad::PI);
+        assert!(v[5].is_nan());
+    });
+    test_all_assert!(
+        total_cmp_low_less:
+            Quad(1.0, 0.0, 0.0, -1e-60).total_cmp(&Quad(1.0, 0.0, 0.0, 0.0)) == Ordering::Less;
+        total_cmp_low_greater:
+            Quad(1.0, 1e-20, 0.0, 0.0).total_cmp(&Quad(1.0, 0.0, 0.0, 0.0)) == Ordering::Greater;
+        total_cmp_low_neg_zero:
+            Quad(1.0, -0.0, 0.0, -0.0).total_cmp(&Quad(1.0, 0.0, 0.0, 0.0)) == Ordering::Equal;
+        total_cmp_neg_low_neg_zero:
+            Quad(-1.0, 0.0, -0.0, 0.0).total_cmp(&Quad(-1.0, -0.0, 0.0, 0.0)) == Ordering::Equal;
+    );
 }
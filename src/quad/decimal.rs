@@ -0,0 +1,421 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+#[cfg(feature = "bigdecimal")]
+use crate::common::bigint as b;
+use crate::error::{TryFromErrorKind, TryFromQuadError};
+#[cfg(feature = "bigdecimal")]
+use crate::quad::from::from_quotient;
+use crate::quad::Quad;
+#[cfg(feature = "bigdecimal")]
+use bigdecimal::BigDecimal;
+#[cfg(feature = "bigdecimal")]
+use num_bigint::{BigInt, Sign};
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::Decimal;
+use std::convert::TryFrom;
+
+// 2⁹⁶, one more than the largest mantissa that a `Decimal` can have
+#[cfg(feature = "rust_decimal")]
+const DECIMAL_LIMIT: f64 = 79228162514264337593543950336.0;
+
+#[cfg(feature = "rust_decimal")]
+impl From<Decimal> for Quad {
+    /// Generates a `Quad` from a [`Decimal`].
+    ///
+    /// A `Decimal` is a 96-bit integer divided by a power of ten no larger than 10²⁸, and
+    /// both of those are exactly representable as `Quad`s. The only rounding is in the
+    /// division, which is done with the full precision of a `Quad`. A `Decimal` with a scale
+    /// of 0 converts exactly.
+    ///
+    /// This is only available when the `rust_decimal` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let x = Quad::from(Decimal::new(-15, 1));
+    /// assert!(x == qd!(-1.5));
+    ///
+    /// // 0.3333333333333333333333333333, which is as close to 1/3 as a `Decimal` gets
+    /// let third = Decimal::ONE / Decimal::from(3);
+    /// assert!((Quad::from(third) - qd!(1) / qd!(3)).abs() < qd!(1e-28));
+    /// ```
+    ///
+    /// [`Decimal`]: https://docs.rs/rust_decimal/1/rust_decimal/struct.Decimal.html
+    fn from(d: Decimal) -> Quad {
+        Quad::from(d.mantissa()) / Quad::from(10u128.pow(d.scale()))
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl TryFrom<Quad> for Decimal {
+    type Error = TryFromQuadError;
+
+    /// Converts a `Quad` into the nearest [`Decimal`], or returns an error if the `Quad` is
+    /// `NaN`, infinite, or too large in magnitude.
+    ///
+    /// A `Decimal` has a 96-bit mantissa and at most 28 digits after the decimal point, so it
+    /// holds 28 or 29 significant digits, far fewer than a `Quad`. The `Quad` is rounded to
+    /// as many decimal places as will fit, with ties going to the value whose last digit is
+    /// even (the same rounding that `Decimal` does itself), and trailing zeros are removed.
+    /// A `Quad` too small to have a non-zero digit in the first 28 places becomes 0, while
+    /// one of magnitude 2⁹⁶ (about 7.9 × 10²⁸) or more is out of range.
+    ///
+    /// This is only available when the `rust_decimal` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use rust_decimal::Decimal;
+    /// use std::convert::TryFrom;
+    ///
+    /// let x = Decimal::try_from(qd!(-1.5)).unwrap();
+    /// assert!(x == Decimal::new(-15, 1));
+    ///
+    /// let pi = Decimal::try_from(Quad::PI).unwrap();
+    /// assert!(pi.to_string() == "3.1415926535897932384626433833");
+    ///
+    /// assert!(Decimal::try_from(qd!(1e30)).is_err());
+    /// assert!(Decimal::try_from(Quad::NAN).is_err());
+    /// ```
+    ///
+    /// [`Decimal`]: https://docs.rs/rust_decimal/1/rust_decimal/struct.Decimal.html
+    fn try_from(x: Quad) -> Result<Decimal, TryFromQuadError> {
+        if x.is_nan() {
+            return Err(TryFromQuadError {
+                kind: TryFromErrorKind::NaN,
+            });
+        }
+        if x.is_infinite() {
+            return Err(TryFromQuadError {
+                kind: TryFromErrorKind::Infinite,
+            });
+        }
+
+        // The number of digits before the decimal point decides how many can come after it.
+        // The logarithm could be off by enough to miscount those digits by one, so this
+        // starts a couple of places past the most that could fit and works back from there.
+        let digits = if x.0 == 0.0 {
+            0
+        } else {
+            x.0.abs().log10().floor() as i32 + 1
+        };
+        let start = (31 - digits).clamp(0, 28) as u32;
+        for scale in (0..=start).rev() {
+            let m = round_even(x * Quad::from(10u128.pow(scale)));
+            if m.abs() < Quad::from(DECIMAL_LIMIT) {
+                // Every component of an integral `Quad` is integral itself
+                let m = m.0 as i128 + m.1 as i128 + m.2 as i128 + m.3 as i128;
+                return Ok(Decimal::from_i128_with_scale(m, scale).normalize());
+            }
+        }
+        Err(TryFromQuadError {
+            kind: TryFromErrorKind::OutOfRange,
+        })
+    }
+}
+
+// Rounds a `Quad` to the nearest integer, with ties going to the even one. `round` sends
+// ties away from zero instead.
+#[cfg(feature = "rust_decimal")]
+fn round_even(x: Quad) -> Quad {
+    let floor = x.floor();
+    let diff = x - floor;
+    let half = Quad::from(0.5);
+    if diff > half || diff == half && (floor * half).fract() != Quad::ZERO {
+        floor + Quad::ONE
+    } else {
+        floor
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl From<&BigDecimal> for Quad {
+    /// Generates a `Quad` from a reference to a [`BigDecimal`].
+    ///
+    /// A `BigDecimal` is an integer times a power of ten, and that exact value is rounded to
+    /// the nearest `Quad` one component at a time, just like a [`BigInt`] (or a quotient of
+    /// them, if the power is negative). A number too large in magnitude to be represented
+    /// becomes [`INFINITY`] or [`NEG_INFINITY`], and one too small becomes zero.
+    ///
+    /// This is only available when the `bigdecimal` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use bigdecimal::BigDecimal;
+    ///
+    /// let x: BigDecimal = "-1.5".parse().unwrap();
+    /// assert!(Quad::from(&x) == qd!(-1.5));
+    ///
+    /// let s = "2.718281828459045235360287471352662497757247093699959574966967627724";
+    /// let e: BigDecimal = s.parse().unwrap();
+    /// assert!(Quad::from(&e) == s.parse::<Quad>().unwrap());
+    /// ```
+    ///
+    /// [`BigDecimal`]: https://docs.rs/bigdecimal/0.4/bigdecimal/struct.BigDecimal.html
+    /// [`BigInt`]: https://docs.rs/num-bigint/0.4/num_bigint/struct.BigInt.html
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    fn from(d: &BigDecimal) -> Quad {
+        let (n, scale) = d.as_bigint_and_exponent();
+        let negative = n.sign() == Sign::Minus;
+        // A rough base-10 exponent, so that huge powers of ten don't get calculated for
+        // numbers that are far out of range anyway
+        let exp = n.bits() as f64 * std::f64::consts::LOG10_2 - scale as f64;
+        if n.sign() == Sign::NoSign || exp < -400.0 {
+            if negative {
+                Quad::NEG_ZERO
+            } else {
+                Quad::ZERO
+            }
+        } else if exp > 400.0 {
+            if negative {
+                Quad::NEG_INFINITY
+            } else {
+                Quad::INFINITY
+            }
+        } else if scale <= 0 {
+            Quad::from(&(n * BigInt::from(10).pow((-scale) as u32)))
+        } else {
+            from_quotient(&n, &BigInt::from(10).pow(scale as u32))
+        }
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl TryFrom<Quad> for BigDecimal {
+    type Error = TryFromQuadError;
+
+    /// Converts a `Quad` into a [`BigDecimal`], exactly, or returns an error if the `Quad` is
+    /// `NaN` or infinite.
+    ///
+    /// Every finite `Quad` is a sum of `f64`s, each of which is an integer times a power of
+    /// two, so it has a decimal expansion that terminates. The result has its trailing zeros
+    /// removed. Zero becomes 0, whatever its sign.
+    ///
+    /// This is only available when the `bigdecimal` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use bigdecimal::BigDecimal;
+    /// use std::convert::TryFrom;
+    ///
+    /// let x = BigDecimal::try_from(qd!(-2.375)).unwrap();
+    /// assert!(x == "-2.375".parse::<BigDecimal>().unwrap());
+    ///
+    /// // No `Quad` is exactly 0.1, so its decimal expansion goes on for a while
+    /// let tenth = BigDecimal::try_from(qd!(0.1)).unwrap();
+    /// assert!(tenth != "0.1".parse::<BigDecimal>().unwrap());
+    /// assert!((Quad::from(&tenth) - qd!(0.1)).abs() < qd!(1e-63));
+    /// ```
+    ///
+    /// [`BigDecimal`]: https://docs.rs/bigdecimal/0.4/bigdecimal/struct.BigDecimal.html
+    fn try_from(x: Quad) -> Result<BigDecimal, TryFromQuadError> {
+        if x.is_nan() {
+            Err(TryFromQuadError {
+                kind: TryFromErrorKind::NaN,
+            })
+        } else if x.is_infinite() {
+            Err(TryFromQuadError {
+                kind: TryFromErrorKind::Infinite,
+            })
+        } else {
+            Ok(b::to_bigdecimal(&[x.0, x.1, x.2, x.3]))
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "rust_decimal")]
+    fn dec(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    fn big(s: &str) -> BigDecimal {
+        s.parse().unwrap()
+    }
+
+    // from_decimal tests
+    #[cfg(feature = "rust_decimal")]
+    test_all_exact!(
+        from_decimal_frac:
+            qd!(-1.5),
+            Quad::from(Decimal::new(-15, 1));
+        from_decimal_zero:
+            Quad::ZERO,
+            Quad::from(Decimal::ZERO);
+        from_decimal_max:
+            qd!(2).powi(96) - qd!(1),
+            Quad::from(Decimal::MAX);
+        from_decimal_min:
+            qd!(1) - qd!(2).powi(96),
+            Quad::from(Decimal::MIN);
+    );
+    #[cfg(feature = "rust_decimal")]
+    test!(from_decimal_inexact: {
+        near!(qd!(1) / qd!(10).powi(28), Quad::from(dec("0.0000000000000000000000000001")));
+        near!(qd!(-123456789) / qd!(1000), Quad::from(dec("-123456.789")));
+        let third = Quad::from(Decimal::ONE / Decimal::from(3));
+        assert!((third - qd!(1) / qd!(3)).abs() < qd!(1e-28));
+    });
+
+    // to_decimal tests
+    #[cfg(feature = "rust_decimal")]
+    test!(to_decimal_exact: {
+        assert_eq!(Decimal::try_from(qd!(-1.5)), Ok(Decimal::new(-15, 1)));
+        assert_eq!(Decimal::try_from(qd!(0)), Ok(Decimal::ZERO));
+        assert_eq!(Decimal::try_from(Quad::NEG_ZERO), Ok(Decimal::ZERO));
+        assert_eq!(Decimal::try_from(qd!(2).powi(96) - qd!(1)), Ok(Decimal::MAX));
+        assert_eq!(Decimal::try_from(qd!(1) - qd!(2).powi(96)), Ok(Decimal::MIN));
+        let x = qd!(12345678901234567890u64) + qd!(0.125);
+        assert_eq!(Decimal::try_from(x), Ok(dec("12345678901234567890.125")));
+    });
+    #[cfg(feature = "rust_decimal")]
+    test!(to_decimal_rounded: {
+        let round = |x: Quad| Decimal::try_from(x).unwrap().to_string();
+        assert_eq!(round(Quad::PI), "3.1415926535897932384626433833");
+        assert_eq!(round(qd!(1) / qd!(3)), "0.3333333333333333333333333333");
+        assert_eq!(round(qd!(-2) / qd!(3)), "-0.6666666666666666666666666667");
+        assert_eq!(round(Quad::E * qd!(1e20)), "271828182845904523536.02874714");
+        assert_eq!(round(qd!(6e-29)), "0.0000000000000000000000000001");
+        assert_eq!(round(qd!(4e-29)), "0");
+    });
+    #[cfg(feature = "rust_decimal")]
+    test!(to_decimal_ties: {
+        // Each of these is exactly halfway between two `Decimal`s
+        let round = |x: Quad| Decimal::try_from(x).unwrap().to_string();
+        assert_eq!(round(qd!(2).powi(-29)), "0.0000000018626451492309570312");
+        assert_eq!(round(qd!(3) * qd!(2).powi(-29)), "0.0000000055879354476928710938");
+        assert_eq!(round(-qd!(2).powi(-30)), "-0.0000000009313225746154785156");
+        let big = Quad::from(dec("70000000000000000000000000000"));
+        assert_eq!(round(big + qd!(0.5)), "70000000000000000000000000000");
+        assert_eq!(round(big + qd!(1.5)), "70000000000000000000000000002");
+        // Bits far below the first component still break the tie
+        assert_eq!(round(qd!(2).powi(-29) + qd!(2).powi(-200)), "0.0000000018626451492309570313");
+    });
+    #[cfg(feature = "rust_decimal")]
+    test!(to_decimal_round_trip: {
+        for s in ["0.0000000000000000000000000001", "-123456.789", "3.1415926535897932384626433833"] {
+            assert_eq!(Decimal::try_from(Quad::from(dec(s))), Ok(dec(s)));
+        }
+        for d in [Decimal::MAX, Decimal::MIN, Decimal::ONE / Decimal::from(7)] {
+            assert_eq!(Decimal::try_from(Quad::from(d)), Ok(d));
+        }
+    });
+    #[cfg(feature = "rust_decimal")]
+    test!(to_decimal_errors: {
+        let kind = |x: Quad| Decimal::try_from(x).unwrap_err().kind;
+        assert_eq!(kind(Quad::NAN), TryFromErrorKind::NaN);
+        assert_eq!(kind(Quad::INFINITY), TryFromErrorKind::Infinite);
+        assert_eq!(kind(Quad::NEG_INFINITY), TryFromErrorKind::Infinite);
+        assert_eq!(kind(qd!(2).powi(96)), TryFromErrorKind::OutOfRange);
+        assert_eq!(kind(-qd!(2).powi(96)), TryFromErrorKind::OutOfRange);
+        assert_eq!(kind(qd!(1e300)), TryFromErrorKind::OutOfRange);
+        // This one only reaches 2⁹⁶ when it's rounded
+        assert_eq!(kind(qd!(2).powi(96) - qd!(0.25)), TryFromErrorKind::OutOfRange);
+
+        let message = Decimal::try_from(Quad::NAN).unwrap_err().to_string();
+        assert_eq!(message, "cannot convert NaN quad-double");
+    });
+
+    // from_bigdecimal tests
+    #[cfg(feature = "bigdecimal")]
+    test_all_exact!(
+        from_bigdecimal_frac:
+            qd!(-1.5),
+            Quad::from(&big("-1.5"));
+        from_bigdecimal_zero:
+            Quad::ZERO,
+            Quad::from(&big("0"));
+        from_bigdecimal_int:
+            Quad::from(&BigInt::from(10).pow(40)),
+            Quad::from(&big("1e40"));
+        from_bigdecimal_overflow:
+            Quad::INFINITY,
+            Quad::from(&big("1e400"));
+        from_bigdecimal_neg_overflow:
+            Quad::NEG_INFINITY,
+            Quad::from(&big("-1e400"));
+        from_bigdecimal_underflow:
+            Quad::ZERO,
+            Quad::from(&big("1e-400"));
+        from_bigdecimal_huge_exp:
+            Quad::INFINITY,
+            Quad::from(&BigDecimal::new(BigInt::from(1), -1_000_000_000_000));
+        from_bigdecimal_tiny_exp:
+            Quad::ZERO,
+            Quad::from(&BigDecimal::new(BigInt::from(1), 1_000_000_000_000));
+    );
+    #[cfg(feature = "bigdecimal")]
+    test!(from_bigdecimal_inexact: {
+        near!(Quad::PI, Quad::from(&big("3.14159265358979323846264338327950288419716939937510582097494459230781640628620899863")));
+        near!(qd!(1) / qd!(3), Quad::from(&big("0.3333333333333333333333333333333333333333333333333333333333333333333333333")));
+        near!(qd!(1) / qd!(10).powi(30), Quad::from(&big("1e-30")));
+        let x = Quad::from(&big("-1e-400"));
+        assert!(x.is_zero() && x.is_sign_negative());
+    });
+    #[cfg(feature = "bigdecimal")]
+    test!(from_bigdecimal_nearest: {
+        // Parsing is correctly rounded, so both have to find the same nearest `Quad`
+        for s in &["0.1", "-2.5e-300", "1e-320", "123456789012345678901234567890.123456789e50"] {
+            assert!(Quad::from(&big(s)) == s.parse::<Quad>().unwrap());
+        }
+    });
+
+    // to_bigdecimal tests
+    #[cfg(feature = "bigdecimal")]
+    test!(to_bigdecimal_exact: {
+        let exact = |x: Quad| BigDecimal::try_from(x).unwrap();
+        assert_eq!(exact(qd!(-2.375)), big("-2.375"));
+        assert_eq!(exact(Quad::NEG_ZERO), big("0"));
+        assert_eq!(exact(qd!(-12345)), big("-12345"));
+        assert_eq!(exact(qd!(2).powi(100)), BigDecimal::from(BigInt::from(1) << 100));
+        assert_eq!(
+            exact(Quad(0.1, 0.0, 0.0, 0.0)),
+            big("0.1000000000000000055511151231257827021181583404541015625")
+        );
+        assert_eq!(
+            exact(Quad(f64::MIN_POSITIVE, 0.0, 0.0, 0.0)),
+            BigDecimal::new(BigInt::from(5).pow(1022), 1022)
+        );
+    });
+    #[cfg(feature = "bigdecimal")]
+    test!(to_bigdecimal_components: {
+        let x = Quad(2f64.powi(60), -0.5, 0.0, 0.0);
+        assert_eq!(BigDecimal::try_from(x).unwrap(), big("1152921504606846975.5"));
+        let x = Quad(1.0, 2f64.powi(-60), 2f64.powi(-120), -2f64.powi(-180));
+        assert_eq!(
+            BigDecimal::try_from(x).unwrap(),
+            BigDecimal::try_from(qd!(1) + qd!(2).powi(-60) + qd!(2).powi(-120) - qd!(2).powi(-180))
+                .unwrap()
+        );
+        let x = Quad(1.5, 2f64.powi(-60), 0.0, 0.0);
+        assert_eq!(
+            BigDecimal::try_from(x).unwrap(),
+            big("1.500000000000000000867361737988403547205962240695953369140625")
+        );
+    });
+    #[cfg(feature = "bigdecimal")]
+    test!(to_bigdecimal_round_trip: {
+        for x in [Quad::PI, -Quad::E, Quad::LN_2 * qd!(1e200), Quad::SQRT_2 / qd!(1e200)] {
+            let d = BigDecimal::try_from(x).unwrap();
+            near!(x, Quad::from(&d));
+        }
+    });
+    #[cfg(feature = "bigdecimal")]
+    test!(to_bigdecimal_errors: {
+        let kind = |x: Quad| BigDecimal::try_from(x).unwrap_err().kind;
+        assert_eq!(kind(Quad::NAN), TryFromErrorKind::NaN);
+        assert_eq!(kind(Quad::INFINITY), TryFromErrorKind::Infinite);
+        assert_eq!(kind(Quad::NEG_INFINITY), TryFromErrorKind::Infinite);
+    });
+}
@@ -5,6 +5,7 @@
 
 use crate::common::primitive as p;
 use crate::common::utils as u;
+use crate::double::Double;
 use crate::quad::Quad;
 use std::ops::{Div, DivAssign};
 
@@ -53,31 +54,7 @@ impl Div for Quad {
     fn div(self, other: Quad) -> Quad {
         match self.pre_div(&other) {
             Some(r) => r,
-            None => {
-                // Strategy:
-                //
-                // Divide the first component of `self` by the first component of `other`.
-                // Then divide the first component of the remainder by the first component
-                // of `other`, then the first component of -that- remainder by the first
-                // component of `other`, and so on until we have five terms we can
-                // renormalize.
-                let q0 = self.0 / other.0;
-                let mut r = self - mul_f64(other, q0);
-
-                let q1 = r.0 / other.0;
-                r -= mul_f64(other, q1);
-
-                let q2 = r.0 / other.0;
-                r -= mul_f64(other, q2);
-
-                let q3 = r.0 / other.0;
-                r -= mul_f64(other, q3);
-
-                let q4 = r.0 / other.0;
-
-                let (a, b, c, d) = u::renorm5(q0, q1, q2, q3, q4);
-                Quad(a, b, c, d)
-            }
+            None => self.div_unchecked(other),
         }
     }
 }
@@ -200,6 +177,342 @@ impl DivAssign<&Quad> for Quad {
     }
 }
 
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Div<f64> for Quad {
+    type Output = Quad;
+
+    /// Divides this `Quad` by an `f64`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `/` operator between a `Quad` and an `f64`.
+    ///
+    /// The `f64` is taken at its exact binary value, as with `Quad::from((x, 0.0, 0.0,
+    /// 0.0))`, and is worked directly into the components rather than first being promoted
+    /// to a `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::PI / 2.5;
+    /// let expected = qd!("1.256637061435917295385057353311801153678867759750042328389977837");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    fn div(self, other: f64) -> Quad {
+        match self.pre_div(&Quad(other, 0.0, 0.0, 0.0)) {
+            Some(r) => r,
+            None => {
+                // Strategy: the same long division as above, except that each partial
+                // product of the divisor and a quotient term is exactly representable by
+                // a two-component `Quad`.
+                let q0 = self.0 / other;
+                let (p0, e0) = p::two_prod(q0, other);
+                let mut r = self - Quad(p0, e0, 0.0, 0.0);
+
+                let q1 = r.0 / other;
+                let (p1, e1) = p::two_prod(q1, other);
+                r -= Quad(p1, e1, 0.0, 0.0);
+
+                let q2 = r.0 / other;
+                let (p2, e2) = p::two_prod(q2, other);
+                r -= Quad(p2, e2, 0.0, 0.0);
+
+                let q3 = r.0 / other;
+
+                let (a, b, c, d) = u::renorm4(q0, q1, q2, q3);
+                Quad(a, b, c, d)
+            }
+        }
+    }
+}
+
+impl Div<f64> for &Quad {
+    type Output = Quad;
+
+    /// Divides a reference to this `Quad` by an `f64`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `/` operator between a reference to a `Quad` and an `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = &Quad::PI / 2.5;
+    /// let expected = qd!("1.256637061435917295385057353311801153678867759750042328389977837");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn div(self, other: f64) -> Quad {
+        (*self).div(other)
+    }
+}
+
+impl Div<Quad> for f64 {
+    type Output = Quad;
+
+    /// Divides this `f64` by a `Quad`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `/` operator between an `f64` and a `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = 2.5 / Quad::PI;
+    /// let expected = qd!("0.7957747154594766788444188168625718101722982287022822437383367203");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn div(self, other: Quad) -> Quad {
+        Quad(self, 0.0, 0.0, 0.0).div(other)
+    }
+}
+
+impl Div<&Quad> for f64 {
+    type Output = Quad;
+
+    /// Divides this `f64` by a reference to a `Quad`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `/` operator between an `f64` and a reference to a `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = 2.5 / &Quad::PI;
+    /// let expected = qd!("0.7957747154594766788444188168625718101722982287022822437383367203");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn div(self, other: &Quad) -> Quad {
+        self.div(*other)
+    }
+}
+
+impl DivAssign<f64> for Quad {
+    /// Divides this `Quad` by an `f64`, modifying this one to equal the result.
+    ///
+    /// This implements the `/=` operator between a `Quad` and an `f64`. As with the
+    /// non-assigning operator, the `f64` is taken at its exact binary value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let mut x = Quad::PI;
+    /// x /= 2.5;
+    /// let expected = qd!("1.256637061435917295385057353311801153678867759750042328389977837");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: f64) {
+        let r = self.div(other);
+        self.0 = r.0;
+        self.1 = r.1;
+        self.2 = r.2;
+        self.3 = r.3;
+    }
+}
+
+impl Div<Double> for Quad {
+    type Output = Quad;
+
+    /// Divides this `Quad` by a `Double`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `/` operator between a `Quad` and a `Double`. The `Double` is
+    /// promoted to a `Quad` first, which is exact.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Double, Quad};
+    /// let x = Quad::E / Double::PI;
+    /// let expected = qd!("0.8652559794322650872177747896460887926106441865097971890284395283");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn div(self, other: Double) -> Quad {
+        self.div(Quad::from(other))
+    }
+}
+
+impl Div<Quad> for Double {
+    type Output = Quad;
+
+    /// Divides this `Double` by a `Quad`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `/` operator between a `Double` and a `Quad`. The `Double` is
+    /// promoted to a `Quad` first, which is exact.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Double, Quad};
+    /// let x = Double::PI / Quad::E;
+    /// let expected = qd!("1.1557273497909217179100931833126974008350950594538274525354585131");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn div(self, other: Quad) -> Quad {
+        Quad::from(self).div(other)
+    }
+}
+
+impl DivAssign<Double> for Quad {
+    /// Divides this `Quad` by a `Double`, modifying this one to equal the result.
+    ///
+    /// This implements the `/=` operator between a `Quad` and a `Double`. As with the
+    /// non-assigning operator, the `Double` is promoted to a `Quad` first.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Double, Quad};
+    /// let mut x = Quad::E;
+    /// x /= Double::PI;
+    /// let expected = qd!("0.8652559794322650872177747896460887926106441865097971890284395283");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: Double) {
+        self.div_assign(Quad::from(other));
+    }
+}
+
+macro_rules! div_int_impl {
+    ($($t:ty)*) => ($(
+        impl Div<$t> for Quad {
+            type Output = Quad;
+
+            /// Divides this `Quad` by an integer, producing a new `Quad` as a result.
+            ///
+            /// This implements the `/` operator between a `Quad` and an integer. Every
+            /// integer of this type converts exactly to an `f64`, so this takes the same
+            /// fast path as the `f64` operator.
+            #[inline]
+            fn div(self, other: $t) -> Quad {
+                self.div(f64::from(other))
+            }
+        }
+
+        impl Div<$t> for &Quad {
+            type Output = Quad;
+
+            /// Divides a reference to this `Quad` by an integer, producing a new `Quad` as
+            /// a result.
+            ///
+            /// This implements the `/` operator between a reference to a `Quad` and an
+            /// integer.
+            #[inline]
+            fn div(self, other: $t) -> Quad {
+                (*self).div(f64::from(other))
+            }
+        }
+
+        impl Div<Quad> for $t {
+            type Output = Quad;
+
+            /// Divides this integer by a `Quad`, producing a new `Quad` as a result.
+            ///
+            /// This implements the `/` operator between an integer and a `Quad`.
+            #[inline]
+            fn div(self, other: Quad) -> Quad {
+                f64::from(self).div(other)
+            }
+        }
+
+        impl DivAssign<$t> for Quad {
+            /// Divides this `Quad` by an integer, modifying this one to equal the result.
+            ///
+            /// This implements the `/=` operator between a `Quad` and an integer.
+            #[inline]
+            fn div_assign(&mut self, other: $t) {
+                self.div_assign(f64::from(other));
+            }
+        }
+    )*);
+}
+
+div_int_impl! { i32 u32 }
+
+impl Quad {
+    /// Divides this `Quad` by another, returning `None` if the quotient overflows or if
+    /// `other` is zero.
+    ///
+    /// This is the same as the `/` operator, except that a quotient that is infinite or
+    /// `NaN` is turned into `None` when both `Quad`s were finite. That includes division by
+    /// zero, which gives an infinity (or `NaN`, for zero divided by zero). As in
+    /// [`checked_add`], infinite and `NaN` arguments still produce `Some`, so a finite
+    /// number divided by an infinity is `Some` zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(6).checked_div(qd!(3)) == Some(qd!(2)));
+    /// assert!(qd!(1).checked_div(Quad::ZERO) == None);
+    /// assert!(Quad::MAX.checked_div(qd!(0.5)) == None);
+    /// assert!(qd!(1).checked_div(Quad::INFINITY) == Some(Quad::ZERO));
+    /// ```
+    ///
+    /// [`checked_add`]: #method.checked_add
+    pub fn checked_div(self, other: Quad) -> Option<Quad> {
+        let r = self / other;
+        if r.is_finite() || !(self.is_finite() && other.is_finite()) {
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    /// Divides this `Quad` by another without checking for special cases first.
+    ///
+    /// This skips the checks for zeros, infinities, and `NaN` that the `/` operator makes
+    /// before dividing, and the ones made by the subtractions inside the division, for
+    /// speed when the dividend is known to be finite and the divisor is known to be finite
+    /// and nonzero. Like [`add_unchecked`], it isn't unsafe, but the result is meaningless
+    /// for any other arguments. For those, it's exactly the same as `/`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::PI.div_unchecked(Quad::E) == Quad::PI / Quad::E);
+    /// ```
+    ///
+    /// [`add_unchecked`]: #method.add_unchecked
+    pub fn div_unchecked(self, other: Quad) -> Quad {
+        // Strategy:
+        //
+        // Divide the first component of `self` by the first component of `other`.
+        // Then divide the first component of the remainder by the first component
+        // of `other`, then the first component of -that- remainder by the first
+        // component of `other`, and so on until we have five terms we can
+        // renormalize.
+        let q0 = self.0 / other.0;
+        let mut r = self.add_unchecked(-mul_f64(other, q0));
+
+        let q1 = r.0 / other.0;
+        r = r.add_unchecked(-mul_f64(other, q1));
+
+        let q2 = r.0 / other.0;
+        r = r.add_unchecked(-mul_f64(other, q2));
+
+        let q3 = r.0 / other.0;
+        r = r.add_unchecked(-mul_f64(other, q3));
+
+        let q4 = r.0 / other.0;
+
+        let (a, b, c, d) = u::renorm5(q0, q1, q2, q3, q4);
+        Quad(a, b, c, d)
+    }
+}
+
 impl Quad {
     // Precalc functions
     //
@@ -356,6 +669,147 @@ mod tests {
             Quad::ONE / Quad::NAN;
     );
 
+    // mixed tests
+    test_all_near!(
+        quad_double:
+            qd!("0.8652559794322650872177747896460887926106441865097971890284395283700"),
+            Quad::E / Double::PI;
+        double_quad:
+            qd!("1.155727349790921717910093183312697400835095059453827452535458513190"),
+            Double::PI / Quad::E;
+    );
+    test_all_exact!(
+        quad_double_inf:
+            Quad::INFINITY,
+            Quad::INFINITY / Double::ONE;
+        double_quad_inf:
+            Quad::INFINITY,
+            Double::INFINITY / Quad::ONE;
+        quad_double_nan:
+            Quad::NAN,
+            Quad::ONE / Double::NAN;
+        double_quad_nan:
+            Quad::NAN,
+            Double::NAN / Quad::ONE;
+    );
+
+    // f64 tests
+    test_all_exact!(
+        f64_int:
+            qd!(3),
+            qd!(6) / 2.0;
+        f64_rev_int:
+            qd!(3),
+            6.0 / qd!(2);
+        f64_id:
+            Quad::PI,
+            Quad::PI / 1.0;
+        f64_zero:
+            Quad::INFINITY,
+            Quad::PI / 0.0;
+        f64_zero_zero:
+            Quad::NAN,
+            Quad::ZERO / 0.0;
+        f64_inf:
+            Quad::ZERO,
+            Quad::PI / f64::INFINITY;
+        f64_nan:
+            Quad::NAN,
+            Quad::ONE / f64::NAN;
+        f64_rev_nan:
+            Quad::NAN,
+            f64::NAN / Quad::ONE;
+    );
+    test_all_near!(
+        f64_num:
+            Quad::PI / Quad(1.1, 0.0, 0.0, 0.0),
+            Quad::PI / 1.1;
+        f64_ref:
+            Quad::PI / Quad(1.1, 0.0, 0.0, 0.0),
+            &Quad::PI / 1.1;
+        f64_rev_num:
+            Quad(1.1, 0.0, 0.0, 0.0) / Quad::PI,
+            1.1 / Quad::PI;
+        f64_rev_ref:
+            Quad(1.1, 0.0, 0.0, 0.0) / Quad::PI,
+            1.1 / &Quad::PI;
+        f64_third:
+            Quad::E / Quad(3.0, 0.0, 0.0, 0.0),
+            Quad::E / 3.0;
+    );
+
+    // checked_div tests
+    test_all_eq!(
+        checked_div_finite:
+            Some(qd!(2)),
+            qd!(6).checked_div(qd!(3));
+        checked_div_overflow:
+            None,
+            Quad::MAX.checked_div(qd!(0.5));
+        checked_div_zero:
+            None,
+            qd!(1).checked_div(Quad::ZERO);
+        checked_div_neg_zero:
+            None,
+            qd!(1).checked_div(Quad::NEG_ZERO);
+        checked_div_zero_zero:
+            None,
+            Quad::ZERO.checked_div(Quad::ZERO);
+        checked_div_by_inf:
+            Some(Quad::ZERO),
+            qd!(1).checked_div(Quad::INFINITY);
+        checked_div_inf:
+            Some(Quad::INFINITY),
+            Quad::INFINITY.checked_div(qd!(2));
+    );
+    test_all_assert!(
+        checked_div_nan:
+            Quad::NAN.checked_div(qd!(1)).unwrap().is_nan();
+        checked_div_inf_inf:
+            Quad::INFINITY.checked_div(Quad::INFINITY).unwrap().is_nan();
+    );
+
+    // div_unchecked tests
+    test_all_exact!(
+        div_unchecked_num:
+            Quad::PI / Quad::E,
+            Quad::PI.div_unchecked(Quad::E);
+        div_unchecked_neg:
+            Quad::PI / -Quad::E,
+            Quad::PI.div_unchecked(-Quad::E);
+        div_unchecked_small:
+            Quad::E / qd!(3.7e-200),
+            Quad::E.div_unchecked(qd!(3.7e-200));
+        div_unchecked_int:
+            qd!(6) / qd!(3),
+            qd!(6).div_unchecked(qd!(3));
+    );
+
+    // integer tests
+    test_all_exact!(
+        int_i32:
+            qd!(1.5),
+            qd!(3) / 2;
+        int_rev_i32:
+            qd!(2),
+            6 / qd!(3);
+        int_u32:
+            qd!(1.5),
+            qd!(3) / 2u32;
+        int_rev_u32:
+            qd!(2),
+            6u32 / qd!(3);
+        int_neg:
+            qd!(-1.5),
+            qd!(3) / -2;
+        int_max:
+            qd!(2147483648.0) / qd!(4294967295.0),
+            qd!(2147483648.0) / u32::MAX;
+        int_ref:
+            Quad::PI / Quad::from(2.0),
+            &Quad::PI / 2;
+    );
+
     // Assign tests. Assign code delegates to div code, so there's no need to re-test all
     // of the cases above.
     test_all!(
@@ -369,5 +823,21 @@ mod tests {
             b /= &Quad::E;
             near!(qd!("1.1557273497909217179100931833126962991208510231644158204997065353273"), b);
         }
+        assign_f64: {
+            let mut c = Quad::PI;
+            c /= 1.1;
+            near!(Quad::PI / Quad(1.1, 0.0, 0.0, 0.0), c);
+        }
+        assign_int: {
+            let mut e = Quad::PI;
+            e /= 2;
+            e /= 3u32;
+            exact!(Quad::PI / 2.0 / 3.0, e);
+        }
+        assign_double: {
+            let mut d = Quad::PI;
+            d /= Double::E;
+            exact!(Quad::PI / Quad::from(Double::E), d);
+        }
     );
 }
@@ -0,0 +1,578 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Implementations of the `simba` scalar traits, which are what `nalgebra` requires of the
+// elements of its vectors and matrices. Most of the methods are the inherent ones of the same
+// names; the rest come from the `num-traits` `Float` implementation.
+
+use crate::quad::common as c;
+use crate::quad::Quad;
+use num_traits::Float;
+use simba::scalar::{ComplexField, Field, RealField, SubsetOf};
+use simba::simd::{PrimitiveSimdValue, SimdValue};
+
+impl SimdValue for Quad {
+    const LANES: usize = 1;
+    type Element = Quad;
+    type SimdBool = bool;
+
+    #[inline]
+    fn splat(val: Quad) -> Quad {
+        val
+    }
+
+    #[inline]
+    fn extract(&self, _: usize) -> Quad {
+        *self
+    }
+
+    #[inline]
+    unsafe fn extract_unchecked(&self, _: usize) -> Quad {
+        *self
+    }
+
+    #[inline]
+    fn replace(&mut self, _: usize, val: Quad) {
+        *self = val;
+    }
+
+    #[inline]
+    unsafe fn replace_unchecked(&mut self, _: usize, val: Quad) {
+        *self = val;
+    }
+
+    #[inline]
+    fn select(self, cond: bool, other: Quad) -> Quad {
+        if cond {
+            self
+        } else {
+            other
+        }
+    }
+}
+
+impl PrimitiveSimdValue for Quad {}
+
+impl Field for Quad {}
+
+impl SubsetOf<Quad> for Quad {
+    #[inline]
+    fn to_superset(&self) -> Quad {
+        *self
+    }
+
+    #[inline]
+    fn from_superset_unchecked(element: &Quad) -> Quad {
+        *element
+    }
+
+    #[inline]
+    fn is_in_subset(_: &Quad) -> bool {
+        true
+    }
+}
+
+impl SubsetOf<Quad> for f64 {
+    #[inline]
+    fn to_superset(&self) -> Quad {
+        Quad(*self, 0.0, 0.0, 0.0)
+    }
+
+    /// Converts a `Quad` to an `f64` by taking its first component, which is the `f64`
+    /// nearest to it.
+    #[inline]
+    fn from_superset_unchecked(element: &Quad) -> f64 {
+        element.0
+    }
+
+    #[inline]
+    fn is_in_subset(_: &Quad) -> bool {
+        true
+    }
+}
+
+impl SubsetOf<Quad> for f32 {
+    #[inline]
+    fn to_superset(&self) -> Quad {
+        Quad(*self as f64, 0.0, 0.0, 0.0)
+    }
+
+    #[inline]
+    fn from_superset_unchecked(element: &Quad) -> f32 {
+        f32::from(*element)
+    }
+
+    #[inline]
+    fn is_in_subset(_: &Quad) -> bool {
+        true
+    }
+}
+
+impl ComplexField for Quad {
+    type RealField = Quad;
+
+    #[inline]
+    fn from_real(re: Quad) -> Quad {
+        re
+    }
+
+    #[inline]
+    fn real(self) -> Quad {
+        self
+    }
+
+    #[inline]
+    fn imaginary(self) -> Quad {
+        Quad::ZERO
+    }
+
+    #[inline]
+    fn modulus(self) -> Quad {
+        self.abs()
+    }
+
+    #[inline]
+    fn modulus_squared(self) -> Quad {
+        self.sqr()
+    }
+
+    #[inline]
+    fn argument(self) -> Quad {
+        if self >= Quad::ZERO {
+            Quad::ZERO
+        } else {
+            Quad::PI
+        }
+    }
+
+    #[inline]
+    fn norm1(self) -> Quad {
+        self.abs()
+    }
+
+    #[inline]
+    fn scale(self, factor: Quad) -> Quad {
+        self * factor
+    }
+
+    #[inline]
+    fn unscale(self, factor: Quad) -> Quad {
+        self / factor
+    }
+
+    #[inline]
+    fn floor(self) -> Quad {
+        self.floor()
+    }
+
+    #[inline]
+    fn ceil(self) -> Quad {
+        self.ceil()
+    }
+
+    #[inline]
+    fn round(self) -> Quad {
+        self.round()
+    }
+
+    #[inline]
+    fn trunc(self) -> Quad {
+        self.trunc()
+    }
+
+    #[inline]
+    fn fract(self) -> Quad {
+        self.fract()
+    }
+
+    #[inline]
+    fn mul_add(self, a: Quad, b: Quad) -> Quad {
+        Float::mul_add(self, a, b)
+    }
+
+    #[inline]
+    fn abs(self) -> Quad {
+        self.abs()
+    }
+
+    #[inline]
+    fn hypot(self, other: Quad) -> Quad {
+        Float::hypot(self, other)
+    }
+
+    #[inline]
+    fn recip(self) -> Quad {
+        self.recip()
+    }
+
+    #[inline]
+    fn conjugate(self) -> Quad {
+        self
+    }
+
+    #[inline]
+    fn sin(self) -> Quad {
+        self.sin()
+    }
+
+    #[inline]
+    fn cos(self) -> Quad {
+        self.cos()
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Quad, Quad) {
+        self.sin_cos()
+    }
+
+    #[inline]
+    fn sinh_cosh(self) -> (Quad, Quad) {
+        self.sinh_cosh()
+    }
+
+    #[inline]
+    fn tan(self) -> Quad {
+        self.tan()
+    }
+
+    #[inline]
+    fn asin(self) -> Quad {
+        self.asin()
+    }
+
+    #[inline]
+    fn acos(self) -> Quad {
+        self.acos()
+    }
+
+    #[inline]
+    fn atan(self) -> Quad {
+        self.atan()
+    }
+
+    #[inline]
+    fn sinh(self) -> Quad {
+        self.sinh()
+    }
+
+    #[inline]
+    fn cosh(self) -> Quad {
+        self.cosh()
+    }
+
+    #[inline]
+    fn tanh(self) -> Quad {
+        self.tanh()
+    }
+
+    #[inline]
+    fn asinh(self) -> Quad {
+        self.asinh()
+    }
+
+    #[inline]
+    fn acosh(self) -> Quad {
+        self.acosh()
+    }
+
+    #[inline]
+    fn atanh(self) -> Quad {
+        self.atanh()
+    }
+
+    #[inline]
+    fn log(self, base: Quad) -> Quad {
+        self.log(base)
+    }
+
+    #[inline]
+    fn log2(self) -> Quad {
+        self.log2()
+    }
+
+    #[inline]
+    fn log10(self) -> Quad {
+        self.log10()
+    }
+
+    #[inline]
+    fn ln(self) -> Quad {
+        self.ln()
+    }
+
+    #[inline]
+    fn ln_1p(self) -> Quad {
+        Float::ln_1p(self)
+    }
+
+    #[inline]
+    fn sqrt(self) -> Quad {
+        self.sqrt()
+    }
+
+    #[inline]
+    fn exp(self) -> Quad {
+        self.exp()
+    }
+
+    #[inline]
+    fn exp2(self) -> Quad {
+        Float::exp2(self)
+    }
+
+    #[inline]
+    fn exp_m1(self) -> Quad {
+        Float::exp_m1(self)
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> Quad {
+        self.powi(n)
+    }
+
+    #[inline]
+    fn powf(self, n: Quad) -> Quad {
+        self.powf(n)
+    }
+
+    /// Raises the `Quad` to a `Quad` power. For real numbers, this is the same as
+    /// [`powf`].
+    ///
+    /// [`powf`]: #method.powf
+    #[inline]
+    fn powc(self, n: Quad) -> Quad {
+        self.powf(n)
+    }
+
+    #[inline]
+    fn cbrt(self) -> Quad {
+        self.cbrt()
+    }
+
+    #[inline]
+    fn is_finite(&self) -> bool {
+        Quad::is_finite(*self)
+    }
+
+    /// Calculates the square root of the `Quad`, or returns `None` if it's negative.
+    #[inline]
+    fn try_sqrt(self) -> Option<Quad> {
+        if self >= Quad::ZERO {
+            Some(self.sqrt())
+        } else {
+            None
+        }
+    }
+}
+
+impl RealField for Quad {
+    #[inline]
+    fn is_sign_positive(&self) -> bool {
+        Quad::is_sign_positive(*self)
+    }
+
+    #[inline]
+    fn is_sign_negative(&self) -> bool {
+        Quad::is_sign_negative(*self)
+    }
+
+    #[inline]
+    fn copysign(self, sign: Quad) -> Quad {
+        Float::copysign(self, sign)
+    }
+
+    #[inline]
+    fn max(self, other: Quad) -> Quad {
+        Float::max(self, other)
+    }
+
+    #[inline]
+    fn min(self, other: Quad) -> Quad {
+        Float::min(self, other)
+    }
+
+    #[inline]
+    fn clamp(self, min: Quad, max: Quad) -> Quad {
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    fn atan2(self, other: Quad) -> Quad {
+        self.atan2(other)
+    }
+
+    /// Returns [`MIN`], the smallest finite `Quad`.
+    ///
+    /// [`MIN`]: #associatedconstant.MIN
+    #[inline]
+    fn min_value() -> Option<Quad> {
+        Some(Quad::MIN)
+    }
+
+    /// Returns [`MAX`], the largest finite `Quad`.
+    ///
+    /// [`MAX`]: #associatedconstant.MAX
+    #[inline]
+    fn max_value() -> Option<Quad> {
+        Some(Quad::MAX)
+    }
+
+    #[inline]
+    fn pi() -> Quad {
+        Quad::PI
+    }
+
+    #[inline]
+    fn two_pi() -> Quad {
+        c::mul_pwr2(Quad::PI, 2.0)
+    }
+
+    #[inline]
+    fn frac_pi_2() -> Quad {
+        Quad::FRAC_PI_2
+    }
+
+    #[inline]
+    fn frac_pi_3() -> Quad {
+        Quad::FRAC_PI_3
+    }
+
+    #[inline]
+    fn frac_pi_4() -> Quad {
+        Quad::FRAC_PI_4
+    }
+
+    #[inline]
+    fn frac_pi_6() -> Quad {
+        Quad::FRAC_PI_6
+    }
+
+    #[inline]
+    fn frac_pi_8() -> Quad {
+        Quad::FRAC_PI_8
+    }
+
+    #[inline]
+    fn frac_1_pi() -> Quad {
+        Quad::FRAC_1_PI
+    }
+
+    #[inline]
+    fn frac_2_pi() -> Quad {
+        Quad::FRAC_2_PI
+    }
+
+    #[inline]
+    fn frac_2_sqrt_pi() -> Quad {
+        Quad::FRAC_2_SQRT_PI
+    }
+
+    #[inline]
+    fn e() -> Quad {
+        Quad::E
+    }
+
+    #[inline]
+    fn log2_e() -> Quad {
+        Quad::LOG2_E
+    }
+
+    #[inline]
+    fn log10_e() -> Quad {
+        Quad::LOG10_E
+    }
+
+    #[inline]
+    fn ln_2() -> Quad {
+        Quad::LN_2
+    }
+
+    #[inline]
+    fn ln_10() -> Quad {
+        Quad::LN_10
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{Matrix3, Rotation2, Vector2, Vector3};
+
+    // A generic function of the sort that these traits are meant to support
+    fn circumference<T: RealField>(r: T) -> T {
+        T::two_pi() * r
+    }
+
+    // Checks that two matrices are equal to within `tol` in every element
+    fn close3(expected: &Matrix3<Quad>, actual: &Matrix3<Quad>, tol: Quad) {
+        for (e, a) in expected.iter().zip(actual.iter()) {
+            assert!((*e - *a).abs() < tol, "expected {}, actual {}", e, a);
+        }
+    }
+
+    #[rustfmt::skip]
+    fn matrix() -> Matrix3<Quad> {
+        Matrix3::new(
+            qd!(4), qd!(-2), qd!(1),
+            qd!(3), qd!(6), qd!(-4),
+            qd!(2), qd!(1), qd!(8),
+        )
+    }
+
+    // real_field tests
+    test!(real_field_generic: {
+        exact!(Quad::PI * qd!(4), circumference(qd!(2)));
+        exact!(Quad::PI, <Quad as RealField>::pi());
+        exact!(Quad::MAX, <Quad as RealField>::max_value().unwrap());
+        exact!(qd!(2), RealField::clamp(qd!(5), qd!(-2), qd!(2)));
+        exact!(qd!(-3), RealField::copysign(qd!(3), qd!(-1)));
+        exact!(qd!(1), RealField::max(Quad::NAN, qd!(1)));
+    });
+    test!(complex_field_methods: {
+        exact!(Quad::ZERO, ComplexField::argument(qd!(2)));
+        exact!(Quad::PI, ComplexField::argument(qd!(-2)));
+        exact!(qd!(9), ComplexField::modulus_squared(qd!(-3)));
+        assert!(ComplexField::try_sqrt(qd!(-1)).is_none());
+        near!(qd!(3), ComplexField::try_sqrt(qd!(9)).unwrap());
+    });
+    test!(subset_of: {
+        let x: Quad = simba::scalar::SupersetOf::<f64>::from_subset(&1.5);
+        exact!(qd!(1.5), x);
+        let y: f64 = simba::scalar::SupersetOf::<f64>::to_subset_unchecked(&Quad::PI);
+        exact!(Quad::PI.0, y);
+    });
+
+    // nalgebra tests
+    test!(nalgebra_inverse: {
+        let m = matrix();
+        let inv = m.try_inverse().unwrap();
+        close3(&Matrix3::identity(), &(m * inv), qd!(1e-60));
+    });
+    test!(nalgebra_lu_solve: {
+        let m = matrix();
+        let x = Vector3::new(qd!(1) / qd!(3), Quad::PI, -Quad::E);
+        let b = m * x;
+        let solved = m.lu().solve(&b).unwrap();
+        for (e, a) in x.iter().zip(solved.iter()) {
+            assert!((*e - *a).abs() < qd!(1e-60), "expected {}, actual {}", e, a);
+        }
+    });
+    test!(nalgebra_determinant: {
+        // 4(48 + 4) + 2(24 + 8) + (3 - 12)
+        near!(qd!(263), matrix().determinant());
+    });
+    test!(nalgebra_rotation: {
+        let rot = Rotation2::new(Quad::FRAC_PI_6);
+        let v = rot * Vector2::new(qd!(2), Quad::ZERO);
+        near!(qd!(3).sqrt(), v[0]);
+        near!(qd!(1), v[1]);
+    });
+}
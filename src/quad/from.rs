@@ -3,9 +3,20 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+#[cfg(feature = "num-bigint")]
+use crate::common::bigint as b;
+use crate::common::primitive as p;
 use crate::common::utils as u;
 use crate::double::Double;
+use crate::error::{ParseQuadError, TryFromErrorKind, TryFromQuadError};
 use crate::quad::Quad;
+#[cfg(feature = "half")]
+use half::{bf16, f16};
+#[cfg(any(feature = "num-rational", feature = "bigdecimal"))]
+use num_bigint::Sign;
+#[cfg(feature = "num-bigint")]
+use num_bigint::{BigInt, BigUint};
+use std::convert::TryFrom;
 use std::f64;
 
 #[inline]
@@ -336,6 +347,107 @@ from_float_impl! {
     f64
 }
 
+#[cfg(feature = "num-bigint")]
+impl From<&BigInt> for Quad {
+    /// Generates a `Quad` from a reference to a [`BigInt`].
+    ///
+    /// The integer is rounded to the nearest `f64`, and what's left of it is rounded to the
+    /// nearest `f64` again to give the next component, and so on, with ties going to even
+    /// each time. That's the nearest `Quad` to the integer, and an integer with no more than
+    /// 212 significant bits converts exactly. Integers too large in magnitude to be
+    /// represented become [`INFINITY`] or [`NEG_INFINITY`].
+    ///
+    /// This is only available when the `num-bigint` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use num_bigint::BigInt;
+    ///
+    /// let n = BigInt::from(1) << 200;
+    /// assert!(Quad::from(&n) == qd!(2).powi(200));
+    ///
+    /// let m = BigInt::from(10).pow(70) + 1;
+    /// assert!(Quad::from(&m) == qd!(10).powi(70));
+    /// ```
+    ///
+    /// [`BigInt`]: https://docs.rs/num-bigint/0.4/num_bigint/struct.BigInt.html
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    fn from(n: &BigInt) -> Quad {
+        let parts = b::split_fraction(n.clone(), BigInt::from(1), 4);
+        Quad(parts[0], parts[1], parts[2], parts[3])
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl From<&BigUint> for Quad {
+    /// Generates a `Quad` from a reference to a [`BigUint`].
+    ///
+    /// The integer is rounded one component at a time, just like a [`BigInt`], which gives
+    /// the nearest `Quad` to it. Integers too large to be represented become [`INFINITY`].
+    ///
+    /// This is only available when the `num-bigint` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = BigUint::from(1u32) << 200;
+    /// assert!(Quad::from(&n) == qd!(2).powi(200));
+    /// ```
+    ///
+    /// [`BigUint`]: https://docs.rs/num-bigint/0.4/num_bigint/struct.BigUint.html
+    /// [`BigInt`]: https://docs.rs/num-bigint/0.4/num_bigint/struct.BigInt.html
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    fn from(n: &BigUint) -> Quad {
+        let parts = b::split_fraction(BigInt::from(n.clone()), BigInt::from(1), 4);
+        Quad(parts[0], parts[1], parts[2], parts[3])
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl Quad {
+    /// Returns the integer part of the `Quad` as a [`BigInt`], or `None` if the `Quad` is
+    /// `NaN` or infinite.
+    ///
+    /// The fractional part is discarded, as with [`trunc`]. Every integer that a `Quad` can
+    /// hold is converted exactly, no matter how large it is, so this is the inverse of the
+    /// conversion from a `BigInt` for any integer with no more than 212 significant bits.
+    ///
+    /// This is only available when the `num-bigint` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use num_bigint::BigInt;
+    ///
+    /// assert!(qd!(-12345.75).to_bigint() == Some(BigInt::from(-12345)));
+    ///
+    /// let x = qd!(2).powi(200) + qd!(3);
+    /// assert!(x.to_bigint() == Some((BigInt::from(1) << 200) + 3));
+    ///
+    /// assert!(Quad::NAN.to_bigint().is_none());
+    /// ```
+    ///
+    /// [`BigInt`]: https://docs.rs/num-bigint/0.4/num_bigint/struct.BigInt.html
+    /// [`trunc`]: #method.trunc
+    pub fn to_bigint(self) -> Option<BigInt> {
+        if self.is_finite() {
+            let t = self.trunc();
+            Some(
+                [t.0, t.1, t.2, t.3]
+                    .iter()
+                    .map(|&x| b::from_integral(x))
+                    .sum(),
+            )
+        } else {
+            None
+        }
+    }
+}
+
 impl From<(f64, f64)> for Quad {
     /// Generates a `Quad` from a 2-tuple of `f64`s.
     ///
@@ -429,55 +541,67 @@ impl From<(f64, f64, f64, f64)> for Quad {
 }
 
 impl From<Double> for Quad {
-    /// Generates a `Quad` from a `Double`.
+    /// Generates a `Quad` from a `Double`, exactly.
+    ///
+    /// The components of the `Double` become the first two components of the `Quad`, and
+    /// the other two are zero. The `Quad` has exactly the same value as the `Double`, so it
+    /// has the same precision to begin with; the extra precision is available for whatever
+    /// is calculated from it. That makes this the way to take a calculation that's been
+    /// working fine with `Double`s and redo the parts of it that need more precision.
     ///
-    /// The new `Quad`'s third and fourth components will be used to account for
-    /// floating-point rounding error at the end of the `Double`, but it will of course
-    /// otherwise only have the precision of the `Double` used to make it.
+    /// Use [`to_double`] to go back the other way.
     ///
     /// # Examples
     /// ```
-    /// # use qd::{dd, qd, Quad};
-    /// let expected = Quad::from("0.9999999303082806237436760862691");
-    /// let a = (dd!(3).powi(15) - dd!(1)) / dd!(3).powi(15);
+    /// # use qd::{dd, qd, Double, Quad};
+    /// let a = Double::ONE / Double::from(3.0);
     /// let x = Quad::from(a);
+    /// assert!(x.to_double() == a);
     ///
-    /// let diff = (x - expected).abs();
-    /// assert!(diff < qd!(1e-60));
+    /// // Exact, so the difference is just the error in the `Double`
+    /// let diff = (x - qd!(1) / qd!(3)).abs();
+    /// assert!(diff > qd!(1e-33) && diff < qd!(1e-32));
     /// ```
+    ///
+    /// [`to_double`]: #method.to_double
+    #[inline]
     fn from(a: Double) -> Quad {
-        a.to_string().parse().unwrap()
+        Quad(a[0], a[1], 0.0, 0.0)
     }
 }
 
-impl From<&str> for Quad {
-    /// Parses a string to create a `Quad`.
-    ///
-    /// The parser works pretty similarly to parsers for `f32` and `f64`. It will fail if
-    /// characters are present that are not digits, decimal points, signs, or exponent
-    /// markers. It will also fail if there are multiples of these or if they're in the
-    /// wrong places; two decimal points or a negative sign after the number will both be
-    /// rejected, for instance.
+impl TryFrom<&str> for Quad {
+    type Error = ParseQuadError;
+
+    /// Parses a string to create a `Quad`, returning an error if the string isn't a valid
+    /// number.
     ///
-    /// Failure will return [`NAN`]. This can be an issue because parsing the string `"nan"`
-    /// *also* produces [`NAN`]. For this reason it's suggested to use [`from_str`] (or its
-    /// associated `parse` function) instead of this function if there is any chance that
-    /// the parsed string will be legitimately [`NAN`].
+    /// This is the same as [`from_str`] (and `parse`), and is meant for strings that come
+    /// from outside of the program, like user input, files, and network requests. The
+    /// [`qd!`] macro also takes a string, but it panics if the string doesn't parse, since
+    /// it's meant for literals written into the code.
     ///
     /// # Examples
     /// ```
     /// # use qd::{qd, Quad};
-    /// let expected = (qd!(3).powi(15) - qd!(1)) / qd!(3).powi(15);
-    /// let x = Quad::from("0.9999999303082806237436760862691492808476631704421807180156648865");
+    /// use qd::error::{ErrorKind, ParseQuadError};
+    /// use std::convert::TryFrom;
     ///
+    /// let expected = (qd!(3).powi(15) - qd!(1)) / qd!(3).powi(15);
+    /// let x = Quad::try_from("0.9999999303082806237436760862691492808476631704421807180156648865").unwrap();
     /// let diff = (x - expected).abs();
     /// assert!(diff < qd!(1e-60));
+    ///
+    /// let err = Quad::try_from("1.2.3").unwrap_err();
+    /// assert!(err == ParseQuadError { kind: ErrorKind::TooManyPoints, position: 3 });
+    /// assert!(Quad::try_from("").unwrap_err().kind == ErrorKind::Empty);
     /// ```
     ///
-    /// [`NAN`]: #associatedconstant.NAN
     /// [`from_str`]: #method.from_str
-    fn from(s: &str) -> Quad {
-        s.parse().unwrap_or(Quad::NAN)
+    /// [`qd!`]: macro.qd.html
+    #[inline]
+    fn try_from(s: &str) -> Result<Quad, ParseQuadError> {
+        s.parse()
     }
 }
 
@@ -487,10 +611,10 @@ impl From<Quad> for f64 {
     /// This will lose precision if the second component of the `Quad` is not 0, but it
     /// will not lose range.
     ///
-    /// No other conversions from `Quad` to numeric types are provided, as every other one
-    /// has the capability of losing range (for example, no other type could be used to
-    /// represent `qd!(1e308)`). Casts can be made from the `f64` provided by this function
-    /// to other numeric types as needed.
+    /// The only other conversions from `Quad` to primitive numeric types are the one into
+    /// `f32` and the `TryFrom` conversions into integers, as every other one has the
+    /// capability of losing range (for example, no integer type could be used to represent
+    /// `qd!(1e308)`).
     ///
     /// # Examples
     /// ```
@@ -507,6 +631,146 @@ impl From<Quad> for f64 {
     }
 }
 
+impl From<Quad> for f32 {
+    /// Converts a `Quad` into an `f32`, correctly rounded.
+    ///
+    /// The whole `Quad` is rounded to the nearest `f32`, with ties going to the one with an
+    /// even last bit. That's not always the same as rounding just its first component, since
+    /// the other components can decide which way a tie goes. This will lose range as well as
+    /// precision; a `Quad` too large in magnitude for an `f32` becomes an infinity, and one
+    /// too small becomes an `f32` subnormal or zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(f32::from(Quad::PI) == std::f32::consts::PI);
+    ///
+    /// // Exactly halfway between two `f32`s in the first component, but not in the whole
+    /// let x = qd!(1) + qd!(2).powi(-24) + qd!(2).powi(-80);
+    /// assert!(f32::from(x) == 1.0000001);
+    /// assert!(f64::from(x) as f32 == 1.0);
+    ///
+    /// assert!(f32::from(qd!(1e300)) == f32::INFINITY);
+    /// ```
+    #[inline]
+    fn from(a: Quad) -> f32 {
+        u::round_to_odd(a.0, a.1 + a.2 + a.3) as f32
+    }
+}
+
+#[cfg(feature = "half")]
+impl From<f16> for Quad {
+    /// Generates a `Quad` from a [`half::f16`], exactly.
+    ///
+    /// Every `f16` is also an `f64`, so it becomes the first component of the `Quad` as it
+    /// is. Unlike the conversion from an `f32`, this doesn't treat the `f16` as the shortest
+    /// decimal number that would round to it; few `f16`s are close enough to a short decimal
+    /// for that to be useful.
+    ///
+    /// This is only available when the `half` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use half::f16;
+    ///
+    /// assert!(Quad::from(f16::from_f32(-1.5)) == qd!(-1.5));
+    /// assert!(Quad::from(f16::MAX) == qd!(65504));
+    /// ```
+    ///
+    /// [`half::f16`]: https://docs.rs/half/2/half/struct.f16.html
+    #[inline]
+    fn from(a: f16) -> Quad {
+        Quad(a.to_f64(), 0.0, 0.0, 0.0)
+    }
+}
+
+#[cfg(feature = "half")]
+impl From<bf16> for Quad {
+    /// Generates a `Quad` from a [`half::bf16`], exactly.
+    ///
+    /// Every `bf16` is also an `f64`, so it becomes the first component of the `Quad` as it
+    /// is.
+    ///
+    /// This is only available when the `half` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use half::bf16;
+    ///
+    /// assert!(Quad::from(bf16::from_f32(0.375)) == qd!(0.375));
+    /// ```
+    ///
+    /// [`half::bf16`]: https://docs.rs/half/2/half/struct.bf16.html
+    #[inline]
+    fn from(a: bf16) -> Quad {
+        Quad(a.to_f64(), 0.0, 0.0, 0.0)
+    }
+}
+
+#[cfg(feature = "half")]
+impl From<Quad> for f16 {
+    /// Converts a `Quad` into a [`half::f16`], correctly rounded.
+    ///
+    /// The whole `Quad` is rounded to the nearest `f16`, with ties going to the one with an
+    /// even last bit. A `Quad` too large in magnitude for an `f16` (anything from 65520 on)
+    /// becomes an infinity, and one too small becomes an `f16` subnormal or zero.
+    ///
+    /// This is only available when the `half` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use half::f16;
+    ///
+    /// assert!(f16::from(qd!(1) / qd!(3)) == f16::from_f32(0.33325195));
+    /// assert!(f16::from(qd!(65519)) == f16::MAX);
+    /// assert!(f16::from(qd!(65520)) == f16::INFINITY);
+    /// ```
+    ///
+    /// [`half::f16`]: https://docs.rs/half/2/half/struct.f16.html
+    #[inline]
+    fn from(a: Quad) -> f16 {
+        // The rounded value is exactly an `f16` (or is too large for one), so `from_f64`
+        // doesn't have to round it again
+        f16::from_f64(u::round_to_format(
+            u::round_to_odd(a.0, a.1 + a.2 + a.3),
+            11,
+            -24,
+        ))
+    }
+}
+
+#[cfg(feature = "half")]
+impl From<Quad> for bf16 {
+    /// Converts a `Quad` into a [`half::bf16`], correctly rounded.
+    ///
+    /// The whole `Quad` is rounded to the nearest `bf16`, with ties going to the one with an
+    /// even last bit. A `Quad` too large in magnitude for a `bf16` becomes an infinity, and
+    /// one too small becomes a `bf16` subnormal or zero.
+    ///
+    /// This is only available when the `half` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use half::bf16;
+    ///
+    /// assert!(bf16::from(Quad::PI) == bf16::from_f32(3.140625));
+    /// ```
+    ///
+    /// [`half::bf16`]: https://docs.rs/half/2/half/struct.bf16.html
+    #[inline]
+    fn from(a: Quad) -> bf16 {
+        bf16::from_f64(u::round_to_format(
+            u::round_to_odd(a.0, a.1 + a.2 + a.3),
+            8,
+            -133,
+        ))
+    }
+}
+
 impl From<Quad> for (f64, f64) {
     /// Converts a `Quad` into a 2-tuple of `f64`s.
     ///
@@ -576,9 +840,424 @@ impl From<Quad> for (f64, f64, f64, f64) {
     }
 }
 
+impl From<[f64; 4]> for Quad {
+    /// Generates a `Quad` from an array of four `f64`s.
+    ///
+    /// This is the same as [`from_array`]. Like the conversion from a tuple, it uses the
+    /// elements of the array as the components of the `Quad` as they are, without
+    /// renormalizing them or accounting for rounding error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let a = Quad::from([
+    ///     3.141592653589793e0,
+    ///     1.2246467991473532e-16,
+    ///     -2.9947698097183397e-33,
+    ///     1.1124542208633655e-49,
+    /// ]);
+    /// assert!(a == Quad::PI);
+    /// ```
+    ///
+    /// [`from_array`]: #method.from_array
+    #[inline]
+    fn from(a: [f64; 4]) -> Quad {
+        Quad::from_array(a)
+    }
+}
+
+impl From<Quad> for [f64; 4] {
+    /// Converts a `Quad` into an array of its four components.
+    ///
+    /// This is the same as [`to_array`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let a = <[f64; 4]>::from(Quad::PI);
+    /// assert!(a[0] == 3.141592653589793e0);
+    /// assert!(a[3] == 1.1124542208633655e-49);
+    /// ```
+    ///
+    /// [`to_array`]: #method.to_array
+    #[inline]
+    fn from(a: Quad) -> [f64; 4] {
+        a.to_array()
+    }
+}
+
+impl AsRef<[f64; 4]> for Quad {
+    /// Borrows the components of the `Quad` as an array.
+    ///
+    /// No copying is involved, since a `Quad` has the same layout in memory as an array of
+    /// four `f64`s. A reference to the array can be passed along to FFI or SIMD code that
+    /// wants a pointer to four `f64`s.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let x = Quad::PI;
+    /// let a: &[f64; 4] = x.as_ref();
+    /// assert!(a[0] == 3.141592653589793e0);
+    /// assert!(a[3] == 1.1124542208633655e-49);
+    /// ```
+    #[inline]
+    fn as_ref(&self) -> &[f64; 4] {
+        // SAFETY: `Quad` is `#[repr(C)]` and made up of nothing but four `f64`s, so it has
+        // the same size, alignment, and layout as `[f64; 4]`.
+        unsafe { &*(self as *const Quad as *const [f64; 4]) }
+    }
+}
+
+// TO INTEGER IMPLEMENTATIONS
+//
+// These truncate toward zero, the same way that casting an `f64` to an integer does, but
+// they fail instead of saturating when the result doesn't fit into the target type. The
+// truncated `Quad`'s components are all integers, so their sum just needs to be checked
+// against the range of the target type before it's calculated.
+
+fn to_int(x: Quad, min: Quad, max: Quad) -> Result<i128, TryFromQuadError> {
+    if x.is_nan() {
+        Err(TryFromQuadError {
+            kind: TryFromErrorKind::NaN,
+        })
+    } else if x.is_infinite() {
+        Err(TryFromQuadError {
+            kind: TryFromErrorKind::Infinite,
+        })
+    } else {
+        let t = x.trunc();
+        if t < min || t > max {
+            Err(TryFromQuadError {
+                kind: TryFromErrorKind::OutOfRange,
+            })
+        } else {
+            Ok(u::wrapping_i128(t.0)
+                .wrapping_add(u::wrapping_i128(t.1))
+                .wrapping_add(u::wrapping_i128(t.2))
+                .wrapping_add(u::wrapping_i128(t.3)))
+        }
+    }
+}
+
+macro_rules! try_from_quad_impl {
+    ($(
+        $(#[$m:meta])*
+        $t:ident
+    )*) => ($(
+        impl TryFrom<Quad> for $t {
+            type Error = TryFromQuadError;
+
+            $(#[$m])*
+            fn try_from(x: Quad) -> Result<$t, TryFromQuadError> {
+                to_int(x, Quad::from($t::MIN), Quad::from($t::MAX)).map(|n| n as $t)
+            }
+        }
+    )*);
+}
+
+try_from_quad_impl! {
+    /// Converts a `Quad` into an `i8`, truncating any fractional part.
+    ///
+    /// This fails with an error of the appropriate kind if the `Quad` is `NaN`, infinite,
+    /// or outside the range of an `i8` once it's been truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(i8::try_from(qd!(-128.9)) == Ok(-128));
+    /// assert!(i8::try_from(qd!(128)).is_err());
+    /// ```
+    i8
+    /// Converts a `Quad` into a `u8`, truncating any fractional part.
+    ///
+    /// This fails with an error of the appropriate kind if the `Quad` is `NaN`, infinite,
+    /// or outside the range of a `u8` once it's been truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(u8::try_from(qd!(-0.5)) == Ok(0));
+    /// assert!(u8::try_from(qd!(-1)).is_err());
+    /// ```
+    u8
+    /// Converts a `Quad` into an `i16`, truncating any fractional part.
+    ///
+    /// This fails with an error of the appropriate kind if the `Quad` is `NaN`, infinite,
+    /// or outside the range of an `i16` once it's been truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(i16::try_from(qd!(32767.5)) == Ok(32767));
+    /// assert!(i16::try_from(qd!(-32769)).is_err());
+    /// ```
+    i16
+    /// Converts a `Quad` into a `u16`, truncating any fractional part.
+    ///
+    /// This fails with an error of the appropriate kind if the `Quad` is `NaN`, infinite,
+    /// or outside the range of a `u16` once it's been truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(u16::try_from(qd!(65535.5)) == Ok(65535));
+    /// assert!(u16::try_from(qd!(65536)).is_err());
+    /// ```
+    u16
+    /// Converts a `Quad` into an `i32`, truncating any fractional part.
+    ///
+    /// This fails with an error of the appropriate kind if the `Quad` is `NaN`, infinite,
+    /// or outside the range of an `i32` once it's been truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(i32::try_from(qd!(-2.5)) == Ok(-2));
+    /// assert!(i32::try_from(Quad::NAN).is_err());
+    /// ```
+    i32
+    /// Converts a `Quad` into a `u32`, truncating any fractional part.
+    ///
+    /// This fails with an error of the appropriate kind if the `Quad` is `NaN`, infinite,
+    /// or outside the range of a `u32` once it's been truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(u32::try_from(qd!(4294967295.9)) == Ok(u32::MAX));
+    /// assert!(u32::try_from(Quad::INFINITY).is_err());
+    /// ```
+    u32
+    /// Converts a `Quad` into an `i64`, truncating any fractional part.
+    ///
+    /// This fails with an error of the appropriate kind if the `Quad` is `NaN`, infinite,
+    /// or outside the range of an `i64` once it's been truncated. Unlike a conversion
+    /// through an `f64`, every digit of an `i64` is kept.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use std::convert::TryFrom;
+    ///
+    /// let x = Quad::from(i64::MAX) - qd!(0.5);
+    /// assert!(i64::try_from(x) == Ok(i64::MAX - 1));
+    /// assert!(i64::try_from(Quad::from(i64::MAX) + qd!(1)).is_err());
+    /// ```
+    i64
+    /// Converts a `Quad` into a `u64`, truncating any fractional part.
+    ///
+    /// This fails with an error of the appropriate kind if the `Quad` is `NaN`, infinite,
+    /// or outside the range of a `u64` once it's been truncated. Unlike a conversion through
+    /// an `f64`, every digit of a `u64` is kept.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(u64::try_from(Quad::from(u64::MAX)) == Ok(u64::MAX));
+    /// assert!(u64::try_from(Quad::from(u64::MAX) * Quad::from(2)).is_err());
+    /// ```
+    u64
+    /// Converts a `Quad` into an `i128`, truncating any fractional part.
+    ///
+    /// This fails with an error of the appropriate kind if the `Quad` is `NaN`, infinite,
+    /// or outside the range of an `i128` once it's been truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(i128::try_from(qd!(2).powi(100) + qd!(0.5)) == Ok(1 << 100));
+    /// assert!(i128::try_from(qd!(2).powi(127)).is_err());
+    /// ```
+    i128
+    /// Converts a `Quad` into a `u128`, truncating any fractional part.
+    ///
+    /// This fails with an error of the appropriate kind if the `Quad` is `NaN`, infinite,
+    /// or outside the range of a `u128` once it's been truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(u128::try_from(Quad::from(u128::MAX)) == Ok(u128::MAX));
+    /// assert!(u128::try_from(qd!(2).powi(128)).is_err());
+    /// ```
+    u128
+}
+
+impl Quad {
+    /// Converts the `Quad` into a [`Double`], correctly rounded.
+    ///
+    /// The result's first component is the `Quad` rounded to the nearest `f64`, and its
+    /// second is the rest of the `Quad` rounded to the nearest `f64`, with ties going to the
+    /// one with an even last bit in both cases. The lower two components are rounded along
+    /// with the second rather than just being dropped, since they can decide which way a tie
+    /// goes. A `Quad` made from a `Double` converts back into exactly that `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, Quad};
+    /// assert!(Quad::PI.to_double() == Double::PI);
+    ///
+    /// let x = Double::ONE / Double::from(3.0);
+    /// assert!(Quad::from(x).to_double() == x);
+    /// ```
+    ///
+    /// [`Double`]: struct.Double.html
+    pub fn to_double(self) -> Double {
+        if self.0 == 0.0 || !self.0.is_finite() {
+            return Double::from((self.0, 0.0));
+        }
+        // Rounding the lower components to odd keeps the information about whether they're
+        // exactly on, above, or below the halfway point between two `f64`s that a component
+        // above them could round to, so each component can be rounded to nearest with only
+        // the ones below it rounded to odd
+        let odd = |a: f64, b: f64| {
+            let (s, e) = p::two_sum(a, b);
+            u::round_to_odd(s, e)
+        };
+        let rest = odd(self.2, self.3);
+        let hi = self.0 + odd(self.1, rest);
+        let lo = if hi == self.0 {
+            self.1 + rest
+        } else {
+            // The second component was exactly half of the spacing of `f64`s around the
+            // first, and the ones below it pushed the sum over into the next `f64`. The
+            // difference between the first two and that `f64` is exact.
+            (self.0 - hi + self.1) + rest
+        };
+        Double::from((hi, lo))
+    }
+
+    /// Converts the `Quad` into an `i64`, truncating any fractional part and saturating at
+    /// the bounds of an `i64`.
+    ///
+    /// This works the way that casting an `f64` to an `i64` does: a `Quad` too large in
+    /// either direction (including an infinity) becomes [`i64::MAX`] or [`i64::MIN`], and
+    /// `NaN` becomes 0. Use `i64::try_from` instead to get an error in those cases.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::from(i64::MAX) - qd!(0.5);
+    /// assert!(x.to_i64_trunc() == i64::MAX - 1);
+    /// assert!(qd!(-1e30).to_i64_trunc() == i64::MIN);
+    /// assert!(Quad::NAN.to_i64_trunc() == 0);
+    /// ```
+    ///
+    /// [`i64::MAX`]: https://doc.rust-lang.org/std/primitive.i64.html#associatedconstant.MAX
+    /// [`i64::MIN`]: https://doc.rust-lang.org/std/primitive.i64.html#associatedconstant.MIN
+    pub fn to_i64_trunc(self) -> i64 {
+        saturate(self, i64::try_from(self), i64::MIN, i64::MAX)
+    }
+
+    /// Converts the `Quad` into a `u64`, truncating any fractional part and saturating at
+    /// the bounds of a `u64`.
+    ///
+    /// This works the way that casting an `f64` to a `u64` does: a `Quad` too large
+    /// (including positive infinity) becomes [`u64::MAX`], one that's negative becomes 0, and
+    /// `NaN` becomes 0. Use `u64::try_from` instead to get an error in those cases.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::from(u64::MAX).to_u64_trunc() == u64::MAX);
+    /// assert!(qd!(-3.5).to_u64_trunc() == 0);
+    /// assert!(Quad::INFINITY.to_u64_trunc() == u64::MAX);
+    /// ```
+    ///
+    /// [`u64::MAX`]: https://doc.rust-lang.org/std/primitive.u64.html#associatedconstant.MAX
+    pub fn to_u64_trunc(self) -> u64 {
+        saturate(self, u64::try_from(self), u64::MIN, u64::MAX)
+    }
+
+    /// Converts the `Quad` into an `i128`, truncating any fractional part and saturating
+    /// at the bounds of an `i128`.
+    ///
+    /// This works the way that casting an `f64` to an `i128` does: a `Quad` too large in
+    /// either direction (including an infinity) becomes [`i128::MAX`] or [`i128::MIN`], and
+    /// `NaN` becomes 0. Use `i128::try_from` instead to get an error in those cases.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!((qd!(2).powi(100) + qd!(3.75)).to_i128_trunc() == (1 << 100) + 3);
+    /// assert!(Quad::NEG_INFINITY.to_i128_trunc() == i128::MIN);
+    /// ```
+    ///
+    /// [`i128::MAX`]: https://doc.rust-lang.org/std/primitive.i128.html#associatedconstant.MAX
+    /// [`i128::MIN`]: https://doc.rust-lang.org/std/primitive.i128.html#associatedconstant.MIN
+    pub fn to_i128_trunc(self) -> i128 {
+        saturate(self, i128::try_from(self), i128::MIN, i128::MAX)
+    }
+
+    /// Converts the `Quad` into a `u128`, truncating any fractional part and saturating
+    /// at the bounds of a `u128`.
+    ///
+    /// This works the way that casting an `f64` to a `u128` does: a `Quad` too large
+    /// (including positive infinity) becomes [`u128::MAX`], one that's negative becomes 0,
+    /// and `NaN` becomes 0. Use `u128::try_from` instead to get an error in those cases.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!((qd!(2).powi(120) - qd!(0.5)).to_u128_trunc() == (1 << 120) - 1);
+    /// assert!(qd!(1e300).to_u128_trunc() == u128::MAX);
+    /// ```
+    ///
+    /// [`u128::MAX`]: https://doc.rust-lang.org/std/primitive.u128.html#associatedconstant.MAX
+    pub fn to_u128_trunc(self) -> u128 {
+        saturate(self, u128::try_from(self), u128::MIN, u128::MAX)
+    }
+}
+
+fn saturate<T>(x: Quad, result: Result<T, TryFromQuadError>, min: T, max: T) -> T
+where
+    T: Default,
+{
+    match result {
+        Ok(n) => n,
+        Err(_) if x.is_nan() => T::default(),
+        Err(_) if x.is_sign_negative() => min,
+        Err(_) => max,
+    }
+}
+
+// Divides one integer by another, giving the nearest `Quad` to the exact quotient. Each
+// component is the nearest `f64` to what's left of the quotient once the ones before it have
+// been taken off. A zero divisor gives an infinity or NaN, as dividing by zero does.
+#[cfg(any(feature = "num-rational", feature = "bigdecimal"))]
+pub(super) fn from_quotient(n: &BigInt, d: &BigInt) -> Quad {
+    let (n, d) = match d.sign() {
+        Sign::NoSign => return Quad::from(n) / Quad::ZERO,
+        Sign::Minus => (-n, -d),
+        Sign::Plus => (n.clone(), d.clone()),
+    };
+    let parts = b::split_fraction(n, d, 4);
+    Quad(parts[0], parts[1], parts[2], parts[3])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::ErrorKind;
 
     // f32 tests
     test_all_exact!(
@@ -639,6 +1318,125 @@ mod tests {
         assert_ne!(qd!(1.1).1, 0.0);
     });
 
+    // to f32 tests
+    test!(to_f32_rounded: {
+        assert!(f32::from(qd!(1.5)) == 1.5);
+        assert!(f32::from(Quad::PI) == std::f32::consts::PI);
+        assert!(f32::from(-Quad::E) == -std::f32::consts::E);
+        assert!(f32::from(qd!(1) / qd!(3)) == 1.0 / 3.0);
+    });
+    test!(to_f32_ties: {
+        // 1 + 2⁻²⁴ is exactly halfway between two `f32`s, so the rest decides
+        assert!(f32::from(Quad(1.0 + 2f64.powi(-24), 0.0, 0.0, 0.0)) == 1.0);
+        assert!(f32::from(Quad(1.0 + 2f64.powi(-23) + 2f64.powi(-24), 0.0, 0.0, 0.0)) == 1.0000002);
+        assert!(f32::from(Quad(1.0 + 2f64.powi(-24), 2f64.powi(-80), 0.0, 0.0)) == 1.0000001);
+        assert!(f32::from(Quad(1.0 + 2f64.powi(-24), -2f64.powi(-80), 0.0, 0.0)) == 1.0);
+        assert!(f32::from(Quad(-1.0 - 2f64.powi(-24), -2f64.powi(-80), 0.0, 0.0)) == -1.0000001);
+        // If the second component is zero, the third breaks the tie
+        assert!(f32::from(Quad(1.0 + 2f64.powi(-24), 0.0, -2f64.powi(-100), 0.0)) == 1.0);
+        assert!(f32::from(Quad(1.0 + 2f64.powi(-24), 0.0, 2f64.powi(-100), 0.0)) == 1.0000001);
+    });
+    test!(to_f32_range: {
+        assert!(f32::from(qd!(1e300)) == f32::INFINITY);
+        assert!(f32::from(qd!(-1e300)) == f32::NEG_INFINITY);
+        assert!(f32::from(qd!(f32::MAX)) == f32::MAX);
+        assert!(f32::from(qd!(2).powi(-149)) == f32::from_bits(1));
+        assert!(f32::from(Quad(2f64.powi(-150), 2f64.powi(-210), 0.0, 0.0)) == f32::from_bits(1));
+        assert!(f32::from(qd!(2).powi(-150)) == 0.0);
+        assert!(f32::from(qd!(1e-300)) == 0.0);
+    });
+    test!(to_f32_special: {
+        assert!(f32::from(Quad::ZERO) == 0.0);
+        assert!(f32::from(Quad::NEG_ZERO).is_sign_negative());
+        assert!(f32::from(Quad::INFINITY) == f32::INFINITY);
+        assert!(f32::from(Quad::NEG_INFINITY) == f32::NEG_INFINITY);
+        assert!(f32::from(Quad::NAN).is_nan());
+    });
+
+    // half tests
+    #[cfg(feature = "half")]
+    test!(from_half: {
+        assert!(Quad::from(f16::from_f32(-1.5)) == qd!(-1.5));
+        assert!(Quad::from(f16::MAX) == qd!(65504));
+        assert!(Quad::from(f16::from_bits(1)) == qd!(2).powi(-24));
+        assert!(Quad::from(f16::INFINITY) == Quad::INFINITY);
+        assert!(Quad::from(f16::NAN).is_nan());
+        assert!(Quad::from(bf16::from_f32(0.375)) == qd!(0.375));
+        assert!(Quad::from(bf16::MAX) == qd!(bf16::MAX.to_f64()));
+        assert!(Quad::from(bf16::NEG_INFINITY) == Quad::NEG_INFINITY);
+    });
+    #[cfg(feature = "half")]
+    test!(to_f16: {
+        assert!(f16::from(qd!(1) / qd!(3)) == f16::from_bits(0x3555));
+        assert!(f16::from(Quad::PI) == f16::from_bits(0x4248));
+        // 1 + 2⁻¹¹ is exactly halfway between two `f16`s
+        assert!(f16::from(qd!(1) + qd!(2).powi(-11)) == f16::ONE);
+        assert!(f16::from(Quad(1.0 + 2f64.powi(-11), 2f64.powi(-80), 0.0, 0.0)) == f16::from_bits(0x3c01));
+        assert!(f16::from(qd!(65519)) == f16::MAX);
+        assert!(f16::from(qd!(65520)) == f16::INFINITY);
+        assert!(f16::from(qd!(-1e10)) == f16::NEG_INFINITY);
+        assert!(f16::from(qd!(2).powi(-24)) == f16::from_bits(1));
+        assert!(f16::from(Quad(2f64.powi(-25), 2f64.powi(-90), 0.0, 0.0)) == f16::from_bits(1));
+        assert!(f16::from(qd!(2).powi(-25)) == f16::ZERO);
+        assert!(f16::from(Quad::NEG_ZERO).is_sign_negative());
+        assert!(f16::from(Quad::NAN).is_nan());
+    });
+    #[cfg(feature = "half")]
+    test!(to_bf16: {
+        assert!(bf16::from(Quad::PI) == bf16::from_f32(3.140625));
+        // 1 + 2⁻⁸ is exactly halfway between two `bf16`s
+        assert!(bf16::from(qd!(1) + qd!(2).powi(-8)) == bf16::ONE);
+        assert!(bf16::from(Quad(1.0 + 2f64.powi(-8), 2f64.powi(-80), 0.0, 0.0)) == bf16::from_bits(0x3f81));
+        assert!(bf16::from(qd!(1e300)) == bf16::INFINITY);
+        assert!(bf16::from(qd!(2).powi(-133)) == bf16::from_bits(1));
+        assert!(bf16::from(qd!(2).powi(-134)) == bf16::ZERO);
+        assert!(bf16::from(Quad::NAN).is_nan());
+    });
+
+    // Double tests
+    test!(from_double_exact: {
+        let x = Quad::from(Double::PI);
+        assert!(x == Quad(Double::PI[0], Double::PI[1], 0.0, 0.0));
+        let a = Double::ONE / Double::from(3.0);
+        let x = Quad::from(a);
+        assert!(x == Quad(a[0], a[1], 0.0, 0.0));
+        assert!(Quad::from(Double::NEG_ZERO).is_sign_negative());
+        assert!(Quad::from(Double::INFINITY) == Quad::INFINITY);
+        assert!(Quad::from(Double::NAN).is_nan());
+    });
+    test!(to_double_rounded: {
+        assert!(Quad::PI.to_double() == Double::PI);
+        let e = Double::from((Quad::E.0, 1.4456468917292502e-16));
+        assert!(Quad::E.to_double() == e);
+        let sqrt_2 = Double::from((Quad::SQRT_2.0, -9.667293313452913e-17));
+        assert!((-Quad::SQRT_2).to_double() == -sqrt_2);
+        for a in [Double::PI, Double::ONE / Double::from(3.0), -Double::SQRT_2 * Double::from(1e200), Double::MAX] {
+            assert!(Quad::from(a).to_double() == a);
+        }
+    });
+    test!(to_double_ties: {
+        // The second component has an odd last bit, and the third is exactly half of its
+        // last place, so the fourth decides
+        let lo = 2f64.powi(-60) + 2f64.powi(-112);
+        let half = 2f64.powi(-113);
+        let x = Quad(1.0, lo, half, 2f64.powi(-200)).to_double();
+        assert!(x == Double::from((1.0, lo + 2.0 * half)));
+        let x = Quad(1.0, lo, half, -2f64.powi(-200)).to_double();
+        assert!(x == Double::from((1.0, lo)));
+        let x = Quad(1.0, lo, half, 0.0).to_double();
+        assert!(x == Double::from((1.0, lo + 2.0 * half)));
+        // The same with the first component, when the rest pushes it over halfway
+        let x = Quad(1.0, 2f64.powi(-53), 2f64.powi(-200), 0.0).to_double();
+        assert!(x == Double::from((1.0 + 2f64.powi(-52), -2f64.powi(-53) + 2f64.powi(-200))));
+    });
+    test!(to_double_special: {
+        assert!(Quad::ZERO.to_double() == Double::ZERO);
+        assert!(Quad::NEG_ZERO.to_double().is_sign_negative());
+        assert!(Quad::INFINITY.to_double() == Double::INFINITY);
+        assert!(Quad::NEG_INFINITY.to_double() == Double::NEG_INFINITY);
+        assert!(Quad::NAN.to_double().is_nan());
+    });
+
     // integer tests
     test_all_eq!(
         i8_min: i8::MIN.to_string(), qd!(i8::MIN).to_string();
@@ -652,4 +1450,224 @@ mod tests {
         i128_min: i128::MIN.to_string(), qd!(i128::MIN).to_string();
         u128_max: u128::MAX.to_string(), qd!(u128::MAX).to_string();
     );
+
+    // to integer tests
+    fn kind<T: TryFrom<Quad, Error = TryFromQuadError>>(x: Quad) -> TryFromErrorKind {
+        match T::try_from(x) {
+            Err(e) => e.kind,
+            Ok(_) => panic!("conversion should have failed"),
+        }
+    }
+
+    test!(to_int_small: {
+        assert_eq!(i8::try_from(qd!(-128.9)), Ok(-128));
+        assert_eq!(i8::try_from(qd!(127.9)), Ok(127));
+        assert_eq!(u8::try_from(qd!(255.5)), Ok(255));
+        assert_eq!(u8::try_from(qd!(-0.5)), Ok(0));
+        assert_eq!(i16::try_from(qd!(-32768)), Ok(-32768));
+        assert_eq!(u16::try_from(qd!(65535)), Ok(65535));
+        assert_eq!(i32::try_from(qd!(-2.5)), Ok(-2));
+        assert_eq!(u32::try_from(qd!(4294967295.5)), Ok(u32::MAX));
+    });
+    test!(to_int_wide: {
+        assert_eq!(i64::try_from(qd!(i64::MIN)), Ok(i64::MIN));
+        assert_eq!(i64::try_from(qd!(i64::MAX)), Ok(i64::MAX));
+        assert_eq!(i64::try_from(qd!(i64::MAX) - qd!(0.5)), Ok(i64::MAX - 1));
+        assert_eq!(u64::try_from(qd!(u64::MAX)), Ok(u64::MAX));
+        assert_eq!(i128::try_from(qd!(i128::MIN)), Ok(i128::MIN));
+        assert_eq!(i128::try_from(qd!(i128::MAX)), Ok(i128::MAX));
+        assert_eq!(u128::try_from(qd!(u128::MAX)), Ok(u128::MAX));
+        assert_eq!(i128::try_from(-qd!(2).powi(100) - qd!(3.5)), Ok(-(1 << 100) - 3));
+        assert_eq!(
+            u128::try_from(Quad(2f64.powi(127), 2f64.powi(70), 2f64.powi(10), 1.0)),
+            Ok((1 << 127) + (1 << 70) + (1 << 10) + 1)
+        );
+        // The first component is rounded up past the largest u128, and the second brings it
+        // back into range
+        assert_eq!(
+            u128::try_from(Quad(2f64.powi(128), -2f64.powi(20), 0.0, 0.0)),
+            Ok(u128::MAX - (1 << 20) + 1)
+        );
+    });
+    test!(to_int_errors: {
+        assert_eq!(kind::<i8>(qd!(128)), TryFromErrorKind::OutOfRange);
+        assert_eq!(kind::<i8>(qd!(-129)), TryFromErrorKind::OutOfRange);
+        assert_eq!(kind::<u8>(qd!(-1)), TryFromErrorKind::OutOfRange);
+        assert_eq!(kind::<u32>(qd!(4294967296u64)), TryFromErrorKind::OutOfRange);
+        assert_eq!(kind::<i64>(qd!(i64::MAX) + qd!(1)), TryFromErrorKind::OutOfRange);
+        assert_eq!(kind::<i128>(qd!(2).powi(127)), TryFromErrorKind::OutOfRange);
+        assert_eq!(kind::<u128>(qd!(2).powi(128)), TryFromErrorKind::OutOfRange);
+        assert_eq!(kind::<i32>(Quad::NAN), TryFromErrorKind::NaN);
+        assert_eq!(kind::<u64>(Quad::INFINITY), TryFromErrorKind::Infinite);
+        assert_eq!(kind::<i128>(Quad::NEG_INFINITY), TryFromErrorKind::Infinite);
+    });
+    test!(to_int_trunc: {
+        assert_eq!((qd!(i64::MAX) - qd!(0.5)).to_i64_trunc(), i64::MAX - 1);
+        assert_eq!(qd!(-1e30).to_i64_trunc(), i64::MIN);
+        assert_eq!(Quad::INFINITY.to_i64_trunc(), i64::MAX);
+        assert_eq!(Quad::NAN.to_i64_trunc(), 0);
+        assert_eq!(qd!(-3.5).to_u64_trunc(), 0);
+        assert_eq!(qd!(1e30).to_u64_trunc(), u64::MAX);
+        assert_eq!(Quad::NEG_INFINITY.to_i128_trunc(), i128::MIN);
+        assert_eq!((qd!(2).powi(100) + qd!(3.75)).to_i128_trunc(), (1 << 100) + 3);
+        assert_eq!(qd!(1e300).to_u128_trunc(), u128::MAX);
+        assert_eq!(Quad::NAN.to_u128_trunc(), 0);
+    });
+
+    // string tests
+    test!(str_try_from: {
+        exact!(qd!(1.5), Quad::try_from("1.5").unwrap());
+        exact!(qd!(-12325), Quad::try_from("-123.25e2").unwrap());
+        exact!(Quad::INFINITY, Quad::try_from("inf").unwrap());
+        assert!(Quad::try_from("nan").unwrap().is_nan());
+        assert!(Quad::try_from("0.1") == "0.1".parse::<Quad>());
+    });
+    test!(str_try_from_error: {
+        let error = |kind, position| Err(ParseQuadError { kind, position });
+        assert!(Quad::try_from("1.2.3") == error(ErrorKind::TooManyPoints, 3));
+        assert!(Quad::try_from("1.5-") == error(ErrorKind::Invalid, 3));
+        assert!(Quad::try_from("abc") == error(ErrorKind::InvalidDigit, 0));
+        assert!(Quad::try_from("") == error(ErrorKind::Empty, 0));
+    });
+    test!(str_macro: {
+        exact!(qd!(2.25), qd!("2.25"));
+        assert!(qd!("nan").is_nan());
+    });
+    #[test]
+    #[should_panic(
+        expected = "too many decimal points in quad-double literal at byte 3: \"1.2.3\""
+    )]
+    fn str_macro_invalid() {
+        qd!("1.2.3");
+    }
+    #[test]
+    #[should_panic(expected = "cannot parse quad-double from empty string")]
+    fn str_macro_empty() {
+        qd!("");
+    }
+
+    // array tests
+    test!(array_from: {
+        let x = Quad::from([3.0, 1e-17, 1e-34, 1e-51]);
+        assert!(x[0] == 3.0);
+        assert!(x[1] == 1e-17);
+        assert!(x[2] == 1e-34);
+        assert!(x[3] == 1e-51);
+        // Like tuple conversion, there's no renormalization
+        let y = Quad::from([1.0, 1.0, 1.0, 1.0]);
+        assert!(y.to_array() == [1.0; 4]);
+    });
+    test!(array_to: {
+        assert!(<[f64; 4]>::from(Quad::E) == [Quad::E[0], Quad::E[1], Quad::E[2], Quad::E[3]]);
+        assert!(<[f64; 4]>::from(Quad::NEG_ZERO)[0].is_sign_negative());
+        assert!(<[f64; 4]>::from(Quad::NAN)[0].is_nan());
+    });
+    test!(array_as_ref: {
+        let x = Quad::LN_2;
+        let a: &[f64; 4] = x.as_ref();
+        assert!(*a == x.to_array());
+        assert!(a.as_ptr() == &x as *const Quad as *const f64);
+    });
+    test!(array_round_trip: {
+        for x in [Quad::PI, -Quad::E, Quad::MAX, Quad::INFINITY, Quad::ZERO] {
+            exact!(x, Quad::from(<[f64; 4]>::from(x)));
+            exact!(x, Quad::from(*x.as_ref()));
+        }
+    });
+
+    // BigInt tests
+    #[cfg(feature = "num-bigint")]
+    mod bigint {
+        use super::*;
+        use num_bigint::{BigInt, BigUint};
+
+        fn pow2(n: u32) -> BigInt {
+            BigInt::from(1) << n
+        }
+
+        test_all_exact!(
+            bigint_zero:
+                Quad::ZERO,
+                Quad::from(&BigInt::from(0));
+            bigint_small:
+                qd!(-12345),
+                Quad::from(&BigInt::from(-12345));
+            bigint_pow2:
+                qd!(2).powi(200),
+                Quad::from(&pow2(200));
+            bigint_exact:
+                Quad(2f64.powi(211), 1.0, 0.0, 0.0),
+                Quad::from(&(pow2(211) + 1));
+            bigint_sparse:
+                Quad(2f64.powi(400), 2f64.powi(188), 0.0, 0.0),
+                Quad::from(&(pow2(400) + pow2(188)));
+            bigint_round_down:
+                Quad(2f64.powi(400), 2f64.powi(300), 2f64.powi(200), 2f64.powi(100)),
+                Quad::from(&(pow2(400) + pow2(300) + pow2(200) + pow2(100) + pow2(46)));
+            bigint_round_up:
+                Quad(2f64.powi(400), 2f64.powi(300), 2f64.powi(200), 2f64.powi(100) + 2f64.powi(48)),
+                Quad::from(&(pow2(400) + pow2(300) + pow2(200) + pow2(100) + pow2(47) + 1));
+            bigint_tie_even:
+                Quad(2f64.powi(400), 2f64.powi(300), 2f64.powi(200), 2f64.powi(100)),
+                Quad::from(&(pow2(400) + pow2(300) + pow2(200) + pow2(100) + pow2(47)));
+            bigint_tie_odd:
+                Quad(2f64.powi(400), 2f64.powi(300), 2f64.powi(200), 2f64.powi(100) + 2f64.powi(49)),
+                Quad::from(&(pow2(400) + pow2(300) + pow2(200) + pow2(100) + pow2(48) + pow2(47)));
+            bigint_carry:
+                Quad(2f64.powi(401), -1.0, 0.0, 0.0),
+                Quad::from(&(pow2(401) - 1));
+            bigint_below_pow2:
+                Quad(2f64.powi(213), -1.0, 0.0, 0.0),
+                Quad::from(&(pow2(213) - 1));
+            bigint_overflow:
+                Quad::INFINITY,
+                Quad::from(&(pow2(1024) - 1));
+            bigint_neg_overflow:
+                Quad::NEG_INFINITY,
+                Quad::from(&-pow2(2000));
+        );
+
+        test_all_exact!(
+            biguint_zero:
+                Quad::ZERO,
+                Quad::from(&BigUint::from(0u32));
+            biguint_round_up:
+                Quad(2f64.powi(400), 2f64.powi(300), 2f64.powi(200), 2f64.powi(100) + 2f64.powi(48)),
+                Quad::from((pow2(400) + pow2(300) + pow2(200) + pow2(100) + pow2(47) + 1u32).magnitude());
+            biguint_overflow:
+                Quad::INFINITY,
+                Quad::from(&(pow2(1024).magnitude() - 1u32));
+        );
+
+        // to_bigint tests
+        test!(to_bigint_integers: {
+            assert_eq!(Quad::ZERO.to_bigint(), Some(BigInt::from(0)));
+            assert_eq!(qd!(-12345).to_bigint(), Some(BigInt::from(-12345)));
+            assert_eq!(Quad(2f64.powi(211), 1.0, 0.0, 0.0).to_bigint(), Some(pow2(211) + 1));
+            let n = -pow2(400) - pow2(189);
+            assert_eq!(Quad::from(&n).to_bigint(), Some(n));
+        });
+        test!(to_bigint_fractions: {
+            assert_eq!(qd!(0.75).to_bigint(), Some(BigInt::from(0)));
+            assert_eq!(qd!(-0.75).to_bigint(), Some(BigInt::from(0)));
+            assert_eq!(qd!(-2.5).to_bigint(), Some(BigInt::from(-2)));
+            // The fractional part is only in the last component
+            let x = Quad(2f64.powi(170), 2f64.powi(100), 2f64.powi(40), 0.5);
+            assert_eq!(x.to_bigint(), Some(pow2(170) + pow2(100) + pow2(40)));
+            let x = Quad(2f64.powi(170), 2f64.powi(100), 2f64.powi(40), -0.5);
+            assert_eq!(x.to_bigint(), Some(pow2(170) + pow2(100) + pow2(40) - 1));
+        });
+        test!(to_bigint_special: {
+            assert!(Quad::NAN.to_bigint().is_none());
+            assert!(Quad::INFINITY.to_bigint().is_none());
+            assert!(Quad::NEG_INFINITY.to_bigint().is_none());
+            assert_eq!(Quad::MAX.to_bigint().unwrap().bits(), 1024);
+        });
+        test!(to_bigint_round_trip: {
+            // 3¹³³ has 211 bits, so it fits
+            let n = BigInt::from(3).pow(133);
+            assert_eq!(Quad::from(&n).to_bigint(), Some(n.clone()));
+            assert_eq!(Quad::from(&-n.clone()).to_bigint(), Some(-n));
+        });
+    }
 }
@@ -3,12 +3,12 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::parse;
 use crate::error::{ErrorKind, ParseQuadError};
+use crate::options::ParseOptions;
 use crate::quad::Quad;
 use std::str::FromStr;
 
-const TEN: Quad = Quad(10.0, 0.0, 0.0, 0.0);
-
 impl FromStr for Quad {
     type Err = ParseQuadError;
 
@@ -20,7 +20,23 @@ impl FromStr for Quad {
     /// wrong places; two decimal points or a negative sign after the number will both be
     /// rejected, for instance.
     ///
-    /// Failure will return a [`ParseQuadError`] of some kind.
+    /// Failure will return a [`ParseQuadError`] that gives the kind of the problem and the
+    /// byte offset in the string where it was found.
+    ///
+    /// The result is correctly rounded. Its first component is the `f64` nearest to the
+    /// exact value of the decimal string, and each component after that is the `f64`
+    /// nearest to what's left once the ones before it are subtracted, with ties going to
+    /// the one with an even last bit. This is done with exact integer arithmetic, so it
+    /// holds for strings of any length and any exponent; a string that's an exact
+    /// expansion of a `Quad` always parses back to exactly that `Quad`. A number too
+    /// large for a `Quad` becomes an infinity, and one too small becomes a subnormal or
+    /// zero.
+    ///
+    /// A string that starts with `0x` or `0X` (after any sign) is instead read as a C99-style
+    /// hexadecimal float, like `0x1.8p+3` (which is 12), with an optional exponent that's
+    /// a power of two. It's rounded in the same way. This is the format written by the
+    /// [`LowerHex`] implementation, which is exact, so it's a way to get exact binary
+    /// values in and out of a `Quad` as text.
     ///
     /// # Examples
     /// ```
@@ -48,98 +64,141 @@ impl FromStr for Quad {
     /// ```
     ///
     /// [`ParseQuadError`]: error/struct.ParseQuadError.html
+    /// [`LowerHex`]: #impl-LowerHex-for-Quad
     fn from_str(s: &str) -> Result<Quad, ParseQuadError> {
-        let mut result = Quad::ZERO;
-        let mut digits = 0;
-        let mut point = -1;
-        let mut sign = 0;
-        let mut exp = 0;
+        Quad::from_str_with(s, &ParseOptions::default())
+    }
+}
 
-        let s = s.trim().to_ascii_lowercase();
+impl Quad {
+    /// Parses a string to create a `Quad`, using the given options for the separators in
+    /// decimal strings.
+    ///
+    /// This works exactly like [`from_str`], except that the group separator in `options`
+    /// (if there is one) is ignored among the digits, in the same way that underscores
+    /// are, and that the decimal separator in `options` takes the place of the period. That
+    /// makes it possible to read numbers like `1,000,000.5` or `1.000.000,5` without
+    /// preprocessing them. Hex strings, `NaN`, and the infinities are read the same way
+    /// that [`from_str`] reads them, and positions in errors are still byte offsets in
+    /// the original string.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad, ParseOptions};
+    /// let options = ParseOptions {
+    ///     group_separator: Some(' '),
+    ///     decimal_separator: ',',
+    /// };
+    /// assert!(Quad::from_str_with("1 000 000,5", &options).unwrap() == qd!(1000000.5));
+    /// assert!(Quad::from_str_with("2,5e-3", &options).unwrap() == qd!("2.5e-3"));
+    /// assert!(Quad::from_str_with("2.5", &options).is_err());
+    /// ```
+    ///
+    /// [`from_str`]: #method.from_str
+    pub fn from_str_with(s: &str, options: &ParseOptions) -> Result<Quad, ParseQuadError> {
+        // Positions in errors are byte offsets in the original string, so they have to
+        // account for any whitespace that's trimmed off of the front
+        let start = s.len() - s.trim_start().len();
+        let s = s.trim();
 
-        match pre_from_str(&s) {
+        match pre_from_str(s) {
             Some(r) => r,
             None => {
-                for (index, ch) in s.chars().enumerate() {
-                    match ch.to_digit(10) {
-                        Some(d) => {
-                            result *= TEN;
-                            result += Quad(d as f64, 0.0, 0.0, 0.0);
-                            digits += 1;
-                        }
-                        None => match ch {
-                            '.' => {
-                                if point >= 0 {
-                                    return Err(ParseQuadError {
-                                        kind: ErrorKind::Invalid,
-                                    });
-                                }
-                                point = digits;
-                            }
-                            '-' => {
-                                if sign != 0 || digits > 0 {
-                                    return Err(ParseQuadError {
-                                        kind: ErrorKind::Invalid,
-                                    });
-                                }
-                                sign = -1;
-                            }
-                            '+' => {
-                                if sign != 0 || digits > 0 {
-                                    return Err(ParseQuadError {
-                                        kind: ErrorKind::Invalid,
-                                    });
-                                }
-                                sign = 1;
-                            }
-                            'e' => {
-                                let end = &s[(index + 1)..];
-                                match end.parse::<i32>() {
-                                    Ok(e) => {
-                                        exp = e;
-                                        break;
-                                    }
-                                    Err(_) => {
-                                        return Err(ParseQuadError {
-                                            kind: ErrorKind::Invalid,
-                                        });
-                                    }
-                                }
-                            }
-                            '_' => {
-                                // just continue; _ is a no-op but not an error
-                            }
-                            _ => {
-                                return Err(ParseQuadError {
-                                    kind: ErrorKind::Invalid,
-                                });
-                            }
-                        },
-                    }
-                }
-
-                if point >= 0 {
-                    exp -= digits - point;
-                }
-                if exp != 0 {
-                    // Do this in two stages if the exponent is too small For exmaple, a
-                    // number with 30 digits could have an exponent as low as -337 and still
-                    // not overflow, but doing the -337 all at once WOULD overflow
-                    if exp < -307 {
-                        let adjust = exp + 307;
-                        result *= TEN.powi(adjust);
-                        exp -= adjust;
-                    }
-                    result *= TEN.powi(exp);
-                }
-                if sign == -1 {
-                    result = -result;
-                }
-
-                Ok(result)
+                let parts = if parse::is_hex(s) {
+                    parse::hex(s, start, 4)
+                } else {
+                    parse::decimal(s, start, 4, options)
+                };
+                parts
+                    .map(|p| Quad(p[0], p[1], p[2], p[3]))
+                    .map_err(|(kind, position)| ParseQuadError { kind, position })
             }
         }
     }
+
+    /// Parses a byte slice of ASCII text to create a `Quad`.
+    ///
+    /// This is [`from_str`] for input that comes in as bytes, like a field read from a file
+    /// or a network buffer, so that it doesn't have to be turned into a `&str` first. It
+    /// accepts exactly what [`from_str`] accepts and gives exactly the same result. Neither
+    /// one allocates, which makes this suitable for parsing large amounts of data. Bytes
+    /// that aren't valid UTF-8 are reported as an invalid digit at the position of the first
+    /// one of them.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::from_ascii(b"2.5E-3").unwrap() == qd!("2.5e-3"));
+    /// assert!(Quad::from_ascii(b"-0X1.8P1").unwrap() == qd!(-3));
+    /// assert!(Quad::from_ascii(b"NaN").unwrap().is_nan());
+    /// assert!(Quad::from_ascii(b"1.5\xff").is_err());
+    /// ```
+    ///
+    /// [`from_str`]: #method.from_str
+    pub fn from_ascii(bytes: &[u8]) -> Result<Quad, ParseQuadError> {
+        match std::str::from_utf8(bytes) {
+            Ok(s) => s.parse(),
+            Err(e) => Err(ParseQuadError {
+                kind: ErrorKind::InvalidDigit,
+                position: e.valid_up_to(),
+            }),
+        }
+    }
+
+    /// Parses a string in the given radix, which can be anything from 2 to 36, to create a
+    /// `Quad`.
+    ///
+    /// This mirrors the `from_str_radix` functions of the integer types. The string is an
+    /// optional sign followed by digits in the radix, where the digits past 9 are the
+    /// letters `a` through `z` (in either case), with an optional point among them.
+    /// Underscores are ignored, and leading and trailing whitespace is trimmed. There's no
+    /// exponent, since `e` is a digit in the larger radices, and there are no special
+    /// tokens for `NaN` or the infinities. The exception is radix 10, for which this does
+    /// exactly what [`from_str`] does.
+    ///
+    /// The result is correctly rounded in the same way as it is by [`from_str`], and a
+    /// string produced by [`to_string_radix`] always parses back into exactly the `Quad`
+    /// that produced it. Failure returns a [`ParseQuadError`] with the kind of the problem and
+    /// the byte offset where it was found, as it does for [`from_str`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::from_str_radix("-1010.11", 2).unwrap() == qd!(-10.75));
+    /// assert!(Quad::from_str_radix("FF.8", 16).unwrap() == qd!(255.5));
+    /// assert!(Quad::from_str_radix("z", 36).unwrap() == qd!(35));
+    /// assert!(Quad::from_str_radix("12", 2).is_err());
+    /// ```
+    ///
+    /// [`from_str`]: #method.from_str
+    /// [`to_string_radix`]: #method.to_string_radix
+    /// [`ParseQuadError`]: error/struct.ParseQuadError.html
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Quad, ParseQuadError> {
+        assert!(
+            (2..=36).contains(&radix),
+            "from_str_radix: radix must lie in the range `[2, 36]`, found {}",
+            radix
+        );
+        if radix == 10 {
+            return s.parse();
+        }
+
+        let start = s.len() - s.trim_start().len();
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseQuadError {
+                kind: ErrorKind::Empty,
+                position: 0,
+            });
+        }
+        parse::radix(s, start, radix, 4)
+            .map(|p| Quad(p[0], p[1], p[2], p[3]))
+            .map_err(|(kind, position)| ParseQuadError { kind, position })
+    }
 }
 
 #[inline]
@@ -147,12 +206,13 @@ fn pre_from_str(s: &str) -> Option<Result<Quad, ParseQuadError>> {
     if s.is_empty() {
         Some(Err(ParseQuadError {
             kind: ErrorKind::Empty,
+            position: 0,
         }))
-    } else if s == "nan" {
+    } else if s.eq_ignore_ascii_case("nan") {
         Some(Ok(Quad::NAN))
-    } else if s == "inf" || s == "infinity" {
+    } else if s.eq_ignore_ascii_case("inf") || s.eq_ignore_ascii_case("infinity") {
         Some(Ok(Quad::INFINITY))
-    } else if s == "-inf" || s == "-infinity" {
+    } else if s.eq_ignore_ascii_case("-inf") || s.eq_ignore_ascii_case("-infinity") {
         Some(Ok(Quad::NEG_INFINITY))
     } else {
         None
@@ -176,31 +236,103 @@ mod tests {
         s.parse().unwrap()
     }
 
-    fn parse_err(s: &str) -> ErrorKind {
-        s.parse::<Quad>().unwrap_err().kind
+    fn parse_err(s: &str) -> (ErrorKind, usize) {
+        let err = s.parse::<Quad>().unwrap_err();
+        (err.kind, err.position)
     }
 
     // error tests
     test_all_eq!(
         empty:
-            ErrorKind::Empty,
+            (ErrorKind::Empty, 0),
             parse_err("");
+        blank:
+            (ErrorKind::Empty, 0),
+            parse_err("   ");
+        sign_only:
+            (ErrorKind::Empty, 1),
+            parse_err("-");
+        plus_only:
+            (ErrorKind::Empty, 1),
+            parse_err("+");
+        padded_sign_only:
+            (ErrorKind::Empty, 3),
+            parse_err("  - ");
+        point_only:
+            (ErrorKind::Invalid, 1),
+            parse_err(".");
+        sign_point_only:
+            (ErrorKind::Invalid, 2),
+            parse_err("-.");
+        exp_only:
+            (ErrorKind::Invalid, 0),
+            parse_err("e5");
+        point_exp_only:
+            (ErrorKind::Invalid, 1),
+            parse_err(".e1");
+        sign_exp_only:
+            (ErrorKind::Invalid, 1),
+            parse_err("-E5");
+        underscore_only:
+            (ErrorKind::Invalid, 1),
+            parse_err("_");
         double_sign:
-            ErrorKind::Invalid,
+            (ErrorKind::Invalid, 1),
             parse_err("++2317");
         double_point:
-            ErrorKind::Invalid,
+            (ErrorKind::TooManyPoints, 4),
             parse_err("2.31.7");
         mid_sign:
-            ErrorKind::Invalid,
+            (ErrorKind::Invalid, 1),
             parse_err("2-317");
         end_letter:
-            ErrorKind::Invalid,
+            (ErrorKind::InvalidDigit, 6),
             parse_err("2.317err");
         mid_letter:
-            ErrorKind::Invalid,
+            (ErrorKind::InvalidDigit, 3),
             parse_err("2.3j7");
+        non_ascii:
+            (ErrorKind::InvalidDigit, 1),
+            parse_err("2\u{e9}5");
+        leading_space:
+            (ErrorKind::InvalidDigit, 5),
+            parse_err("  2.3j7  ");
+        exp_empty:
+            (ErrorKind::Invalid, 4),
+            parse_err("2.5e");
+        exp_sign_only:
+            (ErrorKind::Invalid, 5),
+            parse_err("2.5e-");
+        exp_double_sign:
+            (ErrorKind::Invalid, 5),
+            parse_err("2.5e+-3");
+        exp_point:
+            (ErrorKind::Invalid, 5),
+            parse_err("2.5e1.5");
+        exp_letter:
+            (ErrorKind::InvalidDigit, 6),
+            parse_err("2.5e12x");
+        exp_overflow:
+            (ErrorKind::ExponentOverflow, 4),
+            parse_err("2.5e99999999999");
+        exp_neg_overflow:
+            (ErrorKind::ExponentOverflow, 4),
+            parse_err("2.5e-99999999999");
     );
+    test!(error_display: {
+        assert_eq!(
+            "2.31.7".parse::<Quad>().unwrap_err().to_string(),
+            "too many decimal points in quad-double literal at byte 4"
+        );
+        assert_eq!(
+            "2.5e".parse::<Quad>().unwrap_err().to_string(),
+            "invalid quad-double literal at byte 4"
+        );
+        assert_eq!(
+            "".parse::<Quad>().unwrap_err().to_string(),
+            "cannot parse quad-double from empty string"
+        );
+    });
 
     // zero tests
     test_all_exact!(
@@ -357,4 +489,658 @@ mod tests {
         let x = ((t - qd!(1)) / t) * qd!(10).powi(-100);
         near!(x, s);
     });
+
+    // rounding tests
+    //
+    // Most of these are exact decimal values of numbers halfway between two neighboring
+    // `Quad`s, which round to the one whose last component has an even last bit, or are
+    // within a digit of being halfway.
+    fn pow2(n: i32) -> f64 {
+        2f64.powi(n)
+    }
+
+    test_all_exact!(
+        round_tie_down:
+            Quad(1.0, pow2(-60), pow2(-120), pow2(-180)),
+            parse(concat!(
+                "1.0000000000000000008673617379884035479582786252222173748931468306220897",
+                "589204079978734897094086718449274179462871386212850778411206677422063001",
+                "614281477460776784355486000068132601393750226372470618629861860426899511",
+                "3670825958251953125"
+            ));
+        round_tie_up:
+            Quad(1.0, pow2(-60), pow2(-120), pow2(-180) + pow2(-231)),
+            parse(concat!(
+                "1.0000000000000000008673617379884035479582786252222173748931468306220899",
+                "038112732591008875895545804991320864372076377915112888744425059811743212",
+                "589136695819101816669239295980764991681250679117411855889585581280698534",
+                "1012477874755859375"
+            ));
+        round_above_tie:
+            Quad(1.0, pow2(-60), pow2(-120), pow2(-180) + pow2(-232)),
+            parse(concat!(
+                "1.0000000000000000008673617379884035479582786252222173748931468306220897",
+                "589204079978734897094086718449274179462871386212850778411206677422063001",
+                "614281477460776784355486000068132601393750226372470618629861860426899511",
+                "36708259582519531251"
+            ));
+        round_below_tie:
+            Quad(1.0, pow2(-60), pow2(-120), pow2(-180) + pow2(-232)),
+            parse(concat!(
+                "1.0000000000000000008673617379884035479582786252222173748931468306220899",
+                "038112732591008875895545804991320864372076377915112888744425059811743212",
+                "589136695819101816669239295980764991681250679117411855889585581280698534",
+                "10124778747558593749"
+            ));
+        round_exact:
+            Quad(1.0, pow2(-60), pow2(-113), 0.0),
+            parse(concat!(
+                "1.0000000000000000008673617379884036435024594600577460219395221292463659",
+                "2690508241076940976199693977832794189453125"
+            ));
+        round_negative_exponent:
+            Quad(1e-300, -2.5059094e-317, 0.0, 0.0),
+            parse("1e-300");
+        round_overflow:
+            Quad::INFINITY,
+            parse("1.7976931348623158079372897140530342e308");
+        round_underflow:
+            Quad::ZERO,
+            parse("2.4703282292062327208828439643411068e-324");
+    );
+    test_all_assert!(
+        // Displaying a subnormal doesn't work, so this can't use `exact!`
+        round_min_subnormal:
+            parse("2.4703282292062327208828439643411069e-324")
+                == Quad(f64::from_bits(1), 0.0, 0.0, 0.0);
+    );
+
+    // The same as the first of the ties above
+    const Q1: &str = concat!(
+        "1.0000000000000000008673617379884035479582786252222173748931468306220897",
+        "589204079978734897094086718449274179462871386212850778411206677422063001",
+        "614281477460776784355486000068132601393750226372470618629861860426899511",
+        "3670825958251953125",
+    );
+
+    test!(round_long_input: {
+        // Far more digits than are kept, so what's cut off has to be accounted for
+        let zeros = "0".repeat(3000);
+        exact!(
+            Quad(1.0, pow2(-60), pow2(-120), pow2(-180)),
+            parse(&format!("{}{}", Q1, zeros))
+        );
+        exact!(
+            Quad(1.0, pow2(-60), pow2(-120), pow2(-180) + pow2(-232)),
+            parse(&format!("{}{}1", Q1, zeros))
+        );
+    });
+    test!(round_near_quad: {
+        // So close to a `Quad` with fewer components that finding the last one takes many
+        // more bits than usual. What's left after the 1 is exactly 10^-80, whose own
+        // components are the rest of them.
+        let t = parse("1e-80");
+        let above = format!("1.{}1", "0".repeat(79));
+        exact!(Quad(1.0, t[0], t[1], t[2]), parse(&above));
+        let below = format!("0.{}", "9".repeat(80));
+        exact!(Quad(1.0, -t[0], -t[1], -t[2]), parse(&below));
+    });
+
+    // A simple linear congruential generator makes repeatable random decimal strings, with
+    // up to 80 digits and exponents from well below the smallest subnormal to past the
+    // largest finite number
+    fn random_strings() -> Vec<String> {
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+        let mut next = |n: u64| {
+            seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+            (seed >> 33) % n
+        };
+        (0..500)
+            .map(|_| {
+                let len = next(80) + 1;
+                let digits: String = (0..len).map(|_| (b'0' + next(10) as u8) as char).collect();
+                format!("{}e{}", digits, next(680) as i64 - 370)
+            })
+            .collect()
+    }
+    test!(round_first_component: {
+        // The standard library's parser is correctly rounded, so the first component has to
+        // be the same as what it produces
+        for s in random_strings() {
+            assert!(parse(&s)[0] == s.parse::<f64>().unwrap(), "{}", s);
+        }
+        for s in [
+            "2.4703282292062327208828439643411068e-324",
+            "2.4703282292062327208828439643411069e-324",
+            "1.7976931348623158079372897140530341e308",
+            "1.7976931348623158079372897140530342e308",
+            "4.9406564584124654e-324",
+            "2.2250738585072011e-308",
+        ] {
+            assert!(parse(s)[0] == s.parse::<f64>().unwrap(), "{}", s);
+        }
+    });
+    #[cfg(feature = "num-bigint")]
+    test!(round_random_bigint: {
+        use crate::common::bigint as b;
+        use num_bigint::BigInt;
+
+        for s in random_strings() {
+            let (digits, exp) = s.split_at(s.find('e').unwrap());
+            let num: BigInt = digits.parse().unwrap();
+            let exp: i32 = exp[1..].parse().unwrap();
+            let ten = BigInt::from(10);
+            let (num, den) = if exp >= 0 {
+                (num * ten.pow(exp as u32), BigInt::from(1))
+            } else {
+                (num, ten.pow(-exp as u32))
+            };
+            let parts = b::split_fraction(num, den, 4);
+            let x = parse(&s);
+            for (i, &part) in parts.iter().enumerate() {
+                assert!(x[i] == part, "{}", s);
+            }
+        }
+    });
+
+    // hex tests
+    test_all_exact!(
+        hex_integer:
+            qd!(12),
+            parse("0x1.8p+3");
+        hex_no_exponent:
+            qd!(26),
+            parse("0x1a");
+        hex_no_integer:
+            qd!(0.5),
+            parse("0x.8");
+        hex_trailing_point:
+            qd!(16),
+            parse("0x10.");
+        hex_upper:
+            qd!(-0.0625),
+            parse("-0X1P-4");
+        hex_plus:
+            qd!(2),
+            parse("+0x1p1");
+        hex_mixed_case:
+            qd!(2748),
+            parse("0xAbC");
+        hex_e_digit:
+            qd!(-485),
+            parse("-0x1e5");
+        hex_underscores:
+            qd!(65535),
+            parse("0xff_ff");
+        hex_whitespace:
+            qd!(1),
+            parse("  0x1p0  ");
+        hex_zero:
+            Quad::ZERO,
+            parse("0x0p+0");
+        hex_neg_zero:
+            Quad::NEG_ZERO,
+            parse("-0x0.000p-10");
+        hex_leading_zeros:
+            qd!(10),
+            parse("0x00000000000000000000000000000000000000000a");
+        hex_two_parts:
+            Quad(1.0, pow2(-80), 0.0, 0.0),
+            parse("0x1.00000000000000000001");
+        hex_neg_second:
+            Quad(1.0, -pow2(-80), 0.0, 0.0),
+            parse("0x1.fffffffffffffffffffep-1");
+        hex_pi:
+            Quad::PI,
+            parse("0x1.921fb54442d18469898cc51701b839a252049c1114cf98e804177ep+1");
+        hex_max:
+            Quad::MAX,
+            parse("0x1.fffffffffffff7ffffffffffffdfffffffffffff7ffffffffffffcp+1023");
+        hex_overflow:
+            Quad::INFINITY,
+            parse("0x1p1024");
+        hex_neg_overflow:
+            Quad::NEG_INFINITY,
+            parse("-0x1p99999");
+        hex_underflow:
+            Quad::ZERO,
+            parse("0x1p-1076");
+        hex_half_subnormal:
+            Quad::ZERO,
+            parse("0x1p-1075");
+    );
+    test_all_exact!(
+        // The last component of this `Quad` starts at 2^-180 and ends at 2^-232, so these are
+        // ties (or near ties) in the bit after that
+        hex_round_tie_down:
+            Quad(1.0, pow2(-60), pow2(-120), pow2(-180)),
+            parse("0x1.00000000000000100000000000000100000000000000100000000000008");
+        hex_round_tie_up:
+            Quad(1.0, pow2(-60), pow2(-120), pow2(-180) + pow2(-231)),
+            parse("0x1.00000000000000100000000000000100000000000000100000000000018");
+        hex_round_above_tie:
+            Quad(1.0, pow2(-60), pow2(-120), pow2(-180) + pow2(-232)),
+            parse(concat!(
+                "0x1.00000000000000100000000000000100000000000000100000000000008000000000",
+                "00000000000000000000000000000001"
+            ));
+        hex_round_below_tie:
+            Quad(1.0, pow2(-60), pow2(-120), pow2(-180) + pow2(-232)),
+            parse(concat!(
+                "0x1.00000000000000100000000000000100000000000000100000000000017fffffffff",
+                "ffffffffffffffffffffffffffffffff"
+            ));
+        hex_round_long:
+            Quad(1.0, pow2(-60), pow2(-120), pow2(-180) + pow2(-232)),
+            parse(
+                format!(
+                    "0x1.00000000000000100000000000000100000000000000100000000000008{}1",
+                    "0".repeat(3000)
+                )
+                .as_str()
+            );
+        hex_round_overflow:
+            Quad::INFINITY,
+            parse("0x1.fffffffffffff8p1023");
+    );
+    test_all_assert!(
+        // Subnormals can't be displayed, so these can't be tested with `exact!`
+        hex_min_subnormal:
+            parse("0x1p-1074") == Quad(f64::from_bits(1), 0.0, 0.0, 0.0);
+        hex_round_subnormal:
+            parse("0x1.0000000000001p-1075") == Quad(f64::from_bits(1), 0.0, 0.0, 0.0);
+    );
+    test_all_eq!(
+        hex_empty:
+            (ErrorKind::Invalid, 2),
+            parse_err("0x");
+        hex_no_digits:
+            (ErrorKind::Invalid, 2),
+            parse_err("0xp3");
+        hex_point_only:
+            (ErrorKind::Invalid, 3),
+            parse_err("0x.");
+        hex_double_point:
+            (ErrorKind::TooManyPoints, 5),
+            parse_err("0x1.2.3");
+        hex_bad_digit:
+            (ErrorKind::InvalidDigit, 3),
+            parse_err("0x1g");
+        hex_bad_digit_after_point:
+            (ErrorKind::InvalidDigit, 6),
+            parse_err("-0x1.8q5");
+        hex_inner_sign:
+            (ErrorKind::Invalid, 2),
+            parse_err("0x-1");
+        hex_double_sign:
+            (ErrorKind::Invalid, 1),
+            parse_err("--0x1");
+        hex_exp_empty:
+            (ErrorKind::Invalid, 4),
+            parse_err("0x1p");
+        hex_exp_hex:
+            (ErrorKind::InvalidDigit, 4),
+            parse_err("0x1pa");
+        hex_exp_overflow:
+            (ErrorKind::ExponentOverflow, 4),
+            parse_err("0x1p99999999999");
+        hex_leading_space:
+            (ErrorKind::InvalidDigit, 5),
+            parse_err("  0x1g  ");
+    );
+    test!(hex_matches_decimal: {
+        // Each of these pairs is a hex string and its exact decimal expansion, which are
+        // both exactly the same `Quad`
+        let pairs = [
+            (
+                "0x1.999999999999999999999999999ap+0",
+                "1.600000000000000000000000000000000077037197775489434122239117703397092741524065928615527809597551822662353515625",
+            ),
+            (
+                "0x1.ffffffffffffffffffffffffffffp+96",
+                "158456325028528675187087900671.9999847412109375",
+            ),
+            (
+                "0x3.243f6a8885a308d313198a2e0370734",
+                "3.14159265358979323846264338327950288418353141478922216077851131738713791456774700357190699406828571227379143238067626953125",
+            ),
+        ];
+        for (hex, decimal) in pairs.iter() {
+            assert!(parse(hex) == parse(decimal));
+        }
+    });
+
+    // radix tests
+    fn radix(s: &str, radix: u32) -> Quad {
+        Quad::from_str_radix(s, radix).unwrap()
+    }
+
+    fn radix_err(s: &str, radix: u32) -> (ErrorKind, usize) {
+        let err = Quad::from_str_radix(s, radix).unwrap_err();
+        (err.kind, err.position)
+    }
+
+    test_all_exact!(
+        radix_binary:
+            qd!(-10.75),
+            radix("-1010.11", 2);
+        radix_hex:
+            qd!(255.5),
+            radix("ff.8", 16);
+        radix_hex_upper:
+            qd!(255.5),
+            radix("FF.8", 16);
+        radix_36:
+            qd!(1295),
+            radix("zz", 36);
+        radix_octal_fraction:
+            qd!(0.125),
+            radix("0.1", 8);
+        radix_plus:
+            qd!(5),
+            radix("+101", 2);
+        radix_whitespace:
+            qd!(5),
+            radix("  101  ", 2);
+        radix_underscores:
+            qd!(255),
+            radix("1111_1111", 2);
+        radix_trailing_point:
+            qd!(7),
+            radix("7.", 8);
+        radix_leading_point:
+            qd!(0.5),
+            radix(".1", 2);
+        radix_zero:
+            Quad::ZERO,
+            radix("0.000", 3);
+        radix_neg_zero:
+            Quad::NEG_ZERO,
+            radix("-0", 7);
+        radix_ten:
+            qd!(1500),
+            radix("1.5e3", 10);
+        radix_two_parts:
+            Quad(1.0, pow2(-80), 0.0, 0.0),
+            radix(&format!("1.{}1", "0".repeat(79)), 2);
+        radix_large:
+            Quad(pow2(200), 0.0, 0.0, 0.0),
+            radix(&format!("1{}", "0".repeat(50)), 16);
+        radix_overflow:
+            Quad::INFINITY,
+            radix(&format!("1{}", "0".repeat(1024)), 2);
+        radix_underflow:
+            Quad::ZERO,
+            radix(&format!("0.{}1", "0".repeat(1075)), 2);
+    );
+    test!(radix_rounded: {
+        // A third has no finite expansion in binary, but it's exactly 0.1 in radix 3 (and
+        // 0.4 in radix 12), so these have to round to the same `Quad` as the decimal
+        // expansion does
+        let third = parse(concat!(
+            "0.333333333333333333333333333333333333333333333333333333333333333333333333333",
+            "333333333333333333333333333333333333333333333333333333333333333333333333333333",
+            "333333333333333333333333333333333333333333333333333333333333333333333333333333"
+        ));
+        assert!(radix("0.1", 3) == third);
+        assert!(radix("0.4", 12) == third);
+        assert!(radix("-0.1", 3) == -third);
+        // 0.1 in decimal is 0.2 in radix 20
+        assert!(radix("0.2", 20) == parse("0.1"));
+    });
+    test_all_eq!(
+        radix_empty:
+            (ErrorKind::Empty, 0),
+            radix_err("", 2);
+        radix_blank:
+            (ErrorKind::Empty, 0),
+            radix_err("  ", 16);
+        radix_sign_only:
+            (ErrorKind::Invalid, 1),
+            radix_err("-", 2);
+        radix_point_only:
+            (ErrorKind::Invalid, 1),
+            radix_err(".", 2);
+        radix_digit_too_big:
+            (ErrorKind::InvalidDigit, 1),
+            radix_err("12", 2);
+        radix_letter_too_big:
+            (ErrorKind::InvalidDigit, 1),
+            radix_err("yz", 35);
+        radix_double_point:
+            (ErrorKind::TooManyPoints, 3),
+            radix_err("1.0.1", 2);
+        radix_mid_sign:
+            (ErrorKind::Invalid, 2),
+            radix_err("10-1", 2);
+        radix_double_sign:
+            (ErrorKind::Invalid, 1),
+            radix_err("+-1", 2);
+        radix_no_exponent:
+            (ErrorKind::InvalidDigit, 1),
+            radix_err("1e3", 8);
+        radix_no_special:
+            (ErrorKind::InvalidDigit, 0),
+            radix_err("inf", 16);
+        radix_leading_space:
+            (ErrorKind::InvalidDigit, 3),
+            radix_err("  12", 2);
+    );
+    #[test]
+    #[should_panic(expected = "from_str_radix: radix must lie in the range `[2, 36]`, found 1")]
+    fn radix_too_small() {
+        let _ = Quad::from_str_radix("0", 1);
+    }
+    #[test]
+    #[should_panic(expected = "from_str_radix: radix must lie in the range `[2, 36]`, found 37")]
+    fn radix_too_large() {
+        let _ = Quad::from_str_radix("0", 37);
+    }
+
+    // separator tests
+    fn parse_with(s: &str, group: Option<char>, decimal: char) -> Quad {
+        let options = ParseOptions {
+            group_separator: group,
+            decimal_separator: decimal,
+        };
+        Quad::from_str_with(s, &options).unwrap()
+    }
+
+    fn parse_with_err(s: &str, group: Option<char>, decimal: char) -> (ErrorKind, usize) {
+        let options = ParseOptions {
+            group_separator: group,
+            decimal_separator: decimal,
+        };
+        let err = Quad::from_str_with(s, &options).unwrap_err();
+        (err.kind, err.position)
+    }
+
+    test_all_exact!(
+        sep_underscore:
+            qd!(1000000.5),
+            parse("1_000_000.5");
+        sep_default:
+            qd!(1000000.5),
+            Quad::from_str_with("1_000_000.5", &ParseOptions::default()).unwrap();
+        sep_comma:
+            qd!(-1234567.25),
+            parse_with("-1,234,567.25", Some(','), '.');
+        sep_space:
+            qd!(1234567.25),
+            parse_with("1 234 567.25", Some(' '), '.');
+        sep_apostrophe:
+            qd!(1234567.25),
+            parse_with("1'234'567.25", Some('\''), '.');
+        sep_european:
+            qd!(1234567.25),
+            parse_with("1.234.567,25", Some('.'), ',');
+        sep_european_space:
+            qd!(-1234567.25),
+            parse_with("  -1 234 567,25  ", Some(' '), ',');
+        sep_decimal_comma:
+            qd!(2.5),
+            parse_with("2,5", None, ',');
+        sep_exponent:
+            qd!(0.0025),
+            parse_with("2,5e-3", None, ',');
+        sep_fraction:
+            qd!(0.123456),
+            parse_with("0.123,456", Some(','), '.');
+        sep_leading:
+            qd!(1000),
+            parse_with(",1,000", Some(','), '.');
+        sep_underscore_too:
+            qd!(1000000),
+            parse_with("1,000_000", Some(','), '.');
+        sep_same:
+            qd!(1.5),
+            parse_with("1,5", Some(','), ',');
+        sep_hex:
+            qd!(12),
+            parse_with("0x1.8p3", Some(','), ',');
+        sep_inf:
+            Quad::NEG_INFINITY,
+            parse_with("-inf", Some(','), ',');
+    );
+    test_all_eq!(
+        sep_not_allowed:
+            (ErrorKind::InvalidDigit, 1),
+            parse_err("1,000");
+        sep_wrong_group:
+            (ErrorKind::InvalidDigit, 1),
+            parse_with_err("1 000", Some(','), '.');
+        sep_period_with_comma:
+            (ErrorKind::InvalidDigit, 1),
+            parse_with_err("2.5", None, ',');
+        sep_two_decimals:
+            (ErrorKind::TooManyPoints, 3),
+            parse_with_err("2,5,5", None, ',');
+        sep_in_exponent:
+            (ErrorKind::InvalidDigit, 3),
+            parse_with_err("1e1,000", Some(','), '.');
+        sep_leading_space:
+            (ErrorKind::InvalidDigit, 5),
+            parse_with_err("  1,0x", Some(','), '.');
+        sep_empty:
+            (ErrorKind::Empty, 0),
+            parse_with_err("", Some(','), '.');
+    );
+
+    // accuracy tests
+    //
+    // The expected values here are the exact decimal values rounded to a `Quad`. Parsing
+    // used to multiply by the reciprocal of a power of ten, which lost a great deal of
+    // precision when that reciprocal was near the bottom of the `f64` range.
+    test_all_exact!(
+        accuracy_small_exponent:
+            Quad(
+                9.832567482753465e-231,
+                -5.6602372521406405e-247,
+                7.395946917821175e-264,
+                -4.067032786533478e-281,
+            ),
+            parse(concat!(
+                "9.8325674827534646270453457769152460195285900314491923275236829918437381",
+                "7181463277e-231"
+            ));
+        accuracy_small_exponent_long:
+            Quad(
+                2.852870654478393e-224,
+                7.452970961094902e-241,
+                -1.643411542715668e-257,
+                1.6200640114457176e-275,
+            ),
+            parse(concat!(
+                "2.8528706544783932552570367603255904351411069900882464115020084892827418",
+                "027975392571665142619625235979e-224"
+            ));
+        accuracy_large_exponent:
+            Quad(
+                1.2345678901234567e250,
+                4.766725103485287e233,
+                1.6171602478083272e217,
+                1.0622868440595722e201,
+            ),
+            parse(concat!(
+                "1.2345678901234567890123456789012345678901234567890123456789012345678901",
+                "234567890123456789e250"
+            ));
+        accuracy_many_digits:
+            Quad(
+                3.333333333333333e-1,
+                1.850371707708594e-17,
+                1.0271626370065257e-33,
+                5.701898048196684e-50,
+            ),
+            parse(&format!("0.{}", "3".repeat(100)));
+        // 2⁻²⁰⁰, which has an exact but long decimal representation
+        accuracy_power_of_two:
+            Quad(2f64.powi(-200), 0.0, 0.0, 0.0),
+            parse(concat!(
+                "6.2230152778611417071440640537801242405902521687211671331011166147896988",
+                "340353834411839448231257136169569665895551224821247160434722900390625e-61"
+            ));
+        accuracy_long_integer:
+            Quad::ONE,
+            parse(&format!("1{}e-400", "0".repeat(400)));
+        accuracy_leading_zeros:
+            qd!(1.25),
+            parse(&format!("0.{}125e100", "0".repeat(99)));
+        accuracy_exact_division:
+            qd!(0.375),
+            parse("0.375");
+    );
+
+    // ascii tests
+    fn ascii_err(bytes: &[u8]) -> (ErrorKind, usize) {
+        let err = Quad::from_ascii(bytes).unwrap_err();
+        (err.kind, err.position)
+    }
+
+    test_all_exact!(
+        ascii_decimal:
+            parse("1234.5678"),
+            Quad::from_ascii(b"1234.5678").unwrap();
+        ascii_whitespace:
+            qd!(-2.5),
+            Quad::from_ascii(b"  -2.5  ").unwrap();
+        ascii_pi:
+            Quad::PI,
+            Quad::from_ascii(Quad::PI.to_exact_decimal().as_bytes()).unwrap();
+        ascii_upper_exp:
+            parse("1.5e-300"),
+            Quad::from_ascii(b"1.5E-300").unwrap();
+        ascii_upper_hex:
+            qd!(-12),
+            Quad::from_ascii(b"-0X1.8P3").unwrap();
+        ascii_mixed_hex:
+            qd!(255.5),
+            Quad::from_ascii(b"0xFf.8p0").unwrap();
+        ascii_upper_inf:
+            Quad::INFINITY,
+            Quad::from_ascii(b"INF").unwrap();
+        ascii_title_inf:
+            Quad::NEG_INFINITY,
+            Quad::from_ascii(b"-Infinity").unwrap();
+    );
+    test_all_assert!(
+        ascii_upper_nan:
+            Quad::from_ascii(b"NAN").unwrap().is_nan();
+        ascii_neg_zero:
+            Quad::from_ascii(b"-0").unwrap().is_sign_negative();
+    );
+    test_all_eq!(
+        ascii_empty:
+            (ErrorKind::Empty, 0),
+            ascii_err(b"");
+        ascii_bad_digit:
+            (ErrorKind::InvalidDigit, 3),
+            ascii_err(b" 1.x");
+        ascii_not_utf8:
+            (ErrorKind::InvalidDigit, 3),
+            ascii_err(b"1.5\xff");
+        ascii_not_utf8_first:
+            (ErrorKind::InvalidDigit, 0),
+            ascii_err(b"\xc3\x28");
+    );
 }
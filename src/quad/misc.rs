@@ -3,8 +3,11 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::display as d;
+use crate::common::parse as p;
 use crate::common::utils as u;
 use crate::quad::Quad;
+use crate::round::RoundingMode;
 use std::f64;
 use std::num::FpCategory;
 
@@ -132,6 +135,57 @@ impl Quad {
         }
     }
 
+    /// Rounds the `Quad` to the given number of decimal places, using the given rounding
+    /// mode.
+    ///
+    /// The rounding is done on the exact value of the `Quad` (the sum of its components),
+    /// and the rounded decimal is then converted back to the nearest `Quad`. A number
+    /// that's exactly halfway between two decimals, like 2.5 or 0.125, is a tie that the
+    /// mode decides, which makes [`HalfEven`] the banker's rounding that financial
+    /// calculations often call for. Most decimals with places can't be stored exactly in
+    /// binary, so a result like 0.1 is only as close as a `Quad` can get to it, but it's
+    /// the same `Quad` that parsing the rounded decimal would give.
+    ///
+    /// A number that rounds to zero keeps its sign, as it does with [`round`]. Infinities
+    /// and `NaN` are returned unchanged, as are all numbers when the places are enough to
+    /// hold every digit of the exact value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, RoundingMode};
+    /// assert!(qd!(2.5).round_dp(0, RoundingMode::HalfEven) == qd!(2));
+    /// assert!(qd!(2.5).round_dp(0, RoundingMode::HalfAwayFromZero) == qd!(3));
+    /// assert!(qd!(-0.125).round_dp(2, RoundingMode::HalfEven) == qd!("-0.12"));
+    /// assert!(qd!("1234.5678").round_dp(2, RoundingMode::Down) == qd!("1234.56"));
+    /// assert!(qd!("-1234.5678").round_dp(2, RoundingMode::Down) == qd!("-1234.57"));
+    /// assert!(qd!("0.001").round_dp(2, RoundingMode::Up) == qd!("0.01"));
+    /// ```
+    ///
+    /// [`HalfEven`]: enum.RoundingMode.html#variant.HalfEven
+    /// [`round`]: #method.round
+    pub fn round_dp(self, places: usize, mode: RoundingMode) -> Quad {
+        // The smallest subnormal `f64` has 1074 decimal places, and no finite `Quad` has
+        // more than that
+        if !self.is_finite() || places >= 1074 {
+            return self;
+        }
+
+        let negative = self.is_sign_negative();
+        let digits = d::round_decimal(
+            &[self.0, self.1, self.2, self.3],
+            places as i32,
+            negative,
+            mode,
+        );
+        let parts = p::split_digits(&digits, -(places as i64), 4);
+        let result = Quad(parts[0], parts[1], parts[2], parts[3]);
+        if negative {
+            -result
+        } else {
+            result
+        }
+    }
+
     /// Returns the integer part of the `Quad`.
     ///
     /// # Examples
@@ -508,6 +562,101 @@ mod tests {
             Quad::NAN.round();
     );
 
+    // round_dp tests
+    test_all_exact!(
+        round_dp_half_even_down:
+            qd!(2),
+            qd!(2.5).round_dp(0, RoundingMode::HalfEven);
+        round_dp_half_even_up:
+            qd!(4),
+            qd!(3.5).round_dp(0, RoundingMode::HalfEven);
+        round_dp_half_even_places:
+            qd!("0.12"),
+            qd!(0.125).round_dp(2, RoundingMode::HalfEven);
+        round_dp_half_even_neg:
+            qd!("-0.38"),
+            qd!(-0.375).round_dp(2, RoundingMode::HalfEven);
+        round_dp_half_away:
+            qd!("0.13"),
+            qd!(0.125).round_dp(2, RoundingMode::HalfAwayFromZero);
+        round_dp_half_away_neg:
+            qd!("-0.13"),
+            qd!(-0.125).round_dp(2, RoundingMode::HalfAwayFromZero);
+        round_dp_down:
+            qd!("1234.56"),
+            qd!("1234.5678").round_dp(2, RoundingMode::Down);
+        round_dp_down_neg:
+            qd!("-1234.57"),
+            qd!("-1234.5678").round_dp(2, RoundingMode::Down);
+        round_dp_up:
+            qd!("1234.57"),
+            qd!("1234.5612").round_dp(2, RoundingMode::Up);
+        round_dp_up_neg:
+            qd!("-1234.56"),
+            qd!("-1234.5678").round_dp(2, RoundingMode::Up);
+        round_dp_toward_zero:
+            qd!("-1234.56"),
+            qd!("-1234.5678").round_dp(2, RoundingMode::TowardZero);
+        round_dp_carry:
+            qd!(10),
+            qd!("9.996").round_dp(2, RoundingMode::HalfEven);
+        round_dp_pi:
+            qd!("3.1415926536"),
+            Quad::PI.round_dp(10, RoundingMode::HalfEven);
+        round_dp_large:
+            qd!(1e300),
+            qd!(1e300).round_dp(5, RoundingMode::HalfEven);
+        round_dp_exact:
+            Quad::E,
+            Quad::E.round_dp(1074, RoundingMode::Up);
+        round_dp_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.round_dp(2, RoundingMode::HalfEven);
+        round_dp_neg_inf:
+            Quad::NEG_INFINITY,
+            Quad::NEG_INFINITY.round_dp(2, RoundingMode::Down);
+        round_dp_nan:
+            Quad::NAN,
+            Quad::NAN.round_dp(2, RoundingMode::HalfEven);
+    );
+    test_all_assert!(
+        round_dp_to_zero:
+            qd!(0.004).round_dp(2, RoundingMode::HalfEven).is_zero();
+        round_dp_to_neg_zero:
+            qd!(-0.004).round_dp(2, RoundingMode::HalfEven).is_sign_negative();
+        round_dp_neg_zero:
+            Quad::NEG_ZERO.round_dp(2, RoundingMode::Up).is_sign_negative();
+        round_dp_up_from_zero:
+            qd!(0.001).round_dp(2, RoundingMode::Up) == qd!("0.01");
+        round_dp_down_from_zero:
+            qd!(-0.001).round_dp(2, RoundingMode::Down) == qd!("-0.01");
+    );
+    test!(round_dp_matches_to_fixed: {
+        // Rounding to places gives the same number as parsing the string that `to_fixed`
+        // writes, which rounds in the same way
+        let modes = [
+            RoundingMode::HalfEven,
+            RoundingMode::HalfAwayFromZero,
+            RoundingMode::Down,
+            RoundingMode::Up,
+            RoundingMode::TowardZero,
+        ];
+        for &x in &[
+            Quad::PI,
+            -Quad::E * qd!(1e10),
+            Quad::LN_2 / qd!(1e20),
+            qd!(1) / qd!(3),
+            qd!("-2.675"),
+        ] {
+            for &mode in &modes {
+                for places in [0, 1, 2, 5, 15, 40] {
+                    let expected: Quad = x.to_fixed(places, mode).parse().unwrap();
+                    assert!(x.round_dp(places, mode) == expected, "{:?} {:?} {}", x, mode, places);
+                }
+            }
+        }
+    });
+
     // trunc tests
     test_all_exact!(
         trunc_pi:
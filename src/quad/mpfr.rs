@@ -0,0 +1,172 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::mpfr as m;
+use crate::quad::Quad;
+use rug::Float;
+
+impl From<Quad> for Float {
+    /// Converts a `Quad` into a [`Float`], exactly.
+    ///
+    /// The `Float` is given just enough precision to hold the sum of the components of the
+    /// `Quad`. That's usually around 212 bits, but it can be more for a `Quad` whose
+    /// components are far apart in magnitude, and it's never less than 53. `NaN`, the
+    /// infinities, and the sign of zero all carry over.
+    ///
+    /// This is only available when the `rug` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use rug::Float;
+    ///
+    /// let x = Float::from(qd!(1.5));
+    /// assert!(x == 1.5);
+    ///
+    /// let pi = Float::from(Quad::PI);
+    /// assert!(pi.prec() >= 212);
+    /// assert!(Quad::from(&pi) == Quad::PI);
+    /// ```
+    ///
+    /// [`Float`]: https://docs.rs/rug/1/rug/struct.Float.html
+    fn from(x: Quad) -> Float {
+        m::to_float(&[x.0, x.1, x.2, x.3])
+    }
+}
+
+impl From<&Float> for Quad {
+    /// Generates a `Quad` from a reference to a [`Float`], correctly rounded.
+    ///
+    /// The first component is the `Float` rounded to the nearest `f64`, and each of the
+    /// others is what's left of the `Float` after the ones before it are taken away, again
+    /// rounded to the nearest `f64`, so the result is the `Quad` closest to the `Float`. A
+    /// `Float` with no more than 212 bits of precision converts exactly, as long as it's in
+    /// range. One too large in magnitude becomes [`INFINITY`] or
+    /// [`NEG_INFINITY`], and `NaN` stays `NaN`.
+    ///
+    /// This is only available when the `rug` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use rug::Float;
+    ///
+    /// let x = Float::with_val(53, 1.5);
+    /// assert!(Quad::from(&x) == qd!(1.5));
+    ///
+    /// let third = Float::with_val(300, 1) / 3;
+    /// assert!((Quad::from(&third) - qd!(1) / qd!(3)).abs() < qd!(1e-63));
+    /// ```
+    ///
+    /// [`Float`]: https://docs.rs/rug/1/rug/struct.Float.html
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    fn from(f: &Float) -> Quad {
+        let parts = m::split(f, 4);
+        Quad(parts[0], parts[1], parts[2], parts[3])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pow2(n: i32) -> f64 {
+        2f64.powi(n)
+    }
+
+    // to_float tests
+    test!(to_float_exact: {
+        assert!(Float::from(qd!(1.5)) == 1.5);
+        assert!(Float::from(qd!(-12345)) == -12345);
+        let pi = Float::from(Quad::PI);
+        assert!(pi.to_f64() == Quad::PI.0);
+        let rest = Float::with_val(pi.prec(), &pi - Quad::PI.0);
+        assert!(rest.to_f64() == Quad::PI.1);
+        let rest = Float::with_val(pi.prec(), &rest - Quad::PI.1);
+        assert!(rest.to_f64() == Quad::PI.2);
+        let rest = Float::with_val(pi.prec(), &rest - Quad::PI.2);
+        assert!(rest == Quad::PI.3);
+    });
+    test!(to_float_prec: {
+        assert!(Float::from(qd!(1.5)).prec() == 53);
+        let x = Float::from(Quad(1.0, pow2(-200), pow2(-400), -pow2(-600)));
+        assert!(x.prec() >= 601);
+        let rest = Float::with_val(x.prec(), &x - 1);
+        let rest = Float::with_val(x.prec(), &rest - pow2(-200));
+        assert!(Float::with_val(x.prec(), &rest - pow2(-400)) == -pow2(-600));
+        let x = Float::from(Quad(f64::MAX, 1e200, 1e-100, f64::from_bits(1)));
+        assert!(x.prec() >= 2098);
+        assert!(Quad::from(&x) == Quad(f64::MAX, 1e200, 1e-100, f64::from_bits(1)));
+    });
+    test!(to_float_special: {
+        assert!(Float::from(Quad::NAN).is_nan());
+        let inf = Float::from(Quad::INFINITY);
+        assert!(inf.is_infinite() && inf.is_sign_positive());
+        let neg_inf = Float::from(Quad::NEG_INFINITY);
+        assert!(neg_inf.is_infinite() && neg_inf.is_sign_negative());
+        let zero = Float::from(Quad::ZERO);
+        assert!(zero.is_zero() && zero.is_sign_positive());
+        let neg_zero = Float::from(Quad::NEG_ZERO);
+        assert!(neg_zero.is_zero() && neg_zero.is_sign_negative());
+    });
+
+    // from_float tests
+    test_all_exact!(
+        from_float_small:
+            qd!(1.5),
+            Quad::from(&Float::with_val(53, 1.5));
+        from_float_four_parts:
+            Quad(1.0, pow2(-100), pow2(-200), pow2(-300)),
+            Quad::from(&(Float::with_val(301, 1) + pow2(-100) + pow2(-200) + pow2(-300)));
+        from_float_overflow:
+            Quad::INFINITY,
+            Quad::from(&(Float::with_val(53, f64::MAX) * 2));
+        from_float_neg_overflow:
+            Quad::NEG_INFINITY,
+            Quad::from(&(Float::with_val(53, f64::MAX) * -2));
+        from_float_underflow:
+            Quad::ZERO,
+            Quad::from(&(Float::with_val(53, 1e-300) * 1e-300));
+    );
+    test!(from_float_rounded: {
+        // Just under half of the last place of the fourth component is rounded away, and
+        // just over half of it is rounded up
+        let pi = Float::from(Quad::PI);
+        let next = f64::from_bits(Quad::PI.3.to_bits() + 1);
+        let ulp = next - Quad::PI.3;
+        let under = Float::with_val(300, &pi + ulp * 0.4999);
+        assert!(Quad::from(&under) == Quad::PI);
+        let over = Float::with_val(300, &pi + ulp * 0.5001);
+        assert!(Quad::from(&over) == Quad(Quad::PI.0, Quad::PI.1, Quad::PI.2, next));
+    });
+    test!(from_float_third: {
+        let third = Float::with_val(400, 1) / 3;
+        near!(qd!(1) / qd!(3), Quad::from(&third));
+        // Off by no more than half of the last place of the fourth component
+        let rest = Float::with_val(400, &third - &Float::from(Quad::from(&third)));
+        assert!(rest.abs() <= pow2(-214));
+    });
+    test!(from_float_special: {
+        assert!(Quad::from(&Float::with_val(53, f64::NAN)).is_nan());
+        let neg_zero = Quad::from(&Float::with_val(53, -0.0));
+        assert!(neg_zero.is_zero() && neg_zero.is_sign_negative());
+    });
+
+    // round trip tests
+    test!(round_trip: {
+        for x in [
+            Quad::PI,
+            -Quad::E,
+            Quad::LN_2 * qd!(1e200),
+            Quad::SQRT_2 / qd!(1e200),
+            Quad::MAX,
+            Quad::MIN_POSITIVE,
+            Quad(1.5, pow2(-600), pow2(-700), -pow2(-800)),
+        ] {
+            assert!(Quad::from(&Float::from(x)) == x);
+        }
+    });
+}
@@ -3,9 +3,13 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::binary128 as b;
 use crate::common::primitive as p;
 use crate::common::utils as u;
+use crate::double::Double;
+use crate::quad::common as c;
 use crate::quad::Quad;
+use crate::round::RoundMode;
 use std::ops::{Mul, MulAssign};
 
 impl Mul for Quad {
@@ -54,49 +58,7 @@ impl Mul for Quad {
     fn mul(self, other: Quad) -> Quad {
         match self.pre_mul(&other) {
             Some(r) => r,
-            None => {
-                // O(1) term
-                let (h0, l0) = p::two_prod(self.0, other.0);
-
-                // O(ε) terms
-                let (h1, l1) = p::two_prod(self.0, other.1);
-                let (h2, l2) = p::two_prod(self.1, other.0);
-
-                // O(ε²) terms
-                let (h3, l3) = p::two_prod(self.0, other.2);
-                let (h4, l4) = p::two_prod(self.1, other.1);
-                let (h5, l5) = p::two_prod(self.2, other.0);
-
-                // O(ε³) terms
-                let (h6, l6) = p::two_prod(self.0, other.3);
-                let (h7, l7) = p::two_prod(self.1, other.2);
-                let (h8, l8) = p::two_prod(self.2, other.1);
-                let (h9, l9) = p::two_prod(self.3, other.0);
-
-                // O(ε⁴) terms - the low words aren't necessary for the accuracy we need
-                let ha = self.1 * other.3;
-                let hb = self.2 * other.2;
-                let hc = self.3 * other.1;
-
-                // Each calculation takes all of the high words for the terms of that level,
-                // whatever intermediate words are specified by the algorithm, and whatever
-                // low words fit in the remaining input space.
-
-                // O(1) calculation (pass-through)
-                let r0 = h0;
-                // O(ε) calculation
-                let (r1, t0, t1) = u::three_three_sum(h1, h2, l0);
-                // O(ε²) calculation
-                let (r2, t2, t3) = u::six_three_sum(t0, h3, h4, h5, l1, l2);
-                // O(ε³) calculation
-                let (r3, t4) = u::nine_two_sum(t1, t2, h6, h7, h8, h9, l3, l4, l5);
-                // O(ε⁴) calculation (nine_one_sum)
-                let r4 = t3 + t4 + ha + hb + hc + l6 + l7 + l8 + l9;
-
-                // Results of the prior calculations are renormalized into four f64s.
-                let (a, b, c, d) = u::renorm5(r0, r1, r2, r3, r4);
-                Quad(a, b, c, d)
-            }
+            None => self.mul_unchecked(other),
         }
     }
 }
@@ -221,6 +183,472 @@ impl MulAssign<&Quad> for Quad {
     }
 }
 
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Mul<f64> for Quad {
+    type Output = Quad;
+
+    /// Multiplies this `Quad` by an `f64`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `*` operator between a `Quad` and an `f64`.
+    ///
+    /// The `f64` is taken at its exact binary value, as with `Quad::from((x, 0.0, 0.0,
+    /// 0.0))`, and is worked directly into the components rather than first being promoted
+    /// to a `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::PI * 2.5;
+    /// let expected = qd!("7.853981633974483096156608458198757210492923498437764552437361481");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    fn mul(self, other: f64) -> Quad {
+        match self.pre_mul(&Quad(other, 0.0, 0.0, 0.0)) {
+            Some(r) => r,
+            None => {
+                // This is the multiplication above with every term involving a component of
+                // `other` past the first dropped, since those are all zero
+                let (h0, l0) = p::two_prod(self.0, other);
+                let (h1, l1) = p::two_prod(self.1, other);
+                let (h2, l2) = p::two_prod(self.2, other);
+                let h3 = self.3 * other;
+
+                let s0 = h0;
+                let (s1, t0) = p::two_sum(h1, l0);
+                let (s2, t1, t2) = u::three_three_sum(t0, h2, l1);
+                let (s3, t3) = u::three_two_sum(t1, h3, l2);
+                let s4 = t2 + t3;
+
+                let (a, b, c, d) = u::renorm5(s0, s1, s2, s3, s4);
+                Quad(a, b, c, d)
+            }
+        }
+    }
+}
+
+impl Mul<f64> for &Quad {
+    type Output = Quad;
+
+    /// Multiplies a reference to this `Quad` by an `f64`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `*` operator between a reference to a `Quad` and an `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = &Quad::PI * 2.5;
+    /// let expected = qd!("7.853981633974483096156608458198757210492923498437764552437361481");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn mul(self, other: f64) -> Quad {
+        (*self).mul(other)
+    }
+}
+
+impl Mul<Quad> for f64 {
+    type Output = Quad;
+
+    /// Multiplies this `f64` by a `Quad`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `*` operator between an `f64` and a `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = 2.5 * Quad::PI;
+    /// let expected = qd!("7.853981633974483096156608458198757210492923498437764552437361481");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn mul(self, other: Quad) -> Quad {
+        other.mul(self)
+    }
+}
+
+impl Mul<&Quad> for f64 {
+    type Output = Quad;
+
+    /// Multiplies this `f64` by a reference to a `Quad`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `*` operator between an `f64` and a reference to a `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = 2.5 * &Quad::PI;
+    /// let expected = qd!("7.853981633974483096156608458198757210492923498437764552437361481");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn mul(self, other: &Quad) -> Quad {
+        self.mul(*other)
+    }
+}
+
+impl MulAssign<f64> for Quad {
+    /// Multiplies this `Quad` by an `f64`, modifying this one to equal the result.
+    ///
+    /// This implements the `*=` operator between a `Quad` and an `f64`. As with the
+    /// non-assigning operator, the `f64` is taken at its exact binary value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let mut x = Quad::PI;
+    /// x *= 2.5;
+    /// let expected = qd!("7.853981633974483096156608458198757210492923498437764552437361481");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: f64) {
+        let r = self.mul(other);
+        self.0 = r.0;
+        self.1 = r.1;
+        self.2 = r.2;
+        self.3 = r.3;
+    }
+}
+
+impl Mul<Double> for Quad {
+    type Output = Quad;
+
+    /// Multiplies this `Quad` by a `Double`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `*` operator between a `Quad` and a `Double`. The `Double` is
+    /// promoted to a `Quad` first, which is exact.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Double, Quad};
+    /// let x = Quad::E * Double::PI;
+    /// let expected = qd!("8.5397342226735670654635508695465826356632427108801368297768591420");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn mul(self, other: Double) -> Quad {
+        self.mul(Quad::from(other))
+    }
+}
+
+impl Mul<Quad> for Double {
+    type Output = Quad;
+
+    /// Multiplies this `Double` by a `Quad`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `*` operator between a `Double` and a `Quad`. The `Double` is
+    /// promoted to a `Quad` first, which is exact.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Double, Quad};
+    /// let x = Double::PI * Quad::E;
+    /// let expected = qd!("8.5397342226735670654635508695465826356632427108801368297768591420");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn mul(self, other: Quad) -> Quad {
+        Quad::from(self).mul(other)
+    }
+}
+
+impl MulAssign<Double> for Quad {
+    /// Multiplies this `Quad` by a `Double`, modifying this one to equal the result.
+    ///
+    /// This implements the `*=` operator between a `Quad` and a `Double`. As with the
+    /// non-assigning operator, the `Double` is promoted to a `Quad` first.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Double, Quad};
+    /// let mut x = Quad::E;
+    /// x *= Double::PI;
+    /// let expected = qd!("8.5397342226735670654635508695465826356632427108801368297768591420");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: Double) {
+        self.mul_assign(Quad::from(other));
+    }
+}
+
+macro_rules! mul_int_impl {
+    ($($t:ty)*) => ($(
+        impl Mul<$t> for Quad {
+            type Output = Quad;
+
+            /// Multiplies this `Quad` by an integer, producing a new `Quad` as a result.
+            ///
+            /// This implements the `*` operator between a `Quad` and an integer. Every
+            /// integer of this type converts exactly to an `f64`, so this takes the same
+            /// fast path as the `f64` operator.
+            #[inline]
+            fn mul(self, other: $t) -> Quad {
+                self.mul(f64::from(other))
+            }
+        }
+
+        impl Mul<$t> for &Quad {
+            type Output = Quad;
+
+            /// Multiplies a reference to this `Quad` by an integer, producing a new `Quad`
+            /// as a result.
+            ///
+            /// This implements the `*` operator between a reference to a `Quad` and an
+            /// integer.
+            #[inline]
+            fn mul(self, other: $t) -> Quad {
+                (*self).mul(f64::from(other))
+            }
+        }
+
+        impl Mul<Quad> for $t {
+            type Output = Quad;
+
+            /// Multiplies this integer by a `Quad`, producing a new `Quad` as a result.
+            ///
+            /// This implements the `*` operator between an integer and a `Quad`.
+            #[inline]
+            fn mul(self, other: Quad) -> Quad {
+                f64::from(self).mul(other)
+            }
+        }
+
+        impl MulAssign<$t> for Quad {
+            /// Multiplies this `Quad` by an integer, modifying this one to equal the
+            /// result.
+            ///
+            /// This implements the `*=` operator between a `Quad` and an integer.
+            #[inline]
+            fn mul_assign(&mut self, other: $t) {
+                self.mul_assign(f64::from(other));
+            }
+        }
+    )*);
+}
+
+mul_int_impl! { i32 u32 }
+
+impl Quad {
+    /// Calculates `self * a + b` with only a single rounding at the end.
+    ///
+    /// This is the `Quad` version of the fused multiply-add of [`f64::mul_add`]. The terms
+    /// of the product are added to `b` directly and the result is normalized only once,
+    /// instead of the product being normalized into a `Quad` first. That makes it more
+    /// accurate than `self * a + b` when the product and `b` nearly cancel, and a little
+    /// faster. The smallest terms of the product are still left out, just as they are in
+    /// multiplication, so the result isn't exact when the cancellation is complete all the
+    /// way down to them.
+    ///
+    /// Infinite and `NaN` arguments give the same results as `self * a + b`, as does a
+    /// product that overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = qd!(2).mul_add(qd!(3), qd!(4));
+    /// assert!(x == qd!(10));
+    ///
+    /// let x = Quad::PI.mul_add(Quad::E, Quad::ONE);
+    /// let expected = qd!("9.539734222673567065463550869546574495034888535765114961879601130");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`f64::mul_add`]: https://doc.rust-lang.org/std/primitive.f64.html#method.mul_add
+    pub fn mul_add(self, a: Quad, b: Quad) -> Quad {
+        if !(self.is_finite() && a.is_finite() && b.is_finite()) {
+            return self * a + b;
+        }
+        if (self.is_zero() || a.is_zero()) && b.is_zero() {
+            // Only the signs of the zeros matter, and f64 arithmetic has those right
+            return Quad(self.0 * a.0 + b.0, 0.0, 0.0, 0.0);
+        }
+
+        // These are the same terms, calculated the same way, as in multiplication. See the
+        // comments there.
+        let (h0, l0) = p::two_prod(self.0, a.0);
+        let (h1, l1) = p::two_prod(self.0, a.1);
+        let (h2, l2) = p::two_prod(self.1, a.0);
+        let (h3, l3) = p::two_prod(self.0, a.2);
+        let (h4, l4) = p::two_prod(self.1, a.1);
+        let (h5, l5) = p::two_prod(self.2, a.0);
+        let (h6, l6) = p::two_prod(self.0, a.3);
+        let (h7, l7) = p::two_prod(self.1, a.2);
+        let (h8, l8) = p::two_prod(self.2, a.1);
+        let (h9, l9) = p::two_prod(self.3, a.0);
+        let ha = self.1 * a.3;
+        let hb = self.2 * a.2;
+        let hc = self.3 * a.1;
+
+        let r0 = h0;
+        let (r1, t0, t1) = u::three_three_sum(h1, h2, l0);
+        let (r2, t2, t3) = u::six_three_sum(t0, h3, h4, h5, l1, l2);
+        let (r3, t4) = u::nine_two_sum(t1, t2, h6, h7, h8, h9, l3, l4, l5);
+        let r4 = t3 + t4 + ha + hb + hc + l6 + l7 + l8 + l9;
+
+        // Instead of being renormalized, the terms are added straight to `b`
+        let mut x = [0.0; 5];
+        u::merge_sum(&[r0, r1, r2, r3, r4], &[b.0, b.1, b.2, b.3], &mut x);
+        let (s0, s1, s2, s3) = u::renorm5(x[0], x[1], x[2], x[3], x[4]);
+        if s0.is_finite() {
+            Quad(s0, s1, s2, s3)
+        } else {
+            // The product overflowed
+            self * a + b
+        }
+    }
+
+    /// Multiplies this `Quad` by another, returning `None` if the product overflows.
+    ///
+    /// This is the same as the `*` operator, except that a product that is infinite or
+    /// `NaN` is turned into `None` when both `Quad`s were finite. A product that underflows
+    /// to zero is finite, so it isn't an error. As in [`checked_add`], infinite and `NaN`
+    /// arguments still produce `Some`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(2).checked_mul(qd!(3)) == Some(qd!(6)));
+    /// assert!(Quad::MAX.checked_mul(qd!(2)) == None);
+    /// assert!(Quad::INFINITY.checked_mul(qd!(2)) == Some(Quad::INFINITY));
+    /// ```
+    ///
+    /// [`checked_add`]: #method.checked_add
+    pub fn checked_mul(self, other: Quad) -> Option<Quad> {
+        let r = self * other;
+        if r.is_finite() || !(self.is_finite() && other.is_finite()) {
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    /// Multiplies this `Quad` by another without checking for special cases first.
+    ///
+    /// This skips the checks for zeros, infinities, and `NaN` that the `*` operator makes
+    /// before multiplying, for speed when the arguments are known to be finite. Like
+    /// [`add_unchecked`], it isn't unsafe, but the result is meaningless if either `Quad`
+    /// is infinite or `NaN`. For finite `Quad`s it's the same as `*`, except that a zero
+    /// result may not have the right sign.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::PI.mul_unchecked(Quad::E) == Quad::PI * Quad::E);
+    /// ```
+    ///
+    /// [`add_unchecked`]: #method.add_unchecked
+    pub fn mul_unchecked(self, other: Quad) -> Quad {
+        // O(1) term
+        let (h0, l0) = p::two_prod(self.0, other.0);
+
+        // O(ε) terms
+        let (h1, l1) = p::two_prod(self.0, other.1);
+        let (h2, l2) = p::two_prod(self.1, other.0);
+
+        // O(ε²) terms
+        let (h3, l3) = p::two_prod(self.0, other.2);
+        let (h4, l4) = p::two_prod(self.1, other.1);
+        let (h5, l5) = p::two_prod(self.2, other.0);
+
+        // O(ε³) terms
+        let (h6, l6) = p::two_prod(self.0, other.3);
+        let (h7, l7) = p::two_prod(self.1, other.2);
+        let (h8, l8) = p::two_prod(self.2, other.1);
+        let (h9, l9) = p::two_prod(self.3, other.0);
+
+        // O(ε⁴) terms - the low words aren't necessary for the accuracy we need
+        let ha = self.1 * other.3;
+        let hb = self.2 * other.2;
+        let hc = self.3 * other.1;
+
+        // Each calculation takes all of the high words for the terms of that level,
+        // whatever intermediate words are specified by the algorithm, and whatever
+        // low words fit in the remaining input space.
+
+        // O(1) calculation (pass-through)
+        let r0 = h0;
+        // O(ε) calculation
+        let (r1, t0, t1) = u::three_three_sum(h1, h2, l0);
+        // O(ε²) calculation
+        let (r2, t2, t3) = u::six_three_sum(t0, h3, h4, h5, l1, l2);
+        // O(ε³) calculation
+        let (r3, t4) = u::nine_two_sum(t1, t2, h6, h7, h8, h9, l3, l4, l5);
+        // O(ε⁴) calculation (nine_one_sum)
+        let r4 = t3 + t4 + ha + hb + hc + l6 + l7 + l8 + l9;
+
+        // Results of the prior calculations are renormalized into four f64s.
+        let (a, b, c, d) = u::renorm5(r0, r1, r2, r3, r4);
+        Quad(a, b, c, d)
+    }
+
+    /// Multiplies this `Quad` by another, rounding the product in the direction given by
+    /// `mode`.
+    ///
+    /// The product is first calculated as it is by the `*` operator. It's then compared
+    /// exactly with the sum of the products of each pair of components, however small they
+    /// are, and if it's on the wrong side of that for `mode`, it's stepped until it isn't, as
+    /// in [`add_round`]. The result with [`RoundMode::Up`] is never less than the exact
+    /// product and the result with [`RoundMode::Down`] is never greater, so the two always
+    /// bracket it. [`RoundMode::Nearest`] gives the same result as `*`.
+    ///
+    /// If either `Quad` is infinite or `NaN`, the result is the same as it is with `*`.
+    /// When a product of finite `Quad`s overflows, the result is an infinity if `mode`
+    /// rounds it away from zero and [`MAX`] or [`MIN`] if `mode` rounds it toward zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad, RoundMode};
+    /// let x = qd!(1) / qd!(3);
+    /// assert!(x.mul_round(x, RoundMode::Up) > x.mul_round(x, RoundMode::Down));
+    ///
+    /// // Exact products aren't moved
+    /// assert!(qd!(1.5).mul_round(qd!(-2.5), RoundMode::Up) == qd!(-3.75));
+    /// ```
+    ///
+    /// [`add_round`]: #method.add_round
+    /// [`MAX`]: #associatedconstant.MAX
+    /// [`MIN`]: #associatedconstant.MIN
+    /// [`RoundMode::Up`]: enum.RoundMode.html#variant.Up
+    /// [`RoundMode::Down`]: enum.RoundMode.html#variant.Down
+    /// [`RoundMode::Nearest`]: enum.RoundMode.html#variant.Nearest
+    pub fn mul_round(self, other: Quad, mode: RoundMode) -> Quad {
+        let r = self * other;
+        if !(self.is_finite() && other.is_finite()) {
+            return r;
+        }
+        c::round_directed(r, mode, |x| {
+            let lhs = [self.0, self.1, self.2, self.3];
+            let rhs = [other.0, other.1, other.2, other.3];
+            let mut products = [(0.0, 0.0); 16];
+            for (i, &a) in lhs.iter().enumerate() {
+                for (j, &b) in rhs.iter().enumerate() {
+                    products[4 * i + j] = (-a, b);
+                }
+            }
+            b::sum_sign(&[x.0, x.1, x.2, x.3], &products)
+        })
+    }
+}
+
 impl Quad {
     // Precalc functions
     //
@@ -368,6 +796,260 @@ mod tests {
             Quad::ONE * Quad::NAN;
     );
 
+    // mixed tests
+    test_all_near!(
+        quad_double:
+            qd!("8.539734222673567065463550869546582635663242710880136829776859142030"),
+            Quad::E * Double::PI;
+        double_quad:
+            qd!("8.539734222673567065463550869546582635663242710880136829776859142030"),
+            Double::PI * Quad::E;
+    );
+    test_all_exact!(
+        quad_double_inf:
+            Quad::INFINITY,
+            Quad::INFINITY * Double::ONE;
+        double_quad_inf:
+            Quad::INFINITY,
+            Double::INFINITY * Quad::ONE;
+        quad_double_nan:
+            Quad::NAN,
+            Quad::ONE * Double::NAN;
+        double_quad_nan:
+            Quad::NAN,
+            Double::NAN * Quad::ONE;
+    );
+
+    // f64 tests
+    test_all_exact!(
+        f64_int:
+            qd!(6),
+            qd!(3) * 2.0;
+        f64_rev_int:
+            qd!(6),
+            2.0 * qd!(3);
+        f64_id:
+            Quad::PI,
+            Quad::PI * 1.0;
+        f64_zero:
+            Quad::ZERO,
+            Quad::PI * 0.0;
+        f64_inf:
+            Quad::NEG_INFINITY,
+            Quad::PI * f64::NEG_INFINITY;
+        f64_inf_zero:
+            Quad::NAN,
+            Quad::INFINITY * 0.0;
+        f64_nan:
+            Quad::NAN,
+            Quad::ONE * f64::NAN;
+        f64_rev_nan:
+            Quad::NAN,
+            f64::NAN * Quad::ONE;
+    );
+    test_all_near!(
+        f64_num:
+            Quad::PI * Quad(1.1, 0.0, 0.0, 0.0),
+            Quad::PI * 1.1;
+        f64_ref:
+            Quad::PI * Quad(1.1, 0.0, 0.0, 0.0),
+            &Quad::PI * 1.1;
+        f64_rev_num:
+            Quad::PI * Quad(1.1, 0.0, 0.0, 0.0),
+            1.1 * Quad::PI;
+        f64_rev_ref:
+            Quad::PI * Quad(1.1, 0.0, 0.0, 0.0),
+            1.1 * &Quad::PI;
+        f64_small:
+            Quad::E * Quad(3.7e-200, 0.0, 0.0, 0.0),
+            Quad::E * 3.7e-200;
+    );
+
+    // checked_mul tests
+    test_all_eq!(
+        checked_mul_finite:
+            Some(qd!(6)),
+            qd!(2).checked_mul(qd!(3));
+        checked_mul_overflow:
+            None,
+            Quad::MAX.checked_mul(qd!(2));
+        checked_mul_neg_overflow:
+            None,
+            Quad::MAX.checked_mul(Quad::MIN);
+        checked_mul_underflow:
+            Some(Quad::ZERO),
+            qd!(1e-200).checked_mul(qd!(1e-200));
+        checked_mul_inf:
+            Some(Quad::NEG_INFINITY),
+            Quad::INFINITY.checked_mul(qd!(-2));
+    );
+    test_all_assert!(
+        checked_mul_nan:
+            Quad::NAN.checked_mul(qd!(1)).unwrap().is_nan();
+        checked_mul_inf_zero:
+            Quad::INFINITY.checked_mul(Quad::ZERO).unwrap().is_nan();
+    );
+
+    // mul_unchecked tests
+    test_all_exact!(
+        mul_unchecked_num:
+            Quad::PI * Quad::E,
+            Quad::PI.mul_unchecked(Quad::E);
+        mul_unchecked_neg:
+            Quad::PI * -Quad::E,
+            Quad::PI.mul_unchecked(-Quad::E);
+        mul_unchecked_small:
+            Quad::E * qd!(3.7e-200),
+            Quad::E.mul_unchecked(qd!(3.7e-200));
+        mul_unchecked_int:
+            qd!(6) * qd!(3),
+            qd!(6).mul_unchecked(qd!(3));
+    );
+
+    // mul_round tests
+    test!(mul_round_inexact: {
+        let x = qd!(1) / qd!(3);
+        let y = qd!(1) / qd!(7);
+        let up = x.mul_round(y, RoundMode::Up);
+        let down = x.mul_round(y, RoundMode::Down);
+        let nearest = x * y;
+        assert!(up > down);
+        assert!(down <= nearest && nearest <= up);
+        assert!(up - down <= nearest.abs() * qd!(2f64.powi(-205)));
+    });
+    test!(mul_round_bracket: {
+        let values = [Quad::PI, Quad::E, -Quad::LN_2, qd!(7), qd!(-1e10), qd!(3e-20)];
+        for &a in &values {
+            for &b in &values {
+                let nearest = a * b;
+                let up = a.mul_round(b, RoundMode::Up);
+                let down = a.mul_round(b, RoundMode::Down);
+                assert!(down <= nearest && nearest <= up, "{} * {} is not bracketed", a, b);
+                assert!(up - down <= nearest.abs() * qd!(2f64.powi(-205)));
+            }
+        }
+    });
+    test_all_exact!(
+        mul_round_nearest:
+            Quad::PI * Quad::E,
+            Quad::PI.mul_round(Quad::E, RoundMode::Nearest);
+        mul_round_exact_up:
+            qd!(-3.75),
+            qd!(1.5).mul_round(qd!(-2.5), RoundMode::Up);
+        mul_round_exact_down:
+            qd!(-3.75),
+            qd!(1.5).mul_round(qd!(-2.5), RoundMode::Down);
+        mul_round_toward_zero_pos:
+            Quad::PI.mul_round(Quad::E, RoundMode::Down),
+            Quad::PI.mul_round(Quad::E, RoundMode::TowardZero);
+        mul_round_toward_zero_neg:
+            (-Quad::PI).mul_round(Quad::E, RoundMode::Up),
+            (-Quad::PI).mul_round(Quad::E, RoundMode::TowardZero);
+        mul_round_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.mul_round(qd!(1), RoundMode::Down);
+    );
+    test_all_assert!(
+        mul_round_nan:
+            Quad::NAN.mul_round(qd!(1), RoundMode::Up).is_nan();
+    );
+    test!(mul_round_small: {
+        // Stepping by adding to the product used to be lost in renormalization for
+        // products this small, so this never finished
+        let x = Quad::PI * qd!(1e-107);
+        let y = Quad::E * qd!(1e-140);
+        let up = x.mul_round(y, RoundMode::Up);
+        let down = x.mul_round(y, RoundMode::Down);
+        let nearest = x * y;
+        assert!(down < up);
+        assert!(down <= nearest && nearest <= up);
+        assert!(up - down <= nearest.abs() * qd!(2f64.powi(-205)));
+    });
+    test_all_exact!(
+        mul_round_overflow_down:
+            Quad::MAX,
+            Quad::MAX.mul_round(qd!(2), RoundMode::Down);
+        mul_round_overflow_up:
+            Quad::INFINITY,
+            Quad::MAX.mul_round(qd!(2), RoundMode::Up);
+        mul_round_overflow_toward_zero:
+            Quad::MIN,
+            Quad::MAX.mul_round(qd!(-2), RoundMode::TowardZero);
+    );
+
+    // integer tests
+    test_all_exact!(
+        int_i32:
+            qd!(6),
+            qd!(3) * 2;
+        int_rev_i32:
+            qd!(6),
+            2 * qd!(3);
+        int_u32:
+            qd!(6),
+            qd!(3) * 2u32;
+        int_rev_u32:
+            qd!(6),
+            2u32 * qd!(3);
+        int_neg:
+            qd!(-6),
+            qd!(3) * -2;
+        int_max:
+            qd!(12884901885.0),
+            qd!(3) * u32::MAX;
+        int_ref:
+            Quad::PI * Quad::from(2.0),
+            &Quad::PI * 2;
+    );
+
+    // mul_add tests
+    test_all_exact!(
+        mul_add_ints:
+            qd!(10),
+            qd!(2).mul_add(qd!(3), qd!(4));
+        mul_add_cancel:
+            -Quad(2f64.powi(-240), 0.0, 0.0, 0.0),
+            Quad(1.0, 2f64.powi(-60), 2f64.powi(-120), 2f64.powi(-180))
+                .mul_add(Quad(1.0, -2f64.powi(-60), 0.0, 0.0), -Quad::ONE);
+        mul_add_zero:
+            Quad::ZERO,
+            Quad::PI.mul_add(Quad::ZERO, Quad::ZERO);
+        mul_add_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.mul_add(qd!(2), qd!(1));
+        mul_add_inf_zero:
+            Quad::NAN,
+            Quad::INFINITY.mul_add(Quad::ZERO, qd!(1));
+        mul_add_inf_inf:
+            Quad::NAN,
+            Quad::INFINITY.mul_add(qd!(2), Quad::NEG_INFINITY);
+        mul_add_nan:
+            Quad::NAN,
+            Quad::NAN.mul_add(qd!(2), qd!(1));
+        mul_add_overflow:
+            Quad::MAX * qd!(2) + qd!(1),
+            Quad::MAX.mul_add(qd!(2), qd!(1));
+    );
+    test_all_near!(
+        mul_add_pi_e:
+            qd!("9.5397342226735670654635508695465744950348885357651149618796011301792"),
+            Quad::PI.mul_add(Quad::E, Quad::ONE);
+        mul_add_neg:
+            qd!("-5.3981415690837738270009074862670716108377191363900091409046565379"),
+            Quad::PI.mul_add(-Quad::E, Quad::PI);
+    );
+    test_all_assert!(
+        mul_add_product_error:
+            (Quad::PI.mul_add(Quad::E, -(Quad::PI * Quad::E)) - qd!(7.12486727499591e-65)).abs()
+                < qd!(1e-78);
+        mul_add_cancel_sign:
+            Quad::ONE.mul_add(Quad::ONE, -Quad::ONE).is_sign_positive();
+        mul_add_neg_zero:
+            Quad::NEG_ZERO.mul_add(Quad::ONE, Quad::NEG_ZERO).is_sign_negative();
+        mul_add_mixed_zero:
+            Quad::NEG_ZERO.mul_add(Quad::ONE, Quad::ZERO).is_sign_positive();
+    );
+
     // Assign tests. Assign code delegates to mul code, so there's no need to re-test all
     // of the cases above.
     test_all!(
@@ -381,5 +1063,21 @@ mod tests {
             b *= &Quad::E;
             near!(qd!("8.5397342226735670654635508695465744950348885357651149618796011301762"), b);
         }
+        assign_f64: {
+            let mut c = Quad::PI;
+            c *= 1.1;
+            near!(Quad::PI * Quad(1.1, 0.0, 0.0, 0.0), c);
+        }
+        assign_int: {
+            let mut e = Quad::PI;
+            e *= 2;
+            e *= 3u32;
+            exact!(Quad::PI * 2.0 * 3.0, e);
+        }
+        assign_double: {
+            let mut d = Quad::PI;
+            d *= Double::E;
+            exact!(Quad::PI * Quad::from(Double::E), d);
+        }
     );
 }
@@ -50,6 +50,28 @@ impl Neg for &Quad {
     }
 }
 
+impl Quad {
+    /// Negates this `Quad` in place.
+    ///
+    /// This has the same result as `x = -x`, but it flips the signs of the components
+    /// directly rather than producing a new `Quad` and moving it back into place.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let mut x = Quad::PI;
+    /// x.negate();
+    /// assert!(x == -Quad::PI);
+    /// ```
+    #[inline]
+    pub fn negate(&mut self) {
+        self.0 = -self.0;
+        self.1 = -self.1;
+        self.2 = -self.2;
+        self.3 = -self.3;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,4 +107,69 @@ mod tests {
             Quad::NAN,
             -Quad::NAN;
     );
+
+    // negate tests
+    test_all!(
+        negate_pi: {
+            let mut x = Quad::PI;
+            x.negate();
+            exact!(-Quad::PI, x);
+            x.negate();
+            exact!(Quad::PI, x);
+        }
+        negate_zero: {
+            let mut x = Quad::ZERO;
+            x.negate();
+            assert!(x.is_zero() && x.is_sign_negative());
+            x.negate();
+            assert!(x.is_zero() && x.is_sign_positive());
+        }
+        negate_inf: {
+            let mut x = Quad::INFINITY;
+            x.negate();
+            exact!(Quad::NEG_INFINITY, x);
+        }
+        negate_nan: {
+            let mut x = Quad::NAN;
+            x.negate();
+            assert!(x.is_nan() && x.is_sign_negative());
+        }
+    );
+
+    // Negation and subtraction from zero must agree. The only difference is in the sign of
+    // a zero result: as with `f64`, 0 - 0 is +0 while -0 is -0. Signs are checked
+    // separately because `==` doesn't distinguish between zeros.
+    test!(neg_sub_consistency: {
+        let values = [
+            Quad::PI,
+            -Quad::PI,
+            Quad::E,
+            -Quad::E,
+            Quad::MAX,
+            Quad::MIN,
+            Quad::MIN_POSITIVE,
+            -Quad::MIN_POSITIVE,
+            Quad::INFINITY,
+            Quad::NEG_INFINITY,
+        ];
+        for x in values.iter() {
+            let neg = -*x;
+            let sub = Quad::ZERO - *x;
+            exact!(neg, sub);
+            assert_eq!(neg.is_sign_negative(), sub.is_sign_negative());
+        }
+    });
+    test!(neg_sub_zero: {
+        exact!(-Quad::NEG_ZERO, Quad::ZERO - Quad::NEG_ZERO);
+        assert!((-Quad::NEG_ZERO).is_sign_positive());
+        assert!((Quad::ZERO - Quad::NEG_ZERO).is_sign_positive());
+
+        exact!(-Quad::ZERO, Quad::ZERO - Quad::ZERO);
+        assert!((-Quad::ZERO).is_sign_negative());
+        assert!((Quad::ZERO - Quad::ZERO).is_sign_positive());
+    });
+    test!(neg_sub_nan: {
+        assert!((-Quad::NAN).is_nan());
+        assert!((Quad::ZERO - Quad::NAN).is_nan());
+    });
 }
@@ -0,0 +1,1125 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::error::ParseQuadError;
+use crate::quad::Quad;
+use num_traits::{
+    Bounded, Float, FloatConst, FromPrimitive, Inv, MulAdd, MulAddAssign, Num, One, Pow, Signed,
+    ToPrimitive, Zero,
+};
+use std::num::FpCategory;
+
+// 2¹²⁷ and 2¹²⁸, the bounds of the 128-bit integer types
+const TWO_127: f64 = 1.7014118346046923e38;
+const TWO_128: f64 = 3.402823669209385e38;
+
+impl Zero for Quad {
+    #[inline]
+    fn zero() -> Quad {
+        Quad::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        Quad::is_zero(*self)
+    }
+}
+
+impl One for Quad {
+    #[inline]
+    fn one() -> Quad {
+        Quad::ONE
+    }
+}
+
+impl Num for Quad {
+    type FromStrRadixErr = ParseQuadError;
+
+    /// Parses a `Quad` from a string in the given radix, from 2 to 36.
+    ///
+    /// This is the same as the inherent [`from_str_radix`], so it panics if the radix is
+    /// out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use num_traits::Num;
+    ///
+    /// assert!(<Quad as Num>::from_str_radix("2.5", 10) == Ok(qd!(2.5)));
+    /// assert!(<Quad as Num>::from_str_radix("ff", 16) == Ok(qd!(255)));
+    /// ```
+    ///
+    /// [`from_str_radix`]: #method.from_str_radix
+    fn from_str_radix(s: &str, radix: u32) -> Result<Quad, ParseQuadError> {
+        Quad::from_str_radix(s, radix)
+    }
+}
+
+impl ToPrimitive for Quad {
+    /// Converts the `Quad` into an `i64`, truncating any fractional part.
+    ///
+    /// `None` is returned if the truncated value is outside the range of an `i64` or if the
+    /// `Quad` is `NaN`.
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.to_i128().and_then(|n| n.to_i64())
+    }
+
+    /// Converts the `Quad` into a `u64`, truncating any fractional part.
+    ///
+    /// `None` is returned if the truncated value is outside the range of a `u64` or if the
+    /// `Quad` is `NaN`.
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.to_u128().and_then(|n| n.to_u64())
+    }
+
+    /// Converts the `Quad` into an `i128`, truncating any fractional part.
+    ///
+    /// `None` is returned if the truncated value is outside the range of an `i128` or if the
+    /// `Quad` is `NaN`. Every integer in that range can be represented exactly by a `Quad`,
+    /// so no precision is lost.
+    fn to_i128(&self) -> Option<i128> {
+        let t = self.trunc();
+        let rest = || t.1 as i128 + t.2 as i128 + t.3 as i128;
+        if !(t.0 >= -TWO_127 && t.0 <= TWO_127) {
+            None
+        } else if t.0 == TWO_127 {
+            // The first component rounds up to 2¹²⁷ while the value itself might be just
+            // short of it, in which case the rest of the components are negative
+            let r = rest();
+            if r < 0 {
+                Some(i128::MAX + (r + 1))
+            } else {
+                None
+            }
+        } else {
+            (t.0 as i128).checked_add(rest())
+        }
+    }
+
+    /// Converts the `Quad` into a `u128`, truncating any fractional part.
+    ///
+    /// `None` is returned if the truncated value is outside the range of a `u128` or if the
+    /// `Quad` is `NaN`.
+    fn to_u128(&self) -> Option<u128> {
+        let t = self.trunc();
+        let rest = || t.1 as i128 + t.2 as i128 + t.3 as i128;
+        if !(t.0 >= 0.0 && t.0 <= TWO_128) {
+            None
+        } else if t.0 == TWO_128 {
+            let r = rest();
+            if r < 0 {
+                Some(u128::MAX - (-r as u128 - 1))
+            } else {
+                None
+            }
+        } else if t.0 == 0.0 {
+            Some(0)
+        } else {
+            (t.0 as u128).checked_add_signed(rest())
+        }
+    }
+
+    /// Converts the `Quad` into an `f64`. This is its first component.
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.0)
+    }
+
+    /// Converts the `Quad` into an `f32`. The whole `Quad` is correctly rounded, rather than
+    /// just its first component.
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some(f32::from(*self))
+    }
+}
+
+impl FromPrimitive for Quad {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Quad> {
+        Some(From::from(n))
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Quad> {
+        Some(From::from(n))
+    }
+
+    #[inline]
+    fn from_i128(n: i128) -> Option<Quad> {
+        Some(From::from(n))
+    }
+
+    #[inline]
+    fn from_u128(n: u128) -> Option<Quad> {
+        Some(From::from(n))
+    }
+
+    #[inline]
+    fn from_f32(n: f32) -> Option<Quad> {
+        Some(From::from(n))
+    }
+
+    #[inline]
+    fn from_f64(n: f64) -> Option<Quad> {
+        Some(From::from(n))
+    }
+}
+
+impl num_traits::NumCast for Quad {
+    /// Creates a `Quad` from any number that can be converted into a primitive.
+    ///
+    /// Integers are converted exactly if they fit into an `i128` or a `u128`; other numbers
+    /// go through `f64`. `None` is returned only if the number can't be converted into any
+    /// of those.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x: Quad = num_traits::cast(u128::MAX).unwrap();
+    /// assert!(x == qd!(u128::MAX));
+    /// assert!(x != qd!(u128::MAX as f64));
+    ///
+    /// let y: Quad = num_traits::cast(1.5f32).unwrap();
+    /// assert!(y == qd!(1.5));
+    /// ```
+    fn from<T: ToPrimitive>(n: T) -> Option<Quad> {
+        let f = n.to_f64();
+        // `to_i128` and `to_u128` truncate floats, so they're only used for a number that
+        // turns out to be the same integer when it's converted to `f64`
+        match (n.to_i128(), n.to_u128(), f) {
+            (Some(i), _, Some(f)) if i as f64 == f => Some(From::from(i)),
+            (_, Some(u), Some(f)) if u as f64 == f => Some(From::from(u)),
+            (_, _, f) => f.map(From::from),
+        }
+    }
+}
+
+/// This gives `Quad` the same interface as `f64` in code that's generic over `Float`.
+///
+/// Nearly all of these methods defer to the `Quad` methods of the same names. The rest are
+/// calculated from those, to the full precision of a `Quad` except where noted.
+impl Float for Quad {
+    #[inline]
+    fn nan() -> Quad {
+        Quad::NAN
+    }
+
+    #[inline]
+    fn infinity() -> Quad {
+        Quad::INFINITY
+    }
+
+    #[inline]
+    fn neg_infinity() -> Quad {
+        Quad::NEG_INFINITY
+    }
+
+    #[inline]
+    fn neg_zero() -> Quad {
+        Quad::NEG_ZERO
+    }
+
+    #[inline]
+    fn min_value() -> Quad {
+        Quad::MIN
+    }
+
+    #[inline]
+    fn min_positive_value() -> Quad {
+        Quad::MIN_POSITIVE
+    }
+
+    #[inline]
+    fn epsilon() -> Quad {
+        Quad::EPSILON
+    }
+
+    #[inline]
+    fn max_value() -> Quad {
+        Quad::MAX
+    }
+
+    #[inline]
+    fn is_nan(self) -> bool {
+        self.is_nan()
+    }
+
+    #[inline]
+    fn is_infinite(self) -> bool {
+        self.is_infinite()
+    }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        self.is_finite()
+    }
+
+    #[inline]
+    fn is_normal(self) -> bool {
+        self.is_normal()
+    }
+
+    #[inline]
+    fn is_subnormal(self) -> bool {
+        self.is_subnormal()
+    }
+
+    #[inline]
+    fn classify(self) -> FpCategory {
+        self.classify()
+    }
+
+    #[inline]
+    fn floor(self) -> Quad {
+        self.floor()
+    }
+
+    #[inline]
+    fn ceil(self) -> Quad {
+        self.ceil()
+    }
+
+    #[inline]
+    fn round(self) -> Quad {
+        self.round()
+    }
+
+    #[inline]
+    fn trunc(self) -> Quad {
+        self.trunc()
+    }
+
+    #[inline]
+    fn fract(self) -> Quad {
+        self.fract()
+    }
+
+    #[inline]
+    fn abs(self) -> Quad {
+        self.abs()
+    }
+
+    #[inline]
+    fn signum(self) -> Quad {
+        self.signum()
+    }
+
+    #[inline]
+    fn is_sign_positive(self) -> bool {
+        self.is_sign_positive()
+    }
+
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        self.is_sign_negative()
+    }
+
+    #[inline]
+    fn mul_add(self, a: Quad, b: Quad) -> Quad {
+        self.mul_add(a, b)
+    }
+
+    #[inline]
+    fn recip(self) -> Quad {
+        self.recip()
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> Quad {
+        self.powi(n)
+    }
+
+    #[inline]
+    fn powf(self, n: Quad) -> Quad {
+        self.powf(n)
+    }
+
+    #[inline]
+    fn sqrt(self) -> Quad {
+        self.sqrt()
+    }
+
+    #[inline]
+    fn exp(self) -> Quad {
+        self.exp()
+    }
+
+    /// Calculates 2<sup>x</sup>.
+    ///
+    /// The integer part of the exponent is applied exactly, so that only the fractional part
+    /// goes through [`exp`].
+    ///
+    /// [`exp`]: #method.exp
+    fn exp2(self) -> Quad {
+        if self.is_nan() {
+            Quad::NAN
+        } else if self >= Quad(1024.0, 0.0, 0.0, 0.0) {
+            Quad::INFINITY
+        } else if self < Quad(-1075.0, 0.0, 0.0, 0.0) {
+            Quad::ZERO
+        } else {
+            let n = self.round();
+            let k = n.0 as i32;
+            // Split in two so that neither power of two overflows or underflows on its own
+            ((self - n) * Quad::LN_2)
+                .exp()
+                .ldexp(k / 2)
+                .ldexp(k - k / 2)
+        }
+    }
+
+    #[inline]
+    fn ln(self) -> Quad {
+        self.ln()
+    }
+
+    #[inline]
+    fn log(self, base: Quad) -> Quad {
+        self.log(base)
+    }
+
+    #[inline]
+    fn log2(self) -> Quad {
+        self.log2()
+    }
+
+    #[inline]
+    fn log10(self) -> Quad {
+        self.log10()
+    }
+
+    #[inline]
+    fn to_degrees(self) -> Quad {
+        self * Quad(180.0, 0.0, 0.0, 0.0) / Quad::PI
+    }
+
+    #[inline]
+    fn to_radians(self) -> Quad {
+        self * Quad::PI / Quad(180.0, 0.0, 0.0, 0.0)
+    }
+
+    /// Returns the larger of the two numbers. If one of them is `NaN`, the other is
+    /// returned.
+    #[inline]
+    fn max(self, other: Quad) -> Quad {
+        if self.is_nan() || other > self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the smaller of the two numbers. If one of them is `NaN`, the other is
+    /// returned.
+    #[inline]
+    fn min(self, other: Quad) -> Quad {
+        if self.is_nan() || other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    fn abs_sub(self, other: Quad) -> Quad {
+        self.abs_sub(other)
+    }
+
+    #[inline]
+    fn cbrt(self) -> Quad {
+        self.cbrt()
+    }
+
+    /// Calculates √(x² + y²) without overflow or underflow in the squares.
+    fn hypot(self, other: Quad) -> Quad {
+        if self.is_infinite() || other.is_infinite() {
+            Quad::INFINITY
+        } else if self.is_nan() || other.is_nan() {
+            Quad::NAN
+        } else if self.is_zero() && other.is_zero() {
+            Quad::ZERO
+        } else {
+            // Scale both by the same power of two, which is exact, so that the larger is
+            // near 1 before squaring
+            let exp = |q: Quad| ((q.0.to_bits() >> 52) & 0x7ff) as i32 - 1023;
+            let n = exp(self).max(exp(other));
+            let (x, y) = (self.ldexp(-n), other.ldexp(-n));
+            (x.sqr() + y.sqr()).sqrt().ldexp(n)
+        }
+    }
+
+    #[inline]
+    fn sin(self) -> Quad {
+        self.sin()
+    }
+
+    #[inline]
+    fn cos(self) -> Quad {
+        self.cos()
+    }
+
+    #[inline]
+    fn tan(self) -> Quad {
+        self.tan()
+    }
+
+    #[inline]
+    fn asin(self) -> Quad {
+        self.asin()
+    }
+
+    #[inline]
+    fn acos(self) -> Quad {
+        self.acos()
+    }
+
+    #[inline]
+    fn atan(self) -> Quad {
+        self.atan()
+    }
+
+    #[inline]
+    fn atan2(self, other: Quad) -> Quad {
+        self.atan2(other)
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Quad, Quad) {
+        self.sin_cos()
+    }
+
+    /// Calculates e<sup>x</sup> - 1 accurately even when *x* is close to zero.
+    fn exp_m1(self) -> Quad {
+        if self.is_zero() {
+            self
+        } else if self.abs() < Quad(0.1, 0.0, 0.0, 0.0) {
+            // Sum the Taylor series x + x²/2! + x³/3! + ..., which leaves out the 1 that
+            // would otherwise cancel
+            let threshold = (self * Quad::EPSILON).abs();
+            let mut s = self;
+            let mut t = self;
+            let mut n = 1.0;
+            while t.abs() > threshold {
+                n += 1.0;
+                t = t * self / Quad(n, 0.0, 0.0, 0.0);
+                s += t;
+            }
+            s
+        } else {
+            self.exp() - Quad::ONE
+        }
+    }
+
+    /// Calculates ln(1 + x) accurately even when *x* is close to zero.
+    fn ln_1p(self) -> Quad {
+        if self.is_zero() || self.is_nan() {
+            self
+        } else if self.abs() < Quad(0.5, 0.0, 0.0, 0.0) {
+            // `ln` is only accurate to within an absolute error, which isn't good enough
+            // near 1. Instead, use Newton's iteration to solve eʸ - 1 = x, starting from the
+            // `f64` approximation; each iteration doubles the number of correct bits.
+            let mut y = Quad(self.0.ln_1p(), 0.0, 0.0, 0.0);
+            for _ in 0..3 {
+                let e = Float::exp_m1(y);
+                y -= (e - self) / (e + Quad::ONE);
+            }
+            y
+        } else {
+            (Quad::ONE + self).ln()
+        }
+    }
+
+    #[inline]
+    fn sinh(self) -> Quad {
+        self.sinh()
+    }
+
+    #[inline]
+    fn cosh(self) -> Quad {
+        self.cosh()
+    }
+
+    #[inline]
+    fn tanh(self) -> Quad {
+        self.tanh()
+    }
+
+    #[inline]
+    fn asinh(self) -> Quad {
+        self.asinh()
+    }
+
+    #[inline]
+    fn acosh(self) -> Quad {
+        self.acosh()
+    }
+
+    #[inline]
+    fn atanh(self) -> Quad {
+        self.atanh()
+    }
+
+    /// Returns the mantissa, exponent, and sign of the first component of the `Quad`.
+    ///
+    /// The return type has room for only 64 bits of mantissa, so this cannot describe a
+    /// whole `Quad`. It gives the same result as it would for the `f64` returned by
+    /// `to_f64`.
+    #[inline]
+    fn integer_decode(self) -> (u64, i16, i8) {
+        Float::integer_decode(self.0)
+    }
+}
+
+/// This makes the `Quad` constants available to code that's generic over `FloatConst`.
+///
+/// These are the same values as the associated constants of the same names.
+#[allow(non_snake_case)]
+impl FloatConst for Quad {
+    #[inline]
+    fn E() -> Quad {
+        Quad::E
+    }
+
+    #[inline]
+    fn FRAC_1_PI() -> Quad {
+        Quad::FRAC_1_PI
+    }
+
+    #[inline]
+    fn FRAC_1_SQRT_2() -> Quad {
+        Quad::FRAC_1_SQRT_2
+    }
+
+    #[inline]
+    fn FRAC_2_PI() -> Quad {
+        Quad::FRAC_2_PI
+    }
+
+    #[inline]
+    fn FRAC_2_SQRT_PI() -> Quad {
+        Quad::FRAC_2_SQRT_PI
+    }
+
+    #[inline]
+    fn FRAC_PI_2() -> Quad {
+        Quad::FRAC_PI_2
+    }
+
+    #[inline]
+    fn FRAC_PI_3() -> Quad {
+        Quad::FRAC_PI_3
+    }
+
+    #[inline]
+    fn FRAC_PI_4() -> Quad {
+        Quad::FRAC_PI_4
+    }
+
+    #[inline]
+    fn FRAC_PI_6() -> Quad {
+        Quad::FRAC_PI_6
+    }
+
+    #[inline]
+    fn FRAC_PI_8() -> Quad {
+        Quad::FRAC_PI_8
+    }
+
+    #[inline]
+    fn LN_10() -> Quad {
+        Quad::LN_10
+    }
+
+    #[inline]
+    fn LN_2() -> Quad {
+        Quad::LN_2
+    }
+
+    #[inline]
+    fn LOG10_E() -> Quad {
+        Quad::LOG10_E
+    }
+
+    #[inline]
+    fn LOG2_E() -> Quad {
+        Quad::LOG2_E
+    }
+
+    #[inline]
+    fn PI() -> Quad {
+        Quad::PI
+    }
+
+    #[inline]
+    fn SQRT_2() -> Quad {
+        Quad::SQRT_2
+    }
+
+    #[inline]
+    fn TAU() -> Quad {
+        Quad::TAU
+    }
+
+    #[inline]
+    fn LOG10_2() -> Quad {
+        Quad::LOG10_2
+    }
+
+    #[inline]
+    fn LOG2_10() -> Quad {
+        Quad::LOG2_10
+    }
+}
+
+impl Pow<i32> for Quad {
+    type Output = Quad;
+
+    /// Raises the `Quad` to an integer power. This is the same as [`powi`].
+    ///
+    /// [`powi`]: #method.powi
+    #[inline]
+    fn pow(self, n: i32) -> Quad {
+        self.powi(n)
+    }
+}
+
+impl Pow<Quad> for Quad {
+    type Output = Quad;
+
+    /// Raises the `Quad` to a `Quad` power. This is the same as [`powf`].
+    ///
+    /// [`powf`]: #method.powf
+    #[inline]
+    fn pow(self, n: Quad) -> Quad {
+        self.powf(n)
+    }
+}
+
+impl Inv for Quad {
+    type Output = Quad;
+
+    /// Calculates the reciprocal of the `Quad`. This is the same as [`recip`].
+    ///
+    /// [`recip`]: #method.recip
+    #[inline]
+    fn inv(self) -> Quad {
+        self.recip()
+    }
+}
+
+impl Signed for Quad {
+    #[inline]
+    fn abs(&self) -> Quad {
+        Quad::abs(*self)
+    }
+
+    #[inline]
+    fn abs_sub(&self, other: &Quad) -> Quad {
+        Quad::abs_sub(*self, *other)
+    }
+
+    #[inline]
+    fn signum(&self) -> Quad {
+        Quad::signum(*self)
+    }
+
+    /// Determines whether the `Quad` has a positive sign. As with the `Signed`
+    /// implementation for `f64`, this includes positive zero and positive infinity.
+    #[inline]
+    fn is_positive(&self) -> bool {
+        Quad::is_sign_positive(*self)
+    }
+
+    /// Determines whether the `Quad` has a negative sign. As with the `Signed`
+    /// implementation for `f64`, this includes negative zero and negative infinity.
+    #[inline]
+    fn is_negative(&self) -> bool {
+        Quad::is_sign_negative(*self)
+    }
+}
+
+impl Bounded for Quad {
+    #[inline]
+    fn min_value() -> Quad {
+        Quad::MIN
+    }
+
+    #[inline]
+    fn max_value() -> Quad {
+        Quad::MAX
+    }
+}
+
+impl MulAdd for Quad {
+    type Output = Quad;
+
+    /// Calculates `self * a + b`, as [`Float::mul_add`] does.
+    ///
+    /// [`Float::mul_add`]: #method.mul_add
+    #[inline]
+    fn mul_add(self, a: Quad, b: Quad) -> Quad {
+        Float::mul_add(self, a, b)
+    }
+}
+
+impl MulAddAssign for Quad {
+    /// Assigns `self * a + b` to the `Quad`, calculated as by [`Float::mul_add`].
+    ///
+    /// [`Float::mul_add`]: #method.mul_add
+    #[inline]
+    fn mul_add_assign(&mut self, a: Quad, b: Quad) {
+        *self = Float::mul_add(*self, a, b);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A generic function of the sort this is meant to support. It would be written as
+    // `x.hypot(y)`, if not for `Quad` having inherent methods that take precedence.
+    fn norm<F: Float>(x: F, y: F) -> F {
+        Float::hypot(x, y)
+    }
+
+    fn newton_sqrt<F: Float>(a: F) -> F {
+        let two = F::one() + F::one();
+        let mut x = a;
+        for _ in 0..100 {
+            x = (x + a / x) / two;
+        }
+        x
+    }
+
+    // generic tests
+    test_all_near!(
+        generic_norm:
+            qd!(5),
+            norm(qd!(3), qd!(4));
+        generic_newton_sqrt:
+            Quad::SQRT_2,
+            newton_sqrt(qd!(2));
+    );
+
+    // num tests
+    test_all_exact!(
+        num_zero:
+            Quad::ZERO,
+            <Quad as Zero>::zero();
+        num_one:
+            Quad::ONE,
+            <Quad as One>::one();
+        num_from_str_radix:
+            qd!(2.5),
+            Quad::from_str_radix("2.5", 10).unwrap();
+    );
+    test_all_assert!(
+        num_is_zero:
+            Zero::is_zero(&Quad::NEG_ZERO);
+        num_is_not_zero:
+            !Zero::is_zero(&Quad::EPSILON);
+        num_from_str_radix_hex:
+            <Quad as Num>::from_str_radix("ff", 16) == Ok(qd!(255));
+    );
+
+    // to_primitive tests
+    test_all_eq!(
+        to_i64_trunc:
+            Some(-3),
+            (-Quad::PI).to_i64();
+        to_i64_max:
+            Some(i64::MAX),
+            qd!(i64::MAX).to_i64();
+        to_i64_too_big:
+            None,
+            (qd!(i64::MAX) + Quad::ONE).to_i64();
+        to_i64_nan:
+            None,
+            Quad::NAN.to_i64();
+        to_u64_neg:
+            None,
+            qd!(-1).to_u64();
+        to_i128_max:
+            Some(i128::MAX),
+            qd!(i128::MAX).to_i128();
+        to_i128_min:
+            Some(i128::MIN),
+            qd!(i128::MIN).to_i128();
+        to_i128_too_big:
+            None,
+            (qd!(i128::MAX) + Quad::ONE).to_i128();
+        to_i128_too_small:
+            None,
+            (qd!(i128::MIN) - Quad::ONE).to_i128();
+        to_u128_max:
+            Some(u128::MAX),
+            qd!(u128::MAX).to_u128();
+        to_u128_too_big:
+            None,
+            (qd!(u128::MAX) + Quad::ONE).to_u128();
+        to_u128_inf:
+            None,
+            Quad::INFINITY.to_u128();
+        to_f64:
+            Some(std::f64::consts::PI),
+            Quad::PI.to_f64();
+    );
+
+    // num_cast tests
+    test_all_exact!(
+        num_cast_i128:
+            qd!(i128::MIN),
+            <Quad as num_traits::NumCast>::from(i128::MIN).unwrap();
+        num_cast_u128:
+            qd!(u128::MAX),
+            <Quad as num_traits::NumCast>::from(u128::MAX).unwrap();
+        num_cast_f32:
+            qd!(1.5),
+            <Quad as num_traits::NumCast>::from(1.5f32).unwrap();
+        num_cast_neg_f64:
+            qd!(-2.75),
+            <Quad as num_traits::NumCast>::from(-2.75).unwrap();
+        num_cast_nan:
+            Quad::NAN,
+            <Quad as num_traits::NumCast>::from(f64::NAN).unwrap();
+    );
+
+    // from_primitive tests
+    test_all_exact!(
+        from_i64_min:
+            qd!(i64::MIN),
+            Quad::from_i64(i64::MIN).unwrap();
+        from_u64_max:
+            qd!(u64::MAX),
+            Quad::from_u64(u64::MAX).unwrap();
+        from_i128_min:
+            qd!(i128::MIN),
+            Quad::from_i128(i128::MIN).unwrap();
+        from_u128_max:
+            qd!(u128::MAX),
+            Quad::from_u128(u128::MAX).unwrap();
+        from_f32:
+            qd!(-0.375),
+            Quad::from_f32(-0.375).unwrap();
+        from_f64_inf:
+            Quad::INFINITY,
+            Quad::from_f64(f64::INFINITY).unwrap();
+        from_f64_nan:
+            Quad::NAN,
+            Quad::from_f64(f64::NAN).unwrap();
+    );
+    test!(from_i128_round_trip: {
+        for n in [0, -1, 12345678901234567890123456789, i128::MIN, i128::MAX, i128::MAX - 1] {
+            assert_eq!(Some(n), Quad::from_i128(n).unwrap().to_i128());
+        }
+        for n in [1, u128::MAX, u128::MAX - 1, (1 << 127) + 1] {
+            assert_eq!(Some(n), Quad::from_u128(n).unwrap().to_u128());
+        }
+    });
+
+    // narrow conversion tests
+    test_all_eq!(
+        to_i8:
+            Some(-128),
+            qd!(-128.9).to_i8();
+        to_i8_out_of_range:
+            None,
+            qd!(128).to_i8();
+        to_u8_neg:
+            None,
+            qd!(-1).to_u8();
+        to_i32_nan:
+            None,
+            Quad::NAN.to_i32();
+        to_f32_large:
+            Some(f32::INFINITY),
+            qd!(1e300).to_f32();
+        num_cast_to_i16:
+            Some(-300i16),
+            num_traits::cast::<Quad, i16>(qd!(-300.7));
+        num_cast_to_u64_nan:
+            None,
+            num_traits::cast::<Quad, u64>(Quad::NAN);
+    );
+
+    // float tests
+    test_all_exact!(
+        float_epsilon:
+            Quad::EPSILON,
+            <Quad as Float>::epsilon();
+        float_max_value:
+            Quad::MAX,
+            <Quad as Float>::max_value();
+        float_max_nan:
+            qd!(1),
+            Float::max(Quad::NAN, qd!(1));
+        float_min_nan:
+            qd!(1),
+            Float::min(qd!(1), Quad::NAN);
+        float_exp2_int:
+            qd!(1024),
+            Float::exp2(qd!(10));
+        float_exp2_overflow:
+            Quad::INFINITY,
+            Float::exp2(qd!(1024));
+        float_exp_m1_zero:
+            Quad::ZERO,
+            Float::exp_m1(Quad::ZERO);
+        float_ln_1p_zero:
+            Quad::ZERO,
+            Float::ln_1p(Quad::ZERO);
+    );
+    test_all_near!(
+        float_exp2_fract:
+            Quad::SQRT_2,
+            Float::exp2(qd!(0.5));
+        float_exp_m1_small:
+            qd!("1.000000000465661287452299547510891989830026180352251304285024678401700227413")
+                .ldexp(-30),
+            Float::exp_m1(Quad::ONE.ldexp(-30));
+        float_exp_m1_neg:
+            qd!("-0.06058693718652421388028917537769491547531910945055817799050733794645927775764"),
+            Float::exp_m1(qd!(-0.0625));
+        float_ln_1p_small:
+            qd!("0.9999999995343387129813813213150194574825383348350598727539638812389530378152")
+                .ldexp(-30),
+            Float::ln_1p(Quad::ONE.ldexp(-30));
+        float_ln_1p_neg:
+            qd!("-0.06453852113757117167292391568399292812890862534975384283537781286190120695251"),
+            Float::ln_1p(qd!(-0.0625));
+        float_ln_1p_one:
+            Quad::LN_2,
+            Float::ln_1p(qd!(1));
+        float_to_degrees:
+            qd!(180),
+            Float::to_degrees(Quad::PI);
+        float_hypot:
+            qd!(1e300) * Quad::SQRT_2,
+            Float::hypot(qd!(1e300), qd!(1e300));
+    );
+    test!(float_integer_decode: {
+        assert_eq!(
+            Float::integer_decode(Quad::PI),
+            Float::integer_decode(std::f64::consts::PI)
+        );
+    });
+
+    // float_const tests
+    test!(float_const: {
+        exact!(Quad::E, <Quad as FloatConst>::E());
+        exact!(Quad::FRAC_1_PI, <Quad as FloatConst>::FRAC_1_PI());
+        exact!(Quad::FRAC_1_SQRT_2, <Quad as FloatConst>::FRAC_1_SQRT_2());
+        exact!(Quad::FRAC_2_PI, <Quad as FloatConst>::FRAC_2_PI());
+        exact!(Quad::FRAC_2_SQRT_PI, <Quad as FloatConst>::FRAC_2_SQRT_PI());
+        exact!(Quad::FRAC_PI_2, <Quad as FloatConst>::FRAC_PI_2());
+        exact!(Quad::FRAC_PI_3, <Quad as FloatConst>::FRAC_PI_3());
+        exact!(Quad::FRAC_PI_4, <Quad as FloatConst>::FRAC_PI_4());
+        exact!(Quad::FRAC_PI_6, <Quad as FloatConst>::FRAC_PI_6());
+        exact!(Quad::FRAC_PI_8, <Quad as FloatConst>::FRAC_PI_8());
+        exact!(Quad::LN_10, <Quad as FloatConst>::LN_10());
+        exact!(Quad::LN_2, <Quad as FloatConst>::LN_2());
+        exact!(Quad::LOG10_E, <Quad as FloatConst>::LOG10_E());
+        exact!(Quad::LOG2_E, <Quad as FloatConst>::LOG2_E());
+        exact!(Quad::PI, <Quad as FloatConst>::PI());
+        exact!(Quad::SQRT_2, <Quad as FloatConst>::SQRT_2());
+        exact!(Quad::TAU, <Quad as FloatConst>::TAU());
+        exact!(Quad::LOG10_2, <Quad as FloatConst>::LOG10_2());
+        exact!(Quad::LOG2_10, <Quad as FloatConst>::LOG2_10());
+    });
+    test!(float_const_generic: {
+        fn area<F: Float + FloatConst>(r: F) -> F {
+            F::PI() * r * r
+        }
+        exact!(Quad::PI * qd!(4), area(qd!(2)));
+    });
+
+    // pow tests
+    test_all_exact!(
+        pow_int:
+            qd!(-8),
+            Pow::pow(qd!(-2), 3);
+        pow_neg_int:
+            qd!(0.25),
+            Pow::pow(qd!(2), -2);
+        pow_qd:
+            qd!(8),
+            Pow::pow(qd!(4), qd!(1.5));
+        pow_nan:
+            Quad::NAN,
+            Pow::pow(Quad::NAN, 2);
+    );
+
+    // inv tests
+    test_all_exact!(
+        inv_four:
+            qd!(0.25),
+            Inv::inv(qd!(4));
+        inv_zero:
+            Quad::INFINITY,
+            Inv::inv(Quad::ZERO);
+        inv_neg_zero:
+            Quad::NEG_INFINITY,
+            Inv::inv(Quad::NEG_ZERO);
+    );
+
+    // signed tests
+    test_all_exact!(
+        signed_abs:
+            Quad::PI,
+            Signed::abs(&-Quad::PI);
+        signed_abs_sub:
+            qd!(2),
+            Signed::abs_sub(&qd!(5), &qd!(3));
+        signed_abs_sub_less:
+            Quad::ZERO,
+            Signed::abs_sub(&qd!(3), &qd!(5));
+        signed_signum:
+            Quad::NEG_ONE,
+            Signed::signum(&-Quad::E);
+        signed_signum_nan:
+            Quad::NAN,
+            Signed::signum(&Quad::NAN);
+    );
+    test_all_assert!(
+        signed_is_positive:
+            Signed::is_positive(&Quad::ZERO);
+        signed_is_not_positive:
+            !Signed::is_positive(&Quad::NEG_ZERO);
+        signed_is_negative:
+            Signed::is_negative(&Quad::NEG_INFINITY);
+        signed_is_not_negative:
+            !Signed::is_negative(&Quad::PI);
+    );
+
+    // bounded tests
+    test_all_exact!(
+        bounded_min:
+            Quad::MIN,
+            <Quad as Bounded>::min_value();
+        bounded_max:
+            Quad::MAX,
+            <Quad as Bounded>::max_value();
+    );
+
+    // mul_add tests
+    test!(mul_add_trait: {
+        fn horner<F: MulAdd<Output = F> + Copy>(x: F, coeffs: &[F]) -> F {
+            let mut r = coeffs[0];
+            for &c in &coeffs[1..] {
+                r = r.mul_add(x, c);
+            }
+            r
+        }
+        // 2x² - 3x + 1 at x = 4
+        exact!(qd!(21), horner(qd!(4), &[qd!(2), qd!(-3), qd!(1)]));
+    });
+    test!(mul_add_assign: {
+        let mut x = qd!(3);
+        MulAddAssign::mul_add_assign(&mut x, qd!(4), qd!(5));
+        exact!(qd!(17), x);
+    });
+}
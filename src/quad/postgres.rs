@@ -0,0 +1,185 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::postgres as p;
+use crate::quad::Quad;
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use std::error::Error;
+
+impl ToSql for Quad {
+    /// Writes the `Quad` as a Postgres `NUMERIC`, exactly.
+    ///
+    /// Every `Quad` has a finite decimal expansion, so the `NUMERIC` has exactly the same
+    /// value, with just as many decimal places as it takes to write it out. That can be a lot
+    /// more than 62, since those digits are what it takes to write the binary fraction
+    /// that the `Quad` actually is. `NaN` and the infinities are written as the `NUMERIC`
+    /// special values, which need Postgres 14 or later for the infinities; the sign of a
+    /// zero is lost, as `NUMERIC` has no negative zero.
+    ///
+    /// This is only available when the `postgres` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use bytes::BytesMut;
+    /// use postgres_types::{ToSql, Type};
+    ///
+    /// let mut buf = BytesMut::new();
+    /// qd!(12345.5).to_sql(&Type::NUMERIC, &mut buf).unwrap();
+    /// // 3 digits, weight 1, positive, 1 decimal place, then 1 2345 5000 in base 10000
+    /// assert!(buf[..] == [0, 3, 0, 1, 0, 0, 0, 1, 0, 1, 0x09, 0x29, 0x13, 0x88]);
+    /// ```
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        p::to_numeric(&[self.0, self.1, self.2, self.3], out);
+        Ok(IsNull::No)
+    }
+
+    accepts!(NUMERIC);
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Quad {
+    /// Reads a Postgres `NUMERIC` into a `Quad`.
+    ///
+    /// The `NUMERIC` is rounded to the nearest `f64` for the first component, and what's left
+    /// of it is rounded the same way for each of the others. That's exact for any `NUMERIC`
+    /// that was written from a `Quad` in the first place, so a `Quad` makes the round trip
+    /// through the database without losing anything. One too large in magnitude becomes
+    /// [`INFINITY`] or [`NEG_INFINITY`], and the `NUMERIC` special values become `NaN` and
+    /// the infinities. An error is returned if the data isn't a valid `NUMERIC`.
+    ///
+    /// This is only available when the `postgres` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use postgres_types::{FromSql, Type};
+    ///
+    /// // -1.5, as 2 digits, weight 0, negative, 1 decimal place, then 1 5000
+    /// let raw = [0, 2, 0, 0, 0x40, 0, 0, 1, 0, 1, 0x13, 0x88];
+    /// assert!(Quad::from_sql(&Type::NUMERIC, &raw).unwrap() == qd!(-1.5));
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Quad, Box<dyn Error + Sync + Send>> {
+        let parts = p::from_numeric(raw, 4)?;
+        Ok(Quad(parts[0], parts[1], parts[2], parts[3]))
+    }
+
+    accepts!(NUMERIC);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(x: Quad) -> Vec<u8> {
+        let mut buf = BytesMut::new();
+        x.to_sql(&Type::NUMERIC, &mut buf).unwrap();
+        buf.to_vec()
+    }
+
+    fn decode(raw: &[u8]) -> Quad {
+        Quad::from_sql(&Type::NUMERIC, raw).unwrap()
+    }
+
+    // to_sql tests
+    test!(to_sql_integer: {
+        assert!(encode(qd!(1)) == [0, 1, 0, 0, 0, 0, 0, 0, 0, 1]);
+        assert!(encode(qd!(-10000)) == [0, 1, 0, 1, 0x40, 0, 0, 0, 0, 1]);
+        // 2⁷⁰ = 1180591620717411303424
+        assert!(
+            encode(qd!(2).powi(70))
+                == [
+                    0, 6, 0, 5, 0, 0, 0, 0, 0, 11, 0x1f, 0x7b, 0x06, 0x54, 0x1c, 0x06, 0x04, 0x6a,
+                    0x0d, 0x60
+                ]
+        );
+    });
+    test!(to_sql_fraction: {
+        // 12345.5 is 1 2345 5000, with a weight of 1
+        assert!(encode(qd!(12345.5)) == [0, 3, 0, 1, 0, 0, 0, 1, 0, 1, 0x09, 0x29, 0x13, 0x88]);
+        // 0.0625 is 0625, with a weight of -1
+        assert!(encode(qd!(0.0625)) == [0, 1, 0xff, 0xff, 0, 0, 0, 4, 0x02, 0x71]);
+        // 2⁻³⁰ = 0.000000000931322574615478515625, so 0000 0000 0931 3225 7461 5478 5156 25
+        assert!(
+            encode(qd!(2).powi(-30))
+                == [
+                    0, 6, 0xff, 0xfd, 0, 0, 0, 30, 0x03, 0xa3, 0x0c, 0x99, 0x1d, 0x25, 0x15, 0x66,
+                    0x14, 0x24, 0x09, 0xc4
+                ]
+        );
+    });
+    test!(to_sql_exact: {
+        // The f64 closest to 0.1 has 55 decimal places
+        let raw = encode(Quad(0.1, 0.0, 0.0, 0.0));
+        assert!(raw[6..8] == [0, 55]);
+        assert!(decode(&raw)[0] == 0.1);
+        let third = qd!(1) / qd!(3);
+        assert!(decode(&encode(third)) == third);
+    });
+    test!(to_sql_special: {
+        assert!(encode(Quad::ZERO) == [0, 0, 0, 0, 0, 0, 0, 0]);
+        assert!(encode(Quad::NEG_ZERO) == [0, 0, 0, 0, 0, 0, 0, 0]);
+        assert!(encode(Quad::NAN) == [0, 0, 0, 0, 0xc0, 0, 0, 0]);
+        assert!(encode(Quad::INFINITY) == [0, 0, 0, 0, 0xd0, 0, 0, 0]);
+        assert!(encode(Quad::NEG_INFINITY) == [0, 0, 0, 0, 0xf0, 0, 0, 0]);
+    });
+    test!(to_sql_type: {
+        let mut buf = BytesMut::new();
+        assert!(qd!(1).to_sql_checked(&Type::NUMERIC, &mut buf).is_ok());
+        assert!(qd!(1).to_sql_checked(&Type::FLOAT8, &mut buf).is_err());
+        assert!(<Quad as FromSql>::accepts(&Type::NUMERIC));
+        assert!(!<Quad as FromSql>::accepts(&Type::TEXT));
+    });
+
+    // from_sql tests
+    test!(from_sql_values: {
+        assert!(decode(&[0, 1, 0, 0, 0, 0, 0, 0, 0, 1]) == qd!(1));
+        assert!(decode(&[0, 2, 0, 0, 0x40, 0, 0, 1, 0, 1, 0x13, 0x88]) == qd!(-1.5));
+        // 1 with a weight of 2 is 10000²
+        assert!(decode(&[0, 1, 0, 2, 0, 0, 0, 0, 0, 1]) == qd!(100000000));
+        // 1000 with a weight of -1 is 0.1
+        let tenth = decode(&[0, 1, 0xff, 0xff, 0, 0, 0, 1, 0x03, 0xe8]);
+        near!(qd!(1) / qd!(10), tenth);
+    });
+    test!(from_sql_special: {
+        assert!(decode(&[0, 0, 0, 0, 0, 0, 0, 0]) == Quad::ZERO);
+        assert!(decode(&[0, 0, 0, 0, 0xc0, 0, 0, 0]).is_nan());
+        assert!(decode(&[0, 0, 0, 0, 0xd0, 0, 0, 0]) == Quad::INFINITY);
+        assert!(decode(&[0, 0, 0, 0, 0xf0, 0, 0, 0]) == Quad::NEG_INFINITY);
+        // 1 with a weight of 100 is 10⁴⁰⁰
+        assert!(decode(&[0, 1, 0, 100, 0, 0, 0, 0, 0, 1]) == Quad::INFINITY);
+        assert!(decode(&[0, 1, 0, 100, 0x40, 0, 0, 0, 0, 1]) == Quad::NEG_INFINITY);
+        // 1 with a weight of -100 is 10⁻⁴⁰⁰
+        assert!(decode(&[0, 1, 0xff, 0x9c, 0, 0, 0, 0, 0, 1]) == Quad::ZERO);
+        assert!(decode(&[0, 1, 0xff, 0x9c, 0x40, 0, 0, 0, 0, 1]).is_sign_negative());
+    });
+    test!(from_sql_invalid: {
+        let from = |raw: &[u8]| Quad::from_sql(&Type::NUMERIC, raw);
+        assert!(from(&[0, 0, 0, 0]).is_err());
+        assert!(from(&[0, 2, 0, 0, 0, 0, 0, 0, 0, 1]).is_err());
+        assert!(from(&[0, 1, 0, 0, 0, 0, 0, 0, 0x27, 0x10]).is_err());
+        assert!(from(&[0, 0, 0, 0, 0x12, 0x34, 0, 0]).is_err());
+    });
+
+    // round trip tests
+    test!(round_trip: {
+        for x in [
+            Quad::PI,
+            -Quad::E,
+            Quad::LN_2 * qd!(1e200),
+            Quad::SQRT_2 / qd!(1e200),
+            Quad::MAX,
+            Quad::from(f64::MIN_POSITIVE),
+            Quad(1.0, 2f64.powi(-200), 0.0, 0.0),
+            Quad(f64::from_bits(1), 0.0, 0.0, 0.0),
+        ] {
+            assert!(decode(&encode(x)) == x);
+        }
+    });
+}
@@ -0,0 +1,248 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::utils as u;
+use crate::quad::Quad;
+use rand::distributions::{Distribution, Open01, Standard};
+use rand::Rng;
+
+// 2⁻⁵³, 2⁻¹⁰⁶, 2⁻¹⁵⁹, and 2⁻²¹², the weights of the random bits in each of the components
+const FRAC_53: f64 = 1.0 / (1u64 << 53) as f64;
+const FRAC_106: f64 = FRAC_53 * FRAC_53;
+const FRAC_159: f64 = FRAC_106 * FRAC_53;
+const FRAC_212: f64 = FRAC_106 * FRAC_106;
+
+impl Distribution<Quad> for Standard {
+    /// Generates a random `Quad` uniformly distributed over the half-open range [0, 1).
+    ///
+    /// All 212 bits of the mantissa are random, not just the 53 that fit into the first
+    /// component. The result is a multiple of 2⁻²¹², each possible one being equally likely.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use rand::Rng;
+    ///
+    /// let x: Quad = rand::thread_rng().gen();
+    /// assert!(x >= qd!(0) && x < qd!(1));
+    /// ```
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quad {
+        let a = bits(rng, 53) * FRAC_53;
+        let b = bits(rng, 53) * FRAC_106;
+        let c = bits(rng, 53) * FRAC_159;
+        let d = bits(rng, 53) * FRAC_212;
+        // None of the parts overlap, so adding them is exact
+        Quad(a, 0.0, 0.0, 0.0)
+            + Quad(b, 0.0, 0.0, 0.0)
+            + Quad(c, 0.0, 0.0, 0.0)
+            + Quad(d, 0.0, 0.0, 0.0)
+    }
+}
+
+impl Distribution<Quad> for Open01 {
+    /// Generates a random `Quad` uniformly distributed over the open range (0, 1).
+    ///
+    /// As with [`Standard`], all of the bits of the mantissa are random, except that the
+    /// last one is always set to keep the result away from 0. The result is an odd multiple
+    /// of 2⁻²¹².
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use rand::distributions::Open01;
+    /// use rand::Rng;
+    ///
+    /// let x: Quad = rand::thread_rng().sample(Open01);
+    /// assert!(x > qd!(0) && x < qd!(1));
+    /// ```
+    ///
+    /// [`Standard`]: #impl-Distribution%3CQuad%3E-for-Standard
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quad {
+        let a = bits(rng, 53) * FRAC_53;
+        let b = bits(rng, 53) * FRAC_106;
+        let c = bits(rng, 53) * FRAC_159;
+        let d = (bits(rng, 52) * 2.0 + 1.0) * FRAC_212;
+        Quad(a, 0.0, 0.0, 0.0)
+            + Quad(b, 0.0, 0.0, 0.0)
+            + Quad(c, 0.0, 0.0, 0.0)
+            + Quad(d, 0.0, 0.0, 0.0)
+    }
+}
+
+impl Quad {
+    /// Converts the `Quad` into an `f64` with stochastic rounding, using `rng` as the
+    /// source of randomness.
+    ///
+    /// Rather than always going to the nearest `f64`, as [`From`] does, a `Quad` that
+    /// falls between two `f64`s goes to one or the other at random, with each one chosen
+    /// with a probability proportional to how close the `Quad` is to it. A `Quad` a
+    /// quarter of the way from one `f64` to the next becomes the nearer one three times out
+    /// of four and the farther one the rest of the time. The expected value of the result
+    /// is then the `Quad` itself, so rounding errors don't pile up in one direction over a
+    /// long computation. That's what makes this useful for studying how rounding errors
+    /// accumulate, as is often done in machine learning and climate modeling.
+    ///
+    /// A `Quad` that's exactly an `f64` always becomes that `f64`. The infinities and
+    /// `NaN` carry over, and a finite `Quad` never becomes an infinity.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let mut rng = rand::thread_rng();
+    ///
+    /// let x = Quad::ONE + Quad::from(f64::EPSILON) / qd!(4);
+    /// let y = x.to_f64_stochastic(&mut rng);
+    /// assert!(y == 1.0 || y == 1.0 + f64::EPSILON);
+    ///
+    /// assert!(qd!(1.5).to_f64_stochastic(&mut rng) == 1.5);
+    /// ```
+    ///
+    /// [`From`]: #impl-From%3CQuad%3E-for-f64
+    pub fn to_f64_stochastic<R: Rng + ?Sized>(self, rng: &mut R) -> f64 {
+        // The last component is too small to make any difference to the probability
+        u::round_stochastic(self.0, self.1 + self.2, bits(rng, 53) * FRAC_53)
+    }
+}
+
+// Generates an integer made up of `n` random bits (n <= 53), returned as an `f64`. This is
+// always exactly representable.
+#[inline]
+fn bits<R: Rng + ?Sized>(rng: &mut R, n: u32) -> f64 {
+    (rng.next_u64() >> (64 - n)) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    // Splits the `Quad` into the number of units of 2⁻²¹² it contains, as the high and low
+    // 106 bits. It has to be a whole number less than 2²¹².
+    fn units(x: Quad) -> (u128, u128) {
+        let hi = x.ldexp(106).trunc();
+        let lo = (x.ldexp(106) - hi).ldexp(106);
+        assert!(lo.trunc() == lo, "{:?} does not lie on the grid", x);
+        let int = |q: Quad| (q.0 as i128 + q.1 as i128 + q.2 as i128 + q.3 as i128) as u128;
+        (int(hi), int(lo))
+    }
+
+    // standard tests
+    test!(standard_range: {
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..10_000 {
+            let x: Quad = rng.gen();
+            assert!(x >= Quad::ZERO && x < Quad::ONE);
+            assert!(units(x).0 < 1 << 106);
+        }
+    });
+    test!(standard_low_bits: {
+        // Each of the bits below the first component should be set about half of the time;
+        // an f64 promoted to a Quad would never set any of them
+        let mut rng = StdRng::seed_from_u64(2);
+        let mut counts = [0; 159];
+        for _ in 0..4000 {
+            let (hi, lo) = units(rng.gen());
+            for (i, count) in counts.iter_mut().enumerate() {
+                let bit = if i < 106 { lo >> i } else { hi >> (i - 106) };
+                *count += bit as u32 & 1;
+            }
+        }
+        for count in counts.iter() {
+            assert!(*count > 1700 && *count < 2300, "bit count {}", count);
+        }
+    });
+    test!(standard_mean: {
+        let mut rng = StdRng::seed_from_u64(3);
+        let sum = (0..10_000).fold(Quad::ZERO, |acc, _| acc + rng.gen::<Quad>());
+        let mean = sum / qd!(10_000);
+        assert!((mean - qd!(0.5)).abs() < qd!(0.01));
+    });
+
+    // open01 tests
+    test!(open01_range: {
+        let mut rng = StdRng::seed_from_u64(4);
+        for _ in 0..10_000 {
+            let x: Quad = rng.sample(Open01);
+            assert!(x > Quad::ZERO && x < Quad::ONE);
+            assert!(units(x).1 % 2 == 1);
+        }
+    });
+    test!(open01_low_bits: {
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut counts = [0; 158];
+        for _ in 0..4000 {
+            let (hi, lo) = units(rng.sample(Open01));
+            for (i, count) in counts.iter_mut().enumerate() {
+                let bit = if i < 105 { lo >> (i + 1) } else { hi >> (i - 105) };
+                *count += bit as u32 & 1;
+            }
+        }
+        for count in counts.iter() {
+            assert!(*count > 1700 && *count < 2300, "bit count {}", count);
+        }
+    });
+
+    // stochastic tests
+    // Returns how many of `n` stochastic roundings of `x` go to `up`, checking that all of the
+    // others go to `down`
+    fn count_up(x: Quad, down: f64, up: f64, n: usize, seed: u64) -> usize {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut count = 0;
+        for _ in 0..n {
+            let y = x.to_f64_stochastic(&mut rng);
+            assert!(y == down || y == up, "{} is not {} or {}", y, down, up);
+            count += (y == up) as usize;
+        }
+        count
+    }
+
+    test_all_assert!(
+        stochastic_exact:
+            count_up(qd!(1.5), 1.5, 1.5, 1000, 6) == 1000;
+        stochastic_zero:
+            Quad::NEG_ZERO.to_f64_stochastic(&mut StdRng::seed_from_u64(7)).is_sign_negative();
+        stochastic_inf:
+            Quad::INFINITY.to_f64_stochastic(&mut StdRng::seed_from_u64(8)) == f64::INFINITY;
+        stochastic_neg_inf:
+            Quad::NEG_INFINITY.to_f64_stochastic(&mut StdRng::seed_from_u64(9))
+                == f64::NEG_INFINITY;
+        stochastic_nan:
+            Quad::NAN.to_f64_stochastic(&mut StdRng::seed_from_u64(10)).is_nan();
+        stochastic_max:
+            count_up(Quad(f64::MAX, f64::MAX * f64::EPSILON / 4.0, 0.0, 0.0), f64::MAX, f64::MAX, 1000, 11)
+                == 1000;
+    );
+    test!(stochastic_quarter: {
+        // A quarter of the way from 1 to the next f64 up goes up a quarter of the time
+        let x = Quad(1.0, f64::EPSILON / 4.0, 0.0, 0.0);
+        let count = count_up(x, 1.0, 1.0 + f64::EPSILON, 20_000, 12);
+        assert!(count > 4700 && count < 5300, "count {}", count);
+    });
+    test!(stochastic_below: {
+        // Three quarters of the way from the next f64 down to 2 goes down a quarter of the
+        // time, and the gap below a power of two is half the size of the one above it
+        let below = 2.0 - f64::EPSILON;
+        let x = Quad(2.0, -f64::EPSILON / 4.0, 0.0, 0.0);
+        let count = count_up(x, 2.0, below, 20_000, 13);
+        assert!(count > 4700 && count < 5300, "count {}", count);
+    });
+    test!(stochastic_negative: {
+        let x = Quad(-1.0, -f64::EPSILON * 0.375, 0.0, 0.0);
+        let count = count_up(x, -1.0, -1.0 - f64::EPSILON, 20_000, 14);
+        assert!(count > 7100 && count < 7900, "count {}", count);
+    });
+    test!(stochastic_mean: {
+        // The mean of the rounded numbers is the number itself, not its nearest f64
+        let x = Quad::PI;
+        let mut rng = StdRng::seed_from_u64(15);
+        let n = 20_000;
+        let sum = (0..n).fold(Quad::ZERO, |acc, _| acc + Quad(x.to_f64_stochastic(&mut rng), 0.0, 0.0, 0.0));
+        let ulp = Quad(f64::EPSILON * 2.0, 0.0, 0.0, 0.0);
+        let mean = sum / Quad(n as f64, 0.0, 0.0, 0.0);
+        assert!((mean - x).abs() < ulp / qd!(20));
+        assert!((mean - Quad(x.0, 0.0, 0.0, 0.0)).abs() > ulp / qd!(20));
+    });
+}
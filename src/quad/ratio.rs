@@ -0,0 +1,199 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::bigint as b;
+use crate::quad::from::from_quotient;
+use crate::quad::Quad;
+use num_bigint::BigInt;
+use num_rational::Ratio;
+
+impl From<Ratio<i64>> for Quad {
+    /// Generates a `Quad` from a [`Ratio`] of `i64`s.
+    ///
+    /// Both the numerator and the denominator are exactly representable as `Quad`s, so the
+    /// only rounding is in the division, which is done with the full precision of a `Quad`.
+    ///
+    /// This is only available when the `num-rational` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use num_rational::Ratio;
+    ///
+    /// let x = Quad::from(Ratio::new(-22, 7));
+    /// assert!(x == qd!(-22) / qd!(7));
+    /// ```
+    ///
+    /// [`Ratio`]: https://docs.rs/num-rational/0.4/num_rational/struct.Ratio.html
+    fn from(r: Ratio<i64>) -> Quad {
+        Quad::from(*r.numer()) / Quad::from(*r.denom())
+    }
+}
+
+impl From<&Ratio<BigInt>> for Quad {
+    /// Generates a `Quad` from a reference to a [`Ratio`] of [`BigInt`]s.
+    ///
+    /// The exact quotient is rounded to the nearest `f64`, and what's left of it is
+    /// rounded to the nearest `f64` again to give the next component, and so on. That's the
+    /// nearest `Quad` to the ratio. Only the quotient has to be in range, so a ratio converts
+    /// fine even if its numerator or denominator is far too large to be a `Quad` on its own.
+    /// A quotient too large in magnitude to be represented becomes [`INFINITY`] or
+    /// [`NEG_INFINITY`].
+    ///
+    /// This is only available when the `num-rational` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use num_bigint::BigInt;
+    /// use num_rational::Ratio;
+    ///
+    /// let r = Ratio::new(BigInt::from(1), BigInt::from(3));
+    /// assert!(Quad::from(&r) == qd!(1) / qd!(3));
+    ///
+    /// // Neither 10⁴⁰⁰ nor 3 × 10³⁹⁹ fits into a `Quad`
+    /// let r = Ratio::new(BigInt::from(10).pow(400), BigInt::from(3) * BigInt::from(10).pow(399));
+    /// let expected = Ratio::new(BigInt::from(10), BigInt::from(3));
+    /// assert!(Quad::from(&r) == Quad::from(&expected));
+    /// ```
+    ///
+    /// [`Ratio`]: https://docs.rs/num-rational/0.4/num_rational/struct.Ratio.html
+    /// [`BigInt`]: https://docs.rs/num-bigint/0.4/num_bigint/struct.BigInt.html
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    fn from(r: &Ratio<BigInt>) -> Quad {
+        from_quotient(r.numer(), r.denom())
+    }
+}
+
+impl Quad {
+    /// Converts the `Quad` into a [`Ratio`] of [`BigInt`]s, exactly, or returns `None` if
+    /// the `Quad` is `NaN` or infinite.
+    ///
+    /// Every finite `Quad` is a sum of `f64`s, each of which is an integer times a power of
+    /// two, so the denominator of the result is always a power of two. Zero becomes 0/1,
+    /// whatever its sign.
+    ///
+    /// This is only available when the `num-rational` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use num_bigint::BigInt;
+    /// use num_rational::Ratio;
+    ///
+    /// let r = qd!(-2.375).to_ratio().unwrap();
+    /// assert!(r == Ratio::new(BigInt::from(-19), BigInt::from(8)));
+    ///
+    /// let pi = Quad::PI.to_ratio().unwrap();
+    /// assert!(Quad::from(&pi) == Quad::PI);
+    /// ```
+    ///
+    /// [`Ratio`]: https://docs.rs/num-rational/0.4/num_rational/struct.Ratio.html
+    /// [`BigInt`]: https://docs.rs/num-bigint/0.4/num_bigint/struct.BigInt.html
+    pub fn to_ratio(self) -> Option<Ratio<BigInt>> {
+        if self.is_finite() {
+            Some(b::to_ratio(&[self.0, self.1, self.2, self.3]))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pow2(n: u32) -> BigInt {
+        BigInt::from(1) << n
+    }
+
+    fn big(n: &BigInt, d: &BigInt) -> Ratio<BigInt> {
+        Ratio::new(n.clone(), d.clone())
+    }
+
+    // ratio_i64 tests
+    test_all_exact!(
+        ratio_i64_third:
+            qd!(1) / qd!(3),
+            Quad::from(Ratio::new(1i64, 3));
+        ratio_i64_neg:
+            qd!(-22) / qd!(7),
+            Quad::from(Ratio::new(22i64, -7));
+        ratio_i64_integer:
+            qd!(-12345),
+            Quad::from(Ratio::from_integer(-12345i64));
+        ratio_i64_extremes:
+            qd!(i64::MIN) / qd!(i64::MAX),
+            Quad::from(Ratio::new(i64::MIN, i64::MAX));
+    );
+
+    // ratio_bigint tests
+    test_all_exact!(
+        ratio_bigint_third:
+            qd!(1) / qd!(3),
+            Quad::from(&big(&BigInt::from(1), &BigInt::from(3)));
+        ratio_bigint_pow2:
+            qd!(2),
+            Quad::from(&big(&pow2(2000), &pow2(1999)));
+        ratio_bigint_tiny:
+            qd!(2).powi(-1000),
+            Quad::from(&big(&BigInt::from(1), &pow2(1000)));
+        ratio_bigint_overflow:
+            Quad::INFINITY,
+            Quad::from(&big(&pow2(2000), &BigInt::from(3)));
+        ratio_bigint_neg_overflow:
+            Quad::NEG_INFINITY,
+            Quad::from(&big(&-pow2(2000), &BigInt::from(3)));
+        ratio_bigint_underflow:
+            Quad::ZERO,
+            Quad::from(&big(&BigInt::from(3), &pow2(2000)));
+    );
+    test!(ratio_bigint_large: {
+        // (10⁴⁰⁰ + 1) / (7 × 10³⁹⁹)
+        let ten = BigInt::from(10).pow(399);
+        let r = big(&(&ten * 10 + 1), &(&ten * 7));
+        near!(qd!(10) / qd!(7), Quad::from(&r));
+    });
+    test!(ratio_bigint_nearest: {
+        // Parsing is correctly rounded, so both have to find the same nearest `Quad`
+        let r = big(&BigInt::from(1), &BigInt::from(10));
+        assert!(Quad::from(&r) == "0.1".parse::<Quad>().unwrap());
+        let r = big(&-BigInt::from(3), &BigInt::from(10).pow(40));
+        assert!(Quad::from(&r) == "-3e-40".parse::<Quad>().unwrap());
+        let r = big(&(pow2(213) - 1), &BigInt::from(1));
+        assert!(Quad::from(&r) == Quad(2f64.powi(213), -1.0, 0.0, 0.0));
+    });
+
+    // to_ratio tests
+    test!(to_ratio_exact: {
+        assert_eq!(qd!(0).to_ratio(), Some(Ratio::from_integer(BigInt::from(0))));
+        assert_eq!(Quad::NEG_ZERO.to_ratio(), Some(Ratio::from_integer(BigInt::from(0))));
+        assert_eq!(qd!(-12345).to_ratio(), Some(Ratio::from_integer(BigInt::from(-12345))));
+        assert_eq!(qd!(0.75).to_ratio(), Some(big(&BigInt::from(3), &BigInt::from(4))));
+        assert_eq!(Quad(f64::from_bits(1), 0.0, 0.0, 0.0).to_ratio(), Some(big(&BigInt::from(1), &pow2(1074))));
+        assert_eq!(qd!(2).powi(1000).to_ratio(), Some(Ratio::from_integer(pow2(1000))));
+    });
+    test!(to_ratio_components: {
+        // Both components contribute, with a denominator from the smaller one
+        let x = Quad(2f64.powi(60), -0.5, 0.0, 0.0);
+        assert_eq!(x.to_ratio(), Some(big(&(pow2(61) - 1), &BigInt::from(2))));
+        let x = Quad(1.5, 2f64.powi(-60), 0.0, 0.0);
+        assert_eq!(x.to_ratio(), Some(big(&(BigInt::from(3) * pow2(59) + 1), &pow2(60))));
+    });
+    test!(to_ratio_round_trip: {
+        for x in [Quad::PI, -Quad::E, Quad::LN_2 * qd!(1e200), Quad::SQRT_2 / qd!(1e200), qd!(1) / qd!(3)] {
+            let r = x.to_ratio().unwrap();
+            near!(x, Quad::from(&r));
+        }
+        let x = Quad(1.5, 2f64.powi(-60), 0.0, 0.0);
+        assert_eq!(Quad::from(&x.to_ratio().unwrap()), x);
+    });
+    test!(to_ratio_special: {
+        assert!(Quad::NAN.to_ratio().is_none());
+        assert!(Quad::INFINITY.to_ratio().is_none());
+        assert!(Quad::NEG_INFINITY.to_ratio().is_none());
+    });
+}
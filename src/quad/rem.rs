@@ -3,16 +3,25 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::binary128 as b;
 use crate::quad::Quad;
-use std::ops::{Div, Rem, RemAssign};
+use std::ops::{Rem, RemAssign};
 
 impl Rem for Quad {
     type Output = Quad;
 
-    /// Divides this `Quad` by another, producing a new `Quad` of the remainder as a
-    /// result. This operation uses floored division.
+    /// Divides this `Quad` by another, producing a new `Quad` of the remainder as a result.
+    /// This uses truncated division, so the remainder has the same sign as the dividend.
     ///
-    /// This implements the `%` operator between two `Quad`s.
+    /// This implements the `%` operator between two `Quad`s. It works the same way that `%`
+    /// does for `f64`s: the remainder is this `Quad` minus the divisor times the quotient
+    /// truncated toward zero. It's found by long division, so it's exact however large the
+    /// quotient is, and it's smaller in magnitude than the divisor. The only exception is a
+    /// remainder with too many bits to be a `Quad`, which can happen when the components of
+    /// this `Quad` or of the divisor are very far apart. That one is rounded to the nearest
+    /// `Quad`, which might be the divisor itself. A zero remainder has the sign of this
+    /// `Quad`. A finite `Quad` divided by an infinity is its own remainder, and the
+    /// remainder is `NaN` if this `Quad` is infinite or if the divisor is zero.
     ///
     /// # Examples
     /// ```
@@ -24,15 +33,27 @@ impl Rem for Quad {
     /// assert!(diffx < qd!(1e-60));
     ///
     /// let y = Quad::PI % -Quad::E;
-    /// let ypected = qd!("-2.2949710033282972322579315594258221113173247880248133289589906631");
+    /// let ypected = qd!("0.423310825130748003102355911926840386439922305675146246007976965");
     ///
     /// let diffy = (y - ypected).abs();
     /// assert!(diffy < qd!(1e-60));
+    ///
+    /// assert!(qd!(-7.5) % qd!(2) == qd!(-1.5));
+    /// assert!(qd!(7.5) % Quad::INFINITY == qd!(7.5));
     /// ```
-    #[inline]
     fn rem(self, other: Quad) -> Quad {
-        let n = self.div(other).floor();
-        self - other * n
+        if self.is_finite() && other.is_infinite() {
+            return self;
+        }
+        if !self.is_finite() || !other.is_finite() || other.is_zero() {
+            return Quad::NAN;
+        }
+        let r = b::rem(
+            &[self.0, self.1, self.2, self.3],
+            &[other.0, other.1, other.2, other.3],
+            4,
+        );
+        Quad(r[0], r[1], r[2], r[3])
     }
 }
 
@@ -40,7 +61,8 @@ impl Rem for &Quad {
     type Output = Quad;
 
     /// Divides a reference to this `Quad` by another, producing a new `Quad` of the
-    /// remainder as a result. This operation uses floored division.
+    /// remainder as a result. This uses truncated division, so the remainder has the same
+    /// sign as the dividend.
     ///
     /// This implements the `%` operator between two references to `Quad`s.
     ///
@@ -54,7 +76,7 @@ impl Rem for &Quad {
     /// assert!(diffx < qd!(1e-60));
     ///
     /// let y = &Quad::PI % -Quad::E;
-    /// let ypected = qd!("-2.2949710033282972322579315594258221113173247880248133289589906631");
+    /// let ypected = qd!("0.423310825130748003102355911926840386439922305675146246007976965");
     ///
     /// let diffy = (y - ypected).abs();
     /// assert!(diffy < qd!(1e-60));
@@ -69,7 +91,8 @@ impl Rem<&Quad> for Quad {
     type Output = Quad;
 
     /// Divides this `Quad` by a reference to another, producing a new `Quad` of the
-    /// remainder as a result. This operation uses floored division.
+    /// remainder as a result. This uses truncated division, so the remainder has the same
+    /// sign as the dividend.
     ///
     /// This implements the `%` operator between a `Quad` and a reference to a `Quad`.
     ///
@@ -83,7 +106,7 @@ impl Rem<&Quad> for Quad {
     /// assert!(diffx < qd!(1e-60));
     ///
     /// let y = Quad::PI % -&Quad::E;
-    /// let ypected = qd!("-2.2949710033282972322579315594258221113173247880248133289589906631");
+    /// let ypected = qd!("0.423310825130748003102355911926840386439922305675146246007976965");
     ///
     /// let diffy = (y - ypected).abs();
     /// assert!(diffy < qd!(1e-60));
@@ -98,7 +121,8 @@ impl Rem<Quad> for &Quad {
     type Output = Quad;
 
     /// Divides a reference to this `Quad` by another `Quad`, producing a new `Quad` of the
-    /// remainder as a result. This operation uses floored division.
+    /// remainder as a result. This uses truncated division, so the remainder has the same
+    /// sign as the dividend.
     ///
     /// This implements the `%` operator between a reference to a `Quad` and a `Quad`.
     ///
@@ -112,7 +136,7 @@ impl Rem<Quad> for &Quad {
     /// assert!(diffx < qd!(1e-60));
     ///
     /// let y = &Quad::PI % -&Quad::E;
-    /// let ypected = qd!("-2.2949710033282972322579315594258221113173247880248133289589906631");
+    /// let ypected = qd!("0.423310825130748003102355911926840386439922305675146246007976965");
     ///
     /// let diffy = (y - ypected).abs();
     /// assert!(diffy < qd!(1e-60));
@@ -124,8 +148,8 @@ impl Rem<Quad> for &Quad {
 }
 
 impl RemAssign for Quad {
-    /// Divides this `Quad` by another, modifying this one to equal the remainder. This
-    /// operation uses floored division.
+    /// Divides this `Quad` by another, modifying this one to equal the remainder. This uses
+    /// truncated division, so the remainder has the same sign as the dividend.
     ///
     /// This implements the `%=` operator between two `Quad`s.
     ///
@@ -141,7 +165,7 @@ impl RemAssign for Quad {
     ///
     /// let mut y = Quad::PI;
     /// y %= -Quad::E;
-    /// let ypected = qd!("-2.2949710033282972322579315594258221113173247880248133289589906631");
+    /// let ypected = qd!("0.423310825130748003102355911926840386439922305675146246007976965");
     ///
     /// let diffy = (y - ypected).abs();
     /// assert!(diffy < qd!(1e-60));
@@ -158,7 +182,8 @@ impl RemAssign for Quad {
 
 impl RemAssign<&Quad> for Quad {
     /// Divides this `Quad` by a reference to another, modifying this one to equal the
-    /// remainder. This operation uses floored division.
+    /// remainder. This uses truncated division, so the remainder has the same sign as the
+    /// dividend.
     ///
     /// This implements the `%=` operator between a `Quad` and a reference to a `Quad`.
     ///
@@ -174,7 +199,7 @@ impl RemAssign<&Quad> for Quad {
     ///
     /// let mut y = Quad::PI;
     /// y %= -&Quad::E;
-    /// let ypected = qd!("-2.2949710033282972322579315594258221113173247880248133289589906631");
+    /// let ypected = qd!("0.423310825130748003102355911926840386439922305675146246007976965");
     ///
     /// let diffy = (y - ypected).abs();
     /// assert!(diffy < qd!(1e-60));
@@ -189,6 +214,186 @@ impl RemAssign<&Quad> for Quad {
     }
 }
 
+impl Rem<f64> for Quad {
+    type Output = Quad;
+
+    /// Divides this `Quad` by an `f64`, producing a new `Quad` of the remainder as a result.
+    /// This uses truncated division, so the remainder has the same sign as the dividend.
+    ///
+    /// This implements the `%` operator between a `Quad` and an `f64`. The `f64` is taken at
+    /// its exact binary value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::PI % 2.5;
+    /// let expected = qd!("0.6415926535897932384626433832795028841971693993751058209749445923");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn rem(self, other: f64) -> Quad {
+        self.rem(Quad(other, 0.0, 0.0, 0.0))
+    }
+}
+
+impl Rem<f64> for &Quad {
+    type Output = Quad;
+
+    /// Divides a reference to this `Quad` by an `f64`, producing a new `Quad` of the remainder
+    /// as a result. This uses truncated division, so the remainder has the same sign as the
+    /// dividend.
+    ///
+    /// This implements the `%` operator between a reference to a `Quad` and an `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = &Quad::PI % 2.5;
+    /// let expected = qd!("0.6415926535897932384626433832795028841971693993751058209749445923");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn rem(self, other: f64) -> Quad {
+        (*self).rem(other)
+    }
+}
+
+impl Rem<Quad> for f64 {
+    type Output = Quad;
+
+    /// Divides this `f64` by a `Quad`, producing a new `Quad` of the remainder as a result.
+    /// This uses truncated division, so the remainder has the same sign as the dividend.
+    ///
+    /// This implements the `%` operator between an `f64` and a `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = 4.5 % Quad::PI;
+    /// let expected = qd!("1.358407346410206761537356616720497115802830600624894179025055408");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn rem(self, other: Quad) -> Quad {
+        Quad(self, 0.0, 0.0, 0.0).rem(other)
+    }
+}
+
+impl Rem<&Quad> for f64 {
+    type Output = Quad;
+
+    /// Divides this `f64` by a reference to a `Quad`, producing a new `Quad` of the remainder
+    /// as a result. This uses truncated division, so the remainder has the same sign as the
+    /// dividend.
+    ///
+    /// This implements the `%` operator between an `f64` and a reference to a `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = 4.5 % &Quad::PI;
+    /// let expected = qd!("1.358407346410206761537356616720497115802830600624894179025055408");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn rem(self, other: &Quad) -> Quad {
+        self.rem(*other)
+    }
+}
+
+impl RemAssign<f64> for Quad {
+    /// Divides this `Quad` by an `f64`, modifying this one to equal the remainder. This uses
+    /// truncated division, so the remainder has the same sign as the dividend.
+    ///
+    /// This implements the `%=` operator between a `Quad` and an `f64`. As with the
+    /// non-assigning operator, the `f64` is taken at its exact binary value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let mut x = Quad::PI;
+    /// x %= 2.5;
+    /// let expected = qd!("0.6415926535897932384626433832795028841971693993751058209749445923");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn rem_assign(&mut self, other: f64) {
+        let r = self.rem(other);
+        self.0 = r.0;
+        self.1 = r.1;
+        self.2 = r.2;
+        self.3 = r.3;
+    }
+}
+
+macro_rules! rem_int_impl {
+    ($($t:ty)*) => ($(
+        impl Rem<$t> for Quad {
+            type Output = Quad;
+
+            /// Divides this `Quad` by an integer, producing a new `Quad` of the remainder as
+            /// a result. This uses truncated division, so the remainder has the same sign
+            /// as the dividend.
+            ///
+            /// This implements the `%` operator between a `Quad` and an integer.
+            #[inline]
+            fn rem(self, other: $t) -> Quad {
+                self.rem(f64::from(other))
+            }
+        }
+
+        impl Rem<$t> for &Quad {
+            type Output = Quad;
+
+            /// Divides a reference to this `Quad` by an integer, producing a new `Quad` of
+            /// the remainder as a result.
+            ///
+            /// This implements the `%` operator between a reference to a `Quad` and an
+            /// integer.
+            #[inline]
+            fn rem(self, other: $t) -> Quad {
+                (*self).rem(f64::from(other))
+            }
+        }
+
+        impl Rem<Quad> for $t {
+            type Output = Quad;
+
+            /// Divides this integer by a `Quad`, producing a new `Quad` of the remainder as
+            /// a result.
+            ///
+            /// This implements the `%` operator between an integer and a `Quad`.
+            #[inline]
+            fn rem(self, other: Quad) -> Quad {
+                f64::from(self).rem(other)
+            }
+        }
+
+        impl RemAssign<$t> for Quad {
+            /// Divides this `Quad` by an integer, modifying this one to equal the
+            /// remainder.
+            ///
+            /// This implements the `%=` operator between a `Quad` and an integer.
+            #[inline]
+            fn rem_assign(&mut self, other: $t) {
+                self.rem_assign(f64::from(other));
+            }
+        }
+    )*);
+}
+
+rem_int_impl! { i32 u32 }
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -208,16 +413,16 @@ mod tests {
             qd!("0.42331082513074800310235591192684038643992230567514624600797696458298"),
             &Quad::PI % &Quad::E;
         num_neg_num:
-            qd!("-2.2949710033282972322579315594258221113173247880248133289589906631409"),
+            qd!("0.42331082513074800310235591192684038643992230567514624600797696458298"),
             Quad::PI % -Quad::E;
         num_neg_ref:
-            qd!("-2.2949710033282972322579315594258221113173247880248133289589906631409"),
+            qd!("0.42331082513074800310235591192684038643992230567514624600797696458298"),
             Quad::PI % -&Quad::E;
         ref_neg_num:
-            qd!("-2.2949710033282972322579315594258221113173247880248133289589906631409"),
+            qd!("0.42331082513074800310235591192684038643992230567514624600797696458298"),
             &Quad::PI % -Quad::E;
         ref_neg_ref:
-            qd!("-2.2949710033282972322579315594258221113173247880248133289589906631409"),
+            qd!("0.42331082513074800310235591192684038643992230567514624600797696458298"),
             &Quad::PI % -&Quad::E;
         num_id:
             qd!("0.14159265358979323846264338327950288419716939937510582097494459230689"),
@@ -246,26 +451,26 @@ mod tests {
             Quad::NAN,
             Quad::INFINITY % Quad::ZERO;
         zero_inf:
-            Quad::NAN,
+            Quad::ZERO,
             Quad::ZERO % Quad::INFINITY;
         neg_inf_zero:
             Quad::NAN,
             Quad::NEG_INFINITY % Quad::ZERO;
         zero_neg_inf:
-            Quad::NAN,
+            Quad::ZERO,
             Quad::ZERO % Quad::NEG_INFINITY;
 
         inf_one:
             Quad::NAN,
             Quad::INFINITY % Quad::ONE;
         one_inf:
-            Quad::NAN,
+            Quad::ONE,
             Quad::ONE % Quad::INFINITY;
         neg_inf_one:
             Quad::NAN,
             Quad::NEG_INFINITY % Quad::ONE;
         one_neg_inf:
-            Quad::NAN,
+            Quad::ONE,
             Quad::ONE % Quad::NEG_INFINITY;
         inf_inf:
             Quad::NAN,
@@ -291,6 +496,126 @@ mod tests {
             Quad::ONE % Quad::NAN;
     );
 
+    // sign tests
+    test_all_near!(
+        neg_num_num:
+            qd!("-0.42331082513074800310235591192684038643992230567514624600797696458298"),
+            -Quad::PI % Quad::E;
+        neg_num_neg_num:
+            qd!("-0.42331082513074800310235591192684038643992230567514624600797696458298"),
+            -Quad::PI % -Quad::E;
+    );
+    test_all_exact!(
+        frac_pos_pos:
+            qd!(1.5),
+            qd!(7.5) % qd!(2);
+        frac_neg_pos:
+            qd!(-1.5),
+            qd!(-7.5) % qd!(2);
+        frac_pos_neg:
+            qd!(1.5),
+            qd!(7.5) % qd!(-2);
+        frac_neg_neg:
+            qd!(-1.5),
+            qd!(-7.5) % qd!(-2);
+        smaller_dividend:
+            qd!(-2),
+            qd!(-2) % qd!(3);
+        large_quotient:
+            qd!(2),
+            (qd!(2).powi(100) + qd!(1)) % qd!(3);
+        huge_quotient:
+            Quad(2.055380520108754, 1.4585995581216645e-16, -6.76587161283648e-33, -4.6892787608081836e-49),
+            Quad::PI.ldexp(300) % Quad::E;
+        far_quotient:
+            Quad(
+                -3.597840353351687e-211,
+                -2.0237295965708798e-227,
+                -1.4306772311905304e-244,
+                -6.10216445263193e-262,
+            ),
+            (-Quad::PI).ldexp(-500) % Quad::E.ldexp(-700);
+        inf_divisor:
+            qd!(-7.5),
+            qd!(-7.5) % Quad::NEG_INFINITY;
+    );
+    test_all_assert!(
+        zero_rem_sign:
+            (qd!(4) % qd!(2)).is_sign_positive();
+        neg_zero_rem_sign:
+            (qd!(-4) % qd!(2)).is_sign_negative();
+        neg_zero_dividend:
+            (Quad::NEG_ZERO % qd!(3)).is_sign_negative();
+    );
+    test!(rem_range: {
+        let values = [Quad::PI, -Quad::E, qd!(1e20) / qd!(3), Quad::LN_2, qd!(-12345.678)];
+        for &a in &values {
+            for &b in &values {
+                let r = a % b;
+                assert!(r.abs() < b.abs(), "{} % {} gave {}", a, b, r);
+                assert!(r.is_zero() || r.is_sign_negative() == a.is_sign_negative());
+            }
+        }
+    });
+
+    // f64 tests
+    test_all_exact!(
+        f64_int:
+            qd!(1.5),
+            qd!(7.5) % 2.0;
+        f64_rev_int:
+            qd!(-1.5),
+            -7.5 % qd!(2);
+        f64_inf:
+            qd!(7.5),
+            qd!(7.5) % f64::INFINITY;
+        f64_zero:
+            Quad::NAN,
+            Quad::PI % 0.0;
+        f64_nan:
+            Quad::NAN,
+            Quad::ONE % f64::NAN;
+        f64_rev_nan:
+            Quad::NAN,
+            f64::NAN % Quad::ONE;
+    );
+    test_all_near!(
+        f64_num:
+            Quad::PI % Quad(1.1, 0.0, 0.0, 0.0),
+            Quad::PI % 1.1;
+        f64_ref:
+            Quad::PI % Quad(1.1, 0.0, 0.0, 0.0),
+            &Quad::PI % 1.1;
+        f64_rev_num:
+            Quad(4.5, 0.0, 0.0, 0.0) % Quad::PI,
+            4.5 % Quad::PI;
+        f64_rev_ref:
+            Quad(4.5, 0.0, 0.0, 0.0) % Quad::PI,
+            4.5 % &Quad::PI;
+    );
+
+    // integer tests
+    test_all_exact!(
+        int_i32:
+            qd!(1.5),
+            qd!(7.5) % 2;
+        int_neg_i32:
+            qd!(1.5),
+            qd!(7.5) % -2;
+        int_rev_i32:
+            qd!(-1),
+            -7 % qd!(2);
+        int_u32:
+            qd!(1.5),
+            qd!(7.5) % 2u32;
+        int_rev_u32:
+            qd!(1),
+            7u32 % qd!(3);
+        int_ref:
+            qd!(1.5),
+            &qd!(7.5) % 2;
+    );
+
     test_all!(
         assign_num: {
             let mut a = Quad::PI;
@@ -302,5 +627,17 @@ mod tests {
             b %= &Quad::E;
             near!(qd!("0.42331082513074800310235591192684038643992230567514624600797696458298"), b);
         }
+        assign_f64: {
+            let mut c = Quad::PI;
+            c %= 2.5;
+            near!(Quad::PI - qd!(2.5), c);
+        }
+        assign_int: {
+            let mut d = qd!(-7.5);
+            d %= 2;
+            exact!(qd!(-1.5), d);
+            d %= 1u32;
+            exact!(qd!(-0.5), d);
+        }
     );
 }
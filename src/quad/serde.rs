@@ -0,0 +1,188 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::quad::Quad;
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Unexpected, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+use std::fmt;
+
+impl Serialize for Quad {
+    /// Serializes the `Quad` in a form that depends on the format.
+    ///
+    /// Human-readable formats (like JSON or YAML) get the decimal string from
+    /// [`to_shortest_string`], which is the shortest one that parses back into exactly the
+    /// same `Quad`. Binary formats get the four components as a tuple of four `f64`s, which
+    /// reproduces the `Quad` exactly as well.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let json = serde_json::to_string(&qd!(1.5)).unwrap();
+    /// assert!(json == "\"1.5\"");
+    /// ```
+    ///
+    /// [`to_shortest_string`]: #method.to_shortest_string
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_shortest_string())
+        } else {
+            let mut tuple = serializer.serialize_tuple(4)?;
+            tuple.serialize_element(&self.0)?;
+            tuple.serialize_element(&self.1)?;
+            tuple.serialize_element(&self.2)?;
+            tuple.serialize_element(&self.3)?;
+            tuple.end()
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Quad {
+    /// Deserializes a `Quad` from either of the forms that [`serialize`] produces.
+    ///
+    /// Human-readable formats accept any string that `parse` does, as well as a sequence of
+    /// four numbers taken as the components. The string is parsed exactly as `parse` parses
+    /// it, correctly rounded, so a `Quad` that was serialized comes back unchanged. Binary
+    /// formats expect the tuple of four `f64`s.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x: Quad = serde_json::from_str("\"1.5\"").unwrap();
+    /// assert!(x == qd!(1.5));
+    ///
+    /// let json = serde_json::to_string(&Quad::PI).unwrap();
+    /// let pi: Quad = serde_json::from_str(&json).unwrap();
+    /// assert!(pi == Quad::PI);
+    /// ```
+    ///
+    /// [`serialize`]: #method.serialize
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Quad, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(QuadVisitor)
+        } else {
+            deserializer.deserialize_tuple(4, QuadVisitor)
+        }
+    }
+}
+
+struct QuadVisitor;
+
+impl<'de> Visitor<'de> for QuadVisitor {
+    type Value = Quad;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a decimal string or a tuple of four f64 components")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Quad, E> {
+        v.parse::<Quad>()
+            .map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Quad, A::Error> {
+        let mut parts = [0.0; 4];
+        for (i, part) in parts.iter_mut().enumerate() {
+            *part = match seq.next_element()? {
+                Some(p) => p,
+                None => return Err(de::Error::invalid_length(i, &self)),
+            };
+        }
+        Ok(Quad::from_array(parts))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_test::{
+        assert_de_tokens, assert_de_tokens_error, assert_ser_tokens, assert_tokens, Configure,
+        Readable, Token,
+    };
+
+    fn components(x: Quad) -> [Token; 6] {
+        [
+            Token::Tuple { len: 4 },
+            Token::F64(x.0),
+            Token::F64(x.1),
+            Token::F64(x.2),
+            Token::F64(x.3),
+            Token::TupleEnd,
+        ]
+    }
+
+    // readable tests
+    test!(readable_tokens: {
+        assert_tokens(&qd!(1.5).readable(), &[Token::Str("1.5")]);
+        assert_ser_tokens(
+            &Quad::PI.readable(),
+            &[Token::Str("3.14159265358979323846264338327950288419716939937510582097494459232")],
+        );
+        assert_tokens(&Quad::INFINITY.readable(), &[Token::Str("inf")]);
+        assert_tokens(&Quad::NEG_INFINITY.readable(), &[Token::Str("-inf")]);
+    });
+    test!(readable_lenient: {
+        assert_de_tokens(&qd!(1.5).readable(), &[Token::Str("1.5")]);
+        assert_de_tokens(&qd!(-1234).readable(), &[Token::Str("-1.234e3")]);
+        assert_de_tokens(&Quad::PI.readable(), &components(Quad::PI));
+    });
+    test!(readable_nan: {
+        let json = serde_json::to_string(&Quad::NAN).unwrap();
+        assert!(json == "\"NaN\"");
+        assert!(serde_json::from_str::<Quad>(&json).unwrap().is_nan());
+    });
+    test!(readable_neg_zero: {
+        let json = serde_json::to_string(&Quad::NEG_ZERO).unwrap();
+        let x: Quad = serde_json::from_str(&json).unwrap();
+        assert!(x.is_zero() && x.is_sign_negative());
+    });
+    test!(readable_round_trip: {
+        for x in [Quad::PI, -Quad::E, Quad::LN_2 * qd!(1e200), Quad::SQRT_2 / qd!(1e200)] {
+            let json = serde_json::to_string(&x).unwrap();
+            let y: Quad = serde_json::from_str(&json).unwrap();
+            assert!(y == x);
+        }
+    });
+    test!(readable_round_trip_random: {
+        let mut x = qd!(1) / qd!(7);
+        for i in 0..500 {
+            x = x * Quad::PI + qd!(i) / qd!(3);
+            x = if i % 2 == 0 { x / qd!(1e50) } else { -x * qd!(1e49) };
+            let json = serde_json::to_string(&x).unwrap();
+            let y: Quad = serde_json::from_str(&json).unwrap();
+            assert!(y == x, "{:?} came back as {:?}", x, y);
+        }
+    });
+    test!(readable_errors: {
+        assert_de_tokens_error::<Readable<Quad>>(
+            &[Token::Str("1.5.1")],
+            "invalid value: string \"1.5.1\", expected a decimal string or a tuple of four f64 \
+             components",
+        );
+        assert_de_tokens_error::<Readable<Quad>>(
+            &[Token::Tuple { len: 2 }, Token::F64(1.5), Token::F64(0.0), Token::TupleEnd],
+            "invalid length 2, expected a decimal string or a tuple of four f64 components",
+        );
+    });
+
+    // compact tests
+    test!(compact_tokens: {
+        assert_tokens(
+            &qd!(1.5).compact(),
+            &[
+                Token::Tuple { len: 4 },
+                Token::F64(1.5),
+                Token::F64(0.0),
+                Token::F64(0.0),
+                Token::F64(0.0),
+                Token::TupleEnd,
+            ],
+        );
+    });
+    test!(compact_exact: {
+        for x in [Quad::E, Quad::MAX, Quad::MIN_POSITIVE, qd!(1) / qd!(3)] {
+            assert_tokens(&x.compact(), &components(x));
+        }
+    });
+}
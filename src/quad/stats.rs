@@ -0,0 +1,162 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::quad::common as c;
+use crate::quad::Quad;
+
+impl Quad {
+    /// Returns the `n`th Bernoulli number, B<sub>n</sub>, if it's available.
+    ///
+    /// The Bernoulli numbers appear in many series expansions, such as those for `tan`,
+    /// the Euler-Maclaurin formula, and Stirling's series. This function uses the convention
+    /// that B<sub>1</sub> = -1/2. Every other odd-indexed Bernoulli number is zero. Even
+    /// indices are looked up from a precalculated table that goes through B<sub>40</sub>;
+    /// `None` is returned for larger even indices.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::bernoulli(0) == Some(Quad::ONE));
+    /// assert!(Quad::bernoulli(3) == Some(Quad::ZERO));
+    /// assert!(Quad::bernoulli(42) == None);
+    ///
+    /// let b2 = Quad::bernoulli(2).unwrap();
+    /// assert!((b2 - qd!(1) / qd!(6)).abs() < qd!(1e-64));
+    /// ```
+    pub fn bernoulli(n: usize) -> Option<Quad> {
+        if n == 1 {
+            Some(Quad(-0.5, 0.0, 0.0, 0.0))
+        } else if n % 2 == 1 {
+            Some(Quad::ZERO)
+        } else {
+            c::BERNOULLI.get(n / 2).copied()
+        }
+    }
+
+    /// Rescales a slice of `Quad`s in place so that its smallest value becomes `0` and its
+    /// largest becomes `1`.
+    ///
+    /// One pass over the data finds the smallest and largest values, and a second maps
+    /// each value `x` to `(x - min) / (max - min)`. The smallest and largest values map to
+    /// exactly `0` and `1`, and the values in between are scaled linearly. If all of the
+    /// values are equal, which would make that a division by zero, they all become `0`
+    /// instead. An empty slice is left alone.
+    ///
+    /// `NaN`s are skipped when finding the extremes and are left as `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let mut data = [qd!(2), qd!(6), qd!(3), qd!(10)];
+    /// Quad::normalize_minmax(&mut data);
+    /// assert!(data == [qd!(0), qd!(0.5), qd!(0.125), qd!(1)]);
+    /// ```
+    pub fn normalize_minmax(data: &mut [Quad]) {
+        let mut min = Quad::NAN;
+        let mut max = Quad::NAN;
+        for x in data.iter() {
+            if *x < min || min.is_nan() {
+                min = *x;
+            }
+            if *x > max || max.is_nan() {
+                max = *x;
+            }
+        }
+
+        let range = max - min;
+        for x in data.iter_mut() {
+            if !x.is_nan() {
+                *x = if range.is_zero() {
+                    Quad::ZERO
+                } else {
+                    (*x - min) / range
+                };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // bernoulli tests
+    test_all_exact!(
+        bernoulli_0:
+            Quad::ONE,
+            Quad::bernoulli(0).unwrap();
+        bernoulli_1:
+            qd!(-0.5),
+            Quad::bernoulli(1).unwrap();
+        bernoulli_3:
+            Quad::ZERO,
+            Quad::bernoulli(3).unwrap();
+        bernoulli_41:
+            Quad::ZERO,
+            Quad::bernoulli(41).unwrap();
+    );
+    test_all_near!(
+        bernoulli_2:
+            qd!(1) / qd!(6),
+            Quad::bernoulli(2).unwrap();
+        bernoulli_4:
+            qd!(-1) / qd!(30),
+            Quad::bernoulli(4).unwrap();
+        bernoulli_6:
+            qd!(1) / qd!(42),
+            Quad::bernoulli(6).unwrap();
+        bernoulli_20:
+            qd!(-174_611) / qd!(330),
+            Quad::bernoulli(20).unwrap();
+        bernoulli_40:
+            qd!("-261082718496449122051") / qd!(13_530),
+            Quad::bernoulli(40).unwrap();
+    );
+    test_all_assert!(
+        bernoulli_42:
+            Quad::bernoulli(42).is_none();
+        bernoulli_large:
+            Quad::bernoulli(usize::MAX - 1).is_none();
+    );
+    // normalize_minmax tests
+    test!(normalize_minmax_basic: {
+        let mut data = [qd!(2), qd!(6), qd!(3), qd!(10), qd!(-2)];
+        Quad::normalize_minmax(&mut data);
+        exact!(qd!(1) / qd!(3), data[0]);
+        exact!(qd!(2) / qd!(3), data[1]);
+        exact!(qd!(5) / qd!(12), data[2]);
+        exact!(Quad::ONE, data[3]);
+        exact!(Quad::ZERO, data[4]);
+    });
+    test!(normalize_minmax_inexact: {
+        let mut data = [Quad::PI, Quad::E, Quad::LN_2, Quad::PI * qd!(1e10), Quad::LN_10];
+        let original = data;
+        Quad::normalize_minmax(&mut data);
+        exact!(Quad::ZERO, data[2]);
+        exact!(Quad::ONE, data[3]);
+        let (min, max) = (original[2], original[3]);
+        for i in [0, 1, 4] {
+            near!((original[i] - min) / (max - min), data[i]);
+            assert!(data[i] > Quad::ZERO && data[i] < Quad::ONE);
+        }
+    });
+    test!(normalize_minmax_equal: {
+        let mut data = [Quad::PI, Quad::PI, Quad::PI];
+        Quad::normalize_minmax(&mut data);
+        assert!(data == [Quad::ZERO; 3]);
+    });
+    test!(normalize_minmax_nan: {
+        let mut data = [qd!(1), Quad::NAN, qd!(5)];
+        Quad::normalize_minmax(&mut data);
+        exact!(Quad::ZERO, data[0]);
+        assert!(data[1].is_nan());
+        exact!(Quad::ONE, data[2]);
+    });
+    test!(normalize_minmax_empty: {
+        let mut data: [Quad; 0] = [];
+        Quad::normalize_minmax(&mut data);
+        assert!(data.is_empty());
+    });
+}
@@ -3,7 +3,11 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::binary128 as b;
+use crate::double::Double;
+use crate::quad::common as c;
 use crate::quad::Quad;
+use crate::round::RoundMode;
 use std::ops::{Add, Sub, SubAssign};
 
 impl Sub for Quad {
@@ -150,9 +154,347 @@ impl SubAssign<&Quad> for Quad {
     }
 }
 
+impl Sub<f64> for Quad {
+    type Output = Quad;
+
+    /// Subtracts an `f64` from this `Quad`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `-` operator between a `Quad` and an `f64`.
+    ///
+    /// The `f64` is taken at its exact binary value, as with `Quad::from((x, 0.0, 0.0,
+    /// 0.0))`, and is worked directly into the components rather than first being promoted
+    /// to a `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::PI - 1.5;
+    /// let expected = qd!("1.641592653589793238462643383279502884197169399375105820974944592");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn sub(self, other: f64) -> Quad {
+        self.add(-other)
+    }
+}
+
+impl Sub<f64> for &Quad {
+    type Output = Quad;
+
+    /// Subtracts an `f64` from a reference to this `Quad`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `-` operator between a reference to a `Quad` and an `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = &Quad::PI - 1.5;
+    /// let expected = qd!("1.641592653589793238462643383279502884197169399375105820974944592");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn sub(self, other: f64) -> Quad {
+        (*self).sub(other)
+    }
+}
+
+impl Sub<Quad> for f64 {
+    type Output = Quad;
+
+    /// Subtracts a `Quad` from this `f64`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `-` operator between an `f64` and a `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = 1.5 - Quad::PI;
+    /// let expected = qd!("-1.641592653589793238462643383279502884197169399375105820974944592");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn sub(self, other: Quad) -> Quad {
+        (-other).add(self)
+    }
+}
+
+impl Sub<&Quad> for f64 {
+    type Output = Quad;
+
+    /// Subtracts a reference to a `Quad` from this `f64`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `-` operator between an `f64` and a reference to a `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = 1.5 - &Quad::PI;
+    /// let expected = qd!("-1.641592653589793238462643383279502884197169399375105820974944592");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn sub(self, other: &Quad) -> Quad {
+        self.sub(*other)
+    }
+}
+
+impl SubAssign<f64> for Quad {
+    /// Subtracts an `f64` from this `Quad`, modifying this one to equal the result.
+    ///
+    /// This implements the `-=` operator between a `Quad` and an `f64`. As with the
+    /// non-assigning operator, the `f64` is taken at its exact binary value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let mut x = Quad::PI;
+    /// x -= 1.5;
+    /// let expected = qd!("1.641592653589793238462643383279502884197169399375105820974944592");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: f64) {
+        let r = self.sub(other);
+        self.0 = r.0;
+        self.1 = r.1;
+        self.2 = r.2;
+        self.3 = r.3;
+    }
+}
+
+impl Sub<Double> for Quad {
+    type Output = Quad;
+
+    /// Subtracts a `Double` from this `Quad`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `-` operator between a `Quad` and a `Double`. The `Double` is
+    /// promoted to a `Quad` first, which is exact.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Double, Quad};
+    /// let x = Quad::E - Double::PI;
+    /// let expected = qd!("-0.423310825130748003102355911926843381209732024014700887602244840");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn sub(self, other: Double) -> Quad {
+        self.sub(Quad::from(other))
+    }
+}
+
+impl Sub<Quad> for Double {
+    type Output = Quad;
+
+    /// Subtracts a `Quad` from this `Double`, producing a new `Quad` as a result.
+    ///
+    /// This implements the `-` operator between a `Double` and a `Quad`. The `Double` is
+    /// promoted to a `Quad` first, which is exact.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Double, Quad};
+    /// let x = Double::PI - Quad::E;
+    /// let expected = qd!("0.4233108251307480031023559119268433812097320240147008876022448400");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn sub(self, other: Quad) -> Quad {
+        Quad::from(self).sub(other)
+    }
+}
+
+impl SubAssign<Double> for Quad {
+    /// Subtracts a `Double` from this `Quad`, modifying this one to equal the result.
+    ///
+    /// This implements the `-=` operator between a `Quad` and a `Double`. As with the
+    /// non-assigning operator, the `Double` is promoted to a `Quad` first.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Double, Quad};
+    /// let mut x = Quad::E;
+    /// x -= Double::PI;
+    /// let expected = qd!("-0.423310825130748003102355911926843381209732024014700887602244840");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: Double) {
+        self.sub_assign(Quad::from(other));
+    }
+}
+
+macro_rules! sub_int_impl {
+    ($($t:ty)*) => ($(
+        impl Sub<$t> for Quad {
+            type Output = Quad;
+
+            /// Subtracts an integer from this `Quad`, producing a new `Quad` as a result.
+            ///
+            /// This implements the `-` operator between a `Quad` and an integer. Every
+            /// integer of this type converts exactly to an `f64`, so this takes the same
+            /// fast path as the `f64` operator.
+            #[inline]
+            fn sub(self, other: $t) -> Quad {
+                self.sub(f64::from(other))
+            }
+        }
+
+        impl Sub<$t> for &Quad {
+            type Output = Quad;
+
+            /// Subtracts an integer from a reference to this `Quad`, producing a new `Quad`
+            /// as a result.
+            ///
+            /// This implements the `-` operator between a reference to a `Quad` and an
+            /// integer.
+            #[inline]
+            fn sub(self, other: $t) -> Quad {
+                (*self).sub(f64::from(other))
+            }
+        }
+
+        impl Sub<Quad> for $t {
+            type Output = Quad;
+
+            /// Subtracts a `Quad` from this integer, producing a new `Quad` as a result.
+            ///
+            /// This implements the `-` operator between an integer and a `Quad`.
+            #[inline]
+            fn sub(self, other: Quad) -> Quad {
+                f64::from(self).sub(other)
+            }
+        }
+
+        impl SubAssign<$t> for Quad {
+            /// Subtracts an integer from this `Quad`, modifying this one to equal the
+            /// result.
+            ///
+            /// This implements the `-=` operator between a `Quad` and an integer.
+            #[inline]
+            fn sub_assign(&mut self, other: $t) {
+                self.sub_assign(f64::from(other));
+            }
+        }
+    )*);
+}
+
+sub_int_impl! { i32 u32 }
+
+impl Quad {
+    /// Calculates the positive difference between this `Quad` and another, which is the
+    /// difference if this one is larger and zero otherwise.
+    ///
+    /// This is the same as C's `fdim`. Unlike `(a - b).max(Quad::ZERO)`, it never returns
+    /// negative zero; if this `Quad` is less than or equal to `other`, the result is
+    /// positive zero. If either `Quad` is `NaN`, the result is `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(5).abs_sub(qd!(3)) == qd!(2));
+    /// assert!(qd!(3).abs_sub(qd!(5)) == qd!(0));
+    /// assert!(Quad::NAN.abs_sub(qd!(1)).is_nan());
+    /// ```
+    pub fn abs_sub(self, other: Quad) -> Quad {
+        if self.is_nan() || other.is_nan() {
+            Quad::NAN
+        } else if self <= other {
+            Quad::ZERO
+        } else {
+            self - other
+        }
+    }
+
+    /// Subtracts another `Quad` from this one, returning `None` if the difference overflows.
+    ///
+    /// This is the same as the `-` operator, except that a difference that is infinite or
+    /// `NaN` is turned into `None` when both `Quad`s were finite. Infinite and `NaN`
+    /// arguments are handled the same way as they are in [`checked_add`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(5).checked_sub(qd!(3)) == Some(qd!(2)));
+    /// assert!(Quad::MAX.checked_sub(Quad::MIN) == None);
+    /// assert!(Quad::INFINITY.checked_sub(qd!(1)) == Some(Quad::INFINITY));
+    /// ```
+    ///
+    /// [`checked_add`]: #method.checked_add
+    pub fn checked_sub(self, other: Quad) -> Option<Quad> {
+        let r = self - other;
+        if r.is_finite() || !(self.is_finite() && other.is_finite()) {
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    /// Subtracts another `Quad` from this one, rounding the difference in the direction
+    /// given by `mode`.
+    ///
+    /// This works the same way as [`add_round`]: the difference from the `-` operator is
+    /// compared exactly with the true difference of the components, and stepped until it's
+    /// on the side of it that `mode` asks for. [`RoundMode::Up`] and [`RoundMode::Down`]
+    /// always bracket the exact difference, and [`RoundMode::Nearest`] gives the same
+    /// result as `-`.
+    ///
+    /// If either `Quad` is infinite or `NaN`, the result is the same as it is with `-`.
+    /// When a difference of finite `Quad`s overflows, the result is an infinity if `mode`
+    /// rounds it away from zero and [`MAX`] or [`MIN`] if `mode` rounds it toward zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad, RoundMode};
+    /// let x = qd!(1) / qd!(3);
+    /// let y = qd!(1) / qd!(7e40);
+    /// assert!(x.sub_round(y, RoundMode::Up) > x.sub_round(y, RoundMode::Down));
+    ///
+    /// // Exact differences aren't moved
+    /// assert!(qd!(3.75).sub_round(qd!(2.25), RoundMode::Up) == qd!(1.5));
+    /// ```
+    ///
+    /// [`add_round`]: #method.add_round
+    /// [`MAX`]: #associatedconstant.MAX
+    /// [`MIN`]: #associatedconstant.MIN
+    /// [`RoundMode::Up`]: enum.RoundMode.html#variant.Up
+    /// [`RoundMode::Down`]: enum.RoundMode.html#variant.Down
+    /// [`RoundMode::Nearest`]: enum.RoundMode.html#variant.Nearest
+    pub fn sub_round(self, other: Quad, mode: RoundMode) -> Quad {
+        let r = self - other;
+        if !(self.is_finite() && other.is_finite()) {
+            return r;
+        }
+        c::round_directed(r, mode, |x| {
+            let terms = [
+                x.0, x.1, x.2, x.3, -self.0, -self.1, -self.2, -self.3, other.0, other.1, other.2,
+                other.3,
+            ];
+            b::sum_sign(&terms, &[])
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::f64;
 
     // sub tests
     test_all_near!(
@@ -242,6 +584,218 @@ mod tests {
             Quad::NEG_INFINITY - Quad::NEG_INFINITY;
     );
 
+    // abs_sub tests
+    test_all_exact!(
+        abs_sub_greater:
+            qd!(2),
+            qd!(5).abs_sub(qd!(3));
+        abs_sub_less:
+            Quad::ZERO,
+            qd!(3).abs_sub(qd!(5));
+        abs_sub_equal:
+            Quad::ZERO,
+            Quad::PI.abs_sub(Quad::PI);
+        abs_sub_close:
+            Quad::PI - Quad::E,
+            Quad::PI.abs_sub(Quad::E);
+        abs_sub_neg:
+            qd!(2),
+            qd!(-3).abs_sub(qd!(-5));
+        abs_sub_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.abs_sub(Quad::PI);
+        abs_sub_neg_inf:
+            Quad::ZERO,
+            Quad::NEG_INFINITY.abs_sub(Quad::PI);
+        abs_sub_inf_inf:
+            Quad::ZERO,
+            Quad::INFINITY.abs_sub(Quad::INFINITY);
+        abs_sub_nan:
+            Quad::NAN,
+            Quad::NAN.abs_sub(Quad::PI);
+        abs_sub_nan_other:
+            Quad::NAN,
+            Quad::PI.abs_sub(Quad::NAN);
+    );
+    test_all_assert!(
+        abs_sub_zero_sign:
+            qd!(-3).abs_sub(qd!(5)).is_sign_positive();
+        abs_sub_neg_zero_sign:
+            Quad::NEG_ZERO.abs_sub(Quad::ZERO).is_sign_positive();
+    );
+
+    // mixed tests
+    test_all_near!(
+        quad_double:
+            qd!("-0.4233108251307480031023559119268433812097320240147008876022448400060"),
+            Quad::E - Double::PI;
+        double_quad:
+            qd!("0.4233108251307480031023559119268433812097320240147008876022448400060"),
+            Double::PI - Quad::E;
+        quad_double_error:
+            qd!("-2.994769809718339554641594267875436871069827524400322451068235044950e-33"),
+            Quad::PI - Double::PI;
+    );
+    test_all_exact!(
+        quad_double_inf:
+            Quad::INFINITY,
+            Quad::INFINITY - Double::ONE;
+        double_quad_inf:
+            Quad::INFINITY,
+            Double::INFINITY - Quad::ONE;
+        quad_double_nan:
+            Quad::NAN,
+            Quad::ONE - Double::NAN;
+        double_quad_nan:
+            Quad::NAN,
+            Double::NAN - Quad::ONE;
+    );
+
+    // f64 tests
+    test_all_exact!(
+        f64_int:
+            qd!(1),
+            qd!(3) - 2.0;
+        f64_rev_int:
+            qd!(-1),
+            2.0 - qd!(3);
+        f64_id:
+            Quad::PI,
+            Quad::PI - 0.0;
+        f64_inf:
+            Quad::NEG_INFINITY,
+            Quad::ONE - f64::INFINITY;
+        f64_rev_inf:
+            Quad::INFINITY,
+            f64::INFINITY - Quad::ONE;
+        f64_inf_inf:
+            Quad::NAN,
+            Quad::INFINITY - f64::INFINITY;
+        f64_nan:
+            Quad::NAN,
+            Quad::ONE - f64::NAN;
+        f64_rev_nan:
+            Quad::NAN,
+            f64::NAN - Quad::ONE;
+    );
+    test_all_near!(
+        f64_num:
+            Quad::PI - Quad(1.1, 0.0, 0.0, 0.0),
+            Quad::PI - 1.1;
+        f64_ref:
+            Quad::PI - Quad(1.1, 0.0, 0.0, 0.0),
+            &Quad::PI - 1.1;
+        f64_rev_num:
+            Quad(1.1, 0.0, 0.0, 0.0) - Quad::PI,
+            1.1 - Quad::PI;
+        f64_rev_ref:
+            Quad(1.1, 0.0, 0.0, 0.0) - Quad::PI,
+            1.1 - &Quad::PI;
+        f64_cancel:
+            Quad::E - Quad(f64::consts::E, 0.0, 0.0, 0.0),
+            Quad::E - f64::consts::E;
+    );
+
+    test_all_assert!(
+        f64_rev_zero:
+            (0.0 - Quad::ZERO).is_sign_positive();
+    );
+
+    // checked_sub tests
+    test_all_eq!(
+        checked_sub_finite:
+            Some(qd!(2)),
+            qd!(5).checked_sub(qd!(3));
+        checked_sub_overflow:
+            None,
+            Quad::MAX.checked_sub(Quad::MIN);
+        checked_sub_neg_overflow:
+            None,
+            Quad::MIN.checked_sub(Quad::MAX);
+        checked_sub_inf:
+            Some(Quad::NEG_INFINITY),
+            qd!(1).checked_sub(Quad::INFINITY);
+    );
+    test_all_assert!(
+        checked_sub_nan:
+            Quad::NAN.checked_sub(qd!(1)).unwrap().is_nan();
+        checked_sub_inf_inf:
+            Quad::INFINITY.checked_sub(Quad::INFINITY).unwrap().is_nan();
+    );
+
+    // sub_round tests
+    test!(sub_round_inexact: {
+        let x = qd!(1) / qd!(3);
+        let y = qd!(1) / qd!(7e40);
+        let up = x.sub_round(y, RoundMode::Up);
+        let down = x.sub_round(y, RoundMode::Down);
+        let nearest = x - y;
+        assert!(up > down);
+        assert!(down <= nearest && nearest <= up);
+        assert!(up - down <= nearest.abs() * qd!(2f64.powi(-205)));
+    });
+    test!(sub_round_bracket: {
+        let values = [Quad::PI, Quad::E, -Quad::LN_2, qd!(7), qd!(-1e10), qd!(3e-20)];
+        for &a in &values {
+            for &b in &values {
+                let nearest = a - b;
+                let up = a.sub_round(b, RoundMode::Up);
+                let down = a.sub_round(b, RoundMode::Down);
+                assert!(down <= nearest && nearest <= up, "{} - {} is not bracketed", a, b);
+                assert!(up - down <= nearest.abs() * qd!(2f64.powi(-205)));
+            }
+        }
+    });
+    test_all_exact!(
+        sub_round_nearest:
+            Quad::PI - Quad::E,
+            Quad::PI.sub_round(Quad::E, RoundMode::Nearest);
+        sub_round_exact_up:
+            qd!(1.5),
+            qd!(3.75).sub_round(qd!(2.25), RoundMode::Up);
+        sub_round_exact_down:
+            qd!(1.5),
+            qd!(3.75).sub_round(qd!(2.25), RoundMode::Down);
+        sub_round_toward_zero_pos:
+            Quad::PI.sub_round(Quad::E, RoundMode::Down),
+            Quad::PI.sub_round(Quad::E, RoundMode::TowardZero);
+        sub_round_toward_zero_neg:
+            (-Quad::PI).sub_round(Quad::E, RoundMode::Up),
+            (-Quad::PI).sub_round(Quad::E, RoundMode::TowardZero);
+        sub_round_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.sub_round(qd!(1), RoundMode::Down);
+    );
+    test_all_assert!(
+        sub_round_nan:
+            Quad::NAN.sub_round(qd!(1), RoundMode::Up).is_nan();
+    );
+
+    // integer tests
+    test_all_exact!(
+        int_i32:
+            qd!(1),
+            qd!(3) - 2;
+        int_rev_i32:
+            qd!(-1),
+            2 - qd!(3);
+        int_u32:
+            qd!(1),
+            qd!(3) - 2u32;
+        int_rev_u32:
+            qd!(-1),
+            2u32 - qd!(3);
+        int_neg:
+            qd!(5),
+            qd!(3) - -2;
+        int_min:
+            qd!(2147483651.0),
+            qd!(3) - i32::MIN;
+        int_ref:
+            Quad::PI - Quad::from(2.0),
+            &Quad::PI - 2;
+    );
+
     // Assign tests. Assign code delegates to sub code, so there's no need to re-test all
     // of the cases above.
     test_all!(
@@ -255,5 +809,21 @@ mod tests {
             b -= &Quad::E;
             near!(qd!("0.42331082513074800310235591192684038643992230567514624600797696458298"), b);
         }
+        assign_f64: {
+            let mut c = Quad::PI;
+            c -= 1.1;
+            near!(Quad::PI - Quad(1.1, 0.0, 0.0, 0.0), c);
+        }
+        assign_int: {
+            let mut e = Quad::PI;
+            e -= 2;
+            e -= 3u32;
+            exact!(Quad::PI - 2.0 - 3.0, e);
+        }
+        assign_double: {
+            let mut d = Quad::PI;
+            d -= Double::E;
+            exact!(Quad::PI - Quad::from(Double::E), d);
+        }
     );
 }
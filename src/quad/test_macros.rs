@@ -5,8 +5,8 @@
 
 macro_rules! prec {
     ($expected:expr, $actual:expr, $digits:expr $(,)?) => {
-        let expected = Quad::from($expected);
-        let actual = Quad::from($actual);
+        let expected = qd!($expected);
+        let actual = qd!($actual);
         let mag = if expected.is_zero() {
             1
         } else {
@@ -52,8 +52,8 @@ macro_rules! near {
 
 macro_rules! exact {
     ($expected:expr, $actual:expr $(,)?) => {
-        let expected = Quad::from($expected);
-        let actual = Quad::from($actual);
+        let expected = qd!($expected);
+        let actual = qd!($actual);
         let message = format!(
             concat!(
                 "\n",
@@ -83,12 +83,6 @@ macro_rules! exact {
     };
 }
 
-macro_rules! test {
-    ($name:ident: { $($tt:tt)* }) => {
-        #[test] fn $name() { $($tt)* }
-    };
-}
-
 macro_rules! test_prec {
     ($name:ident: $expected:expr, $actual:expr, $digits:expr $(,)?) => {
         #[test]
@@ -126,16 +126,10 @@ impl Quad {
 
     /// Calculates the natural logarithm, log<sub>*e*</sub>, of the `Quad`.
     ///
-    /// This calculation relies upon the [`exp`] calculation, in the opposite direction. A
-    /// large positive logarithm, for example, will require the calculation of a large
-    /// negative exponential.
-    ///
-    /// For the same reasons that negative values of [`exp`] are limited to -470, the
-    /// accurate results of this function are limited to the number whose logarithm is 460,
-    /// which is around 2 &times; 10<sup>200</sup>. Take care with this; unlike in
-    /// [`exp`], [`INFINITY`] is *not* returned. In that function, exceeding the maximum
-    /// refers to actually overflowing an `f64`, which is appropriate to call [`INFINITY`];
-    /// here, it means `470`.
+    /// This calculation relies upon the [`exp`] calculation, in the opposite direction.
+    /// The power of two nearest to the number is factored out first, so that the
+    /// exponential never has to come anywhere near over- or underflowing, which keeps the
+    /// result accurate over the whole range of `Quad`s, subnormals included.
     ///
     /// # Examples
     /// ```
@@ -148,7 +142,6 @@ impl Quad {
     /// ```
     ///
     /// [`exp`]: #method.exp
-    /// [`INFINITY`]: #associatedconstant.INFINITY
     pub fn ln(self) -> Quad {
         match self.pre_ln() {
             Some(r) => r,
@@ -171,30 +164,37 @@ impl Quad {
                 //
                 // Because the derivative of exp(x) is exp(x), this is perhaps the simplest
                 // of all Newton iterations.
-                let mut x = Quad(self.0.ln(), 0.0, 0.0, 0.0); // initial approximation
+                //
+                // The exponential of a large logarithm over- or underflows, so the power of
+                // two nearest to the number is taken out of it first, leaving a number
+                // between √½ and √2. The logarithm of the power is just a multiple of
+                // ln(2), since ln(a × 2ⁿ) = ln(a) + n ln(2). The scaling is done in two
+                // steps because 2⁻ⁿ doesn't fit into an `f64` for the smallest subnormals.
+                let n = self.0.log2().round() as i32;
+                let a = self.ldexp(-n / 2).ldexp(n / 2 - n);
+                let mut x = Quad(a.0.ln(), 0.0, 0.0, 0.0); // initial approximation
 
                 let k = x.0.abs().log2().floor() as i32;
                 let eps = c::mul_pwr2(Quad::EPSILON, 2f64.powi(k + 2));
 
                 let mut i = 0;
-                loop {
-                    let r = x + self * (-x).exp() - Quad::ONE;
+                let r = loop {
+                    let r = x + a * (-x).exp() - Quad::ONE;
                     if (x - r).abs() < eps || i > 5 {
-                        return r;
+                        break r;
                     }
                     x = r;
                     i += 1;
-                }
+                };
+                r + Quad::LN_2 * Quad::from(n)
             }
         }
     }
 
     /// Calculates the base-10 logarithm, log<sub>10</sub>, of the `Quad`.
     ///
-    /// As with [`ln`], this has an upper usable range less than the size of the numbers
-    /// themselves. In this case, that upper limit is around 10<sup>200</sup>. Over this
-    /// number, the output is not reliable, but it does not return [`INFINITY`] because the
-    /// number 200 is so plainly not infinite.
+    /// This is the natural logarithm from [`ln`] divided by ln(10), so it's accurate over
+    /// the same range.
     ///
     /// # Examples
     /// ```
@@ -206,7 +206,6 @@ impl Quad {
     /// assert!(diff < qd!(1e-60));
     /// ```
     ///
-    /// [`INFINITY`]: #associatedconstant.INFINITY
     /// [`ln`]: #method.ln
     #[inline]
     pub fn log10(self) -> Quad {
@@ -475,6 +474,18 @@ mod tests {
         ln_neg_250:
             qd!("-575.64627324851142100449786367109105190027537215719324400833197524208"),
             qd!("1e-250").ln();
+        ln_300:
+            qd!("690.7755278982137052579021966605136811506599904414932315503943764831"),
+            Quad(1e300, 0.0, 0.0, 0.0).ln();
+        ln_neg_300:
+            qd!("-690.7755278982137051803383445701005029086133415836413440625472017900"),
+            Quad(1e-300, 0.0, 0.0, 0.0).ln();
+        ln_max:
+            qd!("709.7827128933839967877345411419149740658986876509939181273658937494"),
+            Quad::MAX.ln();
+        ln_min_subnormal:
+            qd!("-744.4400719213812623141072984460816341130871443029141429256103301959"),
+            Quad(5e-324, 0.0, 0.0, 0.0).ln();
     );
     test_all_exact!(
         ln_neg_pi:
@@ -191,6 +191,108 @@ impl Quad {
         s / c
     }
 
+    /// Computes the cotangent (cot) of the `Quad`, the reciprocal of its tangent.
+    ///
+    /// The cotangent has poles at every multiple of π. Arguments close to a pole produce
+    /// very large results. An argument that is zero, or that is so close to a multiple of π
+    /// that its sine is calculated as zero, is taken to be at the pole and produces an
+    /// infinity. Since the cotangent is an odd function, that infinity is positive for
+    /// positive arguments and negative for negative ones (including negative zero).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::FRAC_PI_4.cot();
+    /// let expected = qd!(1);
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// assert!(Quad::ZERO.cot() == Quad::INFINITY);
+    /// ```
+    pub fn cot(self) -> Quad {
+        match self.pre_cot() {
+            Some(r) => r,
+            None => {
+                let (s, c) = self.abs().sin_cos();
+                let r = if s.is_zero() { Quad::INFINITY } else { c / s };
+                if self.is_sign_negative() {
+                    -r
+                } else {
+                    r
+                }
+            }
+        }
+    }
+
+    /// Computes the secant (sec) of the `Quad`, the reciprocal of its cosine.
+    ///
+    /// The secant has poles at every odd multiple of π/2. Arguments close to a pole produce
+    /// very large results. An argument that is so close to an odd multiple of π/2 that its
+    /// cosine is calculated as zero is taken to be at the pole and produces positive
+    /// infinity, whatever its sign, since the secant is an even function.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::ZERO.sec() == Quad::ONE);
+    ///
+    /// let x = Quad::FRAC_PI_3.sec();
+    /// let expected = qd!(2);
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    pub fn sec(self) -> Quad {
+        match self.pre_sec() {
+            Some(r) => r,
+            None => {
+                let c = self.abs().cos();
+                if c.is_zero() {
+                    Quad::INFINITY
+                } else {
+                    c.recip()
+                }
+            }
+        }
+    }
+
+    /// Computes the cosecant (csc) of the `Quad`, the reciprocal of its sine.
+    ///
+    /// The cosecant has poles at every multiple of π. Arguments close to a pole produce
+    /// very large results. An argument that is zero, or that is so close to a multiple of π
+    /// that its sine is calculated as zero, is taken to be at the pole and produces an
+    /// infinity. Since the cosecant is an odd function, that infinity is positive for
+    /// positive arguments and negative for negative ones (including negative zero).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::FRAC_PI_2.csc();
+    /// let expected = qd!(1);
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// assert!(Quad::NEG_ZERO.csc() == Quad::NEG_INFINITY);
+    /// ```
+    pub fn csc(self) -> Quad {
+        match self.pre_csc() {
+            Some(r) => r,
+            None => {
+                let s = self.abs().sin();
+                let r = if s.is_zero() {
+                    Quad::INFINITY
+                } else {
+                    s.recip()
+                };
+                if self.is_sign_negative() {
+                    -r
+                } else {
+                    r
+                }
+            }
+        }
+    }
+
     /// Computes the 2-argument inverse tangent (tan<sup>-1</sup>) of this `Quad` and
     /// another `Quad`.
     ///
@@ -386,6 +488,47 @@ impl Quad {
         }
     }
 
+    #[inline]
+    fn pre_cot(&self) -> Option<Quad> {
+        if self.is_zero() {
+            if self.is_sign_negative() {
+                Some(Quad::NEG_INFINITY)
+            } else {
+                Some(Quad::INFINITY)
+            }
+        } else if !self.is_finite() {
+            Some(Quad::NAN)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_sec(&self) -> Option<Quad> {
+        if self.is_zero() {
+            Some(Quad::ONE)
+        } else if !self.is_finite() {
+            Some(Quad::NAN)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_csc(&self) -> Option<Quad> {
+        if self.is_zero() {
+            if self.is_sign_negative() {
+                Some(Quad::NEG_INFINITY)
+            } else {
+                Some(Quad::INFINITY)
+            }
+        } else if !self.is_finite() {
+            Some(Quad::NAN)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn pre_atan2(&self, other: &Quad) -> Option<Quad> {
         if other.is_zero() {
@@ -921,6 +1064,150 @@ mod tests {
             Quad::NAN.tan();
     );
 
+    // cot tests
+    test_all_near!(
+        cot_one:
+            qd!("0.64209261593433070300641998659426562023027811391817137910116228042627"),
+            Quad::ONE.cot();
+        cot_neg_2_5:
+            qd!("1.33864812830415136021088702399024513004058654784608106974234862674418"),
+            qd!(-2.5).cot();
+        cot_ten:
+            qd!("1.54235104535692004827746935568242931132066720640196249091947160619819"),
+            qd!(10).cot();
+    );
+    test_all_prec!(
+        cot_pi_4:
+            Quad::ONE,
+            Quad::FRAC_PI_4.cot(),
+            60;
+        cot_neg_pi_4:
+            Quad::NEG_ONE,
+            (-Quad::FRAC_PI_4).cot(),
+            60;
+    );
+    test_all_exact!(
+        cot_zero:
+            Quad::INFINITY,
+            Quad::ZERO.cot();
+        cot_neg_zero:
+            Quad::NEG_INFINITY,
+            Quad::NEG_ZERO.cot();
+        cot_inf:
+            Quad::NAN,
+            Quad::INFINITY.cot();
+        cot_neg_inf:
+            Quad::NAN,
+            Quad::NEG_INFINITY.cot();
+        cot_nan:
+            Quad::NAN,
+            Quad::NAN.cot();
+    );
+    test_all_assert!(
+        cot_pi:
+            Quad::PI.cot() > qd!(1e30);
+        cot_neg_pi:
+            (-Quad::PI).cot() < qd!(-1e30);
+        cot_odd:
+            (-Quad::E).cot() == -Quad::E.cot();
+    );
+
+    // sec tests
+    test_all_near!(
+        sec_one:
+            qd!("1.85081571768092561791175324139865019347039665509400929883515827785881"),
+            Quad::ONE.sec();
+        sec_neg_2_5:
+            qd!("-1.2482156514688178309064332740748829915782862657840703162104107060071"),
+            qd!(-2.5).sec();
+        sec_ten:
+            qd!("-1.1917935066878958108796938984273465902047299220035186727706136445417"),
+            qd!(10).sec();
+    );
+    test_all_prec!(
+        sec_pi_3:
+            qd!(2),
+            Quad::FRAC_PI_3.sec(),
+            60;
+        sec_3_pi_4:
+            -Quad::SQRT_2,
+            Quad::FRAC_3_PI_4.sec(),
+            60;
+    );
+    test_all_exact!(
+        sec_zero:
+            Quad::ONE,
+            Quad::ZERO.sec();
+        sec_neg_zero:
+            Quad::ONE,
+            Quad::NEG_ZERO.sec();
+        sec_inf:
+            Quad::NAN,
+            Quad::INFINITY.sec();
+        sec_neg_inf:
+            Quad::NAN,
+            Quad::NEG_INFINITY.sec();
+        sec_nan:
+            Quad::NAN,
+            Quad::NAN.sec();
+    );
+    test_all_assert!(
+        sec_pi_2:
+            Quad::FRAC_PI_2.sec() > qd!(1e30);
+        sec_neg_pi_2:
+            (-Quad::FRAC_PI_2).sec() > qd!(1e30);
+        sec_even:
+            (-Quad::E).sec() == Quad::E.sec();
+    );
+
+    // csc tests
+    test_all_near!(
+        csc_one:
+            qd!("1.18839510577812121626159945237455100352782983409796262526525366635918"),
+            Quad::ONE.csc();
+        csc_neg_2_5:
+            qd!("-1.6709215455586799279466768703959988013510511517599811113052697904504"),
+            qd!(-2.5).csc();
+        csc_ten:
+            qd!("-1.8381639608896655887052365237649145973889020018834973862573493747201"),
+            qd!(10).csc();
+    );
+    test_all_prec!(
+        csc_pi_2:
+            Quad::ONE,
+            Quad::FRAC_PI_2.csc(),
+            60;
+        csc_pi_6:
+            qd!(2),
+            Quad::FRAC_PI_6.csc(),
+            60;
+    );
+    test_all_exact!(
+        csc_zero:
+            Quad::INFINITY,
+            Quad::ZERO.csc();
+        csc_neg_zero:
+            Quad::NEG_INFINITY,
+            Quad::NEG_ZERO.csc();
+        csc_inf:
+            Quad::NAN,
+            Quad::INFINITY.csc();
+        csc_neg_inf:
+            Quad::NAN,
+            Quad::NEG_INFINITY.csc();
+        csc_nan:
+            Quad::NAN,
+            Quad::NAN.csc();
+    );
+    test_all_assert!(
+        csc_pi:
+            Quad::PI.csc().abs() > qd!(1e30);
+        csc_neg_pi:
+            (-Quad::PI).csc() == -Quad::PI.csc();
+        csc_odd:
+            (-Quad::E).csc() == -Quad::E.csc();
+    );
+
     // atan2 test
     test_all_near!(
         atan2_pos_pos:
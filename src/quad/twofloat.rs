@@ -0,0 +1,140 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::utils as u;
+use crate::quad::Quad;
+use twofloat::TwoFloat;
+
+impl From<TwoFloat> for Quad {
+    /// Generates a `Quad` from a [`TwoFloat`], exactly.
+    ///
+    /// A `TwoFloat` is an unevaluated sum of a high and a low `f64`, so its two parts become
+    /// the first two components of the `Quad`, and the other two are zero.
+    ///
+    /// This is only available when the `twofloat` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use twofloat::TwoFloat;
+    ///
+    /// let x = TwoFloat::new_add(1.0, 1e-20);
+    /// let q = Quad::from(x);
+    /// assert!(q == Quad::from((1.0, 1e-20)));
+    ///
+    /// // The `Quad` can then be used for calculations that need more precision
+    /// let y = Quad::from(TwoFloat::from(0.5)).asin();
+    /// assert!((y - Quad::FRAC_PI_6).abs() < qd!(1e-60));
+    /// ```
+    ///
+    /// [`TwoFloat`]: https://docs.rs/twofloat/0.8/twofloat/struct.TwoFloat.html
+    #[inline]
+    fn from(x: TwoFloat) -> Quad {
+        Quad(x.hi(), x.lo(), 0.0, 0.0)
+    }
+}
+
+impl From<Quad> for TwoFloat {
+    /// Converts a `Quad` into a [`TwoFloat`], rounding it to double-double precision.
+    ///
+    /// The high part of the `TwoFloat` is the first component of the `Quad`, and the low part
+    /// is the rest of the `Quad` rounded to an `f64`. `NaN`, the infinities, and the sign of
+    /// zero all carry over.
+    ///
+    /// This is only available when the `twofloat` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use twofloat::TwoFloat;
+    ///
+    /// let x = TwoFloat::from(Quad::PI);
+    /// assert!(x.hi() == Quad::PI[0] && x.lo() == Quad::PI[1]);
+    ///
+    /// let third = qd!(1) / qd!(3);
+    /// let y = TwoFloat::from(third);
+    /// assert!((Quad::from(y) - third).abs() < qd!(1e-32));
+    /// ```
+    ///
+    /// [`TwoFloat`]: https://docs.rs/twofloat/0.8/twofloat/struct.TwoFloat.html
+    fn from(x: Quad) -> TwoFloat {
+        // `TwoFloat` has no way to set its parts directly, but for a normalized pair of
+        // components, the two-sum that `new_add` does gives them back unchanged. That isn't
+        // true of infinities or of negative zero, which have no other components anyway.
+        if x.1 == 0.0 || !x.0.is_finite() {
+            TwoFloat::from(x.0)
+        } else {
+            let (a, b) = u::renorm2(x.0, x.1 + x.2);
+            TwoFloat::new_add(a, b)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parts(x: TwoFloat) -> (f64, f64) {
+        (x.hi(), x.lo())
+    }
+
+    // from_twofloat tests
+    test_all_exact!(
+        from_twofloat_f64:
+            qd!(1.5),
+            Quad::from(TwoFloat::from(1.5));
+        from_twofloat_pair:
+            Quad(1.0, 1e-20, 0.0, 0.0),
+            Quad::from(TwoFloat::new_add(1.0, 1e-20));
+        from_twofloat_neg_pair:
+            Quad(-1.0, 1e-20, 0.0, 0.0),
+            Quad::from(TwoFloat::new_add(-1.0, 1e-20));
+        from_twofloat_inf:
+            Quad::INFINITY,
+            Quad::from(TwoFloat::from(f64::INFINITY));
+        from_twofloat_neg_inf:
+            Quad::NEG_INFINITY,
+            Quad::from(TwoFloat::from(f64::NEG_INFINITY));
+    );
+    test!(from_twofloat_special: {
+        assert!(Quad::from(TwoFloat::from(f64::NAN)).is_nan());
+        let neg_zero = Quad::from(TwoFloat::from(-0.0));
+        assert!(neg_zero.is_zero() && neg_zero.is_sign_negative());
+    });
+    test!(from_twofloat_arithmetic: {
+        let x = TwoFloat::new_add(1.0, 1e-20) * TwoFloat::from(3.0);
+        // `TwoFloat` only has double-double precision, so the product has been rounded
+        assert!((Quad::from(x) - Quad::from((1.0, 1e-20)) * qd!(3)).abs() < qd!(1e-35));
+    });
+
+    // to_twofloat tests
+    test!(to_twofloat_rounded: {
+        for x in [Quad::PI, -Quad::E, Quad::LN_2 * qd!(1e200), qd!(1) / qd!(3)] {
+            let t = TwoFloat::from(x);
+            assert_eq!(t.hi(), x.0);
+            assert!((Quad::from(t) - x).abs() < x.abs() * qd!(1e-31));
+        }
+        // The third component rounds the second one up
+        let x = Quad(1.0, 2f64.powi(-60), 2f64.powi(-113) * 1.5, 0.0);
+        assert_eq!(parts(TwoFloat::from(x)), (1.0, 2f64.powi(-60) + 2f64.powi(-112)));
+    });
+    test!(to_twofloat_exact: {
+        let x = Quad(1.5, 1e-20, 0.0, 0.0);
+        assert_eq!(parts(TwoFloat::from(x)), (1.5, 1e-20));
+        assert!(Quad::from(TwoFloat::from(x)) == x);
+    });
+    test!(to_twofloat_special: {
+        assert!(TwoFloat::from(Quad::NAN).hi().is_nan());
+        assert_eq!(parts(TwoFloat::from(Quad::INFINITY)), (f64::INFINITY, 0.0));
+        assert_eq!(parts(TwoFloat::from(Quad::NEG_INFINITY)), (f64::NEG_INFINITY, 0.0));
+        let neg_zero = TwoFloat::from(Quad::NEG_ZERO);
+        assert!(neg_zero.hi() == 0.0 && neg_zero.hi().is_sign_negative());
+    });
+    test!(to_twofloat_valid: {
+        for x in [Quad::PI, Quad::LN_2 * qd!(1e200), Quad::SQRT_2 / qd!(1e200)] {
+            assert!(TwoFloat::from(x).is_valid());
+        }
+    });
+}
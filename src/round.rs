@@ -0,0 +1,68 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+/// The direction in which to round the result of an operation that takes a rounding mode.
+///
+/// These correspond to the rounding-direction attributes defined by IEEE 754. The ordinary
+/// arithmetic operators always round to nearest.
+///
+/// This is for rounding to the nearby binary numbers that a `Double` or `Quad` can
+/// represent, where a result exactly halfway between two of them can't happen in the
+/// operations that take it. Rounding to a number of decimal digits, where halfway cases
+/// are common, uses [`RoundingMode`] instead, which has the same three directed modes
+/// along with a choice of what to do with those ties.
+///
+/// [`RoundingMode`]: enum.RoundingMode.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundMode {
+    /// Rounds to the nearest representable value. This gives the same results as the
+    /// ordinary operators.
+    Nearest,
+    /// Rounds toward negative infinity, so that the result is never greater than the exact
+    /// value.
+    Down,
+    /// Rounds toward positive infinity, so that the result is never less than the exact
+    /// value.
+    Up,
+    /// Rounds toward zero, so that the result is never greater in magnitude than the exact
+    /// value.
+    TowardZero,
+}
+
+/// The way to round a number to a given number of decimal digits, for operations that
+/// round to decimal places or significant digits rather than to the nearest `Double` or
+/// `Quad`.
+///
+/// Unlike [`RoundMode`], which only has to say which way to go when a result falls between
+/// two representable numbers, this has to say which way to go when a number is exactly
+/// halfway between two decimals, as a number like 2.5 often is. The rounding is always done
+/// on the exact value of the number (the sum of its components). Its `Down`, `Up`, and
+/// `TowardZero` modes go in the same directions as the ones of `RoundMode`, but toward
+/// decimals rather than binary numbers.
+///
+/// [`RoundMode`]: enum.RoundMode.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Rounds to the nearest decimal, with ties going to the one whose last digit is even.
+    /// This is also called banker's rounding, and it's what IEEE 754 calls
+    /// roundTiesToEven.
+    HalfEven,
+    /// Rounds to the nearest decimal, with ties going to the one that's larger in
+    /// magnitude, so -2.5 rounds to -3. This is the rounding that's usually taught in
+    /// school, the one that [`Double::round`] does. It differs from
+    /// [`Double::round_half_up`], which always sends ties toward positive infinity.
+    ///
+    /// [`Double::round`]: struct.Double.html#method.round
+    /// [`Double::round_half_up`]: struct.Double.html#method.round_half_up
+    HalfAwayFromZero,
+    /// Rounds toward negative infinity, so that the result is never greater than the
+    /// number.
+    Down,
+    /// Rounds toward positive infinity, so that the result is never less than the number.
+    Up,
+    /// Rounds toward zero, so that the result is never greater in magnitude than the
+    /// number. This just drops the extra digits.
+    TowardZero,
+}
@@ -0,0 +1,13 @@
+// Copyright (c) 2021 Thomas J. Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// This one doesn't depend on the type being tested, so it's defined here, where it's
+// available to every module and not only to `double` and `quad` (which have the rest of the
+// test macros).
+macro_rules! test {
+    ($name:ident: { $($tt:tt)* }) => {
+        #[test] fn $name() { $($tt)* }
+    };
+}